@@ -0,0 +1,91 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use thiserror::Error;
+
+use crate::program_ids::{AUCTIONEER, AUCTION_HOUSE};
+
+/// Failures shared by the auction programs, with codes that stay fixed whatever the programs
+/// number them.
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SharedError {
+    // 9000
+    #[error("Bump seed not in hash map")]
+    BumpSeedNotInHashMap = 9000,
+
+    // 9001
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+
+    // 9002
+    #[error("Auction has not started yet")]
+    AuctionNotStarted,
+
+    // 9003
+    #[error("Auction has ended")]
+    AuctionEnded,
+
+    // 9004
+    #[error("Auction has not ended yet")]
+    AuctionActive,
+
+    // 9005
+    #[error("The bid was lower than the highest bid")]
+    BidTooLow,
+}
+
+/// Auction House error codes for the shared errors it can return.
+const AUCTION_HOUSE_CODES: &[(u32, SharedError)] = &[
+    (6007, SharedError::NumericalOverflow),
+    (6034, SharedError::BumpSeedNotInHashMap),
+];
+
+/// Auctioneer error codes for the shared errors it can return.
+const AUCTIONEER_CODES: &[(u32, SharedError)] = &[
+    (6000, SharedError::BumpSeedNotInHashMap),
+    (6001, SharedError::AuctionNotStarted),
+    (6002, SharedError::AuctionEnded),
+    (6003, SharedError::AuctionActive),
+    (6004, SharedError::BidTooLow),
+];
+
+fn program_codes(program_id: &Pubkey) -> &'static [(u32, SharedError)] {
+    if *program_id == AUCTION_HOUSE {
+        AUCTION_HOUSE_CODES
+    } else if *program_id == AUCTIONEER {
+        AUCTIONEER_CODES
+    } else {
+        &[]
+    }
+}
+
+impl SharedError {
+    /// The shared error behind custom error `code` returned by `program_id`, if it is one.
+    pub fn from_program_code(program_id: &Pubkey, code: u32) -> Option<Self> {
+        program_codes(program_id)
+            .iter()
+            .find(|(program_code, _)| *program_code == code)
+            .map(|(_, error)| *error)
+    }
+
+    /// The shared error behind `error` returned by `program_id`, if it is one.
+    pub fn from_program_error(program_id: &Pubkey, error: &ProgramError) -> Option<Self> {
+        match error {
+            ProgramError::Custom(code) => Self::from_program_code(program_id, *code),
+            _ => None,
+        }
+    }
+
+    /// The code `program_id` reports this error with, if the program can return it.
+    pub fn program_code(self, program_id: &Pubkey) -> Option<u32> {
+        program_codes(program_id)
+            .iter()
+            .find(|(_, error)| *error == self)
+            .map(|(code, _)| *code)
+    }
+}
+
+impl From<SharedError> for ProgramError {
+    fn from(e: SharedError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
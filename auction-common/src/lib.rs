@@ -0,0 +1,17 @@
+//! Definitions shared by the Metaplex Auction House and Auctioneer programs.
+//!
+//! Each program numbers its errors independently. [`errors::SharedError`] gives the failures
+//! the programs have in common one stable code, so clients handling several programs match on a
+//! single enum. [`seeds`] holds the PDA seeds more than one program derives, so they change in
+//! one place.
+pub mod errors;
+pub mod seeds;
+
+pub use errors::SharedError;
+
+pub mod program_ids {
+    use solana_program::{pubkey, pubkey::Pubkey};
+
+    pub const AUCTION_HOUSE: Pubkey = pubkey!("hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk");
+    pub const AUCTIONEER: Pubkey = pubkey!("neer8g6yJq2mQM6KbnViEDAD4gr3gRZyMMf4F2p3MEh");
+}
@@ -0,0 +1,82 @@
+//! Seeds of the accounts derived by more than one program, and builders putting them in order.
+//!
+//! Derive with `Pubkey::find_program_address(&seeds::fee_payer(&auction_house), &program_id)`,
+//! or append the bump and sign with them.
+use solana_program::pubkey::Pubkey;
+
+pub const PREFIX: &str = "auction_house";
+pub const FEE_PAYER: &str = "fee_payer";
+pub const TREASURY: &str = "treasury";
+pub const SIGNER: &str = "signer";
+pub const AUCTIONEER: &str = "auctioneer";
+pub const LISTING_CONFIG: &str = "listing_config";
+
+/// Auction House instance, derived by Auction House.
+pub fn auction_house<'a>(creator: &'a Pubkey, treasury_mint: &'a Pubkey) -> [&'a [u8]; 3] {
+    [PREFIX.as_bytes(), creator.as_ref(), treasury_mint.as_ref()]
+}
+
+/// Auction House fee account, derived by Auction House.
+pub fn fee_payer(auction_house: &Pubkey) -> [&[u8]; 3] {
+    [
+        PREFIX.as_bytes(),
+        auction_house.as_ref(),
+        FEE_PAYER.as_bytes(),
+    ]
+}
+
+/// Auction House treasury account, derived by Auction House.
+pub fn treasury(auction_house: &Pubkey) -> [&[u8]; 3] {
+    [
+        PREFIX.as_bytes(),
+        auction_house.as_ref(),
+        TREASURY.as_bytes(),
+    ]
+}
+
+/// Buyer escrow payment account, derived by Auction House.
+pub fn escrow_payment<'a>(auction_house: &'a Pubkey, wallet: &'a Pubkey) -> [&'a [u8]; 3] {
+    [PREFIX.as_bytes(), auction_house.as_ref(), wallet.as_ref()]
+}
+
+/// Program as signer delegate of listed tokens, derived by Auction House.
+pub fn program_as_signer() -> [&'static [u8]; 2] {
+    [PREFIX.as_bytes(), SIGNER.as_bytes()]
+}
+
+/// Auctioneer PDA storing a delegated auctioneer's scopes, derived by Auction House.
+pub fn auctioneer<'a>(
+    auction_house: &'a Pubkey,
+    auctioneer_authority: &'a Pubkey,
+) -> [&'a [u8]; 3] {
+    [
+        AUCTIONEER.as_bytes(),
+        auction_house.as_ref(),
+        auctioneer_authority.as_ref(),
+    ]
+}
+
+/// Auctioneer authority signing for the Auctioneer program, derived by Auctioneer.
+pub fn auctioneer_authority(auction_house: &Pubkey) -> [&[u8]; 2] {
+    [AUCTIONEER.as_bytes(), auction_house.as_ref()]
+}
+
+/// Listing config, derived by Auctioneer. `token_size` is the little endian token size.
+pub fn listing_config<'a>(
+    wallet: &'a Pubkey,
+    auction_house: &'a Pubkey,
+    token_account: &'a Pubkey,
+    treasury_mint: &'a Pubkey,
+    token_mint: &'a Pubkey,
+    token_size: &'a [u8; 8],
+) -> [&'a [u8]; 7] {
+    [
+        LISTING_CONFIG.as_bytes(),
+        wallet.as_ref(),
+        auction_house.as_ref(),
+        token_account.as_ref(),
+        treasury_mint.as_ref(),
+        token_mint.as_ref(),
+        token_size,
+    ]
+}
@@ -0,0 +1,82 @@
+use mpl_auction_common::{
+    program_ids::{AUCTIONEER, AUCTION_HOUSE},
+    SharedError,
+};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+/// Code of each variant of the error enum in a program's `errors.rs`, numbered from 6000 in
+/// declaration order as Anchor does.
+fn program_error_code(source: &str, variant: &str) -> u32 {
+    let body = &source[source.find("pub enum").unwrap()..];
+    let position = body
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.ends_with(',') && line.chars().next().is_some_and(char::is_uppercase))
+        .position(|line| line.trim_end_matches(',') == variant)
+        .unwrap_or_else(|| panic!("{} is not a program error", variant));
+
+    6000 + position as u32
+}
+
+#[test]
+fn shared_codes_match_program_errors() {
+    let programs = [
+        (
+            AUCTION_HOUSE,
+            include_str!("../../auction-house/program/src/errors.rs"),
+        ),
+        (
+            AUCTIONEER,
+            include_str!("../../auctioneer/program/src/errors.rs"),
+        ),
+    ];
+    let shared = [
+        SharedError::BumpSeedNotInHashMap,
+        SharedError::NumericalOverflow,
+        SharedError::AuctionNotStarted,
+        SharedError::AuctionEnded,
+        SharedError::AuctionActive,
+        SharedError::BidTooLow,
+    ];
+
+    for (program_id, source) in programs {
+        for error in shared {
+            if let Some(code) = error.program_code(&program_id) {
+                assert_eq!(
+                    code,
+                    program_error_code(source, &format!("{:?}", error)),
+                    "{:?}",
+                    error
+                );
+                assert_eq!(
+                    SharedError::from_program_code(&program_id, code),
+                    Some(error)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn program_errors_map_to_shared_errors() {
+    assert_eq!(
+        SharedError::from_program_error(&AUCTIONEER, &ProgramError::Custom(6004)),
+        Some(SharedError::BidTooLow)
+    );
+    assert_eq!(
+        SharedError::from_program_error(&AUCTION_HOUSE, &ProgramError::Custom(6007)),
+        Some(SharedError::NumericalOverflow)
+    );
+    assert_eq!(
+        SharedError::from_program_error(&AUCTION_HOUSE, &ProgramError::Custom(6004)),
+        None
+    );
+    assert_eq!(
+        SharedError::from_program_code(&Pubkey::new_unique(), 6000),
+        None
+    );
+    assert_eq!(
+        ProgramError::from(SharedError::BidTooLow),
+        ProgramError::Custom(9005)
+    );
+}
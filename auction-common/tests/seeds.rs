@@ -0,0 +1,45 @@
+use mpl_auction_common::seeds;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn seed_builders_order_seeds() {
+    let auction_house = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+
+    assert_eq!(
+        seeds::fee_payer(&auction_house),
+        [
+            b"auction_house".as_ref(),
+            auction_house.as_ref(),
+            b"fee_payer".as_ref()
+        ]
+    );
+    assert_eq!(
+        seeds::escrow_payment(&auction_house, &wallet),
+        [
+            b"auction_house".as_ref(),
+            auction_house.as_ref(),
+            wallet.as_ref()
+        ]
+    );
+    assert_eq!(
+        seeds::program_as_signer(),
+        [b"auction_house".as_ref(), b"signer".as_ref()]
+    );
+    assert_eq!(
+        seeds::auctioneer_authority(&auction_house),
+        [b"auctioneer".as_ref(), auction_house.as_ref()]
+    );
+
+    let token_size = 1u64.to_le_bytes();
+    let listing_config = seeds::listing_config(
+        &wallet,
+        &auction_house,
+        &wallet,
+        &auction_house,
+        &wallet,
+        &token_size,
+    );
+    assert_eq!(listing_config[0], b"listing_config");
+    assert_eq!(listing_config[6], token_size);
+}
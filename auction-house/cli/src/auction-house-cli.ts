@@ -6,6 +6,7 @@ import {
   getAuctionHouse,
   getAuctionHouseBuyerEscrow,
   getAuctionHouseFeeAcct,
+  getAuctionHouseLien,
   getAuctionHouseProgramAsSigner,
   getAuctionHouseTradeState,
   getAuctionHouseTreasuryAcct,
@@ -308,6 +309,13 @@ programCommand('sell')
           programAsSigner,
           rent: web3.SYSVAR_RENT_PUBKEY,
         },
+        remainingAccounts: [
+          {
+            pubkey: (await getAuctionHouseLien(auctionHouseKey, mintKey))[0],
+            isWritable: false,
+            isSigner: false,
+          },
+        ],
         signers,
       },
     );
@@ -1295,6 +1303,10 @@ programCommand('create_auction_house')
     '-rso, --requires-sign-off <string>',
     'if true, no txn can occur against this Auction House without AH authority as signer. Good if you are doing all txns through a pass-through GCP or something.',
   )
+  .option(
+    '-im, --immutable <string>',
+    'if true, the Auction House can never be updated after creation.',
+  )
   .option(
     '-twd, --treasury-withdrawal-destination <string>',
     'if you wish to empty the treasury account, this is where it will land, default is your keypair. Pass in a wallet, not an ATA - ATA will be made for you if not present.',
@@ -1311,6 +1323,7 @@ programCommand('create_auction_house')
       sellerFeeBasisPoints,
       canChangeSalePrice,
       requiresSignOff,
+      immutable,
       treasuryWithdrawalDestination,
       feeWithdrawalDestination,
       treasuryMint,
@@ -1362,6 +1375,7 @@ programCommand('create_auction_house')
       sfbp,
       requiresSignOff == 'true',
       canChangeSalePrice == 'true',
+      immutable == 'true',
       {
         accounts: {
           treasuryMint: tMintKey,
@@ -15,6 +15,7 @@ import {
   AUCTION_HOUSE,
   FEE_PAYER,
   TREASURY,
+  LIEN,
   WRAPPED_SOL_MINT,
   TOKEN_ENTANGLEMENT_PROGRAM_ID,
   TOKEN_ENTANGLER,
@@ -453,6 +454,16 @@ export const getAuctionHouseBuyerEscrow = async (
   );
 };
 
+export const getAuctionHouseLien = async (
+  auctionHouse: anchor.web3.PublicKey,
+  tokenMint: anchor.web3.PublicKey,
+): Promise<[PublicKey, number]> => {
+  return await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from(LIEN), auctionHouse.toBuffer(), tokenMint.toBuffer()],
+    AUCTION_HOUSE_PROGRAM_ID,
+  );
+};
+
 export const getAuctionHouseTradeState = async (
   auctionHouse: anchor.web3.PublicKey,
   wallet: anchor.web3.PublicKey,
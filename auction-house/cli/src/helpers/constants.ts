@@ -7,6 +7,7 @@ export const A = 'A';
 export const B = 'B';
 export const FEE_PAYER = 'fee_payer';
 export const TREASURY = 'treasury';
+export const LIEN = 'lien';
 export const MAX_NAME_LENGTH = 32;
 export const MAX_URI_LENGTH = 200;
 export const MAX_SYMBOL_LENGTH = 10;
@@ -0,0 +1,63 @@
+pub use mpl_auction_common::seeds::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER, TREASURY};
+use solana_program::{pubkey, pubkey::Pubkey};
+
+pub const PURCHASE_RECEIPT_PREFIX: &str = "purchase_receipt";
+pub const BID_RECEIPT_PREFIX: &str = "bid_receipt";
+pub const LISTING_RECEIPT_PREFIX: &str = "listing_receipt";
+pub const FEE_STAKE: &str = "fee_stake";
+pub const FEE_STAKE_VAULT: &str = "fee_stake_vault";
+pub const MINT_COOLDOWN: &str = "mint_cooldown";
+pub const AUTHORITY_LOG: &str = "authority_log";
+pub const PROTECTED_SETTLEMENT: &str = "protected_settlement";
+pub const DISPUTE_RECORD: &str = "dispute_record";
+pub const LIEN: &str = "lien";
+pub const FROZEN_PROCEEDS: &str = "frozen_proceeds";
+pub const FROZEN_PROCEEDS_VAULT: &str = "frozen_proceeds_vault";
+pub const LISTING_FEE: &str = "listing_fee";
+pub const DEPOSIT_AUTHORITY: &str = "deposit_authority";
+pub const AUCTIONEER_ALLOWLIST: &str = "auctioneer_allowlist";
+pub const PUBLIC_BID_INDEX: &str = "public_bid_index";
+pub const BID_EXPIRY: &str = "bid_expiry";
+pub const COMPLIANCE_CONFIG: &str = "compliance_config";
+pub const RECEIPT_TREE: &str = "receipt_tree";
+pub const TAX_WITHHOLDING: &str = "tax_withholding";
+pub const WANTED_LISTING: &str = "wanted_listing";
+pub const FEE_SWEEP_CONFIG: &str = "fee_sweep_config";
+pub const TREASURY_WITHDRAWAL_LIMIT: &str = "treasury_withdrawal_limit";
+pub const BID_TICKET_CONFIG: &str = "bid_ticket_config";
+pub const BID_TICKET_BURN: &str = "bid_ticket_burn";
+pub const SETTLEMENT_MARKER: &str = "settlement_marker";
+pub const MARKETPLACE_BINDING: &str = "marketplace_binding";
+pub const AUCTIONEER_STATS: &str = "auctioneer_stats";
+pub const VOUCHER: &str = "voucher";
+pub const LEADERBOARD: &str = "leaderboard";
+pub const ROYALTY_BONUS: &str = "royalty_bonus";
+pub const SHARED_CUSTODY: &str = "shared_custody";
+pub const DELAYED_PAYOUT: &str = "delayed_payout";
+pub const PRICE_HISTORY: &str = "price_history";
+pub const SPENDING_CAP: &str = "spending_cap";
+pub const OMNIBUS_CUSTODIAN: &str = "omnibus_custodian";
+pub const TRADE_ATTRIBUTION: &str = "trade_attribution";
+pub const ACTIVE_LISTING: &str = "active_listing";
+pub const TRADE_SEQUENCE: &str = "trade_sequence";
+pub const HOUSE_NOTICE: &str = "house_notice";
+pub const TRADE_STATE_SIZE: usize = 1;
+pub const CURRENCY_LOCKED_TRADE_STATE_SIZE: usize = 1 + 32 + 1;
+pub const MAX_NUM_SCOPES: usize = 10;
+pub const MAX_AUCTIONEER_PROGRAMS: usize = 8;
+pub const MAX_INDEXED_PUBLIC_BIDS: usize = 16;
+pub const MAX_MARKETPLACE_BINDING_HOUSES: usize = 8;
+pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
+pub const MAX_CO_OWNERS: usize = 10;
+pub const MAX_PRICE_HISTORY_ENTRIES: usize = 32;
+pub const TOKEN_STANDARD_NFT: u8 = 1 << 0;
+pub const TOKEN_STANDARD_SFT: u8 = 1 << 1;
+pub const TOKEN_STANDARD_FUNGIBLE: u8 = 1 << 2;
+pub const TOKEN_STANDARD_PNFT: u8 = 1 << 3;
+pub const ALL_TOKEN_STANDARDS: u8 =
+    TOKEN_STANDARD_NFT | TOKEN_STANDARD_SFT | TOKEN_STANDARD_FUNGIBLE | TOKEN_STANDARD_PNFT;
+
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
@@ -0,0 +1,83 @@
+//! Assembly of the creator remaining accounts that settlement instructions pay royalties to.
+//!
+//! `execute_sale` and the other settlement instructions expect, as their first remaining
+//! accounts, one entry per creator in the token's metadata, in metadata order. Native houses take
+//! the creator's wallet; SPL houses take the wallet followed by its associated token account for
+//! the treasury mint.
+use borsh::BorshDeserialize;
+use solana_program::{instruction::AccountMeta, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::NATIVE_MINT,
+    pda::{
+        find_associated_token_address, find_frozen_proceeds_address,
+        find_frozen_proceeds_vault_address,
+    },
+};
+
+/// A creator entry of a Token Metadata account.
+#[derive(BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MetadataCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+/// The leading fields of a Token Metadata account, up to and including its creators.
+#[derive(BorshDeserialize)]
+struct MetadataPrefix {
+    _key: u8,
+    _update_authority: Pubkey,
+    _mint: Pubkey,
+    _name: String,
+    _symbol: String,
+    _uri: String,
+    _seller_fee_basis_points: u16,
+    creators: Option<Vec<MetadataCreator>>,
+}
+
+/// Read the creators from a Token Metadata account's raw data.
+pub fn metadata_creators(data: &[u8]) -> Result<Vec<MetadataCreator>, ProgramError> {
+    let metadata = MetadataPrefix::deserialize(&mut &data[..])
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok(metadata.creators.unwrap_or_default())
+}
+
+/// The remaining accounts that pay `creators` on a house with `treasury_mint`, in the order the
+/// program reads them.
+///
+/// Every creator is paid, verified or not, so unverified creators are included. Missing creator
+/// token accounts are created by the program from the house fee account, so their addresses are
+/// passed whether or not they exist yet. Creators in `frozen_creators` have a frozen token account;
+/// a non-zero share of theirs is held in the house's frozen proceeds vault, which follows their
+/// token account.
+pub fn creator_remaining_accounts(
+    auction_house: &Pubkey,
+    treasury_mint: &Pubkey,
+    creators: &[MetadataCreator],
+    frozen_creators: &[Pubkey],
+) -> Vec<AccountMeta> {
+    let is_native = *treasury_mint == NATIVE_MINT;
+    let mut accounts = Vec::new();
+    for creator in creators {
+        accounts.push(AccountMeta::new(creator.address, false));
+        if is_native {
+            continue;
+        }
+
+        accounts.push(AccountMeta::new(
+            find_associated_token_address(&creator.address, treasury_mint),
+            false,
+        ));
+        if creator.share > 0 && frozen_creators.contains(&creator.address) {
+            let (vault, _) = find_frozen_proceeds_vault_address(auction_house);
+            let (frozen_proceeds, _) =
+                find_frozen_proceeds_address(auction_house, &creator.address);
+            accounts.push(AccountMeta::new(vault, false));
+            accounts.push(AccountMeta::new(frozen_proceeds, false));
+        }
+    }
+
+    accounts
+}
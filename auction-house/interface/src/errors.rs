@@ -0,0 +1,531 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+/// Errors returned by the Auction House program, numbered as the program reports them.
+#[derive(Error, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AuctionHouseError {
+    // 6000
+    #[error("PublicKeyMismatch")]
+    PublicKeyMismatch = 6000,
+
+    // 6001
+    #[error("InvalidMintAuthority")]
+    InvalidMintAuthority,
+
+    // 6002
+    #[error("UninitializedAccount")]
+    UninitializedAccount,
+
+    // 6003
+    #[error("IncorrectOwner")]
+    IncorrectOwner,
+
+    // 6004
+    #[error("PublicKeysShouldBeUnique")]
+    PublicKeysShouldBeUnique,
+
+    // 6005
+    #[error("StatementFalse")]
+    StatementFalse,
+
+    // 6006
+    #[error("NotRentExempt")]
+    NotRentExempt,
+
+    // 6007
+    #[error("NumericalOverflow")]
+    NumericalOverflow,
+
+    // 6008
+    #[error("Expected a sol account but got an spl token account instead")]
+    ExpectedSolAccount,
+
+    // 6009
+    #[error("Cannot exchange sol for sol")]
+    CannotExchangeSOLForSol,
+
+    // 6010
+    #[error("If paying with sol, sol wallet must be signer")]
+    SOLWalletMustSign,
+
+    // 6011
+    #[error("Cannot take this action without auction house signing too")]
+    CannotTakeThisActionWithoutAuctionHouseSignOff,
+
+    // 6012
+    #[error("No payer present on this txn")]
+    NoPayerPresent,
+
+    // 6013
+    #[error("Derived key invalid")]
+    DerivedKeyInvalid,
+
+    // 6014
+    #[error("Metadata doesn't exist")]
+    MetadataDoesntExist,
+
+    // 6015
+    #[error("Invalid token amount")]
+    InvalidTokenAmount,
+
+    // 6016
+    #[error("Both parties need to agree to this sale")]
+    BothPartiesNeedToAgreeToSale,
+
+    // 6017
+    #[error("Cannot match free sales unless the auction house or seller signs off")]
+    CannotMatchFreeSalesWithoutAuctionHouseOrSellerSignoff,
+
+    // 6018
+    #[error("This sale requires a signer")]
+    SaleRequiresSigner,
+
+    // 6019
+    #[error("Old seller not initialized")]
+    OldSellerNotInitialized,
+
+    // 6020
+    #[error("Seller ata cannot have a delegate set")]
+    SellerATACannotHaveDelegate,
+
+    // 6021
+    #[error("Buyer ata cannot have a delegate set")]
+    BuyerATACannotHaveDelegate,
+
+    // 6022
+    #[error("No valid signer present")]
+    NoValidSignerPresent,
+
+    // 6023
+    #[error("BP must be less than or equal to 10000")]
+    InvalidBasisPoints,
+
+    // 6024
+    #[error("The trade state account does not exist")]
+    TradeStateDoesntExist,
+
+    // 6025
+    #[error("The trade state is not empty")]
+    TradeStateIsNotEmpty,
+
+    // 6026
+    #[error("The receipt is empty")]
+    ReceiptIsEmpty,
+
+    // 6027
+    #[error("The instruction does not match")]
+    InstructionMismatch,
+
+    // 6028
+    #[error("Invalid Auctioneer for this Auction House instance.")]
+    InvalidAuctioneer,
+
+    // 6029
+    #[error("The Auctioneer does not have the correct scope for this action.")]
+    MissingAuctioneerScope,
+
+    // 6030
+    #[error("Must use auctioneer handler.")]
+    MustUseAuctioneerHandler,
+
+    // 6031
+    #[error("No Auctioneer program set.")]
+    NoAuctioneerProgramSet,
+
+    // 6032
+    #[error("Too many scopes.")]
+    TooManyScopes,
+
+    // 6033
+    #[error("Auction House not delegated.")]
+    AuctionHouseNotDelegated,
+
+    // 6034
+    #[error("Bump seed not in hash map.")]
+    BumpSeedNotInHashMap,
+
+    // 6035
+    #[error("The instruction would drain the escrow below rent exemption threshold")]
+    EscrowUnderRentExemption,
+
+    // 6036
+    #[error("Invalid seeds or Auction House not delegated")]
+    InvalidSeedsOrAuctionHouseNotDelegated,
+
+    // 6037
+    #[error("The buyer trade state was unable to be initialized.")]
+    BuyerTradeStateNotValid,
+
+    // 6038
+    #[error("Partial order size and price must both be provided in a partial buy.")]
+    MissingElementForPartialOrder,
+
+    // 6039
+    #[error("Amount of tokens available for purchase is less than the partial order amount.")]
+    NotEnoughTokensAvailableForPurchase,
+
+    // 6040
+    #[error("Calculated partial price does not not partial price that was provided.")]
+    PartialPriceMismatch,
+
+    // 6041
+    #[error("Auction House already delegated.")]
+    AuctionHouseAlreadyDelegated,
+
+    // 6042
+    #[error("Auctioneer Authority Mismatch")]
+    AuctioneerAuthorityMismatch,
+
+    // 6043
+    #[error("Insufficient funds in escrow account to purchase.")]
+    InsufficientFunds,
+
+    // 6044
+    #[error("This sale requires exactly one signer: either the seller or the authority.")]
+    SaleRequiresExactlyOneSigner,
+
+    // 6045
+    #[error("Staked tokens are still within the lockup period.")]
+    FeeStakeLocked,
+
+    // 6046
+    #[error("Not enough tokens staked to withdraw the requested amount.")]
+    InsufficientStake,
+
+    // 6047
+    #[error("The relist cooldown cannot be negative.")]
+    InvalidRelistCooldown,
+
+    // 6048
+    #[error("This mint was settled too recently to be relisted.")]
+    MintInRelistCooldown,
+
+    // 6049
+    #[error("Buyer protection is only available for houses with a native SOL treasury mint.")]
+    BuyerProtectionRequiresNativeMint,
+
+    // 6050
+    #[error("The buyer protection window cannot be negative.")]
+    InvalidBuyerProtectionWindow,
+
+    // 6051
+    #[error("The buyer protection window for this settlement has closed.")]
+    BuyerProtectionWindowClosed,
+
+    // 6052
+    #[error("The buyer protection window for this settlement is still open.")]
+    BuyerProtectionWindowOpen,
+
+    // 6053
+    #[error("Programmable NFTs cannot be sold with buyer protection.")]
+    ProgrammableNftCannotBeProtected,
+
+    // 6054
+    #[error("Only the buyer or seller of a settlement can dispute it.")]
+    NotSettlementParty,
+
+    // 6055
+    #[error("The settlement is in dispute and can only be resolved by the arbiter.")]
+    SettlementInDispute,
+
+    // 6056
+    #[error("The signer is not the Auction House arbiter.")]
+    InvalidArbiter,
+
+    // 6057
+    #[error("Seller financing is not enabled on this Auction House.")]
+    SellerFinancingDisabled,
+
+    // 6058
+    #[error("Seller financing is only available for houses with a native SOL treasury mint.")]
+    SellerFinancingRequiresNativeMint,
+
+    // 6059
+    #[error("A financed sale must be signed by both the buyer and the seller.")]
+    FinancedSaleNotSigned,
+
+    // 6060
+    #[error("The lien amount must be greater than zero.")]
+    InvalidLienAmount,
+
+    // 6061
+    #[error("This mint has an outstanding lien on this Auction House.")]
+    LienOutstanding,
+
+    // 6062
+    #[error("Frozen proceeds vaults are only used by houses with an SPL treasury mint.")]
+    FrozenProceedsRequireSplTreasury,
+
+    // 6063
+    #[error("The recipient token account is still frozen.")]
+    RecipientAccountFrozen,
+
+    // 6064
+    #[error("The listing fee cannot exceed the Auction House seller fee.")]
+    ListingFeeAboveHouseFee,
+
+    // 6065
+    #[error("The minimum listing price cannot exceed the maximum listing price.")]
+    InvalidListingPriceBounds,
+
+    // 6066
+    #[error("The listing price is below the Auction House minimum.")]
+    ListingPriceBelowMinimum,
+
+    // 6067
+    #[error("The listing price is above the Auction House maximum.")]
+    ListingPriceAboveMaximum,
+
+    // 6068
+    #[error(
+        "The asset's rule set does not allow sale transfers, so the listing could never settle."
+    )]
+    TransferBlockedByRuleSet,
+
+    // 6069
+    #[error("The auctioneer authority is not the auctioneer PDA of an allowlisted program.")]
+    AuctioneerProgramNotAllowed,
+
+    // 6070
+    #[error("The auctioneer allowlist has too many programs.")]
+    TooManyAuctioneerPrograms,
+
+    // 6071
+    #[error("The escrow payment account would be left below the rent exempt minimum.")]
+    EscrowNotRentExempt,
+
+    // 6072
+    #[error("Expected auction house, escrow payment account and receipt account triples.")]
+    InvalidWithdrawManyAccounts,
+
+    // 6073
+    #[error("Bid expiries must be set on an open bid and lie in the future.")]
+    InvalidBidExpiry,
+
+    // 6074
+    #[error("The bid has not expired yet.")]
+    BidNotExpired,
+
+    // 6075
+    #[error(
+        "Expected bid expiry, trade state, escrow payment account and receipt account groups."
+    )]
+    InvalidSweepExpiredBidsAccounts,
+
+    // 6076
+    #[error("The compliance program does not match the house's compliance config.")]
+    InvalidComplianceProgram,
+
+    // 6077
+    #[error("The auction house does not log receipts to a receipt tree.")]
+    ReceiptTreeNotSet,
+
+    // 6078
+    #[error("The wanted listing prices or end time are invalid.")]
+    InvalidWantedListing,
+
+    // 6079
+    #[error("The wanted listing has expired.")]
+    WantedListingExpired,
+
+    // 6080
+    #[error("The token does not meet the wanted listing's criteria.")]
+    WantedCriteriaNotMet,
+
+    // 6081
+    #[error("The auction house can change sale prices, so the free trade state is required.")]
+    FreeTradeStateRequired,
+
+    // 6082
+    #[error("Remaining accounts must start with the metadata creators, in order.")]
+    InvalidCreatorAccount,
+
+    // 6083
+    #[error("Fee sweeps are only supported on native SOL auction houses.")]
+    FeeSweepRequiresNativeTreasury,
+
+    // 6084
+    #[error("The fee account balance does not exceed the sweep threshold.")]
+    FeeBelowSweepThreshold,
+
+    // 6085
+    #[error("The treasury withdrawal period must be positive and at most a year.")]
+    InvalidTreasuryWithdrawalPeriod,
+
+    // 6086
+    #[error("The withdrawal exceeds the treasury withdrawal limit for the period.")]
+    TreasuryWithdrawalLimitExceeded,
+
+    // 6087
+    #[error("The bid ticket token account must hold a ticket owned by the bidder.")]
+    InvalidBidTicket,
+
+    // 6088
+    #[error("The bid ticket is not a verified member of the house's ticket collection.")]
+    BidTicketNotInCollection,
+
+    // 6089
+    #[error("The settlement has already been completed.")]
+    SettlementAlreadySettled,
+
+    // 6090
+    #[error("The settlement is escrowed and must be finalized or canceled before it is retried.")]
+    SettlementPartiallySettled,
+
+    // 6091
+    #[error("The settlement has not been started.")]
+    SettlementNotStarted,
+
+    // 6092
+    #[error("Buyer receipt account must be the buyer's associated token account.")]
+    BuyerReceiptNotAssociatedTokenAccount,
+
+    // 6093
+    #[error("The Auction House is immutable and cannot be updated.")]
+    AuctionHouseImmutable,
+
+    // 6094
+    #[error("Signer is not the collection's update authority.")]
+    NotCollectionUpdateAuthority,
+
+    // 6095
+    #[error("Too many approved Auction Houses for a marketplace binding.")]
+    TooManyApprovedAuctionHouses,
+
+    // 6096
+    #[error("The collection's marketplace binding does not approve this Auction House.")]
+    AuctionHouseNotApprovedForCollection,
+
+    // 6097
+    #[error(
+        "Bidding with a transfer is only available for houses with a native SOL treasury mint."
+    )]
+    BidWithTransferRequiresNativeMint,
+
+    // 6098
+    #[error("Vouchers are only available for houses with a native SOL treasury mint.")]
+    VoucherRequiresNativeMint,
+
+    // 6099
+    #[error("The voucher price or redeem deadline is invalid.")]
+    InvalidVoucher,
+
+    // 6100
+    #[error("The voucher has already been sold.")]
+    VoucherAlreadySold,
+
+    // 6101
+    #[error("The voucher has not been sold.")]
+    VoucherNotSold,
+
+    // 6102
+    #[error("The voucher's redeem deadline has passed.")]
+    VoucherRedeemDeadlinePassed,
+
+    // 6103
+    #[error("The voucher's redeem deadline has not passed yet.")]
+    VoucherRedeemDeadlineNotPassed,
+
+    // 6104
+    #[error("The buyer does not hold a token verified as minted by the voucher's creator.")]
+    VoucherNotDelivered,
+
+    // 6105
+    #[error("Expected protected settlement, settlement marker, escrow token account, buyer receipt account, seller and rent payer groups.")]
+    InvalidFinalizeProtectedSettlementsAccounts,
+
+    // 6106
+    #[error("Every entry of the crank batch was already processed or is not due yet.")]
+    NothingToCrank,
+
+    // 6107
+    #[error("The auctioneer grace period must not be negative.")]
+    InvalidAuctioneerGracePeriod,
+
+    // 6108
+    #[error("Bids on an Auction House with a delegated auctioneer cannot be transferred.")]
+    AuctioneerBidNotTransferable,
+
+    // 6109
+    #[error("The leaderboard window must end after it starts.")]
+    InvalidLeaderboardWindow,
+
+    // 6110
+    #[error("The royalty bonus and the token's royalties cannot exceed 10000 basis points.")]
+    RoyaltyBonusTooHigh,
+
+    // 6111
+    #[error("Co-owner shares must add up to 10000 basis points, with a threshold of at least one and at most the number of co-owners.")]
+    InvalidSharedCustody,
+
+    // 6112
+    #[error("The signer is not a co-owner of the shared custody.")]
+    NotCoOwner,
+
+    // 6113
+    #[error("The shared custody token is already listed.")]
+    SharedCustodyAlreadyListed,
+
+    // 6114
+    #[error("The shared custody token has not been sold.")]
+    SharedCustodyNotSold,
+
+    // 6115
+    #[error("Shared custody is only available for houses with a native SOL treasury mint.")]
+    SharedCustodyRequiresNativeMint,
+
+    // 6116
+    #[error("The payout delay cannot be negative.")]
+    InvalidPayoutDelay,
+
+    // 6117
+    #[error("Payout delays are only available for houses with a native SOL treasury mint.")]
+    PayoutDelayRequiresNativeMint,
+
+    // 6118
+    #[error("The payout delay has not passed yet.")]
+    PayoutDelayWindowOpen,
+
+    // 6119
+    #[error("The payout delay has passed, so the proceeds can no longer be clawed back.")]
+    PayoutDelayWindowClosed,
+
+    // 6120
+    #[error("The token standard policy has unknown bits set.")]
+    InvalidTokenStandardPolicy,
+
+    // 6121
+    #[error("This Auction House does not list tokens of this token standard.")]
+    TokenStandardNotAllowed,
+
+    // 6122
+    #[error("This Auction House only lists tokens whose creators and collection are verified.")]
+    UnverifiedCreators,
+
+    // 6123
+    #[error("The frontend must sign bids charged to its spending cap.")]
+    FrontendMustSign,
+
+    // 6124
+    #[error("The bid exceeds the wallet's spending cap for this frontend.")]
+    SpendingCapExceeded,
+
+    // 6125
+    #[error("The seller already has an active listing of this mint on this Auction House.")]
+    ListingAlreadyActive,
+
+    // 6126
+    #[error("The listing was placed in a different treasury currency than the Auction House settles in.")]
+    ListingCurrencyMismatch,
+
+    // 6127
+    #[error("The notice expiry has already passed.")]
+    NoticeExpiryInPast,
+}
+
+impl From<AuctionHouseError> for ProgramError {
+    fn from(e: AuctionHouseError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
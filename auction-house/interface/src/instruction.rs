@@ -0,0 +1,480 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::{
+    constants::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    id,
+    pda::find_program_as_signer_address,
+};
+
+/// Anchor instruction discriminators, the first 8 bytes of each instruction's data.
+pub mod discriminator {
+    pub const WITHDRAW_FROM_FEE: [u8; 8] = [179, 208, 190, 154, 32, 179, 19, 59];
+    pub const WITHDRAW_FROM_TREASURY: [u8; 8] = [0, 164, 86, 76, 56, 72, 12, 170];
+    pub const UPDATE_AUCTION_HOUSE: [u8; 8] = [84, 215, 2, 172, 241, 0, 245, 219];
+    pub const CREATE_AUCTION_HOUSE: [u8; 8] = [221, 66, 242, 159, 249, 206, 134, 241];
+    pub const BUY: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+    pub const AUCTIONEER_BUY: [u8; 8] = [17, 106, 133, 46, 229, 48, 45, 208];
+    pub const PUBLIC_BUY: [u8; 8] = [169, 84, 218, 35, 42, 206, 16, 171];
+    pub const AUCTIONEER_PUBLIC_BUY: [u8; 8] = [221, 239, 99, 240, 86, 46, 213, 126];
+    pub const CANCEL: [u8; 8] = [232, 219, 223, 41, 219, 236, 220, 190];
+    pub const AUCTIONEER_CANCEL: [u8; 8] = [197, 97, 152, 196, 115, 204, 64, 215];
+    pub const DEPOSIT: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+    pub const AUCTIONEER_DEPOSIT: [u8; 8] = [79, 122, 37, 162, 120, 173, 57, 127];
+    pub const EXECUTE_SALE: [u8; 8] = [37, 74, 217, 157, 79, 49, 35, 6];
+    pub const EXECUTE_PARTIAL_SALE: [u8; 8] = [163, 18, 35, 157, 49, 164, 203, 133];
+    pub const STRICT_EXECUTE_SALE: [u8; 8] = [240, 190, 174, 133, 252, 190, 16, 171];
+    pub const AUCTIONEER_EXECUTE_SALE: [u8; 8] = [68, 125, 32, 65, 251, 43, 35, 53];
+    pub const AUCTIONEER_EXECUTE_PARTIAL_SALE: [u8; 8] = [9, 44, 46, 15, 161, 143, 21, 54];
+    pub const SELL: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+    pub const AUCTIONEER_SELL: [u8; 8] = [251, 60, 142, 195, 121, 203, 26, 183];
+    pub const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
+    pub const AUCTIONEER_WITHDRAW: [u8; 8] = [85, 166, 219, 110, 168, 143, 180, 236];
+    pub const WITHDRAW_MANY: [u8; 8] = [75, 157, 179, 58, 143, 102, 113, 57];
+    pub const CLOSE_ESCROW_ACCOUNT: [u8; 8] = [209, 42, 208, 179, 140, 78, 18, 43];
+    pub const DELEGATE_AUCTIONEER: [u8; 8] = [106, 178, 12, 122, 74, 173, 251, 222];
+    pub const UPDATE_AUCTIONEER: [u8; 8] = [103, 255, 80, 234, 94, 56, 168, 208];
+    pub const CREATE_FEE_STAKE_CONFIG: [u8; 8] = [227, 157, 209, 215, 200, 75, 121, 5];
+    pub const UPDATE_FEE_STAKE_CONFIG: [u8; 8] = [125, 207, 205, 13, 147, 70, 247, 243];
+    pub const STAKE_FOR_FEES: [u8; 8] = [153, 54, 177, 57, 221, 62, 59, 18];
+    pub const UNSTAKE: [u8; 8] = [90, 95, 107, 42, 205, 124, 50, 225];
+    pub const SET_RELIST_COOLDOWN: [u8; 8] = [124, 185, 73, 116, 248, 228, 35, 24];
+    pub const CREATE_AUTHORITY_LOG: [u8; 8] = [8, 142, 208, 26, 198, 5, 173, 59];
+    pub const SET_BUYER_PROTECTION: [u8; 8] = [13, 223, 9, 37, 129, 237, 74, 216];
+    pub const CANCEL_PROTECTED_SETTLEMENT: [u8; 8] = [231, 169, 227, 227, 63, 56, 142, 228];
+    pub const FINALIZE_PROTECTED_SETTLEMENT: [u8; 8] = [104, 0, 67, 98, 252, 35, 61, 154];
+    pub const SET_ARBITER: [u8; 8] = [15, 205, 194, 180, 172, 213, 113, 211];
+    pub const OPEN_DISPUTE: [u8; 8] = [137, 25, 99, 119, 23, 223, 161, 42];
+    pub const RESOLVE_DISPUTE: [u8; 8] = [231, 6, 202, 6, 96, 103, 12, 230];
+    pub const SET_SELLER_FINANCING: [u8; 8] = [124, 91, 15, 215, 14, 192, 88, 157];
+    pub const EXECUTE_FINANCED_SALE: [u8; 8] = [220, 243, 31, 79, 42, 39, 51, 118];
+    pub const REPAY_LIEN: [u8; 8] = [156, 155, 140, 231, 240, 155, 121, 58];
+    pub const CREATE_FROZEN_PROCEEDS_VAULT: [u8; 8] = [215, 159, 186, 252, 139, 204, 16, 183];
+    pub const CLAIM_FROZEN_PROCEEDS: [u8; 8] = [191, 36, 155, 81, 87, 78, 108, 73];
+    pub const AUCTIONEER_SET_LISTING_FEE: [u8; 8] = [154, 25, 6, 160, 90, 151, 30, 8];
+    pub const APPROVE_DEPOSIT_AUTHORITY: [u8; 8] = [18, 7, 150, 170, 32, 172, 132, 123];
+    pub const REVOKE_DEPOSIT_AUTHORITY: [u8; 8] = [219, 106, 8, 132, 200, 27, 167, 211];
+    pub const DEPOSIT_ON_BEHALF: [u8; 8] = [102, 200, 184, 158, 165, 255, 72, 95];
+    pub const SET_LISTING_PRICE_BOUNDS: [u8; 8] = [14, 110, 126, 135, 29, 220, 199, 74];
+    pub const CREATE_AUCTIONEER_ALLOWLIST: [u8; 8] = [135, 123, 49, 186, 88, 52, 190, 105];
+    pub const UPDATE_AUCTIONEER_ALLOWLIST: [u8; 8] = [179, 107, 86, 120, 250, 63, 227, 64];
+    pub const CREATE_PUBLIC_BID_INDEX: [u8; 8] = [239, 217, 203, 131, 193, 78, 137, 118];
+    pub const PRUNE_PUBLIC_BID_INDEX: [u8; 8] = [207, 38, 99, 178, 33, 177, 80, 169];
+    pub const SET_BID_EXPIRY: [u8; 8] = [3, 34, 140, 81, 97, 8, 162, 112];
+    pub const SWEEP_EXPIRED_BIDS: [u8; 8] = [61, 246, 192, 11, 39, 44, 94, 32];
+    pub const CREATE_COMPLIANCE_CONFIG: [u8; 8] = [142, 216, 246, 182, 212, 38, 184, 147];
+    pub const UPDATE_COMPLIANCE_CONFIG: [u8; 8] = [200, 202, 189, 153, 37, 141, 20, 69];
+    pub const CREATE_TAX_WITHHOLDING: [u8; 8] = [88, 65, 31, 248, 212, 135, 208, 143];
+    pub const UPDATE_TAX_WITHHOLDING: [u8; 8] = [168, 163, 81, 65, 175, 13, 227, 200];
+    pub const CREATE_WANTED_LISTING: [u8; 8] = [154, 49, 133, 64, 100, 100, 131, 8];
+    pub const CANCEL_WANTED_LISTING: [u8; 8] = [251, 237, 120, 158, 229, 189, 72, 89];
+    pub const FILL_WANTED: [u8; 8] = [150, 194, 108, 104, 152, 167, 97, 142];
+    pub const CREATE_FEE_SWEEP_CONFIG: [u8; 8] = [132, 251, 12, 35, 42, 223, 221, 198];
+    pub const UPDATE_FEE_SWEEP_CONFIG: [u8; 8] = [103, 246, 25, 4, 36, 159, 226, 75];
+    pub const SWEEP_FEE_TO_TREASURY: [u8; 8] = [135, 99, 237, 58, 134, 220, 134, 248];
+    pub const SET_LOW_FEE_BALANCE_THRESHOLD: [u8; 8] = [26, 41, 115, 30, 85, 226, 36, 83];
+    pub const FUND_FEE_ACCOUNT: [u8; 8] = [12, 255, 191, 69, 246, 116, 218, 246];
+    pub const CREATE_TREASURY_WITHDRAWAL_LIMIT: [u8; 8] = [28, 200, 11, 151, 213, 49, 120, 117];
+    pub const UPDATE_TREASURY_WITHDRAWAL_LIMIT: [u8; 8] = [69, 104, 1, 4, 53, 185, 9, 66];
+    pub const AUCTIONEER_WITHDRAW_FROM_TREASURY: [u8; 8] = [68, 179, 156, 184, 26, 82, 31, 11];
+    pub const CREATE_BID_TICKET_CONFIG: [u8; 8] = [205, 238, 195, 229, 207, 113, 154, 130];
+    pub const UPDATE_BID_TICKET_CONFIG: [u8; 8] = [110, 189, 66, 3, 184, 197, 128, 45];
+    pub const ASSERT_SETTLEMENT_STATUS: [u8; 8] = [132, 196, 225, 107, 23, 189, 194, 51];
+    pub const SET_WALLET_BOUND_RECEIPTS: [u8; 8] = [93, 115, 45, 99, 224, 15, 69, 210];
+    pub const CREATE_MARKETPLACE_BINDING: [u8; 8] = [98, 201, 14, 148, 11, 71, 216, 80];
+    pub const UPDATE_MARKETPLACE_BINDING: [u8; 8] = [238, 22, 253, 166, 38, 59, 102, 35];
+    pub const CREATE_AUCTIONEER_STATS: [u8; 8] = [12, 138, 229, 250, 154, 65, 61, 100];
+    pub const BID_WITH_TRANSFER: [u8; 8] = [71, 223, 29, 13, 201, 150, 235, 212];
+    pub const SELL_WITH_RECEIPT: [u8; 8] = [67, 207, 112, 108, 255, 83, 118, 48];
+    pub const BUY_WITH_RECEIPT: [u8; 8] = [217, 4, 123, 230, 197, 244, 77, 205];
+    pub const CREATE_VOUCHER: [u8; 8] = [22, 97, 32, 21, 104, 137, 188, 143];
+    pub const CANCEL_VOUCHER: [u8; 8] = [84, 142, 170, 253, 46, 35, 180, 158];
+    pub const BUY_VOUCHER: [u8; 8] = [199, 50, 71, 239, 102, 17, 24, 39];
+    pub const REDEEM_VOUCHER: [u8; 8] = [50, 219, 8, 127, 45, 96, 161, 92];
+    pub const RECLAIM_VOUCHER: [u8; 8] = [69, 105, 89, 245, 248, 6, 255, 165];
+    pub const FINALIZE_PROTECTED_SETTLEMENTS: [u8; 8] = [134, 139, 183, 126, 95, 61, 170, 154];
+    pub const ROTATE_AUCTIONEER: [u8; 8] = [253, 246, 181, 95, 96, 36, 198, 54];
+    pub const TRANSFER_BID: [u8; 8] = [59, 183, 158, 72, 236, 110, 38, 121];
+    pub const CREATE_LEADERBOARD: [u8; 8] = [154, 109, 101, 182, 52, 26, 30, 220];
+    pub const SET_ROUNDING_POLICY: [u8; 8] = [205, 207, 73, 156, 145, 114, 25, 114];
+    pub const CREATE_ROYALTY_BONUS: [u8; 8] = [111, 223, 31, 2, 202, 104, 208, 201];
+    pub const CLOSE_ROYALTY_BONUS: [u8; 8] = [0, 212, 243, 109, 41, 98, 133, 248];
+    pub const CREATE_SHARED_CUSTODY: [u8; 8] = [16, 92, 154, 45, 133, 1, 125, 197];
+    pub const CO_LIST: [u8; 8] = [252, 25, 208, 63, 188, 21, 188, 230];
+    pub const SETTLE_SHARED_CUSTODY: [u8; 8] = [39, 32, 8, 116, 179, 94, 3, 91];
+    pub const SET_PAYOUT_DELAY: [u8; 8] = [180, 145, 60, 7, 137, 72, 26, 218];
+    pub const CLAIM_PROCEEDS: [u8; 8] = [44, 76, 121, 111, 124, 251, 237, 5];
+    pub const CLAWBACK_PROCEEDS: [u8; 8] = [149, 168, 234, 242, 87, 158, 69, 202];
+    pub const CREATE_PRICE_HISTORY: [u8; 8] = [129, 186, 176, 215, 246, 207, 37, 186];
+    pub const SET_TOKEN_STANDARD_POLICY: [u8; 8] = [137, 43, 147, 32, 162, 101, 233, 189];
+    pub const SET_REQUIRES_VERIFIED_CREATORS: [u8; 8] = [163, 211, 69, 229, 95, 151, 18, 202];
+    pub const SET_SINGLE_ACTIVE_LISTING: [u8; 8] = [78, 2, 45, 135, 55, 94, 171, 46];
+    pub const SET_LISTING_CURRENCY_LOCK: [u8; 8] = [83, 213, 60, 95, 61, 61, 216, 12];
+    pub const CREATE_HOUSE_NOTICE: [u8; 8] = [83, 110, 172, 87, 6, 187, 201, 159];
+    pub const SET_HOUSE_NOTICE: [u8; 8] = [92, 240, 37, 107, 112, 72, 184, 226];
+    pub const CREATE_TRADE_SEQUENCE: [u8; 8] = [69, 197, 212, 230, 179, 182, 10, 191];
+    pub const PREPARE_BUYER: [u8; 8] = [252, 208, 96, 169, 123, 77, 89, 120];
+    pub const CREATE_SPENDING_CAP: [u8; 8] = [175, 173, 12, 90, 35, 234, 95, 81];
+    pub const UPDATE_SPENDING_CAP: [u8; 8] = [60, 225, 241, 31, 175, 223, 104, 40];
+    pub const CLOSE_SPENDING_CAP: [u8; 8] = [95, 71, 144, 82, 171, 29, 98, 137];
+    pub const GET_HOUSE_BALANCES: [u8; 8] = [176, 140, 187, 161, 228, 142, 32, 83];
+    pub const REGISTER_OMNIBUS_CUSTODIAN: [u8; 8] = [109, 135, 122, 35, 188, 10, 181, 124];
+    pub const DEREGISTER_OMNIBUS_CUSTODIAN: [u8; 8] = [125, 115, 85, 184, 82, 239, 90, 210];
+    pub const ATTRIBUTE_TRADE: [u8; 8] = [142, 115, 255, 246, 188, 83, 58, 220];
+    pub const CLOSE_TRADE_ATTRIBUTION: [u8; 8] = [19, 159, 70, 135, 95, 85, 59, 164];
+    pub const PRINT_LISTING_RECEIPT: [u8; 8] = [207, 107, 44, 160, 75, 222, 195, 27];
+    pub const CANCEL_LISTING_RECEIPT: [u8; 8] = [171, 59, 138, 126, 246, 189, 91, 11];
+    pub const PRINT_BID_RECEIPT: [u8; 8] = [94, 249, 90, 230, 239, 64, 68, 218];
+    pub const CANCEL_BID_RECEIPT: [u8; 8] = [246, 108, 27, 229, 220, 42, 176, 43];
+    pub const PRINT_PURCHASE_RECEIPT: [u8; 8] = [227, 154, 251, 7, 180, 56, 100, 143];
+    pub const CREATE_RECEIPT_TREE: [u8; 8] = [58, 4, 151, 167, 147, 192, 71, 30];
+    pub const LOG_RECEIPT: [u8; 8] = [66, 16, 251, 37, 184, 167, 232, 144];
+}
+
+fn instruction_data<T: BorshSerialize>(discriminator: [u8; 8], args: T) -> Vec<u8> {
+    let mut data = discriminator.to_vec();
+    args.serialize(&mut data)
+        .expect("serializing instruction arguments cannot fail");
+    data
+}
+
+/// Accounts for a [`deposit`] instruction.
+pub struct DepositAccounts {
+    pub wallet: Pubkey,
+    pub payment_account: Pubkey,
+    pub transfer_authority: Pubkey,
+    pub escrow_payment_account: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub auction_house_fee_account: Pubkey,
+}
+
+/// Deposit `amount` into the wallet's escrow payment account.
+pub fn deposit(accounts: DepositAccounts, escrow_payment_bump: u8, amount: u64) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.wallet, true),
+            AccountMeta::new(accounts.payment_account, false),
+            AccountMeta::new_readonly(accounts.transfer_authority, false),
+            AccountMeta::new(accounts.escrow_payment_account, false),
+            AccountMeta::new_readonly(accounts.treasury_mint, false),
+            AccountMeta::new_readonly(accounts.authority, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new(accounts.auction_house_fee_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: instruction_data(discriminator::DEPOSIT, (escrow_payment_bump, amount)),
+    }
+}
+
+/// Accounts for a [`prepare_buyer`] instruction.
+pub struct PrepareBuyerAccounts {
+    pub wallet: Pubkey,
+    pub payment_account: Pubkey,
+    pub escrow_payment_account: Pubkey,
+    pub buyer_receipt_token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+/// Create the wallet's escrow payment account and token accounts if they do not exist yet, so it
+/// can bid on `token_mint`.
+pub fn prepare_buyer(accounts: PrepareBuyerAccounts) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(accounts.wallet, true),
+            AccountMeta::new(accounts.payment_account, false),
+            AccountMeta::new(accounts.escrow_payment_account, false),
+            AccountMeta::new(accounts.buyer_receipt_token_account, false),
+            AccountMeta::new_readonly(accounts.token_mint, false),
+            AccountMeta::new_readonly(accounts.treasury_mint, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: instruction_data(discriminator::PREPARE_BUYER, ()),
+    }
+}
+
+/// Accounts for a [`withdraw`] instruction.
+pub struct WithdrawAccounts {
+    pub wallet: Pubkey,
+    pub receipt_account: Pubkey,
+    pub escrow_payment_account: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub auction_house_fee_account: Pubkey,
+}
+
+/// Withdraw `amount` from the wallet's escrow payment account, signed by the wallet.
+pub fn withdraw(accounts: WithdrawAccounts, escrow_payment_bump: u8, amount: u64) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.wallet, true),
+            AccountMeta::new(accounts.receipt_account, false),
+            AccountMeta::new(accounts.escrow_payment_account, false),
+            AccountMeta::new_readonly(accounts.treasury_mint, false),
+            AccountMeta::new_readonly(accounts.authority, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new(accounts.auction_house_fee_account, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: instruction_data(discriminator::WITHDRAW, (escrow_payment_bump, amount)),
+    }
+}
+
+/// Accounts for a [`buy`] or [`public_buy`] instruction.
+pub struct BuyAccounts {
+    pub wallet: Pubkey,
+    pub payment_account: Pubkey,
+    pub transfer_authority: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub escrow_payment_account: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub auction_house_fee_account: Pubkey,
+    pub buyer_trade_state: Pubkey,
+}
+
+fn buy_instruction(
+    discriminator: [u8; 8],
+    accounts: BuyAccounts,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.wallet, true),
+            AccountMeta::new(accounts.payment_account, false),
+            AccountMeta::new_readonly(accounts.transfer_authority, false),
+            AccountMeta::new_readonly(accounts.treasury_mint, false),
+            AccountMeta::new_readonly(accounts.token_account, false),
+            AccountMeta::new_readonly(accounts.metadata, false),
+            AccountMeta::new(accounts.escrow_payment_account, false),
+            AccountMeta::new_readonly(accounts.authority, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new(accounts.auction_house_fee_account, false),
+            AccountMeta::new(accounts.buyer_trade_state, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: instruction_data(
+            discriminator,
+            (
+                trade_state_bump,
+                escrow_payment_bump,
+                buyer_price,
+                token_size,
+            ),
+        ),
+    }
+}
+
+/// Create a private bid on the token held in `token_account`.
+pub fn buy(
+    accounts: BuyAccounts,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Instruction {
+    buy_instruction(
+        discriminator::BUY,
+        accounts,
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )
+}
+
+/// Create a public bid on the token mint of `token_account`.
+pub fn public_buy(
+    accounts: BuyAccounts,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Instruction {
+    buy_instruction(
+        discriminator::PUBLIC_BUY,
+        accounts,
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )
+}
+
+/// Accounts for a [`sell`] instruction.
+pub struct SellAccounts {
+    pub wallet: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub auction_house_fee_account: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub free_seller_trade_state: Pubkey,
+}
+
+/// List the token in `token_account` for sale, signed by the wallet.
+pub fn sell(
+    accounts: SellAccounts,
+    trade_state_bump: u8,
+    free_trade_state_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Instruction {
+    let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.wallet, true),
+            AccountMeta::new(accounts.token_account, false),
+            AccountMeta::new_readonly(accounts.metadata, false),
+            AccountMeta::new_readonly(accounts.authority, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new(accounts.auction_house_fee_account, false),
+            AccountMeta::new(accounts.seller_trade_state, false),
+            AccountMeta::new(accounts.free_seller_trade_state, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(program_as_signer, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: instruction_data(
+            discriminator::SELL,
+            (
+                trade_state_bump,
+                free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price,
+                token_size,
+            ),
+        ),
+    }
+}
+
+/// Accounts for a [`cancel`] instruction.
+pub struct CancelAccounts {
+    pub wallet: Pubkey,
+    pub token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub auction_house_fee_account: Pubkey,
+    pub trade_state: Pubkey,
+}
+
+/// Cancel a bid or listing, signed by the wallet.
+pub fn cancel(accounts: CancelAccounts, buyer_price: u64, token_size: u64) -> Instruction {
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(accounts.wallet, true),
+            AccountMeta::new(accounts.token_account, false),
+            AccountMeta::new_readonly(accounts.token_mint, false),
+            AccountMeta::new_readonly(accounts.authority, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new(accounts.auction_house_fee_account, false),
+            AccountMeta::new(accounts.trade_state, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: instruction_data(discriminator::CANCEL, (buyer_price, token_size)),
+    }
+}
+
+/// Accounts for an [`execute_sale`] instruction.
+pub struct ExecuteSaleAccounts {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub metadata: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub escrow_payment_account: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+    pub buyer_receipt_token_account: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub auction_house_fee_account: Pubkey,
+    pub auction_house_treasury: Pubkey,
+    pub buyer_trade_state: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub free_trade_state: Pubkey,
+}
+
+/// Match a bid and a listing. Creator accounts, and any optional settlement accounts, are
+/// appended by the caller as remaining accounts; see
+/// [`creator_remaining_accounts`](crate::creators::creator_remaining_accounts).
+pub fn execute_sale(
+    accounts: ExecuteSaleAccounts,
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Instruction {
+    let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(accounts.buyer, false),
+            AccountMeta::new(accounts.seller, false),
+            AccountMeta::new(accounts.token_account, false),
+            AccountMeta::new_readonly(accounts.token_mint, false),
+            AccountMeta::new_readonly(accounts.metadata, false),
+            AccountMeta::new_readonly(accounts.treasury_mint, false),
+            AccountMeta::new(accounts.escrow_payment_account, false),
+            AccountMeta::new(accounts.seller_payment_receipt_account, false),
+            AccountMeta::new(accounts.buyer_receipt_token_account, false),
+            AccountMeta::new_readonly(accounts.authority, false),
+            AccountMeta::new_readonly(accounts.auction_house, false),
+            AccountMeta::new(accounts.auction_house_fee_account, false),
+            AccountMeta::new(accounts.auction_house_treasury, false),
+            AccountMeta::new(accounts.buyer_trade_state, false),
+            AccountMeta::new(accounts.seller_trade_state, false),
+            AccountMeta::new(accounts.free_trade_state, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(program_as_signer, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data: instruction_data(
+            discriminator::EXECUTE_SALE,
+            (
+                escrow_payment_bump,
+                free_trade_state_bump,
+                program_as_signer_bump,
+                buyer_price,
+                token_size,
+            ),
+        ),
+    }
+}
@@ -0,0 +1,16 @@
+//! Interface for composing with the Metaplex Auction House program.
+//!
+//! Mirrors the program's account layouts, instruction discriminators, errors and PDAs, and
+//! provides instruction builders for CPI, without depending on the program crate.
+pub mod constants;
+pub mod creators;
+pub mod errors;
+pub mod instruction;
+pub mod lookup_table;
+pub mod pda;
+pub mod state;
+
+pub use errors::AuctionHouseError;
+pub use state::*;
+
+solana_program::declare_id!("hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk");
@@ -0,0 +1,148 @@
+//! Address lookup tables for the accounts every instruction on a house shares, and v0 message
+//! building, so settlements with several creators fit in a transaction.
+//!
+//! Create the table once per house with [`create_house_lookup_table`], then compile any of the
+//! crate's instructions against it with [`versioned_message`] and sign the result as a
+//! versioned transaction.
+use solana_program::{
+    clock::Slot,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, CompileError, VersionedMessage},
+    pubkey,
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+#[allow(deprecated)]
+pub use solana_program::address_lookup_table_account::AddressLookupTableAccount;
+
+use crate::{
+    constants::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    id,
+    pda::find_program_as_signer_address,
+    AuctionHouse,
+};
+
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: Pubkey =
+    pubkey!("AddressLookupTab1e1111111111111111111111111");
+
+// Address lookup table program instruction tags, bincode encoded as little endian u32s.
+const CREATE_LOOKUP_TABLE: u32 = 0;
+const EXTEND_LOOKUP_TABLE: u32 = 2;
+
+/// The accounts shared by every instruction on `auction_house`.
+pub fn house_lookup_table_addresses(
+    auction_house_key: &Pubkey,
+    auction_house: &AuctionHouse,
+) -> Vec<Pubkey> {
+    vec![
+        id(),
+        *auction_house_key,
+        auction_house.authority,
+        auction_house.auction_house_fee_account,
+        auction_house.auction_house_treasury,
+        auction_house.treasury_mint,
+        find_program_as_signer_address().0,
+        TOKEN_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+        system_program::id(),
+        sysvar::rent::id(),
+    ]
+}
+
+/// Return the lookup table `Pubkey` address and bump seed for `authority` at `recent_slot`.
+pub fn find_lookup_table_address(authority: &Pubkey, recent_slot: Slot) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[authority.as_ref(), &recent_slot.to_le_bytes()],
+        &ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+    )
+}
+
+/// Create a lookup table controlled by `authority`. `recent_slot` must be a slot the cluster has
+/// recently seen.
+pub fn create_lookup_table(
+    authority: Pubkey,
+    payer: Pubkey,
+    recent_slot: Slot,
+) -> (Instruction, Pubkey) {
+    let (lookup_table, bump) = find_lookup_table_address(&authority, recent_slot);
+
+    let mut data = CREATE_LOOKUP_TABLE.to_le_bytes().to_vec();
+    data.extend_from_slice(&recent_slot.to_le_bytes());
+    data.push(bump);
+
+    let instruction = Instruction {
+        program_id: ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(lookup_table, false),
+            AccountMeta::new_readonly(authority, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+
+    (instruction, lookup_table)
+}
+
+/// Append `addresses` to `lookup_table`, with `payer` funding the larger account.
+pub fn extend_lookup_table(
+    lookup_table: Pubkey,
+    authority: Pubkey,
+    payer: Pubkey,
+    addresses: &[Pubkey],
+) -> Instruction {
+    let mut data = EXTEND_LOOKUP_TABLE.to_le_bytes().to_vec();
+    data.extend_from_slice(&(addresses.len() as u64).to_le_bytes());
+    for address in addresses {
+        data.extend_from_slice(address.as_ref());
+    }
+
+    Instruction {
+        program_id: ADDRESS_LOOKUP_TABLE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(lookup_table, false),
+            AccountMeta::new_readonly(authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Create a lookup table holding the [house's shared accounts](house_lookup_table_addresses).
+/// The table is usable from the slot after the one these instructions land in.
+pub fn create_house_lookup_table(
+    auction_house_key: &Pubkey,
+    auction_house: &AuctionHouse,
+    authority: Pubkey,
+    payer: Pubkey,
+    recent_slot: Slot,
+) -> (Vec<Instruction>, Pubkey) {
+    let (create, lookup_table) = create_lookup_table(authority, payer, recent_slot);
+    let extend = extend_lookup_table(
+        lookup_table,
+        authority,
+        payer,
+        &house_lookup_table_addresses(auction_house_key, auction_house),
+    );
+
+    (vec![create, extend], lookup_table)
+}
+
+/// Compile `instructions` into a v0 message that loads accounts found in `lookup_tables` by
+/// index instead of listing them in full.
+pub fn versioned_message(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedMessage, CompileError> {
+    Ok(VersionedMessage::V0(v0::Message::try_compile(
+        payer,
+        instructions,
+        lookup_tables,
+        recent_blockhash,
+    )?))
+}
@@ -0,0 +1,546 @@
+use crate::{constants::*, id};
+use mpl_auction_common::seeds;
+use solana_program::pubkey::Pubkey;
+
+pub fn find_auction_house_address(authority: &Pubkey, mint_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::auction_house(authority, mint_address), &id())
+}
+
+pub fn find_auction_house_fee_account_address(auction_house_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::fee_payer(auction_house_address), &id())
+}
+
+pub fn find_auction_house_treasury_address(auction_house_address: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::treasury(auction_house_address), &id())
+}
+
+pub fn find_auction_house_buyer_escrow_account_address(
+    auction_house_address: &Pubkey,
+    wallet: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::escrow_payment(auction_house_address, wallet), &id())
+}
+
+pub fn find_program_as_signer_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::program_as_signer(), &id())
+}
+
+pub fn find_escrow_payment_address(auction_house: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::escrow_payment(auction_house, wallet), &id())
+}
+
+/// Return trade state `Pubkey` address and bump seed.
+pub fn find_trade_state_address(
+    wallet: &Pubkey,
+    auction_house: &Pubkey,
+    token_account: &Pubkey,
+    treasury_mint: &Pubkey,
+    token_mint: &Pubkey,
+    price: u64,
+    token_size: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            wallet.as_ref(),
+            auction_house.as_ref(),
+            token_account.as_ref(),
+            treasury_mint.as_ref(),
+            token_mint.as_ref(),
+            &price.to_le_bytes(),
+            &token_size.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+/// Return trade state `Pubkey` address and bump seed.
+pub fn find_public_bid_trade_state_address(
+    wallet: &Pubkey,
+    auction_house: &Pubkey,
+    treasury_mint: &Pubkey,
+    token_mint: &Pubkey,
+    price: u64,
+    token_size: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            wallet.as_ref(),
+            auction_house.as_ref(),
+            treasury_mint.as_ref(),
+            token_mint.as_ref(),
+            &price.to_le_bytes(),
+            &token_size.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+/// Return bid receipt `Pubkey` address and bump seed.
+pub fn find_bid_receipt_address(trade_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BID_RECEIPT_PREFIX.as_bytes(), trade_state.as_ref()],
+        &id(),
+    )
+}
+
+/// Return liting receipt `Pubkey` address and bump seed.
+pub fn find_listing_receipt_address(trade_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LISTING_RECEIPT_PREFIX.as_bytes(), trade_state.as_ref()],
+        &id(),
+    )
+}
+
+/// Return purchase receipt `Pubkey` address and bump seed.
+pub fn find_purchase_receipt_address(
+    seller_trade_state: &Pubkey,
+    buyer_trade_state: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PURCHASE_RECEIPT_PREFIX.as_bytes(),
+            seller_trade_state.as_ref(),
+            buyer_trade_state.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the `Pubkey` and bump of the Auctioneer PDA.
+pub fn find_auctioneer_pda(auction_house: &Pubkey, auctioneer_authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &seeds::auctioneer(auction_house, auctioneer_authority),
+        &id(),
+    )
+}
+
+pub fn find_auctioneer_trade_state_address(
+    wallet: &Pubkey,
+    auction_house: &Pubkey,
+    token_account: &Pubkey,
+    treasury_mint: &Pubkey,
+    token_mint: &Pubkey,
+    token_size: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PREFIX.as_bytes(),
+            wallet.as_ref(),
+            auction_house.as_ref(),
+            token_account.as_ref(),
+            treasury_mint.as_ref(),
+            token_mint.as_ref(),
+            &u64::MAX.to_le_bytes(),
+            &token_size.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the fee stake config `Pubkey` address and bump seed.
+pub fn find_fee_stake_config_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_STAKE.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return the fee stake vault `Pubkey` address and bump seed.
+pub fn find_fee_stake_vault_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FEE_STAKE_VAULT.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return a wallet's fee stake `Pubkey` address and bump seed.
+pub fn find_fee_stake_address(auction_house: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FEE_STAKE.as_bytes(),
+            auction_house.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the mint cooldown `Pubkey` address and bump seed.
+pub fn find_mint_cooldown_address(auction_house: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MINT_COOLDOWN.as_bytes(),
+            auction_house.as_ref(),
+            mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the authority log `Pubkey` address and bump seed.
+pub fn find_authority_log_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUTHORITY_LOG.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return the protected settlement `Pubkey` address and bump seed.
+pub fn find_protected_settlement_address(
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+    buyer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PROTECTED_SETTLEMENT.as_bytes(),
+            auction_house.as_ref(),
+            token_mint.as_ref(),
+            buyer.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the lien `Pubkey` address and bump seed.
+pub fn find_lien_address(auction_house: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LIEN.as_bytes(), auction_house.as_ref(), token_mint.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the frozen proceeds vault `Pubkey` address and bump seed.
+pub fn find_frozen_proceeds_vault_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FROZEN_PROCEEDS_VAULT.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the frozen proceeds `Pubkey` address and bump seed.
+pub fn find_frozen_proceeds_address(auction_house: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FROZEN_PROCEEDS.as_bytes(),
+            auction_house.as_ref(),
+            recipient.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the listing fee `Pubkey` address and bump seed.
+pub fn find_listing_fee_address(
+    auction_house: &Pubkey,
+    seller_trade_state: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LISTING_FEE.as_bytes(),
+            auction_house.as_ref(),
+            seller_trade_state.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the deposit authority `Pubkey` address and bump seed.
+pub fn find_deposit_authority_address(
+    auction_house: &Pubkey,
+    wallet: &Pubkey,
+    depositor: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            DEPOSIT_AUTHORITY.as_bytes(),
+            auction_house.as_ref(),
+            wallet.as_ref(),
+            depositor.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the auctioneer stats `Pubkey` address and bump seed for an auctioneer authority.
+pub fn find_auctioneer_stats_address(
+    auction_house: &Pubkey,
+    auctioneer_authority: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AUCTIONEER_STATS.as_bytes(),
+            auction_house.as_ref(),
+            auctioneer_authority.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the auctioneer allowlist `Pubkey` address and bump seed.
+pub fn find_auctioneer_allowlist_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AUCTIONEER_ALLOWLIST.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the public bid index `Pubkey` address and bump seed.
+pub fn find_public_bid_index_address(auction_house: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PUBLIC_BID_INDEX.as_bytes(),
+            auction_house.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the bid expiry `Pubkey` address and bump seed.
+pub fn find_bid_expiry_address(trade_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_EXPIRY.as_bytes(), trade_state.as_ref()], &id())
+}
+
+/// Return the compliance config `Pubkey` address and bump seed.
+pub fn find_compliance_config_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COMPLIANCE_CONFIG.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the receipt tree `Pubkey` address and bump seed.
+pub fn find_receipt_tree_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RECEIPT_TREE.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return the tax withholding `Pubkey` address and bump seed.
+pub fn find_tax_withholding_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TAX_WITHHOLDING.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return the wanted listing `Pubkey` address and bump seed.
+pub fn find_wanted_listing_address(
+    auction_house: &Pubkey,
+    buyer: &Pubkey,
+    target: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WANTED_LISTING.as_bytes(),
+            auction_house.as_ref(),
+            buyer.as_ref(),
+            target.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the fee sweep config `Pubkey` address and bump seed.
+pub fn find_fee_sweep_config_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FEE_SWEEP_CONFIG.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the treasury withdrawal limit `Pubkey` address and bump seed.
+pub fn find_treasury_withdrawal_limit_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TREASURY_WITHDRAWAL_LIMIT.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the bid ticket config `Pubkey` address and bump seed.
+pub fn find_bid_ticket_config_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BID_TICKET_CONFIG.as_bytes(), auction_house.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the bid ticket burn `Pubkey` address and bump seed for a bid's trade state.
+pub fn find_bid_ticket_burn_address(trade_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_TICKET_BURN.as_bytes(), trade_state.as_ref()], &id())
+}
+
+/// Return the settlement marker `Pubkey` address and bump seed for a protected settlement.
+pub fn find_settlement_marker_address(protected_settlement: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SETTLEMENT_MARKER.as_bytes(), protected_settlement.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the marketplace binding `Pubkey` address and bump seed for a collection.
+pub fn find_marketplace_binding_address(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MARKETPLACE_BINDING.as_bytes(), collection.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the voucher `Pubkey` address and bump seed.
+pub fn find_voucher_address(
+    auction_house: &Pubkey,
+    creator: &Pubkey,
+    voucher_id: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            VOUCHER.as_bytes(),
+            auction_house.as_ref(),
+            creator.as_ref(),
+            &voucher_id.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the leaderboard `Pubkey` address and bump seed for a competition epoch.
+pub fn find_leaderboard_address(auction_house: &Pubkey, epoch: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LEADERBOARD.as_bytes(),
+            auction_house.as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the royalty bonus `Pubkey` address and bump seed for a listing.
+pub fn find_royalty_bonus_address(
+    auction_house: &Pubkey,
+    seller_trade_state: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ROYALTY_BONUS.as_bytes(),
+            auction_house.as_ref(),
+            seller_trade_state.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the delayed payout `Pubkey` address and bump seed for a sale.
+pub fn find_delayed_payout_address(
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+    buyer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            DELAYED_PAYOUT.as_bytes(),
+            auction_house.as_ref(),
+            token_mint.as_ref(),
+            buyer.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the shared custody `Pubkey` address and bump seed for a token.
+pub fn find_shared_custody_address(auction_house: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SHARED_CUSTODY.as_bytes(),
+            auction_house.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the price history `Pubkey` address and bump seed for a mint.
+pub fn find_price_history_address(auction_house: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PRICE_HISTORY.as_bytes(),
+            auction_house.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the spending cap `Pubkey` address and bump seed for a wallet and frontend.
+pub fn find_spending_cap_address(
+    auction_house: &Pubkey,
+    wallet: &Pubkey,
+    frontend: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SPENDING_CAP.as_bytes(),
+            auction_house.as_ref(),
+            wallet.as_ref(),
+            frontend.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the omnibus custodian `Pubkey` address and bump seed for a custodian wallet.
+pub fn find_omnibus_custodian_address(auction_house: &Pubkey, custodian: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            OMNIBUS_CUSTODIAN.as_bytes(),
+            auction_house.as_ref(),
+            custodian.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the trade attribution `Pubkey` address and bump seed for a custodian's trade state.
+pub fn find_trade_attribution_address(custodian: &Pubkey, trade_state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TRADE_ATTRIBUTION.as_bytes(),
+            custodian.as_ref(),
+            trade_state.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the active listing `Pubkey` address and bump seed.
+pub fn find_active_listing_address(
+    auction_house: &Pubkey,
+    seller: &Pubkey,
+    mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ACTIVE_LISTING.as_bytes(),
+            auction_house.as_ref(),
+            seller.as_ref(),
+            mint.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+/// Return the trade sequence `Pubkey` address and bump seed.
+pub fn find_trade_sequence_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TRADE_SEQUENCE.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return the house notice `Pubkey` address and bump seed.
+pub fn find_house_notice_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOUSE_NOTICE.as_bytes(), auction_house.as_ref()], &id())
+}
+
+/// Return the dispute record `Pubkey` address and bump seed.
+pub fn find_dispute_record_address(protected_settlement: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[DISPUTE_RECORD.as_bytes(), protected_settlement.as_ref()],
+        &id(),
+    )
+}
+
+/// Return the associated token account `Pubkey` address of `wallet` for `mint`.
+pub fn find_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
@@ -0,0 +1,841 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{keccak, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::constants::{MAX_NUM_SCOPES, MAX_PRICE_HISTORY_ENTRIES};
+
+/// An Auction House account with its 8 byte Anchor discriminator.
+pub trait AuctionHouseAccount: BorshDeserialize {
+    const DISCRIMINATOR: [u8; 8];
+
+    /// Deserialize the account from its raw data, checking the discriminator. Trailing padding
+    /// is ignored.
+    fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 || data[..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Self::deserialize(&mut &data[8..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuctionHouse {
+    pub auction_house_fee_account: Pubkey,
+    pub auction_house_treasury: Pubkey,
+    pub treasury_withdrawal_destination: Pubkey,
+    pub fee_withdrawal_destination: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub creator: Pubkey,
+    pub bump: u8,
+    pub treasury_bump: u8,
+    pub fee_payer_bump: u8,
+    pub seller_fee_basis_points: u16,
+    pub requires_sign_off: bool,
+    pub can_change_sale_price: bool,
+    pub escrow_payment_bump: u8,
+    pub has_auctioneer: bool,
+    pub auctioneer_address: Pubkey,
+    pub scopes: [bool; MAX_NUM_SCOPES],
+    pub relist_cooldown_seconds: i64,
+    pub has_authority_log: bool,
+    pub buyer_protection_seconds: i64,
+    pub restocking_fee_basis_points: u16,
+    pub arbiter: Pubkey,
+    pub allow_seller_financing: bool,
+    pub min_listing_price: Option<u64>,
+    pub max_listing_price: Option<u64>,
+    pub has_auctioneer_allowlist: bool,
+    pub has_compliance_hook: bool,
+    pub has_receipt_tree: bool,
+    pub has_tax_withholding: bool,
+    pub low_fee_balance_threshold: u64,
+    pub has_bid_ticket_gate: bool,
+    pub allow_wallet_bound_receipts: bool,
+    pub is_immutable: bool,
+    pub previous_auctioneer_address: Pubkey,
+    pub previous_auctioneer_expires_at: i64,
+    pub rounding_policy: RoundingPolicy,
+    pub payout_delay_seconds: i64,
+    pub token_standard_policy: u8,
+    pub requires_verified_creators: bool,
+    pub single_active_listing: bool,
+    pub has_trade_sequence: bool,
+    pub locks_listing_currency: bool,
+}
+
+impl AuctionHouseAccount for AuctionHouse {
+    const DISCRIMINATOR: [u8; 8] = [40, 108, 215, 107, 213, 85, 245, 48];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Auctioneer {
+    pub auctioneer_authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub auctioneer_program: Pubkey,
+    pub has_stats: bool,
+}
+
+impl AuctionHouseAccount for Auctioneer {
+    const DISCRIMINATOR: [u8; 8] = [46, 101, 92, 150, 138, 30, 245, 120];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeStakeConfig {
+    pub auction_house: Pubkey,
+    pub stake_mint: Pubkey,
+    pub stake_vault: Pubkey,
+    pub min_stake_amount: u64,
+    pub discounted_seller_fee_basis_points: u16,
+    pub lockup_seconds: i64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+impl AuctionHouseAccount for FeeStakeConfig {
+    const DISCRIMINATOR: [u8; 8] = [218, 215, 51, 108, 55, 1, 36, 219];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeStake {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub amount: u64,
+    pub staked_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for FeeStake {
+    const DISCRIMINATOR: [u8; 8] = [230, 118, 81, 187, 45, 78, 41, 217];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MintCooldown {
+    pub auction_house: Pubkey,
+    pub mint: Pubkey,
+    pub last_settled_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for MintCooldown {
+    const DISCRIMINATOR: [u8; 8] = [131, 1, 87, 3, 94, 87, 147, 118];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProtectedSettlement {
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    pub seller_token_account: Pubkey,
+    pub rent_payer: Pubkey,
+    pub token_size: u64,
+    pub seller_proceeds: u64,
+    pub restocking_fee_basis_points: u16,
+    pub release_at: i64,
+    pub bump: u8,
+    pub disputed: bool,
+}
+
+impl AuctionHouseAccount for ProtectedSettlement {
+    const DISCRIMINATOR: [u8; 8] = [4, 10, 209, 69, 189, 115, 177, 154];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DelayedPayout {
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub token_mint: Pubkey,
+    pub rent_payer: Pubkey,
+    pub amount: u64,
+    pub release_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for DelayedPayout {
+    const DISCRIMINATOR: [u8; 8] = [168, 240, 248, 102, 143, 70, 142, 101];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Lien {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub outstanding: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for Lien {
+    const DISCRIMINATOR: [u8; 8] = [42, 39, 130, 104, 109, 197, 122, 174];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FrozenProceeds {
+    pub auction_house: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for FrozenProceeds {
+    const DISCRIMINATOR: [u8; 8] = [226, 184, 33, 197, 201, 4, 241, 247];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListingFee {
+    pub auction_house: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub fee_basis_points: u16,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for ListingFee {
+    const DISCRIMINATOR: [u8; 8] = [83, 45, 163, 139, 214, 40, 195, 194];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyBonus {
+    pub auction_house: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub wallet: Pubkey,
+    pub bonus_basis_points: u16,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for RoyaltyBonus {
+    const DISCRIMINATOR: [u8; 8] = [252, 193, 45, 139, 181, 78, 146, 11];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositAuthority {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub depositor: Pubkey,
+    pub approved_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for DepositAuthority {
+    const DISCRIMINATOR: [u8; 8] = [102, 235, 234, 250, 70, 235, 46, 6];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuctioneerAllowlist {
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub programs: Vec<Pubkey>,
+}
+
+impl AuctionHouseAccount for AuctioneerAllowlist {
+    const DISCRIMINATOR: [u8; 8] = [36, 126, 150, 8, 24, 134, 106, 118];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuctioneerStats {
+    pub auction_house: Pubkey,
+    pub auctioneer_authority: Pubkey,
+    pub auctions_run: u64,
+    pub settled_volume: u64,
+    pub fees_earned: u64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for AuctioneerStats {
+    const DISCRIMINATOR: [u8; 8] = [234, 53, 47, 14, 82, 183, 193, 223];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PublicBidIndex {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    pub bids: Vec<IndexedPublicBid>,
+}
+
+impl AuctionHouseAccount for PublicBidIndex {
+    const DISCRIMINATOR: [u8; 8] = [76, 61, 37, 42, 122, 125, 129, 253];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexedPublicBid {
+    pub trade_state: Pubkey,
+    pub wallet: Pubkey,
+    pub buyer_price: u64,
+    pub token_size: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BidExpiry {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub trade_state: Pubkey,
+    pub buyer_price: u64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for BidExpiry {
+    const DISCRIMINATOR: [u8; 8] = [35, 238, 136, 228, 51, 14, 137, 174];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ComplianceConfig {
+    pub auction_house: Pubkey,
+    pub jurisdiction_code: [u8; 2],
+    pub compliance_program: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for ComplianceConfig {
+    const DISCRIMINATOR: [u8; 8] = [157, 84, 248, 198, 253, 41, 75, 251];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TaxWithholding {
+    pub auction_house: Pubkey,
+    pub withholding_basis_points: u16,
+    pub recipient: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for TaxWithholding {
+    const DISCRIMINATOR: [u8; 8] = [28, 176, 101, 158, 41, 37, 33, 82];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WantedListing {
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    pub criteria: WantedCriteria,
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for WantedListing {
+    const DISCRIMINATOR: [u8; 8] = [235, 141, 177, 203, 30, 64, 89, 230];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WantedCriteria {
+    Mint(Pubkey),
+    Collection(Pubkey),
+}
+
+impl WantedCriteria {
+    pub fn target(&self) -> &Pubkey {
+        match self {
+            WantedCriteria::Mint(mint) => mint,
+            WantedCriteria::Collection(collection) => collection,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeeSweepConfig {
+    pub auction_house: Pubkey,
+    pub threshold: u64,
+    pub total_swept: u64,
+    pub last_swept_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for FeeSweepConfig {
+    const DISCRIMINATOR: [u8; 8] = [77, 87, 63, 129, 103, 196, 148, 60];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TreasuryWithdrawalLimit {
+    pub auction_house: Pubkey,
+    pub max_per_period: u64,
+    pub period_seconds: i64,
+    pub period_start: i64,
+    pub withdrawn_in_period: u64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for TreasuryWithdrawalLimit {
+    const DISCRIMINATOR: [u8; 8] = [62, 122, 149, 11, 214, 192, 235, 1];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BidTicketConfig {
+    pub auction_house: Pubkey,
+    pub collection: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for BidTicketConfig {
+    const DISCRIMINATOR: [u8; 8] = [37, 198, 90, 16, 235, 107, 190, 201];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BidTicketBurn {
+    pub auction_house: Pubkey,
+    pub trade_state: Pubkey,
+    pub wallet: Pubkey,
+    pub ticket_mint: Pubkey,
+    pub burned_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for BidTicketBurn {
+    const DISCRIMINATOR: [u8; 8] = [44, 30, 242, 55, 109, 169, 218, 82];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MarketplaceBinding {
+    pub collection: Pubkey,
+    pub bump: u8,
+    pub approved_houses: Vec<Pubkey>,
+}
+
+impl AuctionHouseAccount for MarketplaceBinding {
+    const DISCRIMINATOR: [u8; 8] = [237, 57, 2, 187, 235, 203, 225, 80];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Voucher {
+    pub auction_house: Pubkey,
+    pub creator: Pubkey,
+    pub id: u64,
+    pub price: u64,
+    pub redeem_deadline: i64,
+    pub buyer: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for Voucher {
+    const DISCRIMINATOR: [u8; 8] = [191, 204, 149, 234, 213, 165, 13, 65];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub auction_house: Pubkey,
+    pub epoch: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub bump: u8,
+    pub buyers: Vec<LeaderboardEntry>,
+    pub sellers: Vec<LeaderboardEntry>,
+}
+
+impl AuctionHouseAccount for Leaderboard {
+    const DISCRIMINATOR: [u8; 8] = [247, 186, 238, 243, 194, 30, 9, 36];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub wallet: Pubkey,
+    pub volume: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SharedCustody {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub depositor: Pubkey,
+    pub threshold: u8,
+    pub listing_price: u64,
+    pub approvals: u16,
+    pub is_listed: bool,
+    pub bump: u8,
+    pub owners: Vec<CoOwner>,
+}
+
+impl AuctionHouseAccount for SharedCustody {
+    const DISCRIMINATOR: [u8; 8] = [181, 165, 122, 228, 88, 12, 131, 187];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoOwner {
+    pub wallet: Pubkey,
+    pub share_basis_points: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PriceHistory {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    pub next_index: u16,
+    pub entries: Vec<PricePoint>,
+}
+
+impl AuctionHouseAccount for PriceHistory {
+    const DISCRIMINATOR: [u8; 8] = [38, 241, 40, 19, 42, 228, 93, 152];
+}
+
+impl PriceHistory {
+    /// The recorded sales from oldest to newest.
+    pub fn chronological(&self) -> impl Iterator<Item = &PricePoint> {
+        let (newer, older) = if self.entries.len() < MAX_PRICE_HISTORY_ENTRIES {
+            (&self.entries[..], &self.entries[..0])
+        } else {
+            self.entries.split_at(self.next_index as usize)
+        };
+        older.iter().chain(newer.iter())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PricePoint {
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HouseBalances {
+    pub treasury_balance: u64,
+    pub fee_account_balance: u64,
+    pub unswept_fees: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SpendingCap {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub frontend: Pubkey,
+    pub cap: u64,
+    pub spent: u64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for SpendingCap {
+    const DISCRIMINATOR: [u8; 8] = [161, 106, 72, 116, 0, 33, 135, 137];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OmnibusCustodian {
+    pub auction_house: Pubkey,
+    pub custodian: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for OmnibusCustodian {
+    const DISCRIMINATOR: [u8; 8] = [150, 221, 186, 254, 162, 10, 228, 41];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TradeAttribution {
+    pub auction_house: Pubkey,
+    pub custodian: Pubkey,
+    pub trade_state: Pubkey,
+    pub sub_account: [u8; 32],
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for TradeAttribution {
+    const DISCRIMINATOR: [u8; 8] = [2, 109, 209, 164, 190, 209, 15, 58];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ActiveListing {
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    pub mint: Pubkey,
+    pub trade_state: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for ActiveListing {
+    const DISCRIMINATOR: [u8; 8] = [30, 86, 118, 97, 162, 4, 91, 137];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TradeSequence {
+    pub auction_house: Pubkey,
+    pub last_sequence: u64,
+    pub last_trade_state: Pubkey,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for TradeSequence {
+    const DISCRIMINATOR: [u8; 8] = [215, 144, 198, 161, 249, 129, 123, 86];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HouseNotice {
+    pub auction_house: Pubkey,
+    pub status: NoticeStatus,
+    pub message_hash: [u8; 32],
+    pub expires_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for HouseNotice {
+    const DISCRIMINATOR: [u8; 8] = [230, 178, 202, 88, 177, 251, 87, 248];
+}
+
+impl HouseNotice {
+    /// Whether the notice should still be shown at `timestamp`.
+    pub fn is_active(&self, timestamp: i64) -> bool {
+        self.status != NoticeStatus::None && (self.expires_at == 0 || timestamp < self.expires_at)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoticeStatus {
+    None,
+    Info,
+    Maintenance,
+    Paused,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SettlementMarker {
+    pub auction_house: Pubkey,
+    pub protected_settlement: Pubkey,
+    pub status: SettlementStatus,
+    pub attempts: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for SettlementMarker {
+    const DISCRIMINATOR: [u8; 8] = [95, 150, 119, 32, 155, 88, 201, 49];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettlementStatus {
+    NotStarted,
+    Escrowed,
+    Settled,
+    RolledBack,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DisputeRecord {
+    pub auction_house: Pubkey,
+    pub protected_settlement: Pubkey,
+    pub opened_by: Pubkey,
+    pub opened_at: i64,
+    pub resolution: Option<DisputeResolution>,
+    pub resolved_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for DisputeRecord {
+    const DISCRIMINATOR: [u8; 8] = [198, 199, 79, 209, 12, 215, 34, 47];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeResolution {
+    Buyer,
+    Seller,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingPolicy {
+    Floor,
+    CreatorCeiling,
+    HalfEven,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorityLog {
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub next_index: u16,
+    pub total_entries: u64,
+    pub entries: Vec<AuthorityLogEntry>,
+}
+
+impl AuctionHouseAccount for AuthorityLog {
+    const DISCRIMINATOR: [u8; 8] = [100, 122, 118, 146, 169, 18, 9, 95];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorityLogEntry {
+    pub action: AuthorityAction,
+    pub authority: Pubkey,
+    pub value: u64,
+    pub timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorityAction {
+    UpdateAuctionHouse,
+    WithdrawFromTreasury,
+    WithdrawFromFee,
+    DelegateAuctioneer,
+    UpdateAuctioneer,
+    CreateFeeStakeConfig,
+    UpdateFeeStakeConfig,
+    SetRelistCooldown,
+    SetBuyerProtection,
+    SetArbiter,
+    SetSellerFinancing,
+    SetListingPriceBounds,
+    CreateAuctioneerAllowlist,
+    UpdateAuctioneerAllowlist,
+    SetComplianceConfig,
+    CreateReceiptTree,
+    SetTaxWithholding,
+    SetFeeSweepThreshold,
+    SetLowFeeBalanceThreshold,
+    SetTreasuryWithdrawalLimit,
+    SetBidTicketConfig,
+    SetWalletBoundReceipts,
+    CreateAuctioneerStats,
+    RotateAuctioneer,
+    CreateLeaderboard,
+    SetRoundingPolicy,
+    SetPayoutDelay,
+    ClawbackProceeds,
+    SetTokenStandardPolicy,
+    SetRequiresVerifiedCreators,
+    SetSingleActiveListing,
+    SetListingCurrencyLock,
+    SetHouseNotice,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListingReceipt {
+    pub trade_state: Pubkey,
+    pub bookkeeper: Pubkey,
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub purchase_receipt: Option<Pubkey>,
+    pub price: u64,
+    pub token_size: u64,
+    pub bump: u8,
+    pub trade_state_bump: u8,
+    pub created_at: i64,
+    pub canceled_at: Option<i64>,
+    pub sub_account: Option<[u8; 32]>,
+    pub sequence: Option<u64>,
+}
+
+impl AuctionHouseAccount for ListingReceipt {
+    const DISCRIMINATOR: [u8; 8] = [240, 71, 225, 94, 200, 75, 84, 231];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BidReceipt {
+    pub trade_state: Pubkey,
+    pub bookkeeper: Pubkey,
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub token_account: Option<Pubkey>,
+    pub purchase_receipt: Option<Pubkey>,
+    pub price: u64,
+    pub token_size: u64,
+    pub bump: u8,
+    pub trade_state_bump: u8,
+    pub created_at: i64,
+    pub canceled_at: Option<i64>,
+    pub sub_account: Option<[u8; 32]>,
+    pub sequence: Option<u64>,
+}
+
+impl AuctionHouseAccount for BidReceipt {
+    const DISCRIMINATOR: [u8; 8] = [186, 150, 141, 135, 59, 122, 39, 99];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PurchaseReceipt {
+    pub bookkeeper: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub auction_house: Pubkey,
+    pub metadata: Pubkey,
+    pub token_size: u64,
+    pub price: u64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub tax_withheld: u64,
+    pub buyer_sub_account: Option<[u8; 32]>,
+    pub seller_sub_account: Option<[u8; 32]>,
+}
+
+impl AuctionHouseAccount for PurchaseReceipt {
+    const DISCRIMINATOR: [u8; 8] = [79, 127, 222, 137, 154, 131, 150, 134];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptTree {
+    pub auction_house: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub leaf_count: u64,
+    pub bump: u8,
+}
+
+impl AuctionHouseAccount for ReceiptTree {
+    const DISCRIMINATOR: [u8; 8] = [229, 241, 21, 162, 193, 150, 129, 203];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum CompressedReceipt {
+    Listing {
+        trade_state: Pubkey,
+        seller: Pubkey,
+        metadata: Pubkey,
+        price: u64,
+        token_size: u64,
+    },
+    Bid {
+        trade_state: Pubkey,
+        buyer: Pubkey,
+        metadata: Pubkey,
+        token_account: Option<Pubkey>,
+        price: u64,
+        token_size: u64,
+    },
+    Purchase {
+        buyer_trade_state: Pubkey,
+        seller_trade_state: Pubkey,
+        buyer: Pubkey,
+        seller: Pubkey,
+        metadata: Pubkey,
+        price: u64,
+        token_size: u64,
+        tax_withheld: u64,
+    },
+    Cancel {
+        trade_state: Pubkey,
+    },
+}
+
+/// Versioned layout of a compressed receipt leaf, as logged to the noop program.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ReceiptLeafSchema {
+    V1 {
+        auction_house: Pubkey,
+        bookkeeper: Pubkey,
+        nonce: u64,
+        created_at: i64,
+        receipt: CompressedReceipt,
+    },
+}
+
+impl ReceiptLeafSchema {
+    /// The leaf hash appended to the receipt tree.
+    pub fn hash(&self) -> Result<[u8; 32], ProgramError> {
+        Ok(keccak::hash(&self.try_to_vec()?).to_bytes())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AuthorityScope {
+    Deposit = 0,
+    Buy = 1,
+    PublicBuy = 2,
+    ExecuteSale = 3,
+    Sell = 4,
+    Cancel = 5,
+    Withdraw = 6,
+    SetFee = 7,
+    PauseListing = 8,
+    WithdrawTreasury = 9,
+}
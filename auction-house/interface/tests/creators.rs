@@ -0,0 +1,95 @@
+use mpl_auction_house_interface::{
+    constants::NATIVE_MINT,
+    creators::{creator_remaining_accounts, metadata_creators, MetadataCreator},
+    pda::{
+        find_associated_token_address, find_frozen_proceeds_address,
+        find_frozen_proceeds_vault_address,
+    },
+};
+use solana_program::pubkey::Pubkey;
+
+fn push_string(data: &mut Vec<u8>, value: &str) {
+    data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    data.extend_from_slice(value.as_bytes());
+}
+
+fn metadata_data(creators: &[MetadataCreator]) -> Vec<u8> {
+    let mut data = vec![4];
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    push_string(&mut data, "Test\0\0\0\0");
+    push_string(&mut data, "TST");
+    push_string(&mut data, "uri");
+    data.extend_from_slice(&500u16.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(&(creators.len() as u32).to_le_bytes());
+    for creator in creators {
+        data.extend_from_slice(creator.address.as_ref());
+        data.push(creator.verified as u8);
+        data.push(creator.share);
+    }
+    // Fields after the creators are ignored.
+    data.extend_from_slice(&[1, 255, 0, 0]);
+    data
+}
+
+fn creators() -> Vec<MetadataCreator> {
+    vec![
+        MetadataCreator {
+            address: Pubkey::new_unique(),
+            verified: true,
+            share: 70,
+        },
+        MetadataCreator {
+            address: Pubkey::new_unique(),
+            verified: false,
+            share: 30,
+        },
+    ]
+}
+
+#[test]
+fn metadata_creators_reads_creators_in_order() {
+    let creators = creators();
+    assert_eq!(
+        metadata_creators(&metadata_data(&creators)).unwrap(),
+        creators
+    );
+    assert!(metadata_creators(&[4, 0, 0]).is_err());
+}
+
+#[test]
+fn native_house_takes_creator_wallets() {
+    let creators = creators();
+    let accounts = creator_remaining_accounts(&Pubkey::new_unique(), &NATIVE_MINT, &creators, &[]);
+
+    let keys: Vec<Pubkey> = accounts.iter().map(|account| account.pubkey).collect();
+    assert_eq!(keys, vec![creators[0].address, creators[1].address]);
+    assert!(accounts.iter().all(|account| account.is_writable));
+}
+
+#[test]
+fn spl_house_takes_creator_token_accounts_and_frozen_proceeds() {
+    let auction_house = Pubkey::new_unique();
+    let treasury_mint = Pubkey::new_unique();
+    let creators = creators();
+    let accounts = creator_remaining_accounts(
+        &auction_house,
+        &treasury_mint,
+        &creators,
+        &[creators[1].address],
+    );
+
+    let keys: Vec<Pubkey> = accounts.iter().map(|account| account.pubkey).collect();
+    assert_eq!(
+        keys,
+        vec![
+            creators[0].address,
+            find_associated_token_address(&creators[0].address, &treasury_mint),
+            creators[1].address,
+            find_associated_token_address(&creators[1].address, &treasury_mint),
+            find_frozen_proceeds_vault_address(&auction_house).0,
+            find_frozen_proceeds_address(&auction_house, &creators[1].address).0,
+        ]
+    );
+}
@@ -0,0 +1,310 @@
+use mpl_auction_house_interface::{instruction::discriminator::*, state::*};
+use solana_program::hash::hash;
+
+fn sighash(namespace: &str, name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator
+        .copy_from_slice(&hash(format!("{}:{}", namespace, name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+#[test]
+fn account_discriminators_match_anchor() {
+    assert_eq!(
+        AuctionHouse::DISCRIMINATOR,
+        sighash("account", "AuctionHouse")
+    );
+    assert_eq!(Auctioneer::DISCRIMINATOR, sighash("account", "Auctioneer"));
+    assert_eq!(
+        FeeStakeConfig::DISCRIMINATOR,
+        sighash("account", "FeeStakeConfig")
+    );
+    assert_eq!(FeeStake::DISCRIMINATOR, sighash("account", "FeeStake"));
+    assert_eq!(
+        MintCooldown::DISCRIMINATOR,
+        sighash("account", "MintCooldown")
+    );
+    assert_eq!(
+        ProtectedSettlement::DISCRIMINATOR,
+        sighash("account", "ProtectedSettlement")
+    );
+    assert_eq!(
+        DelayedPayout::DISCRIMINATOR,
+        sighash("account", "DelayedPayout")
+    );
+    assert_eq!(Lien::DISCRIMINATOR, sighash("account", "Lien"));
+    assert_eq!(
+        FrozenProceeds::DISCRIMINATOR,
+        sighash("account", "FrozenProceeds")
+    );
+    assert_eq!(ListingFee::DISCRIMINATOR, sighash("account", "ListingFee"));
+    assert_eq!(
+        RoyaltyBonus::DISCRIMINATOR,
+        sighash("account", "RoyaltyBonus")
+    );
+    assert_eq!(
+        DepositAuthority::DISCRIMINATOR,
+        sighash("account", "DepositAuthority")
+    );
+    assert_eq!(
+        AuctioneerAllowlist::DISCRIMINATOR,
+        sighash("account", "AuctioneerAllowlist")
+    );
+    assert_eq!(
+        AuctioneerStats::DISCRIMINATOR,
+        sighash("account", "AuctioneerStats")
+    );
+    assert_eq!(
+        PublicBidIndex::DISCRIMINATOR,
+        sighash("account", "PublicBidIndex")
+    );
+    assert_eq!(BidExpiry::DISCRIMINATOR, sighash("account", "BidExpiry"));
+    assert_eq!(
+        ComplianceConfig::DISCRIMINATOR,
+        sighash("account", "ComplianceConfig")
+    );
+    assert_eq!(
+        TaxWithholding::DISCRIMINATOR,
+        sighash("account", "TaxWithholding")
+    );
+    assert_eq!(
+        WantedListing::DISCRIMINATOR,
+        sighash("account", "WantedListing")
+    );
+    assert_eq!(
+        FeeSweepConfig::DISCRIMINATOR,
+        sighash("account", "FeeSweepConfig")
+    );
+    assert_eq!(
+        TreasuryWithdrawalLimit::DISCRIMINATOR,
+        sighash("account", "TreasuryWithdrawalLimit")
+    );
+    assert_eq!(
+        BidTicketConfig::DISCRIMINATOR,
+        sighash("account", "BidTicketConfig")
+    );
+    assert_eq!(
+        BidTicketBurn::DISCRIMINATOR,
+        sighash("account", "BidTicketBurn")
+    );
+    assert_eq!(
+        MarketplaceBinding::DISCRIMINATOR,
+        sighash("account", "MarketplaceBinding")
+    );
+    assert_eq!(Voucher::DISCRIMINATOR, sighash("account", "Voucher"));
+    assert_eq!(
+        Leaderboard::DISCRIMINATOR,
+        sighash("account", "Leaderboard")
+    );
+    assert_eq!(
+        SharedCustody::DISCRIMINATOR,
+        sighash("account", "SharedCustody")
+    );
+    assert_eq!(
+        PriceHistory::DISCRIMINATOR,
+        sighash("account", "PriceHistory")
+    );
+    assert_eq!(
+        SpendingCap::DISCRIMINATOR,
+        sighash("account", "SpendingCap")
+    );
+    assert_eq!(
+        OmnibusCustodian::DISCRIMINATOR,
+        sighash("account", "OmnibusCustodian")
+    );
+    assert_eq!(
+        TradeAttribution::DISCRIMINATOR,
+        sighash("account", "TradeAttribution")
+    );
+    assert_eq!(
+        ActiveListing::DISCRIMINATOR,
+        sighash("account", "ActiveListing")
+    );
+    assert_eq!(
+        TradeSequence::DISCRIMINATOR,
+        sighash("account", "TradeSequence")
+    );
+    assert_eq!(
+        HouseNotice::DISCRIMINATOR,
+        sighash("account", "HouseNotice")
+    );
+    assert_eq!(
+        SettlementMarker::DISCRIMINATOR,
+        sighash("account", "SettlementMarker")
+    );
+    assert_eq!(
+        DisputeRecord::DISCRIMINATOR,
+        sighash("account", "DisputeRecord")
+    );
+    assert_eq!(
+        AuthorityLog::DISCRIMINATOR,
+        sighash("account", "AuthorityLog")
+    );
+    assert_eq!(
+        ListingReceipt::DISCRIMINATOR,
+        sighash("account", "ListingReceipt")
+    );
+    assert_eq!(BidReceipt::DISCRIMINATOR, sighash("account", "BidReceipt"));
+    assert_eq!(
+        PurchaseReceipt::DISCRIMINATOR,
+        sighash("account", "PurchaseReceipt")
+    );
+    assert_eq!(
+        ReceiptTree::DISCRIMINATOR,
+        sighash("account", "ReceiptTree")
+    );
+}
+
+#[test]
+fn instruction_discriminators_match_anchor() {
+    let instructions = [
+        (WITHDRAW_FROM_FEE, "withdraw_from_fee"),
+        (WITHDRAW_FROM_TREASURY, "withdraw_from_treasury"),
+        (UPDATE_AUCTION_HOUSE, "update_auction_house"),
+        (CREATE_AUCTION_HOUSE, "create_auction_house"),
+        (BUY, "buy"),
+        (AUCTIONEER_BUY, "auctioneer_buy"),
+        (PUBLIC_BUY, "public_buy"),
+        (AUCTIONEER_PUBLIC_BUY, "auctioneer_public_buy"),
+        (CANCEL, "cancel"),
+        (AUCTIONEER_CANCEL, "auctioneer_cancel"),
+        (DEPOSIT, "deposit"),
+        (AUCTIONEER_DEPOSIT, "auctioneer_deposit"),
+        (EXECUTE_SALE, "execute_sale"),
+        (EXECUTE_PARTIAL_SALE, "execute_partial_sale"),
+        (STRICT_EXECUTE_SALE, "strict_execute_sale"),
+        (AUCTIONEER_EXECUTE_SALE, "auctioneer_execute_sale"),
+        (
+            AUCTIONEER_EXECUTE_PARTIAL_SALE,
+            "auctioneer_execute_partial_sale",
+        ),
+        (SELL, "sell"),
+        (AUCTIONEER_SELL, "auctioneer_sell"),
+        (WITHDRAW, "withdraw"),
+        (AUCTIONEER_WITHDRAW, "auctioneer_withdraw"),
+        (WITHDRAW_MANY, "withdraw_many"),
+        (CLOSE_ESCROW_ACCOUNT, "close_escrow_account"),
+        (DELEGATE_AUCTIONEER, "delegate_auctioneer"),
+        (UPDATE_AUCTIONEER, "update_auctioneer"),
+        (CREATE_FEE_STAKE_CONFIG, "create_fee_stake_config"),
+        (UPDATE_FEE_STAKE_CONFIG, "update_fee_stake_config"),
+        (STAKE_FOR_FEES, "stake_for_fees"),
+        (UNSTAKE, "unstake"),
+        (SET_RELIST_COOLDOWN, "set_relist_cooldown"),
+        (CREATE_AUTHORITY_LOG, "create_authority_log"),
+        (SET_BUYER_PROTECTION, "set_buyer_protection"),
+        (CANCEL_PROTECTED_SETTLEMENT, "cancel_protected_settlement"),
+        (
+            FINALIZE_PROTECTED_SETTLEMENT,
+            "finalize_protected_settlement",
+        ),
+        (SET_ARBITER, "set_arbiter"),
+        (OPEN_DISPUTE, "open_dispute"),
+        (RESOLVE_DISPUTE, "resolve_dispute"),
+        (SET_SELLER_FINANCING, "set_seller_financing"),
+        (EXECUTE_FINANCED_SALE, "execute_financed_sale"),
+        (REPAY_LIEN, "repay_lien"),
+        (CREATE_FROZEN_PROCEEDS_VAULT, "create_frozen_proceeds_vault"),
+        (CLAIM_FROZEN_PROCEEDS, "claim_frozen_proceeds"),
+        (AUCTIONEER_SET_LISTING_FEE, "auctioneer_set_listing_fee"),
+        (APPROVE_DEPOSIT_AUTHORITY, "approve_deposit_authority"),
+        (REVOKE_DEPOSIT_AUTHORITY, "revoke_deposit_authority"),
+        (DEPOSIT_ON_BEHALF, "deposit_on_behalf"),
+        (SET_LISTING_PRICE_BOUNDS, "set_listing_price_bounds"),
+        (CREATE_AUCTIONEER_ALLOWLIST, "create_auctioneer_allowlist"),
+        (UPDATE_AUCTIONEER_ALLOWLIST, "update_auctioneer_allowlist"),
+        (CREATE_PUBLIC_BID_INDEX, "create_public_bid_index"),
+        (PRUNE_PUBLIC_BID_INDEX, "prune_public_bid_index"),
+        (SET_BID_EXPIRY, "set_bid_expiry"),
+        (SWEEP_EXPIRED_BIDS, "sweep_expired_bids"),
+        (CREATE_COMPLIANCE_CONFIG, "create_compliance_config"),
+        (UPDATE_COMPLIANCE_CONFIG, "update_compliance_config"),
+        (CREATE_TAX_WITHHOLDING, "create_tax_withholding"),
+        (UPDATE_TAX_WITHHOLDING, "update_tax_withholding"),
+        (CREATE_WANTED_LISTING, "create_wanted_listing"),
+        (CANCEL_WANTED_LISTING, "cancel_wanted_listing"),
+        (FILL_WANTED, "fill_wanted"),
+        (CREATE_FEE_SWEEP_CONFIG, "create_fee_sweep_config"),
+        (UPDATE_FEE_SWEEP_CONFIG, "update_fee_sweep_config"),
+        (SWEEP_FEE_TO_TREASURY, "sweep_fee_to_treasury"),
+        (
+            SET_LOW_FEE_BALANCE_THRESHOLD,
+            "set_low_fee_balance_threshold",
+        ),
+        (FUND_FEE_ACCOUNT, "fund_fee_account"),
+        (
+            CREATE_TREASURY_WITHDRAWAL_LIMIT,
+            "create_treasury_withdrawal_limit",
+        ),
+        (
+            UPDATE_TREASURY_WITHDRAWAL_LIMIT,
+            "update_treasury_withdrawal_limit",
+        ),
+        (
+            AUCTIONEER_WITHDRAW_FROM_TREASURY,
+            "auctioneer_withdraw_from_treasury",
+        ),
+        (CREATE_BID_TICKET_CONFIG, "create_bid_ticket_config"),
+        (UPDATE_BID_TICKET_CONFIG, "update_bid_ticket_config"),
+        (ASSERT_SETTLEMENT_STATUS, "assert_settlement_status"),
+        (SET_WALLET_BOUND_RECEIPTS, "set_wallet_bound_receipts"),
+        (CREATE_MARKETPLACE_BINDING, "create_marketplace_binding"),
+        (UPDATE_MARKETPLACE_BINDING, "update_marketplace_binding"),
+        (CREATE_AUCTIONEER_STATS, "create_auctioneer_stats"),
+        (BID_WITH_TRANSFER, "bid_with_transfer"),
+        (SELL_WITH_RECEIPT, "sell_with_receipt"),
+        (BUY_WITH_RECEIPT, "buy_with_receipt"),
+        (CREATE_VOUCHER, "create_voucher"),
+        (CANCEL_VOUCHER, "cancel_voucher"),
+        (BUY_VOUCHER, "buy_voucher"),
+        (REDEEM_VOUCHER, "redeem_voucher"),
+        (RECLAIM_VOUCHER, "reclaim_voucher"),
+        (
+            FINALIZE_PROTECTED_SETTLEMENTS,
+            "finalize_protected_settlements",
+        ),
+        (ROTATE_AUCTIONEER, "rotate_auctioneer"),
+        (TRANSFER_BID, "transfer_bid"),
+        (CREATE_LEADERBOARD, "create_leaderboard"),
+        (SET_ROUNDING_POLICY, "set_rounding_policy"),
+        (CREATE_ROYALTY_BONUS, "create_royalty_bonus"),
+        (CLOSE_ROYALTY_BONUS, "close_royalty_bonus"),
+        (CREATE_SHARED_CUSTODY, "create_shared_custody"),
+        (CO_LIST, "co_list"),
+        (SETTLE_SHARED_CUSTODY, "settle_shared_custody"),
+        (SET_PAYOUT_DELAY, "set_payout_delay"),
+        (CLAIM_PROCEEDS, "claim_proceeds"),
+        (CLAWBACK_PROCEEDS, "clawback_proceeds"),
+        (CREATE_PRICE_HISTORY, "create_price_history"),
+        (SET_TOKEN_STANDARD_POLICY, "set_token_standard_policy"),
+        (
+            SET_REQUIRES_VERIFIED_CREATORS,
+            "set_requires_verified_creators",
+        ),
+        (SET_SINGLE_ACTIVE_LISTING, "set_single_active_listing"),
+        (SET_LISTING_CURRENCY_LOCK, "set_listing_currency_lock"),
+        (CREATE_HOUSE_NOTICE, "create_house_notice"),
+        (SET_HOUSE_NOTICE, "set_house_notice"),
+        (CREATE_TRADE_SEQUENCE, "create_trade_sequence"),
+        (PREPARE_BUYER, "prepare_buyer"),
+        (CREATE_SPENDING_CAP, "create_spending_cap"),
+        (UPDATE_SPENDING_CAP, "update_spending_cap"),
+        (CLOSE_SPENDING_CAP, "close_spending_cap"),
+        (GET_HOUSE_BALANCES, "get_house_balances"),
+        (REGISTER_OMNIBUS_CUSTODIAN, "register_omnibus_custodian"),
+        (DEREGISTER_OMNIBUS_CUSTODIAN, "deregister_omnibus_custodian"),
+        (ATTRIBUTE_TRADE, "attribute_trade"),
+        (CLOSE_TRADE_ATTRIBUTION, "close_trade_attribution"),
+        (PRINT_LISTING_RECEIPT, "print_listing_receipt"),
+        (CANCEL_LISTING_RECEIPT, "cancel_listing_receipt"),
+        (PRINT_BID_RECEIPT, "print_bid_receipt"),
+        (CANCEL_BID_RECEIPT, "cancel_bid_receipt"),
+        (PRINT_PURCHASE_RECEIPT, "print_purchase_receipt"),
+        (CREATE_RECEIPT_TREE, "create_receipt_tree"),
+        (LOG_RECEIPT, "log_receipt"),
+    ];
+
+    for (discriminator, name) in instructions {
+        assert_eq!(discriminator, sighash("global", name), "{}", name);
+    }
+}
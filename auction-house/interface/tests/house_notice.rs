@@ -0,0 +1,35 @@
+use mpl_auction_house_interface::state::{HouseNotice, NoticeStatus};
+use solana_program::pubkey::Pubkey;
+
+fn house_notice(status: NoticeStatus, expires_at: i64) -> HouseNotice {
+    HouseNotice {
+        auction_house: Pubkey::new_unique(),
+        status,
+        message_hash: [7; 32],
+        expires_at,
+        updated_at: 100,
+        bump: 255,
+    }
+}
+
+#[test]
+fn notice_without_expiry_stays_active() {
+    let notice = house_notice(NoticeStatus::Maintenance, 0);
+
+    assert!(notice.is_active(i64::MAX));
+}
+
+#[test]
+fn notice_is_inactive_once_expired() {
+    let notice = house_notice(NoticeStatus::Paused, 200);
+
+    assert!(notice.is_active(199));
+    assert!(!notice.is_active(200));
+}
+
+#[test]
+fn cleared_notice_is_inactive() {
+    let notice = house_notice(NoticeStatus::None, 0);
+
+    assert!(!notice.is_active(100));
+}
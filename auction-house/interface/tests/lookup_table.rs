@@ -0,0 +1,114 @@
+use mpl_auction_house_interface::{
+    constants::MAX_NUM_SCOPES,
+    instruction::{deposit, DepositAccounts},
+    lookup_table::*,
+    pda::find_escrow_payment_address,
+    AuctionHouse, RoundingPolicy,
+};
+use solana_program::{address_lookup_table::instruction as alt, hash::Hash, pubkey::Pubkey};
+
+fn auction_house() -> AuctionHouse {
+    AuctionHouse {
+        auction_house_fee_account: Pubkey::new_unique(),
+        auction_house_treasury: Pubkey::new_unique(),
+        treasury_withdrawal_destination: Pubkey::new_unique(),
+        fee_withdrawal_destination: Pubkey::new_unique(),
+        treasury_mint: Pubkey::new_unique(),
+        authority: Pubkey::new_unique(),
+        creator: Pubkey::new_unique(),
+        bump: 255,
+        treasury_bump: 255,
+        fee_payer_bump: 255,
+        seller_fee_basis_points: 200,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+        escrow_payment_bump: 255,
+        has_auctioneer: false,
+        auctioneer_address: Pubkey::default(),
+        scopes: [false; MAX_NUM_SCOPES],
+        relist_cooldown_seconds: 0,
+        has_authority_log: false,
+        buyer_protection_seconds: 0,
+        restocking_fee_basis_points: 0,
+        arbiter: Pubkey::default(),
+        allow_seller_financing: false,
+        min_listing_price: None,
+        max_listing_price: None,
+        has_auctioneer_allowlist: false,
+        has_compliance_hook: false,
+        has_receipt_tree: false,
+        has_tax_withholding: false,
+        low_fee_balance_threshold: 0,
+        has_bid_ticket_gate: false,
+        allow_wallet_bound_receipts: false,
+        is_immutable: false,
+        previous_auctioneer_address: Pubkey::default(),
+        previous_auctioneer_expires_at: 0,
+        rounding_policy: RoundingPolicy::Floor,
+        payout_delay_seconds: 0,
+        token_standard_policy: 0,
+        requires_verified_creators: false,
+        single_active_listing: false,
+        has_trade_sequence: false,
+        locks_listing_currency: false,
+    }
+}
+
+#[test]
+fn lookup_table_instructions_match_the_lookup_table_program() {
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let (create, lookup_table) = create_lookup_table(authority, payer, 42);
+    assert_eq!(
+        (create, lookup_table),
+        alt::create_lookup_table(authority, payer, 42)
+    );
+    assert_eq!(
+        extend_lookup_table(lookup_table, authority, payer, &addresses),
+        alt::extend_lookup_table(lookup_table, authority, Some(payer), addresses)
+    );
+}
+
+#[test]
+fn versioned_message_loads_house_accounts_from_lookup_table() {
+    let auction_house_key = Pubkey::new_unique();
+    let auction_house = auction_house();
+    let wallet = Pubkey::new_unique();
+    let (escrow_payment_account, escrow_payment_bump) =
+        find_escrow_payment_address(&auction_house_key, &wallet);
+    let ix = deposit(
+        DepositAccounts {
+            wallet,
+            payment_account: wallet,
+            transfer_authority: wallet,
+            escrow_payment_account,
+            treasury_mint: auction_house.treasury_mint,
+            authority: auction_house.authority,
+            auction_house: auction_house_key,
+            auction_house_fee_account: auction_house.auction_house_fee_account,
+        },
+        escrow_payment_bump,
+        1,
+    );
+
+    let (_, lookup_table) =
+        create_house_lookup_table(&auction_house_key, &auction_house, wallet, wallet, 42);
+    let lookup_table_account = AddressLookupTableAccount {
+        key: lookup_table,
+        addresses: house_lookup_table_addresses(&auction_house_key, &auction_house),
+    };
+
+    let message =
+        versioned_message(&wallet, &[ix], &[lookup_table_account], Hash::default()).unwrap();
+    assert_eq!(
+        message.static_account_keys(),
+        &[
+            wallet,
+            escrow_payment_account,
+            mpl_auction_house_interface::id()
+        ]
+    );
+    assert_eq!(message.address_table_lookups().unwrap().len(), 1);
+}
@@ -0,0 +1,41 @@
+use mpl_auction_house_interface::{
+    constants::MAX_PRICE_HISTORY_ENTRIES,
+    state::{PriceHistory, PricePoint},
+};
+use solana_program::pubkey::Pubkey;
+
+fn price_history(prices: impl Iterator<Item = u64>, next_index: u16) -> PriceHistory {
+    PriceHistory {
+        auction_house: Pubkey::new_unique(),
+        token_mint: Pubkey::new_unique(),
+        bump: 255,
+        next_index,
+        entries: prices
+            .map(|price| PricePoint {
+                price,
+                timestamp: price as i64,
+            })
+            .collect(),
+    }
+}
+
+fn chronological_prices(history: &PriceHistory) -> Vec<u64> {
+    history.chronological().map(|point| point.price).collect()
+}
+
+#[test]
+fn chronological_before_the_buffer_wraps() {
+    let history = price_history(1..=3, 3);
+
+    assert_eq!(chronological_prices(&history), vec![1, 2, 3]);
+}
+
+#[test]
+fn chronological_after_the_buffer_wraps() {
+    // Two sales past a full buffer overwrote its two oldest entries.
+    let full = MAX_PRICE_HISTORY_ENTRIES as u64;
+    let history = price_history((full + 1..=full + 2).chain(3..=full), 2);
+
+    let expected: Vec<u64> = (3..=full + 2).collect();
+    assert_eq!(chronological_prices(&history), expected);
+}
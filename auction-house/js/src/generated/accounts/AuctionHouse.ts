@@ -6,8 +6,9 @@
  */
 
 import * as web3 from '@solana/web3.js';
-import * as beetSolana from '@metaplex-foundation/beet-solana';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { RoundingPolicy, roundingPolicyBeet } from '../types/RoundingPolicy';
 
 /**
  * Arguments used to create {@link AuctionHouse}
@@ -31,7 +32,32 @@ export type AuctionHouseArgs = {
   escrowPaymentBump: number;
   hasAuctioneer: boolean;
   auctioneerAddress: web3.PublicKey;
-  scopes: boolean[] /* size: 7 */;
+  scopes: boolean[] /* size: 10 */;
+  relistCooldownSeconds: beet.bignum;
+  hasAuthorityLog: boolean;
+  buyerProtectionSeconds: beet.bignum;
+  restockingFeeBasisPoints: number;
+  arbiter: web3.PublicKey;
+  allowSellerFinancing: boolean;
+  minListingPrice: beet.COption<beet.bignum>;
+  maxListingPrice: beet.COption<beet.bignum>;
+  hasAuctioneerAllowlist: boolean;
+  hasComplianceHook: boolean;
+  hasReceiptTree: boolean;
+  hasTaxWithholding: boolean;
+  lowFeeBalanceThreshold: beet.bignum;
+  hasBidTicketGate: boolean;
+  allowWalletBoundReceipts: boolean;
+  isImmutable: boolean;
+  previousAuctioneerAddress: web3.PublicKey;
+  previousAuctioneerExpiresAt: beet.bignum;
+  roundingPolicy: RoundingPolicy;
+  payoutDelaySeconds: beet.bignum;
+  tokenStandardPolicy: number;
+  requiresVerifiedCreators: boolean;
+  singleActiveListing: boolean;
+  hasTradeSequence: boolean;
+  locksListingCurrency: boolean;
 };
 
 export const auctionHouseDiscriminator = [40, 108, 215, 107, 213, 85, 245, 48];
@@ -60,7 +86,32 @@ export class AuctionHouse implements AuctionHouseArgs {
     readonly escrowPaymentBump: number,
     readonly hasAuctioneer: boolean,
     readonly auctioneerAddress: web3.PublicKey,
-    readonly scopes: boolean[] /* size: 7 */,
+    readonly scopes: boolean[] /* size: 10 */,
+    readonly relistCooldownSeconds: beet.bignum,
+    readonly hasAuthorityLog: boolean,
+    readonly buyerProtectionSeconds: beet.bignum,
+    readonly restockingFeeBasisPoints: number,
+    readonly arbiter: web3.PublicKey,
+    readonly allowSellerFinancing: boolean,
+    readonly minListingPrice: beet.COption<beet.bignum>,
+    readonly maxListingPrice: beet.COption<beet.bignum>,
+    readonly hasAuctioneerAllowlist: boolean,
+    readonly hasComplianceHook: boolean,
+    readonly hasReceiptTree: boolean,
+    readonly hasTaxWithholding: boolean,
+    readonly lowFeeBalanceThreshold: beet.bignum,
+    readonly hasBidTicketGate: boolean,
+    readonly allowWalletBoundReceipts: boolean,
+    readonly isImmutable: boolean,
+    readonly previousAuctioneerAddress: web3.PublicKey,
+    readonly previousAuctioneerExpiresAt: beet.bignum,
+    readonly roundingPolicy: RoundingPolicy,
+    readonly payoutDelaySeconds: beet.bignum,
+    readonly tokenStandardPolicy: number,
+    readonly requiresVerifiedCreators: boolean,
+    readonly singleActiveListing: boolean,
+    readonly hasTradeSequence: boolean,
+    readonly locksListingCurrency: boolean,
   ) {}
 
   /**
@@ -85,6 +136,31 @@ export class AuctionHouse implements AuctionHouseArgs {
       args.hasAuctioneer,
       args.auctioneerAddress,
       args.scopes,
+      args.relistCooldownSeconds,
+      args.hasAuthorityLog,
+      args.buyerProtectionSeconds,
+      args.restockingFeeBasisPoints,
+      args.arbiter,
+      args.allowSellerFinancing,
+      args.minListingPrice,
+      args.maxListingPrice,
+      args.hasAuctioneerAllowlist,
+      args.hasComplianceHook,
+      args.hasReceiptTree,
+      args.hasTaxWithholding,
+      args.lowFeeBalanceThreshold,
+      args.hasBidTicketGate,
+      args.allowWalletBoundReceipts,
+      args.isImmutable,
+      args.previousAuctioneerAddress,
+      args.previousAuctioneerExpiresAt,
+      args.roundingPolicy,
+      args.payoutDelaySeconds,
+      args.tokenStandardPolicy,
+      args.requiresVerifiedCreators,
+      args.singleActiveListing,
+      args.hasTradeSequence,
+      args.locksListingCurrency,
     );
   }
 
@@ -149,31 +225,33 @@ export class AuctionHouse implements AuctionHouseArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link AuctionHouse}
+   * {@link AuctionHouse} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return auctionHouseBeet.byteSize;
+  static byteSize(args: AuctionHouseArgs) {
+    const instance = AuctionHouse.fromArgs(args);
+    return auctionHouseBeet.toFixedFromValue({
+      accountDiscriminator: auctionHouseDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link AuctionHouse} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: AuctionHouseArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(AuctionHouse.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link AuctionHouse} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === AuctionHouse.byteSize;
+    return connection.getMinimumBalanceForRentExemption(AuctionHouse.byteSize(args), commitment);
   }
 
   /**
@@ -199,6 +277,81 @@ export class AuctionHouse implements AuctionHouseArgs {
       hasAuctioneer: this.hasAuctioneer,
       auctioneerAddress: this.auctioneerAddress.toBase58(),
       scopes: this.scopes,
+      relistCooldownSeconds: (() => {
+        const x = <{ toNumber: () => number }>this.relistCooldownSeconds;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      hasAuthorityLog: this.hasAuthorityLog,
+      buyerProtectionSeconds: (() => {
+        const x = <{ toNumber: () => number }>this.buyerProtectionSeconds;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      restockingFeeBasisPoints: this.restockingFeeBasisPoints,
+      arbiter: this.arbiter.toBase58(),
+      allowSellerFinancing: this.allowSellerFinancing,
+      minListingPrice: this.minListingPrice,
+      maxListingPrice: this.maxListingPrice,
+      hasAuctioneerAllowlist: this.hasAuctioneerAllowlist,
+      hasComplianceHook: this.hasComplianceHook,
+      hasReceiptTree: this.hasReceiptTree,
+      hasTaxWithholding: this.hasTaxWithholding,
+      lowFeeBalanceThreshold: (() => {
+        const x = <{ toNumber: () => number }>this.lowFeeBalanceThreshold;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      hasBidTicketGate: this.hasBidTicketGate,
+      allowWalletBoundReceipts: this.allowWalletBoundReceipts,
+      isImmutable: this.isImmutable,
+      previousAuctioneerAddress: this.previousAuctioneerAddress.toBase58(),
+      previousAuctioneerExpiresAt: (() => {
+        const x = <{ toNumber: () => number }>this.previousAuctioneerExpiresAt;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      roundingPolicy: 'RoundingPolicy.' + RoundingPolicy[this.roundingPolicy],
+      payoutDelaySeconds: (() => {
+        const x = <{ toNumber: () => number }>this.payoutDelaySeconds;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      tokenStandardPolicy: this.tokenStandardPolicy,
+      requiresVerifiedCreators: this.requiresVerifiedCreators,
+      singleActiveListing: this.singleActiveListing,
+      hasTradeSequence: this.hasTradeSequence,
+      locksListingCurrency: this.locksListingCurrency,
     };
   }
 }
@@ -207,7 +360,7 @@ export class AuctionHouse implements AuctionHouseArgs {
  * @category Accounts
  * @category generated
  */
-export const auctionHouseBeet = new beet.BeetStruct<
+export const auctionHouseBeet = new beet.FixableBeetStruct<
   AuctionHouse,
   AuctionHouseArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -231,7 +384,32 @@ export const auctionHouseBeet = new beet.BeetStruct<
     ['escrowPaymentBump', beet.u8],
     ['hasAuctioneer', beet.bool],
     ['auctioneerAddress', beetSolana.publicKey],
-    ['scopes', beet.uniformFixedSizeArray(beet.bool, 7)],
+    ['scopes', beet.uniformFixedSizeArray(beet.bool, 10)],
+    ['relistCooldownSeconds', beet.i64],
+    ['hasAuthorityLog', beet.bool],
+    ['buyerProtectionSeconds', beet.i64],
+    ['restockingFeeBasisPoints', beet.u16],
+    ['arbiter', beetSolana.publicKey],
+    ['allowSellerFinancing', beet.bool],
+    ['minListingPrice', beet.coption(beet.u64)],
+    ['maxListingPrice', beet.coption(beet.u64)],
+    ['hasAuctioneerAllowlist', beet.bool],
+    ['hasComplianceHook', beet.bool],
+    ['hasReceiptTree', beet.bool],
+    ['hasTaxWithholding', beet.bool],
+    ['lowFeeBalanceThreshold', beet.u64],
+    ['hasBidTicketGate', beet.bool],
+    ['allowWalletBoundReceipts', beet.bool],
+    ['isImmutable', beet.bool],
+    ['previousAuctioneerAddress', beetSolana.publicKey],
+    ['previousAuctioneerExpiresAt', beet.i64],
+    ['roundingPolicy', roundingPolicyBeet],
+    ['payoutDelaySeconds', beet.i64],
+    ['tokenStandardPolicy', beet.u8],
+    ['requiresVerifiedCreators', beet.bool],
+    ['singleActiveListing', beet.bool],
+    ['hasTradeSequence', beet.bool],
+    ['locksListingCurrency', beet.bool],
   ],
   AuctionHouse.fromArgs,
   'AuctionHouse',
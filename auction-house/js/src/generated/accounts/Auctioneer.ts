@@ -18,6 +18,8 @@ export type AuctioneerArgs = {
   auctioneerAuthority: web3.PublicKey;
   auctionHouse: web3.PublicKey;
   bump: number;
+  auctioneerProgram: web3.PublicKey;
+  hasStats: boolean;
 };
 
 export const auctioneerDiscriminator = [46, 101, 92, 150, 138, 30, 245, 120];
@@ -33,13 +35,21 @@ export class Auctioneer implements AuctioneerArgs {
     readonly auctioneerAuthority: web3.PublicKey,
     readonly auctionHouse: web3.PublicKey,
     readonly bump: number,
+    readonly auctioneerProgram: web3.PublicKey,
+    readonly hasStats: boolean,
   ) {}
 
   /**
    * Creates a {@link Auctioneer} instance from the provided args.
    */
   static fromArgs(args: AuctioneerArgs) {
-    return new Auctioneer(args.auctioneerAuthority, args.auctionHouse, args.bump);
+    return new Auctioneer(
+      args.auctioneerAuthority,
+      args.auctionHouse,
+      args.bump,
+      args.auctioneerProgram,
+      args.hasStats,
+    );
   }
 
   /**
@@ -136,6 +146,8 @@ export class Auctioneer implements AuctioneerArgs {
       auctioneerAuthority: this.auctioneerAuthority.toBase58(),
       auctionHouse: this.auctionHouse.toBase58(),
       bump: this.bump,
+      auctioneerProgram: this.auctioneerProgram.toBase58(),
+      hasStats: this.hasStats,
     };
   }
 }
@@ -155,6 +167,8 @@ export const auctioneerBeet = new beet.BeetStruct<
     ['auctioneerAuthority', beetSolana.publicKey],
     ['auctionHouse', beetSolana.publicKey],
     ['bump', beet.u8],
+    ['auctioneerProgram', beetSolana.publicKey],
+    ['hasStats', beet.bool],
   ],
   Auctioneer.fromArgs,
   'Auctioneer',
@@ -28,6 +28,8 @@ export type BidReceiptArgs = {
   tradeStateBump: number;
   createdAt: beet.bignum;
   canceledAt: beet.COption<beet.bignum>;
+  subAccount: beet.COption<number[] /* size: 32 */>;
+  sequence: beet.COption<beet.bignum>;
 };
 
 export const bidReceiptDiscriminator = [186, 150, 141, 135, 59, 122, 39, 99];
@@ -53,6 +55,8 @@ export class BidReceipt implements BidReceiptArgs {
     readonly tradeStateBump: number,
     readonly createdAt: beet.bignum,
     readonly canceledAt: beet.COption<beet.bignum>,
+    readonly subAccount: beet.COption<number[] /* size: 32 */>,
+    readonly sequence: beet.COption<beet.bignum>,
   ) {}
 
   /**
@@ -73,6 +77,8 @@ export class BidReceipt implements BidReceiptArgs {
       args.tradeStateBump,
       args.createdAt,
       args.canceledAt,
+      args.subAccount,
+      args.sequence,
     );
   }
 
@@ -212,6 +218,8 @@ export class BidReceipt implements BidReceiptArgs {
         return x;
       })(),
       canceledAt: this.canceledAt,
+      subAccount: this.subAccount,
+      sequence: this.sequence,
     };
   }
 }
@@ -241,6 +249,8 @@ export const bidReceiptBeet = new beet.FixableBeetStruct<
     ['tradeStateBump', beet.u8],
     ['createdAt', beet.i64],
     ['canceledAt', beet.coption(beet.i64)],
+    ['subAccount', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['sequence', beet.coption(beet.u64)],
   ],
   BidReceipt.fromArgs,
   'BidReceipt',
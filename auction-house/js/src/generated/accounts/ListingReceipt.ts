@@ -27,6 +27,8 @@ export type ListingReceiptArgs = {
   tradeStateBump: number;
   createdAt: beet.bignum;
   canceledAt: beet.COption<beet.bignum>;
+  subAccount: beet.COption<number[] /* size: 32 */>;
+  sequence: beet.COption<beet.bignum>;
 };
 
 export const listingReceiptDiscriminator = [240, 71, 225, 94, 200, 75, 84, 231];
@@ -51,6 +53,8 @@ export class ListingReceipt implements ListingReceiptArgs {
     readonly tradeStateBump: number,
     readonly createdAt: beet.bignum,
     readonly canceledAt: beet.COption<beet.bignum>,
+    readonly subAccount: beet.COption<number[] /* size: 32 */>,
+    readonly sequence: beet.COption<beet.bignum>,
   ) {}
 
   /**
@@ -70,6 +74,8 @@ export class ListingReceipt implements ListingReceiptArgs {
       args.tradeStateBump,
       args.createdAt,
       args.canceledAt,
+      args.subAccount,
+      args.sequence,
     );
   }
 
@@ -211,6 +217,8 @@ export class ListingReceipt implements ListingReceiptArgs {
         return x;
       })(),
       canceledAt: this.canceledAt,
+      subAccount: this.subAccount,
+      sequence: this.sequence,
     };
   }
 }
@@ -239,6 +247,8 @@ export const listingReceiptBeet = new beet.FixableBeetStruct<
     ['tradeStateBump', beet.u8],
     ['createdAt', beet.i64],
     ['canceledAt', beet.coption(beet.i64)],
+    ['subAccount', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['sequence', beet.coption(beet.u64)],
   ],
   ListingReceipt.fromArgs,
   'ListingReceipt',
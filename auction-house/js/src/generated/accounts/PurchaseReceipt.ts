@@ -24,6 +24,9 @@ export type PurchaseReceiptArgs = {
   price: beet.bignum;
   bump: number;
   createdAt: beet.bignum;
+  taxWithheld: beet.bignum;
+  buyerSubAccount: beet.COption<number[] /* size: 32 */>;
+  sellerSubAccount: beet.COption<number[] /* size: 32 */>;
 };
 
 export const purchaseReceiptDiscriminator = [79, 127, 222, 137, 154, 131, 150, 134];
@@ -45,6 +48,9 @@ export class PurchaseReceipt implements PurchaseReceiptArgs {
     readonly price: beet.bignum,
     readonly bump: number,
     readonly createdAt: beet.bignum,
+    readonly taxWithheld: beet.bignum,
+    readonly buyerSubAccount: beet.COption<number[] /* size: 32 */>,
+    readonly sellerSubAccount: beet.COption<number[] /* size: 32 */>,
   ) {}
 
   /**
@@ -61,6 +67,9 @@ export class PurchaseReceipt implements PurchaseReceiptArgs {
       args.price,
       args.bump,
       args.createdAt,
+      args.taxWithheld,
+      args.buyerSubAccount,
+      args.sellerSubAccount,
     );
   }
 
@@ -125,31 +134,33 @@ export class PurchaseReceipt implements PurchaseReceiptArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link PurchaseReceipt}
+   * {@link PurchaseReceipt} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return purchaseReceiptBeet.byteSize;
+  static byteSize(args: PurchaseReceiptArgs) {
+    const instance = PurchaseReceipt.fromArgs(args);
+    return purchaseReceiptBeet.toFixedFromValue({
+      accountDiscriminator: purchaseReceiptDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link PurchaseReceipt} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: PurchaseReceiptArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(PurchaseReceipt.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link PurchaseReceipt} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === PurchaseReceipt.byteSize;
+    return connection.getMinimumBalanceForRentExemption(PurchaseReceipt.byteSize(args), commitment);
   }
 
   /**
@@ -197,6 +208,19 @@ export class PurchaseReceipt implements PurchaseReceiptArgs {
         }
         return x;
       })(),
+      taxWithheld: (() => {
+        const x = <{ toNumber: () => number }>this.taxWithheld;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      buyerSubAccount: this.buyerSubAccount,
+      sellerSubAccount: this.sellerSubAccount,
     };
   }
 }
@@ -205,7 +229,7 @@ export class PurchaseReceipt implements PurchaseReceiptArgs {
  * @category Accounts
  * @category generated
  */
-export const purchaseReceiptBeet = new beet.BeetStruct<
+export const purchaseReceiptBeet = new beet.FixableBeetStruct<
   PurchaseReceipt,
   PurchaseReceiptArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -222,6 +246,9 @@ export const purchaseReceiptBeet = new beet.BeetStruct<
     ['price', beet.u64],
     ['bump', beet.u8],
     ['createdAt', beet.i64],
+    ['taxWithheld', beet.u64],
+    ['buyerSubAccount', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['sellerSubAccount', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
   ],
   PurchaseReceipt.fromArgs,
   'PurchaseReceipt',
@@ -1,19 +1,127 @@
+export * from './ActiveListing';
 export * from './AuctionHouse';
 export * from './Auctioneer';
+export * from './AuctioneerAllowlist';
+export * from './AuctioneerStats';
+export * from './AuthorityLog';
+export * from './BidExpiry';
 export * from './BidReceipt';
+export * from './BidTicketBurn';
+export * from './BidTicketConfig';
+export * from './ComplianceConfig';
+export * from './DelayedPayout';
+export * from './DepositAuthority';
+export * from './DisputeRecord';
+export * from './FeeStake';
+export * from './FeeStakeConfig';
+export * from './FeeSweepConfig';
+export * from './FrozenProceeds';
+export * from './HouseNotice';
+export * from './Leaderboard';
+export * from './Lien';
+export * from './ListingFee';
 export * from './ListingReceipt';
+export * from './MarketplaceBinding';
+export * from './MintCooldown';
+export * from './OmnibusCustodian';
+export * from './PriceHistory';
+export * from './ProtectedSettlement';
+export * from './PublicBidIndex';
 export * from './PurchaseReceipt';
+export * from './ReceiptTree';
+export * from './RoyaltyBonus';
+export * from './SettlementMarker';
+export * from './SharedCustody';
+export * from './SpendingCap';
+export * from './TaxWithholding';
+export * from './TradeAttribution';
+export * from './TradeSequence';
+export * from './TreasuryWithdrawalLimit';
+export * from './Voucher';
+export * from './WantedListing';
 
+import { ReceiptTree } from './ReceiptTree';
 import { BidReceipt } from './BidReceipt';
 import { ListingReceipt } from './ListingReceipt';
 import { PurchaseReceipt } from './PurchaseReceipt';
 import { AuctionHouse } from './AuctionHouse';
 import { Auctioneer } from './Auctioneer';
+import { FeeStakeConfig } from './FeeStakeConfig';
+import { FeeStake } from './FeeStake';
+import { MintCooldown } from './MintCooldown';
+import { ProtectedSettlement } from './ProtectedSettlement';
+import { DelayedPayout } from './DelayedPayout';
+import { DisputeRecord } from './DisputeRecord';
+import { Lien } from './Lien';
+import { FrozenProceeds } from './FrozenProceeds';
+import { ListingFee } from './ListingFee';
+import { RoyaltyBonus } from './RoyaltyBonus';
+import { DepositAuthority } from './DepositAuthority';
+import { AuctioneerAllowlist } from './AuctioneerAllowlist';
+import { AuctioneerStats } from './AuctioneerStats';
+import { PublicBidIndex } from './PublicBidIndex';
+import { BidExpiry } from './BidExpiry';
+import { ComplianceConfig } from './ComplianceConfig';
+import { TaxWithholding } from './TaxWithholding';
+import { WantedListing } from './WantedListing';
+import { FeeSweepConfig } from './FeeSweepConfig';
+import { TreasuryWithdrawalLimit } from './TreasuryWithdrawalLimit';
+import { BidTicketConfig } from './BidTicketConfig';
+import { BidTicketBurn } from './BidTicketBurn';
+import { MarketplaceBinding } from './MarketplaceBinding';
+import { Voucher } from './Voucher';
+import { Leaderboard } from './Leaderboard';
+import { SharedCustody } from './SharedCustody';
+import { PriceHistory } from './PriceHistory';
+import { SpendingCap } from './SpendingCap';
+import { OmnibusCustodian } from './OmnibusCustodian';
+import { TradeAttribution } from './TradeAttribution';
+import { ActiveListing } from './ActiveListing';
+import { TradeSequence } from './TradeSequence';
+import { HouseNotice } from './HouseNotice';
+import { SettlementMarker } from './SettlementMarker';
+import { AuthorityLog } from './AuthorityLog';
 
 export const accountProviders = {
+  ReceiptTree,
   BidReceipt,
   ListingReceipt,
   PurchaseReceipt,
   AuctionHouse,
   Auctioneer,
+  FeeStakeConfig,
+  FeeStake,
+  MintCooldown,
+  ProtectedSettlement,
+  DelayedPayout,
+  DisputeRecord,
+  Lien,
+  FrozenProceeds,
+  ListingFee,
+  RoyaltyBonus,
+  DepositAuthority,
+  AuctioneerAllowlist,
+  AuctioneerStats,
+  PublicBidIndex,
+  BidExpiry,
+  ComplianceConfig,
+  TaxWithholding,
+  WantedListing,
+  FeeSweepConfig,
+  TreasuryWithdrawalLimit,
+  BidTicketConfig,
+  BidTicketBurn,
+  MarketplaceBinding,
+  Voucher,
+  Leaderboard,
+  SharedCustody,
+  PriceHistory,
+  SpendingCap,
+  OmnibusCustodian,
+  TradeAttribution,
+  ActiveListing,
+  TradeSequence,
+  HouseNotice,
+  SettlementMarker,
+  AuthorityLog,
 };
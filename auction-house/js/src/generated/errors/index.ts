@@ -962,6 +962,1780 @@ createErrorFromNameLookup.set(
   () => new SaleRequiresExactlyOneSignerError(),
 );
 
+/**
+ * FeeStakeLocked: 'Staked tokens are still within the lockup period.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeeStakeLockedError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'FeeStakeLocked';
+  constructor() {
+    super('Staked tokens are still within the lockup period.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeeStakeLockedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new FeeStakeLockedError());
+createErrorFromNameLookup.set('FeeStakeLocked', () => new FeeStakeLockedError());
+
+/**
+ * InsufficientStake: 'Not enough tokens staked to withdraw the requested amount.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InsufficientStakeError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'InsufficientStake';
+  constructor() {
+    super('Not enough tokens staked to withdraw the requested amount.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InsufficientStakeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new InsufficientStakeError());
+createErrorFromNameLookup.set('InsufficientStake', () => new InsufficientStakeError());
+
+/**
+ * InvalidRelistCooldown: 'The relist cooldown cannot be negative.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRelistCooldownError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'InvalidRelistCooldown';
+  constructor() {
+    super('The relist cooldown cannot be negative.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRelistCooldownError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new InvalidRelistCooldownError());
+createErrorFromNameLookup.set('InvalidRelistCooldown', () => new InvalidRelistCooldownError());
+
+/**
+ * MintInRelistCooldown: 'This mint was settled too recently to be relisted.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintInRelistCooldownError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'MintInRelistCooldown';
+  constructor() {
+    super('This mint was settled too recently to be relisted.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintInRelistCooldownError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new MintInRelistCooldownError());
+createErrorFromNameLookup.set('MintInRelistCooldown', () => new MintInRelistCooldownError());
+
+/**
+ * BuyerProtectionRequiresNativeMint: 'Buyer protection is only available for houses with a native SOL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BuyerProtectionRequiresNativeMintError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'BuyerProtectionRequiresNativeMint';
+  constructor() {
+    super('Buyer protection is only available for houses with a native SOL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BuyerProtectionRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new BuyerProtectionRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'BuyerProtectionRequiresNativeMint',
+  () => new BuyerProtectionRequiresNativeMintError(),
+);
+
+/**
+ * InvalidBuyerProtectionWindow: 'The buyer protection window cannot be negative.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBuyerProtectionWindowError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'InvalidBuyerProtectionWindow';
+  constructor() {
+    super('The buyer protection window cannot be negative.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBuyerProtectionWindowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new InvalidBuyerProtectionWindowError());
+createErrorFromNameLookup.set(
+  'InvalidBuyerProtectionWindow',
+  () => new InvalidBuyerProtectionWindowError(),
+);
+
+/**
+ * BuyerProtectionWindowClosed: 'The buyer protection window for this settlement has closed.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BuyerProtectionWindowClosedError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'BuyerProtectionWindowClosed';
+  constructor() {
+    super('The buyer protection window for this settlement has closed.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BuyerProtectionWindowClosedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new BuyerProtectionWindowClosedError());
+createErrorFromNameLookup.set(
+  'BuyerProtectionWindowClosed',
+  () => new BuyerProtectionWindowClosedError(),
+);
+
+/**
+ * BuyerProtectionWindowOpen: 'The buyer protection window for this settlement is still open.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BuyerProtectionWindowOpenError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'BuyerProtectionWindowOpen';
+  constructor() {
+    super('The buyer protection window for this settlement is still open.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BuyerProtectionWindowOpenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new BuyerProtectionWindowOpenError());
+createErrorFromNameLookup.set(
+  'BuyerProtectionWindowOpen',
+  () => new BuyerProtectionWindowOpenError(),
+);
+
+/**
+ * ProgrammableNftCannotBeProtected: 'Programmable NFTs cannot be sold with buyer protection.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgrammableNftCannotBeProtectedError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'ProgrammableNftCannotBeProtected';
+  constructor() {
+    super('Programmable NFTs cannot be sold with buyer protection.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgrammableNftCannotBeProtectedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new ProgrammableNftCannotBeProtectedError());
+createErrorFromNameLookup.set(
+  'ProgrammableNftCannotBeProtected',
+  () => new ProgrammableNftCannotBeProtectedError(),
+);
+
+/**
+ * NotSettlementParty: 'Only the buyer or seller of a settlement can dispute it.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotSettlementPartyError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'NotSettlementParty';
+  constructor() {
+    super('Only the buyer or seller of a settlement can dispute it.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotSettlementPartyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new NotSettlementPartyError());
+createErrorFromNameLookup.set('NotSettlementParty', () => new NotSettlementPartyError());
+
+/**
+ * SettlementInDispute: 'The settlement is in dispute and can only be resolved by the arbiter.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SettlementInDisputeError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'SettlementInDispute';
+  constructor() {
+    super('The settlement is in dispute and can only be resolved by the arbiter.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SettlementInDisputeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new SettlementInDisputeError());
+createErrorFromNameLookup.set('SettlementInDispute', () => new SettlementInDisputeError());
+
+/**
+ * InvalidArbiter: 'The signer is not the Auction House arbiter.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidArbiterError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'InvalidArbiter';
+  constructor() {
+    super('The signer is not the Auction House arbiter.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidArbiterError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new InvalidArbiterError());
+createErrorFromNameLookup.set('InvalidArbiter', () => new InvalidArbiterError());
+
+/**
+ * SellerFinancingDisabled: 'Seller financing is not enabled on this Auction House.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SellerFinancingDisabledError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'SellerFinancingDisabled';
+  constructor() {
+    super('Seller financing is not enabled on this Auction House.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SellerFinancingDisabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new SellerFinancingDisabledError());
+createErrorFromNameLookup.set('SellerFinancingDisabled', () => new SellerFinancingDisabledError());
+
+/**
+ * SellerFinancingRequiresNativeMint: 'Seller financing is only available for houses with a native SOL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SellerFinancingRequiresNativeMintError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'SellerFinancingRequiresNativeMint';
+  constructor() {
+    super('Seller financing is only available for houses with a native SOL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SellerFinancingRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new SellerFinancingRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'SellerFinancingRequiresNativeMint',
+  () => new SellerFinancingRequiresNativeMintError(),
+);
+
+/**
+ * FinancedSaleNotSigned: 'A financed sale must be signed by both the buyer and the seller.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FinancedSaleNotSignedError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'FinancedSaleNotSigned';
+  constructor() {
+    super('A financed sale must be signed by both the buyer and the seller.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FinancedSaleNotSignedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new FinancedSaleNotSignedError());
+createErrorFromNameLookup.set('FinancedSaleNotSigned', () => new FinancedSaleNotSignedError());
+
+/**
+ * InvalidLienAmount: 'The lien amount must be greater than zero.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidLienAmountError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'InvalidLienAmount';
+  constructor() {
+    super('The lien amount must be greater than zero.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidLienAmountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new InvalidLienAmountError());
+createErrorFromNameLookup.set('InvalidLienAmount', () => new InvalidLienAmountError());
+
+/**
+ * LienOutstanding: 'This mint has an outstanding lien on this Auction House.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LienOutstandingError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'LienOutstanding';
+  constructor() {
+    super('This mint has an outstanding lien on this Auction House.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LienOutstandingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new LienOutstandingError());
+createErrorFromNameLookup.set('LienOutstanding', () => new LienOutstandingError());
+
+/**
+ * FrozenProceedsRequireSplTreasury: 'Frozen proceeds vaults are only used by houses with an SPL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FrozenProceedsRequireSplTreasuryError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'FrozenProceedsRequireSplTreasury';
+  constructor() {
+    super('Frozen proceeds vaults are only used by houses with an SPL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FrozenProceedsRequireSplTreasuryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new FrozenProceedsRequireSplTreasuryError());
+createErrorFromNameLookup.set(
+  'FrozenProceedsRequireSplTreasury',
+  () => new FrozenProceedsRequireSplTreasuryError(),
+);
+
+/**
+ * RecipientAccountFrozen: 'The recipient token account is still frozen.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecipientAccountFrozenError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'RecipientAccountFrozen';
+  constructor() {
+    super('The recipient token account is still frozen.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecipientAccountFrozenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new RecipientAccountFrozenError());
+createErrorFromNameLookup.set('RecipientAccountFrozen', () => new RecipientAccountFrozenError());
+
+/**
+ * ListingFeeAboveHouseFee: 'The listing fee cannot exceed the Auction House seller fee.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingFeeAboveHouseFeeError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'ListingFeeAboveHouseFee';
+  constructor() {
+    super('The listing fee cannot exceed the Auction House seller fee.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingFeeAboveHouseFeeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new ListingFeeAboveHouseFeeError());
+createErrorFromNameLookup.set('ListingFeeAboveHouseFee', () => new ListingFeeAboveHouseFeeError());
+
+/**
+ * InvalidListingPriceBounds: 'The minimum listing price cannot exceed the maximum listing price.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidListingPriceBoundsError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'InvalidListingPriceBounds';
+  constructor() {
+    super('The minimum listing price cannot exceed the maximum listing price.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidListingPriceBoundsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new InvalidListingPriceBoundsError());
+createErrorFromNameLookup.set(
+  'InvalidListingPriceBounds',
+  () => new InvalidListingPriceBoundsError(),
+);
+
+/**
+ * ListingPriceBelowMinimum: 'The listing price is below the Auction House minimum.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingPriceBelowMinimumError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'ListingPriceBelowMinimum';
+  constructor() {
+    super('The listing price is below the Auction House minimum.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingPriceBelowMinimumError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new ListingPriceBelowMinimumError());
+createErrorFromNameLookup.set(
+  'ListingPriceBelowMinimum',
+  () => new ListingPriceBelowMinimumError(),
+);
+
+/**
+ * ListingPriceAboveMaximum: 'The listing price is above the Auction House maximum.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingPriceAboveMaximumError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'ListingPriceAboveMaximum';
+  constructor() {
+    super('The listing price is above the Auction House maximum.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingPriceAboveMaximumError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new ListingPriceAboveMaximumError());
+createErrorFromNameLookup.set(
+  'ListingPriceAboveMaximum',
+  () => new ListingPriceAboveMaximumError(),
+);
+
+/**
+ * TransferBlockedByRuleSet: 'The asset's rule set does not allow sale transfers, so the listing could never settle.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransferBlockedByRuleSetError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = 'TransferBlockedByRuleSet';
+  constructor() {
+    super("The asset's rule set does not allow sale transfers, so the listing could never settle.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransferBlockedByRuleSetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new TransferBlockedByRuleSetError());
+createErrorFromNameLookup.set(
+  'TransferBlockedByRuleSet',
+  () => new TransferBlockedByRuleSetError(),
+);
+
+/**
+ * AuctioneerProgramNotAllowed: 'The auctioneer authority is not the auctioneer PDA of an allowlisted program.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctioneerProgramNotAllowedError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = 'AuctioneerProgramNotAllowed';
+  constructor() {
+    super('The auctioneer authority is not the auctioneer PDA of an allowlisted program.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctioneerProgramNotAllowedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new AuctioneerProgramNotAllowedError());
+createErrorFromNameLookup.set(
+  'AuctioneerProgramNotAllowed',
+  () => new AuctioneerProgramNotAllowedError(),
+);
+
+/**
+ * TooManyAuctioneerPrograms: 'The auctioneer allowlist has too many programs.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyAuctioneerProgramsError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = 'TooManyAuctioneerPrograms';
+  constructor() {
+    super('The auctioneer allowlist has too many programs.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyAuctioneerProgramsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new TooManyAuctioneerProgramsError());
+createErrorFromNameLookup.set(
+  'TooManyAuctioneerPrograms',
+  () => new TooManyAuctioneerProgramsError(),
+);
+
+/**
+ * EscrowNotRentExempt: 'The escrow payment account would be left below the rent exempt minimum.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowNotRentExemptError extends Error {
+  readonly code: number = 0x17b7;
+  readonly name: string = 'EscrowNotRentExempt';
+  constructor() {
+    super('The escrow payment account would be left below the rent exempt minimum.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowNotRentExemptError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new EscrowNotRentExemptError());
+createErrorFromNameLookup.set('EscrowNotRentExempt', () => new EscrowNotRentExemptError());
+
+/**
+ * InvalidWithdrawManyAccounts: 'Expected auction house, escrow payment account and receipt account triples.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidWithdrawManyAccountsError extends Error {
+  readonly code: number = 0x17b8;
+  readonly name: string = 'InvalidWithdrawManyAccounts';
+  constructor() {
+    super('Expected auction house, escrow payment account and receipt account triples.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidWithdrawManyAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new InvalidWithdrawManyAccountsError());
+createErrorFromNameLookup.set(
+  'InvalidWithdrawManyAccounts',
+  () => new InvalidWithdrawManyAccountsError(),
+);
+
+/**
+ * InvalidBidExpiry: 'Bid expiries must be set on an open bid and lie in the future.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidExpiryError extends Error {
+  readonly code: number = 0x17b9;
+  readonly name: string = 'InvalidBidExpiry';
+  constructor() {
+    super('Bid expiries must be set on an open bid and lie in the future.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidExpiryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new InvalidBidExpiryError());
+createErrorFromNameLookup.set('InvalidBidExpiry', () => new InvalidBidExpiryError());
+
+/**
+ * BidNotExpired: 'The bid has not expired yet.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidNotExpiredError extends Error {
+  readonly code: number = 0x17ba;
+  readonly name: string = 'BidNotExpired';
+  constructor() {
+    super('The bid has not expired yet.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidNotExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new BidNotExpiredError());
+createErrorFromNameLookup.set('BidNotExpired', () => new BidNotExpiredError());
+
+/**
+ * InvalidSweepExpiredBidsAccounts: 'Expected bid expiry, trade state, escrow payment account and receipt account groups.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSweepExpiredBidsAccountsError extends Error {
+  readonly code: number = 0x17bb;
+  readonly name: string = 'InvalidSweepExpiredBidsAccounts';
+  constructor() {
+    super('Expected bid expiry, trade state, escrow payment account and receipt account groups.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSweepExpiredBidsAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new InvalidSweepExpiredBidsAccountsError());
+createErrorFromNameLookup.set(
+  'InvalidSweepExpiredBidsAccounts',
+  () => new InvalidSweepExpiredBidsAccountsError(),
+);
+
+/**
+ * InvalidComplianceProgram: 'The compliance program does not match the house's compliance config.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidComplianceProgramError extends Error {
+  readonly code: number = 0x17bc;
+  readonly name: string = 'InvalidComplianceProgram';
+  constructor() {
+    super("The compliance program does not match the house's compliance config.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidComplianceProgramError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bc, () => new InvalidComplianceProgramError());
+createErrorFromNameLookup.set(
+  'InvalidComplianceProgram',
+  () => new InvalidComplianceProgramError(),
+);
+
+/**
+ * ReceiptTreeNotSet: 'The auction house does not log receipts to a receipt tree.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReceiptTreeNotSetError extends Error {
+  readonly code: number = 0x17bd;
+  readonly name: string = 'ReceiptTreeNotSet';
+  constructor() {
+    super('The auction house does not log receipts to a receipt tree.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReceiptTreeNotSetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bd, () => new ReceiptTreeNotSetError());
+createErrorFromNameLookup.set('ReceiptTreeNotSet', () => new ReceiptTreeNotSetError());
+
+/**
+ * InvalidWantedListing: 'The wanted listing prices or end time are invalid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidWantedListingError extends Error {
+  readonly code: number = 0x17be;
+  readonly name: string = 'InvalidWantedListing';
+  constructor() {
+    super('The wanted listing prices or end time are invalid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidWantedListingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17be, () => new InvalidWantedListingError());
+createErrorFromNameLookup.set('InvalidWantedListing', () => new InvalidWantedListingError());
+
+/**
+ * WantedListingExpired: 'The wanted listing has expired.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WantedListingExpiredError extends Error {
+  readonly code: number = 0x17bf;
+  readonly name: string = 'WantedListingExpired';
+  constructor() {
+    super('The wanted listing has expired.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WantedListingExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bf, () => new WantedListingExpiredError());
+createErrorFromNameLookup.set('WantedListingExpired', () => new WantedListingExpiredError());
+
+/**
+ * WantedCriteriaNotMet: 'The token does not meet the wanted listing's criteria.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WantedCriteriaNotMetError extends Error {
+  readonly code: number = 0x17c0;
+  readonly name: string = 'WantedCriteriaNotMet';
+  constructor() {
+    super("The token does not meet the wanted listing's criteria.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WantedCriteriaNotMetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c0, () => new WantedCriteriaNotMetError());
+createErrorFromNameLookup.set('WantedCriteriaNotMet', () => new WantedCriteriaNotMetError());
+
+/**
+ * FreeTradeStateRequired: 'The auction house can change sale prices, so the free trade state is required.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FreeTradeStateRequiredError extends Error {
+  readonly code: number = 0x17c1;
+  readonly name: string = 'FreeTradeStateRequired';
+  constructor() {
+    super('The auction house can change sale prices, so the free trade state is required.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FreeTradeStateRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c1, () => new FreeTradeStateRequiredError());
+createErrorFromNameLookup.set('FreeTradeStateRequired', () => new FreeTradeStateRequiredError());
+
+/**
+ * InvalidCreatorAccount: 'Remaining accounts must start with the metadata creators, in order.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCreatorAccountError extends Error {
+  readonly code: number = 0x17c2;
+  readonly name: string = 'InvalidCreatorAccount';
+  constructor() {
+    super('Remaining accounts must start with the metadata creators, in order.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCreatorAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c2, () => new InvalidCreatorAccountError());
+createErrorFromNameLookup.set('InvalidCreatorAccount', () => new InvalidCreatorAccountError());
+
+/**
+ * FeeSweepRequiresNativeTreasury: 'Fee sweeps are only supported on native SOL auction houses.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeeSweepRequiresNativeTreasuryError extends Error {
+  readonly code: number = 0x17c3;
+  readonly name: string = 'FeeSweepRequiresNativeTreasury';
+  constructor() {
+    super('Fee sweeps are only supported on native SOL auction houses.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeeSweepRequiresNativeTreasuryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c3, () => new FeeSweepRequiresNativeTreasuryError());
+createErrorFromNameLookup.set(
+  'FeeSweepRequiresNativeTreasury',
+  () => new FeeSweepRequiresNativeTreasuryError(),
+);
+
+/**
+ * FeeBelowSweepThreshold: 'The fee account balance does not exceed the sweep threshold.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeeBelowSweepThresholdError extends Error {
+  readonly code: number = 0x17c4;
+  readonly name: string = 'FeeBelowSweepThreshold';
+  constructor() {
+    super('The fee account balance does not exceed the sweep threshold.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeeBelowSweepThresholdError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c4, () => new FeeBelowSweepThresholdError());
+createErrorFromNameLookup.set('FeeBelowSweepThreshold', () => new FeeBelowSweepThresholdError());
+
+/**
+ * InvalidTreasuryWithdrawalPeriod: 'The treasury withdrawal period must be positive and at most a year.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTreasuryWithdrawalPeriodError extends Error {
+  readonly code: number = 0x17c5;
+  readonly name: string = 'InvalidTreasuryWithdrawalPeriod';
+  constructor() {
+    super('The treasury withdrawal period must be positive and at most a year.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTreasuryWithdrawalPeriodError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c5, () => new InvalidTreasuryWithdrawalPeriodError());
+createErrorFromNameLookup.set(
+  'InvalidTreasuryWithdrawalPeriod',
+  () => new InvalidTreasuryWithdrawalPeriodError(),
+);
+
+/**
+ * TreasuryWithdrawalLimitExceeded: 'The withdrawal exceeds the treasury withdrawal limit for the period.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreasuryWithdrawalLimitExceededError extends Error {
+  readonly code: number = 0x17c6;
+  readonly name: string = 'TreasuryWithdrawalLimitExceeded';
+  constructor() {
+    super('The withdrawal exceeds the treasury withdrawal limit for the period.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreasuryWithdrawalLimitExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c6, () => new TreasuryWithdrawalLimitExceededError());
+createErrorFromNameLookup.set(
+  'TreasuryWithdrawalLimitExceeded',
+  () => new TreasuryWithdrawalLimitExceededError(),
+);
+
+/**
+ * InvalidBidTicket: 'The bid ticket token account must hold a ticket owned by the bidder.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidTicketError extends Error {
+  readonly code: number = 0x17c7;
+  readonly name: string = 'InvalidBidTicket';
+  constructor() {
+    super('The bid ticket token account must hold a ticket owned by the bidder.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidTicketError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c7, () => new InvalidBidTicketError());
+createErrorFromNameLookup.set('InvalidBidTicket', () => new InvalidBidTicketError());
+
+/**
+ * BidTicketNotInCollection: 'The bid ticket is not a verified member of the house's ticket collection.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidTicketNotInCollectionError extends Error {
+  readonly code: number = 0x17c8;
+  readonly name: string = 'BidTicketNotInCollection';
+  constructor() {
+    super("The bid ticket is not a verified member of the house's ticket collection.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidTicketNotInCollectionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c8, () => new BidTicketNotInCollectionError());
+createErrorFromNameLookup.set(
+  'BidTicketNotInCollection',
+  () => new BidTicketNotInCollectionError(),
+);
+
+/**
+ * SettlementAlreadySettled: 'The settlement has already been completed.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SettlementAlreadySettledError extends Error {
+  readonly code: number = 0x17c9;
+  readonly name: string = 'SettlementAlreadySettled';
+  constructor() {
+    super('The settlement has already been completed.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SettlementAlreadySettledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c9, () => new SettlementAlreadySettledError());
+createErrorFromNameLookup.set(
+  'SettlementAlreadySettled',
+  () => new SettlementAlreadySettledError(),
+);
+
+/**
+ * SettlementPartiallySettled: 'The settlement is escrowed and must be finalized or canceled before it is retried.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SettlementPartiallySettledError extends Error {
+  readonly code: number = 0x17ca;
+  readonly name: string = 'SettlementPartiallySettled';
+  constructor() {
+    super('The settlement is escrowed and must be finalized or canceled before it is retried.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SettlementPartiallySettledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ca, () => new SettlementPartiallySettledError());
+createErrorFromNameLookup.set(
+  'SettlementPartiallySettled',
+  () => new SettlementPartiallySettledError(),
+);
+
+/**
+ * SettlementNotStarted: 'The settlement has not been started.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SettlementNotStartedError extends Error {
+  readonly code: number = 0x17cb;
+  readonly name: string = 'SettlementNotStarted';
+  constructor() {
+    super('The settlement has not been started.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SettlementNotStartedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cb, () => new SettlementNotStartedError());
+createErrorFromNameLookup.set('SettlementNotStarted', () => new SettlementNotStartedError());
+
+/**
+ * BuyerReceiptNotAssociatedTokenAccount: 'Buyer receipt account must be the buyer's associated token account.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BuyerReceiptNotAssociatedTokenAccountError extends Error {
+  readonly code: number = 0x17cc;
+  readonly name: string = 'BuyerReceiptNotAssociatedTokenAccount';
+  constructor() {
+    super("Buyer receipt account must be the buyer's associated token account.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BuyerReceiptNotAssociatedTokenAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cc, () => new BuyerReceiptNotAssociatedTokenAccountError());
+createErrorFromNameLookup.set(
+  'BuyerReceiptNotAssociatedTokenAccount',
+  () => new BuyerReceiptNotAssociatedTokenAccountError(),
+);
+
+/**
+ * AuctionHouseImmutable: 'The Auction House is immutable and cannot be updated.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionHouseImmutableError extends Error {
+  readonly code: number = 0x17cd;
+  readonly name: string = 'AuctionHouseImmutable';
+  constructor() {
+    super('The Auction House is immutable and cannot be updated.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionHouseImmutableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cd, () => new AuctionHouseImmutableError());
+createErrorFromNameLookup.set('AuctionHouseImmutable', () => new AuctionHouseImmutableError());
+
+/**
+ * NotCollectionUpdateAuthority: 'Signer is not the collection's update authority.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotCollectionUpdateAuthorityError extends Error {
+  readonly code: number = 0x17ce;
+  readonly name: string = 'NotCollectionUpdateAuthority';
+  constructor() {
+    super("Signer is not the collection's update authority.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotCollectionUpdateAuthorityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ce, () => new NotCollectionUpdateAuthorityError());
+createErrorFromNameLookup.set(
+  'NotCollectionUpdateAuthority',
+  () => new NotCollectionUpdateAuthorityError(),
+);
+
+/**
+ * TooManyApprovedAuctionHouses: 'Too many approved Auction Houses for a marketplace binding.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyApprovedAuctionHousesError extends Error {
+  readonly code: number = 0x17cf;
+  readonly name: string = 'TooManyApprovedAuctionHouses';
+  constructor() {
+    super('Too many approved Auction Houses for a marketplace binding.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyApprovedAuctionHousesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17cf, () => new TooManyApprovedAuctionHousesError());
+createErrorFromNameLookup.set(
+  'TooManyApprovedAuctionHouses',
+  () => new TooManyApprovedAuctionHousesError(),
+);
+
+/**
+ * AuctionHouseNotApprovedForCollection: 'The collection's marketplace binding does not approve this Auction House.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionHouseNotApprovedForCollectionError extends Error {
+  readonly code: number = 0x17d0;
+  readonly name: string = 'AuctionHouseNotApprovedForCollection';
+  constructor() {
+    super("The collection's marketplace binding does not approve this Auction House.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionHouseNotApprovedForCollectionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d0, () => new AuctionHouseNotApprovedForCollectionError());
+createErrorFromNameLookup.set(
+  'AuctionHouseNotApprovedForCollection',
+  () => new AuctionHouseNotApprovedForCollectionError(),
+);
+
+/**
+ * BidWithTransferRequiresNativeMint: 'Bidding with a transfer is only available for houses with a native SOL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidWithTransferRequiresNativeMintError extends Error {
+  readonly code: number = 0x17d1;
+  readonly name: string = 'BidWithTransferRequiresNativeMint';
+  constructor() {
+    super('Bidding with a transfer is only available for houses with a native SOL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidWithTransferRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d1, () => new BidWithTransferRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'BidWithTransferRequiresNativeMint',
+  () => new BidWithTransferRequiresNativeMintError(),
+);
+
+/**
+ * VoucherRequiresNativeMint: 'Vouchers are only available for houses with a native SOL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherRequiresNativeMintError extends Error {
+  readonly code: number = 0x17d2;
+  readonly name: string = 'VoucherRequiresNativeMint';
+  constructor() {
+    super('Vouchers are only available for houses with a native SOL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d2, () => new VoucherRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'VoucherRequiresNativeMint',
+  () => new VoucherRequiresNativeMintError(),
+);
+
+/**
+ * InvalidVoucher: 'The voucher price or redeem deadline is invalid.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidVoucherError extends Error {
+  readonly code: number = 0x17d3;
+  readonly name: string = 'InvalidVoucher';
+  constructor() {
+    super('The voucher price or redeem deadline is invalid.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidVoucherError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d3, () => new InvalidVoucherError());
+createErrorFromNameLookup.set('InvalidVoucher', () => new InvalidVoucherError());
+
+/**
+ * VoucherAlreadySold: 'The voucher has already been sold.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherAlreadySoldError extends Error {
+  readonly code: number = 0x17d4;
+  readonly name: string = 'VoucherAlreadySold';
+  constructor() {
+    super('The voucher has already been sold.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherAlreadySoldError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d4, () => new VoucherAlreadySoldError());
+createErrorFromNameLookup.set('VoucherAlreadySold', () => new VoucherAlreadySoldError());
+
+/**
+ * VoucherNotSold: 'The voucher has not been sold.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherNotSoldError extends Error {
+  readonly code: number = 0x17d5;
+  readonly name: string = 'VoucherNotSold';
+  constructor() {
+    super('The voucher has not been sold.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherNotSoldError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d5, () => new VoucherNotSoldError());
+createErrorFromNameLookup.set('VoucherNotSold', () => new VoucherNotSoldError());
+
+/**
+ * VoucherRedeemDeadlinePassed: 'The voucher's redeem deadline has passed.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherRedeemDeadlinePassedError extends Error {
+  readonly code: number = 0x17d6;
+  readonly name: string = 'VoucherRedeemDeadlinePassed';
+  constructor() {
+    super("The voucher's redeem deadline has passed.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherRedeemDeadlinePassedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d6, () => new VoucherRedeemDeadlinePassedError());
+createErrorFromNameLookup.set(
+  'VoucherRedeemDeadlinePassed',
+  () => new VoucherRedeemDeadlinePassedError(),
+);
+
+/**
+ * VoucherRedeemDeadlineNotPassed: 'The voucher's redeem deadline has not passed yet.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherRedeemDeadlineNotPassedError extends Error {
+  readonly code: number = 0x17d7;
+  readonly name: string = 'VoucherRedeemDeadlineNotPassed';
+  constructor() {
+    super("The voucher's redeem deadline has not passed yet.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherRedeemDeadlineNotPassedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d7, () => new VoucherRedeemDeadlineNotPassedError());
+createErrorFromNameLookup.set(
+  'VoucherRedeemDeadlineNotPassed',
+  () => new VoucherRedeemDeadlineNotPassedError(),
+);
+
+/**
+ * VoucherNotDelivered: 'The buyer does not hold a token verified as minted by the voucher's creator.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherNotDeliveredError extends Error {
+  readonly code: number = 0x17d8;
+  readonly name: string = 'VoucherNotDelivered';
+  constructor() {
+    super("The buyer does not hold a token verified as minted by the voucher's creator.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherNotDeliveredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d8, () => new VoucherNotDeliveredError());
+createErrorFromNameLookup.set('VoucherNotDelivered', () => new VoucherNotDeliveredError());
+
+/**
+ * InvalidFinalizeProtectedSettlementsAccounts: 'Expected protected settlement, settlement marker, escrow token account, buyer receipt account, seller and rent payer groups.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFinalizeProtectedSettlementsAccountsError extends Error {
+  readonly code: number = 0x17d9;
+  readonly name: string = 'InvalidFinalizeProtectedSettlementsAccounts';
+  constructor() {
+    super('Expected protected settlement, settlement marker, escrow token account, buyer receipt account, seller and rent payer groups.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFinalizeProtectedSettlementsAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17d9, () => new InvalidFinalizeProtectedSettlementsAccountsError());
+createErrorFromNameLookup.set(
+  'InvalidFinalizeProtectedSettlementsAccounts',
+  () => new InvalidFinalizeProtectedSettlementsAccountsError(),
+);
+
+/**
+ * NothingToCrank: 'Every entry of the crank batch was already processed or is not due yet.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NothingToCrankError extends Error {
+  readonly code: number = 0x17da;
+  readonly name: string = 'NothingToCrank';
+  constructor() {
+    super('Every entry of the crank batch was already processed or is not due yet.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NothingToCrankError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17da, () => new NothingToCrankError());
+createErrorFromNameLookup.set('NothingToCrank', () => new NothingToCrankError());
+
+/**
+ * InvalidAuctioneerGracePeriod: 'The auctioneer grace period must not be negative.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAuctioneerGracePeriodError extends Error {
+  readonly code: number = 0x17db;
+  readonly name: string = 'InvalidAuctioneerGracePeriod';
+  constructor() {
+    super('The auctioneer grace period must not be negative.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAuctioneerGracePeriodError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17db, () => new InvalidAuctioneerGracePeriodError());
+createErrorFromNameLookup.set(
+  'InvalidAuctioneerGracePeriod',
+  () => new InvalidAuctioneerGracePeriodError(),
+);
+
+/**
+ * AuctioneerBidNotTransferable: 'Bids on an Auction House with a delegated auctioneer cannot be transferred.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctioneerBidNotTransferableError extends Error {
+  readonly code: number = 0x17dc;
+  readonly name: string = 'AuctioneerBidNotTransferable';
+  constructor() {
+    super('Bids on an Auction House with a delegated auctioneer cannot be transferred.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctioneerBidNotTransferableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17dc, () => new AuctioneerBidNotTransferableError());
+createErrorFromNameLookup.set(
+  'AuctioneerBidNotTransferable',
+  () => new AuctioneerBidNotTransferableError(),
+);
+
+/**
+ * InvalidLeaderboardWindow: 'The leaderboard window must end after it starts.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidLeaderboardWindowError extends Error {
+  readonly code: number = 0x17dd;
+  readonly name: string = 'InvalidLeaderboardWindow';
+  constructor() {
+    super('The leaderboard window must end after it starts.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidLeaderboardWindowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17dd, () => new InvalidLeaderboardWindowError());
+createErrorFromNameLookup.set(
+  'InvalidLeaderboardWindow',
+  () => new InvalidLeaderboardWindowError(),
+);
+
+/**
+ * RoyaltyBonusTooHigh: 'The royalty bonus and the token's royalties cannot exceed 10000 basis points.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RoyaltyBonusTooHighError extends Error {
+  readonly code: number = 0x17de;
+  readonly name: string = 'RoyaltyBonusTooHigh';
+  constructor() {
+    super("The royalty bonus and the token's royalties cannot exceed 10000 basis points.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RoyaltyBonusTooHighError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17de, () => new RoyaltyBonusTooHighError());
+createErrorFromNameLookup.set('RoyaltyBonusTooHigh', () => new RoyaltyBonusTooHighError());
+
+/**
+ * InvalidSharedCustody: 'Co-owner shares must add up to 10000 basis points, with a threshold of at least one and at most the number of co-owners.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSharedCustodyError extends Error {
+  readonly code: number = 0x17df;
+  readonly name: string = 'InvalidSharedCustody';
+  constructor() {
+    super('Co-owner shares must add up to 10000 basis points, with a threshold of at least one and at most the number of co-owners.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSharedCustodyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17df, () => new InvalidSharedCustodyError());
+createErrorFromNameLookup.set('InvalidSharedCustody', () => new InvalidSharedCustodyError());
+
+/**
+ * NotCoOwner: 'The signer is not a co-owner of the shared custody.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotCoOwnerError extends Error {
+  readonly code: number = 0x17e0;
+  readonly name: string = 'NotCoOwner';
+  constructor() {
+    super('The signer is not a co-owner of the shared custody.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotCoOwnerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e0, () => new NotCoOwnerError());
+createErrorFromNameLookup.set('NotCoOwner', () => new NotCoOwnerError());
+
+/**
+ * SharedCustodyAlreadyListed: 'The shared custody token is already listed.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SharedCustodyAlreadyListedError extends Error {
+  readonly code: number = 0x17e1;
+  readonly name: string = 'SharedCustodyAlreadyListed';
+  constructor() {
+    super('The shared custody token is already listed.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SharedCustodyAlreadyListedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e1, () => new SharedCustodyAlreadyListedError());
+createErrorFromNameLookup.set(
+  'SharedCustodyAlreadyListed',
+  () => new SharedCustodyAlreadyListedError(),
+);
+
+/**
+ * SharedCustodyNotSold: 'The shared custody token has not been sold.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SharedCustodyNotSoldError extends Error {
+  readonly code: number = 0x17e2;
+  readonly name: string = 'SharedCustodyNotSold';
+  constructor() {
+    super('The shared custody token has not been sold.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SharedCustodyNotSoldError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e2, () => new SharedCustodyNotSoldError());
+createErrorFromNameLookup.set('SharedCustodyNotSold', () => new SharedCustodyNotSoldError());
+
+/**
+ * SharedCustodyRequiresNativeMint: 'Shared custody is only available for houses with a native SOL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SharedCustodyRequiresNativeMintError extends Error {
+  readonly code: number = 0x17e3;
+  readonly name: string = 'SharedCustodyRequiresNativeMint';
+  constructor() {
+    super('Shared custody is only available for houses with a native SOL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SharedCustodyRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e3, () => new SharedCustodyRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'SharedCustodyRequiresNativeMint',
+  () => new SharedCustodyRequiresNativeMintError(),
+);
+
+/**
+ * InvalidPayoutDelay: 'The payout delay cannot be negative.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPayoutDelayError extends Error {
+  readonly code: number = 0x17e4;
+  readonly name: string = 'InvalidPayoutDelay';
+  constructor() {
+    super('The payout delay cannot be negative.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPayoutDelayError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e4, () => new InvalidPayoutDelayError());
+createErrorFromNameLookup.set('InvalidPayoutDelay', () => new InvalidPayoutDelayError());
+
+/**
+ * PayoutDelayRequiresNativeMint: 'Payout delays are only available for houses with a native SOL treasury mint.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PayoutDelayRequiresNativeMintError extends Error {
+  readonly code: number = 0x17e5;
+  readonly name: string = 'PayoutDelayRequiresNativeMint';
+  constructor() {
+    super('Payout delays are only available for houses with a native SOL treasury mint.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PayoutDelayRequiresNativeMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e5, () => new PayoutDelayRequiresNativeMintError());
+createErrorFromNameLookup.set(
+  'PayoutDelayRequiresNativeMint',
+  () => new PayoutDelayRequiresNativeMintError(),
+);
+
+/**
+ * PayoutDelayWindowOpen: 'The payout delay has not passed yet.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PayoutDelayWindowOpenError extends Error {
+  readonly code: number = 0x17e6;
+  readonly name: string = 'PayoutDelayWindowOpen';
+  constructor() {
+    super('The payout delay has not passed yet.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PayoutDelayWindowOpenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e6, () => new PayoutDelayWindowOpenError());
+createErrorFromNameLookup.set('PayoutDelayWindowOpen', () => new PayoutDelayWindowOpenError());
+
+/**
+ * PayoutDelayWindowClosed: 'The payout delay has passed, so the proceeds can no longer be clawed back.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PayoutDelayWindowClosedError extends Error {
+  readonly code: number = 0x17e7;
+  readonly name: string = 'PayoutDelayWindowClosed';
+  constructor() {
+    super('The payout delay has passed, so the proceeds can no longer be clawed back.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PayoutDelayWindowClosedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e7, () => new PayoutDelayWindowClosedError());
+createErrorFromNameLookup.set('PayoutDelayWindowClosed', () => new PayoutDelayWindowClosedError());
+
+/**
+ * InvalidTokenStandardPolicy: 'The token standard policy has unknown bits set.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTokenStandardPolicyError extends Error {
+  readonly code: number = 0x17e8;
+  readonly name: string = 'InvalidTokenStandardPolicy';
+  constructor() {
+    super('The token standard policy has unknown bits set.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTokenStandardPolicyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e8, () => new InvalidTokenStandardPolicyError());
+createErrorFromNameLookup.set(
+  'InvalidTokenStandardPolicy',
+  () => new InvalidTokenStandardPolicyError(),
+);
+
+/**
+ * TokenStandardNotAllowed: 'This Auction House does not list tokens of this token standard.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenStandardNotAllowedError extends Error {
+  readonly code: number = 0x17e9;
+  readonly name: string = 'TokenStandardNotAllowed';
+  constructor() {
+    super('This Auction House does not list tokens of this token standard.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenStandardNotAllowedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17e9, () => new TokenStandardNotAllowedError());
+createErrorFromNameLookup.set('TokenStandardNotAllowed', () => new TokenStandardNotAllowedError());
+
+/**
+ * UnverifiedCreators: 'This Auction House only lists tokens whose creators and collection are verified.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnverifiedCreatorsError extends Error {
+  readonly code: number = 0x17ea;
+  readonly name: string = 'UnverifiedCreators';
+  constructor() {
+    super('This Auction House only lists tokens whose creators and collection are verified.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnverifiedCreatorsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ea, () => new UnverifiedCreatorsError());
+createErrorFromNameLookup.set('UnverifiedCreators', () => new UnverifiedCreatorsError());
+
+/**
+ * FrontendMustSign: 'The frontend must sign bids charged to its spending cap.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FrontendMustSignError extends Error {
+  readonly code: number = 0x17eb;
+  readonly name: string = 'FrontendMustSign';
+  constructor() {
+    super('The frontend must sign bids charged to its spending cap.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FrontendMustSignError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17eb, () => new FrontendMustSignError());
+createErrorFromNameLookup.set('FrontendMustSign', () => new FrontendMustSignError());
+
+/**
+ * SpendingCapExceeded: 'The bid exceeds the wallet's spending cap for this frontend.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SpendingCapExceededError extends Error {
+  readonly code: number = 0x17ec;
+  readonly name: string = 'SpendingCapExceeded';
+  constructor() {
+    super("The bid exceeds the wallet's spending cap for this frontend.");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SpendingCapExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ec, () => new SpendingCapExceededError());
+createErrorFromNameLookup.set('SpendingCapExceeded', () => new SpendingCapExceededError());
+
+/**
+ * ListingAlreadyActive: 'The seller already has an active listing of this mint on this Auction House.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingAlreadyActiveError extends Error {
+  readonly code: number = 0x17ed;
+  readonly name: string = 'ListingAlreadyActive';
+  constructor() {
+    super('The seller already has an active listing of this mint on this Auction House.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingAlreadyActiveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ed, () => new ListingAlreadyActiveError());
+createErrorFromNameLookup.set('ListingAlreadyActive', () => new ListingAlreadyActiveError());
+
+/**
+ * ListingCurrencyMismatch: 'The listing was placed in a different treasury currency than the Auction House settles in.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingCurrencyMismatchError extends Error {
+  readonly code: number = 0x17ee;
+  readonly name: string = 'ListingCurrencyMismatch';
+  constructor() {
+    super('The listing was placed in a different treasury currency than the Auction House settles in.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingCurrencyMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ee, () => new ListingCurrencyMismatchError());
+createErrorFromNameLookup.set('ListingCurrencyMismatch', () => new ListingCurrencyMismatchError());
+
+/**
+ * NoticeExpiryInPast: 'The notice expiry has already passed.'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoticeExpiryInPastError extends Error {
+  readonly code: number = 0x17ef;
+  readonly name: string = 'NoticeExpiryInPast';
+  constructor() {
+    super('The notice expiry has already passed.');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoticeExpiryInPastError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ef, () => new NoticeExpiryInPastError());
+createErrorFromNameLookup.set('NoticeExpiryInPast', () => new NoticeExpiryInPastError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -21,6 +21,7 @@ export type CreateAuctionHouseInstructionArgs = {
   sellerFeeBasisPoints: number;
   requiresSignOff: boolean;
   canChangeSalePrice: boolean;
+  immutable: boolean;
 };
 /**
  * @category Instructions
@@ -40,6 +41,7 @@ export const createAuctionHouseStruct = new beet.BeetArgsStruct<
     ['sellerFeeBasisPoints', beet.u16],
     ['requiresSignOff', beet.bool],
     ['canChangeSalePrice', beet.bool],
+    ['immutable', beet.bool],
   ],
   'CreateAuctionHouseInstructionArgs',
 );
@@ -1,3 +1,6 @@
+export * from './approveDepositAuthority';
+export * from './assertSettlementStatus';
+export * from './attributeTrade';
 export * from './auctioneerBuy';
 export * from './auctioneerCancel';
 export * from './auctioneerDeposit';
@@ -5,27 +8,115 @@ export * from './auctioneerExecutePartialSale';
 export * from './auctioneerExecuteSale';
 export * from './auctioneerPublicBuy';
 export * from './auctioneerSell';
+export * from './auctioneerSetListingFee';
 export * from './auctioneerWithdraw';
+export * from './auctioneerWithdrawFromTreasury';
+export * from './bidWithTransfer';
 export * from './buy';
+export * from './buyVoucher';
+export * from './buyWithReceipt';
 export * from './cancel';
 export * from './cancelBidReceipt';
 export * from './cancelListingReceipt';
+export * from './cancelProtectedSettlement';
 export * from './cancelRemainingAccounts';
+export * from './cancelVoucher';
+export * from './cancelWantedListing';
+export * from './claimFrozenProceeds';
+export * from './claimProceeds';
+export * from './clawbackProceeds';
 export * from './closeEscrowAccount';
+export * from './closeRoyaltyBonus';
+export * from './closeSpendingCap';
+export * from './closeTradeAttribution';
+export * from './coList';
 export * from './createAuctionHouse';
+export * from './createAuctioneerAllowlist';
+export * from './createAuctioneerStats';
+export * from './createAuthorityLog';
+export * from './createBidTicketConfig';
+export * from './createComplianceConfig';
+export * from './createFeeStakeConfig';
+export * from './createFeeSweepConfig';
+export * from './createFrozenProceedsVault';
+export * from './createHouseNotice';
+export * from './createLeaderboard';
+export * from './createMarketplaceBinding';
+export * from './createPriceHistory';
+export * from './createPublicBidIndex';
+export * from './createReceiptTree';
+export * from './createRoyaltyBonus';
+export * from './createSharedCustody';
+export * from './createSpendingCap';
+export * from './createTaxWithholding';
+export * from './createTradeSequence';
+export * from './createTreasuryWithdrawalLimit';
+export * from './createVoucher';
+export * from './createWantedListing';
 export * from './delegateAuctioneer';
 export * from './deposit';
+export * from './depositOnBehalf';
+export * from './deregisterOmnibusCustodian';
+export * from './executeFinancedSale';
 export * from './executePartialSale';
 export * from './executeSale';
 export * from './executeSaleRemainingAccounts';
+export * from './fillWanted';
+export * from './finalizeProtectedSettlement';
+export * from './finalizeProtectedSettlements';
+export * from './fundFeeAccount';
+export * from './getHouseBalances';
+export * from './logReceipt';
+export * from './openDispute';
+export * from './prepareBuyer';
 export * from './printBidReceipt';
 export * from './printListingReceipt';
 export * from './printPurchaseReceipt';
+export * from './prunePublicBidIndex';
 export * from './publicBuy';
+export * from './reclaimVoucher';
+export * from './redeemVoucher';
+export * from './registerOmnibusCustodian';
+export * from './repayLien';
+export * from './resolveDispute';
+export * from './revokeDepositAuthority';
+export * from './rotateAuctioneer';
 export * from './sell';
 export * from './sellRemainingAccounts';
+export * from './sellWithReceipt';
+export * from './setArbiter';
+export * from './setBidExpiry';
+export * from './setBuyerProtection';
+export * from './setHouseNotice';
+export * from './setListingCurrencyLock';
+export * from './setListingPriceBounds';
+export * from './setLowFeeBalanceThreshold';
+export * from './setPayoutDelay';
+export * from './setRelistCooldown';
+export * from './setRequiresVerifiedCreators';
+export * from './setRoundingPolicy';
+export * from './setSellerFinancing';
+export * from './setSingleActiveListing';
+export * from './setTokenStandardPolicy';
+export * from './setWalletBoundReceipts';
+export * from './settleSharedCustody';
+export * from './stakeForFees';
+export * from './sweepExpiredBids';
+export * from './sweepFeeToTreasury';
+export * from './transferBid';
+export * from './unstake';
 export * from './updateAuctionHouse';
 export * from './updateAuctioneer';
+export * from './updateAuctioneerAllowlist';
+export * from './updateBidTicketConfig';
+export * from './updateComplianceConfig';
+export * from './updateFeeStakeConfig';
+export * from './updateFeeSweepConfig';
+export * from './updateMarketplaceBinding';
+export * from './updateSpendingCap';
+export * from './updateTaxWithholding';
+export * from './updateTreasuryWithdrawalLimit';
 export * from './withdraw';
 export * from './withdrawFromFee';
 export * from './withdrawFromTreasury';
+export * from './withdrawMany';
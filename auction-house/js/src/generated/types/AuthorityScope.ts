@@ -18,6 +18,9 @@ export enum AuthorityScope {
   Sell,
   Cancel,
   Withdraw,
+  SetFee,
+  PauseListing,
+  WithdrawTreasury,
 }
 
 /**
@@ -1,5 +1,23 @@
+export * from './AuthorityAction';
+export * from './AuthorityLogEntry';
 export * from './AuthorityScope';
 export * from './BidType';
 export * from './CancelType';
+export * from './CoOwner';
+export * from './ComplianceAction';
+export * from './ComplianceCheckArgs';
+export * from './CompressedReceipt';
+export * from './DisputeResolution';
+export * from './HouseBalances';
+export * from './IndexedPublicBid';
+export * from './LeaderboardEntry';
 export * from './ListingType';
+export * from './NoticeStatus';
+export * from './PricePoint';
 export * from './PurchaseType';
+export * from './ReceiptLeafSchema';
+export * from './Rounding';
+export * from './RoundingPolicy';
+export * from './RoyaltyPayment';
+export * from './SettlementStatus';
+export * from './WantedCriteria';
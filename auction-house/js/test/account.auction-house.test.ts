@@ -1,5 +1,6 @@
 import { AccountInfo, Keypair, PublicKey } from '@solana/web3.js';
-import { AuctionHouse, AuctionHouseArgs } from '../src/generated';
+import { AuctionHouse, AuctionHouseArgs, RoundingPolicy } from '../src/generated';
+import BN from 'bn.js';
 import test from 'tape';
 import spok from 'spok';
 
@@ -32,7 +33,32 @@ test('account auction-house: round trip serilization', async (t) => {
     escrowPaymentBump: 255,
     hasAuctioneer: false,
     auctioneerAddress: PublicKey.default,
-    scopes: [true, false, true, false, true, true, false],
+    scopes: [true, false, true, false, true, true, false, false, true, false],
+    relistCooldownSeconds: new BN(60),
+    hasAuthorityLog: true,
+    buyerProtectionSeconds: new BN(0),
+    restockingFeeBasisPoints: 250,
+    arbiter: PublicKey.default,
+    allowSellerFinancing: false,
+    minListingPrice: new BN(1000),
+    maxListingPrice: null,
+    hasAuctioneerAllowlist: false,
+    hasComplianceHook: false,
+    hasReceiptTree: false,
+    hasTaxWithholding: false,
+    lowFeeBalanceThreshold: new BN(0),
+    hasBidTicketGate: false,
+    allowWalletBoundReceipts: true,
+    isImmutable: false,
+    previousAuctioneerAddress: PublicKey.default,
+    previousAuctioneerExpiresAt: new BN(0),
+    roundingPolicy: RoundingPolicy.HalfEven,
+    payoutDelaySeconds: new BN(0),
+    tokenStandardPolicy: 0,
+    requiresVerifiedCreators: false,
+    singleActiveListing: true,
+    hasTradeSequence: false,
+    locksListingCurrency: false,
   };
 
   const expected = AuctionHouse.fromArgs(args);
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityScope};
+use crate::{
+    auctioneer_allowlist::approved_auctioneer_program, authority_log::record_authority_action,
+    constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityAction,
+    AuthorityScope,
+};
 
 /// Accounts for the [`delegate_auctioneer` handler](auction_house/fn.delegate_auctioneer.html).
 #[derive(Accounts)]
@@ -73,5 +77,20 @@ pub fn delegate_auctioneer<'info>(
         .get("ah_auctioneer_pda")
         .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
 
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    auctioneer.auctioneer_program = approved_auctioneer_program(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        remaining_accounts,
+    )?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::DelegateAuctioneer,
+        0,
+        remaining_accounts,
+    )?;
+
     Ok(())
 }
@@ -1,4 +1,6 @@
 pub mod delegate;
+pub mod rotate;
 pub mod update;
 pub use delegate::*;
+pub use rotate::*;
 pub use update::*;
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    auctioneer_allowlist::approved_auctioneer_program, authority_log::record_authority_action,
+    constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityAction,
+};
+
+/// Accounts for the [`rotate_auctioneer` handler](auction_house/fn.rotate_auctioneer.html).
+#[derive(Accounts)]
+pub struct RotateAuctioneer<'info> {
+    // Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Account<'info, AuctionHouse>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The auctioneer PDA currently delegated on the Auction House.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            ah_auctioneer_pda.auctioneer_authority.as_ref()
+        ],
+        bump=ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Account<'info, Auctioneer>,
+
+    /// CHECK: The auction house authority can set this to whatever external address they wish.
+    /// The auctioneer authority replacing the current one, typically the PDA of an upgraded
+    /// Auctioneer program.
+    pub new_auctioneer_authority: UncheckedAccount<'info>,
+
+    /// The auctioneer PDA for the new auctioneer authority.
+    #[account(
+        init,
+        payer = authority,
+        space = AUCTIONEER_SIZE,
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            new_auctioneer_authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_ah_auctioneer_pda: Account<'info, Auctioneer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Delegate the Auction House to a new auctioneer authority with the same scopes. The replaced
+/// auctioneer stays valid for `grace_period_seconds`, so listings it placed can still be settled
+/// or canceled.
+pub fn rotate_auctioneer<'info>(
+    ctx: Context<'_, '_, '_, 'info, RotateAuctioneer<'info>>,
+    grace_period_seconds: i64,
+) -> Result<()> {
+    if grace_period_seconds < 0 {
+        return Err(AuctionHouseError::InvalidAuctioneerGracePeriod.into());
+    }
+
+    let auction_house = &mut ctx.accounts.auction_house;
+    if !auction_house.has_auctioneer {
+        return Err(AuctionHouseError::AuctionHouseNotDelegated.into());
+    }
+    if auction_house.auctioneer_address != ctx.accounts.ah_auctioneer_pda.key() {
+        return Err(AuctionHouseError::InvalidAuctioneer.into());
+    }
+
+    auction_house.previous_auctioneer_address = auction_house.auctioneer_address;
+    auction_house.previous_auctioneer_expires_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(grace_period_seconds)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    auction_house.auctioneer_address = ctx.accounts.new_ah_auctioneer_pda.key();
+
+    let auctioneer = &mut ctx.accounts.new_ah_auctioneer_pda;
+    auctioneer.auctioneer_authority = ctx.accounts.new_auctioneer_authority.key();
+    auctioneer.auction_house = ctx.accounts.auction_house.key();
+    auctioneer.bump = *ctx
+        .bumps
+        .get("new_ah_auctioneer_pda")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    auctioneer.auctioneer_program = approved_auctioneer_program(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.new_auctioneer_authority.key(),
+        remaining_accounts,
+    )?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::RotateAuctioneer,
+        grace_period_seconds as u64,
+        remaining_accounts,
+    )?;
+
+    Ok(())
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityScope};
+use crate::{
+    auctioneer_allowlist::approved_auctioneer_program, authority_log::record_authority_action,
+    constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityAction,
+    AuthorityScope,
+};
 
 #[derive(Accounts)]
 pub struct UpdateAuctioneer<'info> {
@@ -63,5 +67,20 @@ pub fn update_auctioneer<'info>(
     auctioneer.auctioneer_authority = ctx.accounts.auctioneer_authority.key();
     auctioneer.auction_house = ctx.accounts.auction_house.key();
 
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    auctioneer.auctioneer_program = approved_auctioneer_program(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        remaining_accounts,
+    )?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::UpdateAuctioneer,
+        0,
+        remaining_accounts,
+    )?;
+
     Ok(())
 }
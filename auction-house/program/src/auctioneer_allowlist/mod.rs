@@ -0,0 +1,203 @@
+//! Per-house allowlist of the auctioneer programs the house authority may delegate to.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, Auctioneer, AuctioneerAllowlist, AuthorityAction,
+};
+
+/// Accounts for the [`create_auctioneer_allowlist` handler](auction_house/fn.create_auctioneer_allowlist.html).
+#[derive(Accounts)]
+pub struct CreateAuctioneerAllowlist<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer allowlist PDA listing the approved auctioneer programs.
+    #[account(
+        init,
+        payer=authority,
+        space=AUCTIONEER_ALLOWLIST_SIZE,
+        seeds=[AUCTIONEER_ALLOWLIST.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub auctioneer_allowlist: Box<Account<'info, AuctioneerAllowlist>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_auctioneer_allowlist` handler](auction_house/fn.update_auctioneer_allowlist.html).
+#[derive(Accounts)]
+pub struct UpdateAuctioneerAllowlist<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer allowlist PDA listing the approved auctioneer programs.
+    #[account(
+        mut,
+        seeds=[AUCTIONEER_ALLOWLIST.as_bytes(), auction_house.key().as_ref()],
+        bump=auctioneer_allowlist.bump,
+        has_one=auction_house
+    )]
+    pub auctioneer_allowlist: Box<Account<'info, AuctioneerAllowlist>>,
+}
+
+pub fn create_auctioneer_allowlist<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAuctioneerAllowlist<'info>>,
+    programs: Vec<Pubkey>,
+) -> Result<()> {
+    if programs.len() > MAX_AUCTIONEER_PROGRAMS {
+        return Err(AuctionHouseError::TooManyAuctioneerPrograms.into());
+    }
+
+    let auctioneer_allowlist = &mut ctx.accounts.auctioneer_allowlist;
+    auctioneer_allowlist.auction_house = ctx.accounts.auction_house.key();
+    auctioneer_allowlist.bump = *ctx
+        .bumps
+        .get("auctioneer_allowlist")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    auctioneer_allowlist.programs = programs;
+
+    ctx.accounts.auction_house.has_auctioneer_allowlist = true;
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    approve_delegated_auctioneer(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.auctioneer_allowlist,
+        remaining_accounts,
+    )?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::CreateAuctioneerAllowlist,
+        ctx.accounts.auctioneer_allowlist.programs.len() as u64,
+        remaining_accounts,
+    )?;
+
+    Ok(())
+}
+
+pub fn update_auctioneer_allowlist<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateAuctioneerAllowlist<'info>>,
+    programs: Vec<Pubkey>,
+) -> Result<()> {
+    if programs.len() > MAX_AUCTIONEER_PROGRAMS {
+        return Err(AuctionHouseError::TooManyAuctioneerPrograms.into());
+    }
+
+    ctx.accounts.auctioneer_allowlist.programs = programs;
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    approve_delegated_auctioneer(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.auctioneer_allowlist,
+        remaining_accounts,
+    )?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::UpdateAuctioneerAllowlist,
+        ctx.accounts.auctioneer_allowlist.programs.len() as u64,
+        remaining_accounts,
+    )?;
+
+    Ok(())
+}
+
+/// Return the allowlisted program whose auctioneer authority PDA for `auction_house` is
+/// `auctioneer_authority`.
+fn find_allowed_auctioneer_program(
+    auctioneer_allowlist: &AuctioneerAllowlist,
+    auction_house: &Pubkey,
+    auctioneer_authority: &Pubkey,
+) -> Result<Pubkey> {
+    auctioneer_allowlist
+        .programs
+        .iter()
+        .find(|program| {
+            let (authority, _) = Pubkey::find_program_address(
+                &[AUCTIONEER.as_bytes(), auction_house.as_ref()],
+                program,
+            );
+            authority == *auctioneer_authority
+        })
+        .copied()
+        .ok_or_else(|| AuctionHouseError::AuctioneerProgramNotAllowed.into())
+}
+
+/// Record the allowlisted program of the auctioneer already delegated on the Auction House.
+///
+/// When the house has an auctioneer, its auctioneer PDA is passed first in `remaining_accounts`.
+fn approve_delegated_auctioneer<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    auctioneer_allowlist: &AuctioneerAllowlist,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auction_house.has_auctioneer {
+        return Ok(());
+    }
+
+    let auctioneer_info = next_account_info(remaining_accounts)?;
+    assert_keys_equal(auction_house.auctioneer_address, auctioneer_info.key())?;
+    let mut auctioneer = Account::<Auctioneer>::try_from(auctioneer_info)?;
+
+    auctioneer.auctioneer_program = find_allowed_auctioneer_program(
+        auctioneer_allowlist,
+        &auction_house.key(),
+        &auctioneer.auctioneer_authority,
+    )?;
+
+    auctioneer.exit(&crate::id())
+}
+
+/// Return the allowlisted program `auctioneer_authority` derives from, or the default key when
+/// the Auction House has no auctioneer allowlist.
+///
+/// When the house has an allowlist, it is passed first in `remaining_accounts`.
+pub fn approved_auctioneer_program<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    auctioneer_authority: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Pubkey> {
+    if !auction_house.has_auctioneer_allowlist {
+        return Ok(Pubkey::default());
+    }
+
+    let auctioneer_allowlist_info = next_account_info(remaining_accounts)?;
+    let auctioneer_allowlist = Account::<AuctioneerAllowlist>::try_from(auctioneer_allowlist_info)?;
+    assert_keys_equal(auctioneer_allowlist.auction_house, auction_house.key())?;
+
+    find_allowed_auctioneer_program(
+        &auctioneer_allowlist,
+        &auction_house.key(),
+        auctioneer_authority,
+    )
+}
@@ -0,0 +1,122 @@
+//! Per-auctioneer settlement stats, so a house can evaluate and compare the auctioneers it
+//! delegates to on-chain. Once the house authority creates an auctioneer's stats account, every
+//! auctioneer settlement updates it.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, Auctioneer, AuctioneerStats, AuthorityAction,
+};
+
+/// Accounts for the [`create_auctioneer_stats` handler](auction_house/fn.create_auctioneer_stats.html).
+#[derive(Accounts)]
+pub struct CreateAuctioneerStats<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        mut,
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            ah_auctioneer_pda.auctioneer_authority.as_ref()
+        ],
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// The auctioneer stats PDA for the delegated auctioneer.
+    #[account(
+        init,
+        payer=authority,
+        space=AUCTIONEER_STATS_SIZE,
+        seeds=[
+            AUCTIONEER_STATS.as_bytes(),
+            auction_house.key().as_ref(),
+            ah_auctioneer_pda.auctioneer_authority.as_ref()
+        ],
+        bump
+    )]
+    pub auctioneer_stats: Box<Account<'info, AuctioneerStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_auctioneer_stats<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAuctioneerStats<'info>>,
+) -> Result<()> {
+    let auctioneer_stats = &mut ctx.accounts.auctioneer_stats;
+    auctioneer_stats.auction_house = ctx.accounts.auction_house.key();
+    auctioneer_stats.auctioneer_authority = ctx.accounts.ah_auctioneer_pda.auctioneer_authority;
+    auctioneer_stats.bump = *ctx
+        .bumps
+        .get("auctioneer_stats")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.ah_auctioneer_pda.has_stats = true;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::CreateAuctioneerStats,
+        0,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Add a settlement of `price` that paid the house `fee` to the auctioneer's stats. Does nothing
+/// when the auctioneer has no stats account.
+///
+/// When it does, the auctioneer stats PDA is passed in `remaining_accounts` after the tax
+/// withholding accounts.
+pub fn record_auctioneer_settlement<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    auctioneer: &Account<'info, Auctioneer>,
+    price: u64,
+    fee: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auctioneer.has_stats {
+        return Ok(());
+    }
+
+    let auctioneer_stats_info = next_account_info(remaining_accounts)?;
+    let mut auctioneer_stats = Account::<AuctioneerStats>::try_from(auctioneer_stats_info)?;
+    assert_keys_equal(auctioneer_stats.auction_house, auction_house.key())?;
+    assert_keys_equal(
+        auctioneer_stats.auctioneer_authority,
+        auctioneer.auctioneer_authority,
+    )?;
+
+    auctioneer_stats.auctions_run = auctioneer_stats
+        .auctions_run
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    auctioneer_stats.settled_volume = auctioneer_stats
+        .settled_volume
+        .checked_add(price)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    auctioneer_stats.fees_earned = auctioneer_stats
+        .fees_earned
+        .checked_add(fee)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    auctioneer_stats.exit(&crate::id())
+}
@@ -0,0 +1,88 @@
+//! On-chain journal of authority-level actions so house users can audit admin behavior.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, AuthorityAction, AuthorityLog,
+    AuthorityLogEntry,
+};
+
+/// Accounts for the [`create_authority_log` handler](auction_house/fn.create_authority_log.html).
+#[derive(Accounts)]
+pub struct CreateAuthorityLog<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The authority log PDA recording authority-level actions.
+    #[account(
+        init,
+        payer=authority,
+        space=AUTHORITY_LOG_SIZE,
+        seeds=[AUTHORITY_LOG.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub authority_log: Box<Account<'info, AuthorityLog>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_authority_log<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAuthorityLog<'info>>,
+) -> Result<()> {
+    let authority_log = &mut ctx.accounts.authority_log;
+    authority_log.auction_house = ctx.accounts.auction_house.key();
+    authority_log.bump = *ctx
+        .bumps
+        .get("authority_log")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    authority_log.next_index = 0;
+    authority_log.total_entries = 0;
+    authority_log.entries = Vec::with_capacity(AUTHORITY_LOG_CAPACITY);
+
+    ctx.accounts.auction_house.has_authority_log = true;
+
+    Ok(())
+}
+
+/// Record `action` in the Auction House authority log.
+///
+/// Once a house has created its log, every authority-level instruction must pass the writable
+/// authority log PDA as its first remaining account. Houses without a log skip recording.
+pub fn record_authority_action<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    authority: &Pubkey,
+    action: AuthorityAction,
+    value: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auction_house.has_authority_log {
+        return Ok(());
+    }
+
+    let authority_log_info = next_account_info(remaining_accounts)?;
+    let mut authority_log = Account::<AuthorityLog>::try_from(authority_log_info)?;
+    assert_keys_equal(authority_log.auction_house, auction_house.key())?;
+
+    authority_log.record(AuthorityLogEntry {
+        action,
+        authority: *authority,
+        value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    authority_log.exit(&crate::id())
+}
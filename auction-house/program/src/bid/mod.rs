@@ -9,9 +9,24 @@ use anchor_lang::{
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use solana_program::program_memory::sol_memset;
 
+#[cfg(feature = "receipts")]
 use crate::{
-    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, Auctioneer, AuthorityScope,
-    TRADE_STATE_SIZE,
+    receipt::{
+        create_receipt_if_not_present, split_receipt_accounts, BidReceipt, BID_RECEIPT_SIZE,
+    },
+    trade_sequence::placed_trade_sequence,
+};
+
+use crate::{
+    bid_ticket::burn_bid_ticket,
+    compliance::{assert_compliance, ComplianceAction},
+    constants::*,
+    errors::AuctionHouseError,
+    public_bid_index::index_public_bid,
+    spending_cap::charge_spending_cap,
+    trade_sequence::record_trade_sequence,
+    utils::*,
+    AuctionHouse, Auctioneer, AuthorityScope, IndexedPublicBid, TRADE_STATE_SIZE,
 };
 
 /// Accounts for the [`public_bid` handler](fn.public_bid.html).
@@ -102,13 +117,43 @@ pub struct PublicBuy<'info> {
 
 /// Create a bid on a specific SPL token.
 /// Public bids are specific to the token itself, rather than the auction, and remain open indefinitely until either the user closes it or the requirements for the bid are met and it is matched with a counter bid and closed as a transaction.
-pub fn public_bid(
-    ctx: Context<PublicBuy>,
+pub fn public_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, PublicBuy<'info>>,
     trade_state_bump: u8,
     escrow_payment_bump: u8,
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    record_trade_sequence(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.buyer_trade_state,
+        remaining_accounts,
+    )?;
+    assert_compliance(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.token_account.mint,
+        ComplianceAction::Buy,
+        buyer_price,
+        remaining_accounts,
+    )?;
+    burn_bid_ticket(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.buyer_trade_state.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent.to_account_info(),
+        remaining_accounts,
+    )?;
+    charge_spending_cap(
+        &ctx.accounts.auction_house.key(),
+        &ctx.accounts.wallet.key(),
+        buyer_price,
+        remaining_accounts,
+    )?;
+
     bid_logic(
         ctx.accounts.wallet.to_owned(),
         ctx.accounts.payment_account.to_owned(),
@@ -135,6 +180,18 @@ pub fn public_bid(
         *ctx.bumps
             .get("buyer_trade_state")
             .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+    )?;
+
+    index_public_bid(
+        remaining_accounts,
+        &ctx.accounts.auction_house.key(),
+        &ctx.accounts.token_account.mint,
+        IndexedPublicBid {
+            trade_state: ctx.accounts.buyer_trade_state.key(),
+            wallet: ctx.accounts.wallet.key(),
+            buyer_price,
+            token_size,
+        },
     )
 }
 
@@ -242,13 +299,43 @@ pub struct AuctioneerPublicBuy<'info> {
 
 /// Create a bid on a specific SPL token.
 /// Public bids are specific to the token itself, rather than the auction, and remain open indefinitely until either the user closes it or the requirements for the bid are met and it is matched with a counter bid and closed as a transaction.
-pub fn auctioneer_public_bid(
-    ctx: Context<AuctioneerPublicBuy>,
+pub fn auctioneer_public_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerPublicBuy<'info>>,
     trade_state_bump: u8,
     escrow_payment_bump: u8,
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    record_trade_sequence(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.buyer_trade_state,
+        remaining_accounts,
+    )?;
+    assert_compliance(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.token_account.mint,
+        ComplianceAction::Buy,
+        buyer_price,
+        remaining_accounts,
+    )?;
+    burn_bid_ticket(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.buyer_trade_state.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent.to_account_info(),
+        remaining_accounts,
+    )?;
+    charge_spending_cap(
+        &ctx.accounts.auction_house.key(),
+        &ctx.accounts.wallet.key(),
+        buyer_price,
+        remaining_accounts,
+    )?;
+
     auctioneer_bid_logic(
         ctx.accounts.wallet.to_owned(),
         ctx.accounts.payment_account.to_owned(),
@@ -277,6 +364,18 @@ pub fn auctioneer_public_bid(
         *ctx.bumps
             .get("buyer_trade_state")
             .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?,
+    )?;
+
+    index_public_bid(
+        remaining_accounts,
+        &ctx.accounts.auction_house.key(),
+        &ctx.accounts.token_account.mint,
+        IndexedPublicBid {
+            trade_state: ctx.accounts.buyer_trade_state.key(),
+            wallet: ctx.accounts.wallet.key(),
+            buyer_price,
+            token_size,
+        },
     )
 }
 
@@ -386,6 +485,36 @@ pub fn private_bid<'info>(
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    record_trade_sequence(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.buyer_trade_state,
+        remaining_accounts,
+    )?;
+    assert_compliance(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.token_account.mint,
+        ComplianceAction::Buy,
+        buyer_price,
+        remaining_accounts,
+    )?;
+    burn_bid_ticket(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.buyer_trade_state.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent.to_account_info(),
+        remaining_accounts,
+    )?;
+    charge_spending_cap(
+        &ctx.accounts.auction_house.key(),
+        &ctx.accounts.wallet.key(),
+        buyer_price,
+        remaining_accounts,
+    )?;
+
     bid_logic(
         ctx.accounts.wallet.to_owned(),
         ctx.accounts.payment_account.to_owned(),
@@ -415,6 +544,111 @@ pub fn private_bid<'info>(
     )
 }
 
+/// Create a private bid and print its bid receipt in one instruction. The receipt and the
+/// bookkeeper paying for it are passed in `remaining_accounts` ahead of the accounts `buy` takes
+/// there.
+#[cfg(feature = "receipts")]
+pub fn buy_with_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    receipt_bump: u8,
+) -> Result<()> {
+    let (receipt, bookkeeper, remaining_accounts) = split_receipt_accounts(ctx.remaining_accounts)?;
+
+    private_bid(
+        Context::new(
+            ctx.program_id,
+            &mut *ctx.accounts,
+            remaining_accounts,
+            ctx.bumps.clone(),
+        ),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )?;
+
+    let accounts = &ctx.accounts;
+    create_receipt_if_not_present(
+        receipt,
+        bookkeeper,
+        &accounts.system_program,
+        &accounts.rent.to_account_info(),
+        BID_RECEIPT_PREFIX,
+        &accounts.buyer_trade_state.key(),
+        receipt_bump,
+        BID_RECEIPT_SIZE,
+    )?;
+
+    let bid_receipt = BidReceipt {
+        trade_state: accounts.buyer_trade_state.key(),
+        bookkeeper: bookkeeper.key(),
+        auction_house: accounts.auction_house.key(),
+        buyer: accounts.wallet.key(),
+        metadata: accounts.metadata.key(),
+        token_account: Some(accounts.token_account.key()),
+        purchase_receipt: None,
+        price: buyer_price,
+        token_size,
+        bump: receipt_bump,
+        trade_state_bump,
+        created_at: Clock::get()?.unix_timestamp,
+        canceled_at: None,
+        // The trade state is created by this instruction, so it cannot be attributed yet.
+        sub_account: None,
+        sequence: placed_trade_sequence(
+            &accounts.auction_house.key(),
+            &accounts.buyer_trade_state.key(),
+            &mut remaining_accounts.iter(),
+        )?,
+    };
+
+    bid_receipt.try_serialize(&mut *receipt.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Fund the escrow with exactly `buyer_price` from the wallet and create a private bid in one
+/// instruction, rather than a `deposit` followed by a `buy`. Each bid placed this way is backed by
+/// its own deposit. Resending an existing bid transfers nothing.
+pub fn bid_with_transfer<'info>(
+    ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    if ctx.accounts.treasury_mint.key() != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::BidWithTransferRequiresNativeMint.into());
+    }
+
+    if ctx.accounts.buyer_trade_state.data_is_empty() {
+        invoke(
+            &system_instruction::transfer(
+                &ctx.accounts.wallet.key(),
+                &ctx.accounts.escrow_payment_account.key(),
+                buyer_price,
+            ),
+            &[
+                ctx.accounts.wallet.to_account_info(),
+                ctx.accounts.escrow_payment_account.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    private_bid(
+        ctx,
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )
+}
+
 /// Accounts for the [`auctioneer_private_bid` handler](fn.auctioneer_private_bid.html).
 #[derive(Accounts)]
 #[instruction(
@@ -533,6 +767,36 @@ pub fn auctioneer_private_bid<'info>(
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    record_trade_sequence(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.buyer_trade_state,
+        remaining_accounts,
+    )?;
+    assert_compliance(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.token_account.mint,
+        ComplianceAction::Buy,
+        buyer_price,
+        remaining_accounts,
+    )?;
+    burn_bid_ticket(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.wallet,
+        &ctx.accounts.buyer_trade_state.key(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent.to_account_info(),
+        remaining_accounts,
+    )?;
+    charge_spending_cap(
+        &ctx.accounts.auction_house.key(),
+        &ctx.accounts.wallet.key(),
+        buyer_price,
+        remaining_accounts,
+    )?;
+
     auctioneer_bid_logic(
         ctx.accounts.wallet.to_owned(),
         ctx.accounts.payment_account.to_owned(),
@@ -0,0 +1,94 @@
+//! Expiries on open bids, after which anyone may sweep the bid for a bounty.
+pub mod sweep;
+pub use sweep::*;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{constants::*, errors::*, utils::*, AuctionHouse, BidExpiry};
+
+/// Accounts for the [`set_bid_expiry` handler](auction_house/fn.set_bid_expiry.html).
+#[derive(Accounts)]
+#[instruction(buyer_price: u64, token_size: u64)]
+pub struct SetBidExpiry<'info> {
+    /// Buyer wallet account. Pays for the bid expiry account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account the bid was placed on.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Validated in set_bid_expiry.
+    /// Buyer trade state PDA account encoding the bid.
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// The bid expiry PDA for the trade state.
+    #[account(
+        init,
+        payer=wallet,
+        space=BID_EXPIRY_SIZE,
+        seeds=[BID_EXPIRY.as_bytes(), trade_state.key().as_ref()],
+        bump
+    )]
+    pub bid_expiry: Box<Account<'info, BidExpiry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Expire an open bid at `expires_at`. The rent paid for the expiry account and the bid's trade
+/// state is the bounty for whoever sweeps it.
+pub fn set_bid_expiry<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetBidExpiry<'info>>,
+    buyer_price: u64,
+    token_size: u64,
+    expires_at: i64,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let token_account = &ctx.accounts.token_account;
+    let trade_state = &ctx.accounts.trade_state;
+
+    // Listings are trade states on the wallet's own token account.
+    if trade_state.data_is_empty() || token_account.owner == wallet.key() {
+        return Err(AuctionHouseError::InvalidBidExpiry.into());
+    }
+
+    let ts_bump = trade_state.try_borrow_data()?[0];
+    assert_valid_trade_state(
+        &wallet.key(),
+        &ctx.accounts.auction_house,
+        buyer_price,
+        token_size,
+        &trade_state.to_account_info(),
+        &token_account.mint,
+        &token_account.key(),
+        ts_bump,
+    )?;
+
+    if expires_at <= Clock::get()?.unix_timestamp {
+        return Err(AuctionHouseError::InvalidBidExpiry.into());
+    }
+
+    let bid_expiry = &mut ctx.accounts.bid_expiry;
+    bid_expiry.auction_house = ctx.accounts.auction_house.key();
+    bid_expiry.wallet = wallet.key();
+    bid_expiry.trade_state = trade_state.key();
+    bid_expiry.buyer_price = buyer_price;
+    bid_expiry.expires_at = expires_at;
+    bid_expiry.bump = *ctx
+        .bumps
+        .get("bid_expiry")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -0,0 +1,217 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::Token;
+use solana_program::program_memory::sol_memset;
+use spl_token::state::Account as SplAccount;
+
+use crate::{constants::*, errors::*, utils::*, AuctionHouse, AuthorityScope, BidExpiry};
+
+/// Number of remaining accounts passed per bid to `sweep_expired_bids`.
+pub const SWEEP_EXPIRED_BIDS_ACCOUNTS_PER_BID: usize = 4;
+
+/// Accounts for the [`sweep_expired_bids` handler](auction_house/fn.sweep_expired_bids.html).
+///
+/// For every bid swept, remaining accounts must hold its bid expiry, its trade state, the
+/// buyer's escrow payment account, and the account refunded: the buyer wallet for native SOL
+/// houses, or the buyer's associated token account for the treasury mint.
+#[derive(Accounts)]
+pub struct SweepExpiredBids<'info> {
+    /// Account running the sweep. Receives the reclaimed rent as a bounty.
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Close expired bids, refunding each bid's price from escrow to the buyer and paying the
+/// reclaimed rent to the cranker. Expiries of bids that already settled or were canceled are
+/// closed without a refund.
+///
+/// Competing crankers may submit overlapping batches, so bids whose expiry another cranker
+/// already closed are skipped and the rest of the batch still lands. Fails without doing anything
+/// when every bid in the batch was already swept, so crankers can drop the batch after simulating
+/// it.
+pub fn sweep_expired_bids<'info>(
+    ctx: Context<'_, '_, '_, 'info, SweepExpiredBids<'info>>,
+) -> Result<()> {
+    let cranker = &ctx.accounts.cranker;
+    let auction_house = &ctx.accounts.auction_house;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let remaining_accounts = ctx.remaining_accounts;
+
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if auction_house.has_auctioneer
+        && (auction_house.scopes[AuthorityScope::Cancel as usize]
+            || auction_house.scopes[AuthorityScope::Withdraw as usize])
+    {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    if remaining_accounts.is_empty()
+        || remaining_accounts.len() % SWEEP_EXPIRED_BIDS_ACCOUNTS_PER_BID != 0
+    {
+        return Err(AuctionHouseError::InvalidSweepExpiredBidsAccounts.into());
+    }
+
+    if remaining_accounts
+        .chunks(SWEEP_EXPIRED_BIDS_ACCOUNTS_PER_BID)
+        .all(|accounts| accounts[0].data_is_empty())
+    {
+        return Err(AuctionHouseError::NothingToCrank.into());
+    }
+
+    let auction_house_key = auction_house.key();
+    let now = Clock::get()?.unix_timestamp;
+    for accounts in remaining_accounts.chunks(SWEEP_EXPIRED_BIDS_ACCOUNTS_PER_BID) {
+        if accounts[0].data_is_empty() {
+            continue;
+        }
+
+        let bid_expiry: Account<BidExpiry> = Account::try_from(&accounts[0])?;
+        let trade_state = &accounts[1];
+        let escrow_payment_account = &accounts[2];
+        let receipt_account = &accounts[3];
+
+        assert_keys_equal(bid_expiry.auction_house, auction_house_key)?;
+        assert_keys_equal(bid_expiry.trade_state, trade_state.key())?;
+        if now < bid_expiry.expires_at {
+            return Err(AuctionHouseError::BidNotExpired.into());
+        }
+
+        if trade_state.lamports() > 0 {
+            refund_expired_bid(
+                auction_house,
+                &bid_expiry,
+                escrow_payment_account,
+                receipt_account,
+                token_program,
+                system_program,
+            )?;
+
+            let trade_state_lamports = trade_state.lamports();
+            **trade_state.lamports.borrow_mut() = 0;
+            **cranker.lamports.borrow_mut() = cranker
+                .lamports()
+                .checked_add(trade_state_lamports)
+                .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+            #[allow(clippy::explicit_auto_deref)]
+            sol_memset(*trade_state.try_borrow_mut_data()?, 0, TRADE_STATE_SIZE);
+        }
+
+        bid_expiry.close(cranker.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Return up to the expired bid's price from the buyer's escrow payment account.
+fn refund_expired_bid<'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    bid_expiry: &Account<'info, BidExpiry>,
+    escrow_payment_account: &AccountInfo<'info>,
+    receipt_account: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let auction_house_key = auction_house.key();
+    let wallet_key = bid_expiry.wallet;
+    let escrow_payment_bump = assert_derivation(
+        &crate::id(),
+        escrow_payment_account,
+        &[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            wallet_key.as_ref(),
+        ],
+    )?;
+
+    if auction_house.treasury_mint != spl_token::native_mint::id() {
+        if escrow_payment_account.data_is_empty() {
+            return Ok(());
+        }
+
+        let escrow: SplAccount = assert_initialized(escrow_payment_account)?;
+        let amount = escrow.amount.min(bid_expiry.buyer_price);
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let rec_acct = assert_is_ata(receipt_account, &wallet_key, &auction_house.treasury_mint)?;
+
+        // make sure you cant get rugged
+        if rec_acct.delegate.is_some() {
+            return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+        }
+
+        let ah_seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            &[auction_house.bump],
+        ];
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                escrow_payment_account.key,
+                receipt_account.key,
+                &auction_house_key,
+                &[],
+                amount,
+            )?,
+            &[
+                escrow_payment_account.clone(),
+                receipt_account.clone(),
+                token_program.to_account_info(),
+                auction_house.to_account_info(),
+            ],
+            &[&ah_seeds],
+        )?;
+    } else {
+        assert_keys_equal(receipt_account.key(), wallet_key)?;
+        let amount = native_escrow_withdrawal_amount(
+            escrow_payment_account.clone(),
+            escrow_payment_account
+                .lamports()
+                .min(bid_expiry.buyer_price),
+        )?;
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let escrow_signer_seeds = [
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            wallet_key.as_ref(),
+            &[escrow_payment_bump],
+        ];
+        invoke_signed(
+            &system_instruction::transfer(escrow_payment_account.key, &wallet_key, amount),
+            &[
+                escrow_payment_account.clone(),
+                receipt_account.clone(),
+                system_program.to_account_info(),
+            ],
+            &[&escrow_signer_seeds],
+        )?;
+
+        assert_escrow_rent_exempt(escrow_payment_account)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+//! Burn-to-bid gate for allowlist auctions. When a house sets a ticket collection, every bid
+//! burns one ticket NFT from that collection and records the burn against the bid's trade state.
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use spl_token::state::Account as SplAccount;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, AuthorityAction, BidTicketBurn, BidTicketConfig,
+};
+
+/// Accounts for the [`create_bid_ticket_config` handler](auction_house/fn.create_bid_ticket_config.html).
+#[derive(Accounts)]
+pub struct CreateBidTicketConfig<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The bid ticket config PDA for the house.
+    #[account(
+        init,
+        payer=authority,
+        space=BID_TICKET_CONFIG_SIZE,
+        seeds=[BID_TICKET_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub bid_ticket_config: Box<Account<'info, BidTicketConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_bid_ticket_config` handler](auction_house/fn.update_bid_ticket_config.html).
+#[derive(Accounts)]
+pub struct UpdateBidTicketConfig<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The bid ticket config PDA for the house.
+    #[account(
+        mut,
+        seeds=[BID_TICKET_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump=bid_ticket_config.bump,
+        has_one=auction_house
+    )]
+    pub bid_ticket_config: Box<Account<'info, BidTicketConfig>>,
+}
+
+pub fn create_bid_ticket_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBidTicketConfig<'info>>,
+    collection: Pubkey,
+) -> Result<()> {
+    let bid_ticket_config = &mut ctx.accounts.bid_ticket_config;
+    bid_ticket_config.auction_house = ctx.accounts.auction_house.key();
+    bid_ticket_config.collection = collection;
+    bid_ticket_config.bump = *ctx
+        .bumps
+        .get("bid_ticket_config")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.auction_house.has_bid_ticket_gate = collection != Pubkey::default();
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetBidTicketConfig,
+        ctx.accounts.auction_house.has_bid_ticket_gate as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+pub fn update_bid_ticket_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateBidTicketConfig<'info>>,
+    collection: Pubkey,
+) -> Result<()> {
+    ctx.accounts.bid_ticket_config.collection = collection;
+
+    ctx.accounts.auction_house.has_bid_ticket_gate = collection != Pubkey::default();
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetBidTicketConfig,
+        ctx.accounts.auction_house.has_bid_ticket_gate as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Burn one of `wallet`'s ticket NFTs and record the burn for `trade_state`. Does nothing when
+/// the Auction House has no bid ticket gate.
+///
+/// When the gate is set, the bid ticket config, the ticket token account, the ticket mint, the
+/// ticket metadata and the uninitialized bid ticket burn PDA are passed in `remaining_accounts`,
+/// after the compliance accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn burn_bid_ticket<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    wallet: &AccountInfo<'info>,
+    trade_state: &Pubkey,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auction_house.has_bid_ticket_gate {
+        return Ok(());
+    }
+
+    let bid_ticket_config_info = next_account_info(remaining_accounts)?;
+    let ticket_token_account = next_account_info(remaining_accounts)?;
+    let ticket_mint = next_account_info(remaining_accounts)?;
+    let ticket_metadata = next_account_info(remaining_accounts)?;
+    let bid_ticket_burn = next_account_info(remaining_accounts)?;
+
+    let bid_ticket_config: Account<BidTicketConfig> = Account::try_from(bid_ticket_config_info)?;
+    assert_keys_equal(bid_ticket_config.auction_house, auction_house.key())?;
+
+    assert_owned_by(ticket_token_account, &spl_token::id())?;
+    let ticket: SplAccount = assert_initialized(ticket_token_account)?;
+    if ticket.owner != wallet.key() || ticket.mint != ticket_mint.key() || ticket.amount < 1 {
+        return err!(AuctionHouseError::InvalidBidTicket);
+    }
+
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        ticket_metadata,
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            ticket_mint.key().as_ref(),
+        ],
+    )?;
+    let metadata = Metadata::from_account_info(ticket_metadata)?;
+    let in_collection = metadata.collection.map_or(false, |c| {
+        c.verified && c.key == bid_ticket_config.collection
+    });
+    if !in_collection {
+        return err!(AuctionHouseError::BidTicketNotInCollection);
+    }
+
+    token::burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: ticket_mint.clone(),
+                from: ticket_token_account.clone(),
+                authority: wallet.clone(),
+            },
+        ),
+        1,
+    )?;
+
+    let burn_bump = assert_derivation(
+        &crate::id(),
+        bid_ticket_burn,
+        &[BID_TICKET_BURN.as_bytes(), trade_state.as_ref()],
+    )?;
+    create_or_allocate_account_raw(
+        crate::id(),
+        bid_ticket_burn,
+        rent,
+        system_program,
+        wallet,
+        BID_TICKET_BURN_SIZE,
+        &[],
+        &[
+            BID_TICKET_BURN.as_bytes(),
+            trade_state.as_ref(),
+            &[burn_bump],
+        ],
+    )?;
+
+    BidTicketBurn {
+        auction_house: auction_house.key(),
+        trade_state: *trade_state,
+        wallet: wallet.key(),
+        ticket_mint: ticket_mint.key(),
+        burned_at: Clock::get()?.unix_timestamp,
+        bump: burn_bump,
+    }
+    .try_serialize(&mut *bid_ticket_burn.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
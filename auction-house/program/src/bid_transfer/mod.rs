@@ -0,0 +1,334 @@
+//! Moving an open bid, and the escrowed payment backing it, to another wallet. Both wallets sign,
+//! so a position can change hands without canceling and rebidding at a different price.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::{invoke, invoke_signed},
+        system_instruction,
+    },
+};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use solana_program::program_memory::sol_memset;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, public_bid_index::unindex_public_bid, utils::*,
+    AuctionHouse,
+};
+
+/// Accounts for the [`transfer_bid` handler](auction_house/fn.transfer_bid.html).
+#[derive(Accounts)]
+#[instruction(buyer_price: u64, token_size: u64, new_trade_state_bump: u8)]
+pub struct TransferBid<'info> {
+    /// Wallet that placed the bid, refunded the rent of its trade state.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Wallet taking over the bid, paying for its new trade state.
+    #[account(mut)]
+    pub new_wallet: Signer<'info>,
+
+    /// SPL token account the bid is on.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Escrow payment account PDA of the wallet that placed the bid.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Escrow payment account PDA of the wallet taking over the bid.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            new_wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub new_escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in transfer_bid.
+    /// Trade state PDA account of the bid being transferred.
+    #[account(mut)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in transfer_bid.
+    /// Trade state PDA account for the same bid placed by the new wallet.
+    #[account(mut)]
+    pub new_buyer_trade_state: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Move an open bid to `new_wallet`: the bid's trade state is closed and recreated for the new
+/// wallet, and `buyer_price` moves from the bidder's escrow to the new wallet's. Records keyed by
+/// the old trade state, such as bid receipts and expiries, are not carried over. A public bid
+/// index passed as the first remaining account drops the old trade state.
+pub fn transfer_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBid<'info>>,
+    buyer_price: u64,
+    token_size: u64,
+    new_trade_state_bump: u8,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let new_wallet = &ctx.accounts.new_wallet;
+    let token_account = &ctx.accounts.token_account;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let auction_house = &ctx.accounts.auction_house;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let new_escrow_payment_account = &ctx.accounts.new_escrow_payment_account;
+    let buyer_trade_state = &ctx.accounts.buyer_trade_state;
+    let new_buyer_trade_state = &ctx.accounts.new_buyer_trade_state;
+    let system_program = &ctx.accounts.system_program;
+    let token_program = &ctx.accounts.token_program;
+    let rent = &ctx.accounts.rent;
+
+    // Auctioneers track the bids on their listings by trade state.
+    if auction_house.has_auctioneer {
+        return Err(AuctionHouseError::AuctioneerBidNotTransferable.into());
+    }
+
+    if buyer_trade_state.data_is_empty() {
+        return Err(AuctionHouseError::TradeStateDoesntExist.into());
+    }
+    if !new_buyer_trade_state.data_is_empty() {
+        return Err(AuctionHouseError::TradeStateIsNotEmpty.into());
+    }
+
+    let ts_bump = buyer_trade_state.try_borrow_data()?[0];
+    assert_valid_trade_state(
+        &wallet.key(),
+        auction_house,
+        buyer_price,
+        token_size,
+        buyer_trade_state,
+        &token_account.mint,
+        &token_account.key(),
+        ts_bump,
+    )?;
+    assert_valid_trade_state(
+        &new_wallet.key(),
+        auction_house,
+        buyer_price,
+        token_size,
+        new_buyer_trade_state,
+        &token_account.mint,
+        &token_account.key(),
+        new_trade_state_bump,
+    )?;
+
+    let auction_house_key = auction_house.key();
+    let wallet_key = wallet.key();
+    let new_wallet_key = new_wallet.key();
+    let token_account_key = token_account.key();
+    let buyer_price_bytes = buyer_price.to_le_bytes();
+    let token_size_bytes = token_size.to_le_bytes();
+
+    // The new trade state takes the same form, public or private, as the bid it replaces.
+    let is_public = Pubkey::create_program_address(
+        &[
+            PREFIX.as_bytes(),
+            wallet_key.as_ref(),
+            auction_house_key.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &buyer_price_bytes,
+            &token_size_bytes,
+            &[ts_bump],
+        ],
+        &crate::id(),
+    )
+    .map_or(false, |key| key == buyer_trade_state.key());
+    let ts_info = new_buyer_trade_state.to_account_info();
+    if is_public {
+        create_or_allocate_account_raw(
+            crate::id(),
+            &ts_info,
+            &rent.to_account_info(),
+            system_program,
+            new_wallet,
+            TRADE_STATE_SIZE,
+            &[],
+            &[
+                PREFIX.as_bytes(),
+                new_wallet_key.as_ref(),
+                auction_house_key.as_ref(),
+                auction_house.treasury_mint.as_ref(),
+                token_account.mint.as_ref(),
+                &buyer_price_bytes,
+                &token_size_bytes,
+                &[new_trade_state_bump],
+            ],
+        )?;
+    } else {
+        create_or_allocate_account_raw(
+            crate::id(),
+            &ts_info,
+            &rent.to_account_info(),
+            system_program,
+            new_wallet,
+            TRADE_STATE_SIZE,
+            &[],
+            &[
+                PREFIX.as_bytes(),
+                new_wallet_key.as_ref(),
+                auction_house_key.as_ref(),
+                token_account_key.as_ref(),
+                auction_house.treasury_mint.as_ref(),
+                token_account.mint.as_ref(),
+                &buyer_price_bytes,
+                &token_size_bytes,
+                &[new_trade_state_bump],
+            ],
+        )?;
+    }
+    #[allow(clippy::explicit_auto_deref)]
+    sol_memset(
+        *ts_info.try_borrow_mut_data()?,
+        new_trade_state_bump,
+        TRADE_STATE_SIZE,
+    );
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    unindex_public_bid(
+        remaining_accounts,
+        &auction_house_key,
+        &token_account.mint,
+        &buyer_trade_state.key(),
+    )?;
+
+    let curr_lamp = buyer_trade_state.lamports();
+    **buyer_trade_state.lamports.borrow_mut() = 0;
+    **wallet.lamports.borrow_mut() = wallet
+        .lamports()
+        .checked_add(curr_lamp)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    #[allow(clippy::explicit_auto_deref)]
+    sol_memset(
+        *buyer_trade_state.try_borrow_mut_data()?,
+        0,
+        TRADE_STATE_SIZE,
+    );
+
+    let escrow_payment_bump = *ctx
+        .bumps
+        .get("escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let new_escrow_payment_bump = *ctx
+        .bumps
+        .get("new_escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+    let new_escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        new_wallet_key.as_ref(),
+        &[new_escrow_payment_bump],
+    ];
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+    create_program_token_account_if_not_present(
+        new_escrow_payment_account,
+        system_program,
+        new_wallet,
+        token_program,
+        treasury_mint,
+        &auction_house.to_account_info(),
+        rent,
+        &new_escrow_signer_seeds,
+        &[],
+        is_native,
+    )?;
+
+    if is_native {
+        verify_withdrawal(escrow_payment_account.to_account_info(), buyer_price)?;
+        invoke_signed(
+            &system_instruction::transfer(
+                &escrow_payment_account.key(),
+                &new_escrow_payment_account.key(),
+                buyer_price,
+            ),
+            &[
+                escrow_payment_account.to_account_info(),
+                new_escrow_payment_account.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[&escrow_signer_seeds],
+        )?;
+        assert_escrow_rent_exempt(&escrow_payment_account.to_account_info())?;
+
+        // The new wallet keeps its escrow rent exempt, as it would when bidding.
+        let rent_shortfall = verify_deposit(new_escrow_payment_account.to_account_info(), 0)?;
+        if rent_shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &new_wallet_key,
+                    &new_escrow_payment_account.key(),
+                    rent_shortfall,
+                ),
+                &[
+                    new_wallet.to_account_info(),
+                    new_escrow_payment_account.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else {
+        let ah_seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            &[auction_house.bump],
+        ];
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                &token_program.key(),
+                &escrow_payment_account.key(),
+                &new_escrow_payment_account.key(),
+                &auction_house_key,
+                &[],
+                buyer_price,
+            )?,
+            &[
+                escrow_payment_account.to_account_info(),
+                new_escrow_payment_account.to_account_info(),
+                token_program.to_account_info(),
+                auction_house.to_account_info(),
+            ],
+            &[&ah_seeds],
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    buyer_protection::{pay_from_protected_settlement, release_protected_tokens},
+    constants::*,
+    errors::AuctionHouseError,
+    math::split_basis_points,
+    settlement_marker::finish_settlement,
+    AuctionHouse, ProtectedSettlement, SettlementMarker, SettlementStatus,
+};
+
+/// Accounts for the [`cancel_protected_settlement` handler](auction_house/fn.cancel_protected_settlement.html).
+#[derive(Accounts)]
+pub struct CancelProtectedSettlement<'info> {
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Seller user wallet account, receiving the restocking fee.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Account that paid the rent for the protected settlement.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Seller token account the escrowed tokens are returned to.
+    #[account(mut)]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token account owned by the protected settlement holding the purchased tokens.
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == protected_settlement.key(),
+        constraint = escrow_token_account.mint == token_mint.key()
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account for the SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Protected settlement PDA holding the seller's proceeds.
+    #[account(
+        mut,
+        seeds = [
+            PROTECTED_SETTLEMENT.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump=protected_settlement.bump,
+        has_one=auction_house,
+        has_one=buyer,
+        has_one=seller,
+        has_one=token_mint,
+        has_one=seller_token_account,
+        has_one=rent_payer,
+        close=rent_payer
+    )]
+    pub protected_settlement: Box<Account<'info, ProtectedSettlement>>,
+
+    /// Settlement marker PDA of the protected settlement.
+    #[account(
+        mut,
+        seeds=[SETTLEMENT_MARKER.as_bytes(), protected_settlement.key().as_ref()],
+        bump=settlement_marker.bump,
+        has_one=protected_settlement
+    )]
+    pub settlement_marker: Box<Account<'info, SettlementMarker>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Cancel a purchase during its buyer protection window. The tokens go back to the seller and
+/// the buyer is refunded the escrowed proceeds less the restocking fee, which goes to the seller.
+pub fn cancel_protected_settlement<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelProtectedSettlement<'info>>,
+) -> Result<()> {
+    let protected_settlement = &ctx.accounts.protected_settlement;
+    if protected_settlement.disputed {
+        return Err(AuctionHouseError::SettlementInDispute.into());
+    }
+
+    if Clock::get()?.unix_timestamp >= protected_settlement.release_at {
+        return Err(AuctionHouseError::BuyerProtectionWindowClosed.into());
+    }
+
+    let (restocking_fee, refund) = split_basis_points(
+        protected_settlement.seller_proceeds,
+        protected_settlement.restocking_fee_basis_points,
+    )?;
+
+    release_protected_tokens(
+        protected_settlement,
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.seller_token_account.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    let settlement_info = protected_settlement.to_account_info();
+    pay_from_protected_settlement(
+        &settlement_info,
+        &ctx.accounts.buyer.to_account_info(),
+        refund,
+    )?;
+    pay_from_protected_settlement(
+        &settlement_info,
+        &ctx.accounts.seller.to_account_info(),
+        restocking_fee,
+    )?;
+
+    finish_settlement(
+        &mut ctx.accounts.settlement_marker,
+        SettlementStatus::RolledBack,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction,
+};
+
+/// Accounts for the [`set_buyer_protection` handler](auction_house/fn.set_buyer_protection.html).
+#[derive(Accounts)]
+pub struct SetBuyerProtection<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_buyer_protection<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetBuyerProtection<'info>>,
+    buyer_protection_seconds: i64,
+    restocking_fee_basis_points: u16,
+) -> Result<()> {
+    if buyer_protection_seconds < 0 {
+        return Err(AuctionHouseError::InvalidBuyerProtectionWindow.into());
+    }
+
+    if restocking_fee_basis_points > 10000 {
+        return Err(AuctionHouseError::InvalidBasisPoints.into());
+    }
+
+    let auction_house = &mut ctx.accounts.auction_house;
+    if buyer_protection_seconds > 0 && auction_house.treasury_mint != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::BuyerProtectionRequiresNativeMint.into());
+    }
+
+    auction_house.buyer_protection_seconds = buyer_protection_seconds;
+    auction_house.restocking_fee_basis_points = restocking_fee_basis_points;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetBuyerProtection,
+        buyer_protection_seconds as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
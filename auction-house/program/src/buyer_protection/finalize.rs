@@ -0,0 +1,308 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use spl_token::state::Account as SplAccount;
+
+use crate::{
+    buyer_protection::{pay_from_protected_settlement, release_protected_tokens},
+    constants::*,
+    errors::AuctionHouseError,
+    settlement_marker::finish_settlement,
+    utils::*,
+    wallet_receipt::assert_buyer_receipt_account,
+    AuctionHouse, ProtectedSettlement, SettlementMarker, SettlementStatus,
+};
+
+/// Number of remaining accounts passed per settlement to `finalize_protected_settlements`.
+pub const FINALIZE_PROTECTED_SETTLEMENTS_ACCOUNTS_PER_SETTLEMENT: usize = 6;
+
+/// Accounts for the [`finalize_protected_settlement` handler](auction_house/fn.finalize_protected_settlement.html).
+#[derive(Accounts)]
+pub struct FinalizeProtectedSettlement<'info> {
+    /// Account paying for the buyer's token account if it does not exist yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Buyer user wallet account.
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Seller user wallet account, receiving the escrowed proceeds.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Account that paid the rent for the protected settlement.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: Created if needed and validated in finalize_protected_settlement.
+    /// Buyer SPL token account to receive the purchased tokens.
+    #[account(mut)]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// Token account owned by the protected settlement holding the purchased tokens.
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == protected_settlement.key(),
+        constraint = escrow_token_account.mint == token_mint.key()
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account for the SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Protected settlement PDA holding the seller's proceeds.
+    #[account(
+        mut,
+        seeds = [
+            PROTECTED_SETTLEMENT.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump=protected_settlement.bump,
+        has_one=auction_house,
+        has_one=buyer,
+        has_one=seller,
+        has_one=token_mint,
+        has_one=rent_payer,
+        close=rent_payer
+    )]
+    pub protected_settlement: Box<Account<'info, ProtectedSettlement>>,
+
+    /// Settlement marker PDA of the protected settlement.
+    #[account(
+        mut,
+        seeds=[SETTLEMENT_MARKER.as_bytes(), protected_settlement.key().as_ref()],
+        bump=settlement_marker.bump,
+        has_one=protected_settlement
+    )]
+    pub settlement_marker: Box<Account<'info, SettlementMarker>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Deliver the escrowed tokens to the buyer and the proceeds to the seller once the buyer
+/// protection window has passed. Anyone may crank this.
+pub fn finalize_protected_settlement<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeProtectedSettlement<'info>>,
+) -> Result<()> {
+    let protected_settlement = &ctx.accounts.protected_settlement;
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+
+    if protected_settlement.disputed {
+        return Err(AuctionHouseError::SettlementInDispute.into());
+    }
+
+    if Clock::get()?.unix_timestamp < protected_settlement.release_at {
+        return Err(AuctionHouseError::BuyerProtectionWindowOpen.into());
+    }
+
+    if buyer_receipt_token_account.data_is_empty() {
+        make_ata(
+            buyer_receipt_token_account.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            &[],
+        )?;
+    }
+
+    let buyer_rec_acct = assert_buyer_receipt_account(
+        &ctx.accounts.auction_house,
+        &buyer_receipt_token_account.to_account_info(),
+        &protected_settlement.buyer,
+        &protected_settlement.token_mint,
+    )?;
+
+    // make sure you cant get rugged
+    if buyer_rec_acct.delegate.is_some() {
+        return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+    }
+
+    release_protected_tokens(
+        protected_settlement,
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &buyer_receipt_token_account.to_account_info(),
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    pay_from_protected_settlement(
+        &protected_settlement.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        protected_settlement.seller_proceeds,
+    )?;
+
+    finish_settlement(
+        &mut ctx.accounts.settlement_marker,
+        SettlementStatus::Settled,
+    )?;
+
+    Ok(())
+}
+
+/// Accounts for the [`finalize_protected_settlements` handler](auction_house/fn.finalize_protected_settlements.html).
+///
+/// For every settlement finalized, remaining accounts must hold the protected settlement, its
+/// settlement marker, its escrow token account, the buyer's receipt token account, the seller
+/// wallet and the settlement's rent payer. Buyer receipt token accounts must already exist.
+#[derive(Accounts)]
+pub struct FinalizeProtectedSettlements<'info> {
+    /// Account running the crank.
+    pub cranker: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Finalize a batch of protected settlements whose buyer protection window has passed.
+///
+/// Competing crankers may submit overlapping batches, so settlements that another cranker already
+/// finalized, that are disputed or that are not due yet are skipped before any work is done, and
+/// the rest of the batch still lands. Fails without doing anything when no settlement in the batch
+/// is due, so crankers can drop the batch after simulating it.
+pub fn finalize_protected_settlements<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeProtectedSettlements<'info>>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let token_program = &ctx.accounts.token_program.to_account_info();
+    let remaining_accounts = ctx.remaining_accounts;
+
+    if remaining_accounts.is_empty()
+        || remaining_accounts.len() % FINALIZE_PROTECTED_SETTLEMENTS_ACCOUNTS_PER_SETTLEMENT != 0
+    {
+        return Err(AuctionHouseError::InvalidFinalizeProtectedSettlementsAccounts.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut due = Vec::new();
+    for accounts in
+        remaining_accounts.chunks(FINALIZE_PROTECTED_SETTLEMENTS_ACCOUNTS_PER_SETTLEMENT)
+    {
+        if protected_settlement_due(auction_house, &accounts[0], &accounts[1], now)? {
+            due.push(accounts);
+        }
+    }
+    if due.is_empty() {
+        return Err(AuctionHouseError::NothingToCrank.into());
+    }
+
+    for accounts in due {
+        let protected_settlement: Account<ProtectedSettlement> = Account::try_from(&accounts[0])?;
+        let mut settlement_marker: Account<SettlementMarker> = Account::try_from(&accounts[1])?;
+        let escrow_token_account = &accounts[2];
+        let buyer_receipt_token_account = &accounts[3];
+        let seller = &accounts[4];
+        let rent_payer = &accounts[5];
+
+        assert_keys_equal(seller.key(), protected_settlement.seller)?;
+        assert_keys_equal(rent_payer.key(), protected_settlement.rent_payer)?;
+
+        assert_owned_by(escrow_token_account, &spl_token::id())?;
+        let escrow: SplAccount = assert_initialized(escrow_token_account)?;
+        assert_keys_equal(escrow.owner, protected_settlement.key())?;
+        assert_keys_equal(escrow.mint, protected_settlement.token_mint)?;
+
+        let buyer_rec_acct = assert_buyer_receipt_account(
+            auction_house,
+            buyer_receipt_token_account,
+            &protected_settlement.buyer,
+            &protected_settlement.token_mint,
+        )?;
+
+        // make sure you cant get rugged
+        if buyer_rec_acct.delegate.is_some() {
+            return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+        }
+
+        release_protected_tokens(
+            &protected_settlement,
+            escrow_token_account,
+            buyer_receipt_token_account,
+            rent_payer,
+            token_program,
+        )?;
+
+        pay_from_protected_settlement(
+            &protected_settlement.to_account_info(),
+            seller,
+            protected_settlement.seller_proceeds,
+        )?;
+
+        finish_settlement(&mut settlement_marker, SettlementStatus::Settled)?;
+        settlement_marker.exit(&crate::id())?;
+
+        close_account(&protected_settlement.to_account_info(), rent_payer)?;
+    }
+
+    Ok(())
+}
+
+/// Whether the protected settlement can be finalized now. Cheap checks only: a settlement that was
+/// already finalized or rolled back, has no settlement marker, is disputed or is still inside its
+/// buyer protection window is not due.
+fn protected_settlement_due(
+    auction_house: &Account<AuctionHouse>,
+    protected_settlement_info: &AccountInfo,
+    settlement_marker_info: &AccountInfo,
+    now: i64,
+) -> Result<bool> {
+    if protected_settlement_info.data_is_empty() || settlement_marker_info.data_is_empty() {
+        return Ok(false);
+    }
+
+    assert_owned_by(protected_settlement_info, &crate::id())?;
+    let protected_settlement = ProtectedSettlement::try_deserialize(
+        &mut protected_settlement_info.try_borrow_data()?.as_ref(),
+    )?;
+    assert_keys_equal(protected_settlement.auction_house, auction_house.key())?;
+    assert_derivation(
+        &crate::id(),
+        settlement_marker_info,
+        &[
+            SETTLEMENT_MARKER.as_bytes(),
+            protected_settlement_info.key.as_ref(),
+        ],
+    )?;
+
+    let settlement_marker =
+        SettlementMarker::try_deserialize(&mut settlement_marker_info.try_borrow_data()?.as_ref())?;
+
+    Ok(settlement_marker.status == SettlementStatus::Escrowed
+        && !protected_settlement.disputed
+        && now >= protected_settlement.release_at)
+}
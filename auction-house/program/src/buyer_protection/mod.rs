@@ -0,0 +1,224 @@
+//! Buyer protection window: settlements are parked in escrow so the buyer can cancel before
+//! delivery in exchange for a restocking fee.
+pub mod cancel;
+pub mod config;
+pub mod finalize;
+pub use cancel::*;
+pub use config::*;
+pub use finalize::*;
+
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use std::slice::Iter;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, pda::find_protected_settlement_address,
+    settlement_marker::start_settlement, utils::*, AuctionHouse, ProtectedSettlement,
+};
+
+/// The protected settlement PDA, the token account it owns for the purchased tokens and the
+/// settlement marker tracking its progress.
+pub struct ProtectedSettlementAccounts<'a, 'info> {
+    pub settlement: &'a AccountInfo<'info>,
+    pub escrow_token_account: &'a AccountInfo<'info>,
+    pub settlement_marker: &'a AccountInfo<'info>,
+    pub bump: u8,
+}
+
+/// Take the protected settlement accounts from `remaining_accounts` when the Auction House has a
+/// buyer protection window set. Returns `None` when buyer protection is disabled.
+///
+/// The settlement PDA, its associated token account for the mint and its settlement marker are
+/// passed after the mint cooldown account and ahead of any programmable NFT accounts.
+pub fn next_protected_settlement_accounts<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    token_mint: &Pubkey,
+    buyer: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<ProtectedSettlementAccounts<'a, 'info>>> {
+    if auction_house.buyer_protection_seconds == 0 {
+        return Ok(None);
+    }
+
+    let settlement = next_account_info(remaining_accounts)?;
+    let escrow_token_account = next_account_info(remaining_accounts)?;
+    let settlement_marker = next_account_info(remaining_accounts)?;
+    let (settlement_key, bump) =
+        find_protected_settlement_address(&auction_house.key(), token_mint, buyer);
+    assert_keys_equal(settlement_key, settlement.key())?;
+
+    Ok(Some(ProtectedSettlementAccounts {
+        settlement,
+        escrow_token_account,
+        settlement_marker,
+        bump,
+    }))
+}
+
+/// Create the protected settlement for a sale and the token account that will hold the purchased
+/// tokens until the settlement is finalized or canceled, and mark the settlement escrowed. The
+/// caller moves the seller's proceeds and the tokens into escrow.
+#[allow(clippy::too_many_arguments)]
+pub fn open_protected_settlement<'a, 'info>(
+    protected_settlement: &ProtectedSettlementAccounts<'a, 'info>,
+    auction_house: &Account<'info, AuctionHouse>,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    token_mint: &AccountInfo<'info>,
+    seller_token_account: &Pubkey,
+    token_size: u64,
+    seller_proceeds: u64,
+    fee_payer: &AccountInfo<'info>,
+    fee_payer_seeds: &[&[u8]],
+    ata_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+) -> Result<()> {
+    let settlement = protected_settlement.settlement;
+    let escrow_token_account = protected_settlement.escrow_token_account;
+
+    let auction_house_key = auction_house.key();
+    start_settlement(
+        protected_settlement.settlement_marker,
+        &settlement.key(),
+        &auction_house_key,
+        fee_payer,
+        fee_payer_seeds,
+        system_program,
+        rent,
+    )?;
+
+    let token_mint_key = token_mint.key();
+    let settlement_seeds = [
+        PROTECTED_SETTLEMENT.as_bytes(),
+        auction_house_key.as_ref(),
+        token_mint_key.as_ref(),
+        buyer.as_ref(),
+        &[protected_settlement.bump],
+    ];
+
+    create_or_allocate_account_raw(
+        crate::id(),
+        settlement,
+        rent,
+        system_program,
+        fee_payer,
+        PROTECTED_SETTLEMENT_SIZE,
+        fee_payer_seeds,
+        &settlement_seeds,
+    )?;
+
+    let release_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(auction_house.buyer_protection_seconds)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    let protected = ProtectedSettlement {
+        auction_house: auction_house_key,
+        buyer: *buyer,
+        seller: *seller,
+        token_mint: token_mint_key,
+        seller_token_account: *seller_token_account,
+        rent_payer: fee_payer.key(),
+        token_size,
+        seller_proceeds,
+        restocking_fee_basis_points: auction_house.restocking_fee_basis_points,
+        release_at,
+        bump: protected_settlement.bump,
+        disputed: false,
+    };
+    protected.try_serialize(&mut *settlement.try_borrow_mut_data()?)?;
+
+    if escrow_token_account.data_is_empty() {
+        make_ata(
+            escrow_token_account.clone(),
+            settlement.clone(),
+            token_mint.clone(),
+            fee_payer.clone(),
+            ata_program.clone(),
+            token_program.clone(),
+            system_program.clone(),
+            rent.clone(),
+            fee_payer_seeds,
+        )?;
+    }
+    assert_is_ata(escrow_token_account, &settlement.key(), &token_mint_key)?;
+
+    Ok(())
+}
+
+/// Move the escrowed tokens of `protected_settlement` to `destination` and close the escrow token
+/// account, returning its rent to the settlement's rent payer.
+pub fn release_protected_tokens<'info>(
+    protected_settlement: &Account<'info, ProtectedSettlement>,
+    escrow_token_account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    rent_payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let settlement_seeds = [
+        PROTECTED_SETTLEMENT.as_bytes(),
+        protected_settlement.auction_house.as_ref(),
+        protected_settlement.token_mint.as_ref(),
+        protected_settlement.buyer.as_ref(),
+        &[protected_settlement.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            escrow_token_account.key,
+            destination.key,
+            &protected_settlement.key(),
+            &[],
+            protected_settlement.token_size,
+        )?,
+        &[
+            escrow_token_account.clone(),
+            destination.clone(),
+            protected_settlement.to_account_info(),
+            token_program.clone(),
+        ],
+        &[&settlement_seeds],
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            token_program.key,
+            escrow_token_account.key,
+            rent_payer.key,
+            &protected_settlement.key(),
+            &[],
+        )?,
+        &[
+            escrow_token_account.clone(),
+            rent_payer.clone(),
+            protected_settlement.to_account_info(),
+            token_program.clone(),
+        ],
+        &[&settlement_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Pay `amount` of the escrowed proceeds held by the protected settlement to `destination`.
+pub fn pay_from_protected_settlement<'info>(
+    settlement: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let settlement_lamports = settlement
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let destination_lamports = destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    **settlement.try_borrow_mut_lamports()? = settlement_lamports;
+    **destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    Ok(())
+}
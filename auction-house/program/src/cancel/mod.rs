@@ -1,7 +1,10 @@
 use anchor_lang::{prelude::*, solana_program::program::invoke, AnchorDeserialize};
 use solana_program::program_memory::sol_memset;
 
-use crate::{constants::*, errors::*, utils::*, AuctionHouse, AuthorityScope, *};
+use crate::{
+    constants::*, errors::*, public_bid_index::unindex_public_bid, utils::*, AuctionHouse,
+    AuthorityScope, *,
+};
 
 use mpl_token_metadata::instruction::{builders::RevokeBuilder, InstructionBuilder, RevokeArgs};
 
@@ -182,8 +185,11 @@ pub fn cancel<'info>(
 ) -> Result<()> {
     let auction_house = &ctx.accounts.auction_house;
 
-    // If it has an auctioneer authority delegated must use auctioneer_* handler.
-    if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::Cancel as usize] {
+    // If it has an auctioneer authority delegated must use auctioneer_* handler. Auctioneer
+    // listings are priced at u64::MAX and are always canceled through the auctioneer.
+    if auction_house.has_auctioneer
+        && (auction_house.scopes[AuthorityScope::Cancel as usize] || buyer_price == u64::MAX)
+    {
         return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
     }
 
@@ -274,6 +280,12 @@ fn cancel_logic<'c, 'info>(
     )?;
 
     let remaining_accounts = &mut remaining_accounts.iter();
+    unindex_public_bid(
+        remaining_accounts,
+        &auction_house_key,
+        &token_account.mint,
+        &trade_state.key(),
+    )?;
 
     if token_account.owner == wallet.key() && wallet.is_signer {
         match next_account_info(remaining_accounts) {
@@ -354,6 +366,7 @@ fn cancel_logic<'c, 'info>(
     }
 
     let curr_lamp = trade_state.lamports();
+    let trade_state_size = trade_state.data_len();
     **trade_state.lamports.borrow_mut() = 0;
 
     **fee_payer.lamports.borrow_mut() = fee_payer
@@ -362,7 +375,7 @@ fn cancel_logic<'c, 'info>(
         .ok_or(AuctionHouseError::NumericalOverflow)?;
 
     #[allow(clippy::explicit_auto_deref)]
-    sol_memset(*trade_state.try_borrow_mut_data()?, 0, TRADE_STATE_SIZE);
+    sol_memset(*trade_state.try_borrow_mut_data()?, 0, trade_state_size);
 
     Ok(())
 }
@@ -0,0 +1,208 @@
+//! Per-house compliance hook invoked on every buy and sell, so regulated venues can enforce
+//! jurisdiction policy on-chain.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke},
+};
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, AuthorityAction, ComplianceConfig,
+};
+
+/// First 8 bytes of the instruction data sent to a compliance program, followed by the borsh
+/// encoded [`ComplianceCheckArgs`].
+pub const COMPLIANCE_CHECK_DISCRIMINATOR: [u8; 8] = [184, 237, 214, 94, 122, 250, 220, 176];
+
+/// The trade a compliance program is asked to approve.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComplianceAction {
+    Buy,
+    Sell,
+}
+
+/// Arguments passed to the compliance program on each check.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ComplianceCheckArgs {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub token_mint: Pubkey,
+    pub jurisdiction_code: [u8; 2],
+    pub action: ComplianceAction,
+    pub price: u64,
+}
+
+/// Accounts for the [`create_compliance_config` handler](auction_house/fn.create_compliance_config.html).
+#[derive(Accounts)]
+pub struct CreateComplianceConfig<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The compliance config PDA for the house.
+    #[account(
+        init,
+        payer=authority,
+        space=COMPLIANCE_CONFIG_SIZE,
+        seeds=[COMPLIANCE_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub compliance_config: Box<Account<'info, ComplianceConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_compliance_config` handler](auction_house/fn.update_compliance_config.html).
+#[derive(Accounts)]
+pub struct UpdateComplianceConfig<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The compliance config PDA for the house.
+    #[account(
+        mut,
+        seeds=[COMPLIANCE_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump=compliance_config.bump,
+        has_one=auction_house
+    )]
+    pub compliance_config: Box<Account<'info, ComplianceConfig>>,
+}
+
+pub fn create_compliance_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateComplianceConfig<'info>>,
+    jurisdiction_code: [u8; 2],
+    compliance_program: Pubkey,
+) -> Result<()> {
+    let compliance_config = &mut ctx.accounts.compliance_config;
+    compliance_config.auction_house = ctx.accounts.auction_house.key();
+    compliance_config.jurisdiction_code = jurisdiction_code;
+    compliance_config.compliance_program = compliance_program;
+    compliance_config.bump = *ctx
+        .bumps
+        .get("compliance_config")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.auction_house.has_compliance_hook = compliance_program != Pubkey::default();
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetComplianceConfig,
+        u16::from_le_bytes(jurisdiction_code) as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+pub fn update_compliance_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateComplianceConfig<'info>>,
+    jurisdiction_code: [u8; 2],
+    compliance_program: Pubkey,
+) -> Result<()> {
+    let compliance_config = &mut ctx.accounts.compliance_config;
+    compliance_config.jurisdiction_code = jurisdiction_code;
+    compliance_config.compliance_program = compliance_program;
+
+    ctx.accounts.auction_house.has_compliance_hook = compliance_program != Pubkey::default();
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetComplianceConfig,
+        u16::from_le_bytes(jurisdiction_code) as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Ask the house's compliance program to approve `action` by `wallet`. Does nothing when the
+/// Auction House has no compliance hook.
+///
+/// When a hook is set, the compliance config and the compliance program are passed in
+/// `remaining_accounts`, ahead of any other optional accounts of the instruction. The compliance
+/// program receives the wallet and the compliance config, both read-only and unsigned.
+pub fn assert_compliance<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    wallet: &AccountInfo<'info>,
+    token_mint: &Pubkey,
+    action: ComplianceAction,
+    price: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auction_house.has_compliance_hook {
+        return Ok(());
+    }
+
+    let compliance_config_info = next_account_info(remaining_accounts)?;
+    let compliance_program = next_account_info(remaining_accounts)?;
+
+    let compliance_config: Account<ComplianceConfig> = Account::try_from(compliance_config_info)?;
+    assert_keys_equal(compliance_config.auction_house, auction_house.key())?;
+    assert_keys_equal(
+        compliance_program.key(),
+        compliance_config.compliance_program,
+    )
+    .map_err(|_| AuctionHouseError::InvalidComplianceProgram)?;
+
+    let mut data = COMPLIANCE_CHECK_DISCRIMINATOR.to_vec();
+    ComplianceCheckArgs {
+        auction_house: auction_house.key(),
+        wallet: wallet.key(),
+        token_mint: *token_mint,
+        jurisdiction_code: compliance_config.jurisdiction_code,
+        action,
+        price,
+    }
+    .serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: compliance_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(wallet.key(), false),
+            AccountMeta::new_readonly(compliance_config_info.key(), false),
+        ],
+        data,
+    };
+
+    // The wallet's signature is never forwarded and every account is read-only, so the hook can
+    // inspect the trade it is approving but not act with any of its parties' authority.
+    invoke(
+        &ix,
+        &[
+            wallet.clone(),
+            compliance_config_info.clone(),
+            compliance_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -1,20 +1,117 @@
+use anchor_lang::prelude::constant;
+
+// Seeds shared with the Auctioneer program through `mpl_auction_common::seeds`, declared here as
+// well so they are listed in the IDL.
+#[constant]
 pub const PREFIX: &str = "auction_house";
+#[constant]
 pub const FEE_PAYER: &str = "fee_payer";
+#[constant]
 pub const TREASURY: &str = "treasury";
+#[constant]
 pub const SIGNER: &str = "signer";
+#[constant]
+pub const AUCTIONEER: &str = "auctioneer";
+
+#[constant]
 pub const PURCHASE_RECEIPT_PREFIX: &str = "purchase_receipt";
+#[constant]
 pub const BID_RECEIPT_PREFIX: &str = "bid_receipt";
+#[constant]
 pub const LISTING_RECEIPT_PREFIX: &str = "listing_receipt";
-pub const AUCTIONEER: &str = "auctioneer";
+#[constant]
+pub const FEE_STAKE: &str = "fee_stake";
+#[constant]
+pub const FEE_STAKE_VAULT: &str = "fee_stake_vault";
+#[constant]
+pub const MINT_COOLDOWN: &str = "mint_cooldown";
+#[constant]
+pub const AUTHORITY_LOG: &str = "authority_log";
+#[constant]
+pub const PROTECTED_SETTLEMENT: &str = "protected_settlement";
+#[constant]
+pub const DISPUTE_RECORD: &str = "dispute_record";
+#[constant]
+pub const LIEN: &str = "lien";
+#[constant]
+pub const FROZEN_PROCEEDS: &str = "frozen_proceeds";
+#[constant]
+pub const FROZEN_PROCEEDS_VAULT: &str = "frozen_proceeds_vault";
+#[constant]
+pub const LISTING_FEE: &str = "listing_fee";
+#[constant]
+pub const DEPOSIT_AUTHORITY: &str = "deposit_authority";
+#[constant]
+pub const AUCTIONEER_ALLOWLIST: &str = "auctioneer_allowlist";
+#[constant]
+pub const PUBLIC_BID_INDEX: &str = "public_bid_index";
+#[constant]
+pub const BID_EXPIRY: &str = "bid_expiry";
+#[constant]
+pub const COMPLIANCE_CONFIG: &str = "compliance_config";
+#[constant]
+pub const RECEIPT_TREE: &str = "receipt_tree";
+#[constant]
+pub const TAX_WITHHOLDING: &str = "tax_withholding";
+#[constant]
+pub const WANTED_LISTING: &str = "wanted_listing";
+#[constant]
+pub const FEE_SWEEP_CONFIG: &str = "fee_sweep_config";
+#[constant]
+pub const TREASURY_WITHDRAWAL_LIMIT: &str = "treasury_withdrawal_limit";
+#[constant]
+pub const BID_TICKET_CONFIG: &str = "bid_ticket_config";
+#[constant]
+pub const BID_TICKET_BURN: &str = "bid_ticket_burn";
+#[constant]
+pub const SETTLEMENT_MARKER: &str = "settlement_marker";
+#[constant]
+pub const MARKETPLACE_BINDING: &str = "marketplace_binding";
+#[constant]
+pub const AUCTIONEER_STATS: &str = "auctioneer_stats";
+#[constant]
+pub const VOUCHER: &str = "voucher";
+#[constant]
+pub const LEADERBOARD: &str = "leaderboard";
+#[constant]
+pub const ROYALTY_BONUS: &str = "royalty_bonus";
+#[constant]
+pub const SHARED_CUSTODY: &str = "shared_custody";
+#[constant]
+pub const DELAYED_PAYOUT: &str = "delayed_payout";
+#[constant]
+pub const PRICE_HISTORY: &str = "price_history";
+#[constant]
+pub const SPENDING_CAP: &str = "spending_cap";
+#[constant]
+pub const OMNIBUS_CUSTODIAN: &str = "omnibus_custodian";
+#[constant]
+pub const TRADE_ATTRIBUTION: &str = "trade_attribution";
+#[constant]
+pub const ACTIVE_LISTING: &str = "active_listing";
+#[constant]
+pub const TRADE_SEQUENCE: &str = "trade_sequence";
+#[constant]
+pub const HOUSE_NOTICE: &str = "house_notice";
+#[constant]
 pub const TRADE_STATE_SIZE: usize = 1;
-pub const MAX_NUM_SCOPES: usize = 7;
+#[constant]
+pub const CURRENCY_LOCKED_TRADE_STATE_SIZE: usize = 1 +     // bump
+32 +                                                        // treasury mint
+1                                                           // treasury mint decimals
+;
+pub const MAX_NUM_SCOPES: usize = 10;
+#[constant]
 pub const AUCTIONEER_SIZE: usize = 8 +                      // Anchor discriminator/sighash
 32 +                                                        // Auctioneer authority
 32 +                                                        // Auction house instance
 1 +                                                         // bump
-63                                                          // Padding
+32 +                                                        // auctioneer program
+1 +                                                         // has stats
+30                                                          // Padding
 ;
 
+#[constant]
 pub const AUCTION_HOUSE_SIZE: usize = 8 +                   // key
 32 +                                                        // fee Payer
 32 +                                                        // treasury
@@ -33,5 +130,457 @@ pub const AUCTION_HOUSE_SIZE: usize = 8 +                   // key
 1 +                                                         // has external auctioneer program as an authority
 32 +                                                         // auctioneer address
 MAX_NUM_SCOPES +                                            // Array of AuthorityScope bools
-172                                                         // padding
+8 +                                                         // relist cooldown seconds
+1 +                                                         // has authority log
+8 +                                                         // buyer protection seconds
+2 +                                                         // restocking fee basis points
+32 +                                                        // arbiter
+1 +                                                         // allow seller financing
+9 +                                                         // min listing price
+9 +                                                         // max listing price
+1 +                                                         // has auctioneer allowlist
+1 +                                                         // has compliance hook
+1 +                                                         // has receipt tree
+1 +                                                         // has tax withholding
+8 +                                                         // low fee balance threshold
+1 +                                                         // has bid ticket gate
+1 +                                                         // allow wallet bound receipts
+1 +                                                         // is immutable
+32 +                                                        // previous auctioneer address
+8 +                                                         // previous auctioneer expires at
+1 +                                                         // rounding policy
+8 +                                                         // payout delay seconds
+1 +                                                         // token standard policy
+1 +                                                         // requires verified creators
+1 +                                                         // single active listing
+1 +                                                         // has trade sequence
+1 +                                                         // locks listing currency
+30                                                          // padding
+;
+
+#[constant]
+pub const FEE_STAKE_CONFIG_SIZE: usize = 8 +               // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // stake mint
+32 +                                                        // stake vault
+8 +                                                         // minimum stake amount
+2 +                                                         // discounted seller fee basis points
+8 +                                                         // lockup seconds
+1 +                                                         // bump
+1 +                                                         // vault bump
+64                                                          // Padding
+;
+
+#[constant]
+pub const FEE_STAKE_SIZE: usize = 8 +                      // Anchor discriminator/sighash
+32 +                                                        // wallet
+32 +                                                        // Auction house instance
+8 +                                                         // staked amount
+8 +                                                         // last staked at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const MINT_COOLDOWN_SIZE: usize = 8 +                  // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // mint
+8 +                                                         // last settled at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+pub const AUTHORITY_LOG_CAPACITY: usize = 64;
+#[constant]
+pub const AUTHORITY_LOG_ENTRY_SIZE: usize = 1 +            // action
+32 +                                                        // authority
+8 +                                                         // value
+8                                                           // timestamp
+;
+
+#[constant]
+pub const AUTHORITY_LOG_SIZE: usize = 8 +                  // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+1 +                                                         // bump
+2 +                                                         // next index
+8 +                                                         // total entries
+4 +                                                         // entries vec length
+AUTHORITY_LOG_CAPACITY * AUTHORITY_LOG_ENTRY_SIZE           // entries
+;
+
+#[constant]
+pub const PROTECTED_SETTLEMENT_SIZE: usize = 8 +           // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // buyer
+32 +                                                        // seller
+32 +                                                        // token mint
+32 +                                                        // seller token account
+32 +                                                        // rent payer
+8 +                                                         // token size
+8 +                                                         // seller proceeds
+2 +                                                         // restocking fee basis points
+8 +                                                         // release at
+1 +                                                         // bump
+1 +                                                         // disputed
+31                                                          // Padding
+;
+
+#[constant]
+pub const DISPUTE_RECORD_SIZE: usize = 8 +                 // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // protected settlement
+32 +                                                        // opened by
+8 +                                                         // opened at
+2 +                                                         // resolution
+9 +                                                         // resolved at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const LIEN_SIZE: usize = 8 +                           // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // token mint
+32 +                                                        // buyer
+32 +                                                        // seller
+8 +                                                         // outstanding balance
+8 +                                                         // created at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const FROZEN_PROCEEDS_SIZE: usize = 8 +                // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // recipient
+8 +                                                         // amount
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const LISTING_FEE_SIZE: usize = 8 +                    // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // seller trade state
+2 +                                                         // fee basis points
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const ROYALTY_BONUS_SIZE: usize = 8 +                  // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // seller trade state
+32 +                                                        // wallet
+2 +                                                         // bonus basis points
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const DELAYED_PAYOUT_SIZE: usize = 8 +                 // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // seller
+32 +                                                        // buyer
+32 +                                                        // token mint
+32 +                                                        // rent payer
+8 +                                                         // amount
+8 +                                                         // release at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const DEPOSIT_AUTHORITY_SIZE: usize = 8 +              // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // wallet
+32 +                                                        // depositor
+8 +                                                         // approved at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+pub const MAX_AUCTIONEER_PROGRAMS: usize = 8;
+#[constant]
+pub const AUCTIONEER_ALLOWLIST_SIZE: usize = 8 +           // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+1 +                                                         // bump
+4 +                                                         // programs vec length
+MAX_AUCTIONEER_PROGRAMS * 32 +                              // programs
+32                                                          // Padding
+;
+
+#[constant]
+pub const AUCTIONEER_STATS_SIZE: usize = 8 +               // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // auctioneer authority
+8 +                                                         // auctions run
+8 +                                                         // settled volume
+8 +                                                         // fees earned
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+pub const MAX_INDEXED_PUBLIC_BIDS: usize = 16;
+#[constant]
+pub const INDEXED_PUBLIC_BID_SIZE: usize = 32 +            // trade state
+32 +                                                        // wallet
+8 +                                                         // buyer price
+8                                                           // token size
+;
+
+#[constant]
+pub const PUBLIC_BID_INDEX_SIZE: usize = 8 +               // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // token mint
+1 +                                                         // bump
+4 +                                                         // bids vec length
+MAX_INDEXED_PUBLIC_BIDS * INDEXED_PUBLIC_BID_SIZE +         // bids
+32                                                          // Padding
+;
+
+#[constant]
+pub const BID_EXPIRY_SIZE: usize = 8 +                     // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // wallet
+32 +                                                        // trade state
+8 +                                                         // buyer price
+8 +                                                         // expires at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const COMPLIANCE_CONFIG_SIZE: usize = 8 +              // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+2 +                                                         // jurisdiction code
+32 +                                                        // compliance program
+1 +                                                         // bump
+32                                                          // Padding
 ;
+
+#[constant]
+pub const TAX_WITHHOLDING_SIZE: usize = 8 +                // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+2 +                                                         // withholding basis points
+32 +                                                        // recipient
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const WANTED_LISTING_SIZE: usize = 8 +                 // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // buyer
+1 + 32 +                                                    // criteria
+8 +                                                         // start price
+8 +                                                         // floor price
+8 +                                                         // start time
+8 +                                                         // end time
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const FEE_SWEEP_CONFIG_SIZE: usize = 8 +               // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+8 +                                                         // threshold
+8 +                                                         // total swept
+8 +                                                         // last swept at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const TREASURY_WITHDRAWAL_LIMIT_SIZE: usize = 8 +      // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+8 +                                                         // max per period
+8 +                                                         // period seconds
+8 +                                                         // period start
+8 +                                                         // withdrawn in period
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+pub const MAX_TREASURY_WITHDRAWAL_PERIOD_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+#[constant]
+pub const BID_TICKET_CONFIG_SIZE: usize = 8 +              // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // collection
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const BID_TICKET_BURN_SIZE: usize = 8 +                // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // trade state
+32 +                                                        // wallet
+32 +                                                        // ticket mint
+8 +                                                         // burned at
+1                                                           // bump
+;
+
+#[constant]
+pub const SETTLEMENT_MARKER_SIZE: usize = 8 +              // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // protected settlement
+1 +                                                         // status
+4 +                                                         // attempts
+8 +                                                         // updated at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+pub const MAX_MARKETPLACE_BINDING_HOUSES: usize = 8;
+#[constant]
+pub const MARKETPLACE_BINDING_SIZE: usize = 8 +            // Anchor discriminator/sighash
+32 +                                                        // collection
+1 +                                                         // bump
+4 +                                                         // approved houses vec length
+MAX_MARKETPLACE_BINDING_HOUSES * 32 +                       // approved houses
+32                                                          // Padding
+;
+
+#[constant]
+pub const VOUCHER_SIZE: usize = 8 +                        // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // creator
+8 +                                                         // id
+8 +                                                         // price
+8 +                                                         // redeem deadline
+32 +                                                        // buyer
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
+#[constant]
+pub const LEADERBOARD_ENTRY_SIZE: usize = 32 +             // wallet
+8                                                           // volume
+;
+
+#[constant]
+pub const LEADERBOARD_SIZE: usize = 8 +                    // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+8 +                                                         // epoch
+8 +                                                         // starts at
+8 +                                                         // ends at
+1 +                                                         // bump
+4 +                                                         // buyers vec length
+MAX_LEADERBOARD_ENTRIES * LEADERBOARD_ENTRY_SIZE +          // buyers
+4 +                                                         // sellers vec length
+MAX_LEADERBOARD_ENTRIES * LEADERBOARD_ENTRY_SIZE +          // sellers
+32                                                          // Padding
+;
+
+pub const MAX_CO_OWNERS: usize = 10;
+#[constant]
+pub const CO_OWNER_SIZE: usize = 32 +                       // wallet
+2                                                           // share basis points
+;
+
+#[constant]
+pub const SHARED_CUSTODY_SIZE: usize = 8 +                  // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // token mint
+32 +                                                        // depositor
+1 +                                                         // threshold
+8 +                                                         // listing price
+2 +                                                         // approvals
+1 +                                                         // is listed
+1 +                                                         // bump
+4 +                                                         // owners vec length
+MAX_CO_OWNERS * CO_OWNER_SIZE +                             // owners
+32                                                          // Padding
+;
+
+pub const MAX_PRICE_HISTORY_ENTRIES: usize = 32;
+#[constant]
+pub const PRICE_POINT_SIZE: usize = 8 +                     // price
+8                                                           // timestamp
+;
+
+#[constant]
+pub const PRICE_HISTORY_SIZE: usize = 8 +                   // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // token mint
+1 +                                                         // bump
+2 +                                                         // next index
+4 +                                                         // entries vec length
+MAX_PRICE_HISTORY_ENTRIES * PRICE_POINT_SIZE +              // entries
+32                                                          // Padding
+;
+
+#[constant]
+pub const SPENDING_CAP_SIZE: usize = 8 +                    // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // wallet
+32 +                                                        // frontend
+8 +                                                         // cap
+8 +                                                         // spent
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const OMNIBUS_CUSTODIAN_SIZE: usize = 8 +               // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // custodian
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const TRADE_ATTRIBUTION_SIZE: usize = 8 +               // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // custodian
+32 +                                                        // trade state
+32 +                                                        // sub account
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const ACTIVE_LISTING_SIZE: usize = 8 +                  // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+32 +                                                        // seller
+32 +                                                        // mint
+32 +                                                        // trade state
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const TRADE_SEQUENCE_SIZE: usize = 8 +                  // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+8 +                                                         // last sequence
+32 +                                                        // last trade state
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+#[constant]
+pub const HOUSE_NOTICE_SIZE: usize = 8 +                    // Anchor discriminator/sighash
+32 +                                                        // Auction house instance
+1 +                                                         // status
+32 +                                                        // message hash
+8 +                                                         // expires at
+8 +                                                         // updated at
+1 +                                                         // bump
+32                                                          // Padding
+;
+
+/// Token standard bits of an Auction House's `token_standard_policy`.
+#[constant]
+pub const TOKEN_STANDARD_NFT: u8 = 1 << 0;
+#[constant]
+pub const TOKEN_STANDARD_SFT: u8 = 1 << 1;
+#[constant]
+pub const TOKEN_STANDARD_FUNGIBLE: u8 = 1 << 2;
+#[constant]
+pub const TOKEN_STANDARD_PNFT: u8 = 1 << 3;
+#[constant]
+pub const ALL_TOKEN_STANDARDS: u8 =
+    TOKEN_STANDARD_NFT | TOKEN_STANDARD_SFT | TOKEN_STANDARD_FUNGIBLE | TOKEN_STANDARD_PNFT;
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, DepositAuthority};
+
+/// Accounts for the [`approve_deposit_authority` handler](auction_house/fn.approve_deposit_authority.html).
+#[derive(Accounts)]
+#[instruction(depositor: Pubkey)]
+pub struct ApproveDepositAuthority<'info> {
+    /// User wallet account whose escrow the depositor may fund.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Deposit authority PDA account recording the approval.
+    #[account(
+        init,
+        payer=wallet,
+        space=DEPOSIT_AUTHORITY_SIZE,
+        seeds=[
+            DEPOSIT_AUTHORITY.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            depositor.as_ref()
+        ],
+        bump
+    )]
+    pub deposit_authority: Box<Account<'info, DepositAuthority>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Approve `depositor` to deposit into the wallet's escrow payment account.
+pub fn approve_deposit_authority<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApproveDepositAuthority<'info>>,
+    depositor: Pubkey,
+) -> Result<()> {
+    let deposit_authority = &mut ctx.accounts.deposit_authority;
+    deposit_authority.auction_house = ctx.accounts.auction_house.key();
+    deposit_authority.wallet = ctx.accounts.wallet.key();
+    deposit_authority.depositor = depositor;
+    deposit_authority.approved_at = Clock::get()?.unix_timestamp;
+    deposit_authority.bump = *ctx
+        .bumps
+        .get("deposit_authority")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`revoke_deposit_authority` handler](auction_house/fn.revoke_deposit_authority.html).
+#[derive(Accounts)]
+pub struct RevokeDepositAuthority<'info> {
+    /// User wallet account that approved the depositor.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Deposit authority PDA account recording the approval.
+    #[account(
+        mut,
+        seeds=[
+            DEPOSIT_AUTHORITY.as_bytes(),
+            deposit_authority.auction_house.as_ref(),
+            wallet.key().as_ref(),
+            deposit_authority.depositor.as_ref()
+        ],
+        bump=deposit_authority.bump,
+        has_one=wallet,
+        close=wallet
+    )]
+    pub deposit_authority: Box<Account<'info, DepositAuthority>>,
+}
+
+/// Revoke a depositor's approval, returning the approval's rent to the wallet.
+pub fn revoke_deposit_authority<'info>(
+    _ctx: Context<'_, '_, '_, 'info, RevokeDepositAuthority<'info>>,
+) -> Result<()> {
+    Ok(())
+}
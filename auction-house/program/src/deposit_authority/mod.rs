@@ -0,0 +1,6 @@
+//! Deposit authorities: a wallet approves another signer, typically a PDA of a program that
+//! custodies funds, to top up the wallet's escrow on its behalf.
+pub mod config;
+pub mod on_behalf;
+pub use config::*;
+pub use on_behalf::*;
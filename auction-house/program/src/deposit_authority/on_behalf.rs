@@ -0,0 +1,177 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, AuthorityScope,
+    DepositAuthority,
+};
+
+/// Accounts for the [`deposit_on_behalf` handler](auction_house/fn.deposit_on_behalf.html).
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8)]
+pub struct DepositOnBehalf<'info> {
+    /// Approved depositor, typically a PDA signing through CPI. Pays for the escrow account if it
+    /// does not exist yet.
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// CHECK: Validated against the deposit authority.
+    /// User wallet account whose escrow is funded.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in deposit_on_behalf.
+    /// Depositor SOL or SPL account to transfer funds from.
+    #[account(mut)]
+    pub payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in deposit_on_behalf.
+    /// SPL token account transfer authority.
+    pub transfer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Deposit authority PDA account approving the depositor.
+    #[account(
+        seeds=[
+            DEPOSIT_AUTHORITY.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            depositor.key().as_ref()
+        ],
+        bump=deposit_authority.bump,
+        has_one=auction_house,
+        has_one=wallet,
+        has_one=depositor
+    )]
+    pub deposit_authority: Box<Account<'info, DepositAuthority>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Deposit `amount` from an approved depositor into a wallet's escrow payment account.
+pub fn deposit_on_behalf<'info>(
+    ctx: Context<'_, '_, '_, 'info, DepositOnBehalf<'info>>,
+    escrow_payment_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let depositor = &ctx.accounts.depositor;
+    let payment_account = &ctx.accounts.payment_account;
+    let transfer_authority = &ctx.accounts.transfer_authority;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let system_program = &ctx.accounts.system_program;
+    let token_program = &ctx.accounts.token_program;
+
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::Deposit as usize] {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    if escrow_payment_bump
+        != *ctx
+            .bumps
+            .get("escrow_payment_account")
+            .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?
+    {
+        return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
+    }
+
+    let auction_house_key = auction_house.key();
+    let wallet_key = ctx.accounts.wallet.key();
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
+    create_program_token_account_if_not_present(
+        escrow_payment_account,
+        system_program,
+        &depositor.to_account_info(),
+        token_program,
+        treasury_mint,
+        &auction_house.to_account_info(),
+        &ctx.accounts.rent,
+        &escrow_signer_seeds,
+        &[],
+        is_native,
+    )?;
+
+    if !is_native {
+        assert_is_ata(payment_account, &depositor.key(), &treasury_mint.key())?;
+        invoke(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                &payment_account.key(),
+                &escrow_payment_account.key(),
+                &transfer_authority.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                escrow_payment_account.to_account_info(),
+                payment_account.to_account_info(),
+                token_program.to_account_info(),
+                transfer_authority.to_account_info(),
+            ],
+        )?;
+    } else {
+        assert_keys_equal(payment_account.key(), depositor.key())?;
+
+        // Get rental exemption shortfall and then add to deposit amount.
+        let rent_shortfall = verify_deposit(escrow_payment_account.to_account_info(), 0)?;
+        let checked_amount = amount
+            .checked_add(rent_shortfall)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+        invoke(
+            &system_instruction::transfer(
+                &payment_account.key(),
+                &escrow_payment_account.key(),
+                checked_amount,
+            ),
+            &[
+                escrow_payment_account.to_account_info(),
+                payment_account.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
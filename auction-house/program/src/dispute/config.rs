@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{authority_log::record_authority_action, constants::*, AuctionHouse, AuthorityAction};
+
+/// Accounts for the [`set_arbiter` handler](auction_house/fn.set_arbiter.html).
+#[derive(Accounts)]
+pub struct SetArbiter<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_arbiter<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetArbiter<'info>>,
+    arbiter: Pubkey,
+) -> Result<()> {
+    ctx.accounts.auction_house.arbiter = arbiter;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetArbiter,
+        0,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
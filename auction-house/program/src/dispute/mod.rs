@@ -0,0 +1,7 @@
+//! Disputes on protected settlements, resolved by the house arbiter toward the buyer or seller.
+pub mod config;
+pub mod open;
+pub mod resolve;
+pub use config::*;
+pub use open::*;
+pub use resolve::*;
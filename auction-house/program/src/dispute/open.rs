@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::AuctionHouseError, DisputeRecord, ProtectedSettlement};
+
+/// Accounts for the [`open_dispute` handler](auction_house/fn.open_dispute.html).
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    /// Buyer or seller of the protected settlement.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Protected settlement PDA being disputed.
+    #[account(
+        mut,
+        seeds = [
+            PROTECTED_SETTLEMENT.as_bytes(),
+            protected_settlement.auction_house.as_ref(),
+            protected_settlement.token_mint.as_ref(),
+            protected_settlement.buyer.as_ref()
+        ],
+        bump=protected_settlement.bump
+    )]
+    pub protected_settlement: Box<Account<'info, ProtectedSettlement>>,
+
+    /// Dispute record PDA tracking the dispute and its outcome.
+    #[account(
+        init,
+        payer=wallet,
+        space=DISPUTE_RECORD_SIZE,
+        seeds=[DISPUTE_RECORD.as_bytes(), protected_settlement.key().as_ref()],
+        bump
+    )]
+    pub dispute_record: Box<Account<'info, DisputeRecord>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Flag a protected settlement as disputed during its buyer protection window. A disputed
+/// settlement can no longer be canceled or finalized until the arbiter resolves it.
+pub fn open_dispute<'info>(ctx: Context<'_, '_, '_, 'info, OpenDispute<'info>>) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let protected_settlement = &mut ctx.accounts.protected_settlement;
+
+    if wallet.key() != protected_settlement.buyer && wallet.key() != protected_settlement.seller {
+        return Err(AuctionHouseError::NotSettlementParty.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= protected_settlement.release_at {
+        return Err(AuctionHouseError::BuyerProtectionWindowClosed.into());
+    }
+
+    protected_settlement.disputed = true;
+
+    let dispute_record = &mut ctx.accounts.dispute_record;
+    dispute_record.auction_house = protected_settlement.auction_house;
+    dispute_record.protected_settlement = protected_settlement.key();
+    dispute_record.opened_by = wallet.key();
+    dispute_record.opened_at = now;
+    dispute_record.resolution = None;
+    dispute_record.resolved_at = None;
+    dispute_record.bump = *ctx
+        .bumps
+        .get("dispute_record")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    buyer_protection::{pay_from_protected_settlement, release_protected_tokens},
+    constants::*,
+    errors::AuctionHouseError,
+    settlement_marker::finish_settlement,
+    utils::*,
+    wallet_receipt::assert_buyer_receipt_account,
+    AuctionHouse, DisputeRecord, DisputeResolution, ProtectedSettlement, SettlementMarker,
+    SettlementStatus,
+};
+
+/// Accounts for the [`resolve_dispute` handler](auction_house/fn.resolve_dispute.html).
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// Auction House arbiter, also paying for the buyer's token account if needed.
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Seller user wallet account.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the protected settlement.
+    /// Account that paid the rent for the protected settlement.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Seller token account the escrowed tokens are returned to when resolving for the buyer.
+    #[account(mut)]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Created if needed and validated in resolve_dispute.
+    /// Buyer SPL token account receiving the tokens when resolving for the seller.
+    #[account(mut)]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// Token account owned by the protected settlement holding the purchased tokens.
+    #[account(
+        mut,
+        constraint = escrow_token_account.owner == protected_settlement.key(),
+        constraint = escrow_token_account.mint == token_mint.key()
+    )]
+    pub escrow_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account for the SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Protected settlement PDA under dispute.
+    #[account(
+        mut,
+        seeds = [
+            PROTECTED_SETTLEMENT.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump=protected_settlement.bump,
+        has_one=auction_house,
+        has_one=buyer,
+        has_one=seller,
+        has_one=token_mint,
+        has_one=seller_token_account,
+        has_one=rent_payer,
+        close=rent_payer
+    )]
+    pub protected_settlement: Box<Account<'info, ProtectedSettlement>>,
+
+    /// Settlement marker PDA of the protected settlement.
+    #[account(
+        mut,
+        seeds=[SETTLEMENT_MARKER.as_bytes(), protected_settlement.key().as_ref()],
+        bump=settlement_marker.bump,
+        has_one=protected_settlement
+    )]
+    pub settlement_marker: Box<Account<'info, SettlementMarker>>,
+
+    /// Dispute record PDA tracking the dispute and its outcome.
+    #[account(
+        mut,
+        seeds=[DISPUTE_RECORD.as_bytes(), protected_settlement.key().as_ref()],
+        bump=dispute_record.bump,
+        has_one=protected_settlement
+    )]
+    pub dispute_record: Box<Account<'info, DisputeRecord>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Resolve a disputed settlement. Resolving for the buyer returns the tokens to the seller and
+/// refunds the buyer in full; resolving for the seller completes the sale as if finalized.
+pub fn resolve_dispute<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResolveDispute<'info>>,
+    resolution: DisputeResolution,
+) -> Result<()> {
+    let protected_settlement = &ctx.accounts.protected_settlement;
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+
+    if ctx.accounts.auction_house.arbiter != ctx.accounts.arbiter.key() {
+        return Err(AuctionHouseError::InvalidArbiter.into());
+    }
+
+    let (token_destination, proceeds_destination) = match resolution {
+        DisputeResolution::Buyer => (
+            ctx.accounts.seller_token_account.to_account_info(),
+            ctx.accounts.buyer.to_account_info(),
+        ),
+        DisputeResolution::Seller => {
+            if buyer_receipt_token_account.data_is_empty() {
+                make_ata(
+                    buyer_receipt_token_account.to_account_info(),
+                    ctx.accounts.buyer.to_account_info(),
+                    ctx.accounts.token_mint.to_account_info(),
+                    ctx.accounts.arbiter.to_account_info(),
+                    ctx.accounts.ata_program.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                    ctx.accounts.rent.to_account_info(),
+                    &[],
+                )?;
+            }
+
+            let buyer_rec_acct = assert_buyer_receipt_account(
+                &ctx.accounts.auction_house,
+                &buyer_receipt_token_account.to_account_info(),
+                &protected_settlement.buyer,
+                &protected_settlement.token_mint,
+            )?;
+
+            // make sure you cant get rugged
+            if buyer_rec_acct.delegate.is_some() {
+                return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+            }
+
+            (
+                buyer_receipt_token_account.to_account_info(),
+                ctx.accounts.seller.to_account_info(),
+            )
+        }
+    };
+
+    release_protected_tokens(
+        protected_settlement,
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &token_destination,
+        &ctx.accounts.rent_payer.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    pay_from_protected_settlement(
+        &protected_settlement.to_account_info(),
+        &proceeds_destination,
+        protected_settlement.seller_proceeds,
+    )?;
+
+    let dispute_record = &mut ctx.accounts.dispute_record;
+    dispute_record.resolution = Some(resolution);
+    dispute_record.resolved_at = Some(Clock::get()?.unix_timestamp);
+
+    let status = match resolution {
+        DisputeResolution::Buyer => SettlementStatus::RolledBack,
+        DisputeResolution::Seller => SettlementStatus::Settled,
+    };
+    finish_settlement(&mut ctx.accounts.settlement_marker, status)?;
+
+    Ok(())
+}
@@ -181,4 +181,338 @@ pub enum AuctionHouseError {
     // 6044
     #[msg("This sale requires exactly one signer: either the seller or the authority.")]
     SaleRequiresExactlyOneSigner,
+
+    // 6045
+    #[msg("Staked tokens are still within the lockup period.")]
+    FeeStakeLocked,
+
+    // 6046
+    #[msg("Not enough tokens staked to withdraw the requested amount.")]
+    InsufficientStake,
+
+    // 6047
+    #[msg("The relist cooldown cannot be negative.")]
+    InvalidRelistCooldown,
+
+    // 6048
+    #[msg("This mint was settled too recently to be relisted.")]
+    MintInRelistCooldown,
+
+    // 6049
+    #[msg("Buyer protection is only available for houses with a native SOL treasury mint.")]
+    BuyerProtectionRequiresNativeMint,
+
+    // 6050
+    #[msg("The buyer protection window cannot be negative.")]
+    InvalidBuyerProtectionWindow,
+
+    // 6051
+    #[msg("The buyer protection window for this settlement has closed.")]
+    BuyerProtectionWindowClosed,
+
+    // 6052
+    #[msg("The buyer protection window for this settlement is still open.")]
+    BuyerProtectionWindowOpen,
+
+    // 6053
+    #[msg("Programmable NFTs cannot be sold with buyer protection.")]
+    ProgrammableNftCannotBeProtected,
+
+    // 6054
+    #[msg("Only the buyer or seller of a settlement can dispute it.")]
+    NotSettlementParty,
+
+    // 6055
+    #[msg("The settlement is in dispute and can only be resolved by the arbiter.")]
+    SettlementInDispute,
+
+    // 6056
+    #[msg("The signer is not the Auction House arbiter.")]
+    InvalidArbiter,
+
+    // 6057
+    #[msg("Seller financing is not enabled on this Auction House.")]
+    SellerFinancingDisabled,
+
+    // 6058
+    #[msg("Seller financing is only available for houses with a native SOL treasury mint.")]
+    SellerFinancingRequiresNativeMint,
+
+    // 6059
+    #[msg("A financed sale must be signed by both the buyer and the seller.")]
+    FinancedSaleNotSigned,
+
+    // 6060
+    #[msg("The lien amount must be greater than zero.")]
+    InvalidLienAmount,
+
+    // 6061
+    #[msg("This mint has an outstanding lien on this Auction House.")]
+    LienOutstanding,
+
+    // 6062
+    #[msg("Frozen proceeds vaults are only used by houses with an SPL treasury mint.")]
+    FrozenProceedsRequireSplTreasury,
+
+    // 6063
+    #[msg("The recipient token account is still frozen.")]
+    RecipientAccountFrozen,
+
+    // 6064
+    #[msg("The listing fee cannot exceed the Auction House seller fee.")]
+    ListingFeeAboveHouseFee,
+
+    // 6065
+    #[msg("The minimum listing price cannot exceed the maximum listing price.")]
+    InvalidListingPriceBounds,
+
+    // 6066
+    #[msg("The listing price is below the Auction House minimum.")]
+    ListingPriceBelowMinimum,
+
+    // 6067
+    #[msg("The listing price is above the Auction House maximum.")]
+    ListingPriceAboveMaximum,
+
+    // 6068
+    #[msg(
+        "The asset's rule set does not allow sale transfers, so the listing could never settle."
+    )]
+    TransferBlockedByRuleSet,
+
+    // 6069
+    #[msg("The auctioneer authority is not the auctioneer PDA of an allowlisted program.")]
+    AuctioneerProgramNotAllowed,
+
+    // 6070
+    #[msg("The auctioneer allowlist has too many programs.")]
+    TooManyAuctioneerPrograms,
+
+    // 6071
+    #[msg("The escrow payment account would be left below the rent exempt minimum.")]
+    EscrowNotRentExempt,
+
+    // 6072
+    #[msg("Expected auction house, escrow payment account and receipt account triples.")]
+    InvalidWithdrawManyAccounts,
+
+    // 6073
+    #[msg("Bid expiries must be set on an open bid and lie in the future.")]
+    InvalidBidExpiry,
+
+    // 6074
+    #[msg("The bid has not expired yet.")]
+    BidNotExpired,
+
+    // 6075
+    #[msg("Expected bid expiry, trade state, escrow payment account and receipt account groups.")]
+    InvalidSweepExpiredBidsAccounts,
+
+    // 6076
+    #[msg("The compliance program does not match the house's compliance config.")]
+    InvalidComplianceProgram,
+
+    // 6077
+    #[msg("The auction house does not log receipts to a receipt tree.")]
+    ReceiptTreeNotSet,
+
+    // 6078
+    #[msg("The wanted listing prices or end time are invalid.")]
+    InvalidWantedListing,
+
+    // 6079
+    #[msg("The wanted listing has expired.")]
+    WantedListingExpired,
+
+    // 6080
+    #[msg("The token does not meet the wanted listing's criteria.")]
+    WantedCriteriaNotMet,
+
+    // 6081
+    #[msg("The auction house can change sale prices, so the free trade state is required.")]
+    FreeTradeStateRequired,
+
+    // 6082
+    #[msg("Remaining accounts must start with the metadata creators, in order.")]
+    InvalidCreatorAccount,
+
+    // 6083
+    #[msg("Fee sweeps are only supported on native SOL auction houses.")]
+    FeeSweepRequiresNativeTreasury,
+
+    // 6084
+    #[msg("The fee account balance does not exceed the sweep threshold.")]
+    FeeBelowSweepThreshold,
+
+    // 6085
+    #[msg("The treasury withdrawal period must be positive and at most a year.")]
+    InvalidTreasuryWithdrawalPeriod,
+
+    // 6086
+    #[msg("The withdrawal exceeds the treasury withdrawal limit for the period.")]
+    TreasuryWithdrawalLimitExceeded,
+
+    // 6087
+    #[msg("The bid ticket token account must hold a ticket owned by the bidder.")]
+    InvalidBidTicket,
+
+    // 6088
+    #[msg("The bid ticket is not a verified member of the house's ticket collection.")]
+    BidTicketNotInCollection,
+
+    // 6089
+    #[msg("The settlement has already been completed.")]
+    SettlementAlreadySettled,
+
+    // 6090
+    #[msg("The settlement is escrowed and must be finalized or canceled before it is retried.")]
+    SettlementPartiallySettled,
+
+    // 6091
+    #[msg("The settlement has not been started.")]
+    SettlementNotStarted,
+
+    // 6092
+    #[msg("Buyer receipt account must be the buyer's associated token account.")]
+    BuyerReceiptNotAssociatedTokenAccount,
+
+    // 6093
+    #[msg("The Auction House is immutable and cannot be updated.")]
+    AuctionHouseImmutable,
+
+    // 6094
+    #[msg("Signer is not the collection's update authority.")]
+    NotCollectionUpdateAuthority,
+
+    // 6095
+    #[msg("Too many approved Auction Houses for a marketplace binding.")]
+    TooManyApprovedAuctionHouses,
+
+    // 6096
+    #[msg("The collection's marketplace binding does not approve this Auction House.")]
+    AuctionHouseNotApprovedForCollection,
+
+    // 6097
+    #[msg("Bidding with a transfer is only available for houses with a native SOL treasury mint.")]
+    BidWithTransferRequiresNativeMint,
+
+    // 6098
+    #[msg("Vouchers are only available for houses with a native SOL treasury mint.")]
+    VoucherRequiresNativeMint,
+
+    // 6099
+    #[msg("The voucher price or redeem deadline is invalid.")]
+    InvalidVoucher,
+
+    // 6100
+    #[msg("The voucher has already been sold.")]
+    VoucherAlreadySold,
+
+    // 6101
+    #[msg("The voucher has not been sold.")]
+    VoucherNotSold,
+
+    // 6102
+    #[msg("The voucher's redeem deadline has passed.")]
+    VoucherRedeemDeadlinePassed,
+
+    // 6103
+    #[msg("The voucher's redeem deadline has not passed yet.")]
+    VoucherRedeemDeadlineNotPassed,
+
+    // 6104
+    #[msg("The buyer does not hold a token verified as minted by the voucher's creator.")]
+    VoucherNotDelivered,
+
+    // 6105
+    #[msg("Expected protected settlement, settlement marker, escrow token account, buyer receipt account, seller and rent payer groups.")]
+    InvalidFinalizeProtectedSettlementsAccounts,
+
+    // 6106
+    #[msg("Every entry of the crank batch was already processed or is not due yet.")]
+    NothingToCrank,
+
+    // 6107
+    #[msg("The auctioneer grace period must not be negative.")]
+    InvalidAuctioneerGracePeriod,
+
+    // 6108
+    #[msg("Bids on an Auction House with a delegated auctioneer cannot be transferred.")]
+    AuctioneerBidNotTransferable,
+
+    // 6109
+    #[msg("The leaderboard window must end after it starts.")]
+    InvalidLeaderboardWindow,
+
+    // 6110
+    #[msg("The royalty bonus and the token's royalties cannot exceed 10000 basis points.")]
+    RoyaltyBonusTooHigh,
+
+    // 6111
+    #[msg("Co-owner shares must add up to 10000 basis points, with a threshold of at least one and at most the number of co-owners.")]
+    InvalidSharedCustody,
+
+    // 6112
+    #[msg("The signer is not a co-owner of the shared custody.")]
+    NotCoOwner,
+
+    // 6113
+    #[msg("The shared custody token is already listed.")]
+    SharedCustodyAlreadyListed,
+
+    // 6114
+    #[msg("The shared custody token has not been sold.")]
+    SharedCustodyNotSold,
+
+    // 6115
+    #[msg("Shared custody is only available for houses with a native SOL treasury mint.")]
+    SharedCustodyRequiresNativeMint,
+
+    // 6116
+    #[msg("The payout delay cannot be negative.")]
+    InvalidPayoutDelay,
+
+    // 6117
+    #[msg("Payout delays are only available for houses with a native SOL treasury mint.")]
+    PayoutDelayRequiresNativeMint,
+
+    // 6118
+    #[msg("The payout delay has not passed yet.")]
+    PayoutDelayWindowOpen,
+
+    // 6119
+    #[msg("The payout delay has passed, so the proceeds can no longer be clawed back.")]
+    PayoutDelayWindowClosed,
+
+    // 6120
+    #[msg("The token standard policy has unknown bits set.")]
+    InvalidTokenStandardPolicy,
+
+    // 6121
+    #[msg("This Auction House does not list tokens of this token standard.")]
+    TokenStandardNotAllowed,
+
+    // 6122
+    #[msg("This Auction House only lists tokens whose creators and collection are verified.")]
+    UnverifiedCreators,
+
+    // 6123
+    #[msg("The frontend must sign bids charged to its spending cap.")]
+    FrontendMustSign,
+
+    // 6124
+    #[msg("The bid exceeds the wallet's spending cap for this frontend.")]
+    SpendingCapExceeded,
+
+    // 6125
+    #[msg("The seller already has an active listing of this mint on this Auction House.")]
+    ListingAlreadyActive,
+
+    // 6126
+    #[msg("The listing was placed in a different treasury currency than the Auction House settles in.")]
+    ListingCurrencyMismatch,
+
+    // 6127
+    #[msg("The notice expiry has already passed.")]
+    NoticeExpiryInPast,
 }
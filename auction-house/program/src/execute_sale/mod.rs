@@ -1,4 +1,23 @@
-use crate::{constants::*, errors::*, utils::*, AuctionHouse, Auctioneer, AuthorityScope, *};
+use crate::{
+    auctioneer_stats::record_auctioneer_settlement,
+    buyer_protection::{next_protected_settlement_accounts, open_protected_settlement},
+    constants::*,
+    errors::*,
+    fee_stake::seller_fee_basis_points_for,
+    frozen_proceeds::transfer_or_hold_proceeds,
+    leaderboard::record_leaderboard_settlement,
+    listing_currency::assert_listing_currency,
+    listing_fee::take_listing_fee_basis_points,
+    math::{checked_sub, partial_fill_price},
+    payout_delay::{next_delayed_payout_account, open_delayed_payout},
+    price_history::record_price_history,
+    relist_cooldown::record_mint_settlement,
+    royalty_bonus::take_royalty_bonus_basis_points,
+    tax_withholding::withhold_tax,
+    utils::*,
+    wallet_receipt::assert_buyer_receipt_account,
+    AuctionHouse, Auctioneer, AuthorityScope, *,
+};
 use anchor_lang::{prelude::*, solana_program::program_pack::Pack, AnchorDeserialize};
 use mpl_token_auth_rules::payload::{Payload, PayloadType, SeedsVec};
 use mpl_token_metadata::{
@@ -139,7 +158,8 @@ pub struct ExecuteSale<'info> {
     pub seller_trade_state: UncheckedAccount<'info>,
 
     /// CHECK: Not dangerous. Account seeds checked in constraint.
-    /// Free seller trade state PDA account encoding a free sell order.
+    /// Free seller trade state PDA account encoding a free sell order. Only required when the
+    /// Auction House can change sale prices.
     #[account(
         mut,
         seeds = [
@@ -154,7 +174,7 @@ pub struct ExecuteSale<'info> {
         ],
         bump
     )]
-    pub free_trade_state: UncheckedAccount<'info>,
+    pub free_trade_state: Option<UncheckedAccount<'info>>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -223,7 +243,7 @@ impl<'info> From<AuctioneerExecuteSale<'info>> for ExecuteSale<'info> {
             auction_house_treasury: a.auction_house_treasury,
             buyer_trade_state: a.buyer_trade_state,
             seller_trade_state: a.seller_trade_state,
-            free_trade_state: a.free_trade_state,
+            free_trade_state: Some(a.free_trade_state),
             token_program: a.token_program,
             system_program: a.system_program,
             ata_program: a.ata_program,
@@ -233,6 +253,25 @@ impl<'info> From<AuctioneerExecuteSale<'info>> for ExecuteSale<'info> {
     }
 }
 
+/// Check the free seller trade state passed to a settlement. Free sell orders can only be
+/// repriced on houses whose authority can change sale prices, so other houses may omit the
+/// account; when it is passed its bump must be canonical.
+pub fn assert_free_trade_state(
+    auction_house: &AuctionHouse,
+    free_trade_state: &Option<UncheckedAccount>,
+    canonical_bump: Option<&u8>,
+    free_trade_state_bump: u8,
+) -> Result<()> {
+    match (free_trade_state, canonical_bump) {
+        (Some(_), Some(bump)) if *bump == free_trade_state_bump => Ok(()),
+        (Some(_), _) => Err(AuctionHouseError::BumpSeedNotInHashMap.into()),
+        (None, _) if auction_house.can_change_sale_price => {
+            Err(AuctionHouseError::FreeTradeStateRequired.into())
+        }
+        (None, _) => Ok(()),
+    }
+}
+
 pub fn execute_sale<'info>(
     ctx: Context<'_, '_, '_, 'info, ExecuteSale<'info>>,
     escrow_payment_bump: u8,
@@ -252,22 +291,24 @@ pub fn execute_sale<'info>(
         .bumps
         .get("escrow_payment_account")
         .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
-    let free_trade_state_canonical_bump = *ctx
-        .bumps
-        .get("free_trade_state")
-        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
     let program_as_signer_canonical_bump = *ctx
         .bumps
         .get("program_as_signer")
         .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
 
     if (escrow_canonical_bump != escrow_payment_bump)
-        || (free_trade_state_canonical_bump != free_trade_state_bump)
         || (program_as_signer_canonical_bump != program_as_signer_bump)
     {
         return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
     }
 
+    assert_free_trade_state(
+        auction_house,
+        &ctx.accounts.free_trade_state,
+        ctx.bumps.get("free_trade_state"),
+        free_trade_state_bump,
+    )?;
+
     execute_sale_logic(
         ctx.accounts,
         ctx.remaining_accounts,
@@ -412,7 +453,8 @@ pub struct ExecutePartialSale<'info> {
     pub seller_trade_state: UncheckedAccount<'info>,
 
     /// CHECK: Not dangerous. Account seeds checked in constraint.
-    /// Free seller trade state PDA account encoding a free sell order.
+    /// Free seller trade state PDA account encoding a free sell order. Only required when the
+    /// Auction House can change sale prices.
     #[account(
         mut,
         seeds = [
@@ -427,7 +469,7 @@ pub struct ExecutePartialSale<'info> {
         ],
         bump
     )]
-    pub free_trade_state: UncheckedAccount<'info>,
+    pub free_trade_state: Option<UncheckedAccount<'info>>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -489,22 +531,24 @@ pub fn execute_partial_sale<'info>(
         .bumps
         .get("escrow_payment_account")
         .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
-    let free_trade_state_canonical_bump = *ctx
-        .bumps
-        .get("free_trade_state")
-        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
     let program_as_signer_canonical_bump = *ctx
         .bumps
         .get("program_as_signer")
         .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
 
     if (escrow_canonical_bump != escrow_payment_bump)
-        || (free_trade_state_canonical_bump != free_trade_state_bump)
         || (program_as_signer_canonical_bump != program_as_signer_bump)
     {
         return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
     }
 
+    assert_free_trade_state(
+        auction_house,
+        &ctx.accounts.free_trade_state,
+        ctx.bumps.get("free_trade_state"),
+        free_trade_state_bump,
+    )?;
+
     let mut accounts: ExecuteSale<'info> = (*ctx.accounts).clone().into();
 
     execute_sale_logic(
@@ -1104,6 +1148,7 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
     {
         return Err(AuctionHouseError::BothPartiesNeedToAgreeToSale.into());
     }
+    assert_listing_currency(seller_trade_state, treasury_mint)?;
 
     let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
 
@@ -1120,7 +1165,7 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
                 ts_bump,
             )?;
 
-            if ((buyer_price / token_size) * size) != price {
+            if partial_fill_price(buyer_price, token_size, size)? != price {
                 return Err(AuctionHouseError::PartialPriceMismatch.into());
             }
 
@@ -1196,23 +1241,13 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
     // rent-exemption checked accounts.  The fee payer makes up the shortfall up to the amount of
     // rent for an empty account.
     if is_native {
-        let rent_shortfall =
-            verify_withdrawal(escrow_payment_account.to_account_info(), buyer_price)?;
-        if rent_shortfall > 0 {
-            invoke_signed(
-                &system_instruction::transfer(
-                    fee_payer.key,
-                    escrow_payment_account.key,
-                    rent_shortfall,
-                ),
-                &[
-                    fee_payer.to_account_info(),
-                    escrow_payment_account.to_account_info(),
-                    system_program.to_account_info(),
-                ],
-                &[fee_payer_seeds],
-            )?;
-        }
+        top_up_escrow_rent_shortfall(
+            &escrow_payment_account.to_account_info(),
+            &fee_payer.to_account_info(),
+            &system_program.to_account_info(),
+            fee_payer_seeds,
+            buyer_price,
+        )?;
     }
 
     if metadata.data_is_empty() {
@@ -1245,6 +1280,13 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
 
     let remaining_accounts = &mut remaining_accounts.iter();
 
+    let royalty_bonus_basis_points = take_royalty_bonus_basis_points(
+        &auction_house.key(),
+        &seller_trade_state.key(),
+        &seller.to_account_info(),
+        remaining_accounts,
+    )?;
+
     let buyer_leftover_after_royalties = pay_creator_fees(
         remaining_accounts,
         &metadata_clone,
@@ -1259,9 +1301,46 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
         &signer_seeds_for_royalties,
         fee_payer_seeds,
         price,
+        royalty_bonus_basis_points,
+        auction_house.rounding_policy.royalty_rounding(),
         is_native,
     )?;
 
+    let seller_fee_basis_points =
+        seller_fee_basis_points_for(auction_house, &seller.key(), remaining_accounts)?;
+    let seller_fee_basis_points = match take_listing_fee_basis_points(
+        auction_house,
+        &seller_trade_state.key(),
+        &fee_payer_clone,
+        remaining_accounts,
+    )? {
+        Some(listing_fee_basis_points) => seller_fee_basis_points.min(listing_fee_basis_points),
+        None => seller_fee_basis_points,
+    };
+
+    record_mint_settlement(
+        auction_house,
+        &token_mint.key(),
+        &fee_payer_clone,
+        fee_payer_seeds,
+        &sys_clone,
+        &rent_clone,
+        remaining_accounts,
+    )?;
+
+    let protected_settlement = next_protected_settlement_accounts(
+        auction_house,
+        &token_mint.key(),
+        &buyer.key(),
+        remaining_accounts,
+    )?;
+    let delayed_payout = next_delayed_payout_account(
+        auction_house,
+        &token_mint.key(),
+        &buyer.key(),
+        remaining_accounts,
+    )?;
+
     let auction_house_fee_paid = pay_auction_house_fees(
         auction_house,
         &treasury_clone,
@@ -1269,13 +1348,71 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
         &token_clone,
         &sys_clone,
         &signer_seeds_for_royalties,
+        seller_fee_basis_points,
+        price,
+        buyer_leftover_after_royalties,
+        is_native,
+    )?;
+
+    let tax_withheld = withhold_tax(
+        auction_house,
+        &escrow_clone,
+        &token_clone,
+        &sys_clone,
+        &signer_seeds_for_royalties,
         price,
         is_native,
+        remaining_accounts,
+    )?;
+
+    record_auctioneer_settlement(
+        auction_house,
+        &accounts.ah_auctioneer_pda,
+        price,
+        auction_house_fee_paid,
+        remaining_accounts,
     )?;
 
-    let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
-        .checked_sub(auction_house_fee_paid)
-        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    record_leaderboard_settlement(
+        &auction_house.key(),
+        &buyer.key(),
+        &seller.key(),
+        price,
+        remaining_accounts,
+    )?;
+
+    record_price_history(
+        &auction_house.key(),
+        &token_mint.key(),
+        price,
+        size,
+        remaining_accounts,
+    )?;
+
+    // The withheld tax comes out of the seller's proceeds.
+    let buyer_leftover_after_royalties_and_house_fee = checked_sub(
+        checked_sub(buyer_leftover_after_royalties, auction_house_fee_paid)?,
+        tax_withheld,
+    )?;
+
+    if let Some(protected_settlement) = &protected_settlement {
+        open_protected_settlement(
+            protected_settlement,
+            auction_house,
+            &buyer.key(),
+            &seller.key(),
+            &token_mint.to_account_info(),
+            &token_account.key(),
+            size,
+            buyer_leftover_after_royalties_and_house_fee,
+            &fee_payer_clone,
+            fee_payer_seeds,
+            &ata_clone,
+            &token_clone,
+            &sys_clone,
+            &rent_clone,
+        )?;
+    }
 
     if !is_native {
         if seller_payment_receipt_account.data_is_empty() {
@@ -1303,66 +1440,103 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
             return Err(AuctionHouseError::SellerATACannotHaveDelegate.into());
         }
 
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                token_program.key,
-                &escrow_payment_account.key(),
-                &seller_payment_receipt_account.key(),
-                &auction_house.key(),
-                &[],
-                buyer_leftover_after_royalties_and_house_fee,
-            )?,
-            &[
-                escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
-                token_program.to_account_info(),
-                auction_house.to_account_info(),
-            ],
-            &[&ah_seeds],
+        transfer_or_hold_proceeds(
+            remaining_accounts,
+            &auction_house.to_account_info(),
+            &escrow_payment_account.to_account_info(),
+            &seller.key(),
+            &seller_payment_receipt_account.to_account_info(),
+            &fee_payer_clone,
+            &token_program.to_account_info(),
+            &system_program.to_account_info(),
+            &rent.to_account_info(),
+            &ah_seeds,
+            fee_payer_seeds,
+            buyer_leftover_after_royalties_and_house_fee,
         )?;
     } else {
-        assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
+        // With buyer protection the seller's proceeds are held by the protected settlement, and
+        // with a payout delay by the delayed payout.
+        let seller_proceeds_destination = match (&protected_settlement, delayed_payout) {
+            (Some(protected_settlement), _) => protected_settlement.settlement.clone(),
+            (None, Some((delayed_payout, delayed_payout_bump))) => {
+                open_delayed_payout(
+                    delayed_payout,
+                    delayed_payout_bump,
+                    auction_house,
+                    &seller.key(),
+                    &buyer.key(),
+                    &token_mint.key(),
+                    buyer_leftover_after_royalties_and_house_fee,
+                    &fee_payer_clone,
+                    fee_payer_seeds,
+                    &sys_clone,
+                    &rent_clone,
+                )?;
+                delayed_payout.clone()
+            }
+            (None, None) => {
+                assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
+                seller_payment_receipt_account.to_account_info()
+            }
+        };
+
         invoke_signed(
             &system_instruction::transfer(
                 escrow_payment_account.key,
-                seller_payment_receipt_account.key,
+                seller_proceeds_destination.key,
                 buyer_leftover_after_royalties_and_house_fee,
             ),
             &[
                 escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
+                seller_proceeds_destination,
                 system_program.to_account_info(),
             ],
             &[&escrow_signer_seeds],
         )?;
-    }
 
-    if buyer_receipt_token_account.data_is_empty() {
-        make_ata(
-            buyer_receipt_token_account.to_account_info(),
-            buyer.to_account_info(),
-            token_mint.to_account_info(),
-            fee_payer.to_account_info(),
-            ata_program.to_account_info(),
-            token_program.to_account_info(),
-            system_program.to_account_info(),
-            rent.to_account_info(),
-            fee_payer_seeds,
-        )?;
-    } else {
-        let data = buyer_receipt_token_account.try_borrow_data()?;
-        let token_account = TokenAccount::try_deserialize(&mut data.as_ref())?;
-        if &token_account.owner != buyer.key {
-            return Err(AuctionHouseError::IncorrectOwner.into());
-        }
+        assert_escrow_rent_exempt(&escrow_payment_account.to_account_info())?;
     }
 
-    let buyer_rec_acct = assert_is_ata(&buyer_receipt_clone, &buyer.key(), &token_mint.key())?;
+    // With buyer protection the purchased tokens are held by the protected settlement.
+    let token_destination = match &protected_settlement {
+        Some(protected_settlement) => protected_settlement.escrow_token_account.clone(),
+        None => {
+            if buyer_receipt_token_account.data_is_empty() {
+                make_ata(
+                    buyer_receipt_token_account.to_account_info(),
+                    buyer.to_account_info(),
+                    token_mint.to_account_info(),
+                    fee_payer.to_account_info(),
+                    ata_program.to_account_info(),
+                    token_program.to_account_info(),
+                    system_program.to_account_info(),
+                    rent.to_account_info(),
+                    fee_payer_seeds,
+                )?;
+            } else {
+                let data = buyer_receipt_token_account.try_borrow_data()?;
+                let token_account = TokenAccount::try_deserialize(&mut data.as_ref())?;
+                if &token_account.owner != buyer.key {
+                    return Err(AuctionHouseError::IncorrectOwner.into());
+                }
+            }
 
-    // make sure you cant get rugged
-    if buyer_rec_acct.delegate.is_some() {
-        return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
-    }
+            let buyer_rec_acct = assert_buyer_receipt_account(
+                auction_house,
+                &buyer_receipt_clone,
+                &buyer.key(),
+                &token_mint.key(),
+            )?;
+
+            // make sure you cant get rugged
+            if buyer_rec_acct.delegate.is_some() {
+                return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+            }
+
+            buyer_receipt_clone
+        }
+    };
 
     let program_as_signer_seeds = [
         PREFIX.as_bytes(),
@@ -1376,6 +1550,10 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
                 metadata_program.key() == mpl_token_metadata::ID,
                 AuctionHouseError::PublicKeyMismatch
             );
+            require!(
+                protected_settlement.is_none(),
+                AuctionHouseError::ProgrammableNftCannotBeProtected
+            );
 
             let edition = next_account_info(remaining_accounts)?;
             let owner_tr = next_account_info(remaining_accounts)?;
@@ -1458,14 +1636,14 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
                 &spl_token::instruction::transfer(
                     token_program.key,
                     &token_account.key(),
-                    &buyer_receipt_token_account.key(),
+                    token_destination.key,
                     &program_as_signer.key(),
                     &[],
                     size,
                 )?,
                 &[
                     token_account.to_account_info(),
-                    buyer_receipt_clone,
+                    token_destination,
                     program_as_signer.to_account_info(),
                     token_clone,
                 ],
@@ -1493,12 +1671,15 @@ fn auctioneer_execute_sale_logic<'c, 'info>(
             )?;
         }
     }
+
+    alert_low_fee_balance(auction_house, &auction_house_fee_account.to_account_info());
+
     Ok(())
 }
 
 /// Execute sale between provided buyer and seller trade state accounts transferring funds to seller wallet and token to buyer wallet.
 #[inline(never)]
-fn execute_sale_logic<'c, 'info>(
+pub(crate) fn execute_sale_logic<'c, 'info>(
     accounts: &mut ExecuteSale<'info>,
     remaining_accounts: &'c [AccountInfo<'info>],
     escrow_payment_bump: u8,
@@ -1574,6 +1755,7 @@ fn execute_sale_logic<'c, 'info>(
     {
         return Err(AuctionHouseError::BothPartiesNeedToAgreeToSale.into());
     }
+    assert_listing_currency(seller_trade_state, treasury_mint)?;
 
     let token_account_data = SplAccount::unpack(&token_account.data.borrow())?;
 
@@ -1590,7 +1772,7 @@ fn execute_sale_logic<'c, 'info>(
                 ts_bump,
             )?;
 
-            if ((buyer_price / token_size) * size) != price {
+            if partial_fill_price(buyer_price, token_size, size)? != price {
                 return Err(AuctionHouseError::PartialPriceMismatch.into());
             }
 
@@ -1666,22 +1848,13 @@ fn execute_sale_logic<'c, 'info>(
     // rent-exemption checked accounts.  The fee payer makes up the shortfall up to the amount of
     // rent for an empty account.
     if is_native {
-        let rent_shortfall = verify_withdrawal(escrow_payment_account.to_account_info(), price)?;
-        if rent_shortfall > 0 {
-            invoke_signed(
-                &system_instruction::transfer(
-                    fee_payer.key,
-                    escrow_payment_account.key,
-                    rent_shortfall,
-                ),
-                &[
-                    fee_payer.to_account_info(),
-                    escrow_payment_account.to_account_info(),
-                    system_program.to_account_info(),
-                ],
-                &[fee_payer_seeds],
-            )?;
-        }
+        top_up_escrow_rent_shortfall(
+            &escrow_payment_account.to_account_info(),
+            &fee_payer.to_account_info(),
+            &system_program.to_account_info(),
+            fee_payer_seeds,
+            price,
+        )?;
     }
 
     if metadata.data_is_empty() {
@@ -1714,6 +1887,13 @@ fn execute_sale_logic<'c, 'info>(
 
     let remaining_accounts = &mut remaining_accounts.iter();
 
+    let royalty_bonus_basis_points = take_royalty_bonus_basis_points(
+        &auction_house.key(),
+        &seller_trade_state.key(),
+        &seller.to_account_info(),
+        remaining_accounts,
+    )?;
+
     let buyer_leftover_after_royalties = pay_creator_fees(
         remaining_accounts,
         &metadata_clone,
@@ -1728,9 +1908,37 @@ fn execute_sale_logic<'c, 'info>(
         &signer_seeds_for_royalties,
         fee_payer_seeds,
         price,
+        royalty_bonus_basis_points,
+        auction_house.rounding_policy.royalty_rounding(),
         is_native,
     )?;
 
+    let seller_fee_basis_points =
+        seller_fee_basis_points_for(auction_house, &seller.key(), remaining_accounts)?;
+
+    record_mint_settlement(
+        auction_house,
+        &token_mint.key(),
+        &fee_payer_clone,
+        fee_payer_seeds,
+        &sys_clone,
+        &rent_clone,
+        remaining_accounts,
+    )?;
+
+    let protected_settlement = next_protected_settlement_accounts(
+        auction_house,
+        &token_mint.key(),
+        &buyer.key(),
+        remaining_accounts,
+    )?;
+    let delayed_payout = next_delayed_payout_account(
+        auction_house,
+        &token_mint.key(),
+        &buyer.key(),
+        remaining_accounts,
+    )?;
+
     let auction_house_fee_paid = pay_auction_house_fees(
         auction_house,
         &treasury_clone,
@@ -1738,13 +1946,63 @@ fn execute_sale_logic<'c, 'info>(
         &token_clone,
         &sys_clone,
         &signer_seeds_for_royalties,
+        seller_fee_basis_points,
         price,
+        buyer_leftover_after_royalties,
         is_native,
     )?;
 
-    let buyer_leftover_after_royalties_and_house_fee = buyer_leftover_after_royalties
-        .checked_sub(auction_house_fee_paid)
-        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let tax_withheld = withhold_tax(
+        auction_house,
+        &escrow_clone,
+        &token_clone,
+        &sys_clone,
+        &signer_seeds_for_royalties,
+        price,
+        is_native,
+        remaining_accounts,
+    )?;
+
+    record_leaderboard_settlement(
+        &auction_house.key(),
+        &buyer.key(),
+        &seller.key(),
+        price,
+        remaining_accounts,
+    )?;
+
+    record_price_history(
+        &auction_house.key(),
+        &token_mint.key(),
+        price,
+        size,
+        remaining_accounts,
+    )?;
+
+    // The withheld tax comes out of the seller's proceeds.
+    let buyer_leftover_after_royalties_and_house_fee = checked_sub(
+        checked_sub(buyer_leftover_after_royalties, auction_house_fee_paid)?,
+        tax_withheld,
+    )?;
+
+    if let Some(protected_settlement) = &protected_settlement {
+        open_protected_settlement(
+            protected_settlement,
+            auction_house,
+            &buyer.key(),
+            &seller.key(),
+            &token_mint.to_account_info(),
+            &token_account.key(),
+            size,
+            buyer_leftover_after_royalties_and_house_fee,
+            &fee_payer_clone,
+            fee_payer_seeds,
+            &ata_clone,
+            &token_clone,
+            &sys_clone,
+            &rent_clone,
+        )?;
+    }
 
     if !is_native {
         if seller_payment_receipt_account.data_is_empty() {
@@ -1772,60 +2030,97 @@ fn execute_sale_logic<'c, 'info>(
             return Err(AuctionHouseError::SellerATACannotHaveDelegate.into());
         }
 
-        invoke_signed(
-            &spl_token::instruction::transfer(
-                token_program.key,
-                &escrow_payment_account.key(),
-                &seller_payment_receipt_account.key(),
-                &auction_house.key(),
-                &[],
-                buyer_leftover_after_royalties_and_house_fee,
-            )?,
-            &[
-                escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
-                token_program.to_account_info(),
-                auction_house.to_account_info(),
-            ],
-            &[&ah_seeds],
+        transfer_or_hold_proceeds(
+            remaining_accounts,
+            &auction_house.to_account_info(),
+            &escrow_payment_account.to_account_info(),
+            &seller.key(),
+            &seller_payment_receipt_account.to_account_info(),
+            &fee_payer_clone,
+            &token_program.to_account_info(),
+            &system_program.to_account_info(),
+            &rent.to_account_info(),
+            &ah_seeds,
+            fee_payer_seeds,
+            buyer_leftover_after_royalties_and_house_fee,
         )?;
     } else {
-        assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
+        // With buyer protection the seller's proceeds are held by the protected settlement, and
+        // with a payout delay by the delayed payout.
+        let seller_proceeds_destination = match (&protected_settlement, delayed_payout) {
+            (Some(protected_settlement), _) => protected_settlement.settlement.clone(),
+            (None, Some((delayed_payout, delayed_payout_bump))) => {
+                open_delayed_payout(
+                    delayed_payout,
+                    delayed_payout_bump,
+                    auction_house,
+                    &seller.key(),
+                    &buyer.key(),
+                    &token_mint.key(),
+                    buyer_leftover_after_royalties_and_house_fee,
+                    &fee_payer_clone,
+                    fee_payer_seeds,
+                    &sys_clone,
+                    &rent_clone,
+                )?;
+                delayed_payout.clone()
+            }
+            (None, None) => {
+                assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
+                seller_payment_receipt_account.to_account_info()
+            }
+        };
+
         invoke_signed(
             &system_instruction::transfer(
                 escrow_payment_account.key,
-                seller_payment_receipt_account.key,
+                seller_proceeds_destination.key,
                 buyer_leftover_after_royalties_and_house_fee,
             ),
             &[
                 escrow_payment_account.to_account_info(),
-                seller_payment_receipt_account.to_account_info(),
+                seller_proceeds_destination,
                 system_program.to_account_info(),
             ],
             &[&escrow_signer_seeds],
         )?;
-    }
 
-    if buyer_receipt_token_account.data_is_empty() {
-        make_ata(
-            buyer_receipt_token_account.to_account_info(),
-            buyer.to_account_info(),
-            token_mint.to_account_info(),
-            fee_payer.to_account_info(),
-            ata_program.to_account_info(),
-            token_program.to_account_info(),
-            system_program.to_account_info(),
-            rent.to_account_info(),
-            fee_payer_seeds,
-        )?;
+        assert_escrow_rent_exempt(&escrow_payment_account.to_account_info())?;
     }
 
-    let buyer_rec_acct = assert_is_ata(&buyer_receipt_clone, &buyer.key(), &token_mint.key())?;
+    // With buyer protection the purchased tokens are held by the protected settlement.
+    let token_destination = match &protected_settlement {
+        Some(protected_settlement) => protected_settlement.escrow_token_account.clone(),
+        None => {
+            if buyer_receipt_token_account.data_is_empty() {
+                make_ata(
+                    buyer_receipt_token_account.to_account_info(),
+                    buyer.to_account_info(),
+                    token_mint.to_account_info(),
+                    fee_payer.to_account_info(),
+                    ata_program.to_account_info(),
+                    token_program.to_account_info(),
+                    system_program.to_account_info(),
+                    rent.to_account_info(),
+                    fee_payer_seeds,
+                )?;
+            }
 
-    // make sure you cant get rugged
-    if buyer_rec_acct.delegate.is_some() {
-        return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
-    }
+            let buyer_rec_acct = assert_buyer_receipt_account(
+                auction_house,
+                &buyer_receipt_clone,
+                &buyer.key(),
+                &token_mint.key(),
+            )?;
+
+            // make sure you cant get rugged
+            if buyer_rec_acct.delegate.is_some() {
+                return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+            }
+
+            buyer_receipt_clone
+        }
+    };
 
     let program_as_signer_seeds = [
         PREFIX.as_bytes(),
@@ -1839,6 +2134,10 @@ fn execute_sale_logic<'c, 'info>(
                 metadata_program.key() == mpl_token_metadata::ID,
                 AuctionHouseError::PublicKeyMismatch
             );
+            require!(
+                protected_settlement.is_none(),
+                AuctionHouseError::ProgrammableNftCannotBeProtected
+            );
 
             let edition = next_account_info(remaining_accounts)?;
             let owner_tr = next_account_info(remaining_accounts)?;
@@ -1921,14 +2220,14 @@ fn execute_sale_logic<'c, 'info>(
                 &spl_token::instruction::transfer(
                     token_program.key,
                     &token_account.key(),
-                    &buyer_receipt_token_account.key(),
+                    token_destination.key,
                     &program_as_signer.key(),
                     &[],
                     size,
                 )?,
                 &[
                     token_account.to_account_info(),
-                    buyer_receipt_clone,
+                    token_destination,
                     program_as_signer.to_account_info(),
                     token_clone,
                 ],
@@ -1950,13 +2249,17 @@ fn execute_sale_logic<'c, 'info>(
             &fee_payer.to_account_info(),
         )?;
 
-        if free_trade_state.lamports() > 0 {
-            close_account(
-                &free_trade_state.to_account_info(),
-                &fee_payer.to_account_info(),
-            )?;
+        if let Some(free_trade_state) = free_trade_state {
+            if free_trade_state.lamports() > 0 {
+                close_account(
+                    &free_trade_state.to_account_info(),
+                    &fee_payer.to_account_info(),
+                )?;
+            }
         }
     }
 
+    alert_low_fee_balance(auction_house, &auction_house_fee_account.to_account_info());
+
     Ok(())
 }
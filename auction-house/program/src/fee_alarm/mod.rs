@@ -0,0 +1,123 @@
+//! Low balance alarm for the fee account. Settlements emit an event once the fee account drops
+//! below the house's threshold, and anyone can top the account up, so sign-off flows that rely on
+//! it don't start failing silently.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+
+use crate::{authority_log::record_authority_action, constants::*, AuctionHouse, AuthorityAction};
+
+/// Emitted by settlements that leave the fee account below the house's low balance threshold.
+#[event]
+pub struct LowFeeAccountBalance {
+    pub auction_house: Pubkey,
+    pub balance: u64,
+    pub threshold: u64,
+}
+
+/// Accounts for the [`set_low_fee_balance_threshold` handler](auction_house/fn.set_low_fee_balance_threshold.html).
+#[derive(Accounts)]
+pub struct SetLowFeeBalanceThreshold<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+/// Accounts for the [`fund_fee_account` handler](auction_house/fn.fund_fee_account.html).
+#[derive(Accounts)]
+pub struct FundFeeAccount<'info> {
+    /// Account sending the lamports.
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_low_fee_balance_threshold<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetLowFeeBalanceThreshold<'info>>,
+    threshold: u64,
+) -> Result<()> {
+    ctx.accounts.auction_house.low_fee_balance_threshold = threshold;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetLowFeeBalanceThreshold,
+        threshold,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+pub fn fund_fee_account<'info>(
+    ctx: Context<'_, '_, '_, 'info, FundFeeAccount<'info>>,
+    amount: u64,
+) -> Result<()> {
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.funder.key(),
+            &ctx.accounts.auction_house_fee_account.key(),
+            amount,
+        ),
+        &[
+            ctx.accounts.funder.to_account_info(),
+            ctx.accounts.auction_house_fee_account.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// Emit [`LowFeeAccountBalance`] if the fee account is below the house's threshold. A zero
+/// threshold turns the alarm off.
+pub fn alert_low_fee_balance(auction_house: &Account<AuctionHouse>, fee_account: &AccountInfo) {
+    let threshold = auction_house.low_fee_balance_threshold;
+    let balance = fee_account.lamports();
+    if threshold > 0 && balance < threshold {
+        emit!(LowFeeAccountBalance {
+            auction_house: auction_house.key(),
+            balance,
+            threshold,
+        });
+    }
+}
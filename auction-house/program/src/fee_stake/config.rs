@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction, FeeStakeConfig,
+};
+
+/// Accounts for the [`create_fee_stake_config` handler](auction_house/fn.create_fee_stake_config.html).
+#[derive(Accounts)]
+pub struct CreateFeeStakeConfig<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Mint of the token wallets stake to receive the fee discount.
+    pub stake_mint: Box<Account<'info, Mint>>,
+
+    /// Fee stake config PDA account.
+    #[account(
+        init,
+        payer = authority,
+        space = FEE_STAKE_CONFIG_SIZE,
+        seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump
+    )]
+    pub fee_stake_config: Box<Account<'info, FeeStakeConfig>>,
+
+    /// Token account owned by the Auction House holding all staked tokens.
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            FEE_STAKE_VAULT.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump,
+        token::mint = stake_mint,
+        token::authority = auction_house
+    )]
+    pub stake_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_fee_stake_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateFeeStakeConfig<'info>>,
+    min_stake_amount: u64,
+    discounted_seller_fee_basis_points: u16,
+    lockup_seconds: i64,
+) -> Result<()> {
+    if discounted_seller_fee_basis_points > ctx.accounts.auction_house.seller_fee_basis_points {
+        return Err(AuctionHouseError::InvalidBasisPoints.into());
+    }
+
+    let fee_stake_config = &mut ctx.accounts.fee_stake_config;
+    fee_stake_config.auction_house = ctx.accounts.auction_house.key();
+    fee_stake_config.stake_mint = ctx.accounts.stake_mint.key();
+    fee_stake_config.stake_vault = ctx.accounts.stake_vault.key();
+    fee_stake_config.min_stake_amount = min_stake_amount;
+    fee_stake_config.discounted_seller_fee_basis_points = discounted_seller_fee_basis_points;
+    fee_stake_config.lockup_seconds = lockup_seconds;
+    fee_stake_config.bump = *ctx
+        .bumps
+        .get("fee_stake_config")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    fee_stake_config.vault_bump = *ctx
+        .bumps
+        .get("stake_vault")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::CreateFeeStakeConfig,
+        min_stake_amount,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Accounts for the [`update_fee_stake_config` handler](auction_house/fn.update_fee_stake_config.html).
+#[derive(Accounts)]
+pub struct UpdateFeeStakeConfig<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Fee stake config PDA account.
+    #[account(
+        mut,
+        seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump=fee_stake_config.bump,
+        has_one=auction_house
+    )]
+    pub fee_stake_config: Box<Account<'info, FeeStakeConfig>>,
+}
+
+pub fn update_fee_stake_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateFeeStakeConfig<'info>>,
+    min_stake_amount: Option<u64>,
+    discounted_seller_fee_basis_points: Option<u16>,
+    lockup_seconds: Option<i64>,
+) -> Result<()> {
+    let fee_stake_config = &mut ctx.accounts.fee_stake_config;
+
+    if let Some(msa) = min_stake_amount {
+        fee_stake_config.min_stake_amount = msa;
+    }
+
+    if let Some(dsfbp) = discounted_seller_fee_basis_points {
+        if dsfbp > ctx.accounts.auction_house.seller_fee_basis_points {
+            return Err(AuctionHouseError::InvalidBasisPoints.into());
+        }
+
+        fee_stake_config.discounted_seller_fee_basis_points = dsfbp;
+    }
+
+    if let Some(ls) = lockup_seconds {
+        fee_stake_config.lockup_seconds = ls;
+    }
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::UpdateFeeStakeConfig,
+        0,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
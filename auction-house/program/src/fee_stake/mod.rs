@@ -0,0 +1,44 @@
+//! Reduced Auction House fees for wallets staking a house-configured token.
+pub mod config;
+pub mod stake;
+pub mod unstake;
+pub use config::*;
+pub use stake::*;
+pub use unstake::*;
+
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{utils::*, AuctionHouse, FeeStake, FeeStakeConfig};
+
+/// Return the seller fee basis points to charge on a sale by `seller`.
+///
+/// The `FeeStakeConfig` and the seller's `FeeStake` accounts are optional and, when used, are
+/// passed after the creator accounts. The discounted rate applies when the seller's stake meets
+/// the configured minimum and never exceeds the house rate.
+pub fn seller_fee_basis_points_for<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    seller: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<u16> {
+    let house_fee_basis_points = auction_house.seller_fee_basis_points;
+
+    let fee_stake_config = match next_program_account::<FeeStakeConfig>(remaining_accounts)? {
+        Some(config) => config,
+        None => return Ok(house_fee_basis_points),
+    };
+    assert_keys_equal(fee_stake_config.auction_house, auction_house.key())?;
+
+    let fee_stake = match next_program_account::<FeeStake>(remaining_accounts)? {
+        Some(stake) => stake,
+        None => return Ok(house_fee_basis_points),
+    };
+    assert_keys_equal(fee_stake.auction_house, auction_house.key())?;
+    assert_keys_equal(fee_stake.wallet, *seller)?;
+
+    if fee_stake.amount < fee_stake_config.min_stake_amount {
+        return Ok(house_fee_basis_points);
+    }
+
+    Ok(house_fee_basis_points.min(fee_stake_config.discounted_seller_fee_basis_points))
+}
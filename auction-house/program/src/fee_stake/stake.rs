@@ -0,0 +1,147 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke};
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, FeeStake, FeeStakeConfig,
+};
+
+/// Accounts for the [`stake_for_fees` handler](auction_house/fn.stake_for_fees.html).
+#[derive(Accounts)]
+pub struct StakeForFees<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Wallet token account holding the tokens to stake.
+    #[account(
+        mut,
+        constraint = stake_token_account.mint == fee_stake_config.stake_mint
+    )]
+    pub stake_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Fee stake config PDA account.
+    #[account(
+        seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump=fee_stake_config.bump,
+        has_one=auction_house,
+        has_one=stake_vault
+    )]
+    pub fee_stake_config: Box<Account<'info, FeeStakeConfig>>,
+
+    /// Token account owned by the Auction House holding all staked tokens.
+    #[account(mut)]
+    pub stake_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Fee stake PDA account recording the wallet's stake.
+    #[account(
+        mut,
+        seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub fee_stake: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Stake `amount` tokens into the Auction House fee stake vault, creating the wallet's fee stake
+/// account if needed. Each stake restarts the lockup period.
+pub fn stake_for_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, StakeForFees<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let stake_token_account = &ctx.accounts.stake_token_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let stake_vault = &ctx.accounts.stake_vault;
+    let fee_stake = &ctx.accounts.fee_stake;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let rent = &ctx.accounts.rent;
+
+    let fee_stake_bump = *ctx
+        .bumps
+        .get("fee_stake")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    let auction_house_key = auction_house.key();
+    let wallet_key = wallet.key();
+    let fee_stake_info = fee_stake.to_account_info();
+
+    let mut stake = if fee_stake_info.data_is_empty() {
+        let fee_stake_seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house_key.as_ref(),
+            wallet_key.as_ref(),
+            &[fee_stake_bump],
+        ];
+
+        create_or_allocate_account_raw(
+            *ctx.program_id,
+            &fee_stake_info,
+            &rent.to_account_info(),
+            system_program,
+            wallet,
+            FEE_STAKE_SIZE,
+            &[],
+            &fee_stake_seeds,
+        )?;
+
+        FeeStake {
+            wallet: wallet_key,
+            auction_house: auction_house_key,
+            amount: 0,
+            staked_at: 0,
+            bump: fee_stake_bump,
+        }
+    } else {
+        FeeStake::try_deserialize(&mut &fee_stake_info.try_borrow_data()?[..])?
+    };
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            &stake_token_account.key(),
+            &stake_vault.key(),
+            &wallet_key,
+            &[],
+            amount,
+        )?,
+        &[
+            stake_token_account.to_account_info(),
+            stake_vault.to_account_info(),
+            token_program.to_account_info(),
+            wallet.to_account_info(),
+        ],
+    )?;
+
+    stake.amount = stake
+        .amount
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    stake.staked_at = Clock::get()?.unix_timestamp;
+
+    stake.try_serialize(&mut *fee_stake_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
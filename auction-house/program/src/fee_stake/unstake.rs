@@ -0,0 +1,123 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, FeeStake, FeeStakeConfig};
+
+/// Accounts for the [`unstake` handler](auction_house/fn.unstake.html).
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// Wallet token account receiving the unstaked tokens.
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == fee_stake_config.stake_mint
+    )]
+    pub destination_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Fee stake config PDA account.
+    #[account(
+        seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump=fee_stake_config.bump,
+        has_one=auction_house,
+        has_one=stake_vault
+    )]
+    pub fee_stake_config: Box<Account<'info, FeeStakeConfig>>,
+
+    /// Token account owned by the Auction House holding all staked tokens.
+    #[account(mut)]
+    pub stake_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Fee stake PDA account recording the wallet's stake.
+    #[account(
+        mut,
+        seeds = [
+            FEE_STAKE.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump=fee_stake.bump,
+        has_one=wallet,
+        has_one=auction_house
+    )]
+    pub fee_stake: Box<Account<'info, FeeStake>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw `amount` staked tokens once the lockup period has passed. The fee stake account is
+/// closed when the full stake is withdrawn.
+pub fn unstake<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>, amount: u64) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let destination_token_account = &ctx.accounts.destination_token_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let fee_stake_config = &ctx.accounts.fee_stake_config;
+    let stake_vault = &ctx.accounts.stake_vault;
+    let token_program = &ctx.accounts.token_program;
+
+    let unlocks_at = ctx
+        .accounts
+        .fee_stake
+        .staked_at
+        .checked_add(fee_stake_config.lockup_seconds)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    if Clock::get()?.unix_timestamp < unlocks_at {
+        return Err(AuctionHouseError::FeeStakeLocked.into());
+    }
+
+    let remaining = ctx
+        .accounts
+        .fee_stake
+        .amount
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::InsufficientStake)?;
+
+    let ah_seeds = [
+        PREFIX.as_bytes(),
+        auction_house.creator.as_ref(),
+        auction_house.treasury_mint.as_ref(),
+        &[auction_house.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            &stake_vault.key(),
+            &destination_token_account.key(),
+            &auction_house.key(),
+            &[],
+            amount,
+        )?,
+        &[
+            stake_vault.to_account_info(),
+            destination_token_account.to_account_info(),
+            token_program.to_account_info(),
+            auction_house.to_account_info(),
+        ],
+        &[&ah_seeds],
+    )?;
+
+    if remaining == 0 {
+        ctx.accounts.fee_stake.close(wallet.to_account_info())?;
+    } else {
+        ctx.accounts.fee_stake.amount = remaining;
+    }
+
+    Ok(())
+}
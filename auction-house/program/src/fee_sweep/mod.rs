@@ -0,0 +1,212 @@
+//! Per-house fee account sweep threshold. Once the fee account holds more than the threshold,
+//! anyone may move the excess to the treasury, keeping the fee account balance bounded.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, math::*,
+    AuctionHouse, AuthorityAction, FeeSweepConfig,
+};
+
+/// Accounts for the [`create_fee_sweep_config` handler](auction_house/fn.create_fee_sweep_config.html).
+#[derive(Accounts)]
+pub struct CreateFeeSweepConfig<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The fee sweep config PDA for the house.
+    #[account(
+        init,
+        payer=authority,
+        space=FEE_SWEEP_CONFIG_SIZE,
+        seeds=[FEE_SWEEP_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub fee_sweep_config: Box<Account<'info, FeeSweepConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_fee_sweep_config` handler](auction_house/fn.update_fee_sweep_config.html).
+#[derive(Accounts)]
+pub struct UpdateFeeSweepConfig<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The fee sweep config PDA for the house.
+    #[account(
+        mut,
+        seeds=[FEE_SWEEP_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump=fee_sweep_config.bump,
+        has_one=auction_house
+    )]
+    pub fee_sweep_config: Box<Account<'info, FeeSweepConfig>>,
+}
+
+/// Accounts for the [`sweep_fee_to_treasury` handler](auction_house/fn.sweep_fee_to_treasury.html).
+#[derive(Accounts)]
+pub struct SweepFeeToTreasury<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=auction_house_fee_account,
+        has_one=auction_house_treasury
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// The fee sweep config PDA for the house.
+    #[account(
+        mut,
+        seeds=[FEE_SWEEP_CONFIG.as_bytes(), auction_house.key().as_ref()],
+        bump=fee_sweep_config.bump,
+        has_one=auction_house
+    )]
+    pub fee_sweep_config: Box<Account<'info, FeeSweepConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_fee_sweep_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateFeeSweepConfig<'info>>,
+    threshold: u64,
+) -> Result<()> {
+    if ctx.accounts.auction_house.treasury_mint != spl_token::native_mint::id() {
+        return err!(AuctionHouseError::FeeSweepRequiresNativeTreasury);
+    }
+
+    let fee_sweep_config = &mut ctx.accounts.fee_sweep_config;
+    fee_sweep_config.auction_house = ctx.accounts.auction_house.key();
+    fee_sweep_config.threshold = threshold;
+    fee_sweep_config.total_swept = 0;
+    fee_sweep_config.last_swept_at = 0;
+    fee_sweep_config.bump = *ctx
+        .bumps
+        .get("fee_sweep_config")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetFeeSweepThreshold,
+        threshold,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+pub fn update_fee_sweep_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateFeeSweepConfig<'info>>,
+    threshold: u64,
+) -> Result<()> {
+    ctx.accounts.fee_sweep_config.threshold = threshold;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetFeeSweepThreshold,
+        threshold,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Move the fee account's balance above the sweep threshold to the treasury.
+pub fn sweep_fee_to_treasury<'info>(
+    ctx: Context<'_, '_, '_, 'info, SweepFeeToTreasury<'info>>,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_fee_account = &ctx.accounts.auction_house_fee_account;
+    let fee_sweep_config = &mut ctx.accounts.fee_sweep_config;
+
+    let balance = auction_house_fee_account.lamports();
+    if balance <= fee_sweep_config.threshold {
+        return err!(AuctionHouseError::FeeBelowSweepThreshold);
+    }
+    let amount = checked_sub(balance, fee_sweep_config.threshold)?;
+
+    let auction_house_key = auction_house.key();
+    let fee_payer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        FEE_PAYER.as_bytes(),
+        &[auction_house.fee_payer_bump],
+    ];
+    invoke_signed(
+        &system_instruction::transfer(
+            &auction_house_fee_account.key(),
+            &ctx.accounts.auction_house_treasury.key(),
+            amount,
+        ),
+        &[
+            auction_house_fee_account.to_account_info(),
+            ctx.accounts.auction_house_treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&fee_payer_seeds],
+    )?;
+
+    fee_sweep_config.total_swept = checked_add(fee_sweep_config.total_swept, amount)?;
+    fee_sweep_config.last_swept_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, FrozenProceeds};
+
+/// Accounts for the [`claim_frozen_proceeds` handler](auction_house/fn.claim_frozen_proceeds.html).
+#[derive(Accounts)]
+pub struct ClaimFrozenProceeds<'info> {
+    /// CHECK: Checked against the frozen proceeds account.
+    /// Wallet the proceeds are owed to.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in claim_frozen_proceeds.
+    /// Recipient's treasury mint associated token account, no longer frozen.
+    #[account(mut)]
+    pub recipient_token_account: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account, refunded the rent of the frozen proceeds account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// Token account owned by the Auction House holding proceeds owed to frozen recipients.
+    #[account(
+        mut,
+        seeds = [
+            FROZEN_PROCEEDS_VAULT.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump
+    )]
+    pub frozen_proceeds_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Frozen proceeds PDA account recording the amount owed to the recipient.
+    #[account(
+        mut,
+        seeds = [
+            FROZEN_PROCEEDS.as_bytes(),
+            auction_house.key().as_ref(),
+            recipient.key().as_ref()
+        ],
+        bump=frozen_proceeds.bump,
+        has_one=auction_house,
+        has_one=recipient,
+        close=auction_house_fee_account
+    )]
+    pub frozen_proceeds: Box<Account<'info, FrozenProceeds>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pay out proceeds held for a recipient whose token account was frozen at settlement. Callable
+/// by anyone once the recipient's associated token account has been thawed.
+pub fn claim_frozen_proceeds<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimFrozenProceeds<'info>>,
+) -> Result<()> {
+    let recipient_token_account = &ctx.accounts.recipient_token_account;
+    let auction_house = &ctx.accounts.auction_house;
+    let frozen_proceeds_vault = &ctx.accounts.frozen_proceeds_vault;
+    let token_program = &ctx.accounts.token_program;
+
+    let recipient_account = assert_is_ata(
+        &recipient_token_account.to_account_info(),
+        &ctx.accounts.recipient.key(),
+        &auction_house.treasury_mint,
+    )?;
+    if recipient_account.is_frozen() {
+        return Err(AuctionHouseError::RecipientAccountFrozen.into());
+    }
+
+    let ah_seeds = [
+        PREFIX.as_bytes(),
+        auction_house.creator.as_ref(),
+        auction_house.treasury_mint.as_ref(),
+        &[auction_house.bump],
+    ];
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            &frozen_proceeds_vault.key(),
+            &recipient_token_account.key(),
+            &auction_house.key(),
+            &[],
+            ctx.accounts.frozen_proceeds.amount,
+        )?,
+        &[
+            frozen_proceeds_vault.to_account_info(),
+            recipient_token_account.to_account_info(),
+            token_program.to_account_info(),
+            auction_house.to_account_info(),
+        ],
+        &[&ah_seeds],
+    )?;
+
+    Ok(())
+}
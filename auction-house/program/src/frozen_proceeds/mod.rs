@@ -0,0 +1,145 @@
+//! Frozen proceeds: treasury mints with a freeze authority (such as permissioned stablecoins) can
+//! leave a recipient's token account frozen. Settlement moves that recipient's share into a vault
+//! owned by the Auction House instead of failing the sale, and the recipient claims it once the
+//! account is thawed.
+pub mod claim;
+pub mod vault;
+pub use claim::*;
+pub use vault::*;
+
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed};
+use spl_token::state::Account as SplAccount;
+use std::slice::Iter;
+
+use crate::{
+    constants::*,
+    errors::AuctionHouseError,
+    pda::{find_frozen_proceeds_address, find_frozen_proceeds_vault_address},
+    utils::*,
+    FrozenProceeds,
+};
+
+/// Transfer `amount` of the treasury mint from the escrow to `recipient_token_account`. When the
+/// mint's freeze authority has frozen that account, the amount is moved into the frozen proceeds
+/// vault instead and credited to the recipient's frozen proceeds PDA.
+///
+/// The vault and the frozen proceeds PDA are only taken from `remaining_accounts` when the
+/// recipient account is frozen, directly after the accounts for that recipient.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_or_hold_proceeds<'a>(
+    remaining_accounts: &mut Iter<AccountInfo<'a>>,
+    auction_house: &AccountInfo<'a>,
+    escrow_payment_account: &AccountInfo<'a>,
+    recipient: &Pubkey,
+    recipient_token_account: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &AccountInfo<'a>,
+    signer_seeds: &[&[u8]],
+    fee_payer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    let recipient_account: SplAccount = assert_initialized(recipient_token_account)?;
+    let destination = if recipient_account.is_frozen() {
+        let vault = next_account_info(remaining_accounts)?;
+        let frozen_proceeds_info = next_account_info(remaining_accounts)?;
+        hold_frozen_proceeds(
+            auction_house.key,
+            recipient,
+            vault,
+            frozen_proceeds_info,
+            fee_payer,
+            system_program,
+            rent,
+            fee_payer_seeds,
+            amount,
+        )?;
+        vault
+    } else {
+        recipient_token_account
+    };
+
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            escrow_payment_account.key,
+            destination.key,
+            auction_house.key,
+            &[],
+            amount,
+        )?,
+        &[
+            escrow_payment_account.clone(),
+            destination.clone(),
+            token_program.clone(),
+            auction_house.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Credit `amount` to the recipient's frozen proceeds PDA, creating it if needed.
+#[allow(clippy::too_many_arguments)]
+fn hold_frozen_proceeds<'a>(
+    auction_house: &Pubkey,
+    recipient: &Pubkey,
+    vault: &AccountInfo<'a>,
+    frozen_proceeds_info: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    rent: &AccountInfo<'a>,
+    fee_payer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    let (vault_key, _) = find_frozen_proceeds_vault_address(auction_house);
+    assert_keys_equal(vault_key, *vault.key)?;
+    let (frozen_proceeds_key, bump) = find_frozen_proceeds_address(auction_house, recipient);
+    assert_keys_equal(frozen_proceeds_key, *frozen_proceeds_info.key)?;
+
+    let held = if frozen_proceeds_info.data_is_empty() {
+        let frozen_proceeds_seeds = [
+            FROZEN_PROCEEDS.as_bytes(),
+            auction_house.as_ref(),
+            recipient.as_ref(),
+            &[bump],
+        ];
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            frozen_proceeds_info,
+            rent,
+            system_program,
+            fee_payer,
+            FROZEN_PROCEEDS_SIZE,
+            fee_payer_seeds,
+            &frozen_proceeds_seeds,
+        )?;
+
+        0
+    } else {
+        assert_owned_by(frozen_proceeds_info, &crate::id())?;
+        FrozenProceeds::try_deserialize(&mut frozen_proceeds_info.try_borrow_data()?.as_ref())?
+            .amount
+    };
+
+    msg!(
+        "Recipient {} token account is frozen, holding {} for claim",
+        recipient,
+        amount
+    );
+
+    let frozen_proceeds = FrozenProceeds {
+        auction_house: *auction_house,
+        recipient: *recipient,
+        amount: held
+            .checked_add(amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?,
+        bump,
+    };
+    frozen_proceeds.try_serialize(&mut *frozen_proceeds_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
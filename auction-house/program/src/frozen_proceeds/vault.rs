@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{constants::*, errors::AuctionHouseError, AuctionHouse};
+
+/// Accounts for the [`create_frozen_proceeds_vault` handler](auction_house/fn.create_frozen_proceeds_vault.html).
+#[derive(Accounts)]
+pub struct CreateFrozenProceedsVault<'info> {
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=treasury_mint
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// Token account owned by the Auction House holding proceeds owed to frozen recipients.
+    #[account(
+        init,
+        payer = authority,
+        seeds = [
+            FROZEN_PROCEEDS_VAULT.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump,
+        token::mint = treasury_mint,
+        token::authority = auction_house
+    )]
+    pub frozen_proceeds_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Create the vault that holds sale proceeds for recipients whose treasury mint token account is
+/// frozen. If the treasury mint freezes new accounts by default, the vault must be thawed by the
+/// freeze authority before it can receive proceeds.
+pub fn create_frozen_proceeds_vault<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateFrozenProceedsVault<'info>>,
+) -> Result<()> {
+    if ctx.accounts.treasury_mint.key() == spl_token::native_mint::id() {
+        return Err(AuctionHouseError::FrozenProceedsRequireSplTreasury.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! House balances: a read-only view of the treasury and fee account balances, returned in the
+//! transaction's return data so dashboards can read them with one simulated instruction.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::set_return_data, program_pack::Pack},
+};
+use anchor_spl::token::Mint;
+use spl_token::state::Account as SplAccount;
+
+use crate::{constants::*, utils::*, AuctionHouse, FeeSweepConfig, HouseBalances};
+
+/// Accounts for the [`get_house_balances` handler](auction_house/fn.get_house_balances.html).
+#[derive(Accounts)]
+pub struct GetHouseBalances<'info> {
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint,
+        has_one=auction_house_fee_account,
+        has_one=auction_house_treasury
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+}
+
+/// Return the house's [`HouseBalances`]. The fee sweep config may be passed in
+/// `remaining_accounts` to report the fees a sweep would move to the treasury.
+pub fn get_house_balances<'info>(
+    ctx: Context<'_, '_, '_, 'info, GetHouseBalances<'info>>,
+) -> Result<()> {
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let treasury_balance = if ctx.accounts.treasury_mint.key() == spl_token::native_mint::id() {
+        auction_house_treasury.lamports()
+    } else if auction_house_treasury.data_is_empty() {
+        0
+    } else {
+        SplAccount::unpack(&auction_house_treasury.try_borrow_data()?)?.amount
+    };
+
+    let fee_account_balance = ctx.accounts.auction_house_fee_account.lamports();
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let unswept_fees = match next_program_account::<FeeSweepConfig>(remaining_accounts)? {
+        Some(fee_sweep_config) => {
+            assert_keys_equal(
+                fee_sweep_config.auction_house,
+                ctx.accounts.auction_house.key(),
+            )?;
+            fee_account_balance.saturating_sub(fee_sweep_config.threshold)
+        }
+        None => 0,
+    };
+
+    let balances = HouseBalances {
+        treasury_balance,
+        fee_account_balance,
+        unswept_fees,
+    };
+    set_return_data(&balances.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! A house notice: a small account the Auction House authority writes maintenance and pause
+//! notices to, so frontends read operational status from chain alongside the house itself.
+use anchor_lang::prelude::*;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction, HouseNotice, NoticeStatus,
+};
+
+/// Accounts for the [`create_house_notice` handler](auction_house/fn.create_house_notice.html).
+#[derive(Accounts)]
+pub struct CreateHouseNotice<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The house notice PDA for the Auction House.
+    #[account(
+        init,
+        payer=authority,
+        space=HOUSE_NOTICE_SIZE,
+        seeds=[HOUSE_NOTICE.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub house_notice: Box<Account<'info, HouseNotice>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_house_notice<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateHouseNotice<'info>>,
+) -> Result<()> {
+    let house_notice = &mut ctx.accounts.house_notice;
+    house_notice.auction_house = ctx.accounts.auction_house.key();
+    house_notice.status = NoticeStatus::None;
+    house_notice.message_hash = [0; 32];
+    house_notice.expires_at = 0;
+    house_notice.updated_at = Clock::get()?.unix_timestamp;
+    house_notice.bump = *ctx
+        .bumps
+        .get("house_notice")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`set_house_notice` handler](auction_house/fn.set_house_notice.html).
+#[derive(Accounts)]
+pub struct SetHouseNotice<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The house notice PDA for the Auction House.
+    #[account(
+        mut,
+        seeds=[HOUSE_NOTICE.as_bytes(), auction_house.key().as_ref()],
+        bump=house_notice.bump,
+        has_one=auction_house
+    )]
+    pub house_notice: Box<Account<'info, HouseNotice>>,
+}
+
+/// Replace the house notice. Clear it by setting `status` to `NoticeStatus::None`.
+pub fn set_house_notice<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetHouseNotice<'info>>,
+    status: NoticeStatus,
+    message_hash: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    if expires_at != 0 && expires_at <= now {
+        return Err(AuctionHouseError::NoticeExpiryInPast.into());
+    }
+
+    let house_notice = &mut ctx.accounts.house_notice;
+    house_notice.status = status;
+    house_notice.message_hash = message_hash;
+    house_notice.expires_at = expires_at;
+    house_notice.updated_at = now;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetHouseNotice,
+        status as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Trading competition leaderboards. The house authority opens a leaderboard for an epoch with a
+//! fixed window, and every sale settled in that window with the leaderboard passed adds its price
+//! to the buyer's and the seller's volume, so competitions can be settled from chain state alone.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, AuthorityAction, Leaderboard,
+};
+
+/// Accounts for the [`create_leaderboard` handler](auction_house/fn.create_leaderboard.html).
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct CreateLeaderboard<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The leaderboard PDA for the epoch.
+    #[account(
+        init,
+        payer=authority,
+        space=LEADERBOARD_SIZE,
+        seeds=[
+            LEADERBOARD.as_bytes(),
+            auction_house.key().as_ref(),
+            &epoch.to_le_bytes()
+        ],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_leaderboard<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateLeaderboard<'info>>,
+    epoch: u64,
+    starts_at: i64,
+    ends_at: i64,
+) -> Result<()> {
+    if ends_at <= starts_at {
+        return Err(AuctionHouseError::InvalidLeaderboardWindow.into());
+    }
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.auction_house = ctx.accounts.auction_house.key();
+    leaderboard.epoch = epoch;
+    leaderboard.starts_at = starts_at;
+    leaderboard.ends_at = ends_at;
+    leaderboard.bump = *ctx
+        .bumps
+        .get("leaderboard")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::CreateLeaderboard,
+        epoch,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Add a sale of `price` to the buyer's and seller's volume on the leaderboard, if one was passed
+/// in `remaining_accounts`. Sales settled outside the leaderboard's window are not counted.
+pub fn record_leaderboard_settlement<'a, 'info>(
+    auction_house: &Pubkey,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    price: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let mut leaderboard = match next_program_account::<Leaderboard>(remaining_accounts)? {
+        Some(leaderboard) => leaderboard,
+        None => return Ok(()),
+    };
+    assert_keys_equal(leaderboard.auction_house, *auction_house)?;
+
+    if !leaderboard.is_open(Clock::get()?.unix_timestamp) {
+        return Ok(());
+    }
+
+    Leaderboard::record(&mut leaderboard.buyers, *buyer, price);
+    Leaderboard::record(&mut leaderboard.sellers, *seller, price);
+
+    leaderboard.exit(&crate::id())
+}
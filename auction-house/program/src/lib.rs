@@ -6,26 +6,88 @@
 #![allow(clippy::result_large_err)]
 
 pub mod auctioneer;
+pub mod auctioneer_allowlist;
+pub mod auctioneer_stats;
+pub mod authority_log;
 pub mod bid;
+pub mod bid_expiry;
+pub mod bid_ticket;
+pub mod bid_transfer;
+pub mod buyer_protection;
 pub mod cancel;
+pub mod compliance;
 pub mod constants;
 pub mod deposit;
+pub mod deposit_authority;
+pub mod dispute;
 pub mod errors;
 pub mod execute_sale;
+pub mod fee_alarm;
+pub mod fee_stake;
+pub mod fee_sweep;
+pub mod frozen_proceeds;
+pub mod house_balances;
+pub mod house_notice;
+pub mod leaderboard;
+pub mod listing_currency;
+pub mod listing_fee;
+pub mod listing_price_bounds;
+pub mod marketplace_binding;
+pub mod math;
+pub mod omnibus;
+pub mod payout_delay;
 pub mod pda;
+pub mod prepare_buyer;
+pub mod price_history;
+pub mod public_bid_index;
+#[cfg(feature = "receipts")]
 pub mod receipt;
+pub mod relist_cooldown;
+pub mod rounding_policy;
+pub mod royalty_bonus;
 pub mod sell;
+pub mod seller_financing;
+pub mod settlement_marker;
+pub mod shared_custody;
+pub mod single_active_listing;
+pub mod spending_cap;
 pub mod state;
+#[cfg(feature = "strict")]
+pub mod strict;
+pub mod tax_withholding;
+pub mod token_standard_policy;
+pub mod trade_sequence;
+pub mod transferability;
+pub mod treasury_withdrawal;
 pub mod utils;
+pub mod verified_creators;
+pub mod voucher;
+pub mod wallet_receipt;
+pub mod wanted;
 pub mod withdraw;
 
 pub use state::*;
 
 use crate::{
-    auctioneer::*, bid::*, cancel::*, constants::*, deposit::*, errors::AuctionHouseError,
-    execute_sale::*, receipt::*, sell::*, utils::*, withdraw::*,
+    auctioneer::*, auctioneer_allowlist::*, auctioneer_stats::*, authority_log::*, bid::*,
+    bid_expiry::*, bid_ticket::*, bid_transfer::*, buyer_protection::*, cancel::*, compliance::*,
+    constants::*, deposit::*, deposit_authority::*, dispute::*, errors::AuctionHouseError,
+    execute_sale::*, fee_alarm::*, fee_stake::*, fee_sweep::*, frozen_proceeds::*,
+    house_balances::*, house_notice::*, leaderboard::*, listing_currency::*, listing_fee::*,
+    listing_price_bounds::*, marketplace_binding::*, omnibus::*, payout_delay::*, prepare_buyer::*,
+    price_history::*, public_bid_index::*, relist_cooldown::*, rounding_policy::*,
+    royalty_bonus::*, sell::*, seller_financing::*, settlement_marker::*, shared_custody::*,
+    single_active_listing::*, spending_cap::*, tax_withholding::*, token_standard_policy::*,
+    trade_sequence::*, treasury_withdrawal::*, utils::*, verified_creators::*, voucher::*,
+    wallet_receipt::*, wanted::*, withdraw::*,
 };
 
+#[cfg(feature = "receipts")]
+use crate::receipt::*;
+
+#[cfg(feature = "strict")]
+use crate::strict::*;
+
 use anchor_lang::{
     prelude::*,
     solana_program::{program::invoke_signed, system_instruction},
@@ -75,6 +137,14 @@ pub mod auction_house {
             &[&seeds],
         )?;
 
+        record_authority_action(
+            &ctx.accounts.auction_house,
+            &ctx.accounts.authority.key(),
+            AuthorityAction::WithdrawFromFee,
+            amount,
+            &mut ctx.remaining_accounts.iter(),
+        )?;
+
         Ok(())
     }
 
@@ -139,6 +209,14 @@ pub mod auction_house {
             )?;
         }
 
+        record_authority_action(
+            &ctx.accounts.auction_house,
+            &ctx.accounts.authority.key(),
+            AuthorityAction::WithdrawFromTreasury,
+            amount,
+            &mut ctx.remaining_accounts.iter(),
+        )?;
+
         Ok(())
     }
 
@@ -163,6 +241,10 @@ pub mod auction_house {
         let rent = &ctx.accounts.rent;
         let is_native = treasury_mint.key() == spl_token::native_mint::id();
 
+        if auction_house.is_immutable {
+            return Err(AuctionHouseError::AuctionHouseImmutable.into());
+        }
+
         if let Some(sfbp) = seller_fee_basis_points {
             if sfbp > 10000 {
                 return Err(AuctionHouseError::InvalidBasisPoints.into());
@@ -209,10 +291,18 @@ pub mod auction_house {
             )?;
         }
 
+        record_authority_action(
+            &ctx.accounts.auction_house,
+            &ctx.accounts.authority.key(),
+            AuthorityAction::UpdateAuctionHouse,
+            0,
+            &mut ctx.remaining_accounts.iter(),
+        )?;
+
         Ok(())
     }
 
-    /// Create a new Auction House instance.
+    /// Create a new Auction House instance. An immutable house can never be updated.
     pub fn create_auction_house<'info>(
         ctx: Context<'_, '_, '_, 'info, CreateAuctionHouse<'info>>,
         _bump: u8,
@@ -221,6 +311,7 @@ pub mod auction_house {
         seller_fee_basis_points: u16,
         requires_sign_off: bool,
         can_change_sale_price: bool,
+        immutable: bool,
     ) -> Result<()> {
         let treasury_mint = &ctx.accounts.treasury_mint;
         let payer = &ctx.accounts.payer;
@@ -269,6 +360,7 @@ pub mod auction_house {
         auction_house.seller_fee_basis_points = seller_fee_basis_points;
         auction_house.requires_sign_off = requires_sign_off;
         auction_house.can_change_sale_price = can_change_sale_price;
+        auction_house.is_immutable = immutable;
         auction_house.creator = authority.key();
         auction_house.authority = authority.key();
         auction_house.treasury_mint = treasury_mint.key();
@@ -348,6 +440,23 @@ pub mod auction_house {
         )
     }
 
+    /// Create a private bid on a native SOL house, funding the escrow with exactly `buyer_price` in the same instruction.
+    pub fn bid_with_transfer<'info>(
+        ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        bid::bid_with_transfer(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
     pub fn auctioneer_buy<'info>(
         ctx: Context<'_, '_, '_, 'info, AuctioneerBuy<'info>>,
         trade_state_bump: u8,
@@ -474,6 +583,26 @@ pub mod auction_house {
         )
     }
 
+    /// Execute a sale with every wallet, token and metadata account fully typed and constrained.
+    #[cfg(feature = "strict")]
+    pub fn strict_execute_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, StrictExecuteSale<'info>>,
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        strict::strict_execute_sale(
+            ctx,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+        )
+    }
+
     pub fn auctioneer_execute_sale<'info>(
         ctx: Context<'_, '_, '_, 'info, AuctioneerExecuteSale<'info>>,
         escrow_payment_bump: u8,
@@ -566,6 +695,13 @@ pub mod auction_house {
         withdraw::auctioneer_withdraw(ctx, escrow_payment_bump, amount)
     }
 
+    /// Withdraw everything held in the wallet's escrow payment accounts across several Auction Houses.
+    pub fn withdraw_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawMany<'info>>,
+    ) -> Result<()> {
+        withdraw::withdraw_many(ctx)
+    }
+
     /// Close the escrow account of the user.
     pub fn close_escrow_account<'info>(
         ctx: Context<'_, '_, '_, 'info, CloseEscrowAccount<'info>>,
@@ -611,7 +747,722 @@ pub mod auction_house {
         auctioneer::update_auctioneer(ctx, scopes)
     }
 
+    /// Delegate the Auction House to a new auctioneer authority, keeping the replaced one valid
+    /// for `grace_period_seconds`.
+    pub fn rotate_auctioneer<'info>(
+        ctx: Context<'_, '_, '_, 'info, RotateAuctioneer<'info>>,
+        grace_period_seconds: i64,
+    ) -> Result<()> {
+        auctioneer::rotate_auctioneer(ctx, grace_period_seconds)
+    }
+
+    /// Create the allowlist of auctioneer programs the house may delegate to.
+    pub fn create_auctioneer_allowlist<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAuctioneerAllowlist<'info>>,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        auctioneer_allowlist::create_auctioneer_allowlist(ctx, programs)
+    }
+
+    /// Replace the auctioneer programs on the house allowlist.
+    pub fn update_auctioneer_allowlist<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAuctioneerAllowlist<'info>>,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        auctioneer_allowlist::update_auctioneer_allowlist(ctx, programs)
+    }
+
+    /// Start tracking settlement stats for the delegated auctioneer.
+    pub fn create_auctioneer_stats<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAuctioneerStats<'info>>,
+    ) -> Result<()> {
+        auctioneer_stats::create_auctioneer_stats(ctx)
+    }
+
+    /// Create the fee stake config and vault used to discount fees for staking wallets.
+    pub fn create_fee_stake_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateFeeStakeConfig<'info>>,
+        min_stake_amount: u64,
+        discounted_seller_fee_basis_points: u16,
+        lockup_seconds: i64,
+    ) -> Result<()> {
+        fee_stake::create_fee_stake_config(
+            ctx,
+            min_stake_amount,
+            discounted_seller_fee_basis_points,
+            lockup_seconds,
+        )
+    }
+
+    /// Update the minimum stake, discounted fee or lockup period of the fee stake config.
+    pub fn update_fee_stake_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateFeeStakeConfig<'info>>,
+        min_stake_amount: Option<u64>,
+        discounted_seller_fee_basis_points: Option<u16>,
+        lockup_seconds: Option<i64>,
+    ) -> Result<()> {
+        fee_stake::update_fee_stake_config(
+            ctx,
+            min_stake_amount,
+            discounted_seller_fee_basis_points,
+            lockup_seconds,
+        )
+    }
+
+    /// Stake `amount` tokens into the fee stake vault to receive discounted fees on sales.
+    pub fn stake_for_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, StakeForFees<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        fee_stake::stake_for_fees(ctx, amount)
+    }
+
+    /// Withdraw `amount` staked tokens from the fee stake vault.
+    pub fn unstake<'info>(
+        ctx: Context<'_, '_, '_, 'info, Unstake<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        fee_stake::unstake(ctx, amount)
+    }
+
+    /// Set how long a mint must wait after settling before it can be listed again. Zero disables the cooldown.
+    pub fn set_relist_cooldown<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRelistCooldown<'info>>,
+        relist_cooldown_seconds: i64,
+    ) -> Result<()> {
+        relist_cooldown::set_relist_cooldown(ctx, relist_cooldown_seconds)
+    }
+
+    /// Create the authority log that journals authority-level actions on the Auction House.
+    pub fn create_authority_log<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAuthorityLog<'info>>,
+    ) -> Result<()> {
+        authority_log::create_authority_log(ctx)
+    }
+
+    /// Set the buyer protection window during which settled sales are held in escrow, and the
+    /// restocking fee charged when a buyer cancels. A zero window disables buyer protection.
+    pub fn set_buyer_protection<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBuyerProtection<'info>>,
+        buyer_protection_seconds: i64,
+        restocking_fee_basis_points: u16,
+    ) -> Result<()> {
+        buyer_protection::set_buyer_protection(
+            ctx,
+            buyer_protection_seconds,
+            restocking_fee_basis_points,
+        )
+    }
+
+    /// Cancel a protected settlement during its buyer protection window for a refund less the restocking fee.
+    pub fn cancel_protected_settlement<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelProtectedSettlement<'info>>,
+    ) -> Result<()> {
+        buyer_protection::cancel_protected_settlement(ctx)
+    }
+
+    /// Deliver a protected settlement to the buyer and seller once its buyer protection window has passed.
+    pub fn finalize_protected_settlement<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeProtectedSettlement<'info>>,
+    ) -> Result<()> {
+        buyer_protection::finalize_protected_settlement(ctx)
+    }
+
+    /// Finalize a batch of protected settlements whose buyer protection window has passed, skipping any already finalized.
+    pub fn finalize_protected_settlements<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeProtectedSettlements<'info>>,
+    ) -> Result<()> {
+        buyer_protection::finalize_protected_settlements(ctx)
+    }
+
+    /// Set how long seller proceeds are held after settlement before they can be claimed. A zero
+    /// delay pays sellers at settlement.
+    pub fn set_payout_delay<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetPayoutDelay<'info>>,
+        payout_delay_seconds: i64,
+    ) -> Result<()> {
+        payout_delay::set_payout_delay(ctx, payout_delay_seconds)
+    }
+
+    /// Pay held proceeds to the seller once the payout delay has passed.
+    pub fn claim_proceeds<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimProceeds<'info>>,
+    ) -> Result<()> {
+        payout_delay::claim_proceeds(ctx)
+    }
+
+    /// Refund held proceeds to the buyer before the payout delay has passed.
+    pub fn clawback_proceeds<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClawbackProceeds<'info>>,
+    ) -> Result<()> {
+        payout_delay::clawback_proceeds(ctx)
+    }
+
+    /// Fail unless a protected sale's settlement is at `expected`. Prepend to a retried
+    /// settlement step so it only runs if the previous attempt did not get that far.
+    pub fn assert_settlement_status<'info>(
+        ctx: Context<'_, '_, '_, 'info, AssertSettlementStatus<'info>>,
+        expected: SettlementStatus,
+    ) -> Result<()> {
+        settlement_marker::assert_settlement_status(ctx, expected)
+    }
+
+    /// Set the arbiter allowed to resolve disputes on protected settlements.
+    pub fn set_arbiter<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetArbiter<'info>>,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        dispute::set_arbiter(ctx, arbiter)
+    }
+
+    /// Open a dispute on a protected settlement during its buyer protection window, freezing it until resolved.
+    pub fn open_dispute<'info>(ctx: Context<'_, '_, '_, 'info, OpenDispute<'info>>) -> Result<()> {
+        dispute::open_dispute(ctx)
+    }
+
+    /// Resolve a disputed protected settlement in favor of the buyer or the seller.
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveDispute<'info>>,
+        resolution: DisputeResolution,
+    ) -> Result<()> {
+        dispute::resolve_dispute(ctx, resolution)
+    }
+
+    /// Allow or disallow financed sales settled on a down payment with a lien for the balance.
+    pub fn set_seller_financing<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetSellerFinancing<'info>>,
+        allow_seller_financing: bool,
+    ) -> Result<()> {
+        seller_financing::set_seller_financing(ctx, allow_seller_financing)
+    }
+
+    /// Set the minimum and maximum prices `sell` accepts for new listings. `None` removes a bound.
+    pub fn set_listing_price_bounds<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetListingPriceBounds<'info>>,
+        min_listing_price: Option<u64>,
+        max_listing_price: Option<u64>,
+    ) -> Result<()> {
+        listing_price_bounds::set_listing_price_bounds(ctx, min_listing_price, max_listing_price)
+    }
+
+    /// Execute a sale on a down payment of `buyer_price`, recording a lien for the `lien_amount` the buyer still owes the seller.
+    #[inline(never)]
+    pub fn execute_financed_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteFinancedSale<'info>>,
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        lien_amount: u64,
+    ) -> Result<()> {
+        seller_financing::execute_financed_sale(
+            ctx,
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+            lien_amount,
+        )
+    }
+
+    /// Repay up to `amount` of a lien to the seller, closing the lien once it is fully repaid.
+    pub fn repay_lien<'info>(
+        ctx: Context<'_, '_, '_, 'info, RepayLien<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        seller_financing::repay_lien(ctx, amount)
+    }
+
+    /// Create the vault holding sale proceeds owed to recipients whose token accounts are frozen.
+    pub fn create_frozen_proceeds_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateFrozenProceedsVault<'info>>,
+    ) -> Result<()> {
+        frozen_proceeds::create_frozen_proceeds_vault(ctx)
+    }
+
+    /// Pay out proceeds held for a recipient once their token account has been thawed.
+    pub fn claim_frozen_proceeds<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimFrozenProceeds<'info>>,
+    ) -> Result<()> {
+        frozen_proceeds::claim_frozen_proceeds(ctx)
+    }
+
+    /// Charge `fee_basis_points` instead of the house fee when an auctioneer listing settles.
+    pub fn auctioneer_set_listing_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerSetListingFee<'info>>,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        listing_fee::auctioneer_set_listing_fee(ctx, fee_basis_points)
+    }
+
+    /// Approve `depositor` to deposit into your escrow payment account on your behalf.
+    pub fn approve_deposit_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveDepositAuthority<'info>>,
+        depositor: Pubkey,
+    ) -> Result<()> {
+        deposit_authority::approve_deposit_authority(ctx, depositor)
+    }
+
+    /// Revoke a depositor's approval to deposit into your escrow payment account.
+    pub fn revoke_deposit_authority<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeDepositAuthority<'info>>,
+    ) -> Result<()> {
+        deposit_authority::revoke_deposit_authority(ctx)
+    }
+
+    /// Deposit `amount` from an approved depositor into a wallet's escrow payment account.
+    pub fn deposit_on_behalf<'info>(
+        ctx: Context<'_, '_, '_, 'info, DepositOnBehalf<'info>>,
+        escrow_payment_bump: u8,
+        amount: u64,
+    ) -> Result<()> {
+        deposit_authority::deposit_on_behalf(ctx, escrow_payment_bump, amount)
+    }
+
+    /// Create the index of the highest open public bids on a mint.
+    pub fn create_public_bid_index<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePublicBidIndex<'info>>,
+    ) -> Result<()> {
+        public_bid_index::create_public_bid_index(ctx)
+    }
+
+    /// Drop settled bids from a public bid index.
+    pub fn prune_public_bid_index<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrunePublicBidIndex<'info>>,
+    ) -> Result<()> {
+        public_bid_index::prune_public_bid_index(ctx)
+    }
+
+    /// Expire an open bid at `expires_at`.
+    pub fn set_bid_expiry<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetBidExpiry<'info>>,
+        buyer_price: u64,
+        token_size: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        bid_expiry::set_bid_expiry(ctx, buyer_price, token_size, expires_at)
+    }
+
+    /// Close expired bids, refunding their buyers and paying the reclaimed rent to the caller.
+    pub fn sweep_expired_bids<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepExpiredBids<'info>>,
+    ) -> Result<()> {
+        bid_expiry::sweep_expired_bids(ctx)
+    }
+
+    /// Create the house's compliance config and require its compliance program to approve every buy and sell.
+    pub fn create_compliance_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateComplianceConfig<'info>>,
+        jurisdiction_code: [u8; 2],
+        compliance_program: Pubkey,
+    ) -> Result<()> {
+        compliance::create_compliance_config(ctx, jurisdiction_code, compliance_program)
+    }
+
+    /// Change the house's jurisdiction or compliance program. The default pubkey turns the hook off.
+    pub fn update_compliance_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateComplianceConfig<'info>>,
+        jurisdiction_code: [u8; 2],
+        compliance_program: Pubkey,
+    ) -> Result<()> {
+        compliance::update_compliance_config(ctx, jurisdiction_code, compliance_program)
+    }
+
+    /// Create the house's tax withholding config, routing `withholding_basis_points` of every sale to `recipient`.
+    pub fn create_tax_withholding<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTaxWithholding<'info>>,
+        withholding_basis_points: u16,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        tax_withholding::create_tax_withholding(ctx, withholding_basis_points, recipient)
+    }
+
+    /// Change the house's tax withholding rate or recipient. Zero basis points turns withholding off.
+    pub fn update_tax_withholding<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateTaxWithholding<'info>>,
+        withholding_basis_points: u16,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        tax_withholding::update_tax_withholding(ctx, withholding_basis_points, recipient)
+    }
+
+    /// Post a public offer, paid from the buyer's escrow, for any token meeting `criteria`. The
+    /// price declines linearly from `start_price` to `floor_price` by `end_time`.
+    pub fn create_wanted_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateWantedListing<'info>>,
+        criteria: WantedCriteria,
+        start_price: u64,
+        floor_price: u64,
+        end_time: i64,
+    ) -> Result<()> {
+        wanted::create_wanted_listing(ctx, criteria, start_price, floor_price, end_time)
+    }
+
+    /// Withdraw a wanted listing, returning its rent to the buyer.
+    pub fn cancel_wanted_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelWantedListing<'info>>,
+    ) -> Result<()> {
+        wanted::cancel_wanted_listing(ctx)
+    }
+
+    /// Sell a token meeting a wanted listing's criteria to its buyer at the current offer price.
+    pub fn fill_wanted<'info>(ctx: Context<'_, '_, '_, 'info, FillWanted<'info>>) -> Result<()> {
+        wanted::fill_wanted(ctx)
+    }
+
+    /// Let anyone sweep the fee account balance above `threshold` to the treasury.
+    pub fn create_fee_sweep_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateFeeSweepConfig<'info>>,
+        threshold: u64,
+    ) -> Result<()> {
+        fee_sweep::create_fee_sweep_config(ctx, threshold)
+    }
+
+    /// Change the fee account balance kept back by fee sweeps.
+    pub fn update_fee_sweep_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateFeeSweepConfig<'info>>,
+        threshold: u64,
+    ) -> Result<()> {
+        fee_sweep::update_fee_sweep_config(ctx, threshold)
+    }
+
+    /// Move the fee account balance above the house's sweep threshold to the treasury.
+    pub fn sweep_fee_to_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepFeeToTreasury<'info>>,
+    ) -> Result<()> {
+        fee_sweep::sweep_fee_to_treasury(ctx)
+    }
+
+    /// Emit a low balance event from settlements that leave the fee account below `threshold`.
+    /// Zero turns the alarm off.
+    pub fn set_low_fee_balance_threshold<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetLowFeeBalanceThreshold<'info>>,
+        threshold: u64,
+    ) -> Result<()> {
+        fee_alarm::set_low_fee_balance_threshold(ctx, threshold)
+    }
+
+    /// Top up the house's fee account with `amount` lamports from any funder.
+    pub fn fund_fee_account<'info>(
+        ctx: Context<'_, '_, '_, 'info, FundFeeAccount<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        fee_alarm::fund_fee_account(ctx, amount)
+    }
+
+    /// Cap the auctioneer's treasury withdrawals at `max_per_period` every `period_seconds`.
+    pub fn create_treasury_withdrawal_limit<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTreasuryWithdrawalLimit<'info>>,
+        max_per_period: u64,
+        period_seconds: i64,
+    ) -> Result<()> {
+        treasury_withdrawal::create_treasury_withdrawal_limit(ctx, max_per_period, period_seconds)
+    }
+
+    /// Change the cap on the auctioneer's treasury withdrawals.
+    pub fn update_treasury_withdrawal_limit<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateTreasuryWithdrawalLimit<'info>>,
+        max_per_period: u64,
+        period_seconds: i64,
+    ) -> Result<()> {
+        treasury_withdrawal::update_treasury_withdrawal_limit(ctx, max_per_period, period_seconds)
+    }
+
+    /// Withdraw `amount` from the treasury as the auctioneer, within the house's withdrawal limit.
+    pub fn auctioneer_withdraw_from_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerWithdrawFromTreasury<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        treasury_withdrawal::auctioneer_withdraw_from_treasury(ctx, amount)
+    }
+
+    /// Require every bid to burn a ticket NFT from `collection`. The default key turns the gate
+    /// off.
+    pub fn create_bid_ticket_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidTicketConfig<'info>>,
+        collection: Pubkey,
+    ) -> Result<()> {
+        bid_ticket::create_bid_ticket_config(ctx, collection)
+    }
+
+    /// Change the ticket collection bids burn from, or turn the gate off with the default key.
+    pub fn update_bid_ticket_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateBidTicketConfig<'info>>,
+        collection: Pubkey,
+    ) -> Result<()> {
+        bid_ticket::update_bid_ticket_config(ctx, collection)
+    }
+
+    /// Allow or disallow buyers receiving purchases at token accounts other than their associated token account.
+    pub fn set_wallet_bound_receipts<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetWalletBoundReceipts<'info>>,
+        allow_wallet_bound_receipts: bool,
+    ) -> Result<()> {
+        wallet_receipt::set_wallet_bound_receipts(ctx, allow_wallet_bound_receipts)
+    }
+
+    /// Set how royalties and house fees are rounded at settlement.
+    pub fn set_rounding_policy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRoundingPolicy<'info>>,
+        rounding_policy: RoundingPolicy,
+    ) -> Result<()> {
+        rounding_policy::set_rounding_policy(ctx, rounding_policy)
+    }
+
+    /// Set which token standards may be listed, as `TOKEN_STANDARD_*` bits. An empty policy
+    /// allows every token standard.
+    pub fn set_token_standard_policy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetTokenStandardPolicy<'info>>,
+        token_standard_policy: u8,
+    ) -> Result<()> {
+        token_standard_policy::set_token_standard_policy(ctx, token_standard_policy)
+    }
+
+    /// Set whether only tokens whose creators and collection are all verified may be listed.
+    pub fn set_requires_verified_creators<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetRequiresVerifiedCreators<'info>>,
+        requires_verified_creators: bool,
+    ) -> Result<()> {
+        verified_creators::set_requires_verified_creators(ctx, requires_verified_creators)
+    }
+
+    /// Set whether a seller may have only one open listing of a mint at a time.
+    pub fn set_single_active_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetSingleActiveListing<'info>>,
+        single_active_listing: bool,
+    ) -> Result<()> {
+        single_active_listing::set_single_active_listing(ctx, single_active_listing)
+    }
+
+    /// Set whether new listings lock the treasury mint and decimals they were placed in.
+    pub fn set_listing_currency_lock<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetListingCurrencyLock<'info>>,
+        locks_listing_currency: bool,
+    ) -> Result<()> {
+        listing_currency::set_listing_currency_lock(ctx, locks_listing_currency)
+    }
+
+    /// Create the house notice frontends read maintenance and pause notices from.
+    pub fn create_house_notice<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateHouseNotice<'info>>,
+    ) -> Result<()> {
+        house_notice::create_house_notice(ctx)
+    }
+
+    /// Post, replace or clear the house notice.
+    pub fn set_house_notice<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetHouseNotice<'info>>,
+        status: NoticeStatus,
+        message_hash: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        house_notice::set_house_notice(ctx, status, message_hash, expires_at)
+    }
+
+    /// Create the trade sequence that numbers listings and bids placed on the Auction House.
+    pub fn create_trade_sequence<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTradeSequence<'info>>,
+    ) -> Result<()> {
+        trade_sequence::create_trade_sequence(ctx)
+    }
+
+    /// Create the escrow and token accounts a wallet needs to bid, skipping any that exist.
+    pub fn prepare_buyer<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrepareBuyer<'info>>,
+    ) -> Result<()> {
+        prepare_buyer::prepare_buyer(ctx)
+    }
+
+    /// Register the Auction Houses a collection's NFTs may be listed on, signed by the collection's update authority.
+    pub fn create_marketplace_binding<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateMarketplaceBinding<'info>>,
+        approved_houses: Vec<Pubkey>,
+    ) -> Result<()> {
+        marketplace_binding::create_marketplace_binding(ctx, approved_houses)
+    }
+
+    /// Replace the Auction Houses approved by a collection's marketplace binding.
+    pub fn update_marketplace_binding<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateMarketplaceBinding<'info>>,
+        approved_houses: Vec<Pubkey>,
+    ) -> Result<()> {
+        marketplace_binding::update_marketplace_binding(ctx, approved_houses)
+    }
+
+    /// List a presale voucher for a token not minted yet, redeemable until `redeem_deadline`.
+    pub fn create_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateVoucher<'info>>,
+        id: u64,
+        price: u64,
+        redeem_deadline: i64,
+    ) -> Result<()> {
+        voucher::create_voucher(ctx, id, price, redeem_deadline)
+    }
+
+    /// Withdraw an unsold voucher, returning its rent to the creator.
+    pub fn cancel_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelVoucher<'info>>,
+    ) -> Result<()> {
+        voucher::cancel_voucher(ctx)
+    }
+
+    /// Buy a voucher, holding the payment in the voucher until it is redeemed or reclaimed.
+    pub fn buy_voucher<'info>(ctx: Context<'_, '_, '_, 'info, BuyVoucher<'info>>) -> Result<()> {
+        voucher::buy_voucher(ctx)
+    }
+
+    /// Prove delivery of a voucher's token to its buyer and release the payment to the creator.
+    pub fn redeem_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemVoucher<'info>>,
+    ) -> Result<()> {
+        voucher::redeem_voucher(ctx)
+    }
+
+    /// Refund the buyer of a voucher that was not redeemed by its deadline.
+    pub fn reclaim_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimVoucher<'info>>,
+    ) -> Result<()> {
+        voucher::reclaim_voucher(ctx)
+    }
+
+    /// Move an open bid and its escrowed payment to another wallet. Both wallets sign.
+    pub fn transfer_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBid<'info>>,
+        buyer_price: u64,
+        token_size: u64,
+        new_trade_state_bump: u8,
+    ) -> Result<()> {
+        bid_transfer::transfer_bid(ctx, buyer_price, token_size, new_trade_state_bump)
+    }
+
+    /// Open a competition leaderboard counting the volume settled between `starts_at` and
+    /// `ends_at` by each buyer and seller.
+    pub fn create_leaderboard<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateLeaderboard<'info>>,
+        epoch: u64,
+        starts_at: i64,
+        ends_at: i64,
+    ) -> Result<()> {
+        leaderboard::create_leaderboard(ctx, epoch, starts_at, ends_at)
+    }
+
+    /// Create the price history recording the last settlement prices of a mint.
+    pub fn create_price_history<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePriceHistory<'info>>,
+    ) -> Result<()> {
+        price_history::create_price_history(ctx)
+    }
+
+    /// Let bids co-signed by `frontend` spend at most `cap` of the wallet's escrow.
+    pub fn create_spending_cap<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSpendingCap<'info>>,
+        cap: u64,
+    ) -> Result<()> {
+        spending_cap::create_spending_cap(ctx, cap)
+    }
+
+    /// Replace a frontend's spending cap, resetting what it has spent.
+    pub fn update_spending_cap<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateSpendingCap<'info>>,
+        cap: u64,
+    ) -> Result<()> {
+        spending_cap::update_spending_cap(ctx, cap)
+    }
+
+    /// Remove a frontend's spending cap, refunding its rent to the wallet.
+    pub fn close_spending_cap<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseSpendingCap<'info>>,
+    ) -> Result<()> {
+        spending_cap::close_spending_cap(ctx)
+    }
+
+    /// Return the treasury, fee account and unswept fee balances of the house as return data.
+    pub fn get_house_balances<'info>(
+        ctx: Context<'_, '_, '_, 'info, GetHouseBalances<'info>>,
+    ) -> Result<()> {
+        house_balances::get_house_balances(ctx)
+    }
+
+    /// Let `custodian` list and bid on behalf of many users, attributing its trades to their
+    /// sub-accounts.
+    pub fn register_omnibus_custodian<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterOmnibusCustodian<'info>>,
+    ) -> Result<()> {
+        omnibus::register_omnibus_custodian(ctx)
+    }
+
+    /// Remove an omnibus custodian, refunding its rent to the authority.
+    pub fn deregister_omnibus_custodian<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeregisterOmnibusCustodian<'info>>,
+    ) -> Result<()> {
+        omnibus::deregister_omnibus_custodian(ctx)
+    }
+
+    /// Attribute an omnibus custodian's trade state to a user sub-account, which is
+    /// recorded on the receipts printed for it.
+    pub fn attribute_trade<'info>(
+        ctx: Context<'_, '_, '_, 'info, AttributeTrade<'info>>,
+        sub_account: [u8; 32],
+    ) -> Result<()> {
+        omnibus::attribute_trade(ctx, sub_account)
+    }
+
+    /// Close the attribution of a canceled or settled trade, refunding its rent to the custodian.
+    pub fn close_trade_attribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseTradeAttribution<'info>>,
+    ) -> Result<()> {
+        omnibus::close_trade_attribution(ctx)
+    }
+
+    /// Add a royalty bonus to a listing, paid to the token's creators on top of its royalties
+    /// when the listing settles.
+    pub fn create_royalty_bonus<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateRoyaltyBonus<'info>>,
+        buyer_price: u64,
+        token_size: u64,
+        bonus_basis_points: u16,
+    ) -> Result<()> {
+        royalty_bonus::create_royalty_bonus(ctx, buyer_price, token_size, bonus_basis_points)
+    }
+
+    /// Remove a listing's royalty bonus, refunding its rent to the seller.
+    pub fn close_royalty_bonus<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseRoyaltyBonus<'info>>,
+    ) -> Result<()> {
+        royalty_bonus::close_royalty_bonus(ctx)
+    }
+
+    /// Deposit a token into custody for several co-owners, who list it together and split the
+    /// proceeds of its sale by their shares.
+    pub fn create_shared_custody<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSharedCustody<'info>>,
+        owners: Vec<CoOwner>,
+        threshold: u8,
+    ) -> Result<()> {
+        shared_custody::create_shared_custody(ctx, owners, threshold)
+    }
+
+    /// Approve listing a shared custody token at `buyer_price`, listing it once enough
+    /// co-owners have approved the same price.
+    pub fn co_list<'info>(
+        ctx: Context<'_, '_, '_, 'info, CoList<'info>>,
+        buyer_price: u64,
+        trade_state_bump: u8,
+    ) -> Result<()> {
+        shared_custody::co_list(ctx, buyer_price, trade_state_bump)
+    }
+
+    /// Split the proceeds of a sold shared custody token between its co-owners.
+    pub fn settle_shared_custody<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleSharedCustody<'info>>,
+    ) -> Result<()> {
+        shared_custody::settle_shared_custody(ctx)
+    }
+
     /// Create a listing receipt by creating a `listing_receipt` account.
+    #[cfg(feature = "receipts")]
     pub fn print_listing_receipt<'info>(
         ctx: Context<'_, '_, '_, 'info, PrintListingReceipt<'info>>,
         receipt_bump: u8,
@@ -620,6 +1471,7 @@ pub mod auction_house {
     }
 
     /// Cancel an active listing receipt by setting the `canceled_at` field to the current time.
+    #[cfg(feature = "receipts")]
     pub fn cancel_listing_receipt<'info>(
         ctx: Context<'_, '_, '_, 'info, CancelListingReceipt<'info>>,
     ) -> Result<()> {
@@ -627,6 +1479,7 @@ pub mod auction_house {
     }
 
     /// Create a bid receipt by creating a `bid_receipt` account.
+    #[cfg(feature = "receipts")]
     pub fn print_bid_receipt<'info>(
         ctx: Context<'_, '_, '_, 'info, PrintBidReceipt<'info>>,
         receipt_bump: u8,
@@ -635,6 +1488,7 @@ pub mod auction_house {
     }
 
     /// Cancel an active bid receipt by setting the `canceled_at` field to the current time.
+    #[cfg(feature = "receipts")]
     pub fn cancel_bid_receipt<'info>(
         ctx: Context<'_, '_, '_, 'info, CancelBidReceipt<'info>>,
     ) -> Result<()> {
@@ -642,6 +1496,7 @@ pub mod auction_house {
     }
 
     /// Create a purchase receipt by creating a `purchase_receipt` account.
+    #[cfg(feature = "receipts")]
     pub fn print_purchase_receipt<'info>(
         ctx: Context<'_, '_, '_, 'info, PrintPurchaseReceipt<'info>>,
         purchase_receipt_bump: u8,
@@ -649,6 +1504,62 @@ pub mod auction_house {
         receipt::print_purchase_receipt(ctx, purchase_receipt_bump)
     }
 
+    /// Log the house's receipts to a compressed merkle tree instead of receipt accounts.
+    #[cfg(feature = "receipts")]
+    pub fn create_receipt_tree<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateReceiptTree<'info>>,
+    ) -> Result<()> {
+        receipt::create_receipt_tree(ctx)
+    }
+
+    /// Append a compressed receipt for the previous listing, bid, purchase or cancel instruction.
+    #[cfg(feature = "receipts")]
+    pub fn log_receipt<'info>(ctx: Context<'_, '_, '_, 'info, LogReceipt<'info>>) -> Result<()> {
+        receipt::log_receipt(ctx)
+    }
+
+    /// Create a listing and print its listing receipt in one instruction.
+    #[cfg(feature = "receipts")]
+    pub fn sell_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        receipt_bump: u8,
+    ) -> Result<()> {
+        sell::sell_with_receipt(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price,
+            token_size,
+            receipt_bump,
+        )
+    }
+
+    /// Create a private bid and print its bid receipt in one instruction.
+    #[cfg(feature = "receipts")]
+    pub fn buy_with_receipt<'info>(
+        ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        receipt_bump: u8,
+    ) -> Result<()> {
+        bid::buy_with_receipt(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size,
+            receipt_bump,
+        )
+    }
+
     #[doc(hidden)]
     pub fn sell_remaining_accounts<'info>(
         _ctx: Context<'_, '_, '_, 'info, SellRemainingAccounts<'info>>,
@@ -0,0 +1,97 @@
+//! The house setting that records the treasury currency a listing was priced in on its seller
+//! trade state, so a listing never settles in a different mint or decimal scale than it was
+//! placed in.
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack};
+use spl_token::state::Mint;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, AuthorityAction,
+};
+
+/// Accounts for the [`set_listing_currency_lock` handler](auction_house/fn.set_listing_currency_lock.html).
+#[derive(Accounts)]
+pub struct SetListingCurrencyLock<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_listing_currency_lock<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetListingCurrencyLock<'info>>,
+    locks_listing_currency: bool,
+) -> Result<()> {
+    ctx.accounts.auction_house.locks_listing_currency = locks_listing_currency;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetListingCurrencyLock,
+        locks_listing_currency as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// The treasury mint and its decimals a new listing locks into its seller trade state, or `None`
+/// when the Auction House does not lock listing currencies.
+///
+/// Houses that do take their treasury mint account from `remaining_accounts`, after the seller's
+/// active listing accounts and ahead of any programmable NFT accounts.
+pub fn next_listing_currency<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<(Pubkey, u8)>> {
+    if !auction_house.locks_listing_currency {
+        return Ok(None);
+    }
+
+    let treasury_mint = next_account_info(remaining_accounts)?;
+    assert_keys_equal(treasury_mint.key(), auction_house.treasury_mint)?;
+    let decimals = Mint::unpack(&treasury_mint.data.borrow())?.decimals;
+
+    Ok(Some((treasury_mint.key(), decimals)))
+}
+
+/// Write the treasury mint and decimals after the bump of a seller trade state created with
+/// `CURRENCY_LOCKED_TRADE_STATE_SIZE`.
+pub fn lock_listing_currency(trade_state: &AccountInfo, mint: &Pubkey, decimals: u8) -> Result<()> {
+    let data = &mut trade_state.try_borrow_mut_data()?;
+    data[1..33].copy_from_slice(mint.as_ref());
+    data[33] = decimals;
+
+    Ok(())
+}
+
+/// Fail unless a currency locked seller trade state was listed in `treasury_mint` at its current
+/// decimals. Trade states listed before the house locked currencies carry no currency and pass.
+pub fn assert_listing_currency(
+    trade_state: &AccountInfo,
+    treasury_mint: &AccountInfo,
+) -> Result<()> {
+    if trade_state.data_len() < CURRENCY_LOCKED_TRADE_STATE_SIZE {
+        return Ok(());
+    }
+
+    let data = trade_state.try_borrow_data()?;
+    let decimals = Mint::unpack(&treasury_mint.data.borrow())?.decimals;
+    if data[1..33] != treasury_mint.key().to_bytes() || data[33] != decimals {
+        return Err(AuctionHouseError::ListingCurrencyMismatch.into());
+    }
+
+    Ok(())
+}
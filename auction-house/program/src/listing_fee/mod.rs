@@ -0,0 +1,178 @@
+//! Per-listing fee overrides set by a delegated auctioneer, e.g. for promotional zero-fee auctions.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, pda::find_listing_fee_address, utils::*, AuctionHouse,
+    Auctioneer, AuthorityScope, ListingFee,
+};
+
+/// Accounts for the [`auctioneer_set_listing_fee` handler](auction_house/fn.auctioneer_set_listing_fee.html).
+#[derive(Accounts)]
+pub struct AuctioneerSetListingFee<'info> {
+    /// CHECK: Validated in ah_auctioneer_pda seeds and in auctioneer_set_listing_fee.
+    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    pub auctioneer_authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account, paying for the listing fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auctioneer_set_listing_fee.
+    /// Seller trade state PDA account of the listing.
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Listing fee PDA account recording the fee charged when the listing settles.
+    #[account(
+        mut,
+        seeds = [
+            LISTING_FEE.as_bytes(),
+            auction_house.key().as_ref(),
+            seller_trade_state.key().as_ref()
+        ],
+        bump
+    )]
+    pub listing_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ],
+        bump
+    )]
+    pub ah_auctioneer_pda: Account<'info, Auctioneer>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Charge `fee_basis_points` instead of the house fee when the listing settles. The fee cannot
+/// exceed the house fee.
+pub fn auctioneer_set_listing_fee<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerSetListingFee<'info>>,
+    fee_basis_points: u16,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let listing_fee_info = ctx.accounts.listing_fee.to_account_info();
+
+    if !auction_house.has_auctioneer {
+        return Err(AuctionHouseError::NoAuctioneerProgramSet.into());
+    }
+
+    assert_valid_auctioneer_and_scope(
+        auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        &ctx.accounts.ah_auctioneer_pda,
+        AuthorityScope::SetFee,
+    )?;
+
+    if fee_basis_points > auction_house.seller_fee_basis_points {
+        return Err(AuctionHouseError::ListingFeeAboveHouseFee.into());
+    }
+
+    if seller_trade_state.data_is_empty() || seller_trade_state.owner != &crate::id() {
+        return Err(AuctionHouseError::TradeStateDoesntExist.into());
+    }
+
+    let auction_house_key = auction_house.key();
+    let seller_trade_state_key = seller_trade_state.key();
+    let bump = *ctx
+        .bumps
+        .get("listing_fee")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    if listing_fee_info.data_is_empty() {
+        let fee_payer_seeds = [
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            FEE_PAYER.as_bytes(),
+            &[auction_house.fee_payer_bump],
+        ];
+        let listing_fee_seeds = [
+            LISTING_FEE.as_bytes(),
+            auction_house_key.as_ref(),
+            seller_trade_state_key.as_ref(),
+            &[bump],
+        ];
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            &listing_fee_info,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.auction_house_fee_account.to_account_info(),
+            LISTING_FEE_SIZE,
+            &fee_payer_seeds,
+            &listing_fee_seeds,
+        )?;
+    }
+
+    let listing_fee = ListingFee {
+        auction_house: auction_house_key,
+        seller_trade_state: seller_trade_state_key,
+        fee_basis_points,
+        bump,
+    };
+    listing_fee.try_serialize(&mut *listing_fee_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Take the listing fee account from `remaining_accounts` when the Auction House has delegated
+/// the `SetFee` scope, returning the listing's fee override if one was set. A listing fee account
+/// is closed back to the fee account once it has been read, as the listing is settling.
+///
+/// The listing fee PDA is passed after any fee stake accounts and ahead of the mint cooldown
+/// account.
+pub fn take_listing_fee_basis_points<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    seller_trade_state: &Pubkey,
+    fee_payer: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<u16>> {
+    if !auction_house.scopes[AuthorityScope::SetFee as usize] {
+        return Ok(None);
+    }
+
+    let listing_fee_info = next_account_info(remaining_accounts)?;
+    let (listing_fee_key, _) = find_listing_fee_address(&auction_house.key(), seller_trade_state);
+    assert_keys_equal(listing_fee_key, listing_fee_info.key())?;
+
+    if listing_fee_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    assert_owned_by(listing_fee_info, &crate::id())?;
+    let listing_fee =
+        ListingFee::try_deserialize(&mut listing_fee_info.try_borrow_data()?.as_ref())?;
+    close_account(listing_fee_info, fee_payer)?;
+
+    Ok(Some(listing_fee.fee_basis_points))
+}
@@ -0,0 +1,79 @@
+//! Per-house bounds on listing prices, used to block dust spam listings and fat-finger prices.
+use anchor_lang::prelude::*;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction,
+};
+
+/// Accounts for the [`set_listing_price_bounds` handler](auction_house/fn.set_listing_price_bounds.html).
+#[derive(Accounts)]
+pub struct SetListingPriceBounds<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_listing_price_bounds<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetListingPriceBounds<'info>>,
+    min_listing_price: Option<u64>,
+    max_listing_price: Option<u64>,
+) -> Result<()> {
+    if let (Some(min), Some(max)) = (min_listing_price, max_listing_price) {
+        if min > max {
+            return Err(AuctionHouseError::InvalidListingPriceBounds.into());
+        }
+    }
+
+    let auction_house = &mut ctx.accounts.auction_house;
+    auction_house.min_listing_price = min_listing_price;
+    auction_house.max_listing_price = max_listing_price;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetListingPriceBounds,
+        min_listing_price.unwrap_or(0),
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Fail when `buyer_price` is outside the listing price bounds set on the Auction House. A listing
+/// at 0 only creates the free seller trade state that lets the authority reprice the listing on
+/// houses that can change sale prices, so it is not held to the bounds.
+pub fn assert_listing_price_in_bounds(
+    auction_house: &AuctionHouse,
+    buyer_price: u64,
+) -> Result<()> {
+    if buyer_price == 0 {
+        return Ok(());
+    }
+
+    if let Some(min_listing_price) = auction_house.min_listing_price {
+        if buyer_price < min_listing_price {
+            return Err(AuctionHouseError::ListingPriceBelowMinimum.into());
+        }
+    }
+
+    if let Some(max_listing_price) = auction_house.max_listing_price {
+        if buyer_price > max_listing_price {
+            return Err(AuctionHouseError::ListingPriceAboveMaximum.into());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+//! Creator-enforced marketplace bindings. A collection's update authority registers the Auction
+//! Houses its NFTs may be listed on, directing secondary sales to houses that enforce royalties.
+//! Selling an NFT from a verified collection passes the collection's binding PDA, which is left
+//! uninitialized when the collection has no binding.
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use std::slice::Iter;
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, MarketplaceBinding};
+
+/// Accounts for the [`create_marketplace_binding` handler](auction_house/fn.create_marketplace_binding.html).
+#[derive(Accounts)]
+pub struct CreateMarketplaceBinding<'info> {
+    /// Update authority of the collection NFT.
+    #[account(mut)]
+    pub update_authority: Signer<'info>,
+
+    /// CHECK: Validated as the mint of `collection_metadata` by assert_collection_update_authority.
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by assert_collection_update_authority.
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// The marketplace binding PDA for the collection.
+    #[account(
+        init,
+        payer=update_authority,
+        space=MARKETPLACE_BINDING_SIZE,
+        seeds=[MARKETPLACE_BINDING.as_bytes(), collection_mint.key().as_ref()],
+        bump
+    )]
+    pub marketplace_binding: Box<Account<'info, MarketplaceBinding>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_marketplace_binding` handler](auction_house/fn.update_marketplace_binding.html).
+#[derive(Accounts)]
+pub struct UpdateMarketplaceBinding<'info> {
+    /// Update authority of the collection NFT.
+    pub update_authority: Signer<'info>,
+
+    /// CHECK: Validated by assert_collection_update_authority.
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// The marketplace binding PDA for the collection.
+    #[account(
+        mut,
+        seeds=[MARKETPLACE_BINDING.as_bytes(), marketplace_binding.collection.as_ref()],
+        bump=marketplace_binding.bump
+    )]
+    pub marketplace_binding: Box<Account<'info, MarketplaceBinding>>,
+}
+
+pub fn create_marketplace_binding<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateMarketplaceBinding<'info>>,
+    approved_houses: Vec<Pubkey>,
+) -> Result<()> {
+    assert_collection_update_authority(
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.collection_mint.key(),
+        &ctx.accounts.update_authority.key(),
+    )?;
+    if approved_houses.len() > MAX_MARKETPLACE_BINDING_HOUSES {
+        return Err(AuctionHouseError::TooManyApprovedAuctionHouses.into());
+    }
+
+    let marketplace_binding = &mut ctx.accounts.marketplace_binding;
+    marketplace_binding.collection = ctx.accounts.collection_mint.key();
+    marketplace_binding.bump = *ctx
+        .bumps
+        .get("marketplace_binding")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    marketplace_binding.approved_houses = approved_houses;
+
+    Ok(())
+}
+
+pub fn update_marketplace_binding<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateMarketplaceBinding<'info>>,
+    approved_houses: Vec<Pubkey>,
+) -> Result<()> {
+    assert_collection_update_authority(
+        &ctx.accounts.collection_metadata,
+        &ctx.accounts.marketplace_binding.collection,
+        &ctx.accounts.update_authority.key(),
+    )?;
+    if approved_houses.len() > MAX_MARKETPLACE_BINDING_HOUSES {
+        return Err(AuctionHouseError::TooManyApprovedAuctionHouses.into());
+    }
+
+    ctx.accounts.marketplace_binding.approved_houses = approved_houses;
+
+    Ok(())
+}
+
+/// Check `collection_metadata` is the metadata of `collection_mint` and that `update_authority` is
+/// its update authority.
+fn assert_collection_update_authority(
+    collection_metadata: &AccountInfo,
+    collection_mint: &Pubkey,
+    update_authority: &Pubkey,
+) -> Result<()> {
+    assert_derivation(
+        &mpl_token_metadata::id(),
+        collection_metadata,
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            collection_mint.as_ref(),
+        ],
+    )?;
+    let metadata = Metadata::from_account_info(collection_metadata)?;
+    if metadata.update_authority != *update_authority {
+        return Err(AuctionHouseError::NotCollectionUpdateAuthority.into());
+    }
+
+    Ok(())
+}
+
+/// Fail when the token described by `metadata` belongs to a verified collection whose marketplace
+/// binding does not approve `auction_house`. A binding with no approved houses allows any house.
+///
+/// For tokens in a verified collection, the collection's marketplace binding PDA is passed in
+/// `remaining_accounts` ahead of any programmable NFT accounts. It may be uninitialized if the
+/// collection has no binding.
+pub fn assert_marketplace_binding_allows<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    metadata: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let collection = match Metadata::from_account_info(metadata)?.collection {
+        Some(collection) if collection.verified => collection.key,
+        _ => return Ok(()),
+    };
+
+    let marketplace_binding_info = next_account_info(remaining_accounts)?;
+    assert_derivation(
+        &crate::id(),
+        marketplace_binding_info,
+        &[MARKETPLACE_BINDING.as_bytes(), collection.as_ref()],
+    )?;
+    if marketplace_binding_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let marketplace_binding: Account<MarketplaceBinding> =
+        Account::try_from(marketplace_binding_info)?;
+    if !marketplace_binding.approved_houses.is_empty()
+        && !marketplace_binding
+            .approved_houses
+            .contains(&auction_house.key())
+    {
+        return Err(AuctionHouseError::AuctionHouseNotApprovedForCollection.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,336 @@
+//! Checked arithmetic for fees, royalties and partial fills. Intermediate products are computed
+//! in `u128` and every result is converted back to `u64` without truncation, so any overflow
+//! surfaces as `NumericalOverflow` rather than a wrapped amount.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::AuctionHouseError;
+
+/// Denominator of amounts expressed in basis points.
+pub const BASIS_POINTS_DENOMINATOR: u64 = 10000;
+
+/// Denominator of creator shares, which are expressed in percent.
+pub const CREATOR_SHARE_DENOMINATOR: u64 = 100;
+
+/// How a fee or royalty that falls between two whole units is rounded.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    Down,
+    Up,
+    /// To the nearest unit, with exact halves rounded to the even one.
+    HalfEven,
+}
+
+/// `amount * numerator / denominator`, rounded down.
+fn mul_div(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    mul_div_rounded(amount, numerator, denominator, Rounding::Down)
+}
+
+/// `amount * numerator / denominator`, rounded by `rounding`.
+fn mul_div_rounded(
+    amount: u64,
+    numerator: u64,
+    denominator: u64,
+    rounding: Rounding,
+) -> Result<u64> {
+    let product = (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let denominator = denominator as u128;
+    let quotient = product
+        .checked_div(denominator)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let remainder = product % denominator;
+
+    let round_up = match rounding {
+        Rounding::Down => false,
+        Rounding::Up => remainder > 0,
+        Rounding::HalfEven => {
+            remainder * 2 > denominator || (remainder * 2 == denominator && quotient % 2 == 1)
+        }
+    };
+    let result = if round_up { quotient + 1 } else { quotient };
+
+    Ok(u64::try_from(result).map_err(|_| AuctionHouseError::NumericalOverflow)?)
+}
+
+/// `a + b`, failing on overflow.
+pub fn checked_add(a: u64, b: u64) -> Result<u64> {
+    Ok(a.checked_add(b)
+        .ok_or(AuctionHouseError::NumericalOverflow)?)
+}
+
+/// `a - b`, failing on underflow.
+pub fn checked_sub(a: u64, b: u64) -> Result<u64> {
+    Ok(a.checked_sub(b)
+        .ok_or(AuctionHouseError::NumericalOverflow)?)
+}
+
+/// The `basis_points` portion of `amount`, rounded down.
+pub fn apply_basis_points(amount: u64, basis_points: u16) -> Result<u64> {
+    mul_div(amount, basis_points as u64, BASIS_POINTS_DENOMINATOR)
+}
+
+/// The `basis_points` portion of `amount`, rounded by `rounding`.
+pub fn apply_basis_points_rounded(
+    amount: u64,
+    basis_points: u16,
+    rounding: Rounding,
+) -> Result<u64> {
+    mul_div_rounded(
+        amount,
+        basis_points as u64,
+        BASIS_POINTS_DENOMINATOR,
+        rounding,
+    )
+}
+
+/// Splits `amount` into the `basis_points` portion and the remainder. The two always add up to
+/// `amount`, so rounding dust stays with the remainder.
+pub fn split_basis_points(amount: u64, basis_points: u16) -> Result<(u64, u64)> {
+    let portion = apply_basis_points(amount, basis_points)?;
+    let remainder = checked_sub(amount, portion)?;
+
+    Ok((portion, remainder))
+}
+
+/// Splits `amount` into the `basis_points` portion, rounded by `rounding`, and the remainder.
+/// The two always add up to `amount`.
+pub fn split_basis_points_rounded(
+    amount: u64,
+    basis_points: u16,
+    rounding: Rounding,
+) -> Result<(u64, u64)> {
+    let portion = apply_basis_points_rounded(amount, basis_points, rounding)?;
+    let remainder = checked_sub(amount, portion)?;
+
+    Ok((portion, remainder))
+}
+
+/// The house's `basis_points` fee on `price`, rounded by `rounding`. Rounding never takes the fee
+/// past `after_royalties`, what is left of the price once royalties are paid, so a royalty and a
+/// fee both rounded up cannot add up to more than the price.
+pub fn house_fee(
+    price: u64,
+    basis_points: u16,
+    rounding: Rounding,
+    after_royalties: u64,
+) -> Result<u64> {
+    let fee = apply_basis_points_rounded(price, basis_points, rounding)?;
+    let floor = apply_basis_points(price, basis_points)?;
+
+    Ok(fee.min(after_royalties.max(floor)))
+}
+
+/// A creator's `share` percent of `total_fee`, rounded down.
+pub fn creator_share(total_fee: u64, share: u8) -> Result<u64> {
+    mul_div(total_fee, share as u64, CREATOR_SHARE_DENOMINATOR)
+}
+
+/// A creator's `share` percent of `total_fee`, rounded by `rounding`.
+pub fn creator_share_rounded(total_fee: u64, share: u8, rounding: Rounding) -> Result<u64> {
+    mul_div_rounded(total_fee, share as u64, CREATOR_SHARE_DENOMINATOR, rounding)
+}
+
+/// Price of `size` tokens from an order of `token_size` tokens at `buyer_price`. The unit price is
+/// rounded down before being multiplied out, matching the price partial buy orders are placed at.
+pub fn partial_fill_price(buyer_price: u64, token_size: u64, size: u64) -> Result<u64> {
+    let unit_price = buyer_price
+        .checked_div(token_size)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    Ok(unit_price
+        .checked_mul(size)
+        .ok_or(AuctionHouseError::NumericalOverflow)?)
+}
+
+/// Price of an offer declining linearly from `start_price` to `floor_price` over `duration`
+/// seconds, `elapsed` seconds in. The decline is rounded down, so the price never undercuts the
+/// line between the two.
+pub fn descending_price(
+    start_price: u64,
+    floor_price: u64,
+    elapsed: i64,
+    duration: i64,
+) -> Result<u64> {
+    if elapsed <= 0 {
+        return Ok(start_price);
+    }
+    if elapsed >= duration {
+        return Ok(floor_price);
+    }
+
+    let decline = mul_div(
+        checked_sub(start_price, floor_price)?,
+        elapsed as u64,
+        duration as u64,
+    )?;
+
+    checked_sub(start_price, decline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RoundingPolicy;
+
+    #[test]
+    fn apply_basis_points_rounds_down() {
+        assert_eq!(apply_basis_points(1_000_000_000, 250).unwrap(), 25_000_000);
+        assert_eq!(apply_basis_points(399, 250).unwrap(), 9);
+        assert_eq!(apply_basis_points(39, 250).unwrap(), 0);
+        assert_eq!(apply_basis_points(u64::MAX, 10000).unwrap(), u64::MAX);
+        assert_eq!(apply_basis_points(u64::MAX, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn apply_basis_points_above_denominator_overflows() {
+        assert!(apply_basis_points(u64::MAX, 10001).is_err());
+        assert_eq!(apply_basis_points(100, 20000).unwrap(), 200);
+    }
+
+    #[test]
+    fn split_basis_points_keeps_dust_in_remainder() {
+        assert_eq!(split_basis_points(399, 250).unwrap(), (9, 390));
+        assert_eq!(split_basis_points(0, 250).unwrap(), (0, 0));
+        assert_eq!(split_basis_points(u64::MAX, 10000).unwrap(), (u64::MAX, 0));
+        assert!(split_basis_points(100, 20000).is_err());
+    }
+
+    #[test]
+    fn creator_shares_never_exceed_total_fee() {
+        let total_fee = 1_001;
+        let shares = [33, 33, 34];
+        let paid: u64 = shares
+            .iter()
+            .map(|share| creator_share(total_fee, *share).unwrap())
+            .sum();
+
+        assert_eq!(paid, 330 + 330 + 340);
+        assert!(paid <= total_fee);
+        assert_eq!(creator_share(u64::MAX, 100).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn apply_basis_points_rounded_follows_rounding() {
+        assert_eq!(
+            apply_basis_points_rounded(399, 250, Rounding::Down).unwrap(),
+            9
+        );
+        assert_eq!(
+            apply_basis_points_rounded(399, 250, Rounding::Up).unwrap(),
+            10
+        );
+        assert_eq!(
+            apply_basis_points_rounded(399, 250, Rounding::HalfEven).unwrap(),
+            10
+        );
+        assert_eq!(
+            apply_basis_points_rounded(20, 250, Rounding::HalfEven).unwrap(),
+            0
+        );
+        assert_eq!(
+            apply_basis_points_rounded(60, 250, Rounding::HalfEven).unwrap(),
+            2
+        );
+        assert_eq!(
+            apply_basis_points_rounded(400, 250, Rounding::Up).unwrap(),
+            10
+        );
+        assert_eq!(
+            apply_basis_points_rounded(u64::MAX, 10000, Rounding::Up).unwrap(),
+            u64::MAX
+        );
+        assert!(apply_basis_points_rounded(u64::MAX, 10001, Rounding::Up).is_err());
+    }
+
+    #[test]
+    fn settlement_totals_reconcile_under_every_rounding_policy() {
+        let policies = [
+            RoundingPolicy::Floor,
+            RoundingPolicy::CreatorCeiling,
+            RoundingPolicy::HalfEven,
+        ];
+        let prices = [0, 1, 7, 99, 399, 1_001, 123_457, 1_000_000_007];
+        let basis_points = [0, 1, 250, 333, 5000, 9999, 10000];
+        let creator_splits: [&[u8]; 5] = [&[100], &[50, 50], &[33, 33, 34], &[1, 99], &[20; 5]];
+
+        for policy in policies {
+            for price in prices {
+                for royalty_basis_points in basis_points {
+                    for fee_basis_points in basis_points {
+                        if royalty_basis_points + fee_basis_points > 10000 {
+                            continue;
+                        }
+                        for shares in creator_splits {
+                            let (royalty, remainder) = split_basis_points_rounded(
+                                price,
+                                royalty_basis_points,
+                                policy.royalty_rounding(),
+                            )
+                            .unwrap();
+                            let mut royalty_left = royalty;
+                            let mut creators_paid = 0;
+                            for share in shares {
+                                let creator_fee = creator_share_rounded(
+                                    royalty,
+                                    *share,
+                                    policy.royalty_rounding(),
+                                )
+                                .unwrap()
+                                .min(royalty_left);
+                                royalty_left -= creator_fee;
+                                creators_paid += creator_fee;
+                            }
+                            let house_fee_paid = house_fee(
+                                price,
+                                fee_basis_points,
+                                policy.fee_rounding(),
+                                remainder + royalty_left,
+                            )
+                            .unwrap();
+                            let seller_proceeds = checked_sub(
+                                checked_add(remainder, royalty_left).unwrap(),
+                                house_fee,
+                            )
+                            .unwrap();
+
+                            assert!(creators_paid <= royalty);
+                            assert_eq!(creators_paid + house_fee_paid + seller_proceeds, price);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn partial_fill_price_uses_rounded_unit_price() {
+        assert_eq!(partial_fill_price(1_000, 10, 3).unwrap(), 300);
+        assert_eq!(partial_fill_price(1_005, 10, 3).unwrap(), 300);
+        assert_eq!(partial_fill_price(9, 10, 5).unwrap(), 0);
+        assert!(partial_fill_price(1_000, 0, 1).is_err());
+        assert!(partial_fill_price(u64::MAX, 1, 2).is_err());
+    }
+
+    #[test]
+    fn checked_add_and_sub_reject_overflow() {
+        assert_eq!(checked_add(1, 2).unwrap(), 3);
+        assert!(checked_add(u64::MAX, 1).is_err());
+        assert_eq!(checked_sub(3, 2).unwrap(), 1);
+        assert!(checked_sub(2, 3).is_err());
+    }
+
+    #[test]
+    fn descending_price_declines_linearly_to_floor() {
+        assert_eq!(descending_price(1_000, 500, -5, 100).unwrap(), 1_000);
+        assert_eq!(descending_price(1_000, 500, 0, 100).unwrap(), 1_000);
+        assert_eq!(descending_price(1_000, 500, 50, 100).unwrap(), 750);
+        assert_eq!(descending_price(1_000, 500, 99, 100).unwrap(), 505);
+        assert_eq!(descending_price(1_000, 500, 100, 100).unwrap(), 500);
+        assert_eq!(descending_price(1_000, 500, 500, 100).unwrap(), 500);
+        assert_eq!(descending_price(1_000, 999, 1, 3).unwrap(), 1_000);
+        assert_eq!(descending_price(700, 700, 50, 100).unwrap(), 700);
+        assert!(descending_price(500, 1_000, 50, 100).is_err());
+    }
+}
@@ -0,0 +1,235 @@
+//! Omnibus custodians: wallets, typically PDAs of a custodial exchange's program, that the house
+//! lets list and bid on behalf of many users. The custodian attributes each of its trade states
+//! to a user sub-account, and receipts printed for the trade record that sub-account.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, OmnibusCustodian,
+    TradeAttribution,
+};
+
+/// Accounts for the [`register_omnibus_custodian` handler](auction_house/fn.register_omnibus_custodian.html).
+#[derive(Accounts)]
+pub struct RegisterOmnibusCustodian<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Any wallet or PDA may act as a custodian.
+    /// Wallet the custodian lists and bids from.
+    pub custodian: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The omnibus custodian PDA registering the custodian with the house.
+    #[account(
+        init,
+        payer=authority,
+        space=OMNIBUS_CUSTODIAN_SIZE,
+        seeds=[
+            OMNIBUS_CUSTODIAN.as_bytes(),
+            auction_house.key().as_ref(),
+            custodian.key().as_ref()
+        ],
+        bump
+    )]
+    pub omnibus_custodian: Box<Account<'info, OmnibusCustodian>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`deregister_omnibus_custodian` handler](auction_house/fn.deregister_omnibus_custodian.html).
+#[derive(Accounts)]
+pub struct DeregisterOmnibusCustodian<'info> {
+    /// Auction House instance authority account, refunded the omnibus custodian's rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The omnibus custodian PDA to remove.
+    #[account(
+        mut,
+        seeds=[
+            OMNIBUS_CUSTODIAN.as_bytes(),
+            auction_house.key().as_ref(),
+            omnibus_custodian.custodian.as_ref()
+        ],
+        bump=omnibus_custodian.bump,
+        has_one=auction_house,
+        close=authority
+    )]
+    pub omnibus_custodian: Box<Account<'info, OmnibusCustodian>>,
+}
+
+/// Accounts for the [`attribute_trade` handler](auction_house/fn.attribute_trade.html).
+#[derive(Accounts)]
+pub struct AttributeTrade<'info> {
+    /// Custodian wallet placing the trade.
+    #[account(mut)]
+    pub custodian: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The omnibus custodian PDA registering the custodian with the house.
+    #[account(
+        seeds=[
+            OMNIBUS_CUSTODIAN.as_bytes(),
+            auction_house.key().as_ref(),
+            custodian.key().as_ref()
+        ],
+        bump=omnibus_custodian.bump,
+        has_one=auction_house,
+        has_one=custodian
+    )]
+    pub omnibus_custodian: Box<Account<'info, OmnibusCustodian>>,
+
+    /// CHECK: Only its address is recorded, as the trade state may not exist yet.
+    /// Listing or bid trade state PDA account of the custodian's.
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// The trade attribution PDA recording the user sub-account.
+    #[account(
+        init,
+        payer=custodian,
+        space=TRADE_ATTRIBUTION_SIZE,
+        seeds=[
+            TRADE_ATTRIBUTION.as_bytes(),
+            custodian.key().as_ref(),
+            trade_state.key().as_ref()
+        ],
+        bump
+    )]
+    pub trade_attribution: Box<Account<'info, TradeAttribution>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`close_trade_attribution` handler](auction_house/fn.close_trade_attribution.html).
+#[derive(Accounts)]
+pub struct CloseTradeAttribution<'info> {
+    /// Custodian wallet, refunded the trade attribution's rent.
+    #[account(mut)]
+    pub custodian: Signer<'info>,
+
+    /// CHECK: Checked against the trade attribution, emptiness checked in close_trade_attribution.
+    /// Trade state the attribution is for.
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// The trade attribution PDA to close.
+    #[account(
+        mut,
+        seeds=[
+            TRADE_ATTRIBUTION.as_bytes(),
+            custodian.key().as_ref(),
+            trade_state.key().as_ref()
+        ],
+        bump=trade_attribution.bump,
+        has_one=custodian,
+        has_one=trade_state,
+        close=custodian
+    )]
+    pub trade_attribution: Box<Account<'info, TradeAttribution>>,
+}
+
+pub fn register_omnibus_custodian<'info>(
+    ctx: Context<'_, '_, '_, 'info, RegisterOmnibusCustodian<'info>>,
+) -> Result<()> {
+    let omnibus_custodian = &mut ctx.accounts.omnibus_custodian;
+    omnibus_custodian.auction_house = ctx.accounts.auction_house.key();
+    omnibus_custodian.custodian = ctx.accounts.custodian.key();
+    omnibus_custodian.bump = *ctx
+        .bumps
+        .get("omnibus_custodian")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Remove a custodian from the house. Its existing trade attributions are left in place.
+pub fn deregister_omnibus_custodian<'info>(
+    _ctx: Context<'_, '_, '_, 'info, DeregisterOmnibusCustodian<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+/// Record the user sub-account a trade state of the custodian's is placed for. Trade state
+/// addresses are known in advance, so a listing or bid can be attributed before it is placed and
+/// its receipt printed in the same transaction.
+pub fn attribute_trade<'info>(
+    ctx: Context<'_, '_, '_, 'info, AttributeTrade<'info>>,
+    sub_account: [u8; 32],
+) -> Result<()> {
+    let trade_attribution = &mut ctx.accounts.trade_attribution;
+    trade_attribution.auction_house = ctx.accounts.auction_house.key();
+    trade_attribution.custodian = ctx.accounts.custodian.key();
+    trade_attribution.trade_state = ctx.accounts.trade_state.key();
+    trade_attribution.sub_account = sub_account;
+    trade_attribution.bump = *ctx
+        .bumps
+        .get("trade_attribution")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Close the attribution of a trade state that has been canceled, settled or never placed.
+/// Receipts for the trade must be printed before it is closed.
+pub fn close_trade_attribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseTradeAttribution<'info>>,
+) -> Result<()> {
+    if !ctx.accounts.trade_state.data_is_empty() {
+        return Err(AuctionHouseError::TradeStateIsNotEmpty.into());
+    }
+
+    Ok(())
+}
+
+/// Take the trade attribution for `trade_state` from `remaining_accounts`, returning the user
+/// sub-account `wallet` traded it for. Returns `None`, leaving the accounts untouched, when the
+/// next account is not an attribution of `trade_state`.
+pub fn attributed_sub_account<'a, 'info>(
+    wallet: &Pubkey,
+    trade_state: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<[u8; 32]>> {
+    let mut accounts = remaining_accounts.clone();
+    match next_program_account::<TradeAttribution>(&mut accounts)? {
+        Some(trade_attribution) if trade_attribution.trade_state == *trade_state => {
+            assert_keys_equal(trade_attribution.custodian, *wallet)?;
+            *remaining_accounts = accounts;
+            Ok(Some(trade_attribution.sub_account))
+        }
+        _ => Ok(None),
+    }
+}
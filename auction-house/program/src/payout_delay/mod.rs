@@ -0,0 +1,272 @@
+//! Payout delay: a fraud window after settlement during which the seller's proceeds are held by
+//! the program. The seller claims them once the delay has passed, and until then the house
+//! authority can claw them back to the buyer.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError,
+    pda::find_delayed_payout_address, utils::*, AuctionHouse, AuthorityAction, DelayedPayout,
+};
+
+/// Accounts for the [`set_payout_delay` handler](auction_house/fn.set_payout_delay.html).
+#[derive(Accounts)]
+pub struct SetPayoutDelay<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+/// Accounts for the [`claim_proceeds` handler](auction_house/fn.claim_proceeds.html).
+#[derive(Accounts)]
+pub struct ClaimProceeds<'info> {
+    /// CHECK: Checked against the delayed payout.
+    /// Seller user wallet account, receiving the held proceeds.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the delayed payout.
+    /// Account that paid the rent for the delayed payout.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Delayed payout PDA holding the seller's proceeds.
+    #[account(
+        mut,
+        seeds = [
+            DELAYED_PAYOUT.as_bytes(),
+            delayed_payout.auction_house.as_ref(),
+            delayed_payout.token_mint.as_ref(),
+            delayed_payout.buyer.as_ref()
+        ],
+        bump=delayed_payout.bump,
+        has_one=seller,
+        has_one=rent_payer,
+        close=rent_payer
+    )]
+    pub delayed_payout: Box<Account<'info, DelayedPayout>>,
+}
+
+/// Accounts for the [`clawback_proceeds` handler](auction_house/fn.clawback_proceeds.html).
+#[derive(Accounts)]
+pub struct ClawbackProceeds<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Checked against the delayed payout.
+    /// Buyer user wallet account, refunded the held proceeds.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the delayed payout.
+    /// Account that paid the rent for the delayed payout.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// Delayed payout PDA holding the seller's proceeds.
+    #[account(
+        mut,
+        seeds = [
+            DELAYED_PAYOUT.as_bytes(),
+            auction_house.key().as_ref(),
+            delayed_payout.token_mint.as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump=delayed_payout.bump,
+        has_one=auction_house,
+        has_one=buyer,
+        has_one=rent_payer,
+        close=rent_payer
+    )]
+    pub delayed_payout: Box<Account<'info, DelayedPayout>>,
+}
+
+pub fn set_payout_delay<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetPayoutDelay<'info>>,
+    payout_delay_seconds: i64,
+) -> Result<()> {
+    if payout_delay_seconds < 0 {
+        return Err(AuctionHouseError::InvalidPayoutDelay.into());
+    }
+
+    let auction_house = &mut ctx.accounts.auction_house;
+    if payout_delay_seconds > 0 && auction_house.treasury_mint != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::PayoutDelayRequiresNativeMint.into());
+    }
+
+    auction_house.payout_delay_seconds = payout_delay_seconds;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetPayoutDelay,
+        payout_delay_seconds as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Pay the held proceeds to the seller once the payout delay has passed. Anyone may crank this.
+pub fn claim_proceeds<'info>(ctx: Context<'_, '_, '_, 'info, ClaimProceeds<'info>>) -> Result<()> {
+    let delayed_payout = &ctx.accounts.delayed_payout;
+
+    if Clock::get()?.unix_timestamp < delayed_payout.release_at {
+        return Err(AuctionHouseError::PayoutDelayWindowOpen.into());
+    }
+
+    pay_from_delayed_payout(
+        &delayed_payout.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        delayed_payout.amount,
+    )
+}
+
+/// Refund the held proceeds to the buyer while the payout delay is still running.
+pub fn clawback_proceeds<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClawbackProceeds<'info>>,
+) -> Result<()> {
+    let delayed_payout = &ctx.accounts.delayed_payout;
+
+    if Clock::get()?.unix_timestamp >= delayed_payout.release_at {
+        return Err(AuctionHouseError::PayoutDelayWindowClosed.into());
+    }
+
+    pay_from_delayed_payout(
+        &delayed_payout.to_account_info(),
+        &ctx.accounts.buyer.to_account_info(),
+        delayed_payout.amount,
+    )?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::ClawbackProceeds,
+        delayed_payout.amount,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Take the delayed payout PDA from `remaining_accounts` when the Auction House has a payout delay
+/// set. Returns `None` when the delay is disabled, or when buyer protection already holds the
+/// proceeds.
+///
+/// The delayed payout PDA is passed directly after the protected settlement accounts would be.
+pub fn next_delayed_payout_account<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    token_mint: &Pubkey,
+    buyer: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<(&'a AccountInfo<'info>, u8)>> {
+    if auction_house.payout_delay_seconds == 0 || auction_house.buyer_protection_seconds > 0 {
+        return Ok(None);
+    }
+
+    let delayed_payout = next_account_info(remaining_accounts)?;
+    let (delayed_payout_key, bump) =
+        find_delayed_payout_address(&auction_house.key(), token_mint, buyer);
+    assert_keys_equal(delayed_payout_key, delayed_payout.key())?;
+
+    Ok(Some((delayed_payout, bump)))
+}
+
+/// Create the delayed payout holding `amount` of the seller's proceeds until the payout delay
+/// has passed. The caller moves the proceeds into it.
+#[allow(clippy::too_many_arguments)]
+pub fn open_delayed_payout<'info>(
+    delayed_payout: &AccountInfo<'info>,
+    bump: u8,
+    auction_house: &Account<'info, AuctionHouse>,
+    seller: &Pubkey,
+    buyer: &Pubkey,
+    token_mint: &Pubkey,
+    amount: u64,
+    fee_payer: &AccountInfo<'info>,
+    fee_payer_seeds: &[&[u8]],
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+) -> Result<()> {
+    let auction_house_key = auction_house.key();
+    create_or_allocate_account_raw(
+        crate::id(),
+        delayed_payout,
+        rent,
+        system_program,
+        fee_payer,
+        DELAYED_PAYOUT_SIZE,
+        fee_payer_seeds,
+        &[
+            DELAYED_PAYOUT.as_bytes(),
+            auction_house_key.as_ref(),
+            token_mint.as_ref(),
+            buyer.as_ref(),
+            &[bump],
+        ],
+    )?;
+
+    let release_at = Clock::get()?
+        .unix_timestamp
+        .checked_add(auction_house.payout_delay_seconds)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    let payout = DelayedPayout {
+        auction_house: auction_house_key,
+        seller: *seller,
+        buyer: *buyer,
+        token_mint: *token_mint,
+        rent_payer: fee_payer.key(),
+        amount,
+        release_at,
+        bump,
+    };
+    payout.try_serialize(&mut *delayed_payout.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Pay `amount` of the proceeds held by the delayed payout to `destination`.
+fn pay_from_delayed_payout<'info>(
+    delayed_payout: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let delayed_payout_lamports = delayed_payout
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let destination_lamports = destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    **delayed_payout.try_borrow_mut_lamports()? = delayed_payout_lamports;
+    **destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! One instruction setting up the accounts a buyer needs before bidding, so wallets do not have to
+//! assemble escrow and token account creation themselves.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*,
+    wallet_receipt::assert_buyer_receipt_account, AuctionHouse,
+};
+
+/// Accounts for the [`prepare_buyer` handler](auction_house/fn.prepare_buyer.html).
+#[derive(Accounts)]
+pub struct PrepareBuyer<'info> {
+    /// User wallet account, paying for any account created.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: Validated in prepare_buyer.
+    /// User SOL or SPL account bids are paid from. For SPL treasuries, the wallet's associated
+    /// token account for the treasury mint.
+    #[account(mut)]
+    pub payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in prepare_buyer.
+    /// Buyer's associated token account for the token to be bought.
+    #[account(mut)]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// Mint of the token to be bought.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    pub token_program: Program<'info, Token>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Fund a native escrow to rent exemption, or create an SPL escrow and the wallet's treasury mint
+/// payment account, and create the wallet's token account for `token_mint`. Accounts that already
+/// exist are left as they are, so the instruction can precede any bid.
+pub fn prepare_buyer<'info>(ctx: Context<'_, '_, '_, 'info, PrepareBuyer<'info>>) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let payment_account = &ctx.accounts.payment_account;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let auction_house = &ctx.accounts.auction_house;
+    let token_program = &ctx.accounts.token_program;
+    let ata_program = &ctx.accounts.ata_program;
+    let system_program = &ctx.accounts.system_program;
+    let rent = &ctx.accounts.rent;
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+    let auction_house_key = auction_house.key();
+    let wallet_key = wallet.key();
+    let escrow_payment_bump = *ctx
+        .bumps
+        .get("escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        wallet_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+
+    if is_native {
+        assert_keys_equal(wallet.key(), payment_account.key())?;
+
+        // A native escrow holds lamports only, so preparing it means funding it to rent exemption.
+        let rent_exempt_lamports = rent.minimum_balance(escrow_payment_account.data_len());
+        if escrow_payment_account.lamports() < rent_exempt_lamports {
+            invoke(
+                &system_instruction::transfer(
+                    &wallet.key(),
+                    &escrow_payment_account.key(),
+                    rent_exempt_lamports - escrow_payment_account.lamports(),
+                ),
+                &[
+                    wallet.to_account_info(),
+                    escrow_payment_account.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+            )?;
+        }
+    } else {
+        if payment_account.data_is_empty() {
+            make_ata(
+                payment_account.to_account_info(),
+                wallet.to_account_info(),
+                treasury_mint.to_account_info(),
+                wallet.to_account_info(),
+                ata_program.to_account_info(),
+                token_program.to_account_info(),
+                system_program.to_account_info(),
+                rent.to_account_info(),
+                &[],
+            )?;
+        }
+        assert_is_ata(
+            &payment_account.to_account_info(),
+            &wallet.key(),
+            &treasury_mint.key(),
+        )?;
+
+        create_program_token_account_if_not_present(
+            escrow_payment_account,
+            system_program,
+            &wallet.to_account_info(),
+            token_program,
+            treasury_mint,
+            &auction_house.to_account_info(),
+            rent,
+            &escrow_signer_seeds,
+            &[],
+            is_native,
+        )?;
+    }
+
+    if buyer_receipt_token_account.data_is_empty() {
+        make_ata(
+            buyer_receipt_token_account.to_account_info(),
+            wallet.to_account_info(),
+            token_mint.to_account_info(),
+            wallet.to_account_info(),
+            ata_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+            &[],
+        )?;
+    }
+    assert_buyer_receipt_account(
+        auction_house,
+        &buyer_receipt_token_account.to_account_info(),
+        &wallet.key(),
+        &token_mint.key(),
+    )?;
+
+    Ok(())
+}
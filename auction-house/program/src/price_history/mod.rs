@@ -0,0 +1,84 @@
+//! Price history: an optional ring buffer per mint of its last settlement prices on an Auction
+//! House, so price charts and lending protocols can read reference prices from chain state
+//! without an oracle.
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use std::slice::Iter;
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, PriceHistory};
+
+/// Accounts for the [`create_price_history` handler](auction_house/fn.create_price_history.html).
+#[derive(Accounts)]
+pub struct CreatePriceHistory<'info> {
+    /// Account paying for the price history. Anyone may create one.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Token mint account whose settlement prices are recorded.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// The price history PDA for the mint.
+    #[account(
+        init,
+        payer=payer,
+        space=PRICE_HISTORY_SIZE,
+        seeds=[
+            PRICE_HISTORY.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_price_history<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatePriceHistory<'info>>,
+) -> Result<()> {
+    let price_history = &mut ctx.accounts.price_history;
+    price_history.auction_house = ctx.accounts.auction_house.key();
+    price_history.token_mint = ctx.accounts.token_mint.key();
+    price_history.bump = *ctx
+        .bumps
+        .get("price_history")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Record a sale of `size` tokens at `price` in the mint's price history, if one was passed in
+/// `remaining_accounts`. The price is recorded per token.
+pub fn record_price_history<'a, 'info>(
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+    price: u64,
+    size: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let mut price_history = match next_program_account::<PriceHistory>(remaining_accounts)? {
+        Some(price_history) => price_history,
+        None => return Ok(()),
+    };
+    assert_keys_equal(price_history.auction_house, *auction_house)?;
+    assert_keys_equal(price_history.token_mint, *token_mint)?;
+
+    let unit_price = price
+        .checked_div(size)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    price_history.record(unit_price, Clock::get()?.unix_timestamp);
+
+    price_history.exit(&crate::id())
+}
@@ -0,0 +1,147 @@
+//! Per-mint index of the highest open public bids. Public bids add themselves to the index and
+//! cancels remove themselves when the index is passed as the first remaining account.
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use std::slice::Iter;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, IndexedPublicBid,
+    PublicBidIndex,
+};
+
+/// Accounts for the [`create_public_bid_index` handler](auction_house/fn.create_public_bid_index.html).
+#[derive(Accounts)]
+pub struct CreatePublicBidIndex<'info> {
+    /// Pays for the public bid index account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Mint of the token the indexed bids are placed on.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The public bid index PDA for the mint.
+    #[account(
+        init,
+        payer=payer,
+        space=PUBLIC_BID_INDEX_SIZE,
+        seeds=[
+            PUBLIC_BID_INDEX.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub public_bid_index: Box<Account<'info, PublicBidIndex>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`prune_public_bid_index` handler](auction_house/fn.prune_public_bid_index.html).
+///
+/// Remaining accounts are the trade states of indexed bids to drop. Only trade states that have
+/// been closed are removed.
+#[derive(Accounts)]
+pub struct PrunePublicBidIndex<'info> {
+    /// The public bid index PDA for the mint.
+    #[account(
+        mut,
+        seeds=[
+            PUBLIC_BID_INDEX.as_bytes(),
+            public_bid_index.auction_house.as_ref(),
+            public_bid_index.token_mint.as_ref()
+        ],
+        bump=public_bid_index.bump
+    )]
+    pub public_bid_index: Box<Account<'info, PublicBidIndex>>,
+}
+
+pub fn create_public_bid_index<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatePublicBidIndex<'info>>,
+) -> Result<()> {
+    let public_bid_index = &mut ctx.accounts.public_bid_index;
+    public_bid_index.auction_house = ctx.accounts.auction_house.key();
+    public_bid_index.token_mint = ctx.accounts.token_mint.key();
+    public_bid_index.bump = *ctx
+        .bumps
+        .get("public_bid_index")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    public_bid_index.bids = Vec::new();
+
+    Ok(())
+}
+
+pub fn prune_public_bid_index<'info>(
+    ctx: Context<'_, '_, '_, 'info, PrunePublicBidIndex<'info>>,
+) -> Result<()> {
+    let public_bid_index = &mut ctx.accounts.public_bid_index;
+    for trade_state in ctx.remaining_accounts {
+        if trade_state.lamports() == 0 {
+            public_bid_index.remove(trade_state.key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Consume the public bid index from `remaining_accounts` if the caller passed one, checking it
+/// belongs to `auction_house` and `token_mint`.
+fn next_public_bid_index<'info>(
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+) -> Result<Option<Account<'info, PublicBidIndex>>> {
+    let public_bid_index = match next_program_account::<PublicBidIndex>(remaining_accounts)? {
+        Some(public_bid_index) => public_bid_index,
+        None => return Ok(None),
+    };
+
+    assert_keys_equal(public_bid_index.auction_house, *auction_house)?;
+    assert_keys_equal(public_bid_index.token_mint, *token_mint)?;
+
+    Ok(Some(public_bid_index))
+}
+
+/// Add `bid` to the public bid index, if one was passed.
+pub fn index_public_bid(
+    remaining_accounts: &mut Iter<AccountInfo>,
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+    bid: IndexedPublicBid,
+) -> Result<()> {
+    if let Some(mut public_bid_index) =
+        next_public_bid_index(remaining_accounts, auction_house, token_mint)?
+    {
+        public_bid_index.insert(bid);
+        public_bid_index.exit(&crate::id())?;
+    }
+
+    Ok(())
+}
+
+/// Remove the bid at `trade_state` from the public bid index, if one was passed.
+pub fn unindex_public_bid(
+    remaining_accounts: &mut Iter<AccountInfo>,
+    auction_house: &Pubkey,
+    token_mint: &Pubkey,
+    trade_state: &Pubkey,
+) -> Result<()> {
+    if let Some(mut public_bid_index) =
+        next_public_bid_index(remaining_accounts, auction_house, token_mint)?
+    {
+        public_bid_index.remove(trade_state);
+        public_bid_index.exit(&crate::id())?;
+    }
+
+    Ok(())
+}
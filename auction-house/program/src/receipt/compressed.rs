@@ -0,0 +1,334 @@
+//! Receipts logged as leaves of an SPL account compression merkle tree instead of PDAs. Each
+//! leaf is written to the noop program so indexers can rebuild it, and only its hash is stored
+//! on-chain.
+use crate::{
+    authority_log::record_authority_action,
+    constants::*,
+    errors::AuctionHouseError,
+    id,
+    instruction::{Buy, Sell},
+    receipt::purchase_price_and_size,
+    tax_withholding::tax_withheld_on,
+    utils::*,
+    AuctionHouse, AuthorityAction,
+};
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use solana_program::{
+    instruction::Instruction, keccak, program::invoke, program::invoke_signed, pubkey, sysvar,
+    sysvar::instructions::get_instruction_relative,
+};
+
+/// SPL account compression program.
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// SPL noop program used to log leaves.
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Sighash of the account compression program's `append` instruction.
+const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+
+#[constant]
+pub const RECEIPT_TREE_SIZE: usize = 8 + //key
+32 + // auction_house
+32 + // merkle_tree
+8 + // leaf_count
+1; // bump
+
+/// The merkle tree a house logs its compressed receipts to. The receipt tree PDA is the tree's
+/// authority.
+#[account]
+pub struct ReceiptTree {
+    pub auction_house: Pubkey,
+    pub merkle_tree: Pubkey,
+    pub leaf_count: u64,
+    pub bump: u8,
+}
+
+/// A receipt stored as a compressed leaf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum CompressedReceipt {
+    Listing {
+        trade_state: Pubkey,
+        seller: Pubkey,
+        metadata: Pubkey,
+        price: u64,
+        token_size: u64,
+    },
+    Bid {
+        trade_state: Pubkey,
+        buyer: Pubkey,
+        metadata: Pubkey,
+        token_account: Option<Pubkey>,
+        price: u64,
+        token_size: u64,
+    },
+    Purchase {
+        buyer_trade_state: Pubkey,
+        seller_trade_state: Pubkey,
+        buyer: Pubkey,
+        seller: Pubkey,
+        metadata: Pubkey,
+        price: u64,
+        token_size: u64,
+        tax_withheld: u64,
+    },
+    Cancel {
+        trade_state: Pubkey,
+    },
+}
+
+/// Versioned layout of a receipt leaf. The leaf hash stored in the tree is the keccak hash of
+/// the borsh encoded schema, which is also the data logged to the noop program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ReceiptLeafSchema {
+    V1 {
+        auction_house: Pubkey,
+        bookkeeper: Pubkey,
+        nonce: u64,
+        created_at: i64,
+        receipt: CompressedReceipt,
+    },
+}
+
+impl ReceiptLeafSchema {
+    pub fn hash(&self) -> Result<[u8; 32]> {
+        Ok(keccak::hash(&self.try_to_vec()?).to_bytes())
+    }
+}
+
+/// Accounts for the [`create_receipt_tree` handler](fn.create_receipt_tree.html).
+#[derive(Accounts)]
+pub struct CreateReceiptTree<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Checked to be owned by the account compression program.
+    /// Merkle tree initialized with the receipt tree PDA as its authority.
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// The receipt tree PDA for the house.
+    #[account(
+        init,
+        payer=authority,
+        space=RECEIPT_TREE_SIZE,
+        seeds=[RECEIPT_TREE.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub receipt_tree: Box<Account<'info, ReceiptTree>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Log the house's receipts to `merkle_tree` instead of receipt PDAs.
+pub fn create_receipt_tree<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateReceiptTree<'info>>,
+) -> Result<()> {
+    assert_owned_by(&ctx.accounts.merkle_tree, &ACCOUNT_COMPRESSION_PROGRAM_ID)?;
+
+    let receipt_tree = &mut ctx.accounts.receipt_tree;
+    receipt_tree.auction_house = ctx.accounts.auction_house.key();
+    receipt_tree.merkle_tree = ctx.accounts.merkle_tree.key();
+    receipt_tree.leaf_count = 0;
+    receipt_tree.bump = *ctx
+        .bumps
+        .get("receipt_tree")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.auction_house.has_receipt_tree = true;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::CreateReceiptTree,
+        0,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Accounts for the [`log_receipt` handler](fn.log_receipt.html).
+#[derive(Accounts)]
+pub struct LogReceipt<'info> {
+    pub bookkeeper: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The receipt tree PDA for the house.
+    #[account(
+        mut,
+        seeds=[RECEIPT_TREE.as_bytes(), auction_house.key().as_ref()],
+        bump=receipt_tree.bump,
+        has_one=auction_house,
+        has_one=merkle_tree
+    )]
+    pub receipt_tree: Box<Account<'info, ReceiptTree>>,
+
+    /// CHECK: Checked against the receipt tree and by the account compression program.
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the address constraint.
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the address constraint.
+    #[account(address = NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the address constraint.
+    #[account(address = sysvar::instructions::id())]
+    pub instruction: UncheckedAccount<'info>,
+}
+
+/// Append a compressed receipt for the previous instruction, which must be a listing, bid,
+/// purchase or cancel on the same Auction House. Purchases on houses that withhold tax take the
+/// tax withholding PDA as a remaining account.
+pub fn log_receipt<'info>(ctx: Context<'_, '_, '_, 'info, LogReceipt<'info>>) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+    if !auction_house.has_receipt_tree {
+        return Err(AuctionHouseError::ReceiptTreeNotSet.into());
+    }
+
+    let prev_instruction = get_instruction_relative(-1, &ctx.accounts.instruction)?;
+    assert_keys_equal(prev_instruction.program_id, id())?;
+    let accounts = &prev_instruction.accounts;
+    let sighash = &prev_instruction.data[..8];
+    let mut buffer = &prev_instruction.data[8..];
+
+    let (prev_auction_house, receipt) = if assert_program_listing_instruction(sighash).is_ok() {
+        let sell_data = Sell::deserialize(&mut buffer)?;
+        (
+            accounts[4].pubkey,
+            CompressedReceipt::Listing {
+                trade_state: accounts[6].pubkey,
+                seller: accounts[0].pubkey,
+                metadata: accounts[2].pubkey,
+                price: sell_data.buyer_price,
+                token_size: sell_data.token_size,
+            },
+        )
+    } else if let Ok(bid_type) = assert_program_bid_instruction(sighash) {
+        let buy_data = Buy::deserialize(&mut buffer)?;
+        let token_account = match bid_type {
+            BidType::PrivateSale | BidType::AuctioneerPrivateSale => Some(accounts[4].pubkey),
+            BidType::PublicSale | BidType::AuctioneerPublicSale => None,
+        };
+        (
+            accounts[8].pubkey,
+            CompressedReceipt::Bid {
+                trade_state: accounts[10].pubkey,
+                buyer: accounts[0].pubkey,
+                metadata: accounts[5].pubkey,
+                token_account,
+                price: buy_data.buyer_price,
+                token_size: buy_data.token_size,
+            },
+        )
+    } else if let Ok(purchase_type) = assert_program_purchase_instruction(sighash) {
+        let (price, token_size) = purchase_price_and_size(&purchase_type, buffer)?;
+        (
+            accounts[10].pubkey,
+            CompressedReceipt::Purchase {
+                buyer_trade_state: accounts[13].pubkey,
+                seller_trade_state: accounts[14].pubkey,
+                buyer: accounts[0].pubkey,
+                seller: accounts[1].pubkey,
+                metadata: accounts[4].pubkey,
+                price,
+                token_size,
+                tax_withheld: tax_withheld_on(
+                    &accounts[10].pubkey,
+                    price,
+                    &mut ctx.remaining_accounts.iter(),
+                )?,
+            },
+        )
+    } else {
+        assert_program_cancel_instruction(sighash)?;
+        (
+            accounts[4].pubkey,
+            CompressedReceipt::Cancel {
+                trade_state: accounts[6].pubkey,
+            },
+        )
+    };
+    assert_keys_equal(prev_auction_house, auction_house.key())?;
+
+    let receipt_tree = &mut ctx.accounts.receipt_tree;
+    let leaf = ReceiptLeafSchema::V1 {
+        auction_house: auction_house.key(),
+        bookkeeper: ctx.accounts.bookkeeper.key(),
+        nonce: receipt_tree.leaf_count,
+        created_at: Clock::get()?.unix_timestamp,
+        receipt,
+    };
+    receipt_tree.leaf_count = receipt_tree
+        .leaf_count
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    let log_wrapper = &ctx.accounts.log_wrapper;
+    invoke(
+        &Instruction {
+            program_id: log_wrapper.key(),
+            accounts: vec![],
+            data: leaf.try_to_vec()?,
+        },
+        &[log_wrapper.to_account_info()],
+    )?;
+
+    let mut data = APPEND_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&leaf.hash()?);
+
+    let receipt_tree_info = receipt_tree.to_account_info();
+    let auction_house_key = auction_house.key();
+    invoke_signed(
+        &Instruction {
+            program_id: ctx.accounts.compression_program.key(),
+            accounts: vec![
+                AccountMeta::new(ctx.accounts.merkle_tree.key(), false),
+                AccountMeta::new_readonly(receipt_tree_info.key(), true),
+                AccountMeta::new_readonly(log_wrapper.key(), false),
+            ],
+            data,
+        },
+        &[
+            ctx.accounts.merkle_tree.to_account_info(),
+            receipt_tree_info,
+            log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+        ],
+        &[&[
+            RECEIPT_TREE.as_bytes(),
+            auction_house_key.as_ref(),
+            &[receipt_tree.bump],
+        ]],
+    )?;
+
+    Ok(())
+}
@@ -1,14 +1,21 @@
 //! Create PDAs to to track the status and results of various Auction House actions.
+pub mod compressed;
+pub use compressed::*;
+
 use crate::{
     constants::*,
     errors::AuctionHouseError,
     id,
-    instruction::{Buy, ExecuteSale, Sell},
+    instruction::{Buy, ExecutePartialSale, ExecuteSale, Sell},
+    omnibus::attributed_sub_account,
+    tax_withholding::tax_withheld_on,
+    trade_sequence::placed_trade_sequence,
     utils::*,
 };
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 use solana_program::{sysvar, sysvar::instructions::get_instruction_relative};
 
+#[constant]
 pub const BID_RECEIPT_SIZE: usize = 8 + //key
 32 + // trade_state
 32 + // bookkeeper
@@ -22,7 +29,9 @@ pub const BID_RECEIPT_SIZE: usize = 8 + //key
 1 + // bump
 1 + // trade_state_bump
 8 + // created_at
-1 + 8; // canceled_at
+1 + 8 + // canceled_at
+1 + 32 + // sub_account
+1 + 8; // sequence
 
 /// Receipt for a bid transaction.
 #[account]
@@ -40,8 +49,13 @@ pub struct BidReceipt {
     pub trade_state_bump: u8,
     pub created_at: i64,
     pub canceled_at: Option<i64>,
+    /// User sub-account an omnibus custodian attributed the trade to.
+    pub sub_account: Option<[u8; 32]>,
+    /// Number of the trade in the order placed on the Auction House, if it has a trade sequence.
+    pub sequence: Option<u64>,
 }
 
+#[constant]
 pub const LISTING_RECEIPT_SIZE: usize = 8 + //key
 32 + // trade_state
 32 + // bookkeeper
@@ -54,7 +68,9 @@ pub const LISTING_RECEIPT_SIZE: usize = 8 + //key
 1 + // bump
 1 + // trade_state_bump
 8 + // created_at
-1 + 8; // canceled_at;
+1 + 8 + // canceled_at
+1 + 32 + // sub_account
+1 + 8; // sequence
 
 /// Receipt for a listing transaction.
 #[account]
@@ -71,8 +87,13 @@ pub struct ListingReceipt {
     pub trade_state_bump: u8,
     pub created_at: i64,
     pub canceled_at: Option<i64>,
+    /// User sub-account an omnibus custodian attributed the trade to.
+    pub sub_account: Option<[u8; 32]>,
+    /// Number of the trade in the order placed on the Auction House, if it has a trade sequence.
+    pub sequence: Option<u64>,
 }
 
+#[constant]
 pub const PURCHASE_RECEIPT_SIZE: usize = 8 + //key
 32 + // bookkeeper
 32 + // buyer
@@ -82,9 +103,13 @@ pub const PURCHASE_RECEIPT_SIZE: usize = 8 + //key
 8 + // token_size
 8 + // price
 1 + // bump
-8; // created_at
+8 + // created_at
+8 + // tax_withheld
+1 + 32 + // buyer_sub_account
+1 + 32; // seller_sub_account
 
-/// Receipt for a purchase transaction.
+/// Receipt for a purchase transaction. `token_size` and `price` are the quantity bought and the
+/// amount paid for it, which for a partial fill are less than the listing's.
 #[account]
 pub struct PurchaseReceipt {
     pub bookkeeper: Pubkey,
@@ -96,6 +121,86 @@ pub struct PurchaseReceipt {
     pub price: u64,
     pub bump: u8,
     pub created_at: i64,
+    pub tax_withheld: u64,
+    /// User sub-accounts omnibus custodians attributed the bid and listing to.
+    pub buyer_sub_account: Option<[u8; 32]>,
+    pub seller_sub_account: Option<[u8; 32]>,
+}
+
+/// The price paid and quantity bought by an execute sale instruction with arguments `data`.
+/// Partial fills report the quantity and price actually filled rather than the full listing.
+pub(crate) fn purchase_price_and_size(
+    purchase_type: &PurchaseType,
+    mut data: &[u8],
+) -> Result<(u64, u64)> {
+    match purchase_type {
+        PurchaseType::ExecuteSale | PurchaseType::AuctioneerExecuteSale => {
+            let execute_sale_data = ExecuteSale::deserialize(&mut data)?;
+            Ok((execute_sale_data.buyer_price, execute_sale_data.token_size))
+        }
+        PurchaseType::ExecutePartialSale | PurchaseType::AuctioneerExecutePartialSale => {
+            let execute_sale_data = ExecutePartialSale::deserialize(&mut data)?;
+            Ok((
+                execute_sale_data
+                    .partial_order_price
+                    .unwrap_or(execute_sale_data.buyer_price),
+                execute_sale_data
+                    .partial_order_size
+                    .unwrap_or(execute_sale_data.token_size),
+            ))
+        }
+    }
+}
+
+/// Split the receipt and the bookkeeper paying for it off the front of an instruction's remaining
+/// accounts, returning them with the remaining accounts that follow.
+pub(crate) fn split_receipt_accounts<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(
+    &'a AccountInfo<'info>,
+    &'a AccountInfo<'info>,
+    &'a [AccountInfo<'info>],
+)> {
+    match remaining_accounts {
+        [receipt, bookkeeper, remaining_accounts @ ..] => {
+            if !bookkeeper.is_signer {
+                return Err(ErrorCode::AccountNotSigner.into());
+            }
+            Ok((receipt, bookkeeper, remaining_accounts))
+        }
+        _ => Err(ErrorCode::AccountNotEnoughKeys.into()),
+    }
+}
+
+/// Check `receipt` is the receipt PDA for `trade_state` under `prefix`, creating it at the
+/// bookkeeper's expense if it does not exist yet. An existing receipt is left to be written over.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_receipt_if_not_present<'info>(
+    receipt: &AccountInfo<'info>,
+    bookkeeper: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    prefix: &str,
+    trade_state: &Pubkey,
+    receipt_bump: u8,
+    size: usize,
+) -> Result<()> {
+    assert_derivation(&id(), receipt, &[prefix.as_bytes(), trade_state.as_ref()])?;
+
+    if receipt.data_is_empty() {
+        create_or_allocate_account_raw(
+            id(),
+            receipt,
+            rent,
+            system_program,
+            bookkeeper,
+            size,
+            &[],
+            &[prefix.as_bytes(), trade_state.as_ref(), &[receipt_bump]],
+        )?;
+    }
+
+    Ok(())
 }
 
 /// Accounts for the [`print_listing_receipt` hanlder](fn.print_listing_receipt.html).
@@ -123,6 +228,10 @@ pub struct PrintListingReceipt<'info> {
 /// The previous instruction is checked to ensure that it is a "Listing" type to
 /// match the receipt type being created. Passing in an empty account results in the PDA
 /// being created; an existing account will be written over.
+///
+/// A listing placed by an omnibus custodian records the user sub-account of the trade
+/// attribution passed as a remaining account. Passing the house's trade sequence after it records
+/// the listing's sequence number, so the receipt must be printed in the transaction placing it.
 pub fn print_listing_receipt<'info>(
     ctx: Context<'_, '_, '_, 'info, PrintListingReceipt<'info>>,
     receipt_bump: u8,
@@ -150,36 +259,18 @@ pub fn print_listing_receipt<'info>(
 
     assert_keys_equal(prev_instruction.program_id, id())?;
 
-    let receipt_info = receipt_account.to_account_info();
-
-    assert_derivation(
-        &id(),
-        &receipt_info,
-        &[
-            LISTING_RECEIPT_PREFIX.as_ref(),
-            seller_trade_state.pubkey.as_ref(),
-        ],
+    create_receipt_if_not_present(
+        receipt_account,
+        bookkeeper_account,
+        system_program,
+        &rent.to_account_info(),
+        LISTING_RECEIPT_PREFIX,
+        &seller_trade_state.pubkey,
+        receipt_bump,
+        LISTING_RECEIPT_SIZE,
     )?;
 
-    if receipt_info.data_is_empty() {
-        let receipt_seeds = [
-            LISTING_RECEIPT_PREFIX.as_bytes(),
-            seller_trade_state.pubkey.as_ref(),
-            &[receipt_bump],
-        ];
-
-        create_or_allocate_account_raw(
-            *ctx.program_id,
-            &receipt_info,
-            &rent.to_account_info(),
-            system_program,
-            bookkeeper_account,
-            LISTING_RECEIPT_SIZE,
-            &[],
-            &receipt_seeds,
-        )?;
-    }
-
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
     let receipt = ListingReceipt {
         trade_state: seller_trade_state.pubkey,
         bookkeeper: bookkeeper_account.key(),
@@ -193,6 +284,16 @@ pub fn print_listing_receipt<'info>(
         trade_state_bump: sell_data.trade_state_bump,
         created_at: clock.unix_timestamp,
         canceled_at: None,
+        sub_account: attributed_sub_account(
+            &wallet.pubkey,
+            &seller_trade_state.pubkey,
+            remaining_accounts,
+        )?,
+        sequence: placed_trade_sequence(
+            &auction_house.pubkey,
+            &seller_trade_state.pubkey,
+            remaining_accounts,
+        )?,
     };
 
     receipt.try_serialize(&mut *receipt_account.try_borrow_mut_data()?)?;
@@ -278,6 +379,10 @@ pub struct PrintBidReceipt<'info> {
 /// The previous instruction is checked to ensure that it is a "Bid" type to
 /// match the receipt type being created. Passing in an empty account results in the PDA
 /// being created; an existing account will be written over.
+///
+/// A bid placed by an omnibus custodian records the user sub-account of the trade attribution
+/// passed as a remaining account. Passing the house's trade sequence after it records the bid's
+/// sequence number, so the receipt must be printed in the transaction placing it.
 pub fn print_bid_receipt<'info>(
     ctx: Context<'_, '_, '_, 'info, PrintBidReceipt<'info>>,
     receipt_bump: u8,
@@ -290,8 +395,6 @@ pub fn print_bid_receipt<'info>(
     let system_program = &ctx.accounts.system_program;
     let clock = Clock::get()?;
 
-    let receipt_info = receipt_account.to_account_info();
-
     let prev_instruction = get_instruction_relative(-1, instruction_account)?;
     let prev_instruction_accounts = prev_instruction.accounts;
 
@@ -313,38 +416,20 @@ pub fn print_bid_receipt<'info>(
         BidType::AuctioneerPublicSale => None,
     };
 
-    assert_derivation(
-        &id(),
-        &receipt_info,
-        &[
-            BID_RECEIPT_PREFIX.as_ref(),
-            buyer_trade_state.pubkey.as_ref(),
-        ],
-    )?;
-
     assert_keys_equal(prev_instruction.program_id, id())?;
 
-    let receipt_info = receipt_account.to_account_info();
-
-    if receipt_info.data_is_empty() {
-        let receipt_seeds = [
-            BID_RECEIPT_PREFIX.as_bytes(),
-            buyer_trade_state.pubkey.as_ref(),
-            &[receipt_bump],
-        ];
-
-        create_or_allocate_account_raw(
-            *ctx.program_id,
-            &receipt_info,
-            &rent.to_account_info(),
-            system_program,
-            bookkeeper_account,
-            BID_RECEIPT_SIZE,
-            &[],
-            &receipt_seeds,
-        )?;
-    }
+    create_receipt_if_not_present(
+        receipt_account,
+        bookkeeper_account,
+        system_program,
+        &rent.to_account_info(),
+        BID_RECEIPT_PREFIX,
+        &buyer_trade_state.pubkey,
+        receipt_bump,
+        BID_RECEIPT_SIZE,
+    )?;
 
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
     let receipt = BidReceipt {
         token_account,
         trade_state: buyer_trade_state.pubkey,
@@ -359,6 +444,16 @@ pub fn print_bid_receipt<'info>(
         trade_state_bump: buy_data.trade_state_bump,
         created_at: clock.unix_timestamp,
         canceled_at: None,
+        sub_account: attributed_sub_account(
+            &wallet.pubkey,
+            &buyer_trade_state.pubkey,
+            remaining_accounts,
+        )?,
+        sequence: placed_trade_sequence(
+            &auction_house.pubkey,
+            &buyer_trade_state.pubkey,
+            remaining_accounts,
+        )?,
     };
 
     receipt.try_serialize(&mut *receipt_account.try_borrow_mut_data()?)?;
@@ -452,6 +547,10 @@ pub struct PrintPurchaseReceipt<'info> {
 /// The previous instruction is checked to ensure that it is a "Purchase" type to
 /// match the receipt type being created. Passing in an empty account results in the PDA
 /// being created; an existing account will be written over.
+///
+/// On houses that withhold tax, the tax withholding PDA is passed as a remaining account so the
+/// withheld amount is recorded on the receipt. It is followed by the trade attributions of an
+/// omnibus buyer and seller, in that order, so their user sub-accounts are recorded too.
 pub fn print_purchase_receipt<'info>(
     ctx: Context<'_, '_, '_, 'info, PrintPurchaseReceipt<'info>>,
     purchase_receipt_bump: u8,
@@ -468,10 +567,8 @@ pub fn print_purchase_receipt<'info>(
     let prev_instruction = get_instruction_relative(-1, instruction_account)?;
     let prev_instruction_accounts = prev_instruction.accounts;
 
-    let mut buffer = &prev_instruction.data[8..];
-    let execute_sale_data = ExecuteSale::deserialize(&mut buffer)?;
-
-    assert_program_purchase_instruction(&prev_instruction.data[..8])?;
+    let purchase_type = assert_program_purchase_instruction(&prev_instruction.data[..8])?;
+    let (price, token_size) = purchase_price_and_size(&purchase_type, &prev_instruction.data[8..])?;
 
     assert_keys_equal(prev_instruction.program_id, id())?;
 
@@ -538,6 +635,7 @@ pub fn print_purchase_receipt<'info>(
         )?;
     }
 
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
     let purchase = PurchaseReceipt {
         buyer: buyer.pubkey,
         seller: seller.pubkey,
@@ -545,9 +643,20 @@ pub fn print_purchase_receipt<'info>(
         metadata: metadata.pubkey,
         bookkeeper: bookkeeper.key(),
         bump: purchase_receipt_bump,
-        price: execute_sale_data.buyer_price,
-        token_size: execute_sale_data.token_size,
+        price,
+        token_size,
         created_at: timestamp,
+        tax_withheld: tax_withheld_on(&auction_house.pubkey, price, remaining_accounts)?,
+        buyer_sub_account: attributed_sub_account(
+            &buyer.pubkey,
+            &buyer_trade_state.pubkey,
+            remaining_accounts,
+        )?,
+        seller_sub_account: attributed_sub_account(
+            &seller.pubkey,
+            &seller_trade_state.pubkey,
+            remaining_accounts,
+        )?,
     };
 
     purchase.try_serialize(&mut *purchase_receipt_account.try_borrow_mut_data()?)?;
@@ -0,0 +1,157 @@
+//! Per-mint relisting cooldown used to throttle wash-trading loops.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, AuthorityAction, MintCooldown,
+};
+
+/// Accounts for the [`set_relist_cooldown` handler](auction_house/fn.set_relist_cooldown.html).
+#[derive(Accounts)]
+pub struct SetRelistCooldown<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_relist_cooldown<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetRelistCooldown<'info>>,
+    relist_cooldown_seconds: i64,
+) -> Result<()> {
+    if relist_cooldown_seconds < 0 {
+        return Err(AuctionHouseError::InvalidRelistCooldown.into());
+    }
+
+    ctx.accounts.auction_house.relist_cooldown_seconds = relist_cooldown_seconds;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetRelistCooldown,
+        relist_cooldown_seconds as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Take the mint cooldown account from `remaining_accounts` when the Auction House has a relist
+/// cooldown set, checking it is the PDA for `mint`. Returns `None` when there is no cooldown.
+fn next_mint_cooldown_account<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    mint: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<(&'a AccountInfo<'info>, u8)>> {
+    if auction_house.relist_cooldown_seconds == 0 {
+        return Ok(None);
+    }
+
+    let mint_cooldown_info = next_account_info(remaining_accounts)?;
+    let auction_house_key = auction_house.key();
+    let (mint_cooldown_key, bump) = Pubkey::find_program_address(
+        &[
+            MINT_COOLDOWN.as_bytes(),
+            auction_house_key.as_ref(),
+            mint.as_ref(),
+        ],
+        &crate::id(),
+    );
+    assert_keys_equal(mint_cooldown_key, mint_cooldown_info.key())?;
+
+    Ok(Some((mint_cooldown_info, bump)))
+}
+
+/// Fail when `mint` was settled on this Auction House less than the relist cooldown ago.
+///
+/// When a cooldown is set, the mint cooldown account is passed in `remaining_accounts` ahead of
+/// any programmable NFT accounts. It may be uninitialized if the mint has never sold here.
+pub fn assert_relist_cooldown_elapsed<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    mint: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let mint_cooldown_info =
+        match next_mint_cooldown_account(auction_house, mint, remaining_accounts)? {
+            Some((info, _)) => info,
+            None => return Ok(()),
+        };
+
+    if mint_cooldown_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let mint_cooldown: Account<MintCooldown> = Account::try_from(mint_cooldown_info)?;
+    let relistable_at = mint_cooldown
+        .last_settled_at
+        .checked_add(auction_house.relist_cooldown_seconds)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    if Clock::get()?.unix_timestamp < relistable_at {
+        return Err(AuctionHouseError::MintInRelistCooldown.into());
+    }
+
+    Ok(())
+}
+
+/// Record a settlement of `mint` so the relist cooldown starts now, creating the mint cooldown
+/// account on its first sale. Does nothing when the Auction House has no cooldown set.
+pub fn record_mint_settlement<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    mint: &Pubkey,
+    fee_payer: &AccountInfo<'info>,
+    fee_payer_seeds: &[&[u8]],
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let (mint_cooldown_info, bump) =
+        match next_mint_cooldown_account(auction_house, mint, remaining_accounts)? {
+            Some(next) => next,
+            None => return Ok(()),
+        };
+
+    if mint_cooldown_info.data_is_empty() {
+        let auction_house_key = auction_house.key();
+        let mint_cooldown_seeds = [
+            MINT_COOLDOWN.as_bytes(),
+            auction_house_key.as_ref(),
+            mint.as_ref(),
+            &[bump],
+        ];
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            mint_cooldown_info,
+            rent,
+            system_program,
+            fee_payer,
+            MINT_COOLDOWN_SIZE,
+            fee_payer_seeds,
+            &mint_cooldown_seeds,
+        )?;
+    } else {
+        assert_owned_by(mint_cooldown_info, &crate::id())?;
+    }
+
+    let mint_cooldown = MintCooldown {
+        auction_house: auction_house.key(),
+        mint: *mint,
+        last_settled_at: Clock::get()?.unix_timestamp,
+        bump,
+    };
+    mint_cooldown.try_serialize(&mut *mint_cooldown_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+//! The house setting for how royalties and house fees are rounded at settlement.
+use anchor_lang::prelude::*;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction, RoundingPolicy,
+};
+
+/// Accounts for the [`set_rounding_policy` handler](auction_house/fn.set_rounding_policy.html).
+#[derive(Accounts)]
+pub struct SetRoundingPolicy<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_rounding_policy<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetRoundingPolicy<'info>>,
+    rounding_policy: RoundingPolicy,
+) -> Result<()> {
+    // Rounding changes the fees charged, which an immutable house has committed to.
+    if ctx.accounts.auction_house.is_immutable {
+        return Err(AuctionHouseError::AuctionHouseImmutable.into());
+    }
+
+    ctx.accounts.auction_house.rounding_policy = rounding_policy;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetRoundingPolicy,
+        rounding_policy as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+//! Royalty bonuses sellers add to their listings, e.g. to tip the creators of a token they are
+//! selling. The bonus is paid on top of the token's royalties, split between its creators in the
+//! same shares, when the listing settles.
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use std::slice::Iter;
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, RoyaltyBonus};
+
+/// Accounts for the [`create_royalty_bonus` handler](auction_house/fn.create_royalty_bonus.html).
+#[derive(Accounts)]
+pub struct CreateRoyaltyBonus<'info> {
+    /// User wallet account that listed the token, paying for the royalty bonus account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the listed token.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated in create_royalty_bonus.
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Validated in create_royalty_bonus.
+    /// Seller trade state PDA account of the listing.
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// The royalty bonus PDA for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=ROYALTY_BONUS_SIZE,
+        seeds=[
+            ROYALTY_BONUS.as_bytes(),
+            auction_house.key().as_ref(),
+            seller_trade_state.key().as_ref()
+        ],
+        bump
+    )]
+    pub royalty_bonus: Box<Account<'info, RoyaltyBonus>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`close_royalty_bonus` handler](auction_house/fn.close_royalty_bonus.html).
+#[derive(Accounts)]
+pub struct CloseRoyaltyBonus<'info> {
+    /// User wallet account that added the royalty bonus, refunded its rent.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The royalty bonus PDA being removed.
+    #[account(
+        mut,
+        seeds=[
+            ROYALTY_BONUS.as_bytes(),
+            royalty_bonus.auction_house.as_ref(),
+            royalty_bonus.seller_trade_state.as_ref()
+        ],
+        bump=royalty_bonus.bump,
+        has_one=wallet,
+        close=wallet
+    )]
+    pub royalty_bonus: Box<Account<'info, RoyaltyBonus>>,
+}
+
+/// Pay `bonus_basis_points` of the price to the token's creators, on top of its royalties, when
+/// the listing at `seller_trade_state` settles.
+pub fn create_royalty_bonus<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateRoyaltyBonus<'info>>,
+    buyer_price: u64,
+    token_size: u64,
+    bonus_basis_points: u16,
+) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let token_account = &ctx.accounts.token_account;
+    let metadata = &ctx.accounts.metadata;
+    let auction_house = &ctx.accounts.auction_house;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+
+    if seller_trade_state.data_is_empty() {
+        return Err(AuctionHouseError::TradeStateDoesntExist.into());
+    }
+    let ts_bump = seller_trade_state.try_borrow_data()?[0];
+    assert_valid_trade_state(
+        &wallet.key(),
+        auction_house,
+        buyer_price,
+        token_size,
+        seller_trade_state,
+        &token_account.mint,
+        &token_account.key(),
+        ts_bump,
+    )?;
+
+    assert_metadata_valid(metadata, token_account)?;
+    let royalty_basis_points = Metadata::from_account_info(&metadata.to_account_info())?
+        .data
+        .seller_fee_basis_points;
+    if bonus_basis_points as u64 + royalty_basis_points as u64 > 10000 {
+        return Err(AuctionHouseError::RoyaltyBonusTooHigh.into());
+    }
+
+    let royalty_bonus = &mut ctx.accounts.royalty_bonus;
+    royalty_bonus.auction_house = auction_house.key();
+    royalty_bonus.seller_trade_state = seller_trade_state.key();
+    royalty_bonus.wallet = wallet.key();
+    royalty_bonus.bonus_basis_points = bonus_basis_points;
+    royalty_bonus.bump = *ctx
+        .bumps
+        .get("royalty_bonus")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+pub fn close_royalty_bonus<'info>(
+    _ctx: Context<'_, '_, '_, 'info, CloseRoyaltyBonus<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+/// Take the listing's royalty bonus from `remaining_accounts` if the caller passed one, returning
+/// its basis points, or zero without one. The royalty bonus account is closed back to the seller,
+/// as the listing is settling.
+///
+/// The royalty bonus PDA is passed first in `remaining_accounts`, ahead of the creator accounts.
+pub fn take_royalty_bonus_basis_points<'a, 'info>(
+    auction_house: &Pubkey,
+    seller_trade_state: &Pubkey,
+    seller: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<u16> {
+    let royalty_bonus = match next_program_account::<RoyaltyBonus>(remaining_accounts)? {
+        Some(royalty_bonus) => royalty_bonus,
+        None => return Ok(0),
+    };
+    assert_keys_equal(royalty_bonus.auction_house, *auction_house)?;
+    assert_keys_equal(royalty_bonus.seller_trade_state, *seller_trade_state)?;
+    assert_keys_equal(royalty_bonus.wallet, seller.key())?;
+
+    close_account(&royalty_bonus.to_account_info(), seller)?;
+
+    Ok(royalty_bonus.bonus_basis_points)
+}
@@ -1,7 +1,31 @@
 use anchor_lang::{prelude::*, solana_program::program::invoke, AnchorDeserialize};
 use spl_token::instruction::approve;
 
-use crate::{constants::*, errors::*, utils::*, AuctionHouse, AuthorityScope, *};
+use crate::{
+    compliance::{assert_compliance, ComplianceAction},
+    constants::*,
+    errors::*,
+    listing_currency::{lock_listing_currency, next_listing_currency},
+    listing_price_bounds::assert_listing_price_in_bounds,
+    marketplace_binding::assert_marketplace_binding_allows,
+    relist_cooldown::assert_relist_cooldown_elapsed,
+    seller_financing::assert_no_outstanding_lien,
+    single_active_listing::record_active_listing,
+    token_standard_policy::assert_token_standard_allowed,
+    trade_sequence::record_trade_sequence,
+    transferability::assert_rule_set_allows_sale_transfer,
+    utils::*,
+    verified_creators::assert_creators_verified,
+    AuctionHouse, AuthorityScope, *,
+};
+
+#[cfg(feature = "receipts")]
+use crate::{
+    receipt::{
+        create_receipt_if_not_present, split_receipt_accounts, ListingReceipt, LISTING_RECEIPT_SIZE,
+    },
+    trade_sequence::placed_trade_sequence,
+};
 
 use mpl_token_auth_rules::payload::{Payload, PayloadType, SeedsVec};
 use mpl_token_metadata::{
@@ -301,6 +325,8 @@ pub fn sell<'info>(
         return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
     }
 
+    assert_listing_price_in_bounds(auction_house, buyer_price)?;
+
     sell_logic(
         ctx.accounts,
         ctx.remaining_accounts,
@@ -313,6 +339,74 @@ pub fn sell<'info>(
     )
 }
 
+/// Create a listing and print its listing receipt in one instruction. The receipt and the
+/// bookkeeper paying for it are passed in `remaining_accounts` ahead of the accounts `sell`
+/// takes there.
+#[cfg(feature = "receipts")]
+pub fn sell_with_receipt<'info>(
+    ctx: Context<'_, '_, '_, 'info, Sell<'info>>,
+    trade_state_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+    receipt_bump: u8,
+) -> Result<()> {
+    let (receipt, bookkeeper, remaining_accounts) = split_receipt_accounts(ctx.remaining_accounts)?;
+
+    sell(
+        Context::new(
+            ctx.program_id,
+            &mut *ctx.accounts,
+            remaining_accounts,
+            ctx.bumps.clone(),
+        ),
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+    )?;
+
+    let accounts = &ctx.accounts;
+    create_receipt_if_not_present(
+        receipt,
+        bookkeeper,
+        &accounts.system_program,
+        &accounts.rent.to_account_info(),
+        LISTING_RECEIPT_PREFIX,
+        &accounts.seller_trade_state.key(),
+        receipt_bump,
+        LISTING_RECEIPT_SIZE,
+    )?;
+
+    let listing_receipt = ListingReceipt {
+        trade_state: accounts.seller_trade_state.key(),
+        bookkeeper: bookkeeper.key(),
+        auction_house: accounts.auction_house.key(),
+        seller: accounts.wallet.key(),
+        metadata: accounts.metadata.key(),
+        purchase_receipt: None,
+        price: buyer_price,
+        token_size,
+        bump: receipt_bump,
+        trade_state_bump,
+        created_at: Clock::get()?.unix_timestamp,
+        canceled_at: None,
+        // The trade state is created by this instruction, so it cannot be attributed yet.
+        sub_account: None,
+        sequence: placed_trade_sequence(
+            &accounts.auction_house.key(),
+            &accounts.seller_trade_state.key(),
+            &mut remaining_accounts.iter(),
+        )?,
+    };
+
+    listing_receipt.try_serialize(&mut *receipt.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
 /// Create a sell bid by creating a `seller_trade_state` account and approving the program as the token delegate.
 pub fn auctioneer_sell<'info>(
     ctx: Context<'_, '_, '_, 'info, AuctioneerSell<'info>>,
@@ -431,12 +525,53 @@ fn sell_logic<'c, 'info>(
 
     assert_metadata_valid(metadata, token_account)?;
 
-    if token_size > token_account.amount {
+    if token_size == 0 || token_size > token_account.amount {
         return Err(AuctionHouseError::InvalidTokenAmount.into());
     }
 
+    assert_token_standard_allowed(
+        auction_house,
+        &metadata.to_account_info(),
+        token_account.amount,
+    )?;
+    assert_creators_verified(auction_house, &metadata.to_account_info())?;
+
     let remaining_accounts = &mut remaining_accounts.iter();
 
+    record_trade_sequence(
+        auction_house,
+        &seller_trade_state.to_account_info(),
+        remaining_accounts,
+    )?;
+    assert_compliance(
+        auction_house,
+        wallet,
+        &token_account.mint,
+        ComplianceAction::Sell,
+        buyer_price,
+        remaining_accounts,
+    )?;
+    assert_relist_cooldown_elapsed(auction_house, &token_account.mint, remaining_accounts)?;
+    assert_no_outstanding_lien(auction_house, &token_account.mint, remaining_accounts)?;
+    assert_marketplace_binding_allows(
+        auction_house,
+        &metadata.to_account_info(),
+        remaining_accounts,
+    )?;
+    record_active_listing(
+        auction_house,
+        &wallet.key(),
+        &token_account.mint,
+        &seller_trade_state.key(),
+        (!wallet.is_signer).then(|| free_seller_trade_state.key()),
+        &fee_payer,
+        fee_seeds,
+        &system_program.to_account_info(),
+        &rent.to_account_info(),
+        remaining_accounts,
+    )?;
+    let listing_currency = next_listing_currency(auction_house, remaining_accounts)?;
+
     if wallet.is_signer {
         match next_account_info(remaining_accounts) {
             Ok(metadata_program) => {
@@ -453,6 +588,8 @@ fn sell_logic<'c, 'info>(
                 let auth_rules = next_account_info(remaining_accounts)?;
                 let sysvar_instructions = next_account_info(remaining_accounts)?;
 
+                assert_rule_set_allows_sale_transfer(auth_rules)?;
+
                 let delegate = DelegateBuilder::new()
                     .delegate_record(delegate_record.key())
                     .delegate(program_as_signer.key())
@@ -554,10 +691,17 @@ fn sell_logic<'c, 'info>(
             &rent.to_account_info(),
             system_program,
             &fee_payer,
-            TRADE_STATE_SIZE,
+            match listing_currency {
+                Some(_) => CURRENCY_LOCKED_TRADE_STATE_SIZE,
+                None => TRADE_STATE_SIZE,
+            },
             fee_seeds,
             &ts_seeds,
         )?;
+
+        if let Some((mint, decimals)) = listing_currency {
+            lock_listing_currency(&ts_info, &mint, decimals)?;
+        }
     }
 
     let data = &mut ts_info.data.borrow_mut();
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction,
+};
+
+/// Accounts for the [`set_seller_financing` handler](auction_house/fn.set_seller_financing.html).
+#[derive(Accounts)]
+pub struct SetSellerFinancing<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_seller_financing<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetSellerFinancing<'info>>,
+    allow_seller_financing: bool,
+) -> Result<()> {
+    let auction_house = &mut ctx.accounts.auction_house;
+    if allow_seller_financing && auction_house.treasury_mint != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::SellerFinancingRequiresNativeMint.into());
+    }
+
+    auction_house.allow_seller_financing = allow_seller_financing;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetSellerFinancing,
+        allow_seller_financing as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+//! Seller financing: a sale settles on a down payment while a lien records the balance the
+//! buyer still owes the seller. The token cannot be relisted on the house until it is repaid.
+pub mod config;
+pub mod execute;
+pub mod repay;
+pub use config::*;
+pub use execute::*;
+pub use repay::*;
+
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse};
+
+/// Fail when `mint` has an outstanding lien on this Auction House.
+///
+/// The lien account for the mint is passed in `remaining_accounts` after the mint cooldown
+/// account and ahead of any programmable NFT accounts. It is uninitialized unless the mint was
+/// sold with financing. It is checked whether or not the house still allows seller financing, so
+/// disabling financing does not release the liens already recorded.
+pub fn assert_no_outstanding_lien<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    mint: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let lien_info = next_account_info(remaining_accounts)?;
+    let auction_house_key = auction_house.key();
+    let (lien_key, _) = Pubkey::find_program_address(
+        &[LIEN.as_bytes(), auction_house_key.as_ref(), mint.as_ref()],
+        &crate::id(),
+    );
+    assert_keys_equal(lien_key, lien_info.key())?;
+
+    if !lien_info.data_is_empty() {
+        return Err(AuctionHouseError::LienOutstanding.into());
+    }
+
+    Ok(())
+}
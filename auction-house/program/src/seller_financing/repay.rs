@@ -0,0 +1,84 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+
+use crate::{constants::*, errors::AuctionHouseError, AuctionHouse, Lien};
+
+/// Accounts for the [`repay_lien` handler](auction_house/fn.repay_lien.html).
+#[derive(Accounts)]
+pub struct RepayLien<'info> {
+    /// Account repaying the lien, usually the buyer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Checked against the lien.
+    /// Seller user wallet account, receiving the repayment.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// Lien PDA recording the balance still owed.
+    #[account(
+        mut,
+        seeds = [
+            LIEN.as_bytes(),
+            auction_house.key().as_ref(),
+            lien.token_mint.as_ref()
+        ],
+        bump=lien.bump,
+        has_one=auction_house,
+        has_one=seller
+    )]
+    pub lien: Box<Account<'info, Lien>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay up to `amount` lamports of the lien's outstanding balance to the seller. The lien is
+/// closed to the seller once fully repaid, allowing the token to be relisted.
+pub fn repay_lien<'info>(
+    ctx: Context<'_, '_, '_, 'info, RepayLien<'info>>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Err(AuctionHouseError::InvalidLienAmount.into());
+    }
+
+    let repayment = amount.min(ctx.accounts.lien.outstanding);
+
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.seller.key(),
+            repayment,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.seller.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let lien = &mut ctx.accounts.lien;
+    lien.outstanding = lien
+        .outstanding
+        .checked_sub(repayment)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    if lien.outstanding == 0 {
+        lien.close(ctx.accounts.seller.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Idempotency markers for escrowed settlements. A protected sale settles over several
+//! transactions, so each one records how far it got and clients can check the marker before
+//! retrying a step instead of guessing from which accounts still exist.
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, SettlementMarker, SettlementStatus,
+};
+
+/// Accounts for the [`assert_settlement_status` handler](auction_house/fn.assert_settlement_status.html).
+#[derive(Accounts)]
+pub struct AssertSettlementStatus<'info> {
+    /// CHECK: Only used as a seed of the settlement marker.
+    /// Protected settlement PDA of the sale.
+    pub protected_settlement: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Settlement marker PDA of the sale, uninitialized until the sale is first executed.
+    #[account(
+        seeds=[SETTLEMENT_MARKER.as_bytes(), protected_settlement.key().as_ref()],
+        bump
+    )]
+    pub settlement_marker: UncheckedAccount<'info>,
+}
+
+/// Fail unless the sale's settlement is at `expected`, with an error naming the status it is
+/// actually at. A rolled back settlement passes as `NotStarted`, since the sale can be executed
+/// again from the start.
+pub fn assert_settlement_status<'info>(
+    ctx: Context<'_, '_, '_, 'info, AssertSettlementStatus<'info>>,
+    expected: SettlementStatus,
+) -> Result<()> {
+    let settlement_marker = &ctx.accounts.settlement_marker;
+    let status = if settlement_marker.data_is_empty() {
+        SettlementStatus::NotStarted
+    } else {
+        SettlementMarker::try_deserialize(&mut &settlement_marker.try_borrow_data()?[..])?.status
+    };
+
+    match (status, expected) {
+        (actual, expected) if actual == expected => Ok(()),
+        (SettlementStatus::RolledBack, SettlementStatus::NotStarted) => Ok(()),
+        (SettlementStatus::Escrowed, _) => err!(AuctionHouseError::SettlementPartiallySettled),
+        (SettlementStatus::Settled, _) => err!(AuctionHouseError::SettlementAlreadySettled),
+        _ => err!(AuctionHouseError::SettlementNotStarted),
+    }
+}
+
+/// Mark the settlement escrowed as a protected sale opens it, creating the marker on the sale's
+/// first attempt. Fails if an earlier attempt left the settlement escrowed, since that must be
+/// finalized or canceled first.
+pub fn start_settlement<'info>(
+    settlement_marker: &AccountInfo<'info>,
+    protected_settlement: &Pubkey,
+    auction_house: &Pubkey,
+    fee_payer: &AccountInfo<'info>,
+    fee_payer_seeds: &[&[u8]],
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+) -> Result<()> {
+    let bump = assert_derivation(
+        &crate::id(),
+        settlement_marker,
+        &[SETTLEMENT_MARKER.as_bytes(), protected_settlement.as_ref()],
+    )?;
+
+    let mut marker = if settlement_marker.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::id(),
+            settlement_marker,
+            rent,
+            system_program,
+            fee_payer,
+            SETTLEMENT_MARKER_SIZE,
+            fee_payer_seeds,
+            &[
+                SETTLEMENT_MARKER.as_bytes(),
+                protected_settlement.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        SettlementMarker {
+            auction_house: *auction_house,
+            protected_settlement: *protected_settlement,
+            status: SettlementStatus::NotStarted,
+            attempts: 0,
+            updated_at: 0,
+            bump,
+        }
+    } else {
+        SettlementMarker::try_deserialize(&mut &settlement_marker.try_borrow_data()?[..])?
+    };
+
+    if marker.status == SettlementStatus::Escrowed {
+        return err!(AuctionHouseError::SettlementPartiallySettled);
+    }
+
+    marker.status = SettlementStatus::Escrowed;
+    marker.attempts = marker.attempts.saturating_add(1);
+    marker.updated_at = Clock::get()?.unix_timestamp;
+    marker.try_serialize(&mut *settlement_marker.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Record that the escrowed settlement was completed or rolled back.
+pub fn finish_settlement(
+    settlement_marker: &mut Account<SettlementMarker>,
+    status: SettlementStatus,
+) -> Result<()> {
+    if settlement_marker.status != SettlementStatus::Escrowed {
+        return err!(AuctionHouseError::SettlementNotStarted);
+    }
+
+    settlement_marker.status = status;
+    settlement_marker.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
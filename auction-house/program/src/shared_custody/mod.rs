@@ -0,0 +1,427 @@
+//! Shared custody for NFTs owned by several wallets. The token is held by a custody PDA that
+//! records each co-owner's share, and is only listed once enough co-owners approve the same price.
+//! The listing is an ordinary listing with the custody PDA as the seller, so it settles through
+//! `execute_sale`, after which the proceeds are split between the co-owners by their shares.
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::{invoke, invoke_signed},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use solana_program::program_memory::sol_memset;
+
+use crate::{
+    constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, CoOwner, SharedCustody,
+};
+
+/// Accounts for the [`create_shared_custody` handler](auction_house/fn.create_shared_custody.html).
+#[derive(Accounts)]
+pub struct CreateSharedCustody<'info> {
+    /// Wallet depositing the token, paying for the shared custody accounts.
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// SPL token account the token is deposited from.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account of the deposited token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The shared custody PDA for the token.
+    #[account(
+        init,
+        payer=depositor,
+        space=SHARED_CUSTODY_SIZE,
+        seeds=[
+            SHARED_CUSTODY.as_bytes(),
+            auction_house.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub shared_custody: Box<Account<'info, SharedCustody>>,
+
+    /// CHECK: Created and validated in create_shared_custody.
+    /// Associated token account of the shared custody PDA, holding the token.
+    #[account(mut)]
+    pub custody_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts for the [`co_list` handler](auction_house/fn.co_list.html).
+#[derive(Accounts)]
+pub struct CoList<'info> {
+    /// Co-owner approving the listing, paying for the seller trade state if the listing activates.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The shared custody PDA holding the token.
+    #[account(
+        mut,
+        seeds=[
+            SHARED_CUSTODY.as_bytes(),
+            auction_house.key().as_ref(),
+            shared_custody.token_mint.as_ref()
+        ],
+        bump=shared_custody.bump
+    )]
+    pub shared_custody: Box<Account<'info, SharedCustody>>,
+
+    /// Associated token account of the shared custody PDA, holding the token.
+    #[account(mut)]
+    pub custody_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated in co_list.
+    /// Seller trade state PDA account of the shared custody's listing.
+    #[account(mut)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts for the [`settle_shared_custody` handler](auction_house/fn.settle_shared_custody.html).
+#[derive(Accounts)]
+pub struct SettleSharedCustody<'info> {
+    /// CHECK: Validated as the shared custody's depositor.
+    /// Wallet that deposited the token, refunded the rent of the shared custody accounts.
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+
+    /// The shared custody PDA of the sold token.
+    #[account(
+        mut,
+        seeds=[
+            SHARED_CUSTODY.as_bytes(),
+            shared_custody.auction_house.as_ref(),
+            shared_custody.token_mint.as_ref()
+        ],
+        bump=shared_custody.bump,
+        has_one=depositor,
+        close=depositor
+    )]
+    pub shared_custody: Box<Account<'info, SharedCustody>>,
+
+    /// Associated token account of the shared custody PDA, emptied by the sale.
+    #[account(mut)]
+    pub custody_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Move the depositor's token into custody for `owners`, `threshold` of whom must approve a
+/// price before it is listed. Shares are in basis points and must add up to 10000.
+pub fn create_shared_custody<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSharedCustody<'info>>,
+    owners: Vec<CoOwner>,
+    threshold: u8,
+) -> Result<()> {
+    let depositor = &ctx.accounts.depositor;
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let auction_house = &ctx.accounts.auction_house;
+    let shared_custody = &ctx.accounts.shared_custody;
+    let custody_token_account = &ctx.accounts.custody_token_account;
+    let token_program = &ctx.accounts.token_program;
+
+    // Proceeds are split from the lamports the sale pays into the custody PDA.
+    if auction_house.treasury_mint != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::SharedCustodyRequiresNativeMint.into());
+    }
+
+    let total_shares = owners
+        .iter()
+        .map(|owner| owner.share_basis_points as u64)
+        .sum::<u64>();
+    let has_duplicates = owners
+        .iter()
+        .enumerate()
+        .any(|(i, owner)| owners[..i].iter().any(|o| o.wallet == owner.wallet));
+    if owners.is_empty()
+        || owners.len() > MAX_CO_OWNERS
+        || has_duplicates
+        || total_shares != 10000
+        || threshold == 0
+        || threshold as usize > owners.len()
+    {
+        return Err(AuctionHouseError::InvalidSharedCustody.into());
+    }
+
+    make_ata(
+        custody_token_account.to_account_info(),
+        shared_custody.to_account_info(),
+        token_mint.to_account_info(),
+        depositor.to_account_info(),
+        ctx.accounts.ata_program.to_account_info(),
+        token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &[],
+    )?;
+    assert_is_ata(
+        &custody_token_account.to_account_info(),
+        &shared_custody.key(),
+        &token_mint.key(),
+    )?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            &token_program.key(),
+            &token_account.key(),
+            &custody_token_account.key(),
+            &depositor.key(),
+            &[],
+            1,
+        )?,
+        &[
+            token_account.to_account_info(),
+            custody_token_account.to_account_info(),
+            depositor.to_account_info(),
+            token_program.to_account_info(),
+        ],
+    )?;
+
+    let shared_custody = &mut ctx.accounts.shared_custody;
+    shared_custody.auction_house = auction_house.key();
+    shared_custody.token_mint = token_mint.key();
+    shared_custody.depositor = depositor.key();
+    shared_custody.threshold = threshold;
+    shared_custody.owners = owners;
+    shared_custody.bump = *ctx
+        .bumps
+        .get("shared_custody")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Approve listing the shared custody's token at `buyer_price`. Approving a different price than
+/// the pending one discards the approvals given so far. The approval that reaches the threshold
+/// lists the token, with the shared custody PDA as the seller.
+pub fn co_list<'info>(
+    ctx: Context<'_, '_, '_, 'info, CoList<'info>>,
+    buyer_price: u64,
+    trade_state_bump: u8,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let auction_house = &ctx.accounts.auction_house;
+    let custody_token_account = &ctx.accounts.custody_token_account;
+    let seller_trade_state = &ctx.accounts.seller_trade_state;
+    let program_as_signer = &ctx.accounts.program_as_signer;
+    let token_program = &ctx.accounts.token_program;
+    let shared_custody = &mut ctx.accounts.shared_custody;
+
+    assert_is_ata(
+        &custody_token_account.to_account_info(),
+        &shared_custody.key(),
+        &shared_custody.token_mint,
+    )?;
+    if shared_custody.is_listed {
+        return Err(AuctionHouseError::SharedCustodyAlreadyListed.into());
+    }
+    let index = shared_custody
+        .owners
+        .iter()
+        .position(|co_owner| co_owner.wallet == owner.key())
+        .ok_or(AuctionHouseError::NotCoOwner)?;
+
+    if shared_custody.listing_price != buyer_price {
+        shared_custody.listing_price = buyer_price;
+        shared_custody.approvals = 0;
+    }
+    shared_custody.approvals |= 1 << index;
+
+    if shared_custody.approval_count() < shared_custody.threshold {
+        return Ok(());
+    }
+
+    let shared_custody_key = shared_custody.key();
+    let token_mint = shared_custody.token_mint;
+    assert_valid_trade_state(
+        &shared_custody_key,
+        auction_house,
+        buyer_price,
+        1,
+        seller_trade_state,
+        &token_mint,
+        &custody_token_account.key(),
+        trade_state_bump,
+    )?;
+    if !seller_trade_state.data_is_empty() {
+        return Err(AuctionHouseError::TradeStateIsNotEmpty.into());
+    }
+
+    let auction_house_key = auction_house.key();
+    let custody_token_account_key = custody_token_account.key();
+    let ts_info = seller_trade_state.to_account_info();
+    create_or_allocate_account_raw(
+        crate::id(),
+        &ts_info,
+        &ctx.accounts.rent.to_account_info(),
+        &ctx.accounts.system_program,
+        owner,
+        TRADE_STATE_SIZE,
+        &[],
+        &[
+            PREFIX.as_bytes(),
+            shared_custody_key.as_ref(),
+            auction_house_key.as_ref(),
+            custody_token_account_key.as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.as_ref(),
+            &buyer_price.to_le_bytes(),
+            &1u64.to_le_bytes(),
+            &[trade_state_bump],
+        ],
+    )?;
+    #[allow(clippy::explicit_auto_deref)]
+    sol_memset(
+        *ts_info.try_borrow_mut_data()?,
+        trade_state_bump,
+        TRADE_STATE_SIZE,
+    );
+
+    let custody_seeds = [
+        SHARED_CUSTODY.as_bytes(),
+        auction_house_key.as_ref(),
+        token_mint.as_ref(),
+        &[shared_custody.bump],
+    ];
+    invoke_signed(
+        &spl_token::instruction::approve(
+            &token_program.key(),
+            &custody_token_account_key,
+            &program_as_signer.key(),
+            &shared_custody_key,
+            &[],
+            1,
+        )?,
+        &[
+            token_program.to_account_info(),
+            custody_token_account.to_account_info(),
+            program_as_signer.to_account_info(),
+            shared_custody.to_account_info(),
+        ],
+        &[&custody_seeds],
+    )?;
+
+    shared_custody.is_listed = true;
+
+    Ok(())
+}
+
+/// Split the proceeds of the shared custody's sale between its co-owners by their shares, and
+/// close the shared custody accounts back to the depositor. Rounding dust goes to the last
+/// co-owner.
+///
+/// The co-owner wallets are passed in `remaining_accounts`, in the order they were recorded.
+pub fn settle_shared_custody<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleSharedCustody<'info>>,
+) -> Result<()> {
+    let depositor = &ctx.accounts.depositor;
+    let shared_custody = &ctx.accounts.shared_custody;
+    let custody_token_account = &ctx.accounts.custody_token_account;
+    let token_program = &ctx.accounts.token_program;
+
+    assert_is_ata(
+        &custody_token_account.to_account_info(),
+        &shared_custody.key(),
+        &shared_custody.token_mint,
+    )?;
+    if custody_token_account.amount > 0 {
+        return Err(AuctionHouseError::SharedCustodyNotSold.into());
+    }
+
+    let custody_info = shared_custody.to_account_info();
+    let proceeds = custody_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(custody_info.data_len()));
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let mut paid = 0u64;
+    for (i, co_owner) in shared_custody.owners.iter().enumerate() {
+        let wallet = next_account_info(remaining_accounts)?;
+        assert_keys_equal(wallet.key(), co_owner.wallet)?;
+
+        let amount = if i == shared_custody.owners.len() - 1 {
+            proceeds - paid
+        } else {
+            (proceeds as u128)
+                .checked_mul(co_owner.share_basis_points as u128)
+                .and_then(|product| product.checked_div(10000))
+                .and_then(|share| u64::try_from(share).ok())
+                .ok_or(AuctionHouseError::NumericalOverflow)?
+        };
+        paid = paid
+            .checked_add(amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+        **custody_info.lamports.borrow_mut() = custody_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+        **wallet.lamports.borrow_mut() = wallet
+            .lamports()
+            .checked_add(amount)
+            .ok_or(AuctionHouseError::NumericalOverflow)?;
+    }
+
+    let custody_seeds = [
+        SHARED_CUSTODY.as_bytes(),
+        shared_custody.auction_house.as_ref(),
+        shared_custody.token_mint.as_ref(),
+        &[shared_custody.bump],
+    ];
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            &token_program.key(),
+            &custody_token_account.key(),
+            &depositor.key(),
+            &shared_custody.key(),
+            &[],
+        )?,
+        &[
+            custody_token_account.to_account_info(),
+            depositor.to_account_info(),
+            custody_info,
+            token_program.to_account_info(),
+        ],
+        &[&custody_seeds],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! The house setting that allows a seller only one open listing of a mint at a time, so a token
+//! cannot end up with several trade states listing it at different prices.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    ActiveListing, AuctionHouse, AuthorityAction,
+};
+
+/// Accounts for the [`set_single_active_listing` handler](auction_house/fn.set_single_active_listing.html).
+#[derive(Accounts)]
+pub struct SetSingleActiveListing<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_single_active_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetSingleActiveListing<'info>>,
+    single_active_listing: bool,
+) -> Result<()> {
+    ctx.accounts.auction_house.single_active_listing = single_active_listing;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetSingleActiveListing,
+        single_active_listing as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Record `trade_state` as the seller's active listing of `mint` when the Auction House allows
+/// only one at a time, failing if another listing of theirs is still open.
+///
+/// The active listing account is passed in `remaining_accounts` ahead of any programmable NFT
+/// accounts. It may be uninitialized if the seller has never listed the mint here. When it records
+/// a different trade state, that trade state is passed next, and is replaced only if it has been
+/// canceled or sold. `repriced_trade_state` is the free listing an authority is repricing, which
+/// is replaced while still open.
+#[allow(clippy::too_many_arguments)]
+pub fn record_active_listing<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    seller: &Pubkey,
+    mint: &Pubkey,
+    trade_state: &Pubkey,
+    repriced_trade_state: Option<Pubkey>,
+    fee_payer: &AccountInfo<'info>,
+    fee_payer_seeds: &[&[u8]],
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auction_house.single_active_listing {
+        return Ok(());
+    }
+
+    let active_listing_info = next_account_info(remaining_accounts)?;
+    let auction_house_key = auction_house.key();
+    let (active_listing_key, bump) = Pubkey::find_program_address(
+        &[
+            ACTIVE_LISTING.as_bytes(),
+            auction_house_key.as_ref(),
+            seller.as_ref(),
+            mint.as_ref(),
+        ],
+        &crate::id(),
+    );
+    assert_keys_equal(active_listing_key, active_listing_info.key())?;
+
+    if active_listing_info.data_is_empty() {
+        let active_listing_seeds = [
+            ACTIVE_LISTING.as_bytes(),
+            auction_house_key.as_ref(),
+            seller.as_ref(),
+            mint.as_ref(),
+            &[bump],
+        ];
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            active_listing_info,
+            rent,
+            system_program,
+            fee_payer,
+            ACTIVE_LISTING_SIZE,
+            fee_payer_seeds,
+            &active_listing_seeds,
+        )?;
+    } else {
+        let active_listing: Account<ActiveListing> = Account::try_from(active_listing_info)?;
+        if active_listing.trade_state == *trade_state {
+            return Ok(());
+        }
+
+        let previous_trade_state = next_account_info(remaining_accounts)?;
+        assert_keys_equal(active_listing.trade_state, previous_trade_state.key())?;
+        if !previous_trade_state.data_is_empty()
+            && repriced_trade_state != Some(active_listing.trade_state)
+        {
+            return Err(AuctionHouseError::ListingAlreadyActive.into());
+        }
+    }
+
+    let active_listing = ActiveListing {
+        auction_house: auction_house_key,
+        seller: *seller,
+        mint: *mint,
+        trade_state: *trade_state,
+        bump,
+    };
+    active_listing.try_serialize(&mut *active_listing_info.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
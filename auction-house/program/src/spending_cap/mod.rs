@@ -0,0 +1,161 @@
+//! Spending caps: a wallet sets aside part of its escrow for a marketplace frontend, so bids the
+//! frontend co-signs can spend at most the cap. A bid carries the cap and the frontend's
+//! signature in `remaining_accounts`; bids without them are not charged.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, SpendingCap};
+
+/// Accounts for the [`create_spending_cap` handler](auction_house/fn.create_spending_cap.html).
+#[derive(Accounts)]
+pub struct CreateSpendingCap<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: Any key may act as a frontend.
+    /// Key the marketplace frontend co-signs its bids with.
+    pub frontend: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The spending cap PDA for the wallet and frontend.
+    #[account(
+        init,
+        payer=wallet,
+        space=SPENDING_CAP_SIZE,
+        seeds=[
+            SPENDING_CAP.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref(),
+            frontend.key().as_ref()
+        ],
+        bump
+    )]
+    pub spending_cap: Box<Account<'info, SpendingCap>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_spending_cap` handler](auction_house/fn.update_spending_cap.html).
+#[derive(Accounts)]
+pub struct UpdateSpendingCap<'info> {
+    /// User wallet account.
+    pub wallet: Signer<'info>,
+
+    /// The spending cap PDA for the wallet and frontend.
+    #[account(
+        mut,
+        seeds=[
+            SPENDING_CAP.as_bytes(),
+            spending_cap.auction_house.as_ref(),
+            wallet.key().as_ref(),
+            spending_cap.frontend.as_ref()
+        ],
+        bump=spending_cap.bump,
+        has_one=wallet
+    )]
+    pub spending_cap: Box<Account<'info, SpendingCap>>,
+}
+
+/// Accounts for the [`close_spending_cap` handler](auction_house/fn.close_spending_cap.html).
+#[derive(Accounts)]
+pub struct CloseSpendingCap<'info> {
+    /// User wallet account, refunded the spending cap's rent.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The spending cap PDA for the wallet and frontend.
+    #[account(
+        mut,
+        seeds=[
+            SPENDING_CAP.as_bytes(),
+            spending_cap.auction_house.as_ref(),
+            wallet.key().as_ref(),
+            spending_cap.frontend.as_ref()
+        ],
+        bump=spending_cap.bump,
+        has_one=wallet,
+        close=wallet
+    )]
+    pub spending_cap: Box<Account<'info, SpendingCap>>,
+}
+
+pub fn create_spending_cap<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSpendingCap<'info>>,
+    cap: u64,
+) -> Result<()> {
+    let spending_cap = &mut ctx.accounts.spending_cap;
+    spending_cap.auction_house = ctx.accounts.auction_house.key();
+    spending_cap.wallet = ctx.accounts.wallet.key();
+    spending_cap.frontend = ctx.accounts.frontend.key();
+    spending_cap.cap = cap;
+    spending_cap.spent = 0;
+    spending_cap.bump = *ctx
+        .bumps
+        .get("spending_cap")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Replace the cap, starting a new allocation with nothing spent.
+pub fn update_spending_cap<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateSpendingCap<'info>>,
+    cap: u64,
+) -> Result<()> {
+    let spending_cap = &mut ctx.accounts.spending_cap;
+    spending_cap.cap = cap;
+    spending_cap.spent = 0;
+
+    Ok(())
+}
+
+pub fn close_spending_cap<'info>(
+    _ctx: Context<'_, '_, '_, 'info, CloseSpendingCap<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+/// Charge a bid of `buyer_price` to the spending cap the wallet set for a frontend, if one was
+/// passed in `remaining_accounts`.
+///
+/// The spending cap is passed first, followed by the frontend, which must sign the bid.
+pub fn charge_spending_cap<'a, 'info>(
+    auction_house: &Pubkey,
+    wallet: &Pubkey,
+    buyer_price: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    let mut spending_cap = match next_program_account::<SpendingCap>(remaining_accounts)? {
+        Some(spending_cap) => spending_cap,
+        None => return Ok(()),
+    };
+    assert_keys_equal(spending_cap.auction_house, *auction_house)?;
+    assert_keys_equal(spending_cap.wallet, *wallet)?;
+
+    let frontend = next_account_info(remaining_accounts)?;
+    assert_keys_equal(spending_cap.frontend, *frontend.key)?;
+    if !frontend.is_signer {
+        return Err(AuctionHouseError::FrontendMustSign.into());
+    }
+
+    spending_cap.spent = spending_cap
+        .spent
+        .checked_add(buyer_price)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    if spending_cap.spent > spending_cap.cap {
+        return Err(AuctionHouseError::SpendingCapExceeded.into());
+    }
+
+    spending_cap.exit(&crate::id())
+}
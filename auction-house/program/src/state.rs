@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 
-use crate::constants::*;
+use crate::{constants::*, math::Rounding};
 
 #[account]
 pub struct AuctionHouse {
@@ -21,6 +21,34 @@ pub struct AuctionHouse {
     pub has_auctioneer: bool,
     pub auctioneer_address: Pubkey,
     pub scopes: [bool; MAX_NUM_SCOPES],
+    pub relist_cooldown_seconds: i64,
+    pub has_authority_log: bool,
+    pub buyer_protection_seconds: i64,
+    pub restocking_fee_basis_points: u16,
+    pub arbiter: Pubkey,
+    pub allow_seller_financing: bool,
+    pub min_listing_price: Option<u64>,
+    pub max_listing_price: Option<u64>,
+    pub has_auctioneer_allowlist: bool,
+    pub has_compliance_hook: bool,
+    pub has_receipt_tree: bool,
+    pub has_tax_withholding: bool,
+    pub low_fee_balance_threshold: u64,
+    pub has_bid_ticket_gate: bool,
+    pub allow_wallet_bound_receipts: bool,
+    pub is_immutable: bool,
+    /// Auctioneer PDA replaced by the last rotation, still accepted until
+    /// `previous_auctioneer_expires_at`.
+    pub previous_auctioneer_address: Pubkey,
+    pub previous_auctioneer_expires_at: i64,
+    pub rounding_policy: RoundingPolicy,
+    pub payout_delay_seconds: i64,
+    /// Token standards that may be listed, as `TOKEN_STANDARD_*` bits. Empty allows all of them.
+    pub token_standard_policy: u8,
+    pub requires_verified_creators: bool,
+    pub single_active_listing: bool,
+    pub has_trade_sequence: bool,
+    pub locks_listing_currency: bool,
 }
 
 #[account]
@@ -28,6 +56,637 @@ pub struct Auctioneer {
     pub auctioneer_authority: Pubkey,
     pub auction_house: Pubkey,
     pub bump: u8,
+    pub auctioneer_program: Pubkey,
+    pub has_stats: bool,
+}
+
+/// House-wide configuration for the staking fee discount.
+#[account]
+pub struct FeeStakeConfig {
+    pub auction_house: Pubkey,
+    pub stake_mint: Pubkey,
+    pub stake_vault: Pubkey,
+    pub min_stake_amount: u64,
+    pub discounted_seller_fee_basis_points: u16,
+    pub lockup_seconds: i64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+/// Amount a wallet has staked into an Auction House fee stake vault.
+#[account]
+pub struct FeeStake {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub amount: u64,
+    pub staked_at: i64,
+    pub bump: u8,
+}
+
+/// Time a mint was last settled on an Auction House, used to enforce the relist cooldown.
+#[account]
+pub struct MintCooldown {
+    pub auction_house: Pubkey,
+    pub mint: Pubkey,
+    pub last_settled_at: i64,
+    pub bump: u8,
+}
+
+/// A sale held in escrow during the buyer protection window. The settlement PDA holds the
+/// seller's proceeds and owns the token account holding the purchased tokens.
+#[account]
+pub struct ProtectedSettlement {
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    pub seller_token_account: Pubkey,
+    pub rent_payer: Pubkey,
+    pub token_size: u64,
+    pub seller_proceeds: u64,
+    pub restocking_fee_basis_points: u16,
+    pub release_at: i64,
+    pub bump: u8,
+    pub disputed: bool,
+}
+
+/// Seller proceeds held after settlement until the house payout delay has passed.
+#[account]
+pub struct DelayedPayout {
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub token_mint: Pubkey,
+    pub rent_payer: Pubkey,
+    pub amount: u64,
+    pub release_at: i64,
+    pub bump: u8,
+}
+
+/// A dispute raised on a protected settlement and its resolution by the house arbiter.
+#[account]
+pub struct DisputeRecord {
+    pub auction_house: Pubkey,
+    pub protected_settlement: Pubkey,
+    pub opened_by: Pubkey,
+    pub opened_at: i64,
+    pub resolution: Option<DisputeResolution>,
+    pub resolved_at: Option<i64>,
+    pub bump: u8,
+}
+
+/// The balance the buyer of a financed sale still owes the seller. The token cannot be relisted
+/// on the Auction House until the lien is repaid.
+#[account]
+pub struct Lien {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub outstanding: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Sale proceeds held for a recipient whose treasury mint token account was frozen at
+/// settlement.
+#[account]
+pub struct FrozenProceeds {
+    pub auction_house: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+/// A fee set by the auctioneer for a single listing, charged instead of the house fee when the
+/// listing settles.
+#[account]
+pub struct ListingFee {
+    pub auction_house: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub fee_basis_points: u16,
+    pub bump: u8,
+}
+
+/// Extra royalty a seller adds to a listing, paid to the token's creators on top of its royalties
+/// when the listing settles.
+#[account]
+pub struct RoyaltyBonus {
+    pub auction_house: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub wallet: Pubkey,
+    pub bonus_basis_points: u16,
+    pub bump: u8,
+}
+
+/// A wallet's approval for `depositor` to deposit into its escrow payment account.
+#[account]
+pub struct DepositAuthority {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub depositor: Pubkey,
+    pub approved_at: i64,
+    pub bump: u8,
+}
+
+/// Auctioneer programs the house authority may delegate to. Delegated auctioneer authorities
+/// must be the auctioneer PDA of one of these programs.
+#[account]
+pub struct AuctioneerAllowlist {
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub programs: Vec<Pubkey>,
+}
+
+/// Settlements run by an auctioneer delegated on an Auction House, and the house fees they paid.
+#[account]
+pub struct AuctioneerStats {
+    pub auction_house: Pubkey,
+    pub auctioneer_authority: Pubkey,
+    pub auctions_run: u64,
+    pub settled_volume: u64,
+    pub fees_earned: u64,
+    pub bump: u8,
+}
+
+/// The highest open public bids on a mint, sorted by buyer price, so sellers can find the best
+/// offer on their token on-chain. Bids settled by execute_sale stay indexed until pruned.
+#[account]
+pub struct PublicBidIndex {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    pub bids: Vec<IndexedPublicBid>,
+}
+
+impl PublicBidIndex {
+    /// Insert `bid` in buyer price order, replacing any entry for the same trade state. Once the
+    /// index is full the lowest bid is dropped, so a bid below every indexed bid is not added.
+    pub fn insert(&mut self, bid: IndexedPublicBid) {
+        self.remove(&bid.trade_state);
+
+        let position = self
+            .bids
+            .iter()
+            .position(|indexed| indexed.buyer_price < bid.buyer_price)
+            .unwrap_or(self.bids.len());
+        if position >= MAX_INDEXED_PUBLIC_BIDS {
+            return;
+        }
+
+        self.bids.insert(position, bid);
+        self.bids.truncate(MAX_INDEXED_PUBLIC_BIDS);
+    }
+
+    /// Remove the entry for `trade_state`, if it is indexed.
+    pub fn remove(&mut self, trade_state: &Pubkey) {
+        self.bids
+            .retain(|indexed| indexed.trade_state != *trade_state);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IndexedPublicBid {
+    pub trade_state: Pubkey,
+    pub wallet: Pubkey,
+    pub buyer_price: u64,
+    pub token_size: u64,
+}
+
+/// When an open bid expires. Once it has, anyone may sweep the bid, refunding its price from
+/// escrow to the buyer and keeping the reclaimed rent.
+#[account]
+pub struct BidExpiry {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub trade_state: Pubkey,
+    pub buyer_price: u64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+/// The house's jurisdiction and the program asked to approve each buy and sell on it.
+#[account]
+pub struct ComplianceConfig {
+    pub auction_house: Pubkey,
+    pub jurisdiction_code: [u8; 2],
+    pub compliance_program: Pubkey,
+    pub bump: u8,
+}
+
+/// The share of each sale withheld as tax at source and the account it is routed to.
+#[account]
+pub struct TaxWithholding {
+    pub auction_house: Pubkey,
+    pub withholding_basis_points: u16,
+    pub recipient: Pubkey,
+    pub bump: u8,
+}
+
+/// A buyer's public offer for any token meeting `criteria`, paid from the buyer's escrow. The
+/// offered price declines linearly from `start_price` at `start_time` to `floor_price` at
+/// `end_time`; equal prices make it a fixed price offer.
+#[account]
+pub struct WantedListing {
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    pub criteria: WantedCriteria,
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WantedCriteria {
+    /// Only the token of this mint fills the listing.
+    Mint(Pubkey),
+    /// Any token whose metadata has this verified collection fills the listing.
+    Collection(Pubkey),
+}
+
+impl WantedCriteria {
+    /// The mint or collection key the criteria is matched against.
+    pub fn target(&self) -> &Pubkey {
+        match self {
+            WantedCriteria::Mint(mint) => mint,
+            WantedCriteria::Collection(collection) => collection,
+        }
+    }
+}
+
+/// The fee account balance above which anyone may sweep the excess to the treasury, and a
+/// running record of the sweeps.
+#[account]
+pub struct FeeSweepConfig {
+    pub auction_house: Pubkey,
+    pub threshold: u64,
+    pub total_swept: u64,
+    pub last_swept_at: i64,
+    pub bump: u8,
+}
+
+/// The most the house's auctioneer may withdraw from the treasury in each period of
+/// `period_seconds`, and the amount withdrawn in the current period.
+#[account]
+pub struct TreasuryWithdrawalLimit {
+    pub auction_house: Pubkey,
+    pub max_per_period: u64,
+    pub period_seconds: i64,
+    pub period_start: i64,
+    pub withdrawn_in_period: u64,
+    pub bump: u8,
+}
+
+/// The verified collection whose NFTs bidders burn as tickets to bid on the house.
+#[account]
+pub struct BidTicketConfig {
+    pub auction_house: Pubkey,
+    pub collection: Pubkey,
+    pub bump: u8,
+}
+
+/// The ticket NFT burned to place the bid encoded by `trade_state`.
+#[account]
+pub struct BidTicketBurn {
+    pub auction_house: Pubkey,
+    pub trade_state: Pubkey,
+    pub wallet: Pubkey,
+    pub ticket_mint: Pubkey,
+    pub burned_at: i64,
+    pub bump: u8,
+}
+
+/// The Auction Houses a collection's update authority approves for listing the collection's NFTs.
+/// An empty list approves every house.
+#[account]
+pub struct MarketplaceBinding {
+    pub collection: Pubkey,
+    pub bump: u8,
+    pub approved_houses: Vec<Pubkey>,
+}
+
+/// A creator's presale voucher for a token that is not minted yet. The buyer's payment is held in
+/// the voucher until the creator redeems it by delivering the token, or returned to the buyer
+/// once `redeem_deadline` passes.
+#[account]
+pub struct Voucher {
+    pub auction_house: Pubkey,
+    pub creator: Pubkey,
+    pub id: u64,
+    pub price: u64,
+    pub redeem_deadline: i64,
+    /// The default `Pubkey` until the voucher is sold.
+    pub buyer: Pubkey,
+    pub bump: u8,
+}
+
+/// The top buyers and sellers by settled volume on an Auction House during a competition epoch.
+/// Sales settled between `starts_at` and `ends_at` with the leaderboard passed are counted.
+#[account]
+pub struct Leaderboard {
+    pub auction_house: Pubkey,
+    pub epoch: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub bump: u8,
+    pub buyers: Vec<LeaderboardEntry>,
+    pub sellers: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Whether sales settled at `now` count towards the leaderboard.
+    pub fn is_open(&self, now: i64) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    /// Add `volume` to `wallet` in `entries`, keeping them in volume order. Once the leaderboard
+    /// is full the lowest entry is dropped, so a wallet below every ranked wallet is not added.
+    pub fn record(entries: &mut Vec<LeaderboardEntry>, wallet: Pubkey, volume: u64) {
+        let total = match entries.iter().position(|entry| entry.wallet == wallet) {
+            Some(index) => entries.remove(index).volume,
+            None => 0,
+        }
+        .saturating_add(volume);
+
+        let position = entries
+            .iter()
+            .position(|entry| entry.volume < total)
+            .unwrap_or(entries.len());
+        if position >= MAX_LEADERBOARD_ENTRIES {
+            return;
+        }
+
+        entries.insert(
+            position,
+            LeaderboardEntry {
+                wallet,
+                volume: total,
+            },
+        );
+        entries.truncate(MAX_LEADERBOARD_ENTRIES);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LeaderboardEntry {
+    pub wallet: Pubkey,
+    pub volume: u64,
+}
+
+/// An NFT held in custody for several co-owners. It is listed once `threshold` co-owners approve
+/// the same price, and the proceeds of its sale are split between them by their shares.
+#[account]
+pub struct SharedCustody {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub depositor: Pubkey,
+    pub threshold: u8,
+    pub listing_price: u64,
+    /// Bit `i` is set once `owners[i]` has approved listing at `listing_price`.
+    pub approvals: u16,
+    pub is_listed: bool,
+    pub bump: u8,
+    pub owners: Vec<CoOwner>,
+}
+
+impl SharedCustody {
+    /// Number of co-owners that have approved listing at `listing_price`.
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CoOwner {
+    pub wallet: Pubkey,
+    pub share_basis_points: u16,
+}
+
+/// The last `MAX_PRICE_HISTORY_ENTRIES` settlement prices of a mint on an Auction House, per token
+/// sold. Once full, each sale overwrites the oldest entry.
+#[account]
+pub struct PriceHistory {
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub bump: u8,
+    /// Index in `entries` the next sale is recorded at.
+    pub next_index: u16,
+    pub entries: Vec<PricePoint>,
+}
+
+impl PriceHistory {
+    pub fn record(&mut self, price: u64, timestamp: i64) {
+        let point = PricePoint { price, timestamp };
+        let index = self.next_index as usize;
+        if index < self.entries.len() {
+            self.entries[index] = point;
+        } else {
+            self.entries.push(point);
+        }
+        self.next_index = ((index + 1) % MAX_PRICE_HISTORY_ENTRIES) as u16;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PricePoint {
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+/// Balances returned by `get_house_balances`. `unswept_fees` is the part of the fee account
+/// balance above the fee sweep threshold, or zero when no fee sweep config was passed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HouseBalances {
+    pub treasury_balance: u64,
+    pub fee_account_balance: u64,
+    pub unswept_fees: u64,
+}
+
+/// The part of a wallet's escrow a marketplace frontend may spend on the bids it co-signs.
+#[account]
+pub struct SpendingCap {
+    pub auction_house: Pubkey,
+    pub wallet: Pubkey,
+    pub frontend: Pubkey,
+    pub cap: u64,
+    /// Total of the bids charged to the cap since it was last set.
+    pub spent: u64,
+    pub bump: u8,
+}
+
+/// A custodian the house lets list and bid on behalf of many users.
+#[account]
+pub struct OmnibusCustodian {
+    pub auction_house: Pubkey,
+    pub custodian: Pubkey,
+    pub bump: u8,
+}
+
+/// The user sub-account an omnibus custodian placed a listing or bid for.
+#[account]
+pub struct TradeAttribution {
+    pub auction_house: Pubkey,
+    pub custodian: Pubkey,
+    pub trade_state: Pubkey,
+    /// Identifier of the user within the custodian's books, opaque to the house.
+    pub sub_account: [u8; 32],
+    pub bump: u8,
+}
+
+/// The listing a seller currently has for a mint, on Auction Houses allowing only one at a time.
+#[account]
+pub struct ActiveListing {
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    pub mint: Pubkey,
+    pub trade_state: Pubkey,
+    pub bump: u8,
+}
+
+/// The number of listings and bids placed on an Auction House, and the trade state of the last one.
+#[account]
+pub struct TradeSequence {
+    pub auction_house: Pubkey,
+    pub last_sequence: u64,
+    pub last_trade_state: Pubkey,
+    pub bump: u8,
+}
+
+/// An operational notice the Auction House authority publishes for frontends to show, such as a
+/// maintenance or pause banner. The message text is kept off chain and identified by its hash.
+#[account]
+pub struct HouseNotice {
+    pub auction_house: Pubkey,
+    pub status: NoticeStatus,
+    pub message_hash: [u8; 32],
+    /// Unix timestamp after which the notice no longer applies, or zero if it does not expire.
+    pub expires_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl HouseNotice {
+    /// Whether the notice should still be shown at `timestamp`.
+    pub fn is_active(&self, timestamp: i64) -> bool {
+        self.status != NoticeStatus::None && (self.expires_at == 0 || timestamp < self.expires_at)
+    }
+}
+
+/// How far an escrowed settlement has progressed, so a failed step can be retried safely.
+#[account]
+pub struct SettlementMarker {
+    pub auction_house: Pubkey,
+    pub protected_settlement: Pubkey,
+    pub status: SettlementStatus,
+    /// Number of times the sale has been executed into escrow.
+    pub attempts: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettlementStatus {
+    /// The sale has not been executed.
+    NotStarted,
+    /// The sale was executed and its tokens and proceeds are held in escrow.
+    Escrowed,
+    /// The escrow was released to the buyer and seller.
+    Settled,
+    /// The escrow was returned, undoing the sale.
+    RolledBack,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NoticeStatus {
+    /// No notice is posted.
+    None,
+    /// General information with no effect on trading.
+    Info,
+    /// Maintenance is underway or scheduled, and trading may be degraded.
+    Maintenance,
+    /// The house has paused trading.
+    Paused,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisputeResolution {
+    /// The tokens go back to the seller and the buyer is refunded in full.
+    Buyer,
+    /// The tokens are delivered to the buyer and the seller is paid.
+    Seller,
+}
+
+/// Ring buffer of the most recent authority-level actions taken on an Auction House.
+#[account]
+pub struct AuthorityLog {
+    pub auction_house: Pubkey,
+    pub bump: u8,
+    pub next_index: u16,
+    pub total_entries: u64,
+    pub entries: Vec<AuthorityLogEntry>,
+}
+
+impl AuthorityLog {
+    /// Append `entry`, overwriting the oldest entry once the log is full.
+    pub fn record(&mut self, entry: AuthorityLogEntry) {
+        let index = self.next_index as usize;
+        if index < self.entries.len() {
+            self.entries[index] = entry;
+        } else {
+            self.entries.push(entry);
+        }
+
+        self.next_index = ((index + 1) % AUTHORITY_LOG_CAPACITY) as u16;
+        self.total_entries = self.total_entries.saturating_add(1);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct AuthorityLogEntry {
+    pub action: AuthorityAction,
+    pub authority: Pubkey,
+    /// Action-specific value, such as the amount withdrawn or the new relist cooldown.
+    pub value: u64,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuthorityAction {
+    UpdateAuctionHouse,
+    WithdrawFromTreasury,
+    WithdrawFromFee,
+    DelegateAuctioneer,
+    UpdateAuctioneer,
+    CreateFeeStakeConfig,
+    UpdateFeeStakeConfig,
+    SetRelistCooldown,
+    SetBuyerProtection,
+    SetArbiter,
+    SetSellerFinancing,
+    SetListingPriceBounds,
+    CreateAuctioneerAllowlist,
+    UpdateAuctioneerAllowlist,
+    SetComplianceConfig,
+    CreateReceiptTree,
+    SetTaxWithholding,
+    SetFeeSweepThreshold,
+    SetLowFeeBalanceThreshold,
+    SetTreasuryWithdrawalLimit,
+    SetBidTicketConfig,
+    SetWalletBoundReceipts,
+    CreateAuctioneerStats,
+    RotateAuctioneer,
+    CreateLeaderboard,
+    SetRoundingPolicy,
+    SetPayoutDelay,
+    ClawbackProceeds,
+    SetTokenStandardPolicy,
+    SetRequiresVerifiedCreators,
+    SetSingleActiveListing,
+    SetListingCurrencyLock,
+    SetHouseNotice,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -40,4 +699,38 @@ pub enum AuthorityScope {
     Sell = 4,
     Cancel = 5,
     Withdraw = 6,
+    SetFee = 7,
+    PauseListing = 8,
+    WithdrawTreasury = 9,
+}
+
+/// How settlement rounds royalties and house fees that fall between two whole units of the
+/// treasury mint. Whatever is not paid out stays with the seller.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingPolicy {
+    /// Round royalties and house fees down.
+    Floor,
+    /// Round royalties, and each creator's share of them, up. House fees are rounded down.
+    CreatorCeiling,
+    /// Round royalties and house fees to the nearest unit, with exact halves rounded to even.
+    HalfEven,
+}
+
+impl RoundingPolicy {
+    /// Rounding applied to the royalty on a sale and to each creator's share of it.
+    pub fn royalty_rounding(&self) -> Rounding {
+        match self {
+            RoundingPolicy::Floor => Rounding::Down,
+            RoundingPolicy::CreatorCeiling => Rounding::Up,
+            RoundingPolicy::HalfEven => Rounding::HalfEven,
+        }
+    }
+
+    /// Rounding applied to the house fee on a sale.
+    pub fn fee_rounding(&self) -> Rounding {
+        match self {
+            RoundingPolicy::Floor | RoundingPolicy::CreatorCeiling => Rounding::Down,
+            RoundingPolicy::HalfEven => Rounding::HalfEven,
+        }
+    }
 }
@@ -0,0 +1,263 @@
+//! Fully typed variants of instructions whose primary accounts are otherwise left unchecked to
+//! stay under the stack limit. Built with the `strict` feature, for houses that would rather pay
+//! the extra compute for Anchor's own validation of every account.
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    constants::*, errors::AuctionHouseError, execute_sale::*, AuctionHouse, AuthorityScope,
+};
+
+/// Accounts for the [`strict_execute_sale` handler](auction_house/fn.strict_execute_sale.html).
+///
+/// Typed accounts are boxed to keep the deserialized struct off the stack.
+#[derive(Accounts, Clone)]
+#[instruction(
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64
+)]
+pub struct StrictExecuteSale<'info> {
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+
+    /// Seller user wallet account.
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    /// Token account where the SPL token is stored.
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = seller
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account for the SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Not dangerous. Account seeds and owner checked in constraint.
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(
+        seeds = [
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref()
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+        owner = mpl_token_metadata::ID
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Auction House treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in execute_sale_logic.
+    /// Seller SOL or SPL account to receive payment at.
+    #[account(mut)]
+    pub seller_payment_receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in execute_sale_logic.
+    /// Buyer SPL token account to receive purchased item at.
+    #[account(mut)]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in execute_sale_logic.
+    /// Auction House instance authority.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority,
+        has_one=treasury_mint,
+        has_one=auction_house_treasury,
+        has_one=auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        bump=auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in execute_sale_logic.
+    /// Buyer trade state PDA account encoding the buy order.
+    #[account(mut)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &buyer_price.to_le_bytes(),
+            &token_size.to_le_bytes()
+        ],
+        bump = seller_trade_state.to_account_info().data.borrow()[0]
+    )]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order. Only required when the
+    /// Auction House can change sale prices.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &0u64.to_le_bytes(),
+            &token_size.to_le_bytes()
+        ],
+        bump
+    )]
+    pub free_trade_state: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> From<StrictExecuteSale<'info>> for ExecuteSale<'info> {
+    fn from(a: StrictExecuteSale<'info>) -> ExecuteSale<'info> {
+        ExecuteSale {
+            buyer: UncheckedAccount::try_from(a.buyer.to_account_info()),
+            seller: UncheckedAccount::try_from(a.seller.to_account_info()),
+            token_account: UncheckedAccount::try_from(a.token_account.to_account_info()),
+            token_mint: UncheckedAccount::try_from(a.token_mint.to_account_info()),
+            metadata: a.metadata,
+            treasury_mint: UncheckedAccount::try_from(a.treasury_mint.to_account_info()),
+            escrow_payment_account: a.escrow_payment_account,
+            seller_payment_receipt_account: a.seller_payment_receipt_account,
+            buyer_receipt_token_account: a.buyer_receipt_token_account,
+            authority: a.authority,
+            auction_house: a.auction_house,
+            auction_house_fee_account: a.auction_house_fee_account,
+            auction_house_treasury: a.auction_house_treasury,
+            buyer_trade_state: a.buyer_trade_state,
+            seller_trade_state: a.seller_trade_state,
+            free_trade_state: a.free_trade_state,
+            token_program: a.token_program,
+            system_program: a.system_program,
+            ata_program: a.ata_program,
+            program_as_signer: a.program_as_signer,
+            rent: a.rent,
+        }
+    }
+}
+
+/// Settle a sale like `execute_sale`, with the wallets, token account, mints and metadata
+/// validated by Anchor before the sale logic runs.
+pub fn strict_execute_sale<'info>(
+    ctx: Context<'_, '_, '_, 'info, StrictExecuteSale<'info>>,
+    escrow_payment_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    let auction_house = &ctx.accounts.auction_house;
+
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::ExecuteSale as usize] {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    let escrow_canonical_bump = *ctx
+        .bumps
+        .get("escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let program_as_signer_canonical_bump = *ctx
+        .bumps
+        .get("program_as_signer")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    if (escrow_canonical_bump != escrow_payment_bump)
+        || (program_as_signer_canonical_bump != program_as_signer_bump)
+    {
+        return Err(AuctionHouseError::BumpSeedNotInHashMap.into());
+    }
+
+    assert_free_trade_state(
+        auction_house,
+        &ctx.accounts.free_trade_state,
+        ctx.bumps.get("free_trade_state"),
+        free_trade_state_bump,
+    )?;
+
+    let mut accounts: ExecuteSale<'info> = (*ctx.accounts).clone().into();
+
+    execute_sale_logic(
+        &mut accounts,
+        ctx.remaining_accounts,
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        buyer_price,
+        token_size,
+        None,
+        None,
+    )
+}
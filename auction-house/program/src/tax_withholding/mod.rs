@@ -0,0 +1,220 @@
+//! Per-house tax withholding for jurisdictions that require marketplaces to withhold tax at
+//! source. A slice of every sale is routed from the seller's proceeds to the house's tax
+//! recipient.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use std::slice::Iter;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError,
+    math::apply_basis_points, utils::*, AuctionHouse, AuthorityAction, TaxWithholding,
+};
+
+/// Accounts for the [`create_tax_withholding` handler](auction_house/fn.create_tax_withholding.html).
+#[derive(Accounts)]
+pub struct CreateTaxWithholding<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The tax withholding PDA for the house.
+    #[account(
+        init,
+        payer=authority,
+        space=TAX_WITHHOLDING_SIZE,
+        seeds=[TAX_WITHHOLDING.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub tax_withholding: Box<Account<'info, TaxWithholding>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_tax_withholding` handler](auction_house/fn.update_tax_withholding.html).
+#[derive(Accounts)]
+pub struct UpdateTaxWithholding<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The tax withholding PDA for the house.
+    #[account(
+        mut,
+        seeds=[TAX_WITHHOLDING.as_bytes(), auction_house.key().as_ref()],
+        bump=tax_withholding.bump,
+        has_one=auction_house
+    )]
+    pub tax_withholding: Box<Account<'info, TaxWithholding>>,
+}
+
+pub fn create_tax_withholding<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateTaxWithholding<'info>>,
+    withholding_basis_points: u16,
+    recipient: Pubkey,
+) -> Result<()> {
+    if withholding_basis_points > 10000 {
+        return Err(AuctionHouseError::InvalidBasisPoints.into());
+    }
+
+    let tax_withholding = &mut ctx.accounts.tax_withholding;
+    tax_withholding.auction_house = ctx.accounts.auction_house.key();
+    tax_withholding.withholding_basis_points = withholding_basis_points;
+    tax_withholding.recipient = recipient;
+    tax_withholding.bump = *ctx
+        .bumps
+        .get("tax_withholding")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.auction_house.has_tax_withholding = withholding_basis_points > 0;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetTaxWithholding,
+        withholding_basis_points as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+pub fn update_tax_withholding<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateTaxWithholding<'info>>,
+    withholding_basis_points: u16,
+    recipient: Pubkey,
+) -> Result<()> {
+    if withholding_basis_points > 10000 {
+        return Err(AuctionHouseError::InvalidBasisPoints.into());
+    }
+
+    let tax_withholding = &mut ctx.accounts.tax_withholding;
+    tax_withholding.withholding_basis_points = withholding_basis_points;
+    tax_withholding.recipient = recipient;
+
+    ctx.accounts.auction_house.has_tax_withholding = withholding_basis_points > 0;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetTaxWithholding,
+        withholding_basis_points as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Route the withheld slice of `price` from the escrow payment account to the house's tax
+/// recipient, returning the amount withheld. Does nothing when the Auction House does not
+/// withhold tax.
+///
+/// When it does, the tax withholding PDA and the account receiving the tax are taken from
+/// `remaining_accounts`: the recipient itself for native SOL houses, or the recipient's
+/// associated token account for the treasury mint.
+#[allow(clippy::too_many_arguments)]
+pub fn withhold_tax<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    escrow_payment_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    price: u64,
+    is_native: bool,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<u64> {
+    if !auction_house.has_tax_withholding {
+        return Ok(0);
+    }
+
+    let tax_withholding_info = next_account_info(remaining_accounts)?;
+    let tax_recipient = next_account_info(remaining_accounts)?;
+
+    let tax_withholding: Account<TaxWithholding> = Account::try_from(tax_withholding_info)?;
+    assert_keys_equal(tax_withholding.auction_house, auction_house.key())?;
+
+    let amount = apply_basis_points(price, tax_withholding.withholding_basis_points)?;
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    if !is_native {
+        assert_is_ata(
+            tax_recipient,
+            &tax_withholding.recipient,
+            &auction_house.treasury_mint,
+        )?;
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                escrow_payment_account.key,
+                tax_recipient.key,
+                &auction_house.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                escrow_payment_account.clone(),
+                tax_recipient.clone(),
+                token_program.clone(),
+                auction_house.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+    } else {
+        assert_keys_equal(tax_recipient.key(), tax_withholding.recipient)?;
+        invoke_signed(
+            &system_instruction::transfer(escrow_payment_account.key, tax_recipient.key, amount),
+            &[
+                escrow_payment_account.clone(),
+                tax_recipient.clone(),
+                system_program.clone(),
+            ],
+            &[signer_seeds],
+        )?;
+    }
+
+    Ok(amount)
+}
+
+/// The tax withheld from a sale of `price` on `auction_house`, for the purchase receipt. The tax
+/// withholding PDA is taken from `remaining_accounts` when passed; without it nothing was withheld.
+pub fn tax_withheld_on<'a, 'info>(
+    auction_house: &Pubkey,
+    price: u64,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<u64> {
+    match next_program_account::<TaxWithholding>(remaining_accounts)? {
+        Some(tax_withholding) => {
+            assert_keys_equal(tax_withholding.auction_house, *auction_house)?;
+            apply_basis_points(price, tax_withholding.withholding_basis_points)
+        }
+        None => Ok(0),
+    }
+}
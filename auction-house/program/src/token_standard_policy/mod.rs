@@ -0,0 +1,81 @@
+//! The house policy for which token standards may be listed, so a house specializing in one asset
+//! class rejects the others when they are listed rather than when they fail to settle.
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount, TokenStandard};
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction,
+};
+
+/// Accounts for the [`set_token_standard_policy` handler](auction_house/fn.set_token_standard_policy.html).
+#[derive(Accounts)]
+pub struct SetTokenStandardPolicy<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_token_standard_policy<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetTokenStandardPolicy<'info>>,
+    token_standard_policy: u8,
+) -> Result<()> {
+    if token_standard_policy & !ALL_TOKEN_STANDARDS != 0 {
+        return Err(AuctionHouseError::InvalidTokenStandardPolicy.into());
+    }
+
+    ctx.accounts.auction_house.token_standard_policy = token_standard_policy;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetTokenStandardPolicy,
+        token_standard_policy as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Fail unless the house's token standard policy allows listing the token described by
+/// `metadata`. An empty policy allows every token standard.
+pub fn assert_token_standard_allowed<'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    metadata: &AccountInfo<'info>,
+    seller_token_amount: u64,
+) -> Result<()> {
+    if auction_house.token_standard_policy == 0 {
+        return Ok(());
+    }
+
+    let token_standard = match Metadata::from_account_info(metadata)?.token_standard {
+        Some(TokenStandard::NonFungible) | Some(TokenStandard::NonFungibleEdition) => {
+            TOKEN_STANDARD_NFT
+        }
+        Some(TokenStandard::FungibleAsset) => TOKEN_STANDARD_SFT,
+        Some(TokenStandard::Fungible) => TOKEN_STANDARD_FUNGIBLE,
+        Some(TokenStandard::ProgrammableNonFungible) => TOKEN_STANDARD_PNFT,
+        // Metadata older than token standards only decorates NFTs and SFTs, and a seller can only
+        // hold more than one token of an SFT.
+        None if seller_token_amount > 1 => TOKEN_STANDARD_SFT,
+        None => TOKEN_STANDARD_NFT,
+    };
+
+    if auction_house.token_standard_policy & token_standard == 0 {
+        return Err(AuctionHouseError::TokenStandardNotAllowed.into());
+    }
+
+    Ok(())
+}
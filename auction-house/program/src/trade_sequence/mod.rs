@@ -0,0 +1,108 @@
+//! House-wide sequence numbers for placed listings and bids, so aggregators routing a sweep have
+//! an on-chain tiebreaker for which of two equally priced orders came first.
+use anchor_lang::prelude::*;
+use std::slice::Iter;
+
+use crate::{constants::*, errors::AuctionHouseError, utils::*, AuctionHouse, TradeSequence};
+
+/// Accounts for the [`create_trade_sequence` handler](auction_house/fn.create_trade_sequence.html).
+#[derive(Accounts)]
+pub struct CreateTradeSequence<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The trade sequence PDA numbering the house's listings and bids.
+    #[account(
+        init,
+        payer=authority,
+        space=TRADE_SEQUENCE_SIZE,
+        seeds=[TRADE_SEQUENCE.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub trade_sequence: Box<Account<'info, TradeSequence>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_trade_sequence<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateTradeSequence<'info>>,
+) -> Result<()> {
+    let trade_sequence = &mut ctx.accounts.trade_sequence;
+    trade_sequence.auction_house = ctx.accounts.auction_house.key();
+    trade_sequence.last_sequence = 0;
+    trade_sequence.last_trade_state = Pubkey::default();
+    trade_sequence.bump = *ctx
+        .bumps
+        .get("trade_sequence")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.auction_house.has_trade_sequence = true;
+
+    Ok(())
+}
+
+/// Number `trade_state` as the house's next listing or bid if it is being placed, rather than an
+/// existing order being resent. Called before the trade state is created.
+///
+/// Once a house has created its trade sequence, every listing and bid instruction must pass the
+/// writable trade sequence PDA as its first remaining account. Houses without one skip numbering.
+pub fn record_trade_sequence<'a, 'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    trade_state: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<()> {
+    if !auction_house.has_trade_sequence {
+        return Ok(());
+    }
+
+    let trade_sequence_info = next_account_info(remaining_accounts)?;
+    let mut trade_sequence = Account::<TradeSequence>::try_from(trade_sequence_info)?;
+    assert_keys_equal(trade_sequence.auction_house, auction_house.key())?;
+
+    if !trade_state.data_is_empty() {
+        return Ok(());
+    }
+
+    trade_sequence.last_sequence = trade_sequence
+        .last_sequence
+        .checked_add(1)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    trade_sequence.last_trade_state = trade_state.key();
+
+    trade_sequence.exit(&crate::id())
+}
+
+/// Take the trade sequence from `remaining_accounts`, returning the sequence number of
+/// `trade_state` when it is the order most recently placed on the house. Returns `None`, leaving
+/// the accounts untouched, when the next account is not a trade sequence.
+pub fn placed_trade_sequence<'a, 'info>(
+    auction_house: &Pubkey,
+    trade_state: &Pubkey,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<u64>> {
+    let trade_sequence = match next_program_account::<TradeSequence>(remaining_accounts)? {
+        Some(trade_sequence) => trade_sequence,
+        None => return Ok(None),
+    };
+    assert_keys_equal(trade_sequence.auction_house, *auction_house)?;
+
+    if trade_sequence.last_trade_state != *trade_state {
+        return Ok(None);
+    }
+
+    Ok(Some(trade_sequence.last_sequence))
+}
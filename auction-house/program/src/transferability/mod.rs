@@ -0,0 +1,53 @@
+//! Checks run at listing time that the asset can actually be transferred when the sale settles.
+use anchor_lang::prelude::*;
+use mpl_token_auth_rules::{
+    state::{RuleSetV1, RULE_SET_LIB_VERSION},
+    utils::get_existing_revision_map,
+};
+
+use crate::errors::AuctionHouseError;
+
+/// Operation Token Metadata authorizes when the sale delegate transfers a programmable NFT.
+const SALE_DELEGATE_TRANSFER_OPERATION: &str = "Transfer:SaleDelegate";
+/// Namespace Token Metadata falls back to when a rule set has no rule for the full operation.
+const TRANSFER_OPERATION_NAMESPACE: &str = "Transfer";
+
+/// Fail when the latest revision of a programmable NFT's rule set has no rule for sale delegate
+/// transfers. Token Metadata rejects transfers for operations a rule set does not define, so such
+/// a listing could be created but never settled.
+///
+/// Programmable NFTs without a rule set pass a placeholder that is not owned by the rule set
+/// program, and rule sets in a format this program cannot read are left to the delegate CPI.
+pub fn assert_rule_set_allows_sale_transfer(auth_rules: &AccountInfo) -> Result<()> {
+    if auth_rules.owner != &mpl_token_auth_rules::ID || auth_rules.data_is_empty() {
+        return Ok(());
+    }
+
+    let (revision_map, revision_map_location) = get_existing_revision_map(auth_rules)?;
+    let start = match revision_map.rule_set_revisions.last() {
+        Some(start) => *start,
+        None => return Ok(()),
+    };
+
+    let data = auth_rules.try_borrow_data()?;
+    if start >= revision_map_location || data[start] != RULE_SET_LIB_VERSION {
+        return Ok(());
+    }
+
+    let rule_set: RuleSetV1 = match rmp_serde::from_slice(&data[start + 1..revision_map_location]) {
+        Ok(rule_set) => rule_set,
+        Err(_) => return Ok(()),
+    };
+
+    if !rule_set
+        .operations
+        .contains_key(SALE_DELEGATE_TRANSFER_OPERATION)
+        && !rule_set
+            .operations
+            .contains_key(TRANSFER_OPERATION_NAMESPACE)
+    {
+        return Err(AuctionHouseError::TransferBlockedByRuleSet.into());
+    }
+
+    Ok(())
+}
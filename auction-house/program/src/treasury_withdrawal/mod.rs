@@ -0,0 +1,298 @@
+//! Treasury withdrawals by a delegated auctioneer. With the `WithdrawTreasury` scope delegated and
+//! a withdrawal limit created, the auctioneer authority can pay out of the treasury up to the
+//! house's limit per period, so routine payouts don't need the top-level authority key.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::{Mint, Token};
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, math::*,
+    utils::*, AuctionHouse, Auctioneer, AuthorityAction, AuthorityScope, TreasuryWithdrawalLimit,
+};
+
+/// Accounts for the [`create_treasury_withdrawal_limit` handler](auction_house/fn.create_treasury_withdrawal_limit.html).
+#[derive(Accounts)]
+pub struct CreateTreasuryWithdrawalLimit<'info> {
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The treasury withdrawal limit PDA for the house.
+    #[account(
+        init,
+        payer=authority,
+        space=TREASURY_WITHDRAWAL_LIMIT_SIZE,
+        seeds=[TREASURY_WITHDRAWAL_LIMIT.as_bytes(), auction_house.key().as_ref()],
+        bump
+    )]
+    pub treasury_withdrawal_limit: Box<Account<'info, TreasuryWithdrawalLimit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`update_treasury_withdrawal_limit` handler](auction_house/fn.update_treasury_withdrawal_limit.html).
+#[derive(Accounts)]
+pub struct UpdateTreasuryWithdrawalLimit<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The treasury withdrawal limit PDA for the house.
+    #[account(
+        mut,
+        seeds=[TREASURY_WITHDRAWAL_LIMIT.as_bytes(), auction_house.key().as_ref()],
+        bump=treasury_withdrawal_limit.bump,
+        has_one=auction_house
+    )]
+    pub treasury_withdrawal_limit: Box<Account<'info, TreasuryWithdrawalLimit>>,
+}
+
+/// Accounts for the [`auctioneer_withdraw_from_treasury` handler](auction_house/fn.auctioneer_withdraw_from_treasury.html).
+#[derive(Accounts)]
+pub struct AuctioneerWithdrawFromTreasury<'info> {
+    /// Treasury mint account, either native SOL mint or a SPL token mint.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    pub auctioneer_authority: Signer<'info>,
+
+    /// CHECK: The auctioneer chooses the payee, bounded by the withdrawal limit.
+    /// SOL or SPL token account to receive the withdrawal.
+    #[account(mut)]
+    pub treasury_withdrawal_destination: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House treasury PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint,
+        has_one=auction_house_treasury
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ],
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// The treasury withdrawal limit PDA for the house.
+    #[account(
+        mut,
+        seeds=[TREASURY_WITHDRAWAL_LIMIT.as_bytes(), auction_house.key().as_ref()],
+        bump=treasury_withdrawal_limit.bump,
+        has_one=auction_house
+    )]
+    pub treasury_withdrawal_limit: Box<Account<'info, TreasuryWithdrawalLimit>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Check a withdrawal period is positive and at most `MAX_TREASURY_WITHDRAWAL_PERIOD_SECONDS`, so
+/// the end of a period always fits in a timestamp.
+fn assert_valid_period(period_seconds: i64) -> Result<()> {
+    if period_seconds <= 0 || period_seconds > MAX_TREASURY_WITHDRAWAL_PERIOD_SECONDS {
+        return err!(AuctionHouseError::InvalidTreasuryWithdrawalPeriod);
+    }
+
+    Ok(())
+}
+
+pub fn create_treasury_withdrawal_limit<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateTreasuryWithdrawalLimit<'info>>,
+    max_per_period: u64,
+    period_seconds: i64,
+) -> Result<()> {
+    assert_valid_period(period_seconds)?;
+
+    let treasury_withdrawal_limit = &mut ctx.accounts.treasury_withdrawal_limit;
+    treasury_withdrawal_limit.auction_house = ctx.accounts.auction_house.key();
+    treasury_withdrawal_limit.max_per_period = max_per_period;
+    treasury_withdrawal_limit.period_seconds = period_seconds;
+    treasury_withdrawal_limit.period_start = 0;
+    treasury_withdrawal_limit.withdrawn_in_period = 0;
+    treasury_withdrawal_limit.bump = *ctx
+        .bumps
+        .get("treasury_withdrawal_limit")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetTreasuryWithdrawalLimit,
+        max_per_period,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+pub fn update_treasury_withdrawal_limit<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateTreasuryWithdrawalLimit<'info>>,
+    max_per_period: u64,
+    period_seconds: i64,
+) -> Result<()> {
+    assert_valid_period(period_seconds)?;
+
+    let treasury_withdrawal_limit = &mut ctx.accounts.treasury_withdrawal_limit;
+    treasury_withdrawal_limit.max_per_period = max_per_period;
+    treasury_withdrawal_limit.period_seconds = period_seconds;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetTreasuryWithdrawalLimit,
+        max_per_period,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Withdraw `amount` from the treasury as the house's auctioneer, counting it against the
+/// withdrawal limit for the current period.
+pub fn auctioneer_withdraw_from_treasury<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerWithdrawFromTreasury<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let treasury_withdrawal_destination = &ctx.accounts.treasury_withdrawal_destination;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let auction_house = &ctx.accounts.auction_house;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    if !auction_house.has_auctioneer {
+        return Err(AuctionHouseError::NoAuctioneerProgramSet.into());
+    }
+
+    assert_valid_auctioneer_and_scope(
+        auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        &ctx.accounts.ah_auctioneer_pda,
+        AuthorityScope::WithdrawTreasury,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let treasury_withdrawal_limit = &mut ctx.accounts.treasury_withdrawal_limit;
+    let period_end = treasury_withdrawal_limit
+        .period_start
+        .checked_add(treasury_withdrawal_limit.period_seconds)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    if now >= period_end {
+        treasury_withdrawal_limit.period_start = now;
+        treasury_withdrawal_limit.withdrawn_in_period = 0;
+    }
+    let withdrawn_in_period = checked_add(treasury_withdrawal_limit.withdrawn_in_period, amount)?;
+    if withdrawn_in_period > treasury_withdrawal_limit.max_per_period {
+        return err!(AuctionHouseError::TreasuryWithdrawalLimitExceeded);
+    }
+    treasury_withdrawal_limit.withdrawn_in_period = withdrawn_in_period;
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+    let auction_house_seeds = [
+        PREFIX.as_bytes(),
+        auction_house.creator.as_ref(),
+        auction_house.treasury_mint.as_ref(),
+        &[auction_house.bump],
+    ];
+
+    let ah_key = auction_house.key();
+    let auction_house_treasury_seeds = [
+        PREFIX.as_bytes(),
+        ah_key.as_ref(),
+        TREASURY.as_bytes(),
+        &[auction_house.treasury_bump],
+    ];
+    if !is_native {
+        invoke_signed(
+            &spl_token::instruction::transfer(
+                token_program.key,
+                &auction_house_treasury.key(),
+                &treasury_withdrawal_destination.key(),
+                &auction_house.key(),
+                &[],
+                amount,
+            )?,
+            &[
+                auction_house_treasury.to_account_info(),
+                treasury_withdrawal_destination.to_account_info(),
+                token_program.to_account_info(),
+                auction_house.to_account_info(),
+            ],
+            &[&auction_house_seeds],
+        )?;
+    } else {
+        invoke_signed(
+            &system_instruction::transfer(
+                &auction_house_treasury.key(),
+                &treasury_withdrawal_destination.key(),
+                amount,
+            ),
+            &[
+                auction_house_treasury.to_account_info(),
+                treasury_withdrawal_destination.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[&auction_house_treasury_seeds],
+        )?;
+    }
+
+    record_authority_action(
+        auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        AuthorityAction::WithdrawFromTreasury,
+        amount,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
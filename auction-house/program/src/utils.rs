@@ -1,5 +1,6 @@
 use crate::{
-    constants::*, errors::AuctionHouseError, AuctionHouse, Auctioneer, AuthorityScope, PREFIX,
+    constants::*, errors::AuctionHouseError, frozen_proceeds::transfer_or_hold_proceeds, math::*,
+    AuctionHouse, Auctioneer, AuthorityScope, PREFIX,
 };
 
 use anchor_lang::{
@@ -12,6 +13,7 @@ use anchor_lang::{
         pubkey::PUBKEY_BYTES,
         system_instruction,
     },
+    Discriminator,
 };
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use arrayref::array_ref;
@@ -196,6 +198,8 @@ pub enum ListingType {
 pub enum PurchaseType {
     ExecuteSale,
     AuctioneerExecuteSale,
+    ExecutePartialSale,
+    AuctioneerExecutePartialSale,
 }
 
 #[derive(Debug, Clone)]
@@ -208,6 +212,7 @@ pub fn assert_program_bid_instruction(sighash: &[u8]) -> Result<BidType> {
     match sighash {
         [169, 84, 218, 35, 42, 206, 16, 171] => Ok(BidType::PublicSale),
         [102, 6, 61, 18, 1, 218, 235, 234] => Ok(BidType::PrivateSale),
+        [71, 223, 29, 13, 201, 150, 235, 212] => Ok(BidType::PrivateSale),
         [221, 239, 99, 240, 86, 46, 213, 126] => Ok(BidType::AuctioneerPublicSale),
         [17, 106, 133, 46, 229, 48, 45, 208] => Ok(BidType::AuctioneerPrivateSale),
         _ => Err(AuctionHouseError::InstructionMismatch.into()),
@@ -226,6 +231,8 @@ pub fn assert_program_purchase_instruction(sighash: &[u8]) -> Result<PurchaseTyp
     match sighash {
         [37, 74, 217, 157, 79, 49, 35, 6] => Ok(PurchaseType::ExecuteSale),
         [68, 125, 32, 65, 251, 43, 35, 53] => Ok(PurchaseType::AuctioneerExecuteSale),
+        [163, 18, 35, 157, 49, 164, 203, 133] => Ok(PurchaseType::ExecutePartialSale),
+        [9, 44, 46, 15, 161, 143, 21, 54] => Ok(PurchaseType::AuctioneerExecutePartialSale),
         _ => Err(AuctionHouseError::InstructionMismatch.into()),
     }
 }
@@ -271,15 +278,17 @@ pub fn pay_auction_house_fees<'a>(
     token_program: &AccountInfo<'a>,
     system_program: &AccountInfo<'a>,
     signer_seeds: &[&[u8]],
+    seller_fee_basis_points: u16,
     size: u64,
+    after_royalties: u64,
     is_native: bool,
 ) -> Result<u64> {
-    let fees = auction_house.seller_fee_basis_points;
-    let total_fee = (fees as u128)
-        .checked_mul(size as u128)
-        .ok_or(AuctionHouseError::NumericalOverflow)?
-        .checked_div(10000)
-        .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
+    let total_fee = house_fee(
+        size,
+        seller_fee_basis_points,
+        auction_house.rounding_policy.fee_rounding(),
+        after_royalties,
+    )?;
     if !is_native {
         invoke_signed(
             &spl_token::instruction::transfer(
@@ -362,6 +371,22 @@ pub fn create_program_token_account_if_not_present<'a>(
     Ok(())
 }
 
+/// A creator's share of the royalties paid on a sale.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoyaltyPayment {
+    pub creator: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted by settlements that pay royalties, listing what each creator received so royalties can
+/// be tracked from logs without decoding the remaining accounts.
+#[event]
+pub struct RoyaltiesPaid {
+    pub mint: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub payments: Vec<RoyaltyPayment>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn pay_creator_fees<'a>(
     remaining_accounts: &mut Iter<AccountInfo<'a>>,
@@ -377,34 +402,35 @@ pub fn pay_creator_fees<'a>(
     signer_seeds: &[&[u8]],
     fee_payer_seeds: &[&[u8]],
     size: u64,
+    bonus_basis_points: u16,
+    rounding: Rounding,
     is_native: bool,
 ) -> Result<u64> {
     let metadata = Metadata::from_account_info(metadata_info)?;
-    let fees = metadata.data.seller_fee_basis_points;
-    let total_fee = (fees as u128)
-        .checked_mul(size as u128)
-        .ok_or(AuctionHouseError::NumericalOverflow)?
-        .checked_div(10000)
-        .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
-    let mut remaining_fee = total_fee;
-    let remaining_size = size
-        .checked_sub(total_fee)
+    // A seller's royalty bonus is split between the creators like the royalties themselves.
+    let royalty_basis_points = metadata
+        .data
+        .seller_fee_basis_points
+        .checked_add(bonus_basis_points)
         .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let (total_fee, remaining_size) =
+        split_basis_points_rounded(size, royalty_basis_points, rounding)?;
+    let mut remaining_fee = total_fee;
+    let mut payments = Vec::new();
     match metadata.data.creators {
         Some(creators) => {
             for creator in creators {
-                let pct = creator.share as u128;
+                // Shares rounded up can add up to more than the royalty, so the last creators
+                // are paid at most what is left of it.
                 let creator_fee =
-                    pct.checked_mul(total_fee as u128)
-                        .ok_or(AuctionHouseError::NumericalOverflow)?
-                        .checked_div(100)
-                        .ok_or(AuctionHouseError::NumericalOverflow)? as u64;
-                let current_creator_info = next_account_info(remaining_accounts)?;
+                    creator_share_rounded(total_fee, creator.share, rounding)?.min(remaining_fee);
+                let current_creator_info =
+                    next_creator_account(remaining_accounts, &creator.address)?;
                 let creator_rent_minimum =
                     Rent::get()?.minimum_balance(current_creator_info.data.borrow().len());
                 if is_native
-                    && ((creator_fee + **current_creator_info.lamports.borrow())
-                        < creator_rent_minimum)
+                    && checked_add(creator_fee, current_creator_info.lamports())?
+                        < creator_rent_minimum
                 {
                     msg!(
                         "cannot pay creator {} {} lamports since balance violates rent exempt minimum",
@@ -414,10 +440,7 @@ pub fn pay_creator_fees<'a>(
                     continue;
                 }
 
-                remaining_fee = remaining_fee
-                    .checked_sub(creator_fee)
-                    .ok_or(AuctionHouseError::NumericalOverflow)?;
-                assert_keys_equal(creator.address, *current_creator_info.key)?;
+                remaining_fee = checked_sub(remaining_fee, creator_fee)?;
                 if !is_native {
                     let current_creator_token_account_info = next_account_info(remaining_accounts)?;
                     if current_creator_token_account_info.data_is_empty() {
@@ -439,22 +462,19 @@ pub fn pay_creator_fees<'a>(
                         &treasury_mint.key(),
                     )?;
                     if creator_fee > 0 {
-                        invoke_signed(
-                            &spl_token::instruction::transfer(
-                                token_program.key,
-                                escrow_payment_account.key,
-                                current_creator_token_account_info.key,
-                                payment_account_owner.key,
-                                &[],
-                                creator_fee,
-                            )?,
-                            &[
-                                escrow_payment_account.clone(),
-                                current_creator_token_account_info.clone(),
-                                token_program.clone(),
-                                payment_account_owner.clone(),
-                            ],
-                            &[signer_seeds],
+                        transfer_or_hold_proceeds(
+                            remaining_accounts,
+                            payment_account_owner,
+                            escrow_payment_account,
+                            current_creator_info.key,
+                            current_creator_token_account_info,
+                            fee_payer,
+                            token_program,
+                            system_program,
+                            rent,
+                            signer_seeds,
+                            fee_payer_seeds,
+                            creator_fee,
                         )?;
                     }
                 } else if creator_fee > 0 {
@@ -472,16 +492,67 @@ pub fn pay_creator_fees<'a>(
                         &[signer_seeds],
                     )?;
                 }
+
+                if creator_fee > 0 {
+                    payments.push(RoyaltyPayment {
+                        creator: creator.address,
+                        amount: creator_fee,
+                    });
+                }
             }
         }
         None => {
             msg!("No creators found in metadata");
         }
     }
+
+    if !payments.is_empty() {
+        emit!(RoyaltiesPaid {
+            mint: metadata.mint,
+            treasury_mint: treasury_mint.key(),
+            payments,
+        });
+    }
     // Any dust is returned to the party posting the NFT
-    Ok(remaining_size
-        .checked_add(remaining_fee)
-        .ok_or(AuctionHouseError::NumericalOverflow)?)
+    checked_add(remaining_size, remaining_fee)
+}
+
+/// Consume the next remaining account, which must be the wallet of `creator`. Logs the expected
+/// creator so integrators can see which account is missing or out of order.
+fn next_creator_account<'a, 'info>(
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+    creator: &Pubkey,
+) -> Result<&'a AccountInfo<'info>> {
+    match remaining_accounts.next() {
+        Some(creator_info) if creator_info.key == creator => Ok(creator_info),
+        _ => {
+            msg!("expected creator {} as the next remaining account", creator);
+            err!(AuctionHouseError::InvalidCreatorAccount)
+        }
+    }
+}
+
+/// Consume the next remaining account if it is a `T` account owned by this program, otherwise
+/// leave the iterator untouched so that optional accounts can be omitted by the caller.
+pub fn next_program_account<'a, 'info, T>(
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<Account<'info, T>>>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Discriminator + Clone,
+{
+    let account_info = match remaining_accounts.clone().next() {
+        Some(info)
+            if info.owner == &crate::id()
+                && info.data_len() >= 8
+                && info.try_borrow_data()?[..8] == T::discriminator() =>
+        {
+            info
+        }
+        _ => return Ok(None),
+    };
+    remaining_accounts.next();
+
+    Ok(Some(Account::try_from(account_info)?))
 }
 
 /// Cheap method to just grab mint Pubkey from token account, instead of deserializing entire thing
@@ -658,18 +729,76 @@ pub fn verify_deposit(account: AccountInfo, amount: u64) -> Result<u64> {
     Ok(rent_minimum.saturating_sub(total))
 }
 
+// Returns how much of `amount` can be withdrawn from a native escrow payment account. The amount
+// is reduced so the escrow stays rent exempt. Escrows funded before rent exemption was enforced
+// can hold less than the rent exempt minimum; that dust can never be left behind, so the whole
+// balance is swept and the escrow is emptied.
+pub fn native_escrow_withdrawal_amount(escrow: AccountInfo, amount: u64) -> Result<u64> {
+    let rent_minimum = (Rent::get()?).minimum_balance(escrow.data_len());
+    if amount > 0 && escrow.lamports() < rent_minimum {
+        return Ok(escrow.lamports());
+    }
+
+    let rent_shortfall = verify_withdrawal(escrow, amount)?;
+    let checked_amount = amount
+        .checked_sub(rent_shortfall)
+        .ok_or(AuctionHouseError::InsufficientFunds)?;
+
+    Ok(checked_amount)
+}
+
+// Tops up a native escrow payment account from the Auction House fee account so that `amount`
+// can be paid out of it while keeping it rent exempt. This settles the dust left in escrows
+// funded before rent exemption was enforced; the top up is never more than the rent exempt
+// minimum.
+pub fn top_up_escrow_rent_shortfall<'a>(
+    escrow: &AccountInfo<'a>,
+    fee_payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    fee_payer_seeds: &[&[u8]],
+    amount: u64,
+) -> Result<()> {
+    let rent_shortfall = verify_withdrawal(escrow.clone(), amount)?;
+    if rent_shortfall > 0 {
+        invoke_signed(
+            &system_instruction::transfer(fee_payer.key, escrow.key, rent_shortfall),
+            &[fee_payer.clone(), escrow.clone(), system_program.clone()],
+            &[fee_payer_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
+// Asserts that a native escrow payment account is either empty or rent exempt after funds were
+// moved out of it.
+pub fn assert_escrow_rent_exempt(escrow: &AccountInfo) -> Result<()> {
+    let rent_minimum = (Rent::get()?).minimum_balance(escrow.data_len());
+    if escrow.lamports() > 0 && escrow.lamports() < rent_minimum {
+        return Err(AuctionHouseError::EscrowNotRentExempt.into());
+    }
+
+    Ok(())
+}
+
 pub fn assert_valid_auctioneer_and_scope(
     auction_house_instance: &Account<AuctionHouse>,
     auctioneer_authority: &Pubkey,
     auctioneer_pda: &Account<Auctioneer>,
     scope: AuthorityScope,
 ) -> Result<()> {
-    // Assert the Auctioneer is tagged on the auction house
-    assert_keys_equal(
-        auction_house_instance.auctioneer_address,
-        auctioneer_pda.key(),
-    )
-    .map_err(|_e| AuctionHouseError::InvalidAuctioneer)?;
+    // Assert the Auctioneer is tagged on the auction house, or was rotated out less than its
+    // grace period ago.
+    let is_previous_in_grace_period = auction_house_instance.previous_auctioneer_address
+        == auctioneer_pda.key()
+        && Clock::get()?.unix_timestamp < auction_house_instance.previous_auctioneer_expires_at;
+    if !is_previous_in_grace_period {
+        assert_keys_equal(
+            auction_house_instance.auctioneer_address,
+            auctioneer_pda.key(),
+        )
+        .map_err(|_e| AuctionHouseError::InvalidAuctioneer)?;
+    }
     // Assert the auctioneer_authority is tagged in the Auctioneer
     assert_keys_equal(
         auctioneer_pda.auctioneer_authority,
@@ -680,6 +809,13 @@ pub fn assert_valid_auctioneer_and_scope(
     assert_keys_equal(auctioneer_pda.auction_house, auction_house_instance.key())
         .map_err(|_e| AuctionHouseError::InvalidAuctioneer)?;
 
+    // Houses with an allowlist record the approved program when the auctioneer is delegated.
+    if auction_house_instance.has_auctioneer_allowlist
+        && auctioneer_pda.auctioneer_program == Pubkey::default()
+    {
+        return Err(AuctionHouseError::AuctioneerProgramNotAllowed.into());
+    }
+
     if !(auction_house_instance.scopes[scope as usize]) {
         return Err(AuctionHouseError::MissingAuctioneerScope.into());
     }
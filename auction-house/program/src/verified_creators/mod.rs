@@ -0,0 +1,71 @@
+//! The house setting for curated marketplaces that only list tokens whose creators and collection
+//! are verified, so a spoofed copy of an "official" item cannot be listed.
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, AuctionHouse,
+    AuthorityAction,
+};
+
+/// Accounts for the [`set_requires_verified_creators` handler](auction_house/fn.set_requires_verified_creators.html).
+#[derive(Accounts)]
+pub struct SetRequiresVerifiedCreators<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_requires_verified_creators<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetRequiresVerifiedCreators<'info>>,
+    requires_verified_creators: bool,
+) -> Result<()> {
+    ctx.accounts.auction_house.requires_verified_creators = requires_verified_creators;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetRequiresVerifiedCreators,
+        requires_verified_creators as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Fail if the Auction House requires verified creators and any creator or the collection of the
+/// token described by `metadata` is unverified.
+pub fn assert_creators_verified<'info>(
+    auction_house: &Account<'info, AuctionHouse>,
+    metadata: &AccountInfo<'info>,
+) -> Result<()> {
+    if !auction_house.requires_verified_creators {
+        return Ok(());
+    }
+
+    let metadata = Metadata::from_account_info(metadata)?;
+    let creators_verified = metadata.data.creators.map_or(true, |creators| {
+        creators.iter().all(|creator| creator.verified)
+    });
+    let collection_verified = metadata
+        .collection
+        .map_or(true, |collection| collection.verified);
+
+    if !creators_verified || !collection_verified {
+        return Err(AuctionHouseError::UnverifiedCreators.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,344 @@
+//! Presale vouchers for tokens that are not minted yet. A creator lists a voucher at a price, a
+//! buyer pays for it into the voucher PDA, and the payment stays there until the creator proves
+//! delivery by redeeming the voucher against a token they minted to the buyer. Vouchers not
+//! redeemed by their deadline can be reclaimed by the buyer.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{Mint, TokenAccount};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use crate::{
+    constants::*, errors::AuctionHouseError, math::apply_basis_points_rounded, utils::*,
+    AuctionHouse, Voucher,
+};
+
+/// Accounts for the [`create_voucher` handler](auction_house/fn.create_voucher.html).
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateVoucher<'info> {
+    /// Creator wallet account listing the voucher.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The voucher PDA for the creator and id.
+    #[account(
+        init,
+        payer=creator,
+        space=VOUCHER_SIZE,
+        seeds=[
+            VOUCHER.as_bytes(),
+            auction_house.key().as_ref(),
+            creator.key().as_ref(),
+            &id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`cancel_voucher` handler](auction_house/fn.cancel_voucher.html).
+#[derive(Accounts)]
+pub struct CancelVoucher<'info> {
+    /// Creator wallet account that listed the voucher.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The unsold voucher PDA being withdrawn.
+    #[account(
+        mut,
+        seeds=[
+            VOUCHER.as_bytes(),
+            voucher.auction_house.as_ref(),
+            creator.key().as_ref(),
+            &voucher.id.to_le_bytes()
+        ],
+        bump=voucher.bump,
+        has_one=creator,
+        close=creator
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+}
+
+/// Accounts for the [`buy_voucher` handler](auction_house/fn.buy_voucher.html).
+#[derive(Accounts)]
+pub struct BuyVoucher<'info> {
+    /// User wallet account paying for the voucher.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The voucher PDA being bought, holding the payment until it is redeemed or reclaimed.
+    #[account(
+        mut,
+        seeds=[
+            VOUCHER.as_bytes(),
+            auction_house.key().as_ref(),
+            voucher.creator.as_ref(),
+            &voucher.id.to_le_bytes()
+        ],
+        bump=voucher.bump,
+        has_one=auction_house
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`redeem_voucher` handler](auction_house/fn.redeem_voucher.html).
+#[derive(Accounts)]
+pub struct RedeemVoucher<'info> {
+    /// Creator wallet account that listed the voucher, paid the voucher price less the house fee.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: Validated by the voucher's has_one constraint.
+    /// Buyer user wallet account.
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Buyer token account holding the delivered token.
+    #[account(token::mint=token_mint, token::authority=buyer)]
+    pub buyer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Mint of the delivered token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Validated in redeem_voucher.
+    /// Metaplex metadata account decorating the token mint.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=auction_house_treasury
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// The voucher PDA being redeemed.
+    #[account(
+        mut,
+        seeds=[
+            VOUCHER.as_bytes(),
+            auction_house.key().as_ref(),
+            creator.key().as_ref(),
+            &voucher.id.to_le_bytes()
+        ],
+        bump=voucher.bump,
+        has_one=auction_house,
+        has_one=creator,
+        has_one=buyer,
+        close=creator
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+}
+
+/// Accounts for the [`reclaim_voucher` handler](auction_house/fn.reclaim_voucher.html).
+#[derive(Accounts)]
+pub struct ReclaimVoucher<'info> {
+    /// User wallet account that bought the voucher, refunded the voucher price.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Validated by the voucher's has_one constraint.
+    /// Creator wallet account that listed the voucher, refunded the voucher's rent.
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// The expired voucher PDA being reclaimed.
+    #[account(
+        mut,
+        seeds=[
+            VOUCHER.as_bytes(),
+            voucher.auction_house.as_ref(),
+            creator.key().as_ref(),
+            &voucher.id.to_le_bytes()
+        ],
+        bump=voucher.bump,
+        has_one=creator,
+        has_one=buyer,
+        close=creator
+    )]
+    pub voucher: Box<Account<'info, Voucher>>,
+}
+
+pub fn create_voucher<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateVoucher<'info>>,
+    id: u64,
+    price: u64,
+    redeem_deadline: i64,
+) -> Result<()> {
+    if ctx.accounts.auction_house.treasury_mint != spl_token::native_mint::id() {
+        return Err(AuctionHouseError::VoucherRequiresNativeMint.into());
+    }
+    if price == 0 || redeem_deadline <= Clock::get()?.unix_timestamp {
+        return Err(AuctionHouseError::InvalidVoucher.into());
+    }
+
+    let voucher = &mut ctx.accounts.voucher;
+    voucher.auction_house = ctx.accounts.auction_house.key();
+    voucher.creator = ctx.accounts.creator.key();
+    voucher.id = id;
+    voucher.price = price;
+    voucher.redeem_deadline = redeem_deadline;
+    voucher.buyer = Pubkey::default();
+    voucher.bump = *ctx
+        .bumps
+        .get("voucher")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+pub fn cancel_voucher<'info>(ctx: Context<'_, '_, '_, 'info, CancelVoucher<'info>>) -> Result<()> {
+    if ctx.accounts.voucher.buyer != Pubkey::default() {
+        return Err(AuctionHouseError::VoucherAlreadySold.into());
+    }
+
+    Ok(())
+}
+
+pub fn buy_voucher<'info>(ctx: Context<'_, '_, '_, 'info, BuyVoucher<'info>>) -> Result<()> {
+    let buyer = &ctx.accounts.buyer;
+    let voucher = &mut ctx.accounts.voucher;
+
+    if voucher.buyer != Pubkey::default() {
+        return Err(AuctionHouseError::VoucherAlreadySold.into());
+    }
+    if Clock::get()?.unix_timestamp > voucher.redeem_deadline {
+        return Err(AuctionHouseError::VoucherRedeemDeadlinePassed.into());
+    }
+
+    invoke(
+        &system_instruction::transfer(&buyer.key(), &voucher.key(), voucher.price),
+        &[
+            buyer.to_account_info(),
+            voucher.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    voucher.buyer = buyer.key();
+
+    Ok(())
+}
+
+pub fn redeem_voucher<'info>(ctx: Context<'_, '_, '_, 'info, RedeemVoucher<'info>>) -> Result<()> {
+    let voucher = &ctx.accounts.voucher;
+    let auction_house = &ctx.accounts.auction_house;
+    let metadata = &ctx.accounts.metadata;
+
+    if voucher.buyer == Pubkey::default() {
+        return Err(AuctionHouseError::VoucherNotSold.into());
+    }
+    if Clock::get()?.unix_timestamp > voucher.redeem_deadline {
+        return Err(AuctionHouseError::VoucherRedeemDeadlinePassed.into());
+    }
+
+    assert_metadata_valid(metadata, &ctx.accounts.buyer_token_account)?;
+    let token_metadata = Metadata::from_account_info(&metadata.to_account_info())?;
+    let minted_by_creator = token_metadata
+        .data
+        .creators
+        .iter()
+        .flatten()
+        .any(|creator| creator.verified && creator.address == voucher.creator);
+    if !minted_by_creator || ctx.accounts.buyer_token_account.amount == 0 {
+        return Err(AuctionHouseError::VoucherNotDelivered.into());
+    }
+
+    // The house fee goes to the treasury; closing the voucher pays the creator the rest of the
+    // price along with the voucher's rent.
+    let fee = apply_basis_points_rounded(
+        voucher.price,
+        auction_house.seller_fee_basis_points,
+        auction_house.rounding_policy.fee_rounding(),
+    )?;
+    pay_from_voucher(
+        &voucher.to_account_info(),
+        &ctx.accounts.auction_house_treasury,
+        fee,
+    )?;
+
+    Ok(())
+}
+
+pub fn reclaim_voucher<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimVoucher<'info>>,
+) -> Result<()> {
+    let voucher = &ctx.accounts.voucher;
+
+    if Clock::get()?.unix_timestamp <= voucher.redeem_deadline {
+        return Err(AuctionHouseError::VoucherRedeemDeadlineNotPassed.into());
+    }
+
+    pay_from_voucher(
+        &voucher.to_account_info(),
+        &ctx.accounts.buyer,
+        voucher.price,
+    )?;
+
+    Ok(())
+}
+
+/// Pay `amount` of the payment held by the voucher to `destination`.
+fn pay_from_voucher<'info>(
+    voucher: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let voucher_lamports = voucher
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+    let destination_lamports = destination
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionHouseError::NumericalOverflow)?;
+
+    **voucher.try_borrow_mut_lamports()? = voucher_lamports;
+    **destination.try_borrow_mut_lamports()? = destination_lamports;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! Wallet-bound buyer receipt accounts, for custodial wallets that cannot hold tokens in
+//! associated token accounts. When a house allows them, any token account the buyer owns for the
+//! mint may receive a purchase; otherwise the buyer's associated token account is required.
+use anchor_lang::prelude::*;
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::state::Account as SplAccount;
+
+use crate::{
+    authority_log::record_authority_action, constants::*, errors::AuctionHouseError, utils::*,
+    AuctionHouse, AuthorityAction,
+};
+
+/// Accounts for the [`set_wallet_bound_receipts` handler](auction_house/fn.set_wallet_bound_receipts.html).
+#[derive(Accounts)]
+pub struct SetWalletBoundReceipts<'info> {
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=authority
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+pub fn set_wallet_bound_receipts<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetWalletBoundReceipts<'info>>,
+    allow_wallet_bound_receipts: bool,
+) -> Result<()> {
+    ctx.accounts.auction_house.allow_wallet_bound_receipts = allow_wallet_bound_receipts;
+
+    record_authority_action(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.authority.key(),
+        AuthorityAction::SetWalletBoundReceipts,
+        allow_wallet_bound_receipts as u64,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+
+    Ok(())
+}
+
+/// Check `receipt` is a token account for `mint` owned by `wallet`, and that it is the wallet's
+/// associated token account unless the Auction House allows wallet-bound receipts.
+pub fn assert_buyer_receipt_account(
+    auction_house: &AuctionHouse,
+    receipt: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<SplAccount> {
+    let receipt_account = assert_is_ata(receipt, wallet, mint)?;
+    if !auction_house.allow_wallet_bound_receipts
+        && get_associated_token_address(wallet, mint) != receipt.key()
+    {
+        return Err(AuctionHouseError::BuyerReceiptNotAssociatedTokenAccount.into());
+    }
+
+    Ok(receipt_account)
+}
@@ -0,0 +1,504 @@
+//! Buyer-initiated "wanted" listings. A buyer posts a public offer, paid from their escrow, for
+//! any token of a specific mint or verified collection, and sellers fill it directly.
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::{invoke, invoke_signed},
+        system_instruction,
+    },
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+use spl_token::state::Account as SplAccount;
+
+use crate::{
+    compliance::{assert_compliance, ComplianceAction},
+    constants::*,
+    errors::AuctionHouseError,
+    frozen_proceeds::transfer_or_hold_proceeds,
+    math::{checked_sub, descending_price},
+    relist_cooldown::{assert_relist_cooldown_elapsed, record_mint_settlement},
+    seller_financing::assert_no_outstanding_lien,
+    tax_withholding::withhold_tax,
+    utils::*,
+    wallet_receipt::assert_buyer_receipt_account,
+    AuctionHouse, AuthorityScope, WantedCriteria, WantedListing,
+};
+
+/// Accounts for the [`create_wanted_listing` handler](auction_house/fn.create_wanted_listing.html).
+#[derive(Accounts)]
+#[instruction(criteria: WantedCriteria)]
+pub struct CreateWantedListing<'info> {
+    /// User wallet account posting the offer.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account the offer is paid from.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The wanted listing PDA for the buyer and criteria.
+    #[account(
+        init,
+        payer=buyer,
+        space=WANTED_LISTING_SIZE,
+        seeds=[
+            WANTED_LISTING.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref(),
+            criteria.target().as_ref()
+        ],
+        bump
+    )]
+    pub wanted_listing: Box<Account<'info, WantedListing>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`cancel_wanted_listing` handler](auction_house/fn.cancel_wanted_listing.html).
+#[derive(Accounts)]
+pub struct CancelWantedListing<'info> {
+    /// User wallet account that posted the offer.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// The wanted listing PDA being withdrawn.
+    #[account(
+        mut,
+        seeds=[
+            WANTED_LISTING.as_bytes(),
+            wanted_listing.auction_house.as_ref(),
+            buyer.key().as_ref(),
+            wanted_listing.criteria.target().as_ref()
+        ],
+        bump=wanted_listing.bump,
+        has_one=buyer,
+        close=buyer
+    )]
+    pub wanted_listing: Box<Account<'info, WantedListing>>,
+}
+
+/// Accounts for the [`fill_wanted` handler](auction_house/fn.fill_wanted.html).
+#[derive(Accounts)]
+pub struct FillWanted<'info> {
+    /// User wallet account selling into the offer.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// Seller token account holding the token being sold.
+    #[account(mut, token::mint=token_mint, token::authority=seller)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account of the token being sold.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Validated in fill_wanted.
+    /// Metaplex metadata account decorating the token mint.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the wanted listing's has_one constraint.
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in fill_wanted.
+    /// Buyer SPL token account to receive the token at.
+    #[account(mut)]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account the offer is paid from.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in fill_wanted.
+    /// Seller SOL or SPL account to receive payment at.
+    #[account(mut)]
+    pub seller_payment_receipt_account: UncheckedAccount<'info>,
+
+    /// Auction House treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        bump=auction_house.bump,
+        has_one=treasury_mint,
+        has_one=auction_house_treasury
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        bump=auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// The wanted listing PDA being filled.
+    #[account(
+        mut,
+        seeds=[
+            WANTED_LISTING.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref(),
+            wanted_listing.criteria.target().as_ref()
+        ],
+        bump=wanted_listing.bump,
+        has_one=auction_house,
+        has_one=buyer,
+        close=buyer
+    )]
+    pub wanted_listing: Box<Account<'info, WantedListing>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_wanted_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateWantedListing<'info>>,
+    criteria: WantedCriteria,
+    start_price: u64,
+    floor_price: u64,
+    end_time: i64,
+) -> Result<()> {
+    let start_time = Clock::get()?.unix_timestamp;
+    if floor_price > start_price || end_time <= start_time {
+        return Err(AuctionHouseError::InvalidWantedListing.into());
+    }
+
+    // The offer is only posted once the escrow can pay its opening price.
+    let escrow_payment_account = ctx.accounts.escrow_payment_account.to_account_info();
+    if ctx.accounts.auction_house.treasury_mint == spl_token::native_mint::id() {
+        if verify_withdrawal(escrow_payment_account, start_price)? > 0 {
+            return Err(AuctionHouseError::InsufficientFunds.into());
+        }
+    } else {
+        let escrow: SplAccount = assert_initialized(&escrow_payment_account)?;
+        if escrow.amount < start_price {
+            return Err(AuctionHouseError::InsufficientFunds.into());
+        }
+    }
+
+    let wanted_listing = &mut ctx.accounts.wanted_listing;
+    wanted_listing.auction_house = ctx.accounts.auction_house.key();
+    wanted_listing.buyer = ctx.accounts.buyer.key();
+    wanted_listing.criteria = criteria;
+    wanted_listing.start_price = start_price;
+    wanted_listing.floor_price = floor_price;
+    wanted_listing.start_time = start_time;
+    wanted_listing.end_time = end_time;
+    wanted_listing.bump = *ctx
+        .bumps
+        .get("wanted_listing")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+pub fn cancel_wanted_listing<'info>(
+    _ctx: Context<'_, '_, '_, 'info, CancelWantedListing<'info>>,
+) -> Result<()> {
+    Ok(())
+}
+
+/// Sell one token meeting the wanted listing's criteria to its buyer at the current offer price.
+///
+/// Royalties, the house fee and any withheld tax come out of the price as in `execute_sale`. The
+/// optional accounts are passed in `remaining_accounts` in the order: compliance config and
+/// program, mint cooldown, lien, creators, tax withholding, then the frozen proceeds accounts of
+/// the seller.
+pub fn fill_wanted<'info>(ctx: Context<'_, '_, '_, 'info, FillWanted<'info>>) -> Result<()> {
+    let seller = &ctx.accounts.seller;
+    let token_account = &ctx.accounts.token_account;
+    let token_mint = &ctx.accounts.token_mint;
+    let metadata = &ctx.accounts.metadata;
+    let buyer = &ctx.accounts.buyer;
+    let buyer_receipt_token_account = &ctx.accounts.buyer_receipt_token_account;
+    let escrow_payment_account = &ctx.accounts.escrow_payment_account;
+    let seller_payment_receipt_account = &ctx.accounts.seller_payment_receipt_account;
+    let treasury_mint = &ctx.accounts.treasury_mint;
+    let auction_house = &ctx.accounts.auction_house;
+    let auction_house_treasury = &ctx.accounts.auction_house_treasury;
+    let wanted_listing = &ctx.accounts.wanted_listing;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let ata_program = &ctx.accounts.ata_program;
+    let rent = &ctx.accounts.rent;
+
+    // If it has an auctioneer authority delegated must use auctioneer_* handler.
+    if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::ExecuteSale as usize] {
+        return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= wanted_listing.end_time {
+        return Err(AuctionHouseError::WantedListingExpired.into());
+    }
+    let price = descending_price(
+        wanted_listing.start_price,
+        wanted_listing.floor_price,
+        now - wanted_listing.start_time,
+        wanted_listing.end_time - wanted_listing.start_time,
+    )?;
+
+    if token_account.amount < 1 {
+        return Err(AuctionHouseError::InvalidTokenAmount.into());
+    }
+    assert_metadata_valid(metadata, token_account)?;
+
+    let token_metadata = Metadata::from_account_info(&metadata.to_account_info())?;
+    let meets_criteria = match wanted_listing.criteria {
+        WantedCriteria::Mint(mint) => mint == token_mint.key(),
+        WantedCriteria::Collection(collection) => token_metadata
+            .collection
+            .map_or(false, |c| c.verified && c.key == collection),
+    };
+    if !meets_criteria {
+        return Err(AuctionHouseError::WantedCriteriaNotMet.into());
+    }
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+
+    assert_compliance(
+        auction_house,
+        &seller.to_account_info(),
+        &token_mint.key(),
+        ComplianceAction::Sell,
+        price,
+        remaining_accounts,
+    )?;
+    // The mint cooldown account is checked for the seller and restarted for the buyer.
+    let mint_cooldown_accounts = &mut remaining_accounts.clone();
+    assert_relist_cooldown_elapsed(auction_house, &token_mint.key(), remaining_accounts)?;
+    assert_no_outstanding_lien(auction_house, &token_mint.key(), remaining_accounts)?;
+
+    let is_native = treasury_mint.key() == spl_token::native_mint::id();
+
+    let auction_house_key = auction_house.key();
+    let buyer_key = buyer.key();
+    let escrow_payment_bump = *ctx
+        .bumps
+        .get("escrow_payment_account")
+        .ok_or(AuctionHouseError::BumpSeedNotInHashMap)?;
+    let escrow_signer_seeds = [
+        PREFIX.as_bytes(),
+        auction_house_key.as_ref(),
+        buyer_key.as_ref(),
+        &[escrow_payment_bump],
+    ];
+
+    let ah_seeds = [
+        PREFIX.as_bytes(),
+        auction_house.creator.as_ref(),
+        auction_house.treasury_mint.as_ref(),
+        &[auction_house.bump],
+    ];
+
+    // with the native account, the escrow is its own owner,
+    // whereas with token, it is the auction house that is owner.
+    let signer_seeds_for_royalties = if is_native {
+        escrow_signer_seeds
+    } else {
+        ah_seeds
+    };
+
+    let buyer_leftover_after_royalties = pay_creator_fees(
+        remaining_accounts,
+        &metadata.to_account_info(),
+        &escrow_payment_account.to_account_info(),
+        &auction_house.to_account_info(),
+        &seller.to_account_info(),
+        &treasury_mint.to_account_info(),
+        &ata_program.to_account_info(),
+        &token_program.to_account_info(),
+        &system_program.to_account_info(),
+        &rent.to_account_info(),
+        &signer_seeds_for_royalties,
+        &[],
+        price,
+        0,
+        auction_house.rounding_policy.royalty_rounding(),
+        is_native,
+    )?;
+
+    record_mint_settlement(
+        auction_house,
+        &token_mint.key(),
+        &seller.to_account_info(),
+        &[],
+        &system_program.to_account_info(),
+        &rent.to_account_info(),
+        mint_cooldown_accounts,
+    )?;
+
+    let auction_house_fee_paid = pay_auction_house_fees(
+        auction_house,
+        &auction_house_treasury.to_account_info(),
+        &escrow_payment_account.to_account_info(),
+        &token_program.to_account_info(),
+        &system_program.to_account_info(),
+        &signer_seeds_for_royalties,
+        auction_house.seller_fee_basis_points,
+        price,
+        buyer_leftover_after_royalties,
+        is_native,
+    )?;
+
+    let tax_withheld = withhold_tax(
+        auction_house,
+        &escrow_payment_account.to_account_info(),
+        &token_program.to_account_info(),
+        &system_program.to_account_info(),
+        &signer_seeds_for_royalties,
+        price,
+        is_native,
+        remaining_accounts,
+    )?;
+
+    // The withheld tax comes out of the seller's proceeds.
+    let seller_proceeds = checked_sub(
+        checked_sub(buyer_leftover_after_royalties, auction_house_fee_paid)?,
+        tax_withheld,
+    )?;
+
+    if !is_native {
+        if seller_payment_receipt_account.data_is_empty() {
+            make_ata(
+                seller_payment_receipt_account.to_account_info(),
+                seller.to_account_info(),
+                treasury_mint.to_account_info(),
+                seller.to_account_info(),
+                ata_program.to_account_info(),
+                token_program.to_account_info(),
+                system_program.to_account_info(),
+                rent.to_account_info(),
+                &[],
+            )?;
+        }
+
+        let seller_rec_acct = assert_is_ata(
+            &seller_payment_receipt_account.to_account_info(),
+            &seller.key(),
+            &treasury_mint.key(),
+        )?;
+
+        // make sure you cant get rugged
+        if seller_rec_acct.delegate.is_some() {
+            return Err(AuctionHouseError::SellerATACannotHaveDelegate.into());
+        }
+
+        transfer_or_hold_proceeds(
+            remaining_accounts,
+            &auction_house.to_account_info(),
+            &escrow_payment_account.to_account_info(),
+            &seller.key(),
+            &seller_payment_receipt_account.to_account_info(),
+            &seller.to_account_info(),
+            &token_program.to_account_info(),
+            &system_program.to_account_info(),
+            &rent.to_account_info(),
+            &ah_seeds,
+            &[],
+            seller_proceeds,
+        )?;
+    } else {
+        assert_keys_equal(seller_payment_receipt_account.key(), seller.key())?;
+        invoke_signed(
+            &system_instruction::transfer(
+                escrow_payment_account.key,
+                seller_payment_receipt_account.key,
+                seller_proceeds,
+            ),
+            &[
+                escrow_payment_account.to_account_info(),
+                seller_payment_receipt_account.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[&escrow_signer_seeds],
+        )?;
+
+        assert_escrow_rent_exempt(&escrow_payment_account.to_account_info())?;
+    }
+
+    if buyer_receipt_token_account.data_is_empty() {
+        make_ata(
+            buyer_receipt_token_account.to_account_info(),
+            buyer.to_account_info(),
+            token_mint.to_account_info(),
+            seller.to_account_info(),
+            ata_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+            &[],
+        )?;
+    }
+    assert_buyer_receipt_account(
+        auction_house,
+        &buyer_receipt_token_account.to_account_info(),
+        &buyer.key(),
+        &token_mint.key(),
+    )?;
+
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            &token_account.key(),
+            &buyer_receipt_token_account.key(),
+            &seller.key(),
+            &[],
+            1,
+        )?,
+        &[
+            token_account.to_account_info(),
+            buyer_receipt_token_account.to_account_info(),
+            token_program.to_account_info(),
+            seller.to_account_info(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::Token;
+use spl_token::state::Account as SplAccount;
+
+use crate::{constants::*, errors::*, utils::*, AuctionHouse, AuthorityScope};
+
+/// Number of remaining accounts passed per Auction House to `withdraw_many`.
+pub const WITHDRAW_MANY_ACCOUNTS_PER_HOUSE: usize = 3;
+
+/// Accounts for the [`withdraw_many` handler](auction_house/fn.withdraw_many.html).
+///
+/// For every Auction House swept, remaining accounts must hold the Auction House, the wallet's
+/// escrow payment account on it, and the account receiving the funds: the wallet itself for
+/// native SOL houses, or the wallet's associated token account for the treasury mint.
+#[derive(Accounts)]
+pub struct WithdrawMany<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw everything held in the wallet's escrow payment accounts across several Auction Houses.
+pub fn withdraw_many<'info>(ctx: Context<'_, '_, '_, 'info, WithdrawMany<'info>>) -> Result<()> {
+    let wallet = &ctx.accounts.wallet;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let remaining_accounts = ctx.remaining_accounts;
+
+    if remaining_accounts.is_empty()
+        || remaining_accounts.len() % WITHDRAW_MANY_ACCOUNTS_PER_HOUSE != 0
+    {
+        return Err(AuctionHouseError::InvalidWithdrawManyAccounts.into());
+    }
+
+    let wallet_key = wallet.key();
+    for accounts in remaining_accounts.chunks(WITHDRAW_MANY_ACCOUNTS_PER_HOUSE) {
+        let auction_house: Account<AuctionHouse> = Account::try_from(&accounts[0])?;
+        let escrow_payment_account = &accounts[1];
+        let receipt_account = &accounts[2];
+
+        // If it has an auctioneer authority delegated must use auctioneer_* handler.
+        if auction_house.has_auctioneer && auction_house.scopes[AuthorityScope::Withdraw as usize] {
+            return Err(AuctionHouseError::MustUseAuctioneerHandler.into());
+        }
+
+        let auction_house_key = auction_house.key();
+        let escrow_payment_bump = assert_derivation(
+            &crate::id(),
+            escrow_payment_account,
+            &[
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                wallet_key.as_ref(),
+            ],
+        )?;
+
+        if auction_house.treasury_mint != spl_token::native_mint::id() {
+            if escrow_payment_account.data_is_empty() {
+                continue;
+            }
+
+            let escrow: SplAccount = assert_initialized(escrow_payment_account)?;
+            if escrow.amount == 0 {
+                continue;
+            }
+
+            let rec_acct =
+                assert_is_ata(receipt_account, &wallet_key, &auction_house.treasury_mint)?;
+
+            // make sure you cant get rugged
+            if rec_acct.delegate.is_some() {
+                return Err(AuctionHouseError::BuyerATACannotHaveDelegate.into());
+            }
+
+            let ah_seeds = [
+                PREFIX.as_bytes(),
+                auction_house.creator.as_ref(),
+                auction_house.treasury_mint.as_ref(),
+                &[auction_house.bump],
+            ];
+            invoke_signed(
+                &spl_token::instruction::transfer(
+                    token_program.key,
+                    escrow_payment_account.key,
+                    receipt_account.key,
+                    &auction_house_key,
+                    &[],
+                    escrow.amount,
+                )?,
+                &[
+                    escrow_payment_account.clone(),
+                    receipt_account.clone(),
+                    token_program.to_account_info(),
+                    auction_house.to_account_info(),
+                ],
+                &[&ah_seeds],
+            )?;
+        } else {
+            assert_keys_equal(receipt_account.key(), wallet_key)?;
+            if escrow_payment_account.lamports() == 0 {
+                continue;
+            }
+
+            let amount = native_escrow_withdrawal_amount(
+                escrow_payment_account.clone(),
+                escrow_payment_account.lamports(),
+            )?;
+            if amount == 0 {
+                continue;
+            }
+
+            let escrow_signer_seeds = [
+                PREFIX.as_bytes(),
+                auction_house_key.as_ref(),
+                wallet_key.as_ref(),
+                &[escrow_payment_bump],
+            ];
+            invoke_signed(
+                &system_instruction::transfer(escrow_payment_account.key, &wallet_key, amount),
+                &[
+                    escrow_payment_account.clone(),
+                    wallet.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+                &[&escrow_signer_seeds],
+            )?;
+
+            assert_escrow_rent_exempt(escrow_payment_account)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+pub mod many;
+pub use many::*;
+
 use anchor_lang::{prelude::*, AnchorDeserialize};
 
 use crate::{constants::*, errors::*, utils::*, AuctionHouse, AuthorityScope, *};
@@ -330,10 +333,8 @@ fn withdraw_logic<'info>(
         )?;
     } else {
         assert_keys_equal(receipt_account.key(), wallet.key())?;
-        let rent_shortfall = verify_withdrawal(escrow_payment_account.to_account_info(), amount)?;
-        let checked_amount = amount
-            .checked_sub(rent_shortfall)
-            .ok_or(AuctionHouseError::InsufficientFunds)?;
+        let checked_amount =
+            native_escrow_withdrawal_amount(escrow_payment_account.to_account_info(), amount)?;
 
         invoke_signed(
             &system_instruction::transfer(
@@ -348,6 +349,8 @@ fn withdraw_logic<'info>(
             ],
             &[&escrow_signer_seeds],
         )?;
+
+        assert_escrow_rent_exempt(&escrow_payment_account.to_account_info())?;
     }
 
     Ok(())
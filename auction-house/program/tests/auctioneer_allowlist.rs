@@ -0,0 +1,123 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::{helpers::default_scopes, setup_functions::*};
+
+use mpl_auction_house::constants::AUCTIONEER;
+use solana_program::system_program;
+use solana_sdk::instruction::AccountMeta;
+
+async fn delegate_with_allowlist(
+    context: &mut ProgramTestContext,
+    ahkey: Pubkey,
+    authority: &Keypair,
+    auctioneer_authority: Pubkey,
+    auctioneer_allowlist: Pubkey,
+) -> std::result::Result<Pubkey, BanksClientError> {
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let mut accounts = mpl_auction_house::accounts::DelegateAuctioneer {
+        auction_house: ahkey,
+        authority: authority.pubkey(),
+        auctioneer_authority,
+        ah_auctioneer_pda,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new_readonly(auctioneer_allowlist, false));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::DelegateAuctioneer {
+            scopes: default_scopes(),
+        }
+        .data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| ah_auctioneer_pda)
+}
+
+#[tokio::test]
+async fn delegate_allowlisted_auctioneer_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let auctioneer_program = Keypair::new().pubkey();
+    let auctioneer_allowlist = create_auctioneer_allowlist(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        vec![auctioneer_program],
+    )
+    .await
+    .unwrap();
+
+    let (auctioneer_authority, _) = Pubkey::find_program_address(
+        &[AUCTIONEER.as_bytes(), ahkey.as_ref()],
+        &auctioneer_program,
+    );
+    let ah_auctioneer_pda = delegate_with_allowlist(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority,
+        auctioneer_allowlist,
+    )
+    .await
+    .unwrap();
+
+    let auctioneer_account = context
+        .banks_client
+        .get_account(ah_auctioneer_pda)
+        .await
+        .expect("Error getting auctioneer account")
+        .expect("Auctioneer empty");
+    let auctioneer = Auctioneer::try_deserialize(&mut auctioneer_account.data.as_ref())
+        .expect("Failed to deserialize Auctioneer data");
+
+    assert_eq!(auctioneer.auctioneer_program, auctioneer_program);
+}
+
+#[tokio::test]
+async fn delegate_unlisted_auctioneer_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let auctioneer_allowlist = create_auctioneer_allowlist(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        vec![Keypair::new().pubkey()],
+    )
+    .await
+    .unwrap();
+
+    let err = delegate_with_allowlist(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        Keypair::new().pubkey(),
+        auctioneer_allowlist,
+    )
+    .await
+    .unwrap_err();
+
+    assert_error!(err, AUCTIONEER_PROGRAM_NOT_ALLOWED);
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::{helpers::default_scopes, setup_functions::*};
+
+use mpl_auction_house::{
+    pda::{find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address},
+    AuctioneerStats,
+};
+use solana_sdk::instruction::AccountMeta;
+
+#[tokio::test]
+async fn auctioneer_settlement_updates_stats() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_auth) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let auctioneer_authority = Keypair::new();
+    airdrop(&mut context, &auctioneer_authority.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_auth,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+    let auctioneer_stats = create_auctioneer_stats(
+        &mut context,
+        &ahkey,
+        &ah_auth,
+        &auctioneer_authority.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let (sell_acc, sell_tx) = auctioneer_sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &auctioneer_authority,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (bid_acc, buy_tx) = auctioneer_buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &auctioneer_authority,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let (accounts, _) = auctioneer_execute_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &ah_auth,
+        &auctioneer_authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &test_metadata.token.pubkey(),
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        1,
+        ONE_SOL,
+    );
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(AccountMeta::new(auctioneer_stats, false));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::AuctioneerExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: ONE_SOL,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_auth.pubkey()),
+        &[&ah_auth, &auctioneer_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let stats_account = context
+        .banks_client
+        .get_account(auctioneer_stats)
+        .await
+        .unwrap()
+        .unwrap();
+    let stats = AuctioneerStats::try_deserialize(&mut stats_account.data.as_ref()).unwrap();
+    assert_eq!(stats.auctioneer_authority, auctioneer_authority.pubkey());
+    assert_eq!(stats.auctions_run, 1);
+    assert_eq!(stats.settled_volume, ONE_SOL);
+    assert_eq!(
+        stats.fees_earned,
+        ONE_SOL * ah.seller_fee_basis_points as u64 / 10000
+    );
+}
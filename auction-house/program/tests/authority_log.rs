@@ -0,0 +1,86 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{AuthorityAction, AuthorityLog};
+
+#[tokio::test]
+async fn authority_actions_are_recorded() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let authority_log_key = create_authority_log(&mut context, &ahkey, &ah_authority)
+        .await
+        .unwrap();
+
+    let mut accounts = mpl_auction_house::accounts::SetRelistCooldown {
+        authority: ah_authority.pubkey(),
+        auction_house: ahkey,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(authority_log_key, false));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::SetRelistCooldown {
+            relist_cooldown_seconds: 3_600,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let authority_log_account = context
+        .banks_client
+        .get_account(authority_log_key)
+        .await
+        .expect("Error getting authority log")
+        .expect("Authority log empty");
+    let authority_log = AuthorityLog::try_deserialize(&mut authority_log_account.data.as_ref())
+        .expect("Failed to deserialize authority log data");
+
+    assert_eq!(authority_log.auction_house, ahkey);
+    assert_eq!(authority_log.total_entries, 1);
+    assert_eq!(authority_log.next_index, 1);
+    assert_eq!(
+        authority_log.entries[0].action,
+        AuthorityAction::SetRelistCooldown
+    );
+    assert_eq!(authority_log.entries[0].authority, ah_authority.pubkey());
+    assert_eq!(authority_log.entries[0].value, 3_600);
+}
+
+#[tokio::test]
+async fn authority_action_without_log_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    create_authority_log(&mut context, &ahkey, &ah_authority)
+        .await
+        .unwrap();
+
+    let err = set_relist_cooldown(&mut context, &ahkey, &ah_authority, 3_600)
+        .await
+        .unwrap_err();
+
+    assert_transport_error!(
+        err,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::NotEnoughAccountKeys
+        ))
+    );
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::find_escrow_payment_address;
+
+async fn expiring_bid(
+    context: &mut ProgramTestContext,
+    expires_in: i64,
+) -> (Pubkey, Keypair, Pubkey, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let ((acc, _), buy_tx) = buy(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        ONE_SOL,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let (bid_expiry, expiry_tx) = set_bid_expiry(
+        context,
+        &ahkey,
+        &buyer,
+        &acc.token_account,
+        &acc.buyer_trade_state,
+        ONE_SOL,
+        now + expires_in,
+    );
+    context
+        .banks_client
+        .process_transaction(expiry_tx)
+        .await
+        .unwrap();
+
+    (ahkey, buyer, acc.buyer_trade_state, bid_expiry)
+}
+
+#[tokio::test]
+async fn sweep_expired_bid_refunds_buyer_and_pays_cranker() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ahkey, buyer, trade_state, bid_expiry) = expiring_bid(&mut context, 60).await;
+
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 120;
+    context.set_sysvar(&clock);
+
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let reclaimed = context.banks_client.get_balance(trade_state).await.unwrap()
+        + context.banks_client.get_balance(bid_expiry).await.unwrap();
+    let buyer_before = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+    let cranker_before = context
+        .banks_client
+        .get_balance(cranker.pubkey())
+        .await
+        .unwrap();
+
+    let sweep_tx = sweep_expired_bids(
+        &mut context,
+        &ahkey,
+        &cranker,
+        &[(buyer.pubkey(), trade_state)],
+    );
+    context
+        .banks_client
+        .process_transaction(sweep_tx)
+        .await
+        .unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(trade_state)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(context
+        .banks_client
+        .get_account(bid_expiry)
+        .await
+        .unwrap()
+        .is_none());
+
+    let (escrow, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let escrow_balance = context.banks_client.get_balance(escrow).await.unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(escrow_balance, rent.minimum_balance(0));
+
+    let buyer_after = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(buyer_after - buyer_before, ONE_SOL);
+
+    let cranker_after = context
+        .banks_client
+        .get_balance(cranker.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(cranker_after + 5000 - cranker_before, reclaimed);
+}
+
+#[tokio::test]
+async fn sweep_unexpired_bid_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ahkey, buyer, trade_state, _) = expiring_bid(&mut context, 3600).await;
+
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let sweep_tx = sweep_expired_bids(
+        &mut context,
+        &ahkey,
+        &cranker,
+        &[(buyer.pubkey(), trade_state)],
+    );
+    let error = context
+        .banks_client
+        .process_transaction(sweep_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, BID_NOT_EXPIRED);
+}
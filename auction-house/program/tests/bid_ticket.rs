@@ -0,0 +1,189 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::{
+        find_bid_ticket_burn_address, find_bid_ticket_config_address, find_escrow_payment_address,
+        find_trade_state_address,
+    },
+    BidTicketConfig,
+};
+use solana_sdk::instruction::AccountMeta;
+
+#[tokio::test]
+async fn bid_ticket_gate_is_required_until_turned_off() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let collection = Pubkey::new_unique();
+    let bid_ticket_config =
+        create_bid_ticket_config(&mut context, &ahkey, &ah_authority, collection)
+            .await
+            .unwrap();
+
+    let bid_ticket_config_account = context
+        .banks_client
+        .get_account(bid_ticket_config)
+        .await
+        .unwrap()
+        .unwrap();
+    let config =
+        BidTicketConfig::try_deserialize(&mut bid_ticket_config_account.data.as_ref()).unwrap();
+    assert_eq!(config.auction_house, ahkey);
+    assert_eq!(config.collection, collection);
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_house = AuctionHouse::try_deserialize(&mut ah_account.data.as_ref()).unwrap();
+    assert!(auction_house.has_bid_ticket_gate);
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // The bidder holds a ticket NFT outside the house's ticket collection.
+    let ticket = Metadata::new();
+    airdrop(&mut context, &ticket.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    ticket
+        .create(
+            &mut context,
+            "Ticket".to_string(),
+            "TKT".to_string(),
+            "uri".to_string(),
+            None,
+            0,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let buyer = &ticket.token;
+
+    // Bidding without the ticket accounts fails while the gate is set.
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        ONE_SOL,
+        1,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_transport_error!(
+        err,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::NotEnoughAccountKeys
+        ))
+    );
+
+    let ((buy_accounts, _), _) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        ONE_SOL,
+        1,
+    );
+    let (_, trade_state_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        &ahkey,
+        &buy_accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let mut account_metas = buy_accounts.to_account_metas(None);
+    account_metas.extend([
+        AccountMeta::new_readonly(find_bid_ticket_config_address(&ahkey).0, false),
+        AccountMeta::new(
+            get_associated_token_address(&buyer.pubkey(), &ticket.mint.pubkey()),
+            false,
+        ),
+        AccountMeta::new(ticket.mint.pubkey(), false),
+        AccountMeta::new_readonly(ticket.pubkey, false),
+        AccountMeta::new(
+            find_bid_ticket_burn_address(&buy_accounts.buyer_trade_state).0,
+            false,
+        ),
+    ]);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Buy {
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    let gated_buy_tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(gated_buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, BID_TICKET_NOT_IN_COLLECTION);
+
+    update_bid_ticket_config(&mut context, &ahkey, &ah_authority, Pubkey::default())
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        2 * ONE_SOL,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
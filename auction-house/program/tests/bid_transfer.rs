@@ -0,0 +1,118 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use solana_sdk::sysvar;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{find_escrow_payment_address, find_trade_state_address};
+
+#[tokio::test]
+async fn transfer_bid_moves_trade_state_and_escrow() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    let new_buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &new_buyer.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        ONE_SOL,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let (new_trade_state, new_trade_state_bump) = find_trade_state_address(
+        &new_buyer.pubkey(),
+        &ahkey,
+        &bid_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (new_escrow, _) = find_escrow_payment_address(&ahkey, &new_buyer.pubkey());
+    let accounts = mpl_auction_house::accounts::TransferBid {
+        wallet: buyer.pubkey(),
+        new_wallet: new_buyer.pubkey(),
+        token_account: bid_acc.token_account,
+        treasury_mint: ah.treasury_mint,
+        auction_house: ahkey,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        new_escrow_payment_account: new_escrow,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        new_buyer_trade_state: new_trade_state,
+        token_program: spl_token::id(),
+        system_program: solana_program::system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::TransferBid {
+            buyer_price: ONE_SOL,
+            token_size: 1,
+            new_trade_state_bump,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer, &new_buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(bid_acc.buyer_trade_state)
+        .await
+        .unwrap()
+        .is_none());
+    let new_trade_state_account = context
+        .banks_client
+        .get_account(new_trade_state)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(new_trade_state_account.data[0], new_trade_state_bump);
+
+    let new_escrow_balance = context.banks_client.get_balance(new_escrow).await.unwrap();
+    assert!(new_escrow_balance >= ONE_SOL);
+}
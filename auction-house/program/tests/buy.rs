@@ -5,6 +5,9 @@ pub mod utils;
 use common::*;
 use utils::{helpers::default_scopes, setup_functions::*};
 
+use mpl_auction_house::pda::{find_escrow_payment_address, find_trade_state_address};
+use solana_sdk::instruction::AccountMeta;
+
 #[tokio::test]
 async fn buy_success() {
     let mut context = auction_house_program_test().start_with_context().await;
@@ -84,6 +87,192 @@ async fn buy_success() {
     assert_eq!(bid_receipt.bookkeeper, buyer.pubkey());
 }
 
+#[tokio::test]
+async fn buy_with_receipt_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), ONE_SOL * 10)
+        .await
+        .unwrap();
+
+    let ((acc, print_bid_acc), _) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        ONE_SOL,
+        1,
+    );
+    let (_, bts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, receipt_bump) = find_bid_receipt_address(&acc.buyer_trade_state);
+
+    let mut account_metas = acc.to_account_metas(None);
+    account_metas.push(AccountMeta::new(print_bid_acc.receipt, false));
+    account_metas.push(AccountMeta::new(buyer.pubkey(), true));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::BuyWithReceipt {
+            trade_state_bump: bts_bump,
+            escrow_payment_bump: escrow_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+            receipt_bump,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let bid_receipt_account = context
+        .banks_client
+        .get_account(print_bid_acc.receipt)
+        .await
+        .expect("Error Getting Bid Receipt")
+        .expect("Bid Receipt Empty");
+    let bid_receipt = BidReceipt::try_deserialize(&mut bid_receipt_account.data.as_ref()).unwrap();
+
+    assert_eq!(bid_receipt.price, ONE_SOL);
+    assert_eq!(bid_receipt.auction_house, acc.auction_house);
+    assert_eq!(bid_receipt.metadata, acc.metadata);
+    assert_eq!(bid_receipt.token_account, Some(acc.token_account));
+    assert_eq!(bid_receipt.buyer, acc.wallet);
+    assert_eq!(bid_receipt.trade_state, acc.buyer_trade_state);
+    assert_eq!(bid_receipt.trade_state_bump, bts_bump);
+    assert_eq!(bid_receipt.bookkeeper, buyer.pubkey());
+}
+
+#[tokio::test]
+async fn bid_with_transfer_funds_each_bid() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), ONE_SOL * 10)
+        .await
+        .unwrap();
+
+    // Two bids without a deposit step, each funding the escrow with its own price.
+    for price in [ONE_SOL, 2 * ONE_SOL] {
+        let ((acc, _), _) = buy(
+            &mut context,
+            &ahkey,
+            &ah,
+            &test_metadata,
+            &test_metadata.token.pubkey(),
+            &buyer,
+            price,
+            1,
+        );
+        let (_, bts_bump) = find_trade_state_address(
+            &buyer.pubkey(),
+            &ahkey,
+            &acc.token_account,
+            &ah.treasury_mint,
+            &test_metadata.mint.pubkey(),
+            price,
+            1,
+        );
+        let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+        let instruction = Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::BidWithTransfer {
+                trade_state_bump: bts_bump,
+                escrow_payment_bump: escrow_bump,
+                buyer_price: price,
+                token_size: 1,
+            }
+            .data(),
+            accounts: acc.to_account_metas(None),
+        };
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[&buyer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let bts = context
+            .banks_client
+            .get_account(acc.buyer_trade_state)
+            .await
+            .expect("Error Getting Trade State")
+            .expect("Trade State Empty");
+        assert_eq!(bts.data.len(), 1);
+    }
+
+    let (escrow, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let escrow_account = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        escrow_account.lamports,
+        3 * ONE_SOL + rent.minimum_balance(0)
+    );
+}
+
 #[tokio::test]
 async fn auctioneer_buy_success() {
     let mut context = auction_house_program_test().start_with_context().await;
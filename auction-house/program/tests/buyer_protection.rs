@@ -0,0 +1,248 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::ProtectedSettlement;
+use mpl_testing_utils::solana::{create_associated_token_account, get_token_account};
+use solana_program::{system_program, sysvar};
+use solana_sdk::instruction::AccountMeta;
+
+#[tokio::test]
+async fn set_negative_buyer_protection_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = set_buyer_protection(&mut context, &ahkey, &ah_authority, -1, 0)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_BUYER_PROTECTION_WINDOW);
+}
+
+#[tokio::test]
+async fn cancel_protected_settlement_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 86_400, 1_000)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let settlement_account = context
+        .banks_client
+        .get_account(sale.protected_settlement)
+        .await
+        .expect("Error getting protected settlement")
+        .expect("Protected settlement empty");
+    let settlement = ProtectedSettlement::try_deserialize(&mut settlement_account.data.as_ref())
+        .expect("Failed to deserialize protected settlement data");
+    let escrowed = get_token_account(&mut context, &sale.escrow_token_account)
+        .await
+        .unwrap();
+
+    let seller_proceeds = 100_000_000 - ((ah.seller_fee_basis_points as u64 * 100_000_000) / 10000);
+    assert_eq!(settlement.seller_proceeds, seller_proceeds);
+    assert_eq!(settlement.buyer, sale.buyer.pubkey());
+    assert_eq!(escrowed.amount, 1);
+
+    let buyer_before = context
+        .banks_client
+        .get_account(sale.buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let accounts = mpl_auction_house::accounts::CancelProtectedSettlement {
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: settlement.rent_payer,
+        seller_token_account: sale.seller_token_account,
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CancelProtectedSettlement {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority, &sale.buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_after = context
+        .banks_client
+        .get_account(sale.buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let seller_tokens = get_token_account(&mut context, &sale.seller_token_account)
+        .await
+        .unwrap();
+    let closed_settlement = context
+        .banks_client
+        .get_account(sale.protected_settlement)
+        .await
+        .unwrap();
+
+    let restocking_fee = seller_proceeds * 1_000 / 10000;
+    assert_eq!(
+        buyer_before.lamports + seller_proceeds - restocking_fee,
+        buyer_after.lamports
+    );
+    assert_eq!(seller_tokens.amount, 1);
+    assert!(closed_settlement.is_none());
+}
+
+#[tokio::test]
+async fn finalize_during_protection_window_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 86_400, 1_000)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let accounts = mpl_auction_house::accounts::FinalizeProtectedSettlement {
+        payer: ah_authority.pubkey(),
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: ah.auction_house_fee_account,
+        buyer_receipt_token_account: get_associated_token_address(
+            &sale.buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::FinalizeProtectedSettlement {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, BUYER_PROTECTION_WINDOW_OPEN);
+}
+
+#[tokio::test]
+async fn finalize_protected_settlements_skips_finalized() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 60, 1_000)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+    let buyer_receipt_token_account =
+        create_associated_token_account(&mut context, &sale.buyer, &test_metadata.mint.pubkey())
+            .await
+            .unwrap();
+
+    context.warp_to_slot(100).unwrap();
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 120;
+    context.set_sysvar(&clock);
+
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let mut accounts = mpl_auction_house::accounts::FinalizeProtectedSettlements {
+        cranker: cranker.pubkey(),
+        auction_house: ahkey,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    accounts.extend([
+        AccountMeta::new(sale.protected_settlement, false),
+        AccountMeta::new(sale.settlement_marker, false),
+        AccountMeta::new(sale.escrow_token_account, false),
+        AccountMeta::new(buyer_receipt_token_account, false),
+        AccountMeta::new(test_metadata.token.pubkey(), false),
+        AccountMeta::new(ah.auction_house_fee_account, false),
+    ]);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::FinalizeProtectedSettlements {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction.clone()],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_tokens = get_token_account(&mut context, &buyer_receipt_token_account)
+        .await
+        .unwrap();
+    assert_eq!(buyer_tokens.amount, 1);
+    assert!(context
+        .banks_client
+        .get_account(sale.protected_settlement)
+        .await
+        .unwrap()
+        .is_none());
+
+    // A competing cranker resubmitting the same batch exits without doing anything.
+    context.warp_to_slot(200).unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, NOTHING_TO_CRANK);
+}
@@ -457,6 +457,101 @@ async fn auction_cancel_listing_missing_scope_fails() {
     assert_error!(error, MISSING_AUCTIONEER_SCOPE);
 }
 
+#[tokio::test]
+async fn cancel_auctioneer_listing_directly_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    // Payer Wallet
+    let (ah, ahkey, ah_auth) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(
+        &mut context,
+        &test_metadata.token.pubkey(),
+        100_000_000_000_000,
+    )
+    .await
+    .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Tests".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // Without the Cancel scope the listing could otherwise be canceled around the auctioneer.
+    let auctioneer_authority = Keypair::new();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_auth,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        vec![AuthorityScope::Sell],
+    )
+    .await
+    .unwrap();
+
+    context.warp_to_slot(100).unwrap();
+    let (acc, sell_tx) = auctioneer_sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &auctioneer_authority,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let accounts = mpl_auction_house::accounts::Cancel {
+        auction_house: ahkey,
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        authority: ah.authority,
+        trade_state: acc.seller_trade_state,
+        token_program: spl_token::id(),
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Cancel {
+            buyer_price: u64::MAX,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(error, MUST_USE_AUCTIONEER_HANDLER);
+}
+
 #[tokio::test]
 async fn auction_cancel_listing_no_delegate_fails() {
     let mut context = auction_house_program_test().start_with_context().await;
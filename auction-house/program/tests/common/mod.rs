@@ -3,7 +3,7 @@ pub use anchor_lang::{prelude::*, AccountDeserialize, InstructionData, ToAccount
 pub use mpl_auction_house::{
     pda::{find_auctioneer_pda, find_bid_receipt_address, find_listing_receipt_address},
     receipt::{BidReceipt, ListingReceipt},
-    AuctionHouse, Auctioneer, AuthorityScope,
+    AuctionHouse, Auctioneer, AuthorityScope, FeeStake,
 };
 pub use mpl_testing_utils::{
     assert_error, assert_transport_error, solana::airdrop, utils::Metadata,
@@ -23,7 +23,12 @@ pub use std::assert_eq;
 pub const HAS_ONE_CONSTRAINT_VIOLATION: u32 = 2001;
 pub const INVALID_SEEDS: u32 = 2006;
 pub const ACCOUNT_NOT_INITIALIZED: u32 = 3012;
+pub const PUBLIC_KEY_MISMATCH: u32 = 6000;
+pub const INCORRECT_OWNER: u32 = 6003;
+pub const INVALID_TOKEN_AMOUNT: u32 = 6015;
+pub const INVALID_AUCTIONEER: u32 = 6028;
 pub const MISSING_AUCTIONEER_SCOPE: u32 = 6029;
+pub const MUST_USE_AUCTIONEER_HANDLER: u32 = 6030;
 pub const NO_AUCTIONEER_PROGRAM_SET: u32 = 6031;
 pub const TOO_MANY_SCOPES: u32 = 6032;
 pub const BUMP_SEED_NOT_IN_HASHMAP: u32 = 6034;
@@ -34,6 +39,47 @@ pub const PARTIAL_BUY_PRICE_MISMATCH: u32 = 6040;
 pub const MISSING_ELEMENTS_NEEDED_FOR_PARTIAL_BUY: u32 = 6038;
 pub const AUCTIONEER_ALREADY_DELEGATED: u32 = 6041;
 pub const INSUFFICIENT_FUNDS: u32 = 6043;
+pub const FEE_STAKE_LOCKED: u32 = 6045;
+pub const INVALID_RELIST_COOLDOWN: u32 = 6047;
+pub const INVALID_BUYER_PROTECTION_WINDOW: u32 = 6050;
+pub const BUYER_PROTECTION_WINDOW_OPEN: u32 = 6052;
+pub const NOT_SETTLEMENT_PARTY: u32 = 6054;
+pub const SETTLEMENT_IN_DISPUTE: u32 = 6055;
+pub const LIEN_OUTSTANDING: u32 = 6061;
+pub const FROZEN_PROCEEDS_REQUIRE_SPL_TREASURY: u32 = 6062;
+pub const INVALID_LISTING_PRICE_BOUNDS: u32 = 6065;
+pub const LISTING_PRICE_BELOW_MINIMUM: u32 = 6066;
+pub const TRANSFER_BLOCKED_BY_RULE_SET: u32 = 6068;
+pub const AUCTIONEER_PROGRAM_NOT_ALLOWED: u32 = 6069;
+pub const INVALID_WITHDRAW_MANY_ACCOUNTS: u32 = 6072;
+pub const BID_NOT_EXPIRED: u32 = 6074;
+pub const WANTED_CRITERIA_NOT_MET: u32 = 6080;
+pub const INVALID_CREATOR_ACCOUNT: u32 = 6082;
+pub const FEE_BELOW_SWEEP_THRESHOLD: u32 = 6084;
+pub const INVALID_TREASURY_WITHDRAWAL_PERIOD: u32 = 6085;
+pub const TREASURY_WITHDRAWAL_LIMIT_EXCEEDED: u32 = 6086;
+pub const BID_TICKET_NOT_IN_COLLECTION: u32 = 6088;
+pub const SETTLEMENT_PARTIALLY_SETTLED: u32 = 6090;
+pub const BUYER_RECEIPT_NOT_ASSOCIATED_TOKEN_ACCOUNT: u32 = 6092;
+pub const AUCTION_HOUSE_IMMUTABLE: u32 = 6093;
+pub const NOT_COLLECTION_UPDATE_AUTHORITY: u32 = 6094;
+pub const AUCTION_HOUSE_NOT_APPROVED_FOR_COLLECTION: u32 = 6096;
+pub const VOUCHER_REDEEM_DEADLINE_NOT_PASSED: u32 = 6103;
+pub const VOUCHER_NOT_DELIVERED: u32 = 6104;
+pub const NOTHING_TO_CRANK: u32 = 6106;
+pub const INVALID_LEADERBOARD_WINDOW: u32 = 6109;
+pub const ROYALTY_BONUS_TOO_HIGH: u32 = 6110;
+pub const NOT_CO_OWNER: u32 = 6112;
+pub const INVALID_PAYOUT_DELAY: u32 = 6116;
+pub const PAYOUT_DELAY_WINDOW_OPEN: u32 = 6118;
+pub const INVALID_TOKEN_STANDARD_POLICY: u32 = 6120;
+pub const TOKEN_STANDARD_NOT_ALLOWED: u32 = 6121;
+pub const UNVERIFIED_CREATORS: u32 = 6122;
+pub const FRONTEND_MUST_SIGN: u32 = 6123;
+pub const SPENDING_CAP_EXCEEDED: u32 = 6124;
+pub const LISTING_ALREADY_ACTIVE: u32 = 6125;
+pub const LISTING_CURRENCY_MISMATCH: u32 = 6126;
+pub const NOTICE_EXPIRY_IN_PAST: u32 = 6127;
 
 pub const TEN_SOL: u64 = 10_000_000_000;
 pub const ONE_SOL: u64 = 1_000_000_000;
@@ -0,0 +1,98 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::ComplianceConfig;
+
+#[tokio::test]
+async fn compliance_hook_is_required_until_turned_off() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let compliance_program = Keypair::new().pubkey();
+    let compliance_config = create_compliance_config(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        *b"US",
+        compliance_program,
+    )
+    .await
+    .unwrap();
+
+    let compliance_config_account = context
+        .banks_client
+        .get_account(compliance_config)
+        .await
+        .unwrap()
+        .unwrap();
+    let config =
+        ComplianceConfig::try_deserialize(&mut compliance_config_account.data.as_ref()).unwrap();
+    assert_eq!(config.auction_house, ahkey);
+    assert_eq!(config.jurisdiction_code, *b"US");
+    assert_eq!(config.compliance_program, compliance_program);
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_house = AuctionHouse::try_deserialize(&mut ah_account.data.as_ref()).unwrap();
+    assert!(auction_house.has_compliance_hook);
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // Selling without the compliance accounts fails while the hook is set.
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+    assert_transport_error!(
+        err,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::NotEnoughAccountKeys
+        ))
+    );
+
+    update_compliance_config(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        *b"US",
+        Pubkey::default(),
+    )
+    .await
+    .unwrap();
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 2 * ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+}
@@ -62,6 +62,7 @@ async fn init_native_success() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     );
 
     let auction_house_account = auction_house.await.unwrap();
@@ -145,6 +146,7 @@ async fn init_native_success_reinitialize_fail() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     )
     .await
     .unwrap();
@@ -175,6 +177,7 @@ async fn init_native_success_reinitialize_fail() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     )
     .await
     .unwrap_err();
@@ -236,6 +239,7 @@ async fn init_mint_success() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     );
 
     let auction_house_account = auction_house.await.unwrap();
@@ -321,6 +325,7 @@ async fn init_mint_failure() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     )
     .await
     .unwrap_err();
@@ -374,6 +379,7 @@ async fn init_native_noncanonical_fee_payer_failure() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     );
 
     let result = auction_house.await.unwrap_err();
@@ -426,8 +432,74 @@ async fn init_native_noncanonical_treasury_failure() {
         seller_fee_basis_points,
         false,
         false,
+        false,
     );
 
     let result = auction_house.await.unwrap_err();
     assert_error!(result, BUMP_SEED_NOT_IN_HASHMAP)
 }
+
+#[tokio::test]
+async fn immutable_auction_house_cannot_be_updated() {
+    let mut context = setup_functions::auction_house_program_test()
+        .start_with_context()
+        .await;
+    let twd_key = context.payer.pubkey();
+    let fwd_key = context.payer.pubkey();
+    let t_mint_key = spl_token::native_mint::id();
+    let tdw_ata = twd_key;
+    let seller_fee_basis_points: u16 = 100;
+    let authority = Keypair::new();
+    airdrop(&mut context, &authority.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    // Derive Auction House Key
+    let (auction_house_address, bump) =
+        find_auction_house_address(&authority.pubkey(), &t_mint_key);
+    let (auction_fee_account_key, fee_payer_bump) =
+        find_auction_house_fee_account_address(&auction_house_address);
+    // Derive Auction House Treasury Key
+    let (auction_house_treasury_key, treasury_bump) =
+        find_auction_house_treasury_address(&auction_house_address);
+    setup_functions::create_auction_house(
+        &mut context,
+        &authority,
+        &twd_key,
+        &fwd_key,
+        &t_mint_key,
+        &tdw_ata,
+        &auction_house_address,
+        bump,
+        &auction_fee_account_key,
+        fee_payer_bump,
+        &auction_house_treasury_key,
+        treasury_bump,
+        seller_fee_basis_points,
+        false,
+        false,
+        true,
+    )
+    .await
+    .unwrap();
+
+    let auction_house_acc = context
+        .banks_client
+        .get_account(auction_house_address)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+    let auction_house_data =
+        AuctionHouse::try_deserialize(&mut auction_house_acc.data.as_ref()).unwrap();
+    assert!(auction_house_data.is_immutable);
+
+    let err = setup_functions::update_auction_house(
+        &mut context,
+        &auction_house_address,
+        &auction_house_data,
+        &authority,
+        Some(500),
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, AUCTION_HOUSE_IMMUTABLE);
+}
@@ -125,6 +125,9 @@ async fn too_many_scopes() {
     let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority_pubkey);
 
     let mut scopes = default_scopes();
+    scopes.push(AuthorityScope::SetFee);
+    scopes.push(AuthorityScope::PauseListing);
+    scopes.push(AuthorityScope::WithdrawTreasury);
     scopes.push(AuthorityScope::Buy);
 
     let err = delegate_auctioneer(
@@ -0,0 +1,92 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{find_deposit_authority_address, find_escrow_payment_address};
+use solana_program::{system_program, sysvar};
+
+#[tokio::test]
+async fn deposit_on_behalf_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let wallet = Keypair::new();
+    let depositor = Keypair::new();
+    airdrop(&mut context, &wallet.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &depositor.pubkey(), ONE_SOL * 2)
+        .await
+        .unwrap();
+
+    let (deposit_authority, _) =
+        find_deposit_authority_address(&ahkey, &wallet.pubkey(), &depositor.pubkey());
+    let accounts = mpl_auction_house::accounts::ApproveDepositAuthority {
+        wallet: wallet.pubkey(),
+        auction_house: ahkey,
+        deposit_authority,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ApproveDepositAuthority {
+            depositor: depositor.pubkey(),
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&wallet.pubkey()),
+        &[&wallet],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow, escrow_bump) = find_escrow_payment_address(&ahkey, &wallet.pubkey());
+    let accounts = mpl_auction_house::accounts::DepositOnBehalf {
+        depositor: depositor.pubkey(),
+        wallet: wallet.pubkey(),
+        payment_account: depositor.pubkey(),
+        transfer_authority: depositor.pubkey(),
+        escrow_payment_account: escrow,
+        treasury_mint: ah.treasury_mint,
+        auction_house: ahkey,
+        deposit_authority,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::DepositOnBehalf {
+            escrow_payment_bump: escrow_bump,
+            amount: ONE_SOL,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&depositor.pubkey()),
+        &[&depositor],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let escrow = context
+        .banks_client
+        .get_account(escrow)
+        .await
+        .expect("Error Getting Escrow")
+        .expect("Escrow");
+    assert_eq!(escrow.lamports, ONE_SOL + rent.minimum_balance(0));
+}
@@ -0,0 +1,224 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::find_dispute_record_address, DisputeRecord, DisputeResolution, ProtectedSettlement,
+};
+use mpl_testing_utils::solana::get_token_account;
+use solana_program::{system_program, sysvar};
+
+fn open_dispute_tx(
+    context: &ProgramTestContext,
+    wallet: &Keypair,
+    protected_settlement: &Pubkey,
+) -> Transaction {
+    let (dispute_record, _) = find_dispute_record_address(protected_settlement);
+    let accounts = mpl_auction_house::accounts::OpenDispute {
+        wallet: wallet.pubkey(),
+        protected_settlement: *protected_settlement,
+        dispute_record,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::OpenDispute {}.data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&wallet.pubkey()),
+        &[wallet],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn open_dispute_by_non_party_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 86_400, 0)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let stranger = Keypair::new();
+    airdrop(&mut context, &stranger.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let tx = open_dispute_tx(&context, &stranger, &sale.protected_settlement);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, NOT_SETTLEMENT_PARTY);
+}
+
+#[tokio::test]
+async fn cancel_disputed_settlement_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 86_400, 0)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let tx = open_dispute_tx(&context, &sale.buyer, &sale.protected_settlement);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let accounts = mpl_auction_house::accounts::CancelProtectedSettlement {
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: ah.auction_house_fee_account,
+        seller_token_account: sale.seller_token_account,
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CancelProtectedSettlement {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&sale.buyer.pubkey()),
+        &[&sale.buyer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, SETTLEMENT_IN_DISPUTE);
+}
+
+#[tokio::test]
+async fn resolve_dispute_for_buyer_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 86_400, 0)
+        .await
+        .unwrap();
+    let arbiter = Keypair::new();
+    airdrop(&mut context, &arbiter.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    set_arbiter(&mut context, &ahkey, &ah_authority, &arbiter.pubkey())
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let tx = open_dispute_tx(&context, &test_metadata.token, &sale.protected_settlement);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let settlement_account = context
+        .banks_client
+        .get_account(sale.protected_settlement)
+        .await
+        .expect("Error getting protected settlement")
+        .expect("Protected settlement empty");
+    let settlement = ProtectedSettlement::try_deserialize(&mut settlement_account.data.as_ref())
+        .expect("Failed to deserialize protected settlement data");
+    assert!(settlement.disputed);
+
+    let buyer_before = context
+        .banks_client
+        .get_account(sale.buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let (dispute_record, _) = find_dispute_record_address(&sale.protected_settlement);
+    let accounts = mpl_auction_house::accounts::ResolveDispute {
+        arbiter: arbiter.pubkey(),
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: settlement.rent_payer,
+        seller_token_account: sale.seller_token_account,
+        buyer_receipt_token_account: get_associated_token_address(
+            &sale.buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        dispute_record,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ResolveDispute {
+            resolution: DisputeResolution::Buyer,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&arbiter.pubkey()),
+        &[&arbiter],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_after = context
+        .banks_client
+        .get_account(sale.buyer.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let seller_tokens = get_token_account(&mut context, &sale.seller_token_account)
+        .await
+        .unwrap();
+    let record_account = context
+        .banks_client
+        .get_account(dispute_record)
+        .await
+        .expect("Error getting dispute record")
+        .expect("Dispute record empty");
+    let record = DisputeRecord::try_deserialize(&mut record_account.data.as_ref())
+        .expect("Failed to deserialize dispute record data");
+
+    assert_eq!(
+        buyer_before.lamports + settlement.seller_proceeds,
+        buyer_after.lamports
+    );
+    assert_eq!(seller_tokens.amount, 1);
+    assert_eq!(record.opened_by, test_metadata.token.pubkey());
+    assert_eq!(record.resolution, Some(DisputeResolution::Buyer));
+}
@@ -111,7 +111,7 @@ async fn execute_sale_existing_token_account_success() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -508,7 +508,7 @@ async fn execute_sale_pnft_existing_token_account_success() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -692,7 +692,7 @@ async fn execute_sale_wrong_token_account_owner_success() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: malicious_buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -820,7 +820,7 @@ async fn execute_sale_success() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -1007,7 +1007,7 @@ async fn execute_sale_bad_trade_state_failure() {
         seller_trade_state: bid_acc1.buyer_trade_state,
         buyer_trade_state: bid_acc0.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer0_token_account,
         escrow_payment_account: bid_acc0.escrow_payment_account,
@@ -2487,7 +2487,7 @@ async fn execute_sale_partial_order_success() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -2627,7 +2627,7 @@ async fn execute_sale_partial_order_success() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -2813,7 +2813,7 @@ async fn execute_sale_partial_order_bad_trade_state_failure() {
         seller_trade_state: partial_order_acc1.buyer_trade_state,
         buyer_trade_state: partial_order_acc0.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer0_token_account,
         escrow_payment_account: partial_order_acc0.escrow_payment_account,
@@ -2971,7 +2971,7 @@ async fn execute_sale_fail_buyer_trade_state_does_not_exist() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -3098,7 +3098,7 @@ async fn execute_sale_partial_order_order_exceeds_tokens() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -3234,7 +3234,7 @@ async fn execute_sale_partial_order_order_exceeds_tokens() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -3351,7 +3351,7 @@ async fn execute_sale_partial_order_fail_price_mismatch() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -3487,7 +3487,7 @@ async fn execute_sale_partial_order_fail_price_mismatch() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -3604,7 +3604,7 @@ async fn execute_sale_partial_order_fail_missing_elements() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: partial_order_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: partial_order_acc.escrow_payment_account,
@@ -3725,7 +3725,7 @@ async fn execute_sale_pre_partial_bid() {
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -3813,6 +3813,152 @@ async fn execute_sale_pre_partial_bid() {
     assert_eq!(buyer_token_after.amount, 1);
 }
 
+#[tokio::test]
+async fn execute_sale_creators_out_of_order_failure() {
+    let metadata_creators = vec![
+        (Pubkey::new_unique(), 60, true),
+        (Pubkey::new_unique(), 40, true),
+    ];
+    let mut context = auction_house_program_test().start_with_context().await;
+    // Payer Wallet
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    for (pubkey, _, fund_creator) in &metadata_creators {
+        if *fund_creator {
+            airdrop(&mut context, pubkey, 100_000_000).await.unwrap();
+        }
+    }
+
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            Some(
+                metadata_creators
+                    .clone()
+                    .iter()
+                    .map(|(address, share, _)| Creator {
+                        address: *address,
+                        verified: false,
+                        share: *share,
+                    })
+                    .collect(),
+            ),
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        100_000_000,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    let buyer_token_account =
+        get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey());
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: buyer_token_account,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    for (pubkey, _, _) in metadata_creators.iter().rev() {
+        accounts.push(AccountMeta {
+            pubkey: *pubkey,
+            is_signer: false,
+            is_writable: true,
+        });
+    }
+
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 100_000_000,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(&mut context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_CREATOR_ACCOUNT);
+}
+
 #[tokio::test]
 async fn execute_sale_creator_zero_balance() {
     execute_sale_with_creators(vec![(Pubkey::new_unique(), 100, false)]).await;
@@ -3904,7 +4050,7 @@ async fn execute_sale_with_creators(metadata_creators: Vec<(Pubkey, u8, bool)>)
         seller_trade_state: sell_acc.seller_trade_state,
         buyer_trade_state: bid_acc.buyer_trade_state,
         token_program: spl_token::id(),
-        free_trade_state: sell_acc.free_seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
         seller_payment_receipt_account: test_metadata.token.pubkey(),
         buyer_receipt_token_account: buyer_token_account,
         escrow_payment_account: bid_acc.escrow_payment_account,
@@ -4036,3 +4182,120 @@ async fn execute_sale_with_creators(metadata_creators: Vec<(Pubkey, u8, bool)>)
     assert!(seller_before.lamports < seller_after.lamports);
     assert_eq!(buyer_token_after.amount, 1);
 }
+
+#[tokio::test]
+async fn execute_sale_without_free_trade_state_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    assert!(!ah.can_change_sale_price);
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        100_000_000,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    let buyer_token_account =
+        get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey());
+    let accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: None,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: buyer_token_account,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: 0,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 100_000_000,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(&mut context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let buyer_token_after = Account::unpack_from_slice(
+        context
+            .banks_client
+            .get_account(buyer_token_account)
+            .await
+            .unwrap()
+            .unwrap()
+            .data
+            .as_slice(),
+    )
+    .unwrap();
+    assert_eq!(buyer_token_after.amount, 1);
+}
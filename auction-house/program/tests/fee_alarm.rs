@@ -0,0 +1,63 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::AuctionHouse;
+
+#[tokio::test]
+async fn fund_fee_account_tops_up_fee_account() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let funder = Keypair::new();
+    airdrop(&mut context, &funder.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    let fee_before = context
+        .banks_client
+        .get_account(ah.auction_house_fee_account)
+        .await
+        .unwrap()
+        .map_or(0, |account| account.lamports);
+
+    fund_fee_account(&mut context, &ahkey, &ah, &funder, ONE_SOL)
+        .await
+        .unwrap();
+
+    let fee_after = context
+        .banks_client
+        .get_account(ah.auction_house_fee_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(fee_after - fee_before, ONE_SOL);
+}
+
+#[tokio::test]
+async fn set_low_fee_balance_threshold_updates_auction_house() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    set_low_fee_balance_threshold(&mut context, &ahkey, &ah_authority, ONE_SOL)
+        .await
+        .unwrap();
+
+    let auction_house_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_house =
+        AuctionHouse::try_deserialize(&mut auction_house_account.data.as_ref()).unwrap();
+    assert_eq!(auction_house.low_fee_balance_threshold, ONE_SOL);
+}
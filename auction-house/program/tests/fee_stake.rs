@@ -0,0 +1,140 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{find_fee_stake_address, find_fee_stake_vault_address};
+use mpl_testing_utils::solana::{
+    create_associated_token_account, create_mint, get_token_account, mint_tokens,
+};
+
+async fn setup_staker(context: &mut ProgramTestContext, stake_mint: &Keypair) -> (Keypair, Pubkey) {
+    let manager = context.payer.pubkey();
+    create_mint(context, stake_mint, &manager, None)
+        .await
+        .unwrap();
+
+    let staker = Keypair::new();
+    airdrop(context, &staker.pubkey(), ONE_SOL).await.unwrap();
+
+    let staker_ata = create_associated_token_account(context, &staker, &stake_mint.pubkey())
+        .await
+        .unwrap();
+    mint_tokens(
+        context,
+        &stake_mint.pubkey(),
+        &staker_ata,
+        1_000,
+        &manager,
+        None,
+    )
+    .await
+    .unwrap();
+
+    (staker, staker_ata)
+}
+
+#[tokio::test]
+async fn stake_and_unstake_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let stake_mint = Keypair::new();
+    let (staker, staker_ata) = setup_staker(&mut context, &stake_mint).await;
+
+    create_fee_stake_config(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        &stake_mint.pubkey(),
+        500,
+        50,
+        0,
+    )
+    .await
+    .unwrap();
+
+    let (_, stake_tx) = stake_for_fees(&mut context, &ahkey, &staker, &staker_ata, 500);
+    context
+        .banks_client
+        .process_transaction(stake_tx)
+        .await
+        .unwrap();
+
+    let (fee_stake_key, _) = find_fee_stake_address(&ahkey, &staker.pubkey());
+    let fee_stake_account = context
+        .banks_client
+        .get_account(fee_stake_key)
+        .await
+        .expect("Error getting fee stake account")
+        .expect("Fee stake empty");
+    let fee_stake = FeeStake::try_deserialize(&mut fee_stake_account.data.as_ref())
+        .expect("Failed to deserialize fee stake data");
+
+    let (stake_vault, _) = find_fee_stake_vault_address(&ahkey);
+    let vault = get_token_account(&mut context, &stake_vault).await.unwrap();
+
+    assert_eq!(fee_stake.amount, 500);
+    assert_eq!(fee_stake.wallet, staker.pubkey());
+    assert_eq!(vault.amount, 500);
+
+    let (_, unstake_tx) = unstake(&mut context, &ahkey, &staker, &staker_ata, 500);
+    context
+        .banks_client
+        .process_transaction(unstake_tx)
+        .await
+        .unwrap();
+
+    let closed_fee_stake = context
+        .banks_client
+        .get_account(fee_stake_key)
+        .await
+        .expect("Error getting fee stake account");
+    let staker_tokens = get_token_account(&mut context, &staker_ata).await.unwrap();
+
+    assert!(closed_fee_stake.is_none());
+    assert_eq!(staker_tokens.amount, 1_000);
+}
+
+#[tokio::test]
+async fn unstake_during_lockup_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let stake_mint = Keypair::new();
+    let (staker, staker_ata) = setup_staker(&mut context, &stake_mint).await;
+
+    create_fee_stake_config(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        &stake_mint.pubkey(),
+        500,
+        50,
+        86_400,
+    )
+    .await
+    .unwrap();
+
+    let (_, stake_tx) = stake_for_fees(&mut context, &ahkey, &staker, &staker_ata, 500);
+    context
+        .banks_client
+        .process_transaction(stake_tx)
+        .await
+        .unwrap();
+
+    let (_, unstake_tx) = unstake(&mut context, &ahkey, &staker, &staker_ata, 500);
+    let err = context
+        .banks_client
+        .process_transaction(unstake_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, FEE_STAKE_LOCKED);
+}
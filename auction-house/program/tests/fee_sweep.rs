@@ -0,0 +1,86 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::FeeSweepConfig;
+
+#[tokio::test]
+async fn sweep_fee_to_treasury_moves_excess() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let fee_sweep_config = create_fee_sweep_config(&mut context, &ahkey, &ah_authority, ONE_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let fee_before = context
+        .banks_client
+        .get_account(ah.auction_house_fee_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let treasury_before = context
+        .banks_client
+        .get_account(ah.auction_house_treasury)
+        .await
+        .unwrap()
+        .map_or(0, |account| account.lamports);
+
+    sweep_fee_to_treasury(&mut context, &ahkey, &ah)
+        .await
+        .unwrap();
+
+    let fee_after = context
+        .banks_client
+        .get_account(ah.auction_house_fee_account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let treasury_after = context
+        .banks_client
+        .get_account(ah.auction_house_treasury)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(fee_after, ONE_SOL);
+    assert_eq!(treasury_after - treasury_before, fee_before - ONE_SOL);
+
+    let fee_sweep_config_account = context
+        .banks_client
+        .get_account(fee_sweep_config)
+        .await
+        .unwrap()
+        .unwrap();
+    let fee_sweep_config =
+        FeeSweepConfig::try_deserialize(&mut fee_sweep_config_account.data.as_ref()).unwrap();
+    assert_eq!(fee_sweep_config.total_swept, fee_before - ONE_SOL);
+    assert!(fee_sweep_config.last_swept_at > 0);
+}
+
+#[tokio::test]
+async fn sweep_fee_to_treasury_below_threshold_failure() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    create_fee_sweep_config(&mut context, &ahkey, &ah_authority, 100 * TEN_SOL)
+        .await
+        .unwrap();
+
+    let err = sweep_fee_to_treasury(&mut context, &ahkey, &ah)
+        .await
+        .unwrap_err();
+    assert_error!(err, FEE_BELOW_SWEEP_THRESHOLD);
+}
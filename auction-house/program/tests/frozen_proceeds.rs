@@ -0,0 +1,48 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::find_frozen_proceeds_vault_address;
+use solana_program::{system_program, sysvar};
+
+#[tokio::test]
+async fn create_frozen_proceeds_vault_on_native_house_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let (frozen_proceeds_vault, _) = find_frozen_proceeds_vault_address(&ahkey);
+    let accounts = mpl_auction_house::accounts::CreateFrozenProceedsVault {
+        auction_house: ahkey,
+        authority: ah_authority.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        frozen_proceeds_vault,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CreateFrozenProceedsVault {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, FROZEN_PROCEEDS_REQUIRE_SPL_TREASURY);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+#[tokio::test]
+async fn get_house_balances_reports_treasury_and_fees() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let fee_sweep_config = create_fee_sweep_config(&mut context, &ahkey, &ah_authority, ONE_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_treasury, ONE_SOL)
+        .await
+        .unwrap();
+
+    let fee_account_balance = context
+        .banks_client
+        .get_balance(ah.auction_house_fee_account)
+        .await
+        .unwrap();
+    let treasury_balance = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    let balances = get_house_balances(&mut context, &ahkey, &ah, Some(fee_sweep_config))
+        .await
+        .unwrap();
+    assert_eq!(balances.treasury_balance, treasury_balance);
+    assert_eq!(balances.fee_account_balance, fee_account_balance);
+    assert_eq!(balances.unswept_fees, fee_account_balance - ONE_SOL);
+
+    // Without the fee sweep config there is no threshold to sweep above.
+    let balances = get_house_balances(&mut context, &ahkey, &ah, None)
+        .await
+        .unwrap();
+    assert_eq!(balances.fee_account_balance, fee_account_balance);
+    assert_eq!(balances.unswept_fees, 0);
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{pda::find_house_notice_address, HouseNotice, NoticeStatus};
+use solana_sdk::system_program;
+
+fn set_house_notice_transaction(
+    context: &ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    status: NoticeStatus,
+    expires_at: i64,
+) -> Transaction {
+    let (house_notice, _) = find_house_notice_address(ahkey);
+    let accounts = mpl_auction_house::accounts::SetHouseNotice {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        house_notice,
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::SetHouseNotice {
+            status,
+            message_hash: [7; 32],
+            expires_at,
+        }
+        .data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+async fn create_house_notice(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+) -> Pubkey {
+    let (house_notice, _) = find_house_notice_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateHouseNotice {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        house_notice,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CreateHouseNotice {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    house_notice
+}
+
+#[tokio::test]
+async fn set_house_notice_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let house_notice = create_house_notice(&mut context, &ahkey, &ah_authority).await;
+
+    let tx = set_house_notice_transaction(&context, &ahkey, &ah_authority, NoticeStatus::Paused, 0);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let house_notice_account = context
+        .banks_client
+        .get_account(house_notice)
+        .await
+        .unwrap()
+        .unwrap();
+    let house_notice =
+        HouseNotice::try_deserialize(&mut house_notice_account.data.as_ref()).unwrap();
+    assert_eq!(house_notice.auction_house, ahkey);
+    assert_eq!(house_notice.status, NoticeStatus::Paused);
+    assert_eq!(house_notice.message_hash, [7; 32]);
+    assert!(house_notice.is_active(house_notice.updated_at));
+}
+
+#[tokio::test]
+async fn set_house_notice_expired_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    create_house_notice(&mut context, &ahkey, &ah_authority).await;
+
+    let tx = set_house_notice_transaction(&context, &ahkey, &ah_authority, NoticeStatus::Info, 1);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, NOTICE_EXPIRY_IN_PAST);
+}
+
+#[tokio::test]
+async fn set_house_notice_wrong_authority_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    create_house_notice(&mut context, &ahkey, &ah_authority).await;
+
+    let impostor = Keypair::new();
+    airdrop(&mut context, &impostor.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let tx = set_house_notice_transaction(&context, &ahkey, &impostor, NoticeStatus::Paused, 0);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, HAS_ONE_CONSTRAINT_VIOLATION);
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    constants::CURRENCY_LOCKED_TRADE_STATE_SIZE,
+    pda::{find_program_as_signer_address, find_trade_state_address},
+};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+
+/// A signed `sell` transaction of the token, passing `treasury_mint` as the listing currency.
+fn sell_with_listing_currency(
+    context: &ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    treasury_mint: Pubkey,
+) -> (Pubkey, Transaction) {
+    let seller = test_metadata.token.pubkey();
+    let token = get_associated_token_address(&seller, &test_metadata.mint.pubkey());
+    let (seller_trade_state, trade_state_bump) = find_trade_state_address(
+        &seller,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+
+    let mut accounts = mpl_auction_house::accounts::Sell {
+        wallet: seller,
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        token_program: spl_token::id(),
+        system_program: solana_program::system_program::id(),
+        program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+    accounts.push(AccountMeta::new_readonly(treasury_mint, false));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+
+    (
+        seller_trade_state,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&seller),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+async fn listing_currency_context(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Metadata) {
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(context).await.unwrap();
+    set_listing_currency_lock(context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    (ah, ahkey, test_metadata)
+}
+
+#[tokio::test]
+async fn sell_locks_listing_currency() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata) = listing_currency_context(&mut context).await;
+
+    let (seller_trade_state, sell_tx) =
+        sell_with_listing_currency(&context, &ahkey, &ah, &test_metadata, ah.treasury_mint);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let trade_state = context
+        .banks_client
+        .get_account(seller_trade_state)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trade_state.data.len(), CURRENCY_LOCKED_TRADE_STATE_SIZE);
+    assert_eq!(&trade_state.data[1..33], ah.treasury_mint.as_ref());
+    assert_eq!(trade_state.data[33], spl_token::native_mint::DECIMALS);
+}
+
+#[tokio::test]
+async fn sell_with_wrong_listing_currency_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata) = listing_currency_context(&mut context).await;
+
+    let (_, sell_tx) = sell_with_listing_currency(
+        &context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        test_metadata.mint.pubkey(),
+    );
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, PUBLIC_KEY_MISMATCH);
+}
@@ -0,0 +1,223 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{find_program_as_signer_address, find_trade_state_address};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use solana_program::{system_program, sysvar};
+
+#[tokio::test]
+async fn set_listing_price_bounds_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    set_listing_price_bounds(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        Some(1_000),
+        Some(TEN_SOL),
+    )
+    .await
+    .unwrap();
+
+    let auction_house_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .expect("Error getting auction house")
+        .expect("Auction house empty");
+    let auction_house = AuctionHouse::try_deserialize(&mut auction_house_account.data.as_ref())
+        .expect("Failed to deserialize auction house data");
+
+    assert_eq!(auction_house.min_listing_price, Some(1_000));
+    assert_eq!(auction_house.max_listing_price, Some(TEN_SOL));
+}
+
+#[tokio::test]
+async fn set_inverted_listing_price_bounds_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = set_listing_price_bounds(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        Some(TEN_SOL),
+        Some(ONE_SOL),
+    )
+    .await
+    .unwrap_err();
+
+    assert_error!(err, INVALID_LISTING_PRICE_BOUNDS);
+}
+
+#[tokio::test]
+async fn sell_below_minimum_listing_price_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_listing_price_bounds(&mut context, &ahkey, &ah_authority, Some(ONE_SOL), None)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 1, 1);
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, LISTING_PRICE_BELOW_MINIMUM);
+}
+
+#[tokio::test]
+async fn reprice_free_listing_with_minimum_listing_price_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_listing_price_bounds(&mut context, &ahkey, &ah_authority, Some(ONE_SOL), None)
+        .await
+        .unwrap();
+
+    let accounts = mpl_auction_house::accounts::UpdateAuctionHouse {
+        treasury_mint: ah.treasury_mint,
+        payer: ah_authority.pubkey(),
+        authority: ah_authority.pubkey(),
+        new_authority: ah.authority,
+        fee_withdrawal_destination: ah.fee_withdrawal_destination,
+        treasury_withdrawal_destination: ah.treasury_withdrawal_destination,
+        treasury_withdrawal_destination_owner: ah.treasury_withdrawal_destination,
+        auction_house: ahkey,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::UpdateAuctionHouse {
+            seller_fee_basis_points: None,
+            requires_sign_off: None,
+            can_change_sale_price: Some(true),
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // The seller lists at 0, authorizing the authority to pick the price.
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 0, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    // The authority reprices the listing without the seller signing.
+    let ((sell_acc, _), _) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL * 2, 1);
+    let mut accounts = sell_acc.to_account_metas(None);
+    for account in accounts.iter_mut() {
+        if account.pubkey == ah_authority.pubkey() {
+            account.is_signer = true;
+        }
+    }
+    accounts.push(lien_account_meta(&ahkey, &test_metadata.mint.pubkey()));
+    let (_, sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL * 2,
+        1,
+    );
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, pas_bump) = find_program_as_signer_address();
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: ONE_SOL * 2,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let seller_trade_state = context
+        .banks_client
+        .get_account(sell_acc.seller_trade_state)
+        .await
+        .unwrap();
+    assert!(seller_trade_state.is_some());
+}
@@ -0,0 +1,235 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{
+    find_marketplace_binding_address, find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_testing_utils::utils::MasterEditionV2;
+use mpl_token_metadata::state::Collection;
+use solana_sdk::instruction::AccountMeta;
+
+/// Create an NFT owned by its metadata's token keypair, verified into a new collection whose
+/// update authority is the context payer. Returns the NFT and the collection NFT.
+async fn collection_nft(context: &mut ProgramTestContext) -> (Metadata, Metadata) {
+    let collection = Metadata::new();
+    collection
+        .create(
+            context,
+            "Collection".to_string(),
+            "COL".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            true,
+            1,
+        )
+        .await
+        .unwrap();
+    MasterEditionV2::new(&collection)
+        .create(context, Some(0))
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            true,
+            1,
+        )
+        .await
+        .unwrap();
+    test_metadata
+        .update_v2(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            true,
+            Some(Collection {
+                verified: false,
+                key: collection.mint.pubkey(),
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    let collection_authority = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    test_metadata
+        .verify_collection(
+            context,
+            collection.pubkey,
+            collection_authority,
+            collection.mint.pubkey(),
+            collection.master_edition,
+            None,
+        )
+        .await
+        .unwrap();
+
+    (test_metadata, collection)
+}
+
+/// List `test_metadata` for `sale_price`, passing the collection's marketplace binding.
+async fn sell_with_marketplace_binding(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    collection: &Metadata,
+    sale_price: u64,
+) -> std::result::Result<(), BanksClientError> {
+    let ((acc, _), _) = sell(context, ahkey, ah, test_metadata, sale_price, 1);
+    let (_, sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        1,
+    );
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, pas_bump) = find_program_as_signer_address();
+    let (marketplace_binding, _) = find_marketplace_binding_address(&collection.mint.pubkey());
+
+    let mut account_metas = acc.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+    account_metas.push(AccountMeta::new_readonly(marketplace_binding, false));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: sale_price,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+#[tokio::test]
+async fn sell_on_approved_house_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, collection) = collection_nft(&mut context).await;
+
+    let update_authority = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    create_marketplace_binding(
+        &mut context,
+        &collection.mint.pubkey(),
+        &update_authority,
+        vec![ahkey],
+    )
+    .await
+    .unwrap();
+
+    sell_with_marketplace_binding(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &collection,
+        ONE_SOL,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn sell_on_unapproved_house_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (test_metadata, collection) = collection_nft(&mut context).await;
+
+    // Without a binding the collection may be listed anywhere.
+    sell_with_marketplace_binding(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &collection,
+        ONE_SOL,
+    )
+    .await
+    .unwrap();
+
+    let update_authority = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    create_marketplace_binding(
+        &mut context,
+        &collection.mint.pubkey(),
+        &update_authority,
+        vec![Pubkey::new_unique()],
+    )
+    .await
+    .unwrap();
+
+    let err = sell_with_marketplace_binding(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &collection,
+        2 * ONE_SOL,
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, AUCTION_HOUSE_NOT_APPROVED_FOR_COLLECTION);
+}
+
+#[tokio::test]
+async fn create_marketplace_binding_requires_update_authority() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, collection) = collection_nft(&mut context).await;
+
+    let not_update_authority = Keypair::new();
+    airdrop(&mut context, &not_update_authority.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let err = create_marketplace_binding(
+        &mut context,
+        &collection.mint.pubkey(),
+        &not_update_authority,
+        vec![Pubkey::new_unique()],
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, NOT_COLLECTION_UPDATE_AUTHORITY);
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use solana_sdk::{instruction::AccountMeta, system_program};
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{
+    find_omnibus_custodian_address, find_program_as_signer_address, find_trade_attribution_address,
+    find_trade_state_address,
+};
+
+/// An instruction attributing `trade_state` to `sub_account` on behalf of `custodian`.
+fn attribute_trade(
+    ahkey: &Pubkey,
+    custodian: &Pubkey,
+    trade_state: &Pubkey,
+    sub_account: [u8; 32],
+) -> (Pubkey, Instruction) {
+    let (omnibus_custodian, _) = find_omnibus_custodian_address(ahkey, custodian);
+    let (trade_attribution, _) = find_trade_attribution_address(custodian, trade_state);
+    let accounts = mpl_auction_house::accounts::AttributeTrade {
+        custodian: *custodian,
+        auction_house: *ahkey,
+        omnibus_custodian,
+        trade_state: *trade_state,
+        trade_attribution,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    (
+        trade_attribution,
+        Instruction {
+            program_id: mpl_auction_house::id(),
+            data: mpl_auction_house::instruction::AttributeTrade { sub_account }.data(),
+            accounts,
+        },
+    )
+}
+
+async fn custodied_token(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Keypair, Metadata) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    (ah, ahkey, authority, test_metadata)
+}
+
+#[tokio::test]
+async fn listing_receipt_records_attributed_sub_account() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority, test_metadata) = custodied_token(&mut context).await;
+    let custodian = &test_metadata.token;
+    register_omnibus_custodian(&mut context, &ahkey, &authority, &custodian.pubkey())
+        .await
+        .unwrap();
+
+    let ((sell_accounts, receipt_accounts), _) =
+        sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    let sub_account = [7; 32];
+    let (trade_attribution, attribute_instruction) = attribute_trade(
+        &ahkey,
+        &custodian.pubkey(),
+        &sell_accounts.seller_trade_state,
+        sub_account,
+    );
+
+    let (_, trade_state_bump) = find_trade_state_address(
+        &custodian.pubkey(),
+        &ahkey,
+        &sell_accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (_, free_trade_state_bump) = find_trade_state_address(
+        &custodian.pubkey(),
+        &ahkey,
+        &sell_accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, program_as_signer_bump) = find_program_as_signer_address();
+    let mut sell_metas = sell_accounts.to_account_metas(None);
+    sell_metas.push(lien_account_meta(&ahkey, &test_metadata.mint.pubkey()));
+    let sell_instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+        }
+        .data(),
+        accounts: sell_metas,
+    };
+
+    let (_, receipt_bump) = find_listing_receipt_address(&sell_accounts.seller_trade_state);
+    let mut receipt_metas = receipt_accounts.to_account_metas(None);
+    receipt_metas.push(AccountMeta::new_readonly(trade_attribution, false));
+    let print_receipt_instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::PrintListingReceipt { receipt_bump }.data(),
+        accounts: receipt_metas,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            attribute_instruction,
+            sell_instruction,
+            print_receipt_instruction,
+        ],
+        Some(&custodian.pubkey()),
+        &[custodian],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let receipt_account = context
+        .banks_client
+        .get_account(receipt_accounts.receipt)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt = ListingReceipt::try_deserialize(&mut receipt_account.data.as_ref()).unwrap();
+    assert_eq!(receipt.seller, custodian.pubkey());
+    assert_eq!(receipt.sub_account, Some(sub_account));
+}
+
+#[tokio::test]
+async fn unregistered_custodian_cannot_attribute_trades() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _, test_metadata) = custodied_token(&mut context).await;
+    let custodian = &test_metadata.token;
+
+    let ((sell_accounts, _), _) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    let (_, attribute_instruction) = attribute_trade(
+        &ahkey,
+        &custodian.pubkey(),
+        &sell_accounts.seller_trade_state,
+        [7; 32],
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[attribute_instruction],
+        Some(&custodian.pubkey()),
+        &[custodian],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, ACCOUNT_NOT_INITIALIZED);
+}
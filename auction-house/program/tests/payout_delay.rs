@@ -0,0 +1,111 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::DelayedPayout;
+
+#[tokio::test]
+async fn set_negative_payout_delay_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = set_payout_delay(&mut context, &ahkey, &ah_authority, -1)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_PAYOUT_DELAY);
+}
+
+#[tokio::test]
+async fn claim_during_payout_delay_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_payout_delay(&mut context, &ahkey, &ah_authority, 86_400)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale = execute_delayed_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let payout_account = context
+        .banks_client
+        .get_account(sale.delayed_payout)
+        .await
+        .expect("Error getting delayed payout")
+        .expect("Delayed payout empty");
+    let payout = DelayedPayout::try_deserialize(&mut payout_account.data.as_ref())
+        .expect("Failed to deserialize delayed payout data");
+    let seller_proceeds = 100_000_000 - ((ah.seller_fee_basis_points as u64 * 100_000_000) / 10000);
+    assert_eq!(payout.amount, seller_proceeds);
+    assert_eq!(payout.seller, test_metadata.token.pubkey());
+
+    let err = claim_proceeds(
+        &mut context,
+        &payout.seller,
+        &payout.rent_payer,
+        &sale.delayed_payout,
+    )
+    .await
+    .unwrap_err();
+
+    assert_error!(err, PAYOUT_DELAY_WINDOW_OPEN);
+}
+
+#[tokio::test]
+async fn clawback_proceeds_refunds_buyer() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_payout_delay(&mut context, &ahkey, &ah_authority, 86_400)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale = execute_delayed_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let payout_account = context
+        .banks_client
+        .get_account(sale.delayed_payout)
+        .await
+        .expect("Error getting delayed payout")
+        .expect("Delayed payout empty");
+    let payout = DelayedPayout::try_deserialize(&mut payout_account.data.as_ref())
+        .expect("Failed to deserialize delayed payout data");
+    let buyer_before = context
+        .banks_client
+        .get_balance(sale.buyer.pubkey())
+        .await
+        .unwrap();
+
+    clawback_proceeds(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        &sale.buyer.pubkey(),
+        &payout.rent_payer,
+        &sale.delayed_payout,
+    )
+    .await
+    .unwrap();
+
+    let buyer_after = context
+        .banks_client
+        .get_balance(sale.buyer.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(buyer_after - buyer_before, payout.amount);
+    assert!(context
+        .banks_client
+        .get_account(sale.delayed_payout)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::find_escrow_payment_address;
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use solana_program::program_pack::Pack;
+use solana_sdk::system_program;
+
+fn prepare_buyer_transaction(
+    context: &ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    buyer: &Keypair,
+    token_mint: &Pubkey,
+) -> Transaction {
+    let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let accounts = mpl_auction_house::accounts::PrepareBuyer {
+        wallet: buyer.pubkey(),
+        payment_account: buyer.pubkey(),
+        escrow_payment_account,
+        buyer_receipt_token_account: get_associated_token_address(&buyer.pubkey(), token_mint),
+        token_mint: *token_mint,
+        treasury_mint: ah.treasury_mint,
+        auction_house: *ahkey,
+        token_program: spl_token::id(),
+        ata_program: spl_associated_token_account::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::PrepareBuyer {}.data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn prepare_buyer_creates_escrow_and_receipt_account() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    let tx = prepare_buyer_transaction(&context, &ahkey, &ah, &buyer, &test_metadata.mint.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let (escrow_payment_account, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let escrow = context
+        .banks_client
+        .get_account(escrow_payment_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(escrow.lamports, rent.minimum_balance(0));
+
+    let receipt_account = context
+        .banks_client
+        .get_account(get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt_account =
+        spl_token::state::Account::unpack_from_slice(&receipt_account.data).unwrap();
+    assert_eq!(receipt_account.owner, buyer.pubkey());
+    assert_eq!(receipt_account.mint, test_metadata.mint.pubkey());
+
+    // Preparing again leaves the existing accounts in place.
+    context.warp_to_slot(100).unwrap();
+    let tx = prepare_buyer_transaction(&context, &ahkey, &ah, &buyer, &test_metadata.mint.pubkey());
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let escrow = context
+        .banks_client
+        .get_account(escrow_payment_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(escrow.lamports, rent.minimum_balance(0));
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::{find_escrow_payment_address, find_public_bid_trade_state_address},
+    PublicBidIndex,
+};
+use solana_sdk::instruction::AccountMeta;
+
+#[tokio::test]
+async fn public_bid_index_tracks_bids_and_cancels() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let public_bid_index =
+        create_public_bid_index(&mut context, &ahkey, &test_metadata.mint.pubkey())
+            .await
+            .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    let ((acc, _), _) = public_buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        ONE_SOL,
+    );
+    let (_, trade_state_bump) = find_public_bid_trade_state_address(
+        &buyer.pubkey(),
+        &ahkey,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+
+    let mut accounts = acc.to_account_metas(None);
+    accounts.push(AccountMeta::new(public_bid_index, false));
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::PublicBuy {
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let index_account = context
+        .banks_client
+        .get_account(public_bid_index)
+        .await
+        .unwrap()
+        .unwrap();
+    let index = PublicBidIndex::try_deserialize(&mut index_account.data.as_ref()).unwrap();
+    assert_eq!(index.bids.len(), 1);
+    assert_eq!(index.bids[0].trade_state, acc.buyer_trade_state);
+    assert_eq!(index.bids[0].wallet, buyer.pubkey());
+    assert_eq!(index.bids[0].buyer_price, ONE_SOL);
+
+    let mut accounts = mpl_auction_house::accounts::Cancel {
+        auction_house: ahkey,
+        wallet: buyer.pubkey(),
+        token_account: acc.token_account,
+        authority: ah.authority,
+        trade_state: acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(public_bid_index, false));
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Cancel {
+            buyer_price: ONE_SOL,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let index_account = context
+        .banks_client
+        .get_account(public_bid_index)
+        .await
+        .unwrap()
+        .unwrap();
+    let index = PublicBidIndex::try_deserialize(&mut index_account.data.as_ref()).unwrap();
+    assert!(index.bids.is_empty());
+}
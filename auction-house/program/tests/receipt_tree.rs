@@ -0,0 +1,34 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+#[tokio::test]
+async fn create_receipt_tree_requires_compression_tree() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    // A system account is not an account compression merkle tree.
+    let merkle_tree = Keypair::new();
+    airdrop(&mut context, &merkle_tree.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let error = create_receipt_tree(&mut context, &ahkey, &ah_authority, &merkle_tree.pubkey())
+        .await
+        .unwrap_err();
+    assert_error!(error, INCORRECT_OWNER);
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_house = AuctionHouse::try_deserialize(&mut ah_account.data.as_ref()).unwrap();
+    assert!(!auction_house.has_receipt_tree);
+}
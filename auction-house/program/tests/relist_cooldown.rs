@@ -0,0 +1,131 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{
+    find_mint_cooldown_address, find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+
+#[tokio::test]
+async fn set_relist_cooldown_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    set_relist_cooldown(&mut context, &ahkey, &ah_authority, 3_600)
+        .await
+        .unwrap();
+
+    let auction_house_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .expect("Error getting auction house")
+        .expect("Auction house empty");
+    let auction_house = AuctionHouse::try_deserialize(&mut auction_house_account.data.as_ref())
+        .expect("Failed to deserialize auction house data");
+
+    assert_eq!(auction_house.relist_cooldown_seconds, 3_600);
+}
+
+#[tokio::test]
+async fn set_negative_relist_cooldown_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = set_relist_cooldown(&mut context, &ahkey, &ah_authority, -1)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_RELIST_COOLDOWN);
+}
+
+#[tokio::test]
+async fn sell_unsold_mint_with_relist_cooldown() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_relist_cooldown(&mut context, &ahkey, &ah_authority, 3_600)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((acc, _), _) = sell(&mut context, &ahkey, &ah, &test_metadata, 1, 1);
+    let (_, sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+        1,
+    );
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, pas_bump) = find_program_as_signer_address();
+    let (mint_cooldown, _) = find_mint_cooldown_address(&ahkey, &test_metadata.mint.pubkey());
+
+    let mut account_metas = acc.to_account_metas(None);
+    account_metas.push(AccountMeta::new(mint_cooldown, false));
+    account_metas.push(lien_account_meta(&ahkey, &test_metadata.mint.pubkey()));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 1,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let sts = context
+        .banks_client
+        .get_account(acc.seller_trade_state)
+        .await
+        .expect("Error Getting Trade State")
+        .expect("Trade State Empty");
+    assert_eq!(sts.data.len(), 1);
+}
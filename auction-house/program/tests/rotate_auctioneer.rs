@@ -0,0 +1,107 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::{helpers::default_scopes, setup_functions::*};
+
+#[tokio::test]
+async fn rotated_auctioneer_valid_until_grace_period_ends() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_auth) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let old_authority = Keypair::new();
+    let new_authority = Keypair::new();
+    airdrop(&mut context, &old_authority.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let (old_auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &old_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_auth,
+        old_authority.pubkey(),
+        old_auctioneer_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+
+    let new_auctioneer_pda = rotate_auctioneer(
+        &mut context,
+        &ahkey,
+        &ah_auth,
+        &old_authority.pubkey(),
+        &new_authority.pubkey(),
+        3600,
+    )
+    .await
+    .unwrap();
+
+    let ah_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let ah_data = AuctionHouse::try_deserialize(&mut ah_account.data.as_ref()).unwrap();
+    assert_eq!(ah_data.auctioneer_address, new_auctioneer_pda);
+    assert_eq!(ah_data.previous_auctioneer_address, old_auctioneer_pda);
+
+    // The replaced auctioneer can still list during the grace period.
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let (_, sell_tx) = auctioneer_sell(&mut context, &ahkey, &ah, &test_metadata, &old_authority);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(100).unwrap();
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 7200;
+    context.set_sysvar(&clock);
+
+    let second_metadata = Metadata::new();
+    airdrop(&mut context, &second_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    second_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let (_, sell_tx) = auctioneer_sell(&mut context, &ahkey, &ah, &second_metadata, &old_authority);
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_AUCTIONEER);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::RoundingPolicy;
+
+#[tokio::test]
+async fn half_even_rounds_house_fee_to_nearest() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_rounding_policy(&mut context, &ahkey, &authority, RoundingPolicy::HalfEven)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // The 1% house fee on this price is 10_000_000.51 lamports.
+    let price = ONE_SOL + 51;
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, price, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        price,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let (_, sale_tx) = execute_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &test_metadata.token.pubkey(),
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        1,
+        price,
+    );
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let treasury_before = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+    context
+        .banks_client
+        .process_transaction(sale_tx)
+        .await
+        .unwrap();
+    let treasury_after = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    assert_eq!(treasury_after - treasury_before, 10_000_001);
+}
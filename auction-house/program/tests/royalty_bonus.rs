@@ -0,0 +1,204 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{
+    find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address,
+};
+use mpl_token_metadata::state::Creator;
+use solana_program::{system_program, sysvar};
+use solana_sdk::instruction::AccountMeta;
+
+#[tokio::test]
+async fn execute_sale_pays_royalty_bonus_to_creators() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let creator = Pubkey::new_unique();
+    airdrop(&mut context, &creator, ONE_SOL).await.unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            Some(vec![Creator {
+                address: creator,
+                verified: false,
+                share: 100,
+            }]),
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let royalty_bonus = create_royalty_bonus(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        ONE_SOL,
+        1,
+        490,
+    )
+    .await
+    .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        ONE_SOL,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(royalty_bonus, false));
+    accounts.push(AccountMeta::new(creator, false));
+
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: ONE_SOL,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let creator_after = context.banks_client.get_balance(creator).await.unwrap();
+    // The 0.1% metadata royalty plus the seller's 4.9% bonus.
+    assert_eq!(creator_after, ONE_SOL + ONE_SOL * 500 / 10000);
+    assert!(context
+        .banks_client
+        .get_account(royalty_bonus)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn create_royalty_bonus_above_total_royalty_failure() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let err = create_royalty_bonus(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        ONE_SOL,
+        1,
+        9991,
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, ROYALTY_BONUS_TOO_HIGH);
+}
@@ -10,8 +10,12 @@ use utils::{
     setup_functions::*,
 };
 
-use mpl_auction_house::{pda::find_program_as_signer_address, receipt::ListingReceipt};
+use mpl_auction_house::{
+    pda::{find_program_as_signer_address, find_trade_state_address},
+    receipt::ListingReceipt,
+};
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use solana_sdk::instruction::AccountMeta;
 use solana_sdk::{signer::Signer, sysvar::clock::Clock};
 use std::assert_eq;
 
@@ -85,6 +89,104 @@ async fn sell_success() {
     assert_eq!(listing_receipt.token_size, 1);
 }
 
+#[tokio::test]
+async fn sell_with_receipt_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    let owner_pubkey = &test_metadata.token.pubkey();
+    airdrop(&mut context, owner_pubkey, TEN_SOL).await.unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((acc, listing_receipt_acc), _) = sell(&mut context, &ahkey, &ah, &test_metadata, 1, 1);
+    let (_, sts_bump) = find_trade_state_address(
+        owner_pubkey,
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+        1,
+    );
+    let (_, free_sts_bump) = find_trade_state_address(
+        owner_pubkey,
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, pas_bump) = find_program_as_signer_address();
+    let (_, receipt_bump) = find_listing_receipt_address(&acc.seller_trade_state);
+
+    let mut account_metas = acc.to_account_metas(None);
+    account_metas.push(AccountMeta::new(listing_receipt_acc.receipt, false));
+    account_metas.push(AccountMeta::new(*owner_pubkey, true));
+    account_metas.push(lien_account_meta(&ahkey, &test_metadata.mint.pubkey()));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::SellWithReceipt {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            buyer_price: 1,
+            token_size: 1,
+            receipt_bump,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(owner_pubkey),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let sts = context
+        .banks_client
+        .get_account(acc.seller_trade_state)
+        .await
+        .expect("Error Getting Trade State")
+        .expect("Trade State Empty");
+    assert_eq!(sts.data.len(), 1);
+
+    let listing_receipt_account = context
+        .banks_client
+        .get_account(listing_receipt_acc.receipt)
+        .await
+        .expect("getting listing receipt")
+        .expect("empty listing receipt data");
+    let listing_receipt =
+        ListingReceipt::try_deserialize(&mut listing_receipt_account.data.as_ref()).unwrap();
+
+    assert_eq!(listing_receipt.trade_state, acc.seller_trade_state);
+    assert_eq!(listing_receipt.auction_house, acc.auction_house);
+    assert_eq!(listing_receipt.metadata, acc.metadata);
+    assert_eq!(listing_receipt.bookkeeper, *owner_pubkey);
+    assert_eq!(listing_receipt.seller, *owner_pubkey);
+    assert_eq!(listing_receipt.price, 1);
+    assert_eq!(listing_receipt.token_size, 1);
+    assert_eq!(listing_receipt.trade_state_bump, sts_bump);
+    assert_eq!(listing_receipt.canceled_at, None);
+}
+
 #[tokio::test]
 async fn sell_pnft_success() {
     let mut context = auction_house_program_test().start_with_context().await;
@@ -183,6 +285,58 @@ async fn sell_pnft_success() {
     assert_eq!(listing_receipt.token_size, 1);
 }
 
+#[tokio::test]
+async fn sell_pnft_with_transfer_blocking_rule_set_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let payer = context.payer.dirty_clone();
+
+    let (rule_set, auth_data) = create_transfer_blocking_rule_set(&mut context, payer).await;
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create_via_builder(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            None,
+            None,
+            true,
+            TokenStandard::ProgrammableNonFungible,
+            None,
+            Some(rule_set),
+            Some(0),
+            Some(PrintSupply::Zero),
+        )
+        .await
+        .unwrap();
+
+    test_metadata
+        .mint_via_builder(&mut context, 1, Some(auth_data))
+        .await
+        .unwrap();
+
+    let (_, sell_pnft_tx) = sell_pnft(&mut context, &ahkey, &ah, &test_metadata, &rule_set, 1, 1);
+
+    let err = context
+        .banks_client
+        .process_transaction(sell_pnft_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, TRANSFER_BLOCKED_BY_RULE_SET);
+}
+
 #[tokio::test]
 async fn auctioneer_sell_success() {
     let mut context = auction_house_program_test().start_with_context().await;
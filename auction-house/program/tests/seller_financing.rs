@@ -0,0 +1,302 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::{
+    find_escrow_payment_address, find_lien_address, find_program_as_signer_address,
+    find_trade_state_address,
+};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use solana_program::{system_program, sysvar};
+
+#[tokio::test]
+async fn set_seller_financing_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    set_seller_financing(&mut context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let auction_house_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .expect("Error getting auction house")
+        .expect("Auction house empty");
+    let auction_house = AuctionHouse::try_deserialize(&mut auction_house_account.data.as_ref())
+        .expect("Failed to deserialize auction house data");
+
+    assert!(auction_house.allow_seller_financing);
+}
+
+#[tokio::test]
+async fn sell_unencumbered_mint_with_seller_financing() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_seller_financing(&mut context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((acc, _), _) = sell(&mut context, &ahkey, &ah, &test_metadata, 1, 1);
+    let (_, sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+        1,
+    );
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, pas_bump) = find_program_as_signer_address();
+    let (lien, _) = find_lien_address(&ahkey, &test_metadata.mint.pubkey());
+
+    let mut account_metas = acc.to_account_metas(None);
+    account_metas.push(AccountMeta::new_readonly(lien, false));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 1,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let sts = context
+        .banks_client
+        .get_account(acc.seller_trade_state)
+        .await
+        .expect("Error Getting Trade State")
+        .expect("Trade State Empty");
+    assert_eq!(sts.data.len(), 1);
+}
+
+#[tokio::test]
+async fn relist_after_disabling_seller_financing_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_seller_financing(&mut context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        ONE_SOL,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    // The buyer takes the token on a down payment, leaving a lien on the mint.
+    let buyer_token_account =
+        get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey());
+    let (lien, _) = find_lien_address(&ahkey, &test_metadata.mint.pubkey());
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+    let accounts = mpl_auction_house::accounts::ExecuteFinancedSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        token_account: sell_acc.token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        treasury_mint: ah.treasury_mint,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: buyer_token_account,
+        authority: ah.authority,
+        auction_house: ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        seller_trade_state: sell_acc.seller_trade_state,
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        program_as_signer: sell_acc.program_as_signer,
+        rent: sysvar::rent::id(),
+        lien,
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteFinancedSale {
+            escrow_payment_bump: escrow_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+            lien_amount: ONE_SOL,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer, &test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    set_seller_financing(&mut context, &ahkey, &ah_authority, false)
+        .await
+        .unwrap();
+
+    // Turning financing off does not release the lien, so the buyer still cannot relist.
+    let (seller_trade_state, sts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        &ahkey,
+        &buyer_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        2 * ONE_SOL,
+        1,
+    );
+    let (free_seller_trade_state, free_sts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        &ahkey,
+        &buyer_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let mut accounts = mpl_auction_house::accounts::Sell {
+        wallet: buyer.pubkey(),
+        token_account: buyer_token_account,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        program_as_signer: sell_acc.program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(lien_account_meta(&ahkey, &test_metadata.mint.pubkey()));
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 2 * ONE_SOL,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[&buyer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, LIEN_OUTSTANDING);
+}
@@ -0,0 +1,336 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::find_dispute_record_address, DisputeResolution, ProtectedSettlement, SettlementMarker,
+    SettlementStatus,
+};
+use solana_program::{system_program, sysvar};
+
+fn assert_settlement_status_tx(
+    context: &ProgramTestContext,
+    payer: &Keypair,
+    sale: &ProtectedSale,
+    expected: SettlementStatus,
+) -> Transaction {
+    let accounts = mpl_auction_house::accounts::AssertSettlementStatus {
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::AssertSettlementStatus { expected }.data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer.pubkey()),
+        &[payer],
+        context.last_blockhash,
+    )
+}
+
+async fn settlement_marker(
+    context: &mut ProgramTestContext,
+    sale: &ProtectedSale,
+) -> SettlementMarker {
+    let marker_account = context
+        .banks_client
+        .get_account(sale.settlement_marker)
+        .await
+        .expect("Error getting settlement marker")
+        .expect("Settlement marker empty");
+    SettlementMarker::try_deserialize(&mut marker_account.data.as_ref())
+        .expect("Failed to deserialize settlement marker data")
+}
+
+#[tokio::test]
+async fn settlement_marker_tracks_escrow_and_rollback() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 86_400, 1_000)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+
+    let marker = settlement_marker(&mut context, &sale).await;
+    assert_eq!(marker.protected_settlement, sale.protected_settlement);
+    assert_eq!(marker.status, SettlementStatus::Escrowed);
+    assert_eq!(marker.attempts, 1);
+
+    // A retry that expects the sale to be done learns it is still escrowed.
+    let tx = assert_settlement_status_tx(&context, &ah_authority, &sale, SettlementStatus::Settled);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, SETTLEMENT_PARTIALLY_SETTLED);
+
+    let settlement_account = context
+        .banks_client
+        .get_account(sale.protected_settlement)
+        .await
+        .unwrap()
+        .unwrap();
+    let settlement =
+        ProtectedSettlement::try_deserialize(&mut settlement_account.data.as_ref()).unwrap();
+    let accounts = mpl_auction_house::accounts::CancelProtectedSettlement {
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: settlement.rent_payer,
+        seller_token_account: sale.seller_token_account,
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CancelProtectedSettlement {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority, &sale.buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let marker = settlement_marker(&mut context, &sale).await;
+    assert_eq!(marker.status, SettlementStatus::RolledBack);
+
+    // A rolled back sale may be executed again from the start.
+    let tx =
+        assert_settlement_status_tx(&context, &ah_authority, &sale, SettlementStatus::NotStarted);
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+async fn protected_settlement(
+    context: &mut ProgramTestContext,
+    sale: &ProtectedSale,
+) -> ProtectedSettlement {
+    let settlement_account = context
+        .banks_client
+        .get_account(sale.protected_settlement)
+        .await
+        .expect("Error getting protected settlement")
+        .expect("Protected settlement empty");
+    ProtectedSettlement::try_deserialize(&mut settlement_account.data.as_ref())
+        .expect("Failed to deserialize protected settlement data")
+}
+
+#[tokio::test]
+async fn protected_sale_repeats_after_cancel_finalize_and_resolve() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_buyer_protection(&mut context, &ahkey, &ah_authority, 60, 0)
+        .await
+        .unwrap();
+    let arbiter = Keypair::new();
+    airdrop(&mut context, &arbiter.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    set_arbiter(&mut context, &ahkey, &ah_authority, &arbiter.pubkey())
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let sale =
+        execute_protected_sale(&mut context, &ah, &ahkey, &ah_authority, &test_metadata).await;
+    let buyer_receipt_token_account =
+        get_associated_token_address(&sale.buyer.pubkey(), &test_metadata.mint.pubkey());
+
+    // The buyer cancels, sending the token back to the seller.
+    let settlement = protected_settlement(&mut context, &sale).await;
+    let accounts = mpl_auction_house::accounts::CancelProtectedSettlement {
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: settlement.rent_payer,
+        seller_token_account: sale.seller_token_account,
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CancelProtectedSettlement {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&sale.buyer.pubkey()),
+        &[&sale.buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    context.warp_to_slot(100).unwrap();
+    let sale = execute_protected_sale_to(
+        &mut context,
+        &ah,
+        &ahkey,
+        &ah_authority,
+        &test_metadata,
+        sale.buyer,
+    )
+    .await;
+    assert_eq!(
+        settlement_marker(&mut context, &sale).await.status,
+        SettlementStatus::Escrowed
+    );
+
+    // Anyone finalizes once the protection window has passed, then the buyer sends the token
+    // back to the seller.
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 120;
+    context.set_sysvar(&clock);
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let settlement = protected_settlement(&mut context, &sale).await;
+    let accounts = mpl_auction_house::accounts::FinalizeProtectedSettlement {
+        payer: cranker.pubkey(),
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: settlement.rent_payer,
+        buyer_receipt_token_account,
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let finalize = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::FinalizeProtectedSettlement {}.data(),
+        accounts,
+    };
+    let return_token = spl_token::instruction::transfer(
+        &spl_token::id(),
+        &buyer_receipt_token_account,
+        &sale.seller_token_account,
+        &sale.buyer.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[finalize, return_token],
+        Some(&cranker.pubkey()),
+        &[&cranker, &sale.buyer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    context.warp_to_slot(200).unwrap();
+    let sale = execute_protected_sale_to(
+        &mut context,
+        &ah,
+        &ahkey,
+        &ah_authority,
+        &test_metadata,
+        sale.buyer,
+    )
+    .await;
+
+    // The seller disputes and the arbiter rules for the buyer, sending the token back.
+    let (dispute_record, _) = find_dispute_record_address(&sale.protected_settlement);
+    let accounts = mpl_auction_house::accounts::OpenDispute {
+        wallet: test_metadata.token.pubkey(),
+        protected_settlement: sale.protected_settlement,
+        dispute_record,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::OpenDispute {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let settlement = protected_settlement(&mut context, &sale).await;
+    let accounts = mpl_auction_house::accounts::ResolveDispute {
+        arbiter: arbiter.pubkey(),
+        buyer: sale.buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        rent_payer: settlement.rent_payer,
+        seller_token_account: sale.seller_token_account,
+        buyer_receipt_token_account,
+        escrow_token_account: sale.escrow_token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        protected_settlement: sale.protected_settlement,
+        settlement_marker: sale.settlement_marker,
+        dispute_record,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ResolveDispute {
+            resolution: DisputeResolution::Buyer,
+        }
+        .data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&arbiter.pubkey()),
+        &[&arbiter],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    context.warp_to_slot(300).unwrap();
+    let sale = execute_protected_sale_to(
+        &mut context,
+        &ah,
+        &ahkey,
+        &ah_authority,
+        &test_metadata,
+        sale.buyer,
+    )
+    .await;
+
+    let marker = settlement_marker(&mut context, &sale).await;
+    assert_eq!(marker.status, SettlementStatus::Escrowed);
+    assert_eq!(marker.attempts, 4);
+}
@@ -0,0 +1,259 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{pda::find_purchase_receipt_address, receipt::PurchaseReceipt};
+use solana_program::program_pack::Pack;
+use spl_token::state::Account;
+
+/// Mint `supply` editions of a semi-fungible token to a funded seller.
+async fn create_sft(context: &mut ProgramTestContext, supply: u64) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            supply,
+        )
+        .await
+        .unwrap();
+
+    test_metadata
+}
+
+async fn token_account(context: &mut ProgramTestContext, address: Pubkey) -> Account {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    Account::unpack_from_slice(&account.data).unwrap()
+}
+
+async fn purchase_receipt(context: &mut ProgramTestContext, address: Pubkey) -> PurchaseReceipt {
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    PurchaseReceipt::try_deserialize(&mut account.data.as_ref()).unwrap()
+}
+
+#[tokio::test]
+async fn sell_delegates_only_listed_quantity() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context, 10).await;
+
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 4 * ONE_SOL, 4);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let seller_token = token_account(&mut context, sell_acc.token_account).await;
+    assert_eq!(seller_token.amount, 10);
+    assert_eq!(seller_token.delegated_amount, 4);
+}
+
+#[tokio::test]
+async fn sell_rejects_zero_token_size() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context, 10).await;
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 0);
+    let error = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, INVALID_TOKEN_AMOUNT);
+}
+
+#[tokio::test]
+async fn sale_from_larger_balance_leaves_remainder_with_seller() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context, 10).await;
+
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 4 * ONE_SOL, 4);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        4 * ONE_SOL,
+        4,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let (_, sale_tx) = execute_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &test_metadata.token.pubkey(),
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        4,
+        4 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(sale_tx)
+        .await
+        .unwrap();
+
+    let buyer_token = token_account(
+        &mut context,
+        get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey()),
+    )
+    .await;
+    assert_eq!(buyer_token.amount, 4);
+
+    let seller_token = token_account(&mut context, sell_acc.token_account).await;
+    assert_eq!(seller_token.amount, 6);
+    assert_eq!(seller_token.delegated_amount, 0);
+
+    let seller_trade_state = context
+        .banks_client
+        .get_account(sell_acc.seller_trade_state)
+        .await
+        .unwrap();
+    assert!(seller_trade_state.is_none());
+
+    let (receipt, _) =
+        find_purchase_receipt_address(&sell_acc.seller_trade_state, &bid_acc.buyer_trade_state);
+    let receipt = purchase_receipt(&mut context, receipt).await;
+    assert_eq!(receipt.token_size, 4);
+    assert_eq!(receipt.price, 4 * ONE_SOL);
+}
+
+#[tokio::test]
+async fn partial_fill_keeps_listing_and_records_filled_quantity() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = create_sft(&mut context, 10).await;
+
+    let ((sell_acc, _), sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, 6 * ONE_SOL, 6);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        2 * ONE_SOL,
+        2,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let (_, sale_tx) = execute_partial_sale(
+        &mut context,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &test_metadata.token.pubkey(),
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        6,
+        6 * ONE_SOL,
+        2,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(sale_tx)
+        .await
+        .unwrap();
+
+    let buyer_token = token_account(
+        &mut context,
+        get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey()),
+    )
+    .await;
+    assert_eq!(buyer_token.amount, 2);
+
+    // The rest of the listing stays delegated and open for further fills.
+    let seller_token = token_account(&mut context, sell_acc.token_account).await;
+    assert_eq!(seller_token.amount, 8);
+    assert_eq!(seller_token.delegated_amount, 4);
+
+    let seller_trade_state = context
+        .banks_client
+        .get_account(sell_acc.seller_trade_state)
+        .await
+        .unwrap();
+    assert!(seller_trade_state.is_some());
+
+    let (receipt, _) =
+        find_purchase_receipt_address(&sell_acc.seller_trade_state, &bid_acc.buyer_trade_state);
+    let receipt = purchase_receipt(&mut context, receipt).await;
+    assert_eq!(receipt.token_size, 2);
+    assert_eq!(receipt.price, 2 * ONE_SOL);
+}
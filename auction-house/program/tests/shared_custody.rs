@@ -0,0 +1,280 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::{find_escrow_payment_address, find_program_as_signer_address, find_trade_state_address},
+    CoOwner, SharedCustody,
+};
+use solana_program::{system_program, sysvar};
+
+/// Deposit a new token into shared custody for two co-owners holding 60% and 40%, both of whom
+/// must approve a listing. Returns the auction house, the depositor's metadata, the shared
+/// custody and the co-owners.
+async fn shared_custody_test_context(
+    context: &mut ProgramTestContext,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    Pubkey,
+    Keypair,
+    Keypair,
+) {
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(context).await.unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let owner_a = Keypair::new();
+    let owner_b = Keypair::new();
+    airdrop(context, &owner_a.pubkey(), ONE_SOL).await.unwrap();
+    airdrop(context, &owner_b.pubkey(), ONE_SOL).await.unwrap();
+    let shared_custody = create_shared_custody(
+        context,
+        &ahkey,
+        &test_metadata,
+        vec![
+            CoOwner {
+                wallet: owner_a.pubkey(),
+                share_basis_points: 6000,
+            },
+            CoOwner {
+                wallet: owner_b.pubkey(),
+                share_basis_points: 4000,
+            },
+        ],
+        2,
+    )
+    .await
+    .unwrap();
+
+    (
+        ah,
+        ahkey,
+        ah_authority,
+        test_metadata,
+        shared_custody,
+        owner_a,
+        owner_b,
+    )
+}
+
+#[tokio::test]
+async fn co_listed_sale_splits_proceeds_by_share() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority, test_metadata, shared_custody, owner_a, owner_b) =
+        shared_custody_test_context(&mut context).await;
+    let token_mint = test_metadata.mint.pubkey();
+
+    let seller_trade_state = co_list(
+        &mut context,
+        &ahkey,
+        &ah,
+        &shared_custody,
+        &token_mint,
+        &owner_a,
+        ONE_SOL,
+    )
+    .await
+    .unwrap();
+    assert!(context
+        .banks_client
+        .get_account(seller_trade_state)
+        .await
+        .unwrap()
+        .is_none());
+
+    co_list(
+        &mut context,
+        &ahkey,
+        &ah,
+        &shared_custody,
+        &token_mint,
+        &owner_b,
+        ONE_SOL,
+    )
+    .await
+    .unwrap();
+    let custody_account = context
+        .banks_client
+        .get_account(shared_custody)
+        .await
+        .unwrap()
+        .unwrap();
+    let custody_data = SharedCustody::try_deserialize(&mut custody_account.data.as_ref()).unwrap();
+    assert!(custody_data.is_listed);
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &shared_custody,
+        &buyer,
+        ONE_SOL,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let custody_token_account = get_associated_token_address(&shared_custody, &token_mint);
+    let (free_trade_state, free_sts_bump) = find_trade_state_address(
+        &shared_custody,
+        &ahkey,
+        &custody_token_account,
+        &ah.treasury_mint,
+        &token_mint,
+        0,
+        1,
+    );
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: shared_custody,
+        auction_house: ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: custody_token_account,
+        authority: ah.authority,
+        seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: Some(free_trade_state),
+        seller_payment_receipt_account: shared_custody,
+        buyer_receipt_token_account: get_associated_token_address(&buyer.pubkey(), &token_mint),
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: ONE_SOL,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let custody_account = context
+        .banks_client
+        .get_account(shared_custody)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let proceeds = custody_account.lamports - rent.minimum_balance(custody_account.data.len());
+    let owner_a_before = context
+        .banks_client
+        .get_balance(owner_a.pubkey())
+        .await
+        .unwrap();
+    let owner_b_before = context
+        .banks_client
+        .get_balance(owner_b.pubkey())
+        .await
+        .unwrap();
+
+    settle_shared_custody(
+        &mut context,
+        &shared_custody,
+        &test_metadata.token.pubkey(),
+        &token_mint,
+        &[owner_a.pubkey(), owner_b.pubkey()],
+    )
+    .await
+    .unwrap();
+
+    let owner_a_after = context
+        .banks_client
+        .get_balance(owner_a.pubkey())
+        .await
+        .unwrap();
+    let owner_b_after = context
+        .banks_client
+        .get_balance(owner_b.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(owner_a_after - owner_a_before, proceeds * 6000 / 10000);
+    assert_eq!(
+        owner_b_after - owner_b_before,
+        proceeds - proceeds * 6000 / 10000
+    );
+    assert!(context
+        .banks_client
+        .get_account(shared_custody)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn co_list_by_non_owner_failure() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _, test_metadata, shared_custody, _, _) =
+        shared_custody_test_context(&mut context).await;
+
+    let stranger = Keypair::new();
+    airdrop(&mut context, &stranger.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let err = co_list(
+        &mut context,
+        &ahkey,
+        &ah,
+        &shared_custody,
+        &test_metadata.mint.pubkey(),
+        &stranger,
+        ONE_SOL,
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, NOT_CO_OWNER);
+}
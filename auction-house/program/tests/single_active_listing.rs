@@ -0,0 +1,178 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::{find_active_listing_address, find_program_as_signer_address, find_trade_state_address},
+    ActiveListing,
+};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+
+/// A signed `sell` transaction of the token at `sale_price`, passing the seller's active listing
+/// account and then `extra_accounts`.
+fn sell_with_active_listing(
+    context: &ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    sale_price: u64,
+    extra_accounts: Vec<AccountMeta>,
+) -> (Pubkey, Transaction) {
+    let seller = test_metadata.token.pubkey();
+    let token = get_associated_token_address(&seller, &test_metadata.mint.pubkey());
+    let (seller_trade_state, trade_state_bump) = find_trade_state_address(
+        &seller,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        1,
+    );
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (program_as_signer, program_as_signer_bump) = find_program_as_signer_address();
+    let (active_listing, _) =
+        find_active_listing_address(ahkey, &seller, &test_metadata.mint.pubkey());
+
+    let mut accounts = mpl_auction_house::accounts::Sell {
+        wallet: seller,
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        token_program: spl_token::id(),
+        system_program: solana_program::system_program::id(),
+        program_as_signer,
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+    accounts.push(AccountMeta::new(active_listing, false));
+    accounts.extend(extra_accounts);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price: sale_price,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+
+    (
+        seller_trade_state,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&seller),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+async fn single_active_listing_context(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Metadata) {
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(context).await.unwrap();
+    set_single_active_listing(context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    (ah, ahkey, test_metadata)
+}
+
+#[tokio::test]
+async fn sell_records_active_listing() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata) = single_active_listing_context(&mut context).await;
+
+    let (seller_trade_state, sell_tx) =
+        sell_with_active_listing(&context, &ahkey, &ah, &test_metadata, ONE_SOL, vec![]);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (active_listing, _) = find_active_listing_address(
+        &ahkey,
+        &test_metadata.token.pubkey(),
+        &test_metadata.mint.pubkey(),
+    );
+    let active_listing_account = context
+        .banks_client
+        .get_account(active_listing)
+        .await
+        .unwrap()
+        .unwrap();
+    let active_listing =
+        ActiveListing::try_deserialize(&mut active_listing_account.data.as_ref()).unwrap();
+    assert_eq!(active_listing.trade_state, seller_trade_state);
+    assert_eq!(active_listing.mint, test_metadata.mint.pubkey());
+}
+
+#[tokio::test]
+async fn second_listing_of_mint_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata) = single_active_listing_context(&mut context).await;
+
+    let (first_trade_state, sell_tx) =
+        sell_with_active_listing(&context, &ahkey, &ah, &test_metadata, ONE_SOL, vec![]);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell_with_active_listing(
+        &context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        2 * ONE_SOL,
+        vec![AccountMeta::new_readonly(first_trade_state, false)],
+    );
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, LISTING_ALREADY_ACTIVE);
+}
@@ -0,0 +1,192 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::{find_escrow_payment_address, find_trade_state_address},
+    SpendingCap,
+};
+use solana_sdk::instruction::AccountMeta;
+
+/// A private bid charged to `spending_cap`, co-signed by `frontend` when it is a signer.
+#[allow(clippy::too_many_arguments)]
+fn capped_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Keypair,
+    frontend: &Keypair,
+    spending_cap: &Pubkey,
+    frontend_signs: bool,
+    buyer_price: u64,
+) -> Transaction {
+    let ((buy_accounts, _), _) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        buyer,
+        buyer_price,
+        1,
+    );
+    let (_, trade_state_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &buy_accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        buyer_price,
+        1,
+    );
+    let (_, escrow_payment_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let mut account_metas = buy_accounts.to_account_metas(None);
+    account_metas.extend([
+        AccountMeta::new(*spending_cap, false),
+        AccountMeta::new_readonly(frontend.pubkey(), frontend_signs),
+    ]);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Buy {
+            trade_state_bump,
+            escrow_payment_bump,
+            buyer_price,
+            token_size: 1,
+        }
+        .data(),
+        accounts: account_metas,
+    };
+
+    if frontend_signs {
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer, frontend],
+            context.last_blockhash,
+        )
+    } else {
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        )
+    }
+}
+
+async fn capped_buyer(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Metadata, Keypair, Keypair, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let frontend = Keypair::new();
+    let spending_cap =
+        create_spending_cap(context, &ahkey, &buyer, &frontend.pubkey(), ONE_SOL * 3 / 2)
+            .await
+            .unwrap();
+
+    (ah, ahkey, test_metadata, buyer, frontend, spending_cap)
+}
+
+#[tokio::test]
+async fn frontend_bids_are_charged_to_the_spending_cap() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, buyer, frontend, spending_cap) =
+        capped_buyer(&mut context).await;
+
+    let bid_tx = capped_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &frontend,
+        &spending_cap,
+        true,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap();
+
+    let spending_cap_account = context
+        .banks_client
+        .get_account(spending_cap)
+        .await
+        .unwrap()
+        .unwrap();
+    let cap = SpendingCap::try_deserialize(&mut spending_cap_account.data.as_ref()).unwrap();
+    assert_eq!(cap.frontend, frontend.pubkey());
+    assert_eq!(cap.spent, ONE_SOL);
+
+    // A second bid through the frontend would take it past its cap.
+    let bid_tx = capped_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &frontend,
+        &spending_cap,
+        true,
+        ONE_SOL * 2,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, SPENDING_CAP_EXCEEDED);
+}
+
+#[tokio::test]
+async fn spending_cap_requires_frontend_signature() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, buyer, frontend, spending_cap) =
+        capped_buyer(&mut context).await;
+
+    let bid_tx = capped_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &frontend,
+        &spending_cap,
+        false,
+        ONE_SOL,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, FRONTEND_MUST_SIGN);
+}
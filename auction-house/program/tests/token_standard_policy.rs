@@ -0,0 +1,102 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::constants::{TOKEN_STANDARD_NFT, TOKEN_STANDARD_PNFT};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+
+#[tokio::test]
+async fn set_token_standard_policy_with_unknown_bits_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = set_token_standard_policy(&mut context, &ahkey, &ah_authority, 1 << 7)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_TOKEN_STANDARD_POLICY);
+}
+
+#[tokio::test]
+async fn sell_nft_on_pnft_only_house_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_token_standard_policy(&mut context, &ahkey, &ah_authority, TOKEN_STANDARD_PNFT)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, TOKEN_STANDARD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn sell_nft_on_nft_house_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_token_standard_policy(
+        &mut context,
+        &ahkey,
+        &ah_authority,
+        TOKEN_STANDARD_NFT | TOKEN_STANDARD_PNFT,
+    )
+    .await
+    .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::{
+    pda::{find_program_as_signer_address, find_trade_state_address},
+    TradeSequence,
+};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+
+#[tokio::test]
+async fn listing_receipt_records_trade_sequence() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let trade_sequence = create_trade_sequence(&mut context, &ahkey, &ah_authority)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_accounts, receipt_accounts), _) =
+        sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    let (_, trade_state_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (_, free_trade_state_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_accounts.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, program_as_signer_bump) = find_program_as_signer_address();
+
+    let mut sell_metas = sell_accounts.to_account_metas(None);
+    sell_metas.push(AccountMeta::new(trade_sequence, false));
+    sell_metas.push(lien_account_meta(&ahkey, &test_metadata.mint.pubkey()));
+    let sell_instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::Sell {
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            buyer_price: ONE_SOL,
+            token_size: 1,
+        }
+        .data(),
+        accounts: sell_metas,
+    };
+
+    let (_, receipt_bump) = find_listing_receipt_address(&sell_accounts.seller_trade_state);
+    let mut receipt_metas = receipt_accounts.to_account_metas(None);
+    receipt_metas.push(AccountMeta::new_readonly(trade_sequence, false));
+    let print_receipt_instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::PrintListingReceipt { receipt_bump }.data(),
+        accounts: receipt_metas,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sell_instruction, print_receipt_instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let trade_sequence_account = context
+        .banks_client
+        .get_account(trade_sequence)
+        .await
+        .unwrap()
+        .unwrap();
+    let trade_sequence =
+        TradeSequence::try_deserialize(&mut trade_sequence_account.data.as_ref()).unwrap();
+    assert_eq!(trade_sequence.last_sequence, 1);
+    assert_eq!(
+        trade_sequence.last_trade_state,
+        sell_accounts.seller_trade_state
+    );
+
+    let receipt_account = context
+        .banks_client
+        .get_account(receipt_accounts.receipt)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt = ListingReceipt::try_deserialize(&mut receipt_account.data.as_ref()).unwrap();
+    assert_eq!(receipt.sequence, Some(1));
+}
+
+#[tokio::test]
+async fn listing_receipt_without_trade_sequence() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((_, receipt_accounts), sell_tx) =
+        sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let receipt_account = context
+        .banks_client
+        .get_account(receipt_accounts.receipt)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt = ListingReceipt::try_deserialize(&mut receipt_account.data.as_ref()).unwrap();
+    assert_eq!(receipt.sequence, None);
+}
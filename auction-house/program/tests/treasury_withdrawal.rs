@@ -0,0 +1,137 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::{helpers::default_scopes, setup_functions::*};
+
+#[tokio::test]
+async fn auctioneer_withdraw_from_treasury_within_limit() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let auctioneer_authority = Keypair::new();
+    airdrop(&mut context, &auctioneer_authority.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        vec![AuthorityScope::WithdrawTreasury],
+    )
+    .await
+    .unwrap();
+
+    create_treasury_withdrawal_limit(&mut context, &ahkey, &ah_authority, ONE_SOL, 86_400)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_treasury, TEN_SOL)
+        .await
+        .unwrap();
+
+    let payee = Pubkey::new_unique();
+    let withdraw_tx = auctioneer_withdraw_from_treasury(
+        &mut context,
+        &ahkey,
+        &ah,
+        &auctioneer_authority,
+        &payee,
+        ONE_SOL / 2,
+    );
+    context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap();
+
+    let payee_account = context
+        .banks_client
+        .get_account(payee)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(payee_account.lamports, ONE_SOL / 2);
+
+    // The second withdrawal would take the period's total over the limit.
+    let withdraw_tx = auctioneer_withdraw_from_treasury(
+        &mut context,
+        &ahkey,
+        &ah,
+        &auctioneer_authority,
+        &payee,
+        ONE_SOL,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, TREASURY_WITHDRAWAL_LIMIT_EXCEEDED);
+}
+
+#[tokio::test]
+async fn auctioneer_withdraw_from_treasury_requires_scope() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let auctioneer_authority = Keypair::new();
+    airdrop(&mut context, &auctioneer_authority.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority.pubkey());
+    delegate_auctioneer(
+        &mut context,
+        ahkey,
+        &ah_authority,
+        auctioneer_authority.pubkey(),
+        auctioneer_pda,
+        default_scopes(),
+    )
+    .await
+    .unwrap();
+
+    create_treasury_withdrawal_limit(&mut context, &ahkey, &ah_authority, ONE_SOL, 86_400)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_treasury, TEN_SOL)
+        .await
+        .unwrap();
+
+    let withdraw_tx = auctioneer_withdraw_from_treasury(
+        &mut context,
+        &ahkey,
+        &ah,
+        &auctioneer_authority,
+        &Pubkey::new_unique(),
+        ONE_SOL / 2,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, MISSING_AUCTIONEER_SCOPE);
+}
+
+#[tokio::test]
+async fn create_treasury_withdrawal_limit_with_unbounded_period_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    // A period this long would overflow the end of the period once a withdrawal starts it.
+    let error =
+        create_treasury_withdrawal_limit(&mut context, &ahkey, &ah_authority, ONE_SOL, i64::MAX)
+            .await
+            .unwrap_err();
+    assert_error!(error, INVALID_TREASURY_WITHDRAWAL_PERIOD);
+}
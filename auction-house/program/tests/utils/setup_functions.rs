@@ -9,13 +9,23 @@ use anchor_lang::*;
 use mpl_auction_house::{
     pda::{
         find_auction_house_address, find_auction_house_fee_account_address,
-        find_auction_house_treasury_address, find_auctioneer_pda,
-        find_auctioneer_trade_state_address, find_bid_receipt_address, find_escrow_payment_address,
-        find_listing_receipt_address, find_program_as_signer_address,
+        find_auction_house_treasury_address, find_auctioneer_allowlist_address,
+        find_auctioneer_pda, find_auctioneer_stats_address, find_auctioneer_trade_state_address,
+        find_authority_log_address, find_bid_expiry_address, find_bid_receipt_address,
+        find_bid_ticket_config_address, find_compliance_config_address,
+        find_delayed_payout_address, find_escrow_payment_address, find_fee_stake_address,
+        find_fee_stake_config_address, find_fee_stake_vault_address, find_fee_sweep_config_address,
+        find_leaderboard_address, find_lien_address, find_listing_receipt_address,
+        find_marketplace_binding_address, find_omnibus_custodian_address,
+        find_price_history_address, find_program_as_signer_address,
+        find_protected_settlement_address, find_public_bid_index_address,
         find_public_bid_trade_state_address, find_purchase_receipt_address,
-        find_trade_state_address,
+        find_receipt_tree_address, find_royalty_bonus_address, find_settlement_marker_address,
+        find_shared_custody_address, find_spending_cap_address, find_tax_withholding_address,
+        find_trade_sequence_address, find_trade_state_address,
+        find_treasury_withdrawal_limit_address, find_voucher_address, find_wanted_listing_address,
     },
-    AuctionHouse, AuthorityScope,
+    AuctionHouse, AuthorityScope, CoOwner, HouseBalances, RoundingPolicy, WantedCriteria,
 };
 
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
@@ -38,7 +48,9 @@ use rmp_serde::Serializer;
 use serde::Serialize;
 use solana_program_test::*;
 use solana_sdk::{
-    compute_budget::ComputeBudgetInstruction, instruction::Instruction, transaction::Transaction,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    transaction::Transaction,
 };
 use spl_associated_token_account::get_associated_token_address;
 
@@ -66,6 +78,7 @@ pub async fn create_auction_house(
     seller_fee_basis_points: u16,
     requires_sign_off: bool,
     can_change_sale_price: bool,
+    immutable: bool,
 ) -> StdResult<Pubkey, BanksClientError> {
     let accounts = mpl_auction_house::accounts::CreateAuctionHouse {
         treasury_mint: *t_mint_key,
@@ -91,6 +104,7 @@ pub async fn create_auction_house(
         seller_fee_basis_points,
         requires_sign_off,
         can_change_sale_price,
+        immutable,
     }
     .data();
 
@@ -114,6 +128,52 @@ pub async fn create_auction_house(
         .map(|_| *auction_house_key)
 }
 
+pub async fn update_auction_house(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    seller_fee_basis_points: Option<u16>,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::UpdateAuctionHouse {
+        treasury_mint: ah.treasury_mint,
+        payer: authority.pubkey(),
+        authority: authority.pubkey(),
+        new_authority: ah.authority,
+        fee_withdrawal_destination: ah.fee_withdrawal_destination,
+        treasury_withdrawal_destination: ah.treasury_withdrawal_destination,
+        treasury_withdrawal_destination_owner: ah.treasury_withdrawal_destination,
+        auction_house: *ahkey,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::UpdateAuctionHouse {
+        seller_fee_basis_points,
+        requires_sign_off: None,
+        can_change_sale_price: None,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
 pub fn deposit(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -671,6 +731,124 @@ pub fn execute_sale(
     ((execute_sale_accounts, print_purchase_receipt_accounts), tx)
 }
 
+pub fn execute_partial_sale(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    token_account: &Pubkey,
+    seller_trade_state: &Pubkey,
+    buyer_trade_state: &Pubkey,
+    token_size: u64,
+    buyer_price: u64,
+    partial_order_size: u64,
+    partial_order_price: u64,
+) -> (
+    (
+        mpl_auction_house::accounts::ExecutePartialSale,
+        mpl_auction_house::accounts::PrintPurchaseReceipt,
+    ),
+    Transaction,
+) {
+    let program_id = mpl_auction_house::id();
+    let buyer_token_account = get_associated_token_address(buyer, &test_metadata.mint.pubkey());
+
+    let (program_as_signer, pas_bump) = find_program_as_signer_address();
+
+    let (free_trade_state, free_sts_bump) = find_trade_state_address(
+        seller,
+        ahkey,
+        token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        token_size,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, buyer);
+    let (purchase_receipt, purchase_receipt_bump) =
+        find_purchase_receipt_address(seller_trade_state, buyer_trade_state);
+    let (listing_receipt, _listing_receipt_bump) = find_listing_receipt_address(seller_trade_state);
+    let (bid_receipt, _bid_receipt_bump) = find_bid_receipt_address(buyer_trade_state);
+    let execute_partial_sale_accounts = mpl_auction_house::accounts::ExecutePartialSale {
+        buyer: *buyer,
+        seller: *seller,
+        auction_house: *ahkey,
+        token_account: *token_account,
+        token_mint: test_metadata.mint.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        seller_trade_state: *seller_trade_state,
+        buyer_trade_state: *buyer_trade_state,
+        free_trade_state: Some(free_trade_state),
+        seller_payment_receipt_account: *seller,
+        buyer_receipt_token_account: buyer_token_account,
+        escrow_payment_account,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        program_as_signer,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    };
+
+    let execute_partial_sale_instruction = Instruction {
+        program_id,
+        data: mpl_auction_house::instruction::ExecutePartialSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size,
+            buyer_price,
+            partial_order_size: Some(partial_order_size),
+            partial_order_price: Some(partial_order_price),
+        }
+        .data(),
+        accounts: execute_partial_sale_accounts.to_account_metas(None),
+    };
+
+    let print_purchase_receipt_accounts = mpl_auction_house::accounts::PrintPurchaseReceipt {
+        purchase_receipt,
+        listing_receipt,
+        bid_receipt,
+        bookkeeper: authority.pubkey(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+        instruction: sysvar::instructions::id(),
+    };
+
+    let print_purchase_receipt_instruction = Instruction {
+        program_id,
+        data: mpl_auction_house::instruction::PrintPurchaseReceipt {
+            purchase_receipt_bump,
+        }
+        .data(),
+        accounts: print_purchase_receipt_accounts.to_account_metas(None),
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            execute_partial_sale_instruction,
+            print_purchase_receipt_instruction,
+        ],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    (
+        (
+            execute_partial_sale_accounts,
+            print_purchase_receipt_accounts,
+        ),
+        tx,
+    )
+}
+
 pub fn auctioneer_execute_sale(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -758,6 +936,12 @@ pub fn auctioneer_execute_sale(
     (execute_sale_accounts, tx)
 }
 
+/// The lien account every sell passes in its remaining accounts, uninitialized unless `mint` was
+/// sold with seller financing.
+pub fn lien_account_meta(ahkey: &Pubkey, mint: &Pubkey) -> AccountMeta {
+    AccountMeta::new_readonly(find_lien_address(ahkey, mint).0, false)
+}
+
 pub fn sell_mint(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -809,7 +993,8 @@ pub fn sell_mint(
         program_as_signer: pas,
         rent: sysvar::rent::id(),
     };
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, test_metadata_mint));
 
     let data = mpl_auction_house::instruction::Sell {
         trade_state_bump: sts_bump,
@@ -898,7 +1083,8 @@ pub fn auctioneer_sell_mint(
         program_as_signer: pas,
         rent: sysvar::rent::id(),
     };
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, test_metadata_mint));
 
     let data = mpl_auction_house::instruction::AuctioneerSell {
         trade_state_bump: sts_bump,
@@ -978,7 +1164,8 @@ pub fn sell(
         program_as_signer: pas,
         rent: sysvar::rent::id(),
     };
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
 
     let data = mpl_auction_house::instruction::Sell {
         trade_state_bump: sts_bump,
@@ -1077,6 +1264,7 @@ pub fn sell_multiple_signers(
         rent: sysvar::rent::id(),
     };
     let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
 
     for account in account_metas.iter_mut() {
         if account.pubkey == ah.authority {
@@ -1193,6 +1381,7 @@ pub fn sell_pnft(
     };
 
     let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
 
     account_metas.append(&mut p_nft_accounts.to_account_metas(None));
 
@@ -1307,6 +1496,7 @@ pub fn auctioneer_sell_pnft(
     };
 
     let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
 
     account_metas.append(&mut p_nft_accounts.to_account_metas(None));
 
@@ -1391,7 +1581,8 @@ pub fn auctioneer_sell(
         program_as_signer: pas,
         rent: sysvar::rent::id(),
     };
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
 
     let data = mpl_auction_house::instruction::AuctioneerSell {
         trade_state_bump: sts_bump,
@@ -1453,129 +1644,2453 @@ pub async fn delegate_auctioneer(
     context.banks_client.process_transaction(tx).await
 }
 
-pub fn withdraw(
+pub async fn rotate_auctioneer(
     context: &mut ProgramTestContext,
-    buyer: &Keypair,
     ahkey: &Pubkey,
-    ah: &AuctionHouse,
-    test_metadata: &Metadata,
-    sale_price: u64,
-    withdraw_amount: u64,
-) -> ((mpl_auction_house::accounts::Withdraw,), Transaction) {
-    let seller_token_account =
-        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
-    let (_buyer_trade_state, _sts_bump) = find_trade_state_address(
-        &buyer.pubkey(),
-        ahkey,
-        &seller_token_account,
-        &ah.treasury_mint,
-        &test_metadata.mint.pubkey(),
-        sale_price,
-        1,
-    );
-    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
-
-    let accounts = mpl_auction_house::accounts::Withdraw {
-        wallet: buyer.pubkey(),
-        escrow_payment_account,
-        receipt_account: buyer.pubkey(),
-        treasury_mint: ah.treasury_mint,
-        authority: ah.authority,
+    authority: &Keypair,
+    auctioneer_authority: &Pubkey,
+    new_auctioneer_authority: &Pubkey,
+    grace_period_seconds: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(ahkey, auctioneer_authority);
+    let (new_ah_auctioneer_pda, _) = find_auctioneer_pda(ahkey, new_auctioneer_authority);
+    let accounts = mpl_auction_house::accounts::RotateAuctioneer {
         auction_house: *ahkey,
-        auction_house_fee_account: ah.auction_house_fee_account,
-        token_program: spl_token::id(),
+        authority: authority.pubkey(),
+        ah_auctioneer_pda,
+        new_auctioneer_authority: *new_auctioneer_authority,
+        new_ah_auctioneer_pda,
         system_program: system_program::id(),
-        ata_program: spl_associated_token_account::id(),
-        rent: sysvar::rent::id(),
-    };
-
-    let accounts_metas = accounts.to_account_metas(None);
+    }
+    .to_account_metas(None);
 
-    let data = mpl_auction_house::instruction::Withdraw {
-        escrow_payment_bump: escrow_bump,
-        amount: withdraw_amount,
+    let data = mpl_auction_house::instruction::RotateAuctioneer {
+        grace_period_seconds,
     }
     .data();
 
     let instruction = Instruction {
         program_id: mpl_auction_house::id(),
         data,
-        accounts: accounts_metas,
+        accounts,
     };
+
     let tx = Transaction::new_signed_with_payer(
         &[instruction],
-        Some(&buyer.pubkey()),
-        &[buyer],
+        Some(&authority.pubkey()),
+        &[authority],
         context.last_blockhash,
     );
 
-    ((accounts,), tx)
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| new_ah_auctioneer_pda)
 }
 
-pub fn auctioneer_withdraw(
+pub async fn create_auctioneer_stats(
     context: &mut ProgramTestContext,
-    buyer: &Keypair,
     ahkey: &Pubkey,
-    ah: &AuctionHouse,
-    test_metadata: &Metadata,
-    auctioneer_authority: &Keypair,
-    sale_price: u64,
-    withdraw_amount: u64,
-) -> (mpl_auction_house::accounts::AuctioneerWithdraw, Transaction) {
-    let seller_token_account =
-        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
-    let (_buyer_trade_state, _sts_bump) = find_trade_state_address(
-        &buyer.pubkey(),
-        ahkey,
-        &seller_token_account,
-        &ah.treasury_mint,
-        &test_metadata.mint.pubkey(),
-        sale_price,
-        1,
-    );
-    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority.pubkey());
-    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
-
-    let accounts = mpl_auction_house::accounts::AuctioneerWithdraw {
-        wallet: buyer.pubkey(),
-        escrow_payment_account,
-        receipt_account: buyer.pubkey(),
-        treasury_mint: ah.treasury_mint,
-        authority: ah.authority,
-        auctioneer_authority: auctioneer_authority.pubkey(),
+    authority: &Keypair,
+    auctioneer_authority: &Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(ahkey, auctioneer_authority);
+    let (auctioneer_stats, _) = find_auctioneer_stats_address(ahkey, auctioneer_authority);
+    let accounts = mpl_auction_house::accounts::CreateAuctioneerStats {
+        authority: authority.pubkey(),
         auction_house: *ahkey,
-        auction_house_fee_account: ah.auction_house_fee_account,
-        ah_auctioneer_pda: auctioneer_pda,
-        token_program: spl_token::id(),
+        ah_auctioneer_pda,
+        auctioneer_stats,
         system_program: system_program::id(),
-        ata_program: spl_associated_token_account::id(),
-        rent: sysvar::rent::id(),
-    };
-
-    let accounts_metas = accounts.to_account_metas(None);
-
-    let data = mpl_auction_house::instruction::AuctioneerWithdraw {
-        escrow_payment_bump: escrow_bump,
-        amount: withdraw_amount,
     }
-    .data();
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateAuctioneerStats {}.data();
 
     let instruction = Instruction {
         program_id: mpl_auction_house::id(),
         data,
-        accounts: accounts_metas,
+        accounts,
     };
+
     let tx = Transaction::new_signed_with_payer(
         &[instruction],
-        Some(&buyer.pubkey()),
-        &[buyer, auctioneer_authority],
+        Some(&authority.pubkey()),
+        &[authority],
         context.last_blockhash,
     );
 
-    (accounts, tx)
-}
-
-pub async fn existing_auction_house_test_context(
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| auctioneer_stats)
+}
+
+pub async fn create_auctioneer_allowlist(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    programs: Vec<Pubkey>,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (auctioneer_allowlist, _) = find_auctioneer_allowlist_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateAuctioneerAllowlist {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        auctioneer_allowlist,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateAuctioneerAllowlist { programs }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| auctioneer_allowlist)
+}
+
+pub async fn create_compliance_config(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    jurisdiction_code: [u8; 2],
+    compliance_program: Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (compliance_config, _) = find_compliance_config_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateComplianceConfig {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        compliance_config,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateComplianceConfig {
+        jurisdiction_code,
+        compliance_program,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| compliance_config)
+}
+
+pub async fn update_compliance_config(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    jurisdiction_code: [u8; 2],
+    compliance_program: Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let (compliance_config, _) = find_compliance_config_address(ahkey);
+    let accounts = mpl_auction_house::accounts::UpdateComplianceConfig {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        compliance_config,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::UpdateComplianceConfig {
+        jurisdiction_code,
+        compliance_program,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_bid_ticket_config(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    collection: Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (bid_ticket_config, _) = find_bid_ticket_config_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateBidTicketConfig {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        bid_ticket_config,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateBidTicketConfig { collection }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| bid_ticket_config)
+}
+
+pub async fn update_bid_ticket_config(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    collection: Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let (bid_ticket_config, _) = find_bid_ticket_config_address(ahkey);
+    let accounts = mpl_auction_house::accounts::UpdateBidTicketConfig {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        bid_ticket_config,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::UpdateBidTicketConfig { collection }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_marketplace_binding(
+    context: &mut ProgramTestContext,
+    collection_mint: &Pubkey,
+    update_authority: &Keypair,
+    approved_houses: Vec<Pubkey>,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (collection_metadata, _) = find_metadata_account(collection_mint);
+    let (marketplace_binding, _) = find_marketplace_binding_address(collection_mint);
+    let accounts = mpl_auction_house::accounts::CreateMarketplaceBinding {
+        update_authority: update_authority.pubkey(),
+        collection_mint: *collection_mint,
+        collection_metadata,
+        marketplace_binding,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateMarketplaceBinding { approved_houses }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&update_authority.pubkey()),
+        &[update_authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| marketplace_binding)
+}
+
+pub async fn create_tax_withholding(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    withholding_basis_points: u16,
+    recipient: Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (tax_withholding, _) = find_tax_withholding_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateTaxWithholding {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        tax_withholding,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateTaxWithholding {
+        withholding_basis_points,
+        recipient,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| tax_withholding)
+}
+
+pub async fn create_fee_sweep_config(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    threshold: u64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (fee_sweep_config, _) = find_fee_sweep_config_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateFeeSweepConfig {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        fee_sweep_config,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateFeeSweepConfig { threshold }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| fee_sweep_config)
+}
+
+pub async fn sweep_fee_to_treasury(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+) -> StdResult<(), BanksClientError> {
+    let (fee_sweep_config, _) = find_fee_sweep_config_address(ahkey);
+    let accounts = mpl_auction_house::accounts::SweepFeeToTreasury {
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        fee_sweep_config,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SweepFeeToTreasury {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_low_fee_balance_threshold(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    threshold: u64,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetLowFeeBalanceThreshold {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetLowFeeBalanceThreshold { threshold }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn fund_fee_account(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    funder: &Keypair,
+    amount: u64,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::FundFeeAccount {
+        funder: funder.pubkey(),
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::FundFeeAccount { amount }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&funder.pubkey()),
+        &[funder],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_treasury_withdrawal_limit(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    max_per_period: u64,
+    period_seconds: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (treasury_withdrawal_limit, _) = find_treasury_withdrawal_limit_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateTreasuryWithdrawalLimit {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        treasury_withdrawal_limit,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateTreasuryWithdrawalLimit {
+        max_per_period,
+        period_seconds,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| treasury_withdrawal_limit)
+}
+
+pub fn auctioneer_withdraw_from_treasury(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    auctioneer_authority: &Keypair,
+    destination: &Pubkey,
+    amount: u64,
+) -> Transaction {
+    let (treasury_withdrawal_limit, _) = find_treasury_withdrawal_limit_address(ahkey);
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority.pubkey());
+    let accounts = mpl_auction_house::accounts::AuctioneerWithdrawFromTreasury {
+        treasury_mint: ah.treasury_mint,
+        auctioneer_authority: auctioneer_authority.pubkey(),
+        treasury_withdrawal_destination: *destination,
+        auction_house_treasury: ah.auction_house_treasury,
+        auction_house: *ahkey,
+        ah_auctioneer_pda,
+        treasury_withdrawal_limit,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::AuctioneerWithdrawFromTreasury { amount }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&auctioneer_authority.pubkey()),
+        &[auctioneer_authority],
+        context.last_blockhash,
+    )
+}
+
+pub async fn create_wanted_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    buyer: &Keypair,
+    criteria: WantedCriteria,
+    start_price: u64,
+    floor_price: u64,
+    end_time: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (wanted_listing, _) =
+        find_wanted_listing_address(ahkey, &buyer.pubkey(), criteria.target());
+    let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let accounts = mpl_auction_house::accounts::CreateWantedListing {
+        buyer: buyer.pubkey(),
+        escrow_payment_account,
+        auction_house: *ahkey,
+        wanted_listing,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateWantedListing {
+        criteria,
+        start_price,
+        floor_price,
+        end_time,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| wanted_listing)
+}
+
+pub fn fill_wanted(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+    wanted_listing: &Pubkey,
+) -> (mpl_auction_house::accounts::FillWanted, Transaction) {
+    let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, buyer);
+    let accounts = mpl_auction_house::accounts::FillWanted {
+        seller: test_metadata.token.pubkey(),
+        token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        buyer: *buyer,
+        buyer_receipt_token_account: get_associated_token_address(
+            buyer,
+            &test_metadata.mint.pubkey(),
+        ),
+        escrow_payment_account,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        auction_house: *ahkey,
+        auction_house_treasury: ah.auction_house_treasury,
+        wanted_listing: *wanted_listing,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    };
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::FillWanted {}.data(),
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub async fn create_voucher(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    creator: &Keypair,
+    id: u64,
+    price: u64,
+    redeem_deadline: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (voucher, _) = find_voucher_address(ahkey, &creator.pubkey(), id);
+    let accounts = mpl_auction_house::accounts::CreateVoucher {
+        creator: creator.pubkey(),
+        auction_house: *ahkey,
+        voucher,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateVoucher {
+        id,
+        price,
+        redeem_deadline,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&creator.pubkey()),
+        &[creator],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| voucher)
+}
+
+pub async fn buy_voucher(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    buyer: &Keypair,
+    voucher: &Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::BuyVoucher {
+        buyer: buyer.pubkey(),
+        auction_house: *ahkey,
+        voucher: *voucher,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::BuyVoucher {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn redeem_voucher(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    creator: &Keypair,
+    test_metadata: &Metadata,
+    voucher: &Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::RedeemVoucher {
+        creator: creator.pubkey(),
+        buyer: test_metadata.token.pubkey(),
+        buyer_token_account: get_associated_token_address(
+            &test_metadata.token.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        auction_house: *ahkey,
+        auction_house_treasury: ah.auction_house_treasury,
+        voucher: *voucher,
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::RedeemVoucher {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&creator.pubkey()),
+        &[creator],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn reclaim_voucher(
+    context: &mut ProgramTestContext,
+    buyer: &Keypair,
+    creator: &Pubkey,
+    voucher: &Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::ReclaimVoucher {
+        buyer: buyer.pubkey(),
+        creator: *creator,
+        voucher: *voucher,
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ReclaimVoucher {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_receipt_tree(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    merkle_tree: &Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (receipt_tree, _) = find_receipt_tree_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateReceiptTree {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        merkle_tree: *merkle_tree,
+        receipt_tree,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateReceiptTree {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| receipt_tree)
+}
+
+pub async fn create_public_bid_index(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    token_mint: &Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (public_bid_index, _) = find_public_bid_index_address(ahkey, token_mint);
+    let accounts = mpl_auction_house::accounts::CreatePublicBidIndex {
+        payer: context.payer.pubkey(),
+        token_mint: *token_mint,
+        auction_house: *ahkey,
+        public_bid_index,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CreatePublicBidIndex {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| public_bid_index)
+}
+
+pub async fn create_leaderboard(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    epoch: u64,
+    starts_at: i64,
+    ends_at: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (leaderboard, _) = find_leaderboard_address(ahkey, epoch);
+    let accounts = mpl_auction_house::accounts::CreateLeaderboard {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        leaderboard,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateLeaderboard {
+        epoch,
+        starts_at,
+        ends_at,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| leaderboard)
+}
+
+pub async fn create_price_history(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    token_mint: &Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (price_history, _) = find_price_history_address(ahkey, token_mint);
+    let accounts = mpl_auction_house::accounts::CreatePriceHistory {
+        payer: context.payer.pubkey(),
+        auction_house: *ahkey,
+        token_mint: *token_mint,
+        price_history,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CreatePriceHistory {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| price_history)
+}
+
+pub async fn create_spending_cap(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    wallet: &Keypair,
+    frontend: &Pubkey,
+    cap: u64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (spending_cap, _) = find_spending_cap_address(ahkey, &wallet.pubkey(), frontend);
+    let accounts = mpl_auction_house::accounts::CreateSpendingCap {
+        wallet: wallet.pubkey(),
+        frontend: *frontend,
+        auction_house: *ahkey,
+        spending_cap,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::CreateSpendingCap { cap }.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&wallet.pubkey()),
+        &[wallet],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| spending_cap)
+}
+
+pub async fn register_omnibus_custodian(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    custodian: &Pubkey,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (omnibus_custodian, _) = find_omnibus_custodian_address(ahkey, custodian);
+    let accounts = mpl_auction_house::accounts::RegisterOmnibusCustodian {
+        authority: authority.pubkey(),
+        custodian: *custodian,
+        auction_house: *ahkey,
+        omnibus_custodian,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::RegisterOmnibusCustodian {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| omnibus_custodian)
+}
+
+/// Simulate `get_house_balances` and decode the balances it returns.
+pub async fn get_house_balances(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    fee_sweep_config: Option<Pubkey>,
+) -> StdResult<HouseBalances, BanksClientError> {
+    let mut accounts = mpl_auction_house::accounts::GetHouseBalances {
+        treasury_mint: ah.treasury_mint,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+    }
+    .to_account_metas(None);
+    if let Some(fee_sweep_config) = fee_sweep_config {
+        accounts.push(AccountMeta::new_readonly(fee_sweep_config, false));
+    }
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::GetHouseBalances {}.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let simulation = context.banks_client.simulate_transaction(tx).await?;
+    simulation.result.unwrap().unwrap();
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .expect("get_house_balances returned no data");
+    assert_eq!(return_data.program_id, mpl_auction_house::id());
+
+    Ok(HouseBalances::try_from_slice(&return_data.data).unwrap())
+}
+
+pub async fn create_fee_stake_config(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    stake_mint: &Pubkey,
+    min_stake_amount: u64,
+    discounted_seller_fee_basis_points: u16,
+    lockup_seconds: i64,
+) -> StdResult<(), BanksClientError> {
+    let (fee_stake_config, _) = find_fee_stake_config_address(ahkey);
+    let (stake_vault, _) = find_fee_stake_vault_address(ahkey);
+
+    let accounts = mpl_auction_house::accounts::CreateFeeStakeConfig {
+        auction_house: *ahkey,
+        authority: authority.pubkey(),
+        stake_mint: *stake_mint,
+        fee_stake_config,
+        stake_vault,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateFeeStakeConfig {
+        min_stake_amount,
+        discounted_seller_fee_basis_points,
+        lockup_seconds,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_relist_cooldown(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    relist_cooldown_seconds: i64,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetRelistCooldown {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetRelistCooldown {
+        relist_cooldown_seconds,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_authority_log(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (authority_log, _) = find_authority_log_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateAuthorityLog {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        authority_log,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateAuthorityLog {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| authority_log)
+}
+
+pub async fn create_trade_sequence(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (trade_sequence, _) = find_trade_sequence_address(ahkey);
+    let accounts = mpl_auction_house::accounts::CreateTradeSequence {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        trade_sequence,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateTradeSequence {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| trade_sequence)
+}
+
+pub async fn set_buyer_protection(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    buyer_protection_seconds: i64,
+    restocking_fee_basis_points: u16,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetBuyerProtection {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetBuyerProtection {
+        buyer_protection_seconds,
+        restocking_fee_basis_points,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_seller_financing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    allow_seller_financing: bool,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetSellerFinancing {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetSellerFinancing {
+        allow_seller_financing,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_wallet_bound_receipts(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    allow_wallet_bound_receipts: bool,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetWalletBoundReceipts {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetWalletBoundReceipts {
+        allow_wallet_bound_receipts,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_royalty_bonus(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    token_account: &Pubkey,
+    seller_trade_state: &Pubkey,
+    buyer_price: u64,
+    token_size: u64,
+    bonus_basis_points: u16,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (royalty_bonus, _) = find_royalty_bonus_address(ahkey, seller_trade_state);
+    let accounts = mpl_auction_house::accounts::CreateRoyaltyBonus {
+        wallet: test_metadata.token.pubkey(),
+        token_account: *token_account,
+        metadata: test_metadata.pubkey,
+        auction_house: *ahkey,
+        seller_trade_state: *seller_trade_state,
+        royalty_bonus,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateRoyaltyBonus {
+        buyer_price,
+        token_size,
+        bonus_basis_points,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| royalty_bonus)
+}
+
+pub async fn create_shared_custody(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    owners: Vec<CoOwner>,
+    threshold: u8,
+) -> StdResult<Pubkey, BanksClientError> {
+    let token_mint = test_metadata.mint.pubkey();
+    let (shared_custody, _) = find_shared_custody_address(ahkey, &token_mint);
+    let accounts = mpl_auction_house::accounts::CreateSharedCustody {
+        depositor: test_metadata.token.pubkey(),
+        token_account: get_associated_token_address(&test_metadata.token.pubkey(), &token_mint),
+        token_mint,
+        auction_house: *ahkey,
+        shared_custody,
+        custody_token_account: get_associated_token_address(&shared_custody, &token_mint),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CreateSharedCustody { owners, threshold }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| shared_custody)
+}
+
+pub async fn co_list(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    shared_custody: &Pubkey,
+    token_mint: &Pubkey,
+    owner: &Keypair,
+    buyer_price: u64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let custody_token_account = get_associated_token_address(shared_custody, token_mint);
+    let (seller_trade_state, trade_state_bump) = find_trade_state_address(
+        shared_custody,
+        ahkey,
+        &custody_token_account,
+        &ah.treasury_mint,
+        token_mint,
+        buyer_price,
+        1,
+    );
+    let (program_as_signer, _) = find_program_as_signer_address();
+    let accounts = mpl_auction_house::accounts::CoList {
+        owner: owner.pubkey(),
+        auction_house: *ahkey,
+        shared_custody: *shared_custody,
+        custody_token_account,
+        seller_trade_state,
+        program_as_signer,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::CoList {
+        buyer_price,
+        trade_state_bump,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&owner.pubkey()),
+        &[owner],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| seller_trade_state)
+}
+
+pub async fn settle_shared_custody(
+    context: &mut ProgramTestContext,
+    shared_custody: &Pubkey,
+    depositor: &Pubkey,
+    token_mint: &Pubkey,
+    owners: &[Pubkey],
+) -> StdResult<(), BanksClientError> {
+    let mut accounts = mpl_auction_house::accounts::SettleSharedCustody {
+        depositor: *depositor,
+        shared_custody: *shared_custody,
+        custody_token_account: get_associated_token_address(shared_custody, token_mint),
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    for owner in owners {
+        accounts.push(AccountMeta::new(*owner, false));
+    }
+
+    let data = mpl_auction_house::instruction::SettleSharedCustody {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_rounding_policy(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    rounding_policy: RoundingPolicy,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetRoundingPolicy {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetRoundingPolicy { rounding_policy }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_token_standard_policy(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    token_standard_policy: u8,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetTokenStandardPolicy {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetTokenStandardPolicy {
+        token_standard_policy,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_requires_verified_creators(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    requires_verified_creators: bool,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetRequiresVerifiedCreators {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetRequiresVerifiedCreators {
+        requires_verified_creators,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_single_active_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    single_active_listing: bool,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetSingleActiveListing {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetSingleActiveListing {
+        single_active_listing,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_listing_currency_lock(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    locks_listing_currency: bool,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetListingCurrencyLock {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetListingCurrencyLock {
+        locks_listing_currency,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_listing_price_bounds(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    min_listing_price: Option<u64>,
+    max_listing_price: Option<u64>,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetListingPriceBounds {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetListingPriceBounds {
+        min_listing_price,
+        max_listing_price,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub struct ProtectedSale {
+    pub buyer: Keypair,
+    pub seller_token_account: Pubkey,
+    pub protected_settlement: Pubkey,
+    pub escrow_token_account: Pubkey,
+    pub settlement_marker: Pubkey,
+}
+
+/// Lists, bids on and executes a sale of `test_metadata` on a house with buyer protection enabled.
+pub async fn execute_protected_sale(
+    context: &mut ProgramTestContext,
+    ah: &AuctionHouse,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+) -> ProtectedSale {
+    airdrop(context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+
+    execute_protected_sale_to(context, ah, ahkey, authority, test_metadata, buyer).await
+}
+
+/// Lists, bids on and executes a protected sale of the already minted `test_metadata` to `buyer`.
+pub async fn execute_protected_sale_to(
+    context: &mut ProgramTestContext,
+    ah: &AuctionHouse,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    buyer: Keypair,
+) -> ProtectedSale {
+    let ((sell_acc, _), sell_tx) = sell(context, ahkey, ah, test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        100_000_000,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let (protected_settlement, _) =
+        find_protected_settlement_address(ahkey, &test_metadata.mint.pubkey(), &buyer.pubkey());
+    let escrow_token_account =
+        get_associated_token_address(&protected_settlement, &test_metadata.mint.pubkey());
+    let (settlement_marker, _) = find_settlement_marker_address(&protected_settlement);
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(protected_settlement, false));
+    accounts.push(AccountMeta::new(escrow_token_account, false));
+    accounts.push(AccountMeta::new(settlement_marker, false));
+
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 100_000_000,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    ProtectedSale {
+        buyer,
+        seller_token_account: sell_acc.token_account,
+        protected_settlement,
+        escrow_token_account,
+        settlement_marker,
+    }
+}
+
+pub async fn set_payout_delay(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    payout_delay_seconds: i64,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetPayoutDelay {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetPayoutDelay {
+        payout_delay_seconds,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub struct DelayedSale {
+    pub buyer: Keypair,
+    pub delayed_payout: Pubkey,
+}
+
+/// Lists, bids on and executes a sale of `test_metadata` on a house with a payout delay set.
+pub async fn execute_delayed_sale(
+    context: &mut ProgramTestContext,
+    ah: &AuctionHouse,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+) -> DelayedSale {
+    airdrop(context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, _), sell_tx) = sell(context, ahkey, ah, test_metadata, 100_000_000, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let ((bid_acc, _), buy_tx) = buy(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        100_000_000,
+        1,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let (delayed_payout, _) =
+        find_delayed_payout_address(ahkey, &test_metadata.mint.pubkey(), &buyer.pubkey());
+
+    let mut accounts = mpl_auction_house::accounts::ExecuteSale {
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        auction_house: *ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        authority: ah.authority,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: Some(sell_acc.free_seller_trade_state),
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ),
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new(delayed_payout, false));
+
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            _free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            token_size: 1,
+            buyer_price: 100_000_000,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    DelayedSale {
+        buyer,
+        delayed_payout,
+    }
+}
+
+pub async fn claim_proceeds(
+    context: &mut ProgramTestContext,
+    seller: &Pubkey,
+    rent_payer: &Pubkey,
+    delayed_payout: &Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::ClaimProceeds {
+        seller: *seller,
+        rent_payer: *rent_payer,
+        delayed_payout: *delayed_payout,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::ClaimProceeds {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn clawback_proceeds(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    buyer: &Pubkey,
+    rent_payer: &Pubkey,
+    delayed_payout: &Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::ClawbackProceeds {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        buyer: *buyer,
+        rent_payer: *rent_payer,
+        delayed_payout: *delayed_payout,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::ClawbackProceeds {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn set_arbiter(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    arbiter: &Pubkey,
+) -> StdResult<(), BanksClientError> {
+    let accounts = mpl_auction_house::accounts::SetArbiter {
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::SetArbiter { arbiter: *arbiter }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub fn stake_for_fees(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    wallet: &Keypair,
+    stake_token_account: &Pubkey,
+    amount: u64,
+) -> (mpl_auction_house::accounts::StakeForFees, Transaction) {
+    let (fee_stake_config, _) = find_fee_stake_config_address(ahkey);
+    let (stake_vault, _) = find_fee_stake_vault_address(ahkey);
+    let (fee_stake, _) = find_fee_stake_address(ahkey, &wallet.pubkey());
+
+    let accounts = mpl_auction_house::accounts::StakeForFees {
+        wallet: wallet.pubkey(),
+        stake_token_account: *stake_token_account,
+        auction_house: *ahkey,
+        fee_stake_config,
+        stake_vault,
+        fee_stake,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+
+    let data = mpl_auction_house::instruction::StakeForFees { amount }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts: accounts.to_account_metas(None),
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&wallet.pubkey()),
+            &[wallet],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn unstake(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    wallet: &Keypair,
+    destination_token_account: &Pubkey,
+    amount: u64,
+) -> (mpl_auction_house::accounts::Unstake, Transaction) {
+    let (fee_stake_config, _) = find_fee_stake_config_address(ahkey);
+    let (stake_vault, _) = find_fee_stake_vault_address(ahkey);
+    let (fee_stake, _) = find_fee_stake_address(ahkey, &wallet.pubkey());
+
+    let accounts = mpl_auction_house::accounts::Unstake {
+        wallet: wallet.pubkey(),
+        destination_token_account: *destination_token_account,
+        auction_house: *ahkey,
+        fee_stake_config,
+        stake_vault,
+        fee_stake,
+        token_program: spl_token::id(),
+    };
+
+    let data = mpl_auction_house::instruction::Unstake { amount }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts: accounts.to_account_metas(None),
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&wallet.pubkey()),
+            &[wallet],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn withdraw(
+    context: &mut ProgramTestContext,
+    buyer: &Keypair,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    sale_price: u64,
+    withdraw_amount: u64,
+) -> ((mpl_auction_house::accounts::Withdraw,), Transaction) {
+    let seller_token_account =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (_buyer_trade_state, _sts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &seller_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        1,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+
+    let accounts = mpl_auction_house::accounts::Withdraw {
+        wallet: buyer.pubkey(),
+        escrow_payment_account,
+        receipt_account: buyer.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    };
+
+    let accounts_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::Withdraw {
+        escrow_payment_bump: escrow_bump,
+        amount: withdraw_amount,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts: accounts_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+
+    ((accounts,), tx)
+}
+
+pub fn auctioneer_withdraw(
+    context: &mut ProgramTestContext,
+    buyer: &Keypair,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    auctioneer_authority: &Keypair,
+    sale_price: u64,
+    withdraw_amount: u64,
+) -> (mpl_auction_house::accounts::AuctioneerWithdraw, Transaction) {
+    let seller_token_account =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (_buyer_trade_state, _sts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &seller_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        1,
+    );
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority.pubkey());
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+
+    let accounts = mpl_auction_house::accounts::AuctioneerWithdraw {
+        wallet: buyer.pubkey(),
+        escrow_payment_account,
+        receipt_account: buyer.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        authority: ah.authority,
+        auctioneer_authority: auctioneer_authority.pubkey(),
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    };
+
+    let accounts_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auction_house::instruction::AuctioneerWithdraw {
+        escrow_payment_bump: escrow_bump,
+        amount: withdraw_amount,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data,
+        accounts: accounts_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer, auctioneer_authority],
+        context.last_blockhash,
+    );
+
+    (accounts, tx)
+}
+
+/// Builds a `withdraw_many` transaction sweeping `wallet`'s escrows on native SOL Auction Houses.
+pub fn withdraw_many(
+    context: &mut ProgramTestContext,
+    wallet: &Keypair,
+    ahkeys: &[Pubkey],
+) -> Transaction {
+    let mut accounts = mpl_auction_house::accounts::WithdrawMany {
+        wallet: wallet.pubkey(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    for ahkey in ahkeys {
+        let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, &wallet.pubkey());
+        accounts.push(AccountMeta::new_readonly(*ahkey, false));
+        accounts.push(AccountMeta::new(escrow_payment_account, false));
+        accounts.push(AccountMeta::new(wallet.pubkey(), false));
+    }
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::WithdrawMany {}.data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&wallet.pubkey()),
+        &[wallet],
+        context.last_blockhash,
+    )
+}
+
+pub fn set_bid_expiry(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    buyer: &Keypair,
+    token_account: &Pubkey,
+    trade_state: &Pubkey,
+    buyer_price: u64,
+    expires_at: i64,
+) -> (Pubkey, Transaction) {
+    let (bid_expiry, _) = find_bid_expiry_address(trade_state);
+    let accounts = mpl_auction_house::accounts::SetBidExpiry {
+        wallet: buyer.pubkey(),
+        token_account: *token_account,
+        auction_house: *ahkey,
+        trade_state: *trade_state,
+        bid_expiry,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::SetBidExpiry {
+            buyer_price,
+            token_size: 1,
+            expires_at,
+        }
+        .data(),
+        accounts,
+    };
+
+    (
+        bid_expiry,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn sweep_expired_bids(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    cranker: &Keypair,
+    bids: &[(Pubkey, Pubkey)],
+) -> Transaction {
+    let mut accounts = mpl_auction_house::accounts::SweepExpiredBids {
+        cranker: cranker.pubkey(),
+        auction_house: *ahkey,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    for (wallet, trade_state) in bids {
+        let (bid_expiry, _) = find_bid_expiry_address(trade_state);
+        let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, wallet);
+        accounts.push(AccountMeta::new(bid_expiry, false));
+        accounts.push(AccountMeta::new(*trade_state, false));
+        accounts.push(AccountMeta::new(escrow_payment_account, false));
+        accounts.push(AccountMeta::new(*wallet, false));
+    }
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::SweepExpiredBids {}.data(),
+        accounts,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&cranker.pubkey()),
+        &[cranker],
+        context.last_blockhash,
+    )
+}
+
+pub async fn existing_auction_house_test_context(
     context: &mut ProgramTestContext,
 ) -> StdResult<(AuctionHouse, Pubkey, Keypair), BanksClientError> {
     let twd_key = context.payer.pubkey();
@@ -1612,6 +4127,7 @@ pub async fn existing_auction_house_test_context(
         seller_fee_basis_points,
         false,
         false,
+        false,
     );
 
     let auction_house_account = auction_house.await.unwrap();
@@ -1631,11 +4147,6 @@ pub async fn create_sale_delegate_rule_set(
     context: &mut ProgramTestContext,
     creator: Keypair,
 ) -> (Pubkey, AuthorizationData) {
-    let name = String::from("AH");
-    let (ruleset_addr, _ruleset_bump) = find_rule_set_address(creator.pubkey(), name.clone());
-
-    let pass_rule = Rule::Pass;
-
     let sale_delegate_operation = Operation::Transfer {
         scenario: TransferScenario::SaleDelegate,
     };
@@ -1644,13 +4155,47 @@ pub async fn create_sale_delegate_rule_set(
         scenario: DelegateScenario::Token(TokenDelegateRole::Sale),
     };
 
+    create_pass_rule_set(
+        context,
+        creator,
+        "AH",
+        &[sale_delegate_operation, delegate_sale_operation],
+    )
+    .await
+}
+
+/// Creates a rule set that allows sale delegation but defines no transfer rule, so a sale
+/// delegate can never transfer the token.
+pub async fn create_transfer_blocking_rule_set(
+    context: &mut ProgramTestContext,
+    creator: Keypair,
+) -> (Pubkey, AuthorizationData) {
+    let delegate_sale_operation = Operation::Delegate {
+        scenario: DelegateScenario::Token(TokenDelegateRole::Sale),
+    };
+
+    create_pass_rule_set(
+        context,
+        creator,
+        "AH-NoTransfer",
+        &[delegate_sale_operation],
+    )
+    .await
+}
+
+async fn create_pass_rule_set(
+    context: &mut ProgramTestContext,
+    creator: Keypair,
+    name: &str,
+    operations: &[Operation],
+) -> (Pubkey, AuthorizationData) {
+    let name = String::from(name);
+    let (ruleset_addr, _ruleset_bump) = find_rule_set_address(creator.pubkey(), name.clone());
+
     let mut rule_set = RuleSetV1::new(name.clone(), creator.pubkey());
-    rule_set
-        .add(sale_delegate_operation.to_string(), pass_rule.clone())
-        .unwrap();
-    rule_set
-        .add(delegate_sale_operation.to_string(), pass_rule.clone())
-        .unwrap();
+    for operation in operations {
+        rule_set.add(operation.to_string(), Rule::Pass).unwrap();
+    }
 
     let mut serialized_rule_set = Vec::new();
     rule_set
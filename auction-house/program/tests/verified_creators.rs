@@ -0,0 +1,98 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use mpl_token_metadata::state::Creator;
+
+#[tokio::test]
+async fn sell_with_unverified_creator_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_requires_verified_creators(&mut context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            Some(vec![
+                Creator {
+                    address: context.payer.pubkey(),
+                    verified: true,
+                    share: 50,
+                },
+                Creator {
+                    address: Keypair::new().pubkey(),
+                    verified: false,
+                    share: 50,
+                },
+            ]),
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, UNVERIFIED_CREATORS);
+}
+
+#[tokio::test]
+async fn sell_with_verified_creators_success() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_requires_verified_creators(&mut context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            Some(vec![Creator {
+                address: context.payer.pubkey(),
+                verified: true,
+                share: 100,
+            }]),
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let (_, sell_tx) = sell(&mut context, &ahkey, &ah, &test_metadata, ONE_SOL, 1);
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+}
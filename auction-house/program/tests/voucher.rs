@@ -0,0 +1,175 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::Voucher;
+use mpl_token_metadata::state::Creator;
+
+/// Create a voucher listed by the context payer for `ONE_SOL`, redeemable for an hour, and buy
+/// it with the token keypair of a new `Metadata`. Returns the auction house, the creator, the
+/// buyer's metadata and the voucher.
+async fn sold_voucher(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Keypair, Metadata, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let creator = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let voucher = create_voucher(context, &ahkey, &creator, 0, ONE_SOL, now + 3600)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    buy_voucher(context, &ahkey, &test_metadata.token, &voucher)
+        .await
+        .unwrap();
+
+    (ah, ahkey, creator, test_metadata, voucher)
+}
+
+#[tokio::test]
+async fn redeem_voucher_pays_creator() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, creator, test_metadata, voucher) = sold_voucher(&mut context).await;
+
+    let voucher_account = context
+        .banks_client
+        .get_account(voucher)
+        .await
+        .unwrap()
+        .unwrap();
+    let voucher_data = Voucher::try_deserialize(&mut voucher_account.data.as_ref()).unwrap();
+    assert_eq!(voucher_data.buyer, test_metadata.token.pubkey());
+
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            Some(vec![Creator {
+                address: creator.pubkey(),
+                verified: true,
+                share: 100,
+            }]),
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let treasury_before = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+    redeem_voucher(
+        &mut context,
+        &ahkey,
+        &ah,
+        &creator,
+        &test_metadata,
+        &voucher,
+    )
+    .await
+    .unwrap();
+    let treasury_after = context
+        .banks_client
+        .get_balance(ah.auction_house_treasury)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        treasury_after - treasury_before,
+        ONE_SOL * ah.seller_fee_basis_points as u64 / 10000
+    );
+    assert!(context
+        .banks_client
+        .get_account(voucher)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn redeem_voucher_requires_creator_token() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, creator, test_metadata, voucher) = sold_voucher(&mut context).await;
+
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let err = redeem_voucher(
+        &mut context,
+        &ahkey,
+        &ah,
+        &creator,
+        &test_metadata,
+        &voucher,
+    )
+    .await
+    .unwrap_err();
+    assert_error!(err, VOUCHER_NOT_DELIVERED);
+}
+
+#[tokio::test]
+async fn reclaim_voucher_after_deadline() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (_, _, creator, test_metadata, voucher) = sold_voucher(&mut context).await;
+    let buyer = &test_metadata.token;
+
+    let err = reclaim_voucher(&mut context, buyer, &creator.pubkey(), &voucher)
+        .await
+        .unwrap_err();
+    assert_error!(err, VOUCHER_REDEEM_DEADLINE_NOT_PASSED);
+
+    context.warp_to_slot(100).unwrap();
+    let mut clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    clock.unix_timestamp += 7200;
+    context.set_sysvar(&clock);
+
+    let buyer_before = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+    reclaim_voucher(&mut context, buyer, &creator.pubkey(), &voucher)
+        .await
+        .unwrap();
+    let buyer_after = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+
+    // The buyer pays the transaction fee out of the refund.
+    assert!(buyer_after - buyer_before > ONE_SOL - 10_000);
+    assert!(context
+        .banks_client
+        .get_account(voucher)
+        .await
+        .unwrap()
+        .is_none());
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::WantedCriteria;
+use mpl_testing_utils::utils::create_token_account;
+use solana_program::program_pack::Pack;
+
+/// Post a wanted listing for a freshly minted token and return the fill transaction delivering
+/// the token to a custodial (non associated) token account owned by the buyer.
+async fn fill_wanted_to_custodial_account(
+    context: &mut ProgramTestContext,
+    ah: &AuctionHouse,
+    ahkey: &Pubkey,
+) -> (Pubkey, Transaction) {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let (_, deposit_tx) = deposit(context, ahkey, ah, &test_metadata, &buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let custodial_account = Keypair::new();
+    create_token_account(
+        context,
+        &custodial_account,
+        &test_metadata.mint.pubkey(),
+        &buyer.pubkey(),
+    )
+    .await
+    .unwrap();
+
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let wanted_listing = create_wanted_listing(
+        context,
+        ahkey,
+        &buyer,
+        WantedCriteria::Mint(test_metadata.mint.pubkey()),
+        ONE_SOL,
+        ONE_SOL,
+        now + 60,
+    )
+    .await
+    .unwrap();
+
+    let (mut accounts, _) = fill_wanted(
+        context,
+        ahkey,
+        ah,
+        &test_metadata,
+        &buyer.pubkey(),
+        &wanted_listing,
+    );
+    accounts.buyer_receipt_token_account = custodial_account.pubkey();
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::FillWanted {}.data(),
+        accounts: account_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+
+    (custodial_account.pubkey(), tx)
+}
+
+#[tokio::test]
+async fn wallet_bound_receipt_rejected_by_default() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let (_, fill_tx) = fill_wanted_to_custodial_account(&mut context, &ah, &ahkey).await;
+    let error = context
+        .banks_client
+        .process_transaction(fill_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, BUYER_RECEIPT_NOT_ASSOCIATED_TOKEN_ACCOUNT);
+}
+
+#[tokio::test]
+async fn wallet_bound_receipt_accepted_when_allowed() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_wallet_bound_receipts(&mut context, &ahkey, &ah_authority, true)
+        .await
+        .unwrap();
+
+    let auction_house_account = context
+        .banks_client
+        .get_account(ahkey)
+        .await
+        .unwrap()
+        .unwrap();
+    let auction_house =
+        AuctionHouse::try_deserialize(&mut auction_house_account.data.as_ref()).unwrap();
+    assert!(auction_house.allow_wallet_bound_receipts);
+
+    let (custodial_account, fill_tx) =
+        fill_wanted_to_custodial_account(&mut context, &ah, &ahkey).await;
+    context
+        .banks_client
+        .process_transaction(fill_tx)
+        .await
+        .unwrap();
+
+    let custodial_account = context
+        .banks_client
+        .get_account(custodial_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let custodial_account =
+        spl_token::state::Account::unpack_from_slice(&custodial_account.data).unwrap();
+    assert_eq!(custodial_account.amount, 1);
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::WantedCriteria;
+use solana_program::program_pack::Pack;
+
+#[tokio::test]
+async fn fill_wanted_sells_matching_token_to_buyer() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, &buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let wanted_listing = create_wanted_listing(
+        &mut context,
+        &ahkey,
+        &buyer,
+        WantedCriteria::Mint(test_metadata.mint.pubkey()),
+        ONE_SOL,
+        ONE_SOL,
+        now + 60,
+    )
+    .await
+    .unwrap();
+
+    let seller_before = context
+        .banks_client
+        .get_account(test_metadata.token.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let (acc, fill_tx) = fill_wanted(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer.pubkey(),
+        &wanted_listing,
+    );
+    context
+        .banks_client
+        .process_transaction(fill_tx)
+        .await
+        .unwrap();
+
+    let seller_after = context
+        .banks_client
+        .get_account(test_metadata.token.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(seller_after.lamports > seller_before.lamports);
+
+    let buyer_token_account = context
+        .banks_client
+        .get_account(acc.buyer_receipt_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let buyer_token_account =
+        spl_token::state::Account::unpack_from_slice(&buyer_token_account.data).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+
+    let wanted_listing_account = context
+        .banks_client
+        .get_account(wanted_listing)
+        .await
+        .unwrap();
+    assert!(wanted_listing_account.is_none());
+}
+
+#[tokio::test]
+async fn fill_wanted_rejects_token_outside_collection() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, &buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let now = context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .unix_timestamp;
+    let wanted_listing = create_wanted_listing(
+        &mut context,
+        &ahkey,
+        &buyer,
+        WantedCriteria::Collection(Pubkey::new_unique()),
+        ONE_SOL,
+        ONE_SOL / 2,
+        now + 60,
+    )
+    .await
+    .unwrap();
+
+    let (_, fill_tx) = fill_wanted(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer.pubkey(),
+        &wanted_listing,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(fill_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, WANTED_CRITERIA_NOT_MET);
+}
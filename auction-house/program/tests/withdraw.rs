@@ -5,8 +5,10 @@ pub mod utils;
 use common::*;
 use utils::{helpers::*, setup_functions::*};
 
+use mpl_auction_house::pda::find_escrow_payment_address;
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
-use solana_sdk::signer::Signer;
+use solana_program::system_program;
+use solana_sdk::{account::AccountSharedData, signer::Signer};
 use std::assert_eq;
 
 #[tokio::test]
@@ -290,6 +292,54 @@ async fn withdraw_extra_and_adjusted_for_rent_shortfall_success() {
     assert_eq!(rent_exempt_min, escrow_balance_after_withdraw);
 }
 
+#[tokio::test]
+async fn withdraw_sweeps_escrow_below_rent_exempt_minimum() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    // An escrow funded before rent exemption was enforced, holding one lamport less than the
+    // rent exempt minimum.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let rent_exempt_min: u64 = rent.minimum_balance(0);
+    let (escrow, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    context.set_account(
+        &escrow,
+        &AccountSharedData::new(rent_exempt_min - 1, 0, &system_program::id()),
+    );
+
+    let buyer_balance_before = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+
+    let (_, withdraw_tx) = withdraw(&mut context, &buyer, &ahkey, &ah, &test_metadata, 1, 1);
+    context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap();
+
+    let escrow_account = context.banks_client.get_account(escrow).await.unwrap();
+    assert!(escrow_account.is_none());
+
+    // The swept dust outweighs the transaction fee.
+    let buyer_balance_after = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+    assert!(buyer_balance_after > buyer_balance_before);
+}
+
 #[tokio::test]
 async fn auctioneer_withdraw_success() {
     // Setup program test context and a new auction house.
@@ -0,0 +1,86 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auction_house::pda::find_escrow_payment_address;
+
+#[tokio::test]
+async fn withdraw_many_sweeps_every_house() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let (other_ah, other_ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let test_metadata = Metadata::new();
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), ONE_SOL * 3)
+        .await
+        .unwrap();
+
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, &buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &other_ahkey,
+        &other_ah,
+        &test_metadata,
+        &buyer,
+        ONE_SOL / 2,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let balance_before = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+
+    let tx = withdraw_many(&mut context, &buyer, &[ahkey, other_ahkey]);
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    for ahkey in [ahkey, other_ahkey] {
+        let (escrow, _) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+        let escrow_balance = context.banks_client.get_balance(escrow).await.unwrap();
+        assert_eq!(escrow_balance, rent.minimum_balance(0));
+    }
+
+    let balance_after = context
+        .banks_client
+        .get_balance(buyer.pubkey())
+        .await
+        .unwrap();
+    assert!(balance_after > balance_before + ONE_SOL);
+}
+
+#[tokio::test]
+async fn withdraw_many_without_houses_fails() {
+    let mut context = auction_house_program_test().start_with_context().await;
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let tx = withdraw_many(&mut context, &buyer, &[]);
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_WITHDRAW_MANY_ACCOUNTS);
+}
@@ -9,6 +9,8 @@ import * as beet from '@metaplex-foundation/beet';
 import * as web3 from '@solana/web3.js';
 import { ListingConfigVersion, listingConfigVersionBeet } from '../types/ListingConfigVersion';
 import { Bid, bidBeet } from '../types/Bid';
+import { ListingClock, listingClockBeet } from '../types/ListingClock';
+import { ListingFeatures, listingFeaturesBeet } from '../types/ListingFeatures';
 
 /**
  * Arguments used to create {@link ListingConfig}
@@ -26,6 +28,11 @@ export type ListingConfigArgs = {
   timeExtPeriod: number;
   timeExtDelta: number;
   allowHighBidCancel: boolean;
+  tickSize: beet.bignum;
+  feeBasisPoints: beet.COption<number>;
+  clock: ListingClock;
+  features: ListingFeatures;
+  lotMetadata: Uint8Array;
 };
 
 const listingConfigDiscriminator = [183, 196, 26, 41, 131, 46, 184, 115];
@@ -48,6 +55,11 @@ export class ListingConfig implements ListingConfigArgs {
     readonly timeExtPeriod: number,
     readonly timeExtDelta: number,
     readonly allowHighBidCancel: boolean,
+    readonly tickSize: beet.bignum,
+    readonly feeBasisPoints: beet.COption<number>,
+    readonly clock: ListingClock,
+    readonly features: ListingFeatures,
+    readonly lotMetadata: Uint8Array,
   ) {}
 
   /**
@@ -65,6 +77,11 @@ export class ListingConfig implements ListingConfigArgs {
       args.timeExtPeriod,
       args.timeExtDelta,
       args.allowHighBidCancel,
+      args.tickSize,
+      args.feeBasisPoints,
+      args.clock,
+      args.features,
+      args.lotMetadata,
     );
   }
 
@@ -117,31 +134,33 @@ export class ListingConfig implements ListingConfigArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link ListingConfig}
+   * {@link ListingConfig} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return listingConfigBeet.byteSize;
+  static byteSize(args: ListingConfigArgs) {
+    const instance = ListingConfig.fromArgs(args);
+    return listingConfigBeet.toFixedFromValue({
+      accountDiscriminator: listingConfigDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link ListingConfig} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: ListingConfigArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(ListingConfig.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link ListingConfig} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === ListingConfig.byteSize;
+    return connection.getMinimumBalanceForRentExemption(ListingConfig.byteSize(args), commitment);
   }
 
   /**
@@ -180,6 +199,21 @@ export class ListingConfig implements ListingConfigArgs {
       timeExtPeriod: this.timeExtPeriod,
       timeExtDelta: this.timeExtDelta,
       allowHighBidCancel: this.allowHighBidCancel,
+      tickSize: (() => {
+        const x = <{ toNumber: () => number }>this.tickSize;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      feeBasisPoints: this.feeBasisPoints,
+      clock: 'ListingClock.' + ListingClock[this.clock],
+      features: this.features,
+      lotMetadata: this.lotMetadata,
     };
   }
 }
@@ -188,7 +222,7 @@ export class ListingConfig implements ListingConfigArgs {
  * @category Accounts
  * @category generated
  */
-export const listingConfigBeet = new beet.BeetStruct<
+export const listingConfigBeet = new beet.FixableBeetStruct<
   ListingConfig,
   ListingConfigArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -206,6 +240,11 @@ export const listingConfigBeet = new beet.BeetStruct<
     ['timeExtPeriod', beet.u32],
     ['timeExtDelta', beet.u32],
     ['allowHighBidCancel', beet.bool],
+    ['tickSize', beet.u64],
+    ['feeBasisPoints', beet.coption(beet.u16)],
+    ['clock', listingClockBeet],
+    ['features', listingFeaturesBeet],
+    ['lotMetadata', beet.bytes],
   ],
   ListingConfig.fromArgs,
   'ListingConfig',
@@ -1,2 +1,24 @@
+export * from './AuctionSummary';
 export * from './AuctioneerAuthority';
+export * from './AutoAccept';
+export * from './BackupBids';
+export * from './BidLimit';
+export * from './BidPool';
+export * from './BidQualification';
+export * from './BidRateLimit';
+export * from './BidderAllowlist';
+export * from './BidderInvitation';
+export * from './BlindBox';
+export * from './BuyNow';
+export * from './DropItem';
+export * from './DropSchedule';
+export * from './DurationBounds';
+export * from './EndTimeIndex';
 export * from './ListingConfig';
+export * from './ListingPause';
+export * from './PoolContribution';
+export * from './PrivateRoom';
+export * from './PseudonymousBidder';
+export * from './SealedBid';
+export * from './WalletBidCounter';
+export * from './WalletBidRate';
@@ -211,6 +211,1244 @@ export class CannotCancelHighestBidError extends Error {
 createErrorFromCodeLookup.set(0x1779, () => new CannotCancelHighestBidError());
 createErrorFromNameLookup.set('CannotCancelHighestBid', () => new CannotCancelHighestBidError());
 
+/**
+ * AuctionIsPrivate: 'Bids in a private auction room must be sealed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionIsPrivateError extends Error {
+  readonly code: number = 0x177a;
+  readonly name: string = 'AuctionIsPrivate';
+  constructor() {
+    super('Bids in a private auction room must be sealed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionIsPrivateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177a, () => new AuctionIsPrivateError());
+createErrorFromNameLookup.set('AuctionIsPrivate', () => new AuctionIsPrivateError());
+
+/**
+ * ListingHasBids: 'The listing already has bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingHasBidsError extends Error {
+  readonly code: number = 0x177b;
+  readonly name: string = 'ListingHasBids';
+  constructor() {
+    super('The listing already has bids');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingHasBidsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177b, () => new ListingHasBidsError());
+createErrorFromNameLookup.set('ListingHasBids', () => new ListingHasBidsError());
+
+/**
+ * SealedBidTooLarge: 'The sealed bid ciphertext is too large'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SealedBidTooLargeError extends Error {
+  readonly code: number = 0x177c;
+  readonly name: string = 'SealedBidTooLarge';
+  constructor() {
+    super('The sealed bid ciphertext is too large');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SealedBidTooLargeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177c, () => new SealedBidTooLargeError());
+createErrorFromNameLookup.set('SealedBidTooLarge', () => new SealedBidTooLargeError());
+
+/**
+ * BidAlreadyRevealed: 'The sealed bid has already been revealed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidAlreadyRevealedError extends Error {
+  readonly code: number = 0x177d;
+  readonly name: string = 'BidAlreadyRevealed';
+  constructor() {
+    super('The sealed bid has already been revealed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidAlreadyRevealedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177d, () => new BidAlreadyRevealedError());
+createErrorFromNameLookup.set('BidAlreadyRevealed', () => new BidAlreadyRevealedError());
+
+/**
+ * InvalidBidReveal: 'The revealed bid does not match its commitment'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidRevealError extends Error {
+  readonly code: number = 0x177e;
+  readonly name: string = 'InvalidBidReveal';
+  constructor() {
+    super('The revealed bid does not match its commitment');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidRevealError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177e, () => new InvalidBidRevealError());
+createErrorFromNameLookup.set('InvalidBidReveal', () => new InvalidBidRevealError());
+
+/**
+ * PrivateRoomNotRevealed: 'All sealed bids must be revealed before settlement'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PrivateRoomNotRevealedError extends Error {
+  readonly code: number = 0x177f;
+  readonly name: string = 'PrivateRoomNotRevealed';
+  constructor() {
+    super('All sealed bids must be revealed before settlement');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PrivateRoomNotRevealedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177f, () => new PrivateRoomNotRevealedError());
+createErrorFromNameLookup.set('PrivateRoomNotRevealed', () => new PrivateRoomNotRevealedError());
+
+/**
+ * NotPrivateRoomWinner: 'Only the revealed winner can bid at the revealed price'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotPrivateRoomWinnerError extends Error {
+  readonly code: number = 0x1780;
+  readonly name: string = 'NotPrivateRoomWinner';
+  constructor() {
+    super('Only the revealed winner can bid at the revealed price');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotPrivateRoomWinnerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1780, () => new NotPrivateRoomWinnerError());
+createErrorFromNameLookup.set('NotPrivateRoomWinner', () => new NotPrivateRoomWinnerError());
+
+/**
+ * InvalidDropSchedule: 'Drop schedule interval and listing duration must be positive'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDropScheduleError extends Error {
+  readonly code: number = 0x1781;
+  readonly name: string = 'InvalidDropSchedule';
+  constructor() {
+    super('Drop schedule interval and listing duration must be positive');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDropScheduleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1781, () => new InvalidDropScheduleError());
+createErrorFromNameLookup.set('InvalidDropSchedule', () => new InvalidDropScheduleError());
+
+/**
+ * DropNotReady: 'The next drop in the schedule has not been reached yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DropNotReadyError extends Error {
+  readonly code: number = 0x1782;
+  readonly name: string = 'DropNotReady';
+  constructor() {
+    super('The next drop in the schedule has not been reached yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DropNotReadyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new DropNotReadyError());
+createErrorFromNameLookup.set('DropNotReady', () => new DropNotReadyError());
+
+/**
+ * BidNotMultipleOfTickSize: 'The bid must be a multiple of the listing tick size'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidNotMultipleOfTickSizeError extends Error {
+  readonly code: number = 0x1783;
+  readonly name: string = 'BidNotMultipleOfTickSize';
+  constructor() {
+    super('The bid must be a multiple of the listing tick size');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidNotMultipleOfTickSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1783, () => new BidNotMultipleOfTickSizeError());
+createErrorFromNameLookup.set(
+  'BidNotMultipleOfTickSize',
+  () => new BidNotMultipleOfTickSizeError(),
+);
+
+/**
+ * InvalidBidLimit: 'A bid limit must cap the number or the amount of bids per wallet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidLimitError extends Error {
+  readonly code: number = 0x1784;
+  readonly name: string = 'InvalidBidLimit';
+  constructor() {
+    super('A bid limit must cap the number or the amount of bids per wallet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidLimitError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new InvalidBidLimitError());
+createErrorFromNameLookup.set('InvalidBidLimit', () => new InvalidBidLimitError());
+
+/**
+ * WalletBidLimitExceeded: 'The wallet has reached the listing's limit of active bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletBidLimitExceededError extends Error {
+  readonly code: number = 0x1785;
+  readonly name: string = 'WalletBidLimitExceeded';
+  constructor() {
+    super("The wallet has reached the listing's limit of active bids");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletBidLimitExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new WalletBidLimitExceededError());
+createErrorFromNameLookup.set('WalletBidLimitExceeded', () => new WalletBidLimitExceededError());
+
+/**
+ * WalletBidAmountExceeded: 'The wallet has reached the listing's limit on committed bid amount'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletBidAmountExceededError extends Error {
+  readonly code: number = 0x1786;
+  readonly name: string = 'WalletBidAmountExceeded';
+  constructor() {
+    super("The wallet has reached the listing's limit on committed bid amount");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletBidAmountExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new WalletBidAmountExceededError());
+createErrorFromNameLookup.set('WalletBidAmountExceeded', () => new WalletBidAmountExceededError());
+
+/**
+ * BlindBoxNotSold: 'The blind box has not sold yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BlindBoxNotSoldError extends Error {
+  readonly code: number = 0x1787;
+  readonly name: string = 'BlindBoxNotSold';
+  constructor() {
+    super('The blind box has not sold yet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BlindBoxNotSoldError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new BlindBoxNotSoldError());
+createErrorFromNameLookup.set('BlindBoxNotSold', () => new BlindBoxNotSoldError());
+
+/**
+ * InvalidRevealProgram: 'The reveal program does not match the blind box'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRevealProgramError extends Error {
+  readonly code: number = 0x1788;
+  readonly name: string = 'InvalidRevealProgram';
+  constructor() {
+    super('The reveal program does not match the blind box');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRevealProgramError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new InvalidRevealProgramError());
+createErrorFromNameLookup.set('InvalidRevealProgram', () => new InvalidRevealProgramError());
+
+/**
+ * PoolRequiresNativeTreasury: 'Bid pools require an Auction House with a native SOL treasury'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PoolRequiresNativeTreasuryError extends Error {
+  readonly code: number = 0x1789;
+  readonly name: string = 'PoolRequiresNativeTreasury';
+  constructor() {
+    super('Bid pools require an Auction House with a native SOL treasury');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PoolRequiresNativeTreasuryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new PoolRequiresNativeTreasuryError());
+createErrorFromNameLookup.set(
+  'PoolRequiresNativeTreasury',
+  () => new PoolRequiresNativeTreasuryError(),
+);
+
+/**
+ * InvalidPoolContribution: 'Pool contributions must be greater than zero'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPoolContributionError extends Error {
+  readonly code: number = 0x178a;
+  readonly name: string = 'InvalidPoolContribution';
+  constructor() {
+    super('Pool contributions must be greater than zero');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPoolContributionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new InvalidPoolContributionError());
+createErrorFromNameLookup.set('InvalidPoolContribution', () => new InvalidPoolContributionError());
+
+/**
+ * PoolListingOpen: 'The pool's listing has not been decided yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PoolListingOpenError extends Error {
+  readonly code: number = 0x178b;
+  readonly name: string = 'PoolListingOpen';
+  constructor() {
+    super("The pool's listing has not been decided yet");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PoolListingOpenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new PoolListingOpenError());
+createErrorFromNameLookup.set('PoolListingOpen', () => new PoolListingOpenError());
+
+/**
+ * PoolWonListing: 'The pool won the listing, claim shares instead'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PoolWonListingError extends Error {
+  readonly code: number = 0x178c;
+  readonly name: string = 'PoolWonListing';
+  constructor() {
+    super('The pool won the listing, claim shares instead');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PoolWonListingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new PoolWonListingError());
+createErrorFromNameLookup.set('PoolWonListing', () => new PoolWonListingError());
+
+/**
+ * PoolLostListing: 'The pool did not win the listing, refund the contribution instead'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PoolLostListingError extends Error {
+  readonly code: number = 0x178d;
+  readonly name: string = 'PoolLostListing';
+  constructor() {
+    super('The pool did not win the listing, refund the contribution instead');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PoolLostListingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new PoolLostListingError());
+createErrorFromNameLookup.set('PoolLostListing', () => new PoolLostListingError());
+
+/**
+ * InvalidListingCount: 'Bulk listings must contain between one and the maximum number of listings'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidListingCountError extends Error {
+  readonly code: number = 0x178e;
+  readonly name: string = 'InvalidListingCount';
+  constructor() {
+    super('Bulk listings must contain between one and the maximum number of listings');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidListingCountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new InvalidListingCountError());
+createErrorFromNameLookup.set('InvalidListingCount', () => new InvalidListingCountError());
+
+/**
+ * MissingListingAccounts: 'The accounts passed for the bulk listings do not match the listings'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingListingAccountsError extends Error {
+  readonly code: number = 0x178f;
+  readonly name: string = 'MissingListingAccounts';
+  constructor() {
+    super('The accounts passed for the bulk listings do not match the listings');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingListingAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new MissingListingAccountsError());
+createErrorFromNameLookup.set('MissingListingAccounts', () => new MissingListingAccountsError());
+
+/**
+ * ListingStillHeld: 'The previous seller still holds the listed tokens'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingStillHeldError extends Error {
+  readonly code: number = 0x1790;
+  readonly name: string = 'ListingStillHeld';
+  constructor() {
+    super('The previous seller still holds the listed tokens');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingStillHeldError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new ListingStillHeldError());
+createErrorFromNameLookup.set('ListingStillHeld', () => new ListingStillHeldError());
+
+/**
+ * ListingConfigUpToDate: 'The listing config already uses the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingConfigUpToDateError extends Error {
+  readonly code: number = 0x1791;
+  readonly name: string = 'ListingConfigUpToDate';
+  constructor() {
+    super('The listing config already uses the current layout');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingConfigUpToDateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new ListingConfigUpToDateError());
+createErrorFromNameLookup.set('ListingConfigUpToDate', () => new ListingConfigUpToDateError());
+
+/**
+ * InvalidAutoAcceptPrice: 'The auto accept price must be non-zero and at least the reserve price'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAutoAcceptPriceError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = 'InvalidAutoAcceptPrice';
+  constructor() {
+    super('The auto accept price must be non-zero and at least the reserve price');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAutoAcceptPriceError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new InvalidAutoAcceptPriceError());
+createErrorFromNameLookup.set('InvalidAutoAcceptPrice', () => new InvalidAutoAcceptPriceError());
+
+/**
+ * AuctionPaused: 'The auction is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionPausedError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = 'AuctionPaused';
+  constructor() {
+    super('The auction is paused');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionPausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new AuctionPausedError());
+createErrorFromNameLookup.set('AuctionPaused', () => new AuctionPausedError());
+
+/**
+ * BidTiesHighestBid: 'Bid ties the highest bid, which was placed first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidTiesHighestBidError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = 'BidTiesHighestBid';
+  constructor() {
+    super('Bid ties the highest bid, which was placed first');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidTiesHighestBidError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new BidTiesHighestBidError());
+createErrorFromNameLookup.set('BidTiesHighestBid', () => new BidTiesHighestBidError());
+
+/**
+ * InvalidEndTimeBucket: 'End time index buckets must start on a bucket boundary'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidEndTimeBucketError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = 'InvalidEndTimeBucket';
+  constructor() {
+    super('End time index buckets must start on a bucket boundary');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidEndTimeBucketError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new InvalidEndTimeBucketError());
+createErrorFromNameLookup.set('InvalidEndTimeBucket', () => new InvalidEndTimeBucketError());
+
+/**
+ * InvalidEndTimeIndex: 'The end time index does not cover the listing's auction house and end time'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidEndTimeIndexError extends Error {
+  readonly code: number = 0x1796;
+  readonly name: string = 'InvalidEndTimeIndex';
+  constructor() {
+    super("The end time index does not cover the listing's auction house and end time");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidEndTimeIndexError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new InvalidEndTimeIndexError());
+createErrorFromNameLookup.set('InvalidEndTimeIndex', () => new InvalidEndTimeIndexError());
+
+/**
+ * EndTimeIndexFull: 'The end time index bucket is full'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EndTimeIndexFullError extends Error {
+  readonly code: number = 0x1797;
+  readonly name: string = 'EndTimeIndexFull';
+  constructor() {
+    super('The end time index bucket is full');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EndTimeIndexFullError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new EndTimeIndexFullError());
+createErrorFromNameLookup.set('EndTimeIndexFull', () => new EndTimeIndexFullError());
+
+/**
+ * InvalidBuyNowPrice: 'The buy now price must be non-zero'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBuyNowPriceError extends Error {
+  readonly code: number = 0x1798;
+  readonly name: string = 'InvalidBuyNowPrice';
+  constructor() {
+    super('The buy now price must be non-zero');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBuyNowPriceError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new InvalidBuyNowPriceError());
+createErrorFromNameLookup.set('InvalidBuyNowPrice', () => new InvalidBuyNowPriceError());
+
+/**
+ * InvalidBidQualification: 'A bid qualification must require a non-zero escrow balance'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidQualificationError extends Error {
+  readonly code: number = 0x1799;
+  readonly name: string = 'InvalidBidQualification';
+  constructor() {
+    super('A bid qualification must require a non-zero escrow balance');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidQualificationError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new InvalidBidQualificationError());
+createErrorFromNameLookup.set('InvalidBidQualification', () => new InvalidBidQualificationError());
+
+/**
+ * InsufficientEscrowBalance: 'The wallet's escrow balance is below the listing's bidding minimum'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InsufficientEscrowBalanceError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = 'InsufficientEscrowBalance';
+  constructor() {
+    super("The wallet's escrow balance is below the listing's bidding minimum");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InsufficientEscrowBalanceError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new InsufficientEscrowBalanceError());
+createErrorFromNameLookup.set(
+  'InsufficientEscrowBalance',
+  () => new InsufficientEscrowBalanceError(),
+);
+
+/**
+ * NotListingSeller: 'Only the seller holding the listed token can cancel the listing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotListingSellerError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = 'NotListingSeller';
+  constructor() {
+    super('Only the seller holding the listed token can cancel the listing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotListingSellerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new NotListingSellerError());
+createErrorFromNameLookup.set('NotListingSeller', () => new NotListingSellerError());
+
+/**
+ * ListingNotOrphaned: 'The listing's trade state is still open'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingNotOrphanedError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = 'ListingNotOrphaned';
+  constructor() {
+    super("The listing's trade state is still open");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingNotOrphanedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new ListingNotOrphanedError());
+createErrorFromNameLookup.set('ListingNotOrphaned', () => new ListingNotOrphanedError());
+
+/**
+ * SettlementPriceMismatch: 'The settlement price must equal the highest bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SettlementPriceMismatchError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'SettlementPriceMismatch';
+  constructor() {
+    super('The settlement price must equal the highest bid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SettlementPriceMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new SettlementPriceMismatchError());
+createErrorFromNameLookup.set('SettlementPriceMismatch', () => new SettlementPriceMismatchError());
+
+/**
+ * BackupBidTooLow: 'The listing's backup bids are full and the bid does not beat any of them'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BackupBidTooLowError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'BackupBidTooLow';
+  constructor() {
+    super("The listing's backup bids are full and the bid does not beat any of them");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BackupBidTooLowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new BackupBidTooLowError());
+createErrorFromNameLookup.set('BackupBidTooLow', () => new BackupBidTooLowError());
+
+/**
+ * HighestBidCanSettle: 'The highest bidder can still settle their bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class HighestBidCanSettleError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'HighestBidCanSettle';
+  constructor() {
+    super('The highest bidder can still settle their bid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, HighestBidCanSettleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new HighestBidCanSettleError());
+createErrorFromNameLookup.set('HighestBidCanSettle', () => new HighestBidCanSettleError());
+
+/**
+ * NoBackupBids: 'The listing has no backup bids left'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoBackupBidsError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'NoBackupBids';
+  constructor() {
+    super('The listing has no backup bids left');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoBackupBidsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new NoBackupBidsError());
+createErrorFromNameLookup.set('NoBackupBids', () => new NoBackupBidsError());
+
+/**
+ * SlotListingNotIndexable: 'Listings timed in slots cannot be added to an end time index'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SlotListingNotIndexableError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'SlotListingNotIndexable';
+  constructor() {
+    super('Listings timed in slots cannot be added to an end time index');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SlotListingNotIndexableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new SlotListingNotIndexableError());
+createErrorFromNameLookup.set('SlotListingNotIndexable', () => new SlotListingNotIndexableError());
+
+/**
+ * InvalidBidRateLimit: 'A bid rate limit must set a minimum slot gap or cap the number of bids per wallet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidRateLimitError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'InvalidBidRateLimit';
+  constructor() {
+    super('A bid rate limit must set a minimum slot gap or cap the number of bids per wallet');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidRateLimitError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new InvalidBidRateLimitError());
+createErrorFromNameLookup.set('InvalidBidRateLimit', () => new InvalidBidRateLimitError());
+
+/**
+ * WalletBidTooSoon: 'The wallet must wait more slots before bidding on the listing again'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletBidTooSoonError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'WalletBidTooSoon';
+  constructor() {
+    super('The wallet must wait more slots before bidding on the listing again');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletBidTooSoonError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new WalletBidTooSoonError());
+createErrorFromNameLookup.set('WalletBidTooSoon', () => new WalletBidTooSoonError());
+
+/**
+ * WalletBidCountExceeded: 'The wallet has placed the listing's maximum number of bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WalletBidCountExceededError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'WalletBidCountExceeded';
+  constructor() {
+    super("The wallet has placed the listing's maximum number of bids");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WalletBidCountExceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new WalletBidCountExceededError());
+createErrorFromNameLookup.set('WalletBidCountExceeded', () => new WalletBidCountExceededError());
+
+/**
+ * AuctionSummaryFinal: 'The auction summary has already been written'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionSummaryFinalError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'AuctionSummaryFinal';
+  constructor() {
+    super('The auction summary has already been written');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionSummaryFinalError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new AuctionSummaryFinalError());
+createErrorFromNameLookup.set('AuctionSummaryFinal', () => new AuctionSummaryFinalError());
+
+/**
+ * PseudonymousBidRequiresNativeTreasury: 'Pseudonymous bids are only supported for native SOL treasuries'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PseudonymousBidRequiresNativeTreasuryError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'PseudonymousBidRequiresNativeTreasury';
+  constructor() {
+    super('Pseudonymous bids are only supported for native SOL treasuries');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PseudonymousBidRequiresNativeTreasuryError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new PseudonymousBidRequiresNativeTreasuryError());
+createErrorFromNameLookup.set(
+  'PseudonymousBidRequiresNativeTreasury',
+  () => new PseudonymousBidRequiresNativeTreasuryError(),
+);
+
+/**
+ * PseudonymCommitmentMismatch: 'The wallet and salt do not match the pseudonymous bidder's commitment'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PseudonymCommitmentMismatchError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'PseudonymCommitmentMismatch';
+  constructor() {
+    super("The wallet and salt do not match the pseudonymous bidder's commitment");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PseudonymCommitmentMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new PseudonymCommitmentMismatchError());
+createErrorFromNameLookup.set(
+  'PseudonymCommitmentMismatch',
+  () => new PseudonymCommitmentMismatchError(),
+);
+
+/**
+ * PseudonymousBidHighest: 'The pseudonymous bid is the listing's highest bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PseudonymousBidHighestError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'PseudonymousBidHighest';
+  constructor() {
+    super("The pseudonymous bid is the listing's highest bid");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PseudonymousBidHighestError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new PseudonymousBidHighestError());
+createErrorFromNameLookup.set('PseudonymousBidHighest', () => new PseudonymousBidHighestError());
+
+/**
+ * InvalidBidderAllowlist: 'A bidder allowlist must list at most 32 bidders and list at least one or set a merkle root'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBidderAllowlistError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'InvalidBidderAllowlist';
+  constructor() {
+    super('A bidder allowlist must list at most 32 bidders and list at least one or set a merkle root');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBidderAllowlistError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new InvalidBidderAllowlistError());
+createErrorFromNameLookup.set('InvalidBidderAllowlist', () => new InvalidBidderAllowlistError());
+
+/**
+ * BidderNotInvited: 'The wallet is not invited to bid on the listing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidderNotInvitedError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'BidderNotInvited';
+  constructor() {
+    super('The wallet is not invited to bid on the listing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidderNotInvitedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new BidderNotInvitedError());
+createErrorFromNameLookup.set('BidderNotInvited', () => new BidderNotInvitedError());
+
+/**
+ * InvalidAllowlistProof: 'The proof does not show the wallet is in the listing's bidder allowlist'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAllowlistProofError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'InvalidAllowlistProof';
+  constructor() {
+    super("The proof does not show the wallet is in the listing's bidder allowlist");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAllowlistProofError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new InvalidAllowlistProofError());
+createErrorFromNameLookup.set('InvalidAllowlistProof', () => new InvalidAllowlistProofError());
+
+/**
+ * LotMetadataTooLong: 'Lot metadata must be at most 256 bytes'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LotMetadataTooLongError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'LotMetadataTooLong';
+  constructor() {
+    super('Lot metadata must be at most 256 bytes');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LotMetadataTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new LotMetadataTooLongError());
+createErrorFromNameLookup.set('LotMetadataTooLong', () => new LotMetadataTooLongError());
+
+/**
+ * InvalidDurationBounds: 'Minimum auction duration must not exceed the maximum'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDurationBoundsError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'InvalidDurationBounds';
+  constructor() {
+    super('Minimum auction duration must not exceed the maximum');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDurationBoundsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new InvalidDurationBoundsError());
+createErrorFromNameLookup.set('InvalidDurationBounds', () => new InvalidDurationBoundsError());
+
+/**
+ * AuctionTooShort: 'The auction is shorter than the house's minimum duration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionTooShortError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'AuctionTooShort';
+  constructor() {
+    super("The auction is shorter than the house's minimum duration");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionTooShortError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new AuctionTooShortError());
+createErrorFromNameLookup.set('AuctionTooShort', () => new AuctionTooShortError());
+
+/**
+ * AuctionTooLong: 'The auction ends later than the house's maximum duration allows'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionTooLongError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'AuctionTooLong';
+  constructor() {
+    super("The auction ends later than the house's maximum duration allows");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new AuctionTooLongError());
+createErrorFromNameLookup.set('AuctionTooLong', () => new AuctionTooLongError());
+
+/**
+ * ListingConfigLayoutMismatch: 'The listing config data does not match the layout of its version'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingConfigLayoutMismatchError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'ListingConfigLayoutMismatch';
+  constructor() {
+    super('The listing config data does not match the layout of its version');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingConfigLayoutMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new ListingConfigLayoutMismatchError());
+createErrorFromNameLookup.set(
+  'ListingConfigLayoutMismatch',
+  () => new ListingConfigLayoutMismatchError(),
+);
+
+/**
+ * ListingConfigOutdated: 'The listing config must be migrated to the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingConfigOutdatedError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'ListingConfigOutdated';
+  constructor() {
+    super('The listing config must be migrated to the current layout');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingConfigOutdatedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new ListingConfigOutdatedError());
+createErrorFromNameLookup.set('ListingConfigOutdated', () => new ListingConfigOutdatedError());
+
+/**
+ * InvalidFeatureAccount: 'The account does not match the listing's feature account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeatureAccountError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'InvalidFeatureAccount';
+  constructor() {
+    super("The account does not match the listing's feature account");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFeatureAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new InvalidFeatureAccountError());
+createErrorFromNameLookup.set('InvalidFeatureAccount', () => new InvalidFeatureAccountError());
+
+/**
+ * PoolTokenRedeemed: 'The pool's token has already been redeemed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PoolTokenRedeemedError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'PoolTokenRedeemed';
+  constructor() {
+    super("The pool's token has already been redeemed");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PoolTokenRedeemedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new PoolTokenRedeemedError());
+createErrorFromNameLookup.set('PoolTokenRedeemed', () => new PoolTokenRedeemedError());
+
+/**
+ * InvalidRevealWindow: 'The reveal window must be positive'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRevealWindowError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = 'InvalidRevealWindow';
+  constructor() {
+    super('The reveal window must be positive');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRevealWindowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new InvalidRevealWindowError());
+createErrorFromNameLookup.set('InvalidRevealWindow', () => new InvalidRevealWindowError());
+
+/**
+ * RevealWindowClosed: 'The private room's reveal window has closed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RevealWindowClosedError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = 'RevealWindowClosed';
+  constructor() {
+    super("The private room's reveal window has closed");
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RevealWindowClosedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new RevealWindowClosedError());
+createErrorFromNameLookup.set('RevealWindowClosed', () => new RevealWindowClosedError());
+
+/**
+ * ListingNotAdoptable: 'Listings with feature accounts or that sold cannot be adopted'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingNotAdoptableError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = 'ListingNotAdoptable';
+  constructor() {
+    super('Listings with feature accounts or that sold cannot be adopted');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingNotAdoptableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new ListingNotAdoptableError());
+createErrorFromNameLookup.set('ListingNotAdoptable', () => new ListingNotAdoptableError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
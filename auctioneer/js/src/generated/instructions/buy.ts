@@ -47,7 +47,7 @@ const buyStruct = new beet.BeetArgsStruct<
  * @property [] auctionHouseProgram
  * @property [_writable_] listingConfig
  * @property [] seller
- * @property [**signer**] wallet
+ * @property [_writable_, **signer**] wallet
  * @property [_writable_] paymentAccount
  * @property [] transferAuthority
  * @property [] treasuryMint
@@ -137,7 +137,7 @@ export function createBuyInstruction(accounts: BuyInstructionAccounts, args: Buy
     },
     {
       pubkey: wallet,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
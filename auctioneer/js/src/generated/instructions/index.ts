@@ -1,7 +1,48 @@
+export * from './acceptBidderInvitation';
+export * from './addDropItem';
+export * from './adoptListing';
 export * from './authorize';
 export * from './buy';
+export * from './buyNow';
 export * from './cancel';
+export * from './cancelListing';
+export * from './claimPoolShares';
+export * from './claimPseudonymousBid';
+export * from './closeOrphanedListingConfig';
+export * from './contributeToPool';
+export * from './createAuctionSummary';
+export * from './createAutoAccept';
+export * from './createBackupBids';
+export * from './createBidLimit';
+export * from './createBidPool';
+export * from './createBidQualification';
+export * from './createBidRateLimit';
+export * from './createBidderAllowlist';
+export * from './createBlindBox';
+export * from './createBuyNow';
+export * from './createDropSchedule';
+export * from './createEndTimeIndex';
+export * from './createPrivateRoom';
 export * from './deposit';
 export * from './executeSale';
+export * from './migrateListingConfig';
+export * from './openNextDrop';
+export * from './pauseListing';
+export * from './placePoolBid';
+export * from './placePseudonymousBid';
+export * from './placeSealedBid';
+export * from './promoteBackupBid';
+export * from './pruneEndTimeIndex';
+export * from './redeemPoolToken';
+export * from './refundPoolContribution';
+export * from './resumeListing';
+export * from './revealBlindBox';
+export * from './revealSealedBid';
 export * from './sell';
+export * from './sellMany';
+export * from './setDurationBounds';
+export * from './setListingFee';
 export * from './withdraw';
+export * from './withdrawDropProceeds';
+export * from './withdrawPoolEscrow';
+export * from './withdrawPseudonymousEscrow';
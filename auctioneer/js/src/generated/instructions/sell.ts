@@ -8,6 +8,7 @@
 import * as splToken from '@solana/spl-token';
 import * as beet from '@metaplex-foundation/beet';
 import * as web3 from '@solana/web3.js';
+import { ListingClock, listingClockBeet } from '../types/ListingClock';
 
 /**
  * @category Instructions
@@ -27,6 +28,9 @@ export type SellInstructionArgs = {
   timeExtPeriod: beet.COption<number>;
   timeExtDelta: beet.COption<number>;
   allowHighBidCancel: beet.COption<boolean>;
+  tickSize: beet.COption<beet.bignum>;
+  clock: beet.COption<ListingClock>;
+  lotMetadata: beet.COption<Uint8Array>;
 };
 /**
  * @category Instructions
@@ -52,6 +56,9 @@ const sellStruct = new beet.FixableBeetArgsStruct<
     ['timeExtPeriod', beet.coption(beet.u32)],
     ['timeExtDelta', beet.coption(beet.u32)],
     ['allowHighBidCancel', beet.coption(beet.bool)],
+    ['tickSize', beet.coption(beet.u64)],
+    ['clock', beet.coption(listingClockBeet)],
+    ['lotMetadata', beet.coption(beet.bytes)],
   ],
   'SellInstructionArgs',
 );
@@ -60,6 +67,7 @@ const sellStruct = new beet.FixableBeetArgsStruct<
  *
  * @property [] auctionHouseProgram
  * @property [_writable_] listingConfig
+ * @property [] durationBounds
  * @property [_writable_] wallet
  * @property [_writable_] tokenAccount
  * @property [] metadata
@@ -78,6 +86,7 @@ const sellStruct = new beet.FixableBeetArgsStruct<
 export type SellInstructionAccounts = {
   auctionHouseProgram: web3.PublicKey;
   listingConfig: web3.PublicKey;
+  durationBounds: web3.PublicKey;
   wallet: web3.PublicKey;
   tokenAccount: web3.PublicKey;
   metadata: web3.PublicKey;
@@ -110,6 +119,7 @@ export function createSellInstruction(
   const {
     auctionHouseProgram,
     listingConfig,
+    durationBounds,
     wallet,
     tokenAccount,
     metadata,
@@ -138,6 +148,11 @@ export function createSellInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: durationBounds,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: wallet,
       isWritable: true,
@@ -13,6 +13,8 @@ export type Bid = {
   version: ListingConfigVersion;
   amount: beet.bignum;
   buyerTradeState: web3.PublicKey;
+  slot: beet.bignum;
+  sequence: beet.bignum;
 };
 
 /**
@@ -24,6 +26,8 @@ export const bidBeet = new beet.BeetArgsStruct<Bid>(
     ['version', listingConfigVersionBeet],
     ['amount', beet.u64],
     ['buyerTradeState', beetSolana.publicKey],
+    ['slot', beet.u64],
+    ['sequence', beet.u64],
   ],
   'Bid',
 );
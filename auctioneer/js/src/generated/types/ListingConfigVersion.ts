@@ -12,6 +12,12 @@ import * as beet from '@metaplex-foundation/beet';
  */
 export enum ListingConfigVersion {
   V0,
+  V1,
+  V2,
+  V3,
+  V4,
+  V5,
+  V6,
 }
 
 /**
@@ -1,2 +1,6 @@
 export * from './Bid';
+export * from './ListingClock';
 export * from './ListingConfigVersion';
+export * from './ListingFeatures';
+export * from './RevealArgs';
+export * from './SellManyListing';
@@ -0,0 +1,246 @@
+//! Listing portability: the new owner of a listed token takes over the listing after the token
+//! changed hands outside the house.
+use crate::{constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
+use anchor_spl::token::{Token, TokenAccount};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
+    cpi::accounts::AuctioneerSell as AHSell,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+
+use solana_program::program::invoke_signed;
+
+/// Accounts for the [`adopt_listing` handler](fn.adopt_listing.html).
+#[derive(Accounts)]
+#[instruction(trade_state_bump: u8, free_trade_state_bump: u8, program_as_signer_bump: u8, auctioneer_authority_bump: u8, token_size: u64)]
+pub struct AdoptListing<'info> {
+    /// Auction House Program used for CPI call
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// The previous seller's Listing Config the new listing copies its settings from.
+    #[account(
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            previous_seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            previous_token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=previous_listing_config.bump,
+    )]
+    pub previous_listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// CHECK: Checked via previous_listing_config seeds.
+    /// The wallet that created the listing.
+    pub previous_seller: UncheckedAccount<'info>,
+
+    /// SPL token account the listing was created from.
+    #[account(constraint = previous_token_account.mint == token_account.mint)]
+    pub previous_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account of the previous listing, closed if the listing sold.
+    #[account(seeds=[PREFIX.as_bytes(), previous_seller.key().as_ref(), auction_house.key().as_ref(), previous_token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_account.mint.as_ref(), &u64::MAX.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump)]
+    pub previous_seller_trade_state: UncheckedAccount<'info>,
+
+    /// The new owner's Listing Config.
+    #[account(
+        init,
+        payer=wallet,
+        space=LISTING_CONFIG_SIZE,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    // Accounts passed into Auction House CPI call
+    /// New owner wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account of the new owner containing the listed token.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified through CPI
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump=auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the new owner's sell order.
+    #[account(mut, seeds=[PREFIX.as_bytes(), wallet.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_account.mint.as_ref(), &u64::MAX.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump=trade_state_bump)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(mut, seeds=[PREFIX.as_bytes(), wallet.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_account.mint.as_ref(), &0u64.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump=free_trade_state_bump)]
+    pub free_seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+            ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], seeds::program=auction_house_program, bump=program_as_signer_bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Take over a listing whose seller no longer holds the listed token. The new owner gets a
+/// listing with the same schedule and settings, and the program is approved as the delegate of
+/// their token account.
+///
+/// Bids on the previous listing name the previous token account and cannot settle against the
+/// new one, so the adopted listing starts without a highest bid. The previous listing is left for
+/// its seller to cancel and reclaim its rent.
+///
+/// Feature accounts such as a private room or blind box are keyed on the previous listing, so
+/// listings with any cannot be adopted. Neither can a listing that sold through the house, which
+/// closes its seller trade state; a drop schedule's items only ever leave its vault that way.
+pub fn adopt_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, AdoptListing<'info>>,
+    trade_state_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    auctioneer_authority_bump: u8,
+    token_size: u64,
+) -> Result<()> {
+    if ctx.accounts.previous_token_account.amount >= token_size {
+        return err!(AuctioneerError::ListingStillHeld);
+    }
+
+    let previous_listing_config = &ctx.accounts.previous_listing_config;
+    if previous_listing_config.features != ListingFeatures::default()
+        || ctx.accounts.previous_seller_trade_state.data_is_empty()
+    {
+        return err!(AuctioneerError::ListingNotAdoptable);
+    }
+
+    if listing_clock_now(previous_listing_config)? > previous_listing_config.end_time {
+        return err!(AuctioneerError::AuctionEnded);
+    }
+
+    let listing_config = &mut ctx.accounts.listing_config;
+    listing_config.version = ListingConfigVersion::V6;
+    listing_config.highest_bid.version = ListingConfigVersion::V6;
+    listing_config.start_time = previous_listing_config.start_time;
+    listing_config.end_time = previous_listing_config.end_time;
+    listing_config.reserve_price = previous_listing_config.reserve_price;
+    listing_config.min_bid_increment = previous_listing_config.min_bid_increment;
+    listing_config.time_ext_period = previous_listing_config.time_ext_period;
+    listing_config.time_ext_delta = previous_listing_config.time_ext_delta;
+    listing_config.allow_high_bid_cancel = previous_listing_config.allow_high_bid_cancel;
+    listing_config.tick_size = previous_listing_config.tick_size;
+    listing_config.fee_basis_points = None;
+    listing_config.clock = previous_listing_config.clock;
+    listing_config.features = ListingFeatures::default();
+    listing_config.lot_metadata = previous_listing_config.lot_metadata.clone();
+    listing_config.bump = *ctx
+        .bumps
+        .get("listing_config")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHSell {
+        wallet: ctx.accounts.wallet.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+        free_seller_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let sell_data = mpl_auction_house::instruction::AuctioneerSell {
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        token_size,
+    };
+
+    let mut cpi_account_metas: Vec<AccountMeta> = cpi_accounts
+        .to_account_metas(None)
+        .into_iter()
+        .zip(cpi_accounts.to_account_infos())
+        .map(|mut pair| {
+            pair.0.is_signer = pair.1.is_signer;
+            if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                pair.0.is_signer = true;
+            }
+            pair.0
+        })
+        .collect();
+
+    cpi_account_metas.append(&mut ctx.remaining_accounts.to_vec().to_account_metas(None));
+
+    let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
+    cpi_account_infos.append(&mut ctx.remaining_accounts.to_vec());
+
+    let ix = solana_program::instruction::Instruction {
+        program_id: cpi_program.key(),
+        accounts: cpi_account_metas,
+        data: sell_data.data(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds])?;
+
+    Ok(())
+}
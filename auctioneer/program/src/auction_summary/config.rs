@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+use crate::sell::config::ListingClock;
+
+#[constant]
+pub const AUCTION_SUMMARY_SIZE: usize =
+    8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 8 + 8 + 2 + 8 + 8 + 1 + 1;
+
+/// The permanent provenance record of a settled auction. It is reserved while the listing is
+/// live and written once, when the sale executes, after which nothing can change or close it.
+#[account]
+pub struct AuctionSummary {
+    pub listing_config: Pubkey,
+    pub token_mint: Pubkey,
+    pub seller: Pubkey,
+    pub winner: Pubkey,
+    pub price: u64,
+    /// Number of bids that took the lead on the listing.
+    pub bid_count: u64,
+    /// What `start_time` and `duration` are measured in.
+    pub clock: ListingClock,
+    pub start_time: UnixTimestamp,
+    /// Length of the auction including any time extensions.
+    pub duration: i64,
+    pub royalty_basis_points: u16,
+    /// Royalties owed to the token's creators on the sale price.
+    pub royalties: u64,
+    pub settled_at: UnixTimestamp,
+    pub is_final: bool,
+    pub bump: u8,
+}
@@ -0,0 +1,137 @@
+//! Auction summaries, a compact record of a settled auction (winner, price, bid count, duration
+//! and royalties) that galleries can reference as its permanent provenance. The seller reserves
+//! one while the listing is live, since the listing config it summarizes is closed by the sale.
+pub mod config;
+
+use crate::{auction_summary::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+
+/// Accounts for the [`create_auction_summary` handler](fn.create_auction_summary.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateAuctionSummary<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The auction summary for the listing, keyed by its start time so relisting the token
+    /// reserves a new one.
+    #[account(
+        init,
+        payer=wallet,
+        space=AUCTION_SUMMARY_SIZE,
+        seeds=[
+            AUCTION_SUMMARY.as_bytes(),
+            listing_config.key().as_ref(),
+            &listing_config.start_time.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub auction_summary: Box<Account<'info, AuctionSummary>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Reserve the auction summary the listing's sale will be recorded in.
+pub fn create_auction_summary<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAuctionSummary<'info>>,
+    _token_size: u64,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    // A paused listing's start time is a placeholder the summary must not be keyed by.
+    assert_not_paused(&ctx.accounts.listing_config)?;
+
+    let listing_config = &ctx.accounts.listing_config;
+    ctx.accounts.listing_config.features.auction_summary = true;
+
+    let auction_summary = &mut ctx.accounts.auction_summary;
+    auction_summary.listing_config = listing_config.key();
+    auction_summary.token_mint = ctx.accounts.token_account.mint;
+    auction_summary.seller = ctx.accounts.wallet.key();
+    auction_summary.clock = listing_config.clock;
+    auction_summary.start_time = listing_config.start_time;
+    auction_summary.bump = *ctx
+        .bumps
+        .get("auction_summary")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Write the final record of the sale to the listing's auction summary, if it has one.
+pub fn write_auction_summary<'info>(
+    auction_summary: Option<&AccountInfo<'info>>,
+    listing_config: &Account<'info, ListingConfig>,
+    buyer: &Pubkey,
+    metadata: &AccountInfo<'info>,
+    buyer_price: u64,
+) -> Result<()> {
+    let auction_summary = match auction_summary {
+        Some(auction_summary) => auction_summary,
+        None => return Ok(()),
+    };
+
+    let mut summary =
+        AuctionSummary::try_deserialize(&mut &auction_summary.try_borrow_data()?[..])?;
+    if summary.is_final {
+        return err!(AuctioneerError::AuctionSummaryFinal);
+    }
+
+    let royalty_basis_points = Metadata::from_account_info(metadata)?
+        .data
+        .seller_fee_basis_points;
+    let royalties = u128::from(buyer_price)
+        .checked_mul(u128::from(royalty_basis_points))
+        .map(|royalties| royalties / 10000)
+        .and_then(|royalties| u64::try_from(royalties).ok())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    summary.winner = *buyer;
+    summary.price = buyer_price;
+    summary.bid_count = listing_config.highest_bid.sequence;
+    summary.duration = listing_config
+        .end_time
+        .checked_sub(listing_config.start_time)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    summary.royalty_basis_points = royalty_basis_points;
+    summary.royalties = royalties;
+    summary.settled_at = Clock::get()?.unix_timestamp;
+    summary.is_final = true;
+    summary.try_serialize(&mut *auction_summary.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
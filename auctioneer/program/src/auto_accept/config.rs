@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const AUTO_ACCEPT_SIZE: usize = 8 + 32 + 8 + 1;
+
+/// Price at or above which the highest bid on a listing may be settled before the auction ends.
+#[account]
+pub struct AutoAccept {
+    pub listing_config: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
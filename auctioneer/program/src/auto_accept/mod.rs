@@ -0,0 +1,120 @@
+//! Auto-accept thresholds letting a listing settle early once a high enough bid arrives.
+pub mod config;
+
+use crate::{auto_accept::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+
+/// Accounts for the [`create_auto_accept` handler](fn.create_auto_accept.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateAutoAccept<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The auto-accept threshold for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=AUTO_ACCEPT_SIZE,
+        seeds=[AUTO_ACCEPT.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub auto_accept: Box<Account<'info, AutoAccept>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Let anyone settle the listing before it ends once its highest bid reaches `price`. The
+/// seller's signature here stands in for accepting that bid.
+pub fn create_auto_accept<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateAutoAccept<'info>>,
+    _token_size: u64,
+    price: u64,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if price == 0 || price < ctx.accounts.listing_config.reserve_price {
+        return err!(AuctioneerError::InvalidAutoAcceptPrice);
+    }
+
+    ctx.accounts.listing_config.features.auto_accept = true;
+
+    let auto_accept = &mut ctx.accounts.auto_accept;
+    auto_accept.listing_config = ctx.accounts.listing_config.key();
+    auto_accept.price = price;
+    auto_accept.bump = *ctx
+        .bumps
+        .get("auto_accept")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Whether the listing's highest bid has reached its auto-accept price. Listings without an
+/// auto-accept threshold never qualify.
+pub fn auto_accept_reached(
+    auto_accept: Option<&AccountInfo>,
+    listing_config: &Account<ListingConfig>,
+) -> Result<bool> {
+    let auto_accept = match auto_accept {
+        Some(auto_accept) => auto_accept,
+        None => return Ok(false),
+    };
+
+    let auto_accept = AutoAccept::try_deserialize(&mut &auto_accept.try_borrow_data()?[..])?;
+
+    Ok(listing_config.highest_bid.amount >= auto_accept.price)
+}
+
+/// Close the listing's auto-accept threshold, if it has one, to the seller.
+pub fn close_auto_accept<'info>(
+    auto_accept: Option<&AccountInfo<'info>>,
+    seller: &AccountInfo<'info>,
+) -> Result<()> {
+    let auto_accept = match auto_accept {
+        Some(auto_accept) => auto_accept,
+        None => return Ok(()),
+    };
+
+    let auto_accept_lamports = auto_accept.lamports();
+    **seller.lamports.borrow_mut() = seller.lamports().checked_add(auto_accept_lamports).unwrap();
+    **auto_accept.lamports.borrow_mut() = 0;
+
+    let mut source_data = auto_accept.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
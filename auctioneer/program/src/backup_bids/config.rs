@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_BACKUP_BIDS, sell::config::*};
+
+#[constant]
+pub const BACKUP_BIDS_SIZE: usize = 8 + 32 + 4 + BID_SIZE * MAX_BACKUP_BIDS + 1;
+
+/// The next-highest bids on a listing, ordered from highest to lowest, that a crank can fall
+/// back to when the winner cannot settle. Bids that never led the listing have a sequence of 0.
+#[account]
+pub struct BackupBids {
+    pub listing_config: Pubkey,
+    pub bids: Vec<Bid>,
+    pub bump: u8,
+}
@@ -0,0 +1,246 @@
+//! Backup bids, so an auction whose winner fails to settle can fall back to the next-highest bid
+//! instead of failing entirely. Listings with backup bids also accept bids at or below the
+//! highest, which queue behind it without extending the auction.
+pub mod config;
+
+use crate::{backup_bids::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+
+/// Accounts for the [`create_backup_bids` handler](fn.create_backup_bids.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBackupBids<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The backup bids for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BACKUP_BIDS_SIZE,
+        seeds=[BACKUP_BIDS.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub backup_bids: Box<Account<'info, BackupBids>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`promote_backup_bid` handler](fn.promote_backup_bid.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct PromoteBackupBid<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The backup bids for the listing.
+    #[account(mut, seeds=[BACKUP_BIDS.as_bytes(), listing_config.key().as_ref()], bump=backup_bids.bump)]
+    pub backup_bids: Box<Account<'info, BackupBids>>,
+
+    /// CHECK: Checked via listing config seeds.
+    /// The seller of the NFT.
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Checked via listing config seeds.
+    /// SPL token account containing the token for sale.
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Checked via listing config seeds.
+    /// Token mint account for the SPL token.
+    pub token_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Checked via buyer trade state seeds.
+    /// Wallet of the listing's highest bidder.
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Escrow payment account of the highest bidder.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), buyer.key().as_ref()], seeds::program=auction_house_program, bump)]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Trade state of the highest bid, which may have been canceled.
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            buyer.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &listing_config.highest_bid.amount.to_le_bytes(),
+            &token_size.to_le_bytes()
+        ],
+        seeds::program=auction_house_program,
+        bump
+    )]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+}
+
+/// Keep up to `MAX_BACKUP_BIDS` of the next-highest bids on the listing to fall back to if its
+/// winner cannot settle.
+pub fn create_backup_bids<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBackupBids<'info>>,
+    _token_size: u64,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    ctx.accounts.listing_config.features.backup_bids = true;
+
+    let backup_bids = &mut ctx.accounts.backup_bids;
+    backup_bids.listing_config = ctx.accounts.listing_config.key();
+    backup_bids.bids = Vec::new();
+    backup_bids.bump = *ctx
+        .bumps
+        .get("backup_bids")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Replace an ended auction's highest bid with its best backup bid when the highest bidder can no
+/// longer settle, because their bid was canceled or their escrow no longer covers it.
+pub fn promote_backup_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, PromoteBackupBid<'info>>,
+    _token_size: u64,
+) -> Result<()> {
+    assert_auction_over(&ctx.accounts.listing_config)?;
+    assert_highest_bidder(
+        &ctx.accounts.listing_config,
+        ctx.accounts.buyer_trade_state.key(),
+    )?;
+
+    if !ctx.accounts.buyer_trade_state.data_is_empty()
+        && escrow_balance(
+            &ctx.accounts.escrow_payment_account,
+            &ctx.accounts.auction_house.treasury_mint,
+        )? >= ctx.accounts.listing_config.highest_bid.amount
+    {
+        return err!(AuctioneerError::HighestBidCanSettle);
+    }
+
+    let backup_bids = &mut ctx.accounts.backup_bids;
+    if backup_bids.bids.is_empty() {
+        return err!(AuctioneerError::NoBackupBids);
+    }
+    let backup = backup_bids.bids.remove(0);
+
+    let highest_bid = &mut ctx.accounts.listing_config.highest_bid;
+    highest_bid.amount = backup.amount;
+    highest_bid.buyer_trade_state = backup.buyer_trade_state;
+    highest_bid.slot = backup.slot;
+    highest_bid.sequence = highest_bid.sequence.saturating_add(1);
+
+    Ok(())
+}
+
+/// Whether a bid of `buyer_price` queues as a backup bid instead of competing for the lead. Only
+/// listings with backup bids take bids at or below their highest bid.
+pub fn is_backup_bid(
+    backup_bids: Option<&AccountInfo>,
+    listing_config: &Account<ListingConfig>,
+    buyer_price: u64,
+) -> bool {
+    backup_bids.is_some()
+        && listing_config.highest_bid.amount > 0
+        && buyer_price <= listing_config.highest_bid.amount
+}
+
+/// Add `bid` to the listing's backup bids, if it has them, ahead of any lower bids and behind
+/// any equal or higher ones. Once full, the lowest backup bid is dropped to make room.
+pub fn record_backup_bid(backup_bids: Option<&AccountInfo>, bid: Bid) -> Result<()> {
+    let backup_bids = match backup_bids {
+        Some(backup_bids) => backup_bids,
+        None => return Ok(()),
+    };
+
+    let mut backups = BackupBids::try_deserialize(&mut &backup_bids.try_borrow_data()?[..])?;
+
+    let position = backups
+        .bids
+        .iter()
+        .position(|backup| bid.amount > backup.amount)
+        .unwrap_or(backups.bids.len());
+    if position >= MAX_BACKUP_BIDS {
+        return err!(AuctioneerError::BackupBidTooLow);
+    }
+    backups.bids.insert(position, bid);
+    backups.bids.truncate(MAX_BACKUP_BIDS);
+
+    backups.try_serialize(&mut *backup_bids.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Close the listing's backup bids, if it has them, to the seller.
+pub fn close_backup_bids<'info>(
+    backup_bids: Option<&AccountInfo<'info>>,
+    seller: &AccountInfo<'info>,
+) -> Result<()> {
+    let backup_bids = match backup_bids {
+        Some(backup_bids) => backup_bids,
+        None => return Ok(()),
+    };
+
+    let backup_bids_lamports = backup_bids.lamports();
+    **seller.lamports.borrow_mut() = seller.lamports().checked_add(backup_bids_lamports).unwrap();
+    **backup_bids.lamports.borrow_mut() = 0;
+
+    let mut source_data = backup_bids.data.borrow_mut();
+    source_data.fill(0);
+
+    Ok(())
+}
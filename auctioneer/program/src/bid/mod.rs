@@ -11,7 +11,20 @@ use mpl_auction_house::{
     AuctionHouse,
 };
 
-use crate::{constants::*, sell::config::*, utils::*};
+use crate::{
+    backup_bids::{is_backup_bid, record_backup_bid},
+    bid_limit::record_wallet_bid,
+    bid_qualification::assert_bid_qualification,
+    bid_rate_limit::record_wallet_bid_rate,
+    bidder_allowlist::assert_bidder_allowed,
+    constants::*,
+    errors::*,
+    private_room::assert_private_room_bid,
+    sell::config::*,
+    utils::*,
+};
+
+use std::slice::Iter;
 
 /// Accounts for the [`private_bid_with_auctioneer` handler](fn.private_bid_with_auctioneer.html).
 #[derive(Accounts)]
@@ -43,6 +56,7 @@ pub struct AuctioneerBuy<'info> {
 
     // Accounts passed into Auction House CPI call
     /// User wallet account.
+    #[account(mut)]
     wallet: Signer<'info>,
 
     /// CHECK: Verified through CPI
@@ -116,6 +130,208 @@ pub struct AuctioneerBuy<'info> {
     rent: Sysvar<'info, Rent>,
 }
 
+/// The feature accounts a bid reads, taken from the front of `remaining_accounts` in field order
+/// for the features its listing has. Anything after them is passed on to Auction House.
+pub struct BidFeatureAccounts<'a, 'info> {
+    /// The listing's private room.
+    pub private_room: Option<&'a AccountInfo<'info>>,
+    /// The listing's per-wallet bid limit.
+    pub bid_limit: Option<&'a AccountInfo<'info>>,
+    /// The bidder's bid counter and its bump, following the bid limit. Created on the bidder's
+    /// first bid.
+    pub wallet_bid_counter: Option<(&'a AccountInfo<'info>, u8)>,
+    /// The listing's per-wallet bid rate limit.
+    pub bid_rate_limit: Option<&'a AccountInfo<'info>>,
+    /// The bidder's bid rate and its bump, following the bid rate limit. Created on the bidder's
+    /// first bid.
+    pub wallet_bid_rate: Option<(&'a AccountInfo<'info>, u8)>,
+    /// The listing's bidder qualification.
+    pub bid_qualification: Option<&'a AccountInfo<'info>>,
+    /// The listing's bidder allowlist.
+    pub bidder_allowlist: Option<&'a AccountInfo<'info>>,
+    /// The bidder's invitation, following the bidder allowlist. Uninitialized unless the bidder
+    /// proved membership of a merkle allowlist.
+    pub bidder_invitation: Option<&'a AccountInfo<'info>>,
+    /// The listing's backup bids.
+    pub backup_bids: Option<&'a AccountInfo<'info>>,
+}
+
+impl<'a, 'info> BidFeatureAccounts<'a, 'info> {
+    /// Take the feature accounts of a bid from `bidder` on the listing.
+    pub fn next(
+        listing_config: &Account<'info, ListingConfig>,
+        bidder: &Pubkey,
+        remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self> {
+        assert_listing_features_recorded(listing_config)?;
+
+        let features = listing_config.features;
+        let listing_config_key = listing_config.key();
+        let listing_config = listing_config_key.as_ref();
+        let bidder = bidder.as_ref();
+        let account = |(account, _): (&'a AccountInfo<'info>, u8)| account;
+
+        Ok(BidFeatureAccounts {
+            private_room: next_feature_account(
+                remaining_accounts,
+                features.private_room,
+                &[PRIVATE_ROOM.as_bytes(), listing_config],
+            )?
+            .map(account),
+            bid_limit: next_feature_account(
+                remaining_accounts,
+                features.bid_limit,
+                &[BID_LIMIT.as_bytes(), listing_config],
+            )?
+            .map(account),
+            wallet_bid_counter: next_feature_account(
+                remaining_accounts,
+                features.bid_limit,
+                &[WALLET_BID_COUNTER.as_bytes(), listing_config, bidder],
+            )?,
+            bid_rate_limit: next_feature_account(
+                remaining_accounts,
+                features.bid_rate_limit,
+                &[BID_RATE_LIMIT.as_bytes(), listing_config],
+            )?
+            .map(account),
+            wallet_bid_rate: next_feature_account(
+                remaining_accounts,
+                features.bid_rate_limit,
+                &[WALLET_BID_RATE.as_bytes(), listing_config, bidder],
+            )?,
+            bid_qualification: next_feature_account(
+                remaining_accounts,
+                features.bid_qualification,
+                &[BID_QUALIFICATION.as_bytes(), listing_config],
+            )?
+            .map(account),
+            bidder_allowlist: next_feature_account(
+                remaining_accounts,
+                features.bidder_allowlist,
+                &[BIDDER_ALLOWLIST.as_bytes(), listing_config],
+            )?
+            .map(account),
+            bidder_invitation: next_feature_account(
+                remaining_accounts,
+                features.bidder_allowlist,
+                &[BIDDER_INVITATION.as_bytes(), listing_config, bidder],
+            )?
+            .map(account),
+            backup_bids: next_feature_account(
+                remaining_accounts,
+                features.backup_bids,
+                &[BACKUP_BIDS.as_bytes(), listing_config],
+            )?
+            .map(account),
+        })
+    }
+}
+
+/// Check a bid from `bidder` against the listing's per-bidder features and count it towards the
+/// bidder's limits: its allowlist, bid limit, bid rate limit and bid qualification. Every bid
+/// entry point runs this, with `payer` funding the bidder's counters on its first bid.
+#[allow(clippy::too_many_arguments)]
+pub fn assert_bidder_can_bid<'info>(
+    features: &BidFeatureAccounts<'_, 'info>,
+    listing_config: &Pubkey,
+    bidder: &Pubkey,
+    payer: &AccountInfo<'info>,
+    escrow_payment_account: &AccountInfo<'info>,
+    treasury_mint: &Pubkey,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    buyer_price: u64,
+) -> Result<()> {
+    assert_bidder_allowed(
+        features.bidder_allowlist,
+        features.bidder_invitation,
+        bidder,
+    )?;
+
+    if let (Some(bid_limit), Some((wallet_bid_counter, wallet_bid_counter_bump))) =
+        (features.bid_limit, features.wallet_bid_counter)
+    {
+        record_wallet_bid(
+            bid_limit,
+            wallet_bid_counter,
+            listing_config,
+            bidder,
+            payer,
+            system_program,
+            rent,
+            wallet_bid_counter_bump,
+            buyer_price,
+        )?;
+    }
+
+    if let (Some(bid_rate_limit), Some((wallet_bid_rate, wallet_bid_rate_bump))) =
+        (features.bid_rate_limit, features.wallet_bid_rate)
+    {
+        record_wallet_bid_rate(
+            bid_rate_limit,
+            wallet_bid_rate,
+            listing_config,
+            bidder,
+            payer,
+            system_program,
+            rent,
+            wallet_bid_rate_bump,
+        )?;
+    }
+
+    assert_bid_qualification(
+        features.bid_qualification,
+        escrow_payment_account,
+        treasury_mint,
+    )
+}
+
+/// Place a bid from `bidder` on the listing: in its private room, among its backup bids, or as
+/// its new highest bid, extending the auction and keeping the outbid leader as a backup bid.
+pub fn record_listing_bid(
+    features: &BidFeatureAccounts,
+    listing_config: &mut Account<ListingConfig>,
+    bidder: &Pubkey,
+    buyer_trade_state: Pubkey,
+    buyer_price: u64,
+) -> Result<()> {
+    let is_private =
+        assert_private_room_bid(features.private_room, listing_config, bidder, buyer_price)?;
+
+    let is_backup = !is_private && is_backup_bid(features.backup_bids, listing_config, buyer_price);
+
+    if is_backup {
+        assert_auction_active(listing_config)?;
+        assert_exceeds_reserve_price(listing_config, buyer_price)?;
+        return record_backup_bid(
+            features.backup_bids,
+            Bid {
+                version: ListingConfigVersion::V6,
+                amount: buyer_price,
+                buyer_trade_state,
+                slot: Clock::get()?.slot,
+                sequence: 0,
+            },
+        );
+    }
+
+    // The winner of a private room bids the revealed amount after the auction has closed.
+    if !is_private {
+        assert_auction_active(listing_config)?;
+        assert_higher_bid(listing_config, buyer_price)?;
+        assert_exceeds_reserve_price(listing_config, buyer_price)?;
+        process_time_extension(listing_config)?;
+
+        // The outbid leader becomes the first backup bid.
+        if listing_config.highest_bid.amount > 0 {
+            record_backup_bid(features.backup_bids, listing_config.highest_bid.clone())?;
+        }
+    }
+
+    record_highest_bid(listing_config, buyer_price, buyer_trade_state)
+}
+
 /// Create a private bid on a specific SPL token that is *held by a specific wallet*.
 pub fn auctioneer_buy<'info>(
     ctx: Context<'_, '_, '_, 'info, AuctioneerBuy<'info>>,
@@ -125,13 +341,31 @@ pub fn auctioneer_buy<'info>(
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
-    assert_auction_active(&ctx.accounts.listing_config)?;
-    assert_higher_bid(&ctx.accounts.listing_config, buyer_price)?;
-    assert_exceeds_reserve_price(&ctx.accounts.listing_config, buyer_price)?;
-    process_time_extension(&mut ctx.accounts.listing_config)?;
-    ctx.accounts.listing_config.highest_bid.amount = buyer_price;
-    ctx.accounts.listing_config.highest_bid.buyer_trade_state =
-        ctx.accounts.buyer_trade_state.key();
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let features = BidFeatureAccounts::next(
+        &ctx.accounts.listing_config,
+        &ctx.accounts.wallet.key(),
+        remaining_accounts,
+    )?;
+
+    assert_bidder_can_bid(
+        &features,
+        &ctx.accounts.listing_config.key(),
+        &ctx.accounts.wallet.key(),
+        &ctx.accounts.wallet.to_account_info(),
+        &ctx.accounts.escrow_payment_account,
+        &ctx.accounts.treasury_mint.key(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        buyer_price,
+    )?;
+    record_listing_bid(
+        &features,
+        &mut ctx.accounts.listing_config,
+        &ctx.accounts.wallet.key(),
+        ctx.accounts.buyer_trade_state.key(),
+        buyer_price,
+    )?;
 
     let cpi_program = ctx.accounts.auction_house_program.to_account_info();
     let cpi_accounts = AHBuy {
@@ -164,7 +398,10 @@ pub fn auctioneer_buy<'info>(
         &[auctioneer_authority_bump],
     ];
 
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    // Auction House takes its optional bid accounts, such as a compliance hook or bid ticket,
+    // from the `remaining_accounts` after the listing's feature accounts.
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts)
+        .with_remaining_accounts(remaining_accounts.as_slice().to_vec());
     mpl_auction_house::cpi::auctioneer_buy(
         cpi_ctx.with_signer(&[&auctioneer_seeds]),
         trade_state_bump,
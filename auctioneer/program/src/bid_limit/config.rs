@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BID_LIMIT_SIZE: usize = 8 + 32 + 4 + 8 + 1;
+#[constant]
+pub const WALLET_BID_COUNTER_SIZE: usize = 8 + 32 + 32 + 4 + 8 + 1;
+
+/// Per-wallet caps on the bids placed on a listing. A cap of zero is unlimited.
+#[account]
+pub struct BidLimit {
+    pub listing_config: Pubkey,
+    pub max_bids_per_wallet: u32,
+    pub max_amount_per_wallet: u64,
+    pub bump: u8,
+}
+
+/// Bids a wallet currently holds on a listing with a bid limit, and the total amount they commit.
+#[account]
+pub struct WalletBidCounter {
+    pub listing_config: Pubkey,
+    pub wallet: Pubkey,
+    pub active_bids: u32,
+    pub committed_amount: u64,
+    pub bump: u8,
+}
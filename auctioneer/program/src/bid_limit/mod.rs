@@ -0,0 +1,187 @@
+//! Per-wallet limits on the number and total amount of bids on a listing.
+pub mod config;
+
+use crate::{bid_limit::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram,
+    utils::create_or_allocate_account_raw, AuctionHouse,
+};
+
+/// Accounts for the [`create_bid_limit` handler](fn.create_bid_limit.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBidLimit<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The per-wallet bid limit for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BID_LIMIT_SIZE,
+        seeds=[BID_LIMIT.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub bid_limit: Box<Account<'info, BidLimit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cap the number of active bids and the total amount each wallet may bid on a listing without
+/// bids. A cap of zero is unlimited.
+pub fn create_bid_limit<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBidLimit<'info>>,
+    _token_size: u64,
+    max_bids_per_wallet: u32,
+    max_amount_per_wallet: u64,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    if max_bids_per_wallet == 0 && max_amount_per_wallet == 0 {
+        return err!(AuctioneerError::InvalidBidLimit);
+    }
+
+    ctx.accounts.listing_config.features.bid_limit = true;
+
+    let bid_limit = &mut ctx.accounts.bid_limit;
+    bid_limit.listing_config = ctx.accounts.listing_config.key();
+    bid_limit.max_bids_per_wallet = max_bids_per_wallet;
+    bid_limit.max_amount_per_wallet = max_amount_per_wallet;
+    bid_limit.bump = *ctx
+        .bumps
+        .get("bid_limit")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Count a new bid against the listing's bid limit, creating the wallet's counter on its first
+/// bid at `payer`'s expense.
+#[allow(clippy::too_many_arguments)]
+pub fn record_wallet_bid<'info>(
+    bid_limit: &AccountInfo<'info>,
+    wallet_bid_counter: &AccountInfo<'info>,
+    listing_config: &Pubkey,
+    wallet: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    wallet_bid_counter_bump: u8,
+    buyer_price: u64,
+) -> Result<()> {
+    let limit = BidLimit::try_deserialize(&mut &bid_limit.try_borrow_data()?[..])?;
+
+    let mut counter = if wallet_bid_counter.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::id(),
+            wallet_bid_counter,
+            rent,
+            system_program,
+            payer,
+            WALLET_BID_COUNTER_SIZE,
+            &[],
+            &[
+                WALLET_BID_COUNTER.as_bytes(),
+                listing_config.as_ref(),
+                wallet.as_ref(),
+                &[wallet_bid_counter_bump],
+            ],
+        )?;
+
+        WalletBidCounter {
+            listing_config: *listing_config,
+            wallet: *wallet,
+            active_bids: 0,
+            committed_amount: 0,
+            bump: wallet_bid_counter_bump,
+        }
+    } else {
+        WalletBidCounter::try_deserialize(&mut &wallet_bid_counter.try_borrow_data()?[..])?
+    };
+
+    counter.active_bids = counter
+        .active_bids
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    counter.committed_amount = counter
+        .committed_amount
+        .checked_add(buyer_price)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    if limit.max_bids_per_wallet > 0 && counter.active_bids > limit.max_bids_per_wallet {
+        return err!(AuctioneerError::WalletBidLimitExceeded);
+    }
+
+    if limit.max_amount_per_wallet > 0 && counter.committed_amount > limit.max_amount_per_wallet {
+        return err!(AuctioneerError::WalletBidAmountExceeded);
+    }
+
+    counter.try_serialize(&mut *wallet_bid_counter.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Remove a canceled bid from the wallet's counter, closing the counter to the wallet once it
+/// holds no more bids.
+pub fn release_wallet_bid<'info>(
+    wallet_bid_counter: &AccountInfo<'info>,
+    wallet: &AccountInfo<'info>,
+    buyer_price: u64,
+) -> Result<()> {
+    if wallet_bid_counter.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut counter =
+        WalletBidCounter::try_deserialize(&mut &wallet_bid_counter.try_borrow_data()?[..])?;
+    counter.active_bids = counter.active_bids.saturating_sub(1);
+    counter.committed_amount = counter.committed_amount.saturating_sub(buyer_price);
+
+    if counter.active_bids == 0 {
+        let counter_lamports = wallet_bid_counter.lamports();
+        **wallet.lamports.borrow_mut() = wallet.lamports().checked_add(counter_lamports).unwrap();
+        **wallet_bid_counter.lamports.borrow_mut() = 0;
+
+        let mut source_data = wallet_bid_counter.data.borrow_mut();
+        source_data.fill(0);
+    } else {
+        counter.try_serialize(&mut *wallet_bid_counter.try_borrow_mut_data()?)?;
+    }
+
+    Ok(())
+}
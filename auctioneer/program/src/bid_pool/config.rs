@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BID_POOL_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 32 + 8 + 8 + 32 + 1 + 1 + 1;
+#[constant]
+pub const POOL_CONTRIBUTION_SIZE: usize = 8 + 32 + 32 + 8 + 1;
+pub const POOL_SHARE_DECIMALS: u8 = 9;
+
+/// A crowd-buy pool bidding on a listing from its vault with the funds of many contributors.
+#[account]
+pub struct BidPool {
+    pub listing_config: Pubkey,
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_size: u64,
+    pub share_mint: Pubkey,
+    pub total_contributed: u64,
+    pub bid_amount: u64,
+    pub bid_trade_state: Pubkey,
+    pub bump: u8,
+    pub vault_bump: u8,
+    /// Whether a shareholder has taken the won token out of the vault.
+    pub redeemed: bool,
+}
+
+/// Lamports a wallet has contributed to a bid pool.
+#[account]
+pub struct PoolContribution {
+    pub bid_pool: Pubkey,
+    pub contributor: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,1037 @@
+//! Crowd-buy pools bidding on a listing with the pooled funds of many contributors.
+pub mod config;
+
+use crate::{
+    bid::{assert_bidder_can_bid, record_listing_bid, BidFeatureAccounts},
+    bid_pool::config::*,
+    constants::*,
+    errors::*,
+    sell::config::*,
+    utils::*,
+};
+
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{self, spl_token, Burn, Mint, MintTo, Token, TokenAccount, Transfer},
+};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, TRADE_STATE_SIZE},
+    cpi::accounts::{AuctioneerBuy as AHBuy, AuctioneerWithdraw as AHWithdraw},
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::{create_or_allocate_account_raw, make_ata},
+    AuctionHouse,
+};
+
+use solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+/// Accounts for the [`create_bid_pool` handler](fn.create_bid_pool.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBidPool<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Account paying for the pool, its vault and its share mint.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The seller of the NFT
+    /// CHECK: Checked via listing config seeds
+    pub seller: UncheckedAccount<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The bid pool PDA account.
+    #[account(
+        init,
+        payer=payer,
+        space=BID_POOL_SIZE,
+        seeds=[BID_POOL.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA holding the contributions and bidding as the pool's wallet.
+    #[account(mut, seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// Mint of the shares contributors receive when the pool wins.
+    #[account(
+        init,
+        payer=payer,
+        seeds=[BID_POOL_SHARES.as_bytes(), bid_pool.key().as_ref()],
+        bump,
+        mint::decimals=POOL_SHARE_DECIMALS,
+        mint::authority=bid_pool,
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Open a crowd-buy pool on a listing. Contributors fund the pool's vault, which bids as a single
+/// wallet; if it wins, the token stays in the vault and contributors mint shares of it until a
+/// holder of the pool's spent shares redeems them for the token.
+pub fn create_bid_pool<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBidPool<'info>>,
+    token_size: u64,
+) -> Result<()> {
+    if ctx.accounts.auction_house.treasury_mint != spl_token::native_mint::id() {
+        return err!(AuctioneerError::PoolRequiresNativeTreasury);
+    }
+
+    let bid_pool = &mut ctx.accounts.bid_pool;
+    bid_pool.listing_config = ctx.accounts.listing_config.key();
+    bid_pool.auction_house = ctx.accounts.auction_house.key();
+    bid_pool.token_mint = ctx.accounts.token_account.mint;
+    bid_pool.token_size = token_size;
+    bid_pool.share_mint = ctx.accounts.share_mint.key();
+    bid_pool.total_contributed = 0;
+    bid_pool.bid_amount = 0;
+    bid_pool.bid_trade_state = Pubkey::default();
+    bid_pool.redeemed = false;
+    bid_pool.bump = *ctx
+        .bumps
+        .get("bid_pool")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+    bid_pool.vault_bump = *ctx
+        .bumps
+        .get("bid_pool_vault")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    // The vault only ever spends contributions, so it is kept rent exempt.
+    let vault_rent = ctx
+        .accounts
+        .rent
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.bid_pool_vault.lamports());
+    if vault_rent > 0 {
+        invoke(
+            &system_instruction::transfer(
+                &ctx.accounts.payer.key(),
+                &ctx.accounts.bid_pool_vault.key(),
+                vault_rent,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.bid_pool_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Accounts for the [`contribute_to_pool` handler](fn.contribute_to_pool.html).
+#[derive(Accounts)]
+pub struct ContributeToPool<'info> {
+    /// Wallet contributing to the pool.
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// The Listing Config used for listing settings
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The bid pool PDA account.
+    #[account(
+        mut,
+        seeds=[BID_POOL.as_bytes(), listing_config.key().as_ref()],
+        bump=bid_pool.bump,
+        has_one=listing_config,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA holding the contributions and bidding as the pool's wallet.
+    #[account(mut, seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump=bid_pool.vault_bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The contributor's running contribution, created on its first contribution.
+    #[account(mut, seeds=[POOL_CONTRIBUTION.as_bytes(), bid_pool.key().as_ref(), contributor.key().as_ref()], bump)]
+    pub pool_contribution: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Move `amount` lamports into the pool's vault while the listing is live.
+pub fn contribute_to_pool<'info>(
+    ctx: Context<'_, '_, '_, 'info, ContributeToPool<'info>>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return err!(AuctioneerError::InvalidPoolContribution);
+    }
+
+    assert_auction_active(&ctx.accounts.listing_config)?;
+
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.contributor.key(),
+            &ctx.accounts.bid_pool_vault.key(),
+            amount,
+        ),
+        &[
+            ctx.accounts.contributor.to_account_info(),
+            ctx.accounts.bid_pool_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let bid_pool_key = ctx.accounts.bid_pool.key();
+    let contributor = &ctx.accounts.contributor;
+    let pool_contribution = &ctx.accounts.pool_contribution;
+
+    let mut contribution = if pool_contribution.data_is_empty() {
+        let pool_contribution_bump = *ctx
+            .bumps
+            .get("pool_contribution")
+            .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            &pool_contribution.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &contributor.to_account_info(),
+            POOL_CONTRIBUTION_SIZE,
+            &[],
+            &[
+                POOL_CONTRIBUTION.as_bytes(),
+                bid_pool_key.as_ref(),
+                contributor.key.as_ref(),
+                &[pool_contribution_bump],
+            ],
+        )?;
+
+        PoolContribution {
+            bid_pool: bid_pool_key,
+            contributor: contributor.key(),
+            amount: 0,
+            bump: pool_contribution_bump,
+        }
+    } else {
+        PoolContribution::try_deserialize(&mut &pool_contribution.try_borrow_data()?[..])?
+    };
+
+    contribution.amount = contribution
+        .amount
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    contribution.try_serialize(&mut *pool_contribution.try_borrow_mut_data()?)?;
+
+    let bid_pool = &mut ctx.accounts.bid_pool;
+    bid_pool.total_contributed = bid_pool
+        .total_contributed
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`place_pool_bid` handler](fn.place_pool_bid.html).
+#[derive(Accounts)]
+#[instruction(trade_state_bump: u8, escrow_payment_bump: u8, auctioneer_authority_bump: u8)]
+pub struct PlacePoolBid<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Account paying the rent of the pool's trade state and escrow.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &bid_pool.token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The bid pool PDA account.
+    #[account(
+        mut,
+        seeds=[BID_POOL.as_bytes(), listing_config.key().as_ref()],
+        bump=bid_pool.bump,
+        has_one=listing_config,
+        has_one=auction_house,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA holding the contributions and bidding as the pool's wallet.
+    #[account(mut, seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump=bid_pool.vault_bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// The seller of the NFT
+    /// CHECK: Checked via trade state constraints
+    pub seller: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified through CPI
+    /// SPL token account metadata.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Pool vault escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            bid_pool_vault.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds = [PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump = auction_house.bump, has_one = authority, has_one = treasury_mint, has_one = auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds = [PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump = auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Pool trade state PDA, bidding everything contributed so far.
+    #[account(mut, seeds = [PREFIX.as_bytes(), bid_pool_vault.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), treasury_mint.key().as_ref(), token_account.mint.as_ref(), bid_pool.total_contributed.to_le_bytes().as_ref(), bid_pool.token_size.to_le_bytes().as_ref()], seeds::program=auction_house_program, bump = trade_state_bump)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Is used as a seed for ah_auctioneer_pda.
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Permissionless crank bidding everything contributed to the pool so far from its vault.
+pub fn place_pool_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlacePoolBid<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+) -> Result<()> {
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let features = BidFeatureAccounts::next(
+        &ctx.accounts.listing_config,
+        &ctx.accounts.bid_pool_vault.key(),
+        remaining_accounts,
+    )?;
+
+    if features.private_room.is_some() {
+        return err!(AuctioneerError::AuctionIsPrivate);
+    }
+
+    let buyer_price = ctx.accounts.bid_pool.total_contributed;
+    let token_size = ctx.accounts.bid_pool.token_size;
+
+    // The vault bids as the pool's wallet and the payer funds its counters. The vault has no
+    // invitation, so it may only bid on an invite-only listing that lists it.
+    assert_bidder_can_bid(
+        &features,
+        &ctx.accounts.listing_config.key(),
+        &ctx.accounts.bid_pool_vault.key(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.escrow_payment_account,
+        &ctx.accounts.treasury_mint.key(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        buyer_price,
+    )?;
+    record_listing_bid(
+        &features,
+        &mut ctx.accounts.listing_config,
+        &ctx.accounts.bid_pool_vault.key(),
+        ctx.accounts.buyer_trade_state.key(),
+        buyer_price,
+    )?;
+
+    // The payer covers the rent the vault is charged as the bidding wallet, so only
+    // contributions are ever escrowed.
+    let rent_shortfall = ctx
+        .accounts
+        .rent
+        .minimum_balance(TRADE_STATE_SIZE)
+        .checked_add(
+            ctx.accounts
+                .rent
+                .minimum_balance(0)
+                .saturating_sub(ctx.accounts.escrow_payment_account.lamports()),
+        )
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.bid_pool_vault.key(),
+            rent_shortfall,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.bid_pool_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHBuy {
+        wallet: ctx.accounts.bid_pool_vault.to_account_info(),
+        payment_account: ctx.accounts.bid_pool_vault.to_account_info(),
+        transfer_authority: ctx.accounts.bid_pool_vault.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    let bid_pool_key = ctx.accounts.bid_pool.key();
+    let vault_seeds = [
+        BID_POOL_VAULT.as_bytes(),
+        bid_pool_key.as_ref(),
+        &[ctx.accounts.bid_pool.vault_bump],
+    ];
+
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts)
+        .with_remaining_accounts(remaining_accounts.as_slice().to_vec());
+    mpl_auction_house::cpi::auctioneer_buy(
+        cpi_ctx.with_signer(&[&auctioneer_seeds, &vault_seeds]),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )?;
+
+    let bid_pool = &mut ctx.accounts.bid_pool;
+    bid_pool.bid_amount = buyer_price;
+    bid_pool.bid_trade_state = ctx.accounts.buyer_trade_state.key();
+
+    Ok(())
+}
+
+/// Accounts for the [`withdraw_pool_escrow` handler](fn.withdraw_pool_escrow.html).
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8, auctioneer_authority_bump: u8)]
+pub struct WithdrawPoolEscrow<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// CHECK: Checked against the bid pool.
+    /// The Listing Config of the pooled listing, closed once the listing settles.
+    pub listing_config: UncheckedAccount<'info>,
+
+    /// The bid pool PDA account.
+    #[account(
+        seeds=[BID_POOL.as_bytes(), listing_config.key().as_ref()],
+        bump=bid_pool.bump,
+        has_one=listing_config,
+        has_one=auction_house,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA receiving the escrowed bid back.
+    #[account(mut, seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump=bid_pool.vault_bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The pool's last bid trade state, closed once the bid settled.
+    #[account(address=bid_pool.bid_trade_state)]
+    pub bid_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The vault's token account for the listed token, holding it if the pool won.
+    #[account(address=get_associated_token_address(&bid_pool_vault.key(), &bid_pool.token_mint))]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Pool vault escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            bid_pool_vault.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds = [PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump = auction_house.bump, has_one = authority, has_one = treasury_mint, has_one = auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds = [PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump = auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Is used as a seed for ah_auctioneer_pda.
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Permissionless crank returning a losing pool's escrowed bid to its vault for refunds.
+pub fn withdraw_pool_escrow<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawPoolEscrow<'info>>,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+) -> Result<()> {
+    assert_pool_lost(
+        &ctx.accounts.bid_pool,
+        &ctx.accounts.listing_config,
+        &ctx.accounts.bid_trade_state,
+        &ctx.accounts.pool_token_account,
+    )?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHWithdraw {
+        wallet: ctx.accounts.bid_pool_vault.to_account_info(),
+        receipt_account: ctx.accounts.bid_pool_vault.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        ata_program: ctx.accounts.ata_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let withdraw_data = mpl_auction_house::instruction::AuctioneerWithdraw {
+        escrow_payment_bump,
+        amount: ctx.accounts.escrow_payment_account.lamports(),
+    };
+
+    let ix = solana_program::instruction::Instruction {
+        program_id: cpi_program.key(),
+        accounts: cpi_accounts
+            .to_account_metas(None)
+            .into_iter()
+            .zip(cpi_accounts.to_account_infos())
+            .map(|mut pair| {
+                pair.0.is_signer = pair.1.is_signer;
+                if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                    pair.0.is_signer = true;
+                }
+                pair.0
+            })
+            .collect(),
+        data: withdraw_data.data(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    invoke_signed(&ix, &cpi_accounts.to_account_infos(), &[&auctioneer_seeds])?;
+
+    Ok(())
+}
+
+/// Accounts for the [`claim_pool_shares` handler](fn.claim_pool_shares.html).
+#[derive(Accounts)]
+pub struct ClaimPoolShares<'info> {
+    /// Wallet that contributed to the pool, paying for its share account if needed.
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// The bid pool PDA account.
+    #[account(
+        seeds=[BID_POOL.as_bytes(), bid_pool.listing_config.as_ref()],
+        bump=bid_pool.bump,
+        has_one=share_mint,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA paying back the unspent part of the contribution.
+    #[account(mut, seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump=bid_pool.vault_bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The pool's last bid trade state, closed once the bid settled.
+    #[account(address=bid_pool.bid_trade_state)]
+    pub bid_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The vault's token account for the listed token, holding it if the pool won.
+    #[account(address=get_associated_token_address(&bid_pool_vault.key(), &bid_pool.token_mint))]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// The contributor's contribution, closed once claimed.
+    #[account(
+        mut,
+        seeds=[POOL_CONTRIBUTION.as_bytes(), bid_pool.key().as_ref(), contributor.key().as_ref()],
+        bump=pool_contribution.bump,
+        has_one=bid_pool,
+        has_one=contributor,
+        close=contributor,
+    )]
+    pub pool_contribution: Box<Account<'info, PoolContribution>>,
+
+    /// Mint of the pool's shares.
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Created if needed and validated in claim_pool_shares.
+    /// Contributor token account receiving the shares.
+    #[account(mut)]
+    pub share_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Mint a contributor one share per lamport of their contribution spent on the winning bid,
+/// paying back the remainder.
+pub fn claim_pool_shares<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimPoolShares<'info>>,
+) -> Result<()> {
+    if !pool_won(
+        &ctx.accounts.bid_pool,
+        &ctx.accounts.bid_trade_state,
+        &ctx.accounts.pool_token_account,
+    )? {
+        return err!(AuctioneerError::PoolLostListing);
+    }
+
+    let bid_pool = &ctx.accounts.bid_pool;
+    let contributed = ctx.accounts.pool_contribution.amount;
+
+    // Contributions made after the last bid are spent pro rata with the rest. The spent part is
+    // rounded up so the refunds never add up to more than the vault kept back.
+    let total_contributed = bid_pool.total_contributed as u128;
+    let spent = (contributed as u128)
+        .checked_mul(bid_pool.bid_amount as u128)
+        .and_then(|amount| amount.checked_add(total_contributed.saturating_sub(1)))
+        .and_then(|amount| amount.checked_div(total_contributed))
+        .and_then(|amount| u64::try_from(amount).ok())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let refund = contributed
+        .checked_sub(spent)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let share_token_account = &ctx.accounts.share_token_account;
+    if share_token_account.data_is_empty() {
+        make_ata(
+            share_token_account.to_account_info(),
+            ctx.accounts.contributor.to_account_info(),
+            ctx.accounts.share_mint.to_account_info(),
+            ctx.accounts.contributor.to_account_info(),
+            ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            &[],
+        )?;
+    }
+
+    let listing_config_key = bid_pool.listing_config;
+    let bid_pool_seeds = [
+        BID_POOL.as_bytes(),
+        listing_config_key.as_ref(),
+        &[bid_pool.bump],
+    ];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: share_token_account.to_account_info(),
+                authority: bid_pool.to_account_info(),
+            },
+            &[&bid_pool_seeds],
+        ),
+        spent,
+    )?;
+
+    if refund > 0 {
+        pay_from_pool_vault(
+            bid_pool,
+            &ctx.accounts.bid_pool_vault,
+            &ctx.accounts.contributor.to_account_info(),
+            &ctx.accounts.system_program,
+            refund,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Accounts for the [`redeem_pool_token` handler](fn.redeem_pool_token.html).
+#[derive(Accounts)]
+pub struct RedeemPoolToken<'info> {
+    /// Shareholder redeeming the token, paying for its token account if needed.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// The bid pool PDA account.
+    #[account(
+        mut,
+        seeds=[BID_POOL.as_bytes(), bid_pool.listing_config.as_ref()],
+        bump=bid_pool.bump,
+        has_one=share_mint,
+        has_one=token_mint,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA holding the token the pool won.
+    #[account(seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump=bid_pool.vault_bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The pool's last bid trade state, closed once the bid settled.
+    #[account(address=bid_pool.bid_trade_state)]
+    pub bid_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The vault's token account for the listed token, holding it if the pool won.
+    #[account(mut, address=get_associated_token_address(&bid_pool_vault.key(), &bid_pool.token_mint))]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// Mint of the token the pool won.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Mint of the pool's shares.
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    /// The holder's shares, burned on redemption.
+    #[account(mut, token::mint=share_mint, token::authority=holder)]
+    pub share_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Address checked in constraint, created if needed in redeem_pool_token.
+    /// Holder token account receiving the token.
+    #[account(mut, address=get_associated_token_address(&holder.key(), &token_mint.key()))]
+    pub holder_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Burn one share per lamport the pool spent on the token it won to take the token out of the
+/// pool's vault. Contributors claim their shares first and a single holder gathers them to exit.
+pub fn redeem_pool_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemPoolToken<'info>>,
+) -> Result<()> {
+    if ctx.accounts.bid_pool.redeemed {
+        return err!(AuctioneerError::PoolTokenRedeemed);
+    }
+    if !pool_won(
+        &ctx.accounts.bid_pool,
+        &ctx.accounts.bid_trade_state,
+        &ctx.accounts.pool_token_account,
+    )? {
+        return err!(AuctioneerError::PoolLostListing);
+    }
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                from: ctx.accounts.share_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        ctx.accounts.bid_pool.bid_amount,
+    )?;
+
+    let holder_token_account = &ctx.accounts.holder_token_account;
+    if holder_token_account.data_is_empty() {
+        make_ata(
+            holder_token_account.to_account_info(),
+            ctx.accounts.holder.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.holder.to_account_info(),
+            ctx.accounts.ata_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            &[],
+        )?;
+    }
+
+    let bid_pool_key = ctx.accounts.bid_pool.key();
+    let vault_seeds = [
+        BID_POOL_VAULT.as_bytes(),
+        bid_pool_key.as_ref(),
+        &[ctx.accounts.bid_pool.vault_bump],
+    ];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_token_account.to_account_info(),
+                to: holder_token_account.to_account_info(),
+                authority: ctx.accounts.bid_pool_vault.to_account_info(),
+            },
+            &[&vault_seeds],
+        ),
+        ctx.accounts.bid_pool.token_size,
+    )?;
+
+    ctx.accounts.bid_pool.redeemed = true;
+
+    Ok(())
+}
+
+/// Accounts for the [`refund_pool_contribution` handler](fn.refund_pool_contribution.html).
+#[derive(Accounts)]
+pub struct RefundPoolContribution<'info> {
+    /// Wallet that contributed to the pool.
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// CHECK: Checked against the bid pool.
+    /// The Listing Config of the pooled listing, closed once the listing settles.
+    pub listing_config: UncheckedAccount<'info>,
+
+    /// The bid pool PDA account.
+    #[account(
+        seeds=[BID_POOL.as_bytes(), listing_config.key().as_ref()],
+        bump=bid_pool.bump,
+        has_one=listing_config,
+    )]
+    pub bid_pool: Box<Account<'info, BidPool>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA paying back the contribution.
+    #[account(mut, seeds=[BID_POOL_VAULT.as_bytes(), bid_pool.key().as_ref()], bump=bid_pool.vault_bump)]
+    pub bid_pool_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The pool's last bid trade state, closed once the bid settled.
+    #[account(address=bid_pool.bid_trade_state)]
+    pub bid_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The vault's token account for the listed token, holding it if the pool won.
+    #[account(address=get_associated_token_address(&bid_pool_vault.key(), &bid_pool.token_mint))]
+    pub pool_token_account: UncheckedAccount<'info>,
+
+    /// The contributor's contribution, closed once refunded.
+    #[account(
+        mut,
+        seeds=[POOL_CONTRIBUTION.as_bytes(), bid_pool.key().as_ref(), contributor.key().as_ref()],
+        bump=pool_contribution.bump,
+        has_one=bid_pool,
+        has_one=contributor,
+        close=contributor,
+    )]
+    pub pool_contribution: Box<Account<'info, PoolContribution>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund a contribution in full once the pool has lost the listing. The pool's escrowed bid
+/// must first be returned to the vault with `withdraw_pool_escrow`.
+pub fn refund_pool_contribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundPoolContribution<'info>>,
+) -> Result<()> {
+    assert_pool_lost(
+        &ctx.accounts.bid_pool,
+        &ctx.accounts.listing_config,
+        &ctx.accounts.bid_trade_state,
+        &ctx.accounts.pool_token_account,
+    )?;
+
+    pay_from_pool_vault(
+        &ctx.accounts.bid_pool,
+        &ctx.accounts.bid_pool_vault,
+        &ctx.accounts.contributor.to_account_info(),
+        &ctx.accounts.system_program,
+        ctx.accounts.pool_contribution.amount,
+    )
+}
+
+/// Whether the pool won its listing: its last bid settled, closing the bid's trade state, and
+/// the vault received the whole lot, or a shareholder has since redeemed it.
+fn pool_won(
+    bid_pool: &BidPool,
+    bid_trade_state: &AccountInfo,
+    pool_token_account: &AccountInfo,
+) -> Result<bool> {
+    if bid_pool.redeemed {
+        return Ok(true);
+    }
+
+    if bid_pool.bid_amount == 0
+        || !bid_trade_state.data_is_empty()
+        || pool_token_account.data_is_empty()
+    {
+        return Ok(false);
+    }
+
+    let token_account =
+        TokenAccount::try_deserialize(&mut &pool_token_account.try_borrow_data()?[..])?;
+    Ok(token_account.amount >= bid_pool.token_size)
+}
+
+/// A pool has lost once its listing settled to another buyer or was canceled, or once the
+/// auction ended with another bid on top.
+fn assert_pool_lost(
+    bid_pool: &BidPool,
+    listing_config: &AccountInfo,
+    bid_trade_state: &AccountInfo,
+    pool_token_account: &AccountInfo,
+) -> Result<()> {
+    if pool_won(bid_pool, bid_trade_state, pool_token_account)? {
+        return err!(AuctioneerError::PoolWonListing);
+    }
+
+    if !listing_config.data_is_empty() {
+        let listing = ListingConfig::try_deserialize(&mut &listing_config.try_borrow_data()?[..])?;
+        let pool_is_highest = bid_pool.bid_amount > 0
+            && listing.highest_bid.buyer_trade_state == bid_pool.bid_trade_state;
+
+        if listing_clock_now(&listing)? <= listing.end_time || pool_is_highest {
+            return err!(AuctioneerError::PoolListingOpen);
+        }
+    }
+
+    Ok(())
+}
+
+fn pay_from_pool_vault<'info>(
+    bid_pool: &Account<'info, BidPool>,
+    bid_pool_vault: &UncheckedAccount<'info>,
+    destination: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let bid_pool_key = bid_pool.key();
+    let vault_seeds = [
+        BID_POOL_VAULT.as_bytes(),
+        bid_pool_key.as_ref(),
+        &[bid_pool.vault_bump],
+    ];
+
+    invoke_signed(
+        &system_instruction::transfer(&bid_pool_vault.key(), destination.key, amount),
+        &[
+            bid_pool_vault.to_account_info(),
+            destination.clone(),
+            system_program.to_account_info(),
+        ],
+        &[&vault_seeds],
+    )?;
+
+    Ok(())
+}
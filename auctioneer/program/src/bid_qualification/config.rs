@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BID_QUALIFICATION_SIZE: usize = 8 + 32 + 8 + 1;
+
+/// Minimum escrow balance a wallet must hold on the Auction House before it may bid on a listing.
+#[account]
+pub struct BidQualification {
+    pub listing_config: Pubkey,
+    pub min_escrow_balance: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,110 @@
+//! Soft qualification for bidders on premium listings: a wallet must already hold a minimum
+//! balance in its Auction House escrow before it may bid, filtering out spam bids.
+pub mod config;
+
+use crate::{bid_qualification::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+
+/// Accounts for the [`create_bid_qualification` handler](fn.create_bid_qualification.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBidQualification<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The bidder qualification for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BID_QUALIFICATION_SIZE,
+        seeds=[BID_QUALIFICATION.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub bid_qualification: Box<Account<'info, BidQualification>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Require bidders on a listing without bids to hold at least `min_escrow_balance` in escrow.
+pub fn create_bid_qualification<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBidQualification<'info>>,
+    _token_size: u64,
+    min_escrow_balance: u64,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    if min_escrow_balance == 0 {
+        return err!(AuctioneerError::InvalidBidQualification);
+    }
+
+    ctx.accounts.listing_config.features.bid_qualification = true;
+
+    let bid_qualification = &mut ctx.accounts.bid_qualification;
+    bid_qualification.listing_config = ctx.accounts.listing_config.key();
+    bid_qualification.min_escrow_balance = min_escrow_balance;
+    bid_qualification.bump = *ctx
+        .bumps
+        .get("bid_qualification")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Check the bidder's escrow holds the listing's minimum balance, if it has a bid qualification.
+/// Called before the bid tops up the escrow, so only funds deposited beforehand count.
+pub fn assert_bid_qualification(
+    bid_qualification: Option<&AccountInfo>,
+    escrow_payment_account: &AccountInfo,
+    treasury_mint: &Pubkey,
+) -> Result<()> {
+    let bid_qualification = match bid_qualification {
+        Some(bid_qualification) => bid_qualification,
+        None => return Ok(()),
+    };
+
+    let qualification =
+        BidQualification::try_deserialize(&mut &bid_qualification.try_borrow_data()?[..])?;
+
+    if escrow_balance(escrow_payment_account, treasury_mint)? < qualification.min_escrow_balance {
+        return err!(AuctioneerError::InsufficientEscrowBalance);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BID_RATE_LIMIT_SIZE: usize = 8 + 32 + 8 + 4 + 1;
+#[constant]
+pub const WALLET_BID_RATE_SIZE: usize = 8 + 32 + 32 + 4 + 8 + 1;
+
+/// Per-wallet rate limits on bidding on a listing, to give human bidders a chance against bots.
+/// A limit of zero is unlimited.
+#[account]
+pub struct BidRateLimit {
+    pub listing_config: Pubkey,
+    pub min_slot_gap: u64,
+    pub max_bids_per_wallet: u32,
+    pub bump: u8,
+}
+
+/// Bids a wallet has placed on a listing with a bid rate limit. Unlike a bid counter, canceling
+/// a bid does not reset it.
+#[account]
+pub struct WalletBidRate {
+    pub listing_config: Pubkey,
+    pub wallet: Pubkey,
+    pub bids_placed: u32,
+    pub last_bid_slot: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,158 @@
+//! Per-wallet rate limits on bidding on a listing: a minimum number of slots between a wallet's
+//! bids and a cap on the bids it places in total, so bots cannot outbid every human bid the
+//! moment it lands on a hyped auction.
+pub mod config;
+
+use crate::{bid_rate_limit::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram,
+    utils::create_or_allocate_account_raw, AuctionHouse,
+};
+
+/// Accounts for the [`create_bid_rate_limit` handler](fn.create_bid_rate_limit.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBidRateLimit<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The per-wallet bid rate limit for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BID_RATE_LIMIT_SIZE,
+        seeds=[BID_RATE_LIMIT.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub bid_rate_limit: Box<Account<'info, BidRateLimit>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Require each wallet to wait `min_slot_gap` slots between bids on a listing without bids, and
+/// cap the bids it may place at `max_bids_per_wallet`. A limit of zero is unlimited.
+pub fn create_bid_rate_limit<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBidRateLimit<'info>>,
+    _token_size: u64,
+    min_slot_gap: u64,
+    max_bids_per_wallet: u32,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    if min_slot_gap == 0 && max_bids_per_wallet == 0 {
+        return err!(AuctioneerError::InvalidBidRateLimit);
+    }
+
+    ctx.accounts.listing_config.features.bid_rate_limit = true;
+
+    let bid_rate_limit = &mut ctx.accounts.bid_rate_limit;
+    bid_rate_limit.listing_config = ctx.accounts.listing_config.key();
+    bid_rate_limit.min_slot_gap = min_slot_gap;
+    bid_rate_limit.max_bids_per_wallet = max_bids_per_wallet;
+    bid_rate_limit.bump = *ctx
+        .bumps
+        .get("bid_rate_limit")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Check a new bid against the listing's bid rate limit and record it on the wallet's bid rate,
+/// creating it on the wallet's first bid at `payer`'s expense.
+#[allow(clippy::too_many_arguments)]
+pub fn record_wallet_bid_rate<'info>(
+    bid_rate_limit: &AccountInfo<'info>,
+    wallet_bid_rate: &AccountInfo<'info>,
+    listing_config: &Pubkey,
+    wallet: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    wallet_bid_rate_bump: u8,
+) -> Result<()> {
+    let limit = BidRateLimit::try_deserialize(&mut &bid_rate_limit.try_borrow_data()?[..])?;
+    let slot = Clock::get()?.slot;
+
+    let mut rate = if wallet_bid_rate.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::id(),
+            wallet_bid_rate,
+            rent,
+            system_program,
+            payer,
+            WALLET_BID_RATE_SIZE,
+            &[],
+            &[
+                WALLET_BID_RATE.as_bytes(),
+                listing_config.as_ref(),
+                wallet.as_ref(),
+                &[wallet_bid_rate_bump],
+            ],
+        )?;
+
+        WalletBidRate {
+            listing_config: *listing_config,
+            wallet: *wallet,
+            bids_placed: 0,
+            last_bid_slot: 0,
+            bump: wallet_bid_rate_bump,
+        }
+    } else {
+        let rate = WalletBidRate::try_deserialize(&mut &wallet_bid_rate.try_borrow_data()?[..])?;
+
+        if slot < rate.last_bid_slot.saturating_add(limit.min_slot_gap) {
+            return err!(AuctioneerError::WalletBidTooSoon);
+        }
+
+        rate
+    };
+
+    rate.bids_placed = rate
+        .bids_placed
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    rate.last_bid_slot = slot;
+
+    if limit.max_bids_per_wallet > 0 && rate.bids_placed > limit.max_bids_per_wallet {
+        return err!(AuctioneerError::WalletBidCountExceeded);
+    }
+
+    rate.try_serialize(&mut *wallet_bid_rate.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
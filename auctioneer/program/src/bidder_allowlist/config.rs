@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+#[constant]
+pub const BIDDER_ALLOWLIST_SIZE: usize = 8 + 32 + 32 + 4 + 32 * MAX_ALLOWLISTED_BIDDERS + 1;
+#[constant]
+pub const BIDDER_INVITATION_SIZE: usize = 8 + 32 + 32 + 1;
+
+/// Wallets invited to bid on a listing, listed explicitly or committed to by a merkle root over
+/// the keccak hash of each invited wallet.
+#[account]
+pub struct BidderAllowlist {
+    pub listing_config: Pubkey,
+    /// All zeroes when the allowlist has no merkle root.
+    pub merkle_root: [u8; 32],
+    pub bidders: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+/// A wallet's proven membership of a listing's merkle allowlist, so its bids need no proof.
+#[account]
+pub struct BidderInvitation {
+    pub listing_config: Pubkey,
+    pub wallet: Pubkey,
+    pub bump: u8,
+}
@@ -0,0 +1,197 @@
+//! Invite-only listings: a seller allowlist of the wallets that may bid. Small allowlists list
+//! the wallets directly; larger ones commit to them with a merkle root, and each invited wallet
+//! proves its membership once to open a bidder invitation.
+pub mod config;
+
+use crate::{bidder_allowlist::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+use solana_program::keccak;
+
+/// Accounts for the [`create_bidder_allowlist` handler](fn.create_bidder_allowlist.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBidderAllowlist<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The bidder allowlist for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BIDDER_ALLOWLIST_SIZE,
+        seeds=[BIDDER_ALLOWLIST.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub bidder_allowlist: Box<Account<'info, BidderAllowlist>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`accept_bidder_invitation` handler](fn.accept_bidder_invitation.html).
+#[derive(Accounts)]
+pub struct AcceptBidderInvitation<'info> {
+    /// Invited wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The listing's bidder allowlist.
+    #[account(
+        seeds=[BIDDER_ALLOWLIST.as_bytes(), bidder_allowlist.listing_config.as_ref()],
+        bump=bidder_allowlist.bump,
+    )]
+    pub bidder_allowlist: Box<Account<'info, BidderAllowlist>>,
+
+    /// The wallet's invitation to bid on the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BIDDER_INVITATION_SIZE,
+        seeds=[
+            BIDDER_INVITATION.as_bytes(),
+            bidder_allowlist.listing_config.as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump,
+    )]
+    pub bidder_invitation: Box<Account<'info, BidderInvitation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Only accept bids on a listing without bids from `bidders`, or from wallets proven to be in the
+/// merkle tree with `merkle_root`. A zero root disables the merkle allowlist.
+pub fn create_bidder_allowlist<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBidderAllowlist<'info>>,
+    _token_size: u64,
+    bidders: Vec<Pubkey>,
+    merkle_root: [u8; 32],
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    if bidders.len() > MAX_ALLOWLISTED_BIDDERS || (bidders.is_empty() && merkle_root == [0; 32]) {
+        return err!(AuctioneerError::InvalidBidderAllowlist);
+    }
+
+    ctx.accounts.listing_config.features.bidder_allowlist = true;
+
+    let bidder_allowlist = &mut ctx.accounts.bidder_allowlist;
+    bidder_allowlist.listing_config = ctx.accounts.listing_config.key();
+    bidder_allowlist.merkle_root = merkle_root;
+    bidder_allowlist.bidders = bidders;
+    bidder_allowlist.bump = *ctx
+        .bumps
+        .get("bidder_allowlist")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Prove the wallet is in the listing's merkle allowlist, opening its invitation to bid.
+pub fn accept_bidder_invitation<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptBidderInvitation<'info>>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let bidder_allowlist = &ctx.accounts.bidder_allowlist;
+    let wallet = ctx.accounts.wallet.key();
+
+    if bidder_allowlist.merkle_root == [0; 32]
+        || !verify_allowlist_proof(&proof, bidder_allowlist.merkle_root, &wallet)
+    {
+        return err!(AuctioneerError::InvalidAllowlistProof);
+    }
+
+    let bidder_invitation = &mut ctx.accounts.bidder_invitation;
+    bidder_invitation.listing_config = bidder_allowlist.listing_config;
+    bidder_invitation.wallet = wallet;
+    bidder_invitation.bump = *ctx
+        .bumps
+        .get("bidder_invitation")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Check `wallet` is invited to bid on the listing, if it has a bidder allowlist. Bids without a
+/// `bidder_invitation` account are only checked against the listed bidders.
+pub fn assert_bidder_allowed(
+    bidder_allowlist: Option<&AccountInfo>,
+    bidder_invitation: Option<&AccountInfo>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    let bidder_allowlist = match bidder_allowlist {
+        Some(bidder_allowlist) => bidder_allowlist,
+        None => return Ok(()),
+    };
+
+    let allowlist =
+        BidderAllowlist::try_deserialize(&mut &bidder_allowlist.try_borrow_data()?[..])?;
+    if allowlist.bidders.contains(wallet) {
+        return Ok(());
+    }
+
+    // The invitation's address is checked by its seeds, so it only exists once the wallet proved
+    // its membership.
+    if let Some(bidder_invitation) = bidder_invitation {
+        if !bidder_invitation.data_is_empty() {
+            let invitation =
+                BidderInvitation::try_deserialize(&mut &bidder_invitation.try_borrow_data()?[..])?;
+            if invitation.wallet == *wallet {
+                return Ok(());
+            }
+        }
+    }
+
+    err!(AuctioneerError::BidderNotInvited)
+}
+
+/// Verify `proof` shows the keccak hash of `wallet` is a leaf of the tree with `root`, hashing
+/// each pair of nodes in sorted order.
+pub fn verify_allowlist_proof(proof: &[[u8; 32]], root: [u8; 32], wallet: &Pubkey) -> bool {
+    let mut node = keccak::hashv(&[wallet.as_ref()]).0;
+    for sibling in proof {
+        node = if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &node]).0
+        };
+    }
+
+    node == root
+}
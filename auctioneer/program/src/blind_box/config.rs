@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BLIND_BOX_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 33 + 1;
+
+/// First 8 bytes of the instruction data sent to a blind box's reveal program, followed by the
+/// borsh encoded [`RevealArgs`].
+pub const REVEAL_DISCRIMINATOR: [u8; 8] = [9, 35, 59, 190, 167, 249, 76, 115];
+
+/// Marks a listing as an unrevealed blind box. Once it sells, anyone can reveal it by invoking
+/// `reveal_program` with the blind box PDA as the only signer.
+#[account]
+pub struct BlindBox {
+    pub listing_config: Pubkey,
+    pub reveal_program: Pubkey,
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    /// The winning buyer, recorded when the sale settles.
+    pub buyer: Option<Pubkey>,
+    pub bump: u8,
+}
+
+/// Arguments passed to the reveal program when a blind box is revealed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RevealArgs {
+    pub token_mint: Pubkey,
+    pub buyer: Pubkey,
+}
@@ -0,0 +1,219 @@
+//! Blind box listings revealed by a CPI to a reveal program once they sell. The reveal runs in
+//! its own instruction after settlement, so the seller's reveal program never executes inside a
+//! sale or with any signature but the blind box's.
+pub mod config;
+
+use crate::{blind_box::config::*, constants::*, errors::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+use solana_program::{instruction::Instruction, program::invoke_signed};
+
+/// Accounts for the [`create_blind_box` handler](fn.create_blind_box.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBlindBox<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// Token mint account of the listed token.
+    #[account(address = token_account.mint)]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// The blind box marking the listing as unrevealed.
+    #[account(
+        init,
+        payer=wallet,
+        space=BLIND_BOX_SIZE,
+        seeds=[BLIND_BOX.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub blind_box: Box<Account<'info, BlindBox>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Turn a listing without bids into a blind box. Once it sells, anyone can reveal it through
+/// `reveal_program`.
+pub fn create_blind_box<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBlindBox<'info>>,
+    _token_size: u64,
+    reveal_program: Pubkey,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    ctx.accounts.listing_config.features.blind_box = true;
+
+    let blind_box = &mut ctx.accounts.blind_box;
+    blind_box.listing_config = ctx.accounts.listing_config.key();
+    blind_box.reveal_program = reveal_program;
+    blind_box.seller = ctx.accounts.wallet.key();
+    blind_box.token_mint = ctx.accounts.token_mint.key();
+    blind_box.buyer = None;
+    blind_box.bump = *ctx
+        .bumps
+        .get("blind_box")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Record the winning buyer on a listing's blind box when its sale settles, leaving the blind box
+/// open for [`reveal_blind_box`].
+pub fn record_blind_box_sale(blind_box: Option<&AccountInfo>, buyer: &Pubkey) -> Result<()> {
+    let blind_box = match blind_box {
+        Some(blind_box) => blind_box,
+        None => return Ok(()),
+    };
+
+    let mut state = BlindBox::try_deserialize(&mut &blind_box.try_borrow_data()?[..])?;
+    state.buyer = Some(*buyer);
+    state.try_serialize(&mut &mut blind_box.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Accounts for the [`reveal_blind_box` handler](fn.reveal_blind_box.html).
+#[derive(Accounts)]
+pub struct RevealBlindBox<'info> {
+    /// The sold blind box, closed to its seller once revealed.
+    #[account(
+        mut,
+        seeds=[BLIND_BOX.as_bytes(), blind_box.listing_config.as_ref()],
+        bump=blind_box.bump,
+        has_one=seller,
+        has_one=token_mint,
+        has_one=reveal_program @ AuctioneerError::InvalidRevealProgram,
+        close=seller,
+    )]
+    pub blind_box: Box<Account<'info, BlindBox>>,
+
+    /// CHECK: Checked with has_one constraint on the blind box.
+    /// Seller of the blind box, refunded its rent.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the blind box buyer in reveal_blind_box.
+    /// Buyer of the blind box, passed to the reveal program read-only.
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: Checked with has_one constraint on the blind box.
+    /// Token mint account of the sold token.
+    pub token_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Metaplex metadata account decorating the token mint.
+    #[account(
+        seeds=[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref()
+        ],
+        seeds::program=mpl_token_metadata::ID,
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Checked with has_one constraint on the blind box.
+    /// The program the seller chose to reveal the token.
+    #[account(executable)]
+    pub reveal_program: UncheckedAccount<'info>,
+}
+
+/// Invoke a sold blind box's reveal program, then close the blind box to the seller. Callable by
+/// anyone.
+///
+/// The reveal program receives the blind box PDA as its only signer, followed by the buyer, token
+/// mint and metadata read-only, and then the remaining accounts, which are never forwarded as
+/// signers.
+pub fn reveal_blind_box<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealBlindBox<'info>>,
+) -> Result<()> {
+    let blind_box = &ctx.accounts.blind_box;
+    if blind_box.buyer != Some(ctx.accounts.buyer.key()) {
+        return err!(AuctioneerError::BlindBoxNotSold);
+    }
+
+    let mut account_metas = vec![
+        AccountMeta::new_readonly(blind_box.key(), true),
+        AccountMeta::new_readonly(ctx.accounts.buyer.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.token_mint.key(), false),
+        AccountMeta::new_readonly(ctx.accounts.metadata.key(), false),
+    ];
+    account_metas.extend(ctx.remaining_accounts.iter().map(|account| {
+        if account.is_writable {
+            AccountMeta::new(account.key(), false)
+        } else {
+            AccountMeta::new_readonly(account.key(), false)
+        }
+    }));
+
+    let mut account_infos = vec![
+        blind_box.to_account_info(),
+        ctx.accounts.buyer.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.metadata.to_account_info(),
+    ];
+    account_infos.extend(ctx.remaining_accounts.iter().cloned());
+    account_infos.push(ctx.accounts.reveal_program.to_account_info());
+
+    let mut data = REVEAL_DISCRIMINATOR.to_vec();
+    RevealArgs {
+        token_mint: ctx.accounts.token_mint.key(),
+        buyer: ctx.accounts.buyer.key(),
+    }
+    .serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: ctx.accounts.reveal_program.key(),
+        accounts: account_metas,
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &account_infos,
+        &[&[
+            BLIND_BOX.as_bytes(),
+            blind_box.listing_config.as_ref(),
+            &[blind_box.bump],
+        ]],
+    )?;
+
+    Ok(())
+}
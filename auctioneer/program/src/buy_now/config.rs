@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BUY_NOW_SIZE: usize = 8 + 32 + 8 + 1;
+
+/// Marks a listing as fixed price. The listing takes no bids and sells to the first buyer to pay
+/// `price` through `buy_now`.
+#[account]
+pub struct BuyNow {
+    pub listing_config: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+}
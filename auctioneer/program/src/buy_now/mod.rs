@@ -0,0 +1,287 @@
+//! Fixed-price "buy it now" listings. The first buyer to pay the price ends the auction with the
+//! only bid it will take, so the sale settles with `execute_sale` straight away.
+pub mod config;
+
+use crate::{
+    bid::{assert_bidder_can_bid, BidFeatureAccounts},
+    buy_now::config::*,
+    constants::*,
+    errors::*,
+    sell::config::*,
+    utils::*,
+};
+
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::AuctioneerBuy as AHBuy,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+
+/// Accounts for the [`create_buy_now` handler](fn.create_buy_now.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreateBuyNow<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The fixed price of the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=BUY_NOW_SIZE,
+        seeds=[BUY_NOW.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub buy_now: Box<Account<'info, BuyNow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`auctioneer_buy_now` handler](fn.auctioneer_buy_now.html).
+#[derive(Accounts)]
+#[instruction(trade_state_bump: u8, escrow_payment_bump: u8, auctioneer_authority_bump: u8, token_size: u64)]
+pub struct AuctioneerBuyNow<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    // Accounts used for Auctioneer
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The fixed price of the listing, closed to the seller once bought.
+    #[account(
+        mut,
+        seeds=[BUY_NOW.as_bytes(), listing_config.key().as_ref()],
+        bump=buy_now.bump,
+        has_one=listing_config,
+        close=seller,
+    )]
+    pub buy_now: Box<Account<'info, BuyNow>>,
+
+    /// The seller of the NFT
+    /// CHECK: Checked via listing config seeds
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    // Accounts passed into Auction House CPI call
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: Verified through CPI
+    /// User SOL or SPL account to transfer funds from.
+    #[account(mut)]
+    pub payment_account: UncheckedAccount<'info>,
+
+    /// CHECK:
+    /// SPL token account transfer authority.
+    pub transfer_authority: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified through CPI
+    /// SPL token account metadata.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            wallet.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds = [PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump = auction_house.bump, has_one = authority, has_one = treasury_mint, has_one = auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds = [PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump = auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer trade state PDA.
+    #[account(mut, seeds = [PREFIX.as_bytes(), wallet.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), treasury_mint.key().as_ref(), token_account.mint.as_ref(), buy_now.price.to_le_bytes().as_ref(), token_size.to_le_bytes().as_ref()], seeds::program=auction_house_program, bump = trade_state_bump)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Is used as a seed for ah_auctioneer_pda.
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Turn a listing without bids into a fixed-price listing selling at `price`. Regular bids can no
+/// longer meet its reserve price.
+pub fn create_buy_now<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateBuyNow<'info>>,
+    _token_size: u64,
+    price: u64,
+) -> Result<()> {
+    if price == 0 {
+        return err!(AuctioneerError::InvalidBuyNowPrice);
+    }
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    ctx.accounts.listing_config.reserve_price = BUY_NOW_RESERVE_PRICE;
+
+    let buy_now = &mut ctx.accounts.buy_now;
+    buy_now.listing_config = ctx.accounts.listing_config.key();
+    buy_now.price = price;
+    buy_now.bump = *ctx
+        .bumps
+        .get("buy_now")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Bid the fixed price on a buy it now listing and end its auction, so the sale can be executed
+/// in the same transaction.
+pub fn auctioneer_buy_now<'info>(
+    ctx: Context<'_, '_, '_, 'info, AuctioneerBuyNow<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+    token_size: u64,
+) -> Result<()> {
+    let price = ctx.accounts.buy_now.price;
+
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let features = BidFeatureAccounts::next(
+        &ctx.accounts.listing_config,
+        &ctx.accounts.wallet.key(),
+        remaining_accounts,
+    )?;
+
+    assert_bidder_can_bid(
+        &features,
+        &ctx.accounts.listing_config.key(),
+        &ctx.accounts.wallet.key(),
+        &ctx.accounts.wallet.to_account_info(),
+        &ctx.accounts.escrow_payment_account,
+        &ctx.accounts.treasury_mint.key(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        price,
+    )?;
+    assert_auction_active(&ctx.accounts.listing_config)?;
+    record_highest_bid(
+        &mut ctx.accounts.listing_config,
+        price,
+        ctx.accounts.buyer_trade_state.key(),
+    )?;
+    ctx.accounts.listing_config.end_time = listing_clock_now(&ctx.accounts.listing_config)?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHBuy {
+        wallet: ctx.accounts.wallet.to_account_info(),
+        payment_account: ctx.accounts.payment_account.to_account_info(),
+        transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts)
+        .with_remaining_accounts(remaining_accounts.as_slice().to_vec());
+    mpl_auction_house::cpi::auctioneer_buy(
+        cpi_ctx.with_signer(&[&auctioneer_seeds]),
+        trade_state_bump,
+        escrow_payment_bump,
+        price,
+        token_size,
+    )
+}
@@ -10,7 +10,7 @@ use mpl_auction_house::{
 };
 use solana_program::program::invoke_signed;
 
-use crate::{constants::*, errors::*, sell::config::*};
+use crate::{bid_limit::release_wallet_bid, constants::*, errors::*, sell::config::*, utils::*};
 
 /// Accounts for the [`cancel` handler](auction_house/fn.cancel.html).
 #[derive(Accounts, Clone)]
@@ -105,25 +105,243 @@ pub fn auctioneer_cancel<'info>(
         return err!(AuctioneerError::CannotCancelHighestBid);
     }
 
-    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
-    let cpi_accounts = AHCancel {
-        wallet: ctx.accounts.wallet.to_account_info(),
-        token_account: ctx.accounts.token_account.to_account_info(),
-        token_mint: ctx.accounts.token_mint.to_account_info(),
-        auction_house: ctx.accounts.auction_house.to_account_info(),
-        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
-        trade_state: ctx.accounts.trade_state.to_account_info(),
-        authority: ctx.accounts.authority.to_account_info(),
-        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
-        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-    };
+    cancel_via_auction_house(
+        ctx.accounts.auction_house_program.to_account_info(),
+        AHCancel {
+            wallet: ctx.accounts.wallet.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            trade_state: ctx.accounts.trade_state.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        auctioneer_authority_bump,
+        buyer_price,
+        token_size,
+    )?;
+
+    // Bids on listings with a bid limit pass the wallet's bid counter in `remaining_accounts`.
+    if buyer_price != AUCTIONEER_BUYER_PRICE {
+        assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+        let listing_config_key = ctx.accounts.listing_config.key();
+        let wallet_key = ctx.accounts.wallet.key();
+        if let Some((wallet_bid_counter, _)) = next_feature_account(
+            &mut ctx.remaining_accounts.iter(),
+            ctx.accounts.listing_config.features.bid_limit,
+            &[
+                WALLET_BID_COUNTER.as_bytes(),
+                listing_config_key.as_ref(),
+                wallet_key.as_ref(),
+            ],
+        )? {
+            release_wallet_bid(
+                wallet_bid_counter,
+                &ctx.accounts.wallet.to_account_info(),
+                buyer_price,
+            )?;
+        }
+    }
+
+    // Close the Listing Config account if the seller is canceling their listing.
+    if ctx.accounts.token_account.owner == ctx.accounts.wallet.key()
+        && ctx.accounts.wallet.is_signer
+    {
+        let listing_config = &ctx.accounts.listing_config.to_account_info();
+        let seller = &ctx.accounts.seller.to_account_info();
+
+        let listing_config_lamports = listing_config.lamports();
+        **seller.lamports.borrow_mut() = seller
+            .lamports()
+            .checked_add(listing_config_lamports)
+            .unwrap();
+        **listing_config.lamports.borrow_mut() = 0;
+
+        let mut source_data = listing_config.data.borrow_mut();
+        source_data.fill(0);
+    }
+
+    Ok(())
+}
+
+/// Accounts for the [`cancel_listing` handler](auction_house/fn.cancel_listing.html).
+#[derive(Accounts)]
+#[instruction(auctioneer_authority_bump: u8, token_size: u64)]
+pub struct CancelListing<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// The Listing Config closed with the listing.
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Account<'info, ListingConfig>,
+
+    /// The seller canceling their listing.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// SPL token account containing the listed token.
+    #[account(mut, constraint = token_account.owner == seller.key() @ AuctioneerError::NotListingSeller)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account of SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=authority, has_one=auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump=auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated through CPI.
+    /// Seller trade state PDA account of the listing.
+    #[account(mut)]
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Checked in seed constraints
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+            ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Cancel a listing on Auction House and close its Listing Config in one instruction, so the
+/// listing config is never left behind by a cancel that only reaches Auction House.
+pub fn cancel_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelListing<'info>>,
+    auctioneer_authority_bump: u8,
+    token_size: u64,
+) -> Result<()> {
+    cancel_via_auction_house(
+        ctx.accounts.auction_house_program.to_account_info(),
+        AHCancel {
+            wallet: ctx.accounts.seller.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            trade_state: ctx.accounts.trade_state.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        auctioneer_authority_bump,
+        AUCTIONEER_BUYER_PRICE,
+        token_size,
+    )
+}
+
+/// Accounts for the [`close_orphaned_listing_config` handler](auction_house/fn.close_orphaned_listing_config.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CloseOrphanedListingConfig<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// The orphaned Listing Config.
+    #[account(
+        mut,
+        close=seller,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Account<'info, ListingConfig>,
+
+    /// CHECK: Checked via listing_config seeds.
+    /// The seller of the listing, refunded the listing config rent.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
 
+    /// CHECK: Checked via listing_config seeds. May have been closed since the listing.
+    /// SPL token account the listing was created from.
+    pub token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Checked via listing_config seeds.
+    /// Token mint account of the listed token.
+    pub token_mint: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account of the listing, which must have been closed.
+    #[account(seeds=[PREFIX.as_bytes(), seller.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_mint.key().as_ref(), &AUCTIONEER_BUYER_PRICE.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+}
+
+/// Close a Listing Config whose listing was canceled on Auction House without it, refunding the
+/// rent to the seller. Anyone may crank this.
+pub fn close_orphaned_listing_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseOrphanedListingConfig<'info>>,
+    _token_size: u64,
+) -> Result<()> {
+    if ctx.accounts.seller_trade_state.lamports() > 0 {
+        return err!(AuctioneerError::ListingNotOrphaned);
+    }
+
+    Ok(())
+}
+
+/// Cancel a trade state through Auction House, signing as the auctioneer authority.
+fn cancel_via_auction_house<'info>(
+    cpi_program: AccountInfo<'info>,
+    cpi_accounts: AHCancel<'info>,
+    auctioneer_authority_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
     let cancel_data = mpl_auction_house::instruction::AuctioneerCancel {
         buyer_price,
         token_size,
     };
 
+    let auctioneer_authority = cpi_accounts.auctioneer_authority.key();
     let ix = solana_program::instruction::Instruction {
         program_id: cpi_program.key(),
         accounts: cpi_accounts
@@ -132,7 +350,7 @@ pub fn auctioneer_cancel<'info>(
             .zip(cpi_accounts.to_account_infos())
             .map(|mut pair| {
                 pair.0.is_signer = pair.1.is_signer;
-                if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                if pair.0.pubkey == auctioneer_authority {
                     pair.0.is_signer = true;
                 }
                 pair.0
@@ -141,11 +359,7 @@ pub fn auctioneer_cancel<'info>(
         data: cancel_data.data(),
     };
 
-    let auction_house = &ctx.accounts.auction_house;
-    let ah_key = auction_house.key();
-    let auctioneer_authority = &ctx.accounts.auctioneer_authority;
-    let _aa_key = auctioneer_authority.key();
-
+    let ah_key = cpi_accounts.auction_house.key();
     let auctioneer_seeds = [
         AUCTIONEER.as_bytes(),
         ah_key.as_ref(),
@@ -154,23 +368,5 @@ pub fn auctioneer_cancel<'info>(
 
     invoke_signed(&ix, &cpi_accounts.to_account_infos(), &[&auctioneer_seeds])?;
 
-    // Close the Listing Config account if the seller is canceling their listing.
-    if ctx.accounts.token_account.owner == ctx.accounts.wallet.key()
-        && ctx.accounts.wallet.is_signer
-    {
-        let listing_config = &ctx.accounts.listing_config.to_account_info();
-        let seller = &ctx.accounts.seller.to_account_info();
-
-        let listing_config_lamports = listing_config.lamports();
-        **seller.lamports.borrow_mut() = seller
-            .lamports()
-            .checked_add(listing_config_lamports)
-            .unwrap();
-        **listing_config.lamports.borrow_mut() = 0;
-
-        let mut source_data = listing_config.data.borrow_mut();
-        source_data.fill(0);
-    }
-
     Ok(())
 }
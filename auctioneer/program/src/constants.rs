@@ -1,2 +1,71 @@
+use anchor_lang::prelude::constant;
+
+// Shared with Auction House through `mpl_auction_common::seeds`, declared here as well so it is
+// listed in the IDL.
+#[constant]
 pub const LISTING_CONFIG: &str = "listing_config";
+
 pub const AUCTIONEER_BUYER_PRICE: u64 = u64::MAX;
+#[constant]
+pub const PRIVATE_ROOM: &str = "private_room";
+#[constant]
+pub const SEALED_BID: &str = "sealed_bid";
+pub const MAX_SEALED_BID_CIPHERTEXT_LEN: usize = 256;
+#[constant]
+pub const DROP_SCHEDULE: &str = "drop_schedule";
+#[constant]
+pub const DROP_VAULT: &str = "drop_vault";
+#[constant]
+pub const DROP_ITEM: &str = "drop_item";
+#[constant]
+pub const BID_LIMIT: &str = "bid_limit";
+#[constant]
+pub const WALLET_BID_COUNTER: &str = "wallet_bid_counter";
+#[constant]
+pub const BLIND_BOX: &str = "blind_box";
+#[constant]
+pub const BID_POOL: &str = "bid_pool";
+#[constant]
+pub const BID_POOL_VAULT: &str = "bid_pool_vault";
+#[constant]
+pub const BID_POOL_SHARES: &str = "bid_pool_shares";
+#[constant]
+pub const POOL_CONTRIBUTION: &str = "pool_contribution";
+#[constant]
+pub const AUTO_ACCEPT: &str = "auto_accept";
+#[constant]
+pub const LISTING_PAUSE: &str = "listing_pause";
+/// Start and end time of a listing while it is paused.
+pub const PAUSED_TIMESTAMP: i64 = i64::MAX;
+pub const MAX_SELL_MANY_LISTINGS: usize = 5;
+#[constant]
+pub const END_TIME_INDEX: &str = "end_time_index";
+/// Width of the end time buckets listings are indexed in.
+pub const END_TIME_BUCKET_SECONDS: i64 = 3600;
+pub const MAX_END_TIME_INDEX_LISTINGS: usize = 64;
+#[constant]
+pub const BUY_NOW: &str = "buy_now";
+/// Reserve price of buy it now listings, which no regular bid can meet.
+pub const BUY_NOW_RESERVE_PRICE: u64 = u64::MAX;
+#[constant]
+pub const BID_QUALIFICATION: &str = "bid_qualification";
+#[constant]
+pub const BACKUP_BIDS: &str = "backup_bids";
+pub const MAX_BACKUP_BIDS: usize = 3;
+#[constant]
+pub const BID_RATE_LIMIT: &str = "bid_rate_limit";
+#[constant]
+pub const WALLET_BID_RATE: &str = "wallet_bid_rate";
+#[constant]
+pub const AUCTION_SUMMARY: &str = "auction_summary";
+#[constant]
+pub const PSEUDONYMOUS_BIDDER: &str = "pseudonymous_bidder";
+#[constant]
+pub const PSEUDONYMOUS_VAULT: &str = "pseudonymous_vault";
+#[constant]
+pub const BIDDER_ALLOWLIST: &str = "bidder_allowlist";
+pub const MAX_ALLOWLISTED_BIDDERS: usize = 32;
+#[constant]
+pub const BIDDER_INVITATION: &str = "bidder_invitation";
+#[constant]
+pub const DURATION_BOUNDS: &str = "duration_bounds";
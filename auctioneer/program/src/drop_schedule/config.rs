@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+#[constant]
+pub const DROP_SCHEDULE_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 4 + 4 + 1 + 4 + 4 + 1 + 1;
+#[constant]
+pub const DROP_ITEM_SIZE: usize = 8 + 32 + 32 + 4 + 1;
+
+/// A series of timed listings opened one at a time from the schedule's escrowed pool.
+#[account]
+pub struct DropSchedule {
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub start_time: UnixTimestamp,
+    pub interval: i64,
+    pub listing_duration: i64,
+    pub reserve_price: u64,
+    pub min_bid_increment: u64,
+    pub time_ext_period: u32,
+    pub time_ext_delta: u32,
+    pub allow_high_bid_cancel: bool,
+    pub item_count: u32,
+    pub next_index: u32,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
+/// A token escrowed in a drop schedule's pool, listed when the schedule reaches `index`.
+#[account]
+pub struct DropItem {
+    pub drop_schedule: Pubkey,
+    pub mint: Pubkey,
+    pub index: u32,
+    pub bump: u8,
+}
@@ -0,0 +1,500 @@
+//! Scheduled drop series opening timed listings from an escrowed pool.
+pub mod config;
+
+use crate::{constants::*, drop_schedule::config::*, errors::*, sell::config::*};
+
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER, TRADE_STATE_SIZE},
+    cpi::accounts::AuctioneerSell as AHSell,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+
+use solana_program::{
+    clock::UnixTimestamp,
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+/// Accounts for the [`create_drop_schedule` handler](fn.create_drop_schedule.html).
+#[derive(Accounts)]
+pub struct CreateDropSchedule<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller or creator running the drop series.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The drop schedule PDA account.
+    #[account(
+        init,
+        payer=authority,
+        space=DROP_SCHEDULE_SIZE,
+        seeds=[DROP_SCHEDULE.as_bytes(), auction_house.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub drop_schedule: Box<Account<'info, DropSchedule>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA owning the escrowed pool and acting as seller of each drop.
+    #[account(mut, seeds=[DROP_VAULT.as_bytes(), drop_schedule.key().as_ref()], bump)]
+    pub drop_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a drop schedule listing one pooled item every `interval` seconds from `start_time`,
+/// each for `listing_duration` seconds with the given auction settings.
+pub fn create_drop_schedule<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateDropSchedule<'info>>,
+    start_time: UnixTimestamp,
+    interval: i64,
+    listing_duration: i64,
+    reserve_price: Option<u64>,
+    min_bid_increment: Option<u64>,
+    time_ext_period: Option<u32>,
+    time_ext_delta: Option<u32>,
+    allow_high_bid_cancel: Option<bool>,
+) -> Result<()> {
+    if interval <= 0 || listing_duration <= 0 {
+        return err!(AuctioneerError::InvalidDropSchedule);
+    }
+
+    let drop_schedule = &mut ctx.accounts.drop_schedule;
+    drop_schedule.authority = ctx.accounts.authority.key();
+    drop_schedule.auction_house = ctx.accounts.auction_house.key();
+    drop_schedule.start_time = start_time;
+    drop_schedule.interval = interval;
+    drop_schedule.listing_duration = listing_duration;
+    drop_schedule.reserve_price = reserve_price.unwrap_or(0);
+    drop_schedule.min_bid_increment = min_bid_increment.unwrap_or(0);
+    drop_schedule.time_ext_period = time_ext_period.unwrap_or(0);
+    drop_schedule.time_ext_delta = time_ext_delta.unwrap_or(0);
+    drop_schedule.allow_high_bid_cancel = allow_high_bid_cancel.unwrap_or(false);
+    drop_schedule.item_count = 0;
+    drop_schedule.next_index = 0;
+    drop_schedule.bump = *ctx
+        .bumps
+        .get("drop_schedule")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+    drop_schedule.vault_bump = *ctx
+        .bumps
+        .get("drop_vault")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    // The vault pays for each listing's trade state, so it is kept rent exempt.
+    let vault_rent = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(ctx.accounts.drop_vault.lamports());
+    if vault_rent > 0 {
+        invoke(
+            &system_instruction::transfer(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.drop_vault.key(),
+                vault_rent,
+            ),
+            &[
+                ctx.accounts.authority.to_account_info(),
+                ctx.accounts.drop_vault.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Accounts for the [`add_drop_item` handler](fn.add_drop_item.html).
+#[derive(Accounts)]
+pub struct AddDropItem<'info> {
+    /// Seller or creator running the drop series.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The drop schedule PDA account.
+    #[account(
+        mut,
+        seeds=[DROP_SCHEDULE.as_bytes(), drop_schedule.auction_house.as_ref(), authority.key().as_ref()],
+        bump=drop_schedule.bump,
+        has_one=authority,
+    )]
+    pub drop_schedule: Box<Account<'info, DropSchedule>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA owning the escrowed pool and acting as seller of each drop.
+    #[account(mut, seeds=[DROP_VAULT.as_bytes(), drop_schedule.key().as_ref()], bump=drop_schedule.vault_bump)]
+    pub drop_vault: UncheckedAccount<'info>,
+
+    /// Mint of the token added to the pool.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Authority token account the item is escrowed from.
+    #[account(mut, token::mint=token_mint, token::authority=authority)]
+    pub source_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Vault token account holding the item until it is sold.
+    #[account(
+        init,
+        payer=authority,
+        associated_token::mint=token_mint,
+        associated_token::authority=drop_vault,
+    )]
+    pub vault_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The pool entry for the item.
+    #[account(
+        init,
+        payer=authority,
+        space=DROP_ITEM_SIZE,
+        seeds=[DROP_ITEM.as_bytes(), drop_schedule.key().as_ref(), &drop_schedule.item_count.to_le_bytes()],
+        bump,
+    )]
+    pub drop_item: Box<Account<'info, DropItem>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Escrow a token in the drop schedule's pool as its next item.
+pub fn add_drop_item<'info>(ctx: Context<'_, '_, '_, 'info, AddDropItem<'info>>) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_token_account.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    // Fund the trade state the vault creates when this item is listed.
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.drop_vault.key(),
+            ctx.accounts.rent.minimum_balance(TRADE_STATE_SIZE),
+        ),
+        &[
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.drop_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let drop_schedule = &mut ctx.accounts.drop_schedule;
+    let drop_item = &mut ctx.accounts.drop_item;
+    drop_item.drop_schedule = drop_schedule.key();
+    drop_item.mint = ctx.accounts.token_mint.key();
+    drop_item.index = drop_schedule.item_count;
+    drop_item.bump = *ctx
+        .bumps
+        .get("drop_item")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    drop_schedule.item_count = drop_schedule
+        .item_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`open_next_drop` handler](fn.open_next_drop.html).
+#[derive(Accounts)]
+#[instruction(trade_state_bump: u8, free_trade_state_bump: u8, program_as_signer_bump: u8, auctioneer_authority_bump: u8)]
+pub struct OpenNextDrop<'info> {
+    /// Auction House Program used for CPI call
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Anyone cranking the schedule; pays for the listing config.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The drop schedule PDA account.
+    #[account(
+        mut,
+        seeds=[DROP_SCHEDULE.as_bytes(), auction_house.key().as_ref(), drop_schedule.authority.as_ref()],
+        bump=drop_schedule.bump,
+        has_one=auction_house,
+    )]
+    pub drop_schedule: Box<Account<'info, DropSchedule>>,
+
+    /// The pool entry listed by this drop.
+    #[account(
+        seeds=[DROP_ITEM.as_bytes(), drop_schedule.key().as_ref(), &drop_schedule.next_index.to_le_bytes()],
+        bump=drop_item.bump,
+        has_one=drop_schedule,
+    )]
+    pub drop_item: Box<Account<'info, DropItem>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA owning the escrowed pool and acting as seller of each drop.
+    #[account(mut, seeds=[DROP_VAULT.as_bytes(), drop_schedule.key().as_ref()], bump=drop_schedule.vault_bump)]
+    pub drop_vault: UncheckedAccount<'info>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        init,
+        payer=payer,
+        space=LISTING_CONFIG_SIZE,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            drop_vault.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &1u64.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// Vault token account holding the item for sale.
+    #[account(
+        mut,
+        associated_token::mint=drop_item.mint,
+        associated_token::authority=drop_vault,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified through CPI
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump=auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(mut, seeds=[PREFIX.as_bytes(), drop_vault.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_account.mint.as_ref(), &u64::MAX.to_le_bytes(), &1u64.to_le_bytes()], seeds::program=auction_house_program, bump=trade_state_bump)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(mut, seeds=[PREFIX.as_bytes(), drop_vault.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_account.mint.as_ref(), &0u64.to_le_bytes(), &1u64.to_le_bytes()], seeds::program=auction_house_program, bump=free_trade_state_bump)]
+    pub free_seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+            ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], seeds::program=auction_house_program, bump=program_as_signer_bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Permissionless crank listing the schedule's next item once its slot has arrived.
+pub fn open_next_drop<'info>(
+    ctx: Context<'_, '_, '_, 'info, OpenNextDrop<'info>>,
+    trade_state_bump: u8,
+    free_trade_state_bump: u8,
+    program_as_signer_bump: u8,
+    auctioneer_authority_bump: u8,
+) -> Result<()> {
+    let drop_schedule = &ctx.accounts.drop_schedule;
+    let opens_at = drop_schedule
+        .interval
+        .checked_mul(drop_schedule.next_index as i64)
+        .and_then(|offset| offset.checked_add(drop_schedule.start_time))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    if now < opens_at {
+        return err!(AuctioneerError::DropNotReady);
+    }
+
+    let listing_config = &mut ctx.accounts.listing_config;
+    listing_config.version = ListingConfigVersion::V6;
+    listing_config.highest_bid.version = ListingConfigVersion::V6;
+    listing_config.start_time = now;
+    listing_config.end_time = now
+        .checked_add(drop_schedule.listing_duration)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    listing_config.reserve_price = drop_schedule.reserve_price;
+    listing_config.min_bid_increment = drop_schedule.min_bid_increment;
+    listing_config.time_ext_period = drop_schedule.time_ext_period;
+    listing_config.time_ext_delta = drop_schedule.time_ext_delta;
+    listing_config.allow_high_bid_cancel = drop_schedule.allow_high_bid_cancel;
+    listing_config.tick_size = 0;
+    listing_config.fee_basis_points = None;
+    listing_config.clock = ListingClock::UnixTimestamp;
+    listing_config.features = ListingFeatures::default();
+    listing_config.lot_metadata = vec![];
+    listing_config.bump = *ctx
+        .bumps
+        .get("listing_config")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHSell {
+        wallet: ctx.accounts.drop_vault.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+        free_seller_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let sell_data = mpl_auction_house::instruction::AuctioneerSell {
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        token_size: 1,
+    };
+
+    // The vault signs as the seller alongside the auctioneer authority.
+    let mut cpi_account_metas: Vec<AccountMeta> = cpi_accounts
+        .to_account_metas(None)
+        .into_iter()
+        .zip(cpi_accounts.to_account_infos())
+        .map(|mut pair| {
+            pair.0.is_signer = pair.1.is_signer;
+            if pair.0.pubkey == ctx.accounts.auctioneer_authority.key()
+                || pair.0.pubkey == ctx.accounts.drop_vault.key()
+            {
+                pair.0.is_signer = true;
+            }
+            pair.0
+        })
+        .collect();
+
+    cpi_account_metas.append(&mut ctx.remaining_accounts.to_vec().to_account_metas(None));
+
+    let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
+    cpi_account_infos.append(&mut ctx.remaining_accounts.to_vec());
+
+    let ix = solana_program::instruction::Instruction {
+        program_id: cpi_program.key(),
+        accounts: cpi_account_metas,
+        data: sell_data.data(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    let drop_schedule_key = drop_schedule.key();
+    let vault_seeds = [
+        DROP_VAULT.as_bytes(),
+        drop_schedule_key.as_ref(),
+        &[drop_schedule.vault_bump],
+    ];
+
+    invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds, &vault_seeds])?;
+
+    ctx.accounts.drop_schedule.next_index = ctx
+        .accounts
+        .drop_schedule
+        .next_index
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`withdraw_drop_proceeds` handler](fn.withdraw_drop_proceeds.html).
+#[derive(Accounts)]
+pub struct WithdrawDropProceeds<'info> {
+    /// Seller or creator running the drop series.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The drop schedule PDA account.
+    #[account(
+        seeds=[DROP_SCHEDULE.as_bytes(), drop_schedule.auction_house.as_ref(), authority.key().as_ref()],
+        bump=drop_schedule.bump,
+        has_one=authority,
+    )]
+    pub drop_schedule: Box<Account<'info, DropSchedule>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA receiving the sale proceeds of each drop.
+    #[account(mut, seeds=[DROP_VAULT.as_bytes(), drop_schedule.key().as_ref()], bump=drop_schedule.vault_bump)]
+    pub drop_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw `amount` lamports of sale proceeds from the drop vault.
+pub fn withdraw_drop_proceeds<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawDropProceeds<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let drop_schedule_key = ctx.accounts.drop_schedule.key();
+    let vault_seeds = [
+        DROP_VAULT.as_bytes(),
+        drop_schedule_key.as_ref(),
+        &[ctx.accounts.drop_schedule.vault_bump],
+    ];
+
+    invoke_signed(
+        &system_instruction::transfer(
+            &ctx.accounts.drop_vault.key(),
+            &ctx.accounts.authority.key(),
+            amount,
+        ),
+        &[
+            ctx.accounts.drop_vault.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&vault_seeds],
+    )?;
+
+    Ok(())
+}
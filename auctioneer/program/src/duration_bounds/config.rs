@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const DURATION_BOUNDS_SIZE: usize = 8 + 32 + 8 + 8 + 1;
+
+/// House-wide limits on how long an auction may run, in seconds. A bound of zero is unbounded.
+#[account]
+pub struct DurationBounds {
+    pub auction_house: Pubkey,
+    /// Shortest time a listing may be open for bids, from the later of its start and its listing.
+    pub min_duration: i64,
+    /// Longest time from listing to a listing's end.
+    pub max_duration: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,125 @@
+//! House-level bounds on auction durations, so sellers cannot list flash auctions that close
+//! before anyone can bid or auctions that hold the token and bidders' escrow for years.
+pub mod config;
+
+use crate::{constants::*, duration_bounds::config::*, errors::*, sell::config::*};
+
+use anchor_lang::prelude::*;
+use solana_program::clock::{UnixTimestamp, DEFAULT_MS_PER_SLOT};
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram,
+    utils::create_or_allocate_account_raw, AuctionHouse,
+};
+
+/// Accounts for the [`set_duration_bounds` handler](fn.set_duration_bounds.html).
+#[derive(Accounts)]
+pub struct SetDurationBounds<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Auction House instance authority account, paying for the duration bounds.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=authority)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The duration bounds PDA for the Auction House, created on first use.
+    #[account(mut, seeds=[DURATION_BOUNDS.as_bytes(), auction_house.key().as_ref()], bump)]
+    pub duration_bounds: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Require new auctions on the house to be open for bids for at least `min_duration` seconds and
+/// to end within `max_duration` seconds of being listed. A bound of zero is unbounded.
+pub fn set_duration_bounds<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetDurationBounds<'info>>,
+    min_duration: i64,
+    max_duration: i64,
+) -> Result<()> {
+    if min_duration < 0 || max_duration < 0 || (max_duration > 0 && min_duration > max_duration) {
+        return err!(AuctioneerError::InvalidDurationBounds);
+    }
+
+    let duration_bounds = &ctx.accounts.duration_bounds;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let bump = *ctx
+        .bumps
+        .get("duration_bounds")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    if duration_bounds.data_is_empty() {
+        create_or_allocate_account_raw(
+            crate::id(),
+            &duration_bounds.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            DURATION_BOUNDS_SIZE,
+            &[],
+            &[
+                DURATION_BOUNDS.as_bytes(),
+                auction_house_key.as_ref(),
+                &[bump],
+            ],
+        )?;
+    }
+
+    DurationBounds {
+        auction_house: auction_house_key,
+        min_duration,
+        max_duration,
+        bump,
+    }
+    .try_serialize(&mut *duration_bounds.try_borrow_mut_data()?)
+}
+
+/// Check a new listing's times against the house's duration bounds, if it has set any. Bounds
+/// are in seconds, and are converted at the target slot time for listings timed in slots.
+pub fn assert_duration_in_bounds(
+    duration_bounds: &AccountInfo,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    clock: ListingClock,
+) -> Result<()> {
+    if duration_bounds.data_is_empty() {
+        return Ok(());
+    }
+
+    let bounds = DurationBounds::try_deserialize(&mut &duration_bounds.try_borrow_data()?[..])?;
+    let (now, min_duration, max_duration) = match clock {
+        ListingClock::UnixTimestamp => (
+            Clock::get()?.unix_timestamp,
+            bounds.min_duration,
+            bounds.max_duration,
+        ),
+        ListingClock::Slot => (
+            i64::try_from(Clock::get()?.slot).map_err(|_| ProgramError::ArithmeticOverflow)?,
+            seconds_to_slots(bounds.min_duration)?,
+            seconds_to_slots(bounds.max_duration)?,
+        ),
+    };
+
+    let open_duration = end_time.saturating_sub(start_time.max(now));
+    if min_duration > 0 && open_duration < min_duration {
+        return err!(AuctioneerError::AuctionTooShort);
+    }
+
+    if max_duration > 0 && end_time.saturating_sub(now) > max_duration {
+        return err!(AuctioneerError::AuctionTooLong);
+    }
+
+    Ok(())
+}
+
+fn seconds_to_slots(seconds: i64) -> Result<i64> {
+    seconds
+        .checked_mul(1000)
+        .map(|ms| ms / DEFAULT_MS_PER_SLOT as i64)
+        .ok_or_else(|| ProgramError::ArithmeticOverflow.into())
+}
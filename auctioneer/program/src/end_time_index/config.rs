@@ -0,0 +1,67 @@
+use anchor_lang::{prelude::*, Discriminator};
+use solana_program::clock::UnixTimestamp;
+use std::mem::size_of;
+
+use crate::constants::{END_TIME_BUCKET_SECONDS, MAX_END_TIME_INDEX_LISTINGS};
+
+#[constant]
+pub const END_TIME_INDEX_SIZE: usize = 8 + size_of::<EndTimeIndex>();
+
+/// Listing configs on an Auction House whose auctions end within one bucket of
+/// `END_TIME_BUCKET_SECONDS` starting at `bucket_start`.
+///
+/// Zero-copy, so a sell appends to the bucket without deserializing every listing in it. Read
+/// the indexed listings with [`EndTimeIndex::listings`]. Only the index is zero-copy: the listing
+/// configs and Auction House that settlement loads keep their Borsh layouts.
+#[account(zero_copy)]
+pub struct EndTimeIndex {
+    pub auction_house: Pubkey,
+    pub bucket_start: UnixTimestamp,
+    pub listing_slots: [Pubkey; MAX_END_TIME_INDEX_LISTINGS],
+    pub listing_count: u8,
+    pub bump: u8,
+    pub padding: [u8; 6],
+}
+
+impl EndTimeIndex {
+    /// Start of the bucket containing `end_time`.
+    pub fn bucket_start_for(end_time: UnixTimestamp) -> UnixTimestamp {
+        end_time - end_time.rem_euclid(END_TIME_BUCKET_SECONDS)
+    }
+
+    /// Read an end time index from its account data, checking the discriminator.
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        if data.len() < END_TIME_INDEX_SIZE || data[..8] != Self::discriminator() {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+
+        Ok(bytemuck::pod_read_unaligned(&data[8..END_TIME_INDEX_SIZE]))
+    }
+
+    /// The indexed listing configs.
+    pub fn listings(&self) -> &[Pubkey] {
+        &self.listing_slots[..self.listing_count as usize]
+    }
+
+    /// Append `listing_config`, returning false if the index is full.
+    pub fn push(&mut self, listing_config: Pubkey) -> bool {
+        let count = self.listing_count as usize;
+        if count >= MAX_END_TIME_INDEX_LISTINGS {
+            return false;
+        }
+
+        self.listing_slots[count] = listing_config;
+        self.listing_count += 1;
+        true
+    }
+
+    /// Remove `listing_config`, moving the last listing into its slot.
+    pub fn remove(&mut self, listing_config: &Pubkey) {
+        if let Some(position) = self.listings().iter().position(|key| key == listing_config) {
+            let last = self.listing_count as usize - 1;
+            self.listing_slots[position] = self.listing_slots[last];
+            self.listing_slots[last] = Pubkey::default();
+            self.listing_count -= 1;
+        }
+    }
+}
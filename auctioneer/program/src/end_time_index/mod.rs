@@ -0,0 +1,138 @@
+//! Hourly buckets of listings by end time, so settlement cranks can find the auctions ending soon
+//! from chain state alone. A sell adds its listing config to a bucket when the bucket's index is
+//! passed as the first remaining account; settled and cancelled listings are pruned by anyone.
+pub mod config;
+
+use crate::{constants::*, end_time_index::config::*, errors::*};
+
+use anchor_lang::{prelude::*, Discriminator};
+use solana_program::clock::UnixTimestamp;
+
+use mpl_auction_house::{
+    self, constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+
+/// Accounts for the [`create_end_time_index` handler](fn.create_end_time_index.html).
+#[derive(Accounts)]
+#[instruction(bucket_start: i64)]
+pub struct CreateEndTimeIndex<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Pays for the end time index account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The end time index PDA for the bucket.
+    #[account(
+        init,
+        payer=payer,
+        space=END_TIME_INDEX_SIZE,
+        seeds=[
+            END_TIME_INDEX.as_bytes(),
+            auction_house.key().as_ref(),
+            &bucket_start.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub end_time_index: AccountLoader<'info, EndTimeIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`prune_end_time_index` handler](fn.prune_end_time_index.html).
+///
+/// Remaining accounts are the indexed listing configs to drop. Only listing configs that have
+/// been closed are removed.
+#[derive(Accounts)]
+pub struct PruneEndTimeIndex<'info> {
+    /// The end time index PDA for the bucket.
+    #[account(
+        mut,
+        seeds=[
+            END_TIME_INDEX.as_bytes(),
+            end_time_index.load()?.auction_house.as_ref(),
+            &end_time_index.load()?.bucket_start.to_le_bytes()
+        ],
+        bump=end_time_index.load()?.bump,
+    )]
+    pub end_time_index: AccountLoader<'info, EndTimeIndex>,
+}
+
+/// Create the index for the bucket starting at `bucket_start`, which must be a multiple of
+/// `END_TIME_BUCKET_SECONDS`.
+pub fn create_end_time_index<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateEndTimeIndex<'info>>,
+    bucket_start: i64,
+) -> Result<()> {
+    if EndTimeIndex::bucket_start_for(bucket_start) != bucket_start {
+        return err!(AuctioneerError::InvalidEndTimeBucket);
+    }
+
+    let end_time_index = &mut ctx.accounts.end_time_index.load_init()?;
+    end_time_index.auction_house = ctx.accounts.auction_house.key();
+    end_time_index.bucket_start = bucket_start;
+    end_time_index.bump = *ctx
+        .bumps
+        .get("end_time_index")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Drop the closed listing configs passed in `remaining_accounts` from the index.
+pub fn prune_end_time_index<'info>(
+    ctx: Context<'_, '_, '_, 'info, PruneEndTimeIndex<'info>>,
+) -> Result<()> {
+    let end_time_index = &mut ctx.accounts.end_time_index.load_mut()?;
+    for listing_config in ctx.remaining_accounts {
+        if listing_config.lamports() == 0 {
+            end_time_index.remove(listing_config.key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Split an end time index off the front of the sell remaining accounts, if one was passed,
+/// leaving the accounts forwarded to Auction House.
+pub fn split_end_time_index<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(Option<&'a AccountInfo<'info>>, &'a [AccountInfo<'info>])> {
+    match remaining_accounts.split_first() {
+        Some((info, rest))
+            if info.owner == &crate::id()
+                && info.data_len() >= 8
+                && info.try_borrow_data()?[..8] == EndTimeIndex::discriminator() =>
+        {
+            Ok((Some(info), rest))
+        }
+        _ => Ok((None, remaining_accounts)),
+    }
+}
+
+/// Add `listing_config` to the end time index, checking the index covers `auction_house` and the
+/// bucket containing `end_time`.
+pub fn index_listing(
+    end_time_index: &AccountInfo,
+    auction_house: &Pubkey,
+    listing_config: &Pubkey,
+    end_time: UnixTimestamp,
+) -> Result<()> {
+    let end_time_index = AccountLoader::<EndTimeIndex>::try_from(end_time_index)?;
+    let mut index = end_time_index.load_mut()?;
+    if index.auction_house != *auction_house
+        || index.bucket_start != EndTimeIndex::bucket_start_for(end_time)
+    {
+        return err!(AuctioneerError::InvalidEndTimeIndex);
+    }
+    if !index.push(*listing_config) {
+        return err!(AuctioneerError::EndTimeIndexFull);
+    }
+
+    Ok(())
+}
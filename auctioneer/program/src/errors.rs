@@ -41,4 +41,248 @@ pub enum AuctioneerError {
     // 6009
     #[msg("The highest bidder is not allowed to cancel")]
     CannotCancelHighestBid,
+
+    // 6010
+    #[msg("Bids in a private auction room must be sealed")]
+    AuctionIsPrivate,
+
+    // 6011
+    #[msg("The listing already has bids")]
+    ListingHasBids,
+
+    // 6012
+    #[msg("The sealed bid ciphertext is too large")]
+    SealedBidTooLarge,
+
+    // 6013
+    #[msg("The sealed bid has already been revealed")]
+    BidAlreadyRevealed,
+
+    // 6014
+    #[msg("The revealed bid does not match its commitment")]
+    InvalidBidReveal,
+
+    // 6015
+    #[msg("All sealed bids must be revealed before settlement")]
+    PrivateRoomNotRevealed,
+
+    // 6016
+    #[msg("Only the revealed winner can bid at the revealed price")]
+    NotPrivateRoomWinner,
+
+    // 6017
+    #[msg("Drop schedule interval and listing duration must be positive")]
+    InvalidDropSchedule,
+
+    // 6018
+    #[msg("The next drop in the schedule has not been reached yet")]
+    DropNotReady,
+
+    // 6019
+    #[msg("The bid must be a multiple of the listing tick size")]
+    BidNotMultipleOfTickSize,
+
+    // 6020
+    #[msg("A bid limit must cap the number or the amount of bids per wallet")]
+    InvalidBidLimit,
+
+    // 6021
+    #[msg("The wallet has reached the listing's limit of active bids")]
+    WalletBidLimitExceeded,
+
+    // 6022
+    #[msg("The wallet has reached the listing's limit on committed bid amount")]
+    WalletBidAmountExceeded,
+
+    // 6023
+    #[msg("The blind box has not sold yet")]
+    BlindBoxNotSold,
+
+    // 6024
+    #[msg("The reveal program does not match the blind box")]
+    InvalidRevealProgram,
+
+    // 6025
+    #[msg("Bid pools require an Auction House with a native SOL treasury")]
+    PoolRequiresNativeTreasury,
+
+    // 6026
+    #[msg("Pool contributions must be greater than zero")]
+    InvalidPoolContribution,
+
+    // 6027
+    #[msg("The pool's listing has not been decided yet")]
+    PoolListingOpen,
+
+    // 6028
+    #[msg("The pool won the listing, claim shares instead")]
+    PoolWonListing,
+
+    // 6029
+    #[msg("The pool did not win the listing, refund the contribution instead")]
+    PoolLostListing,
+
+    // 6030
+    #[msg("Bulk listings must contain between one and the maximum number of listings")]
+    InvalidListingCount,
+
+    // 6031
+    #[msg("The accounts passed for the bulk listings do not match the listings")]
+    MissingListingAccounts,
+
+    // 6032
+    #[msg("The previous seller still holds the listed tokens")]
+    ListingStillHeld,
+
+    // 6033
+    #[msg("The listing config already uses the current layout")]
+    ListingConfigUpToDate,
+
+    // 6034
+    #[msg("The auto accept price must be non-zero and at least the reserve price")]
+    InvalidAutoAcceptPrice,
+
+    // 6035
+    #[msg("The auction is paused")]
+    AuctionPaused,
+
+    // 6036
+    #[msg("Bid ties the highest bid, which was placed first")]
+    BidTiesHighestBid,
+
+    // 6037
+    #[msg("End time index buckets must start on a bucket boundary")]
+    InvalidEndTimeBucket,
+
+    // 6038
+    #[msg("The end time index does not cover the listing's auction house and end time")]
+    InvalidEndTimeIndex,
+
+    // 6039
+    #[msg("The end time index bucket is full")]
+    EndTimeIndexFull,
+
+    // 6040
+    #[msg("The buy now price must be non-zero")]
+    InvalidBuyNowPrice,
+
+    // 6041
+    #[msg("A bid qualification must require a non-zero escrow balance")]
+    InvalidBidQualification,
+
+    // 6042
+    #[msg("The wallet's escrow balance is below the listing's bidding minimum")]
+    InsufficientEscrowBalance,
+
+    // 6043
+    #[msg("Only the seller holding the listed token can cancel the listing")]
+    NotListingSeller,
+
+    // 6044
+    #[msg("The listing's trade state is still open")]
+    ListingNotOrphaned,
+
+    // 6045
+    #[msg("The settlement price must equal the highest bid")]
+    SettlementPriceMismatch,
+
+    // 6046
+    #[msg("The listing's backup bids are full and the bid does not beat any of them")]
+    BackupBidTooLow,
+
+    // 6047
+    #[msg("The highest bidder can still settle their bid")]
+    HighestBidCanSettle,
+
+    // 6048
+    #[msg("The listing has no backup bids left")]
+    NoBackupBids,
+
+    // 6049
+    #[msg("Listings timed in slots cannot be added to an end time index")]
+    SlotListingNotIndexable,
+
+    // 6050
+    #[msg("A bid rate limit must set a minimum slot gap or cap the number of bids per wallet")]
+    InvalidBidRateLimit,
+
+    // 6051
+    #[msg("The wallet must wait more slots before bidding on the listing again")]
+    WalletBidTooSoon,
+
+    // 6052
+    #[msg("The wallet has placed the listing's maximum number of bids")]
+    WalletBidCountExceeded,
+
+    // 6053
+    #[msg("The auction summary has already been written")]
+    AuctionSummaryFinal,
+
+    // 6054
+    #[msg("Pseudonymous bids are only supported for native SOL treasuries")]
+    PseudonymousBidRequiresNativeTreasury,
+
+    // 6055
+    #[msg("The wallet and salt do not match the pseudonymous bidder's commitment")]
+    PseudonymCommitmentMismatch,
+
+    // 6056
+    #[msg("The pseudonymous bid is the listing's highest bid")]
+    PseudonymousBidHighest,
+
+    // 6057
+    #[msg("A bidder allowlist must list at most 32 bidders and list at least one or set a merkle root")]
+    InvalidBidderAllowlist,
+
+    // 6058
+    #[msg("The wallet is not invited to bid on the listing")]
+    BidderNotInvited,
+
+    // 6059
+    #[msg("The proof does not show the wallet is in the listing's bidder allowlist")]
+    InvalidAllowlistProof,
+
+    // 6060
+    #[msg("Lot metadata must be at most 256 bytes")]
+    LotMetadataTooLong,
+
+    // 6061
+    #[msg("Minimum auction duration must not exceed the maximum")]
+    InvalidDurationBounds,
+
+    // 6062
+    #[msg("The auction is shorter than the house's minimum duration")]
+    AuctionTooShort,
+
+    // 6063
+    #[msg("The auction ends later than the house's maximum duration allows")]
+    AuctionTooLong,
+
+    // 6064
+    #[msg("The listing config data does not match the layout of its version")]
+    ListingConfigLayoutMismatch,
+
+    // 6065
+    #[msg("The listing config must be migrated to the current layout")]
+    ListingConfigOutdated,
+
+    // 6066
+    #[msg("The account does not match the listing's feature account")]
+    InvalidFeatureAccount,
+
+    // 6067
+    #[msg("The pool's token has already been redeemed")]
+    PoolTokenRedeemed,
+
+    // 6068
+    #[msg("The reveal window must be positive")]
+    InvalidRevealWindow,
+
+    // 6069
+    #[msg("The private room's reveal window has closed")]
+    RevealWindowClosed,
+
+    // 6070
+    #[msg("Listings with feature accounts or that sold cannot be adopted")]
+    ListingNotAdoptable,
 }
@@ -9,7 +9,15 @@ use mpl_auction_house::{
     AuctionHouse,
 };
 
-use crate::{constants::*, sell::config::*, utils::*};
+use crate::{
+    auction_summary::write_auction_summary,
+    auto_accept::{auto_accept_reached, close_auto_accept},
+    backup_bids::close_backup_bids,
+    blind_box::record_blind_box_sale,
+    constants::*,
+    sell::{config::*, LotSold},
+    utils::*,
+};
 
 use solana_program::program::invoke_signed;
 
@@ -152,11 +160,53 @@ pub fn auctioneer_execute_sale<'info>(
     buyer_price: u64,
     token_size: u64,
 ) -> Result<()> {
-    assert_auction_over(&ctx.accounts.listing_config)?;
+    assert_not_paused(&ctx.accounts.listing_config)?;
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    // The listing's feature accounts come first in `remaining_accounts`, in `ListingFeatures`
+    // order; Auction House takes the rest.
+    let listing_config_key = ctx.accounts.listing_config.key();
+    let features = ctx.accounts.listing_config.features;
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let blind_box = next_feature_account(
+        remaining_accounts,
+        features.blind_box,
+        &[BLIND_BOX.as_bytes(), listing_config_key.as_ref()],
+    )?
+    .map(|(account, _)| account);
+    let auto_accept = next_feature_account(
+        remaining_accounts,
+        features.auto_accept,
+        &[AUTO_ACCEPT.as_bytes(), listing_config_key.as_ref()],
+    )?
+    .map(|(account, _)| account);
+    let backup_bids = next_feature_account(
+        remaining_accounts,
+        features.backup_bids,
+        &[BACKUP_BIDS.as_bytes(), listing_config_key.as_ref()],
+    )?
+    .map(|(account, _)| account);
+    let auction_summary = next_feature_account(
+        remaining_accounts,
+        features.auction_summary,
+        &[
+            AUCTION_SUMMARY.as_bytes(),
+            listing_config_key.as_ref(),
+            &ctx.accounts.listing_config.start_time.to_le_bytes(),
+        ],
+    )?
+    .map(|(account, _)| account);
+    let remaining_accounts = remaining_accounts.as_slice();
+
+    // A bid at or above the auto-accept price can be settled before the auction ends.
+    if !auto_accept_reached(auto_accept, &ctx.accounts.listing_config)? {
+        assert_auction_over(&ctx.accounts.listing_config)?;
+    }
     assert_highest_bidder(
         &ctx.accounts.listing_config,
         ctx.accounts.buyer_trade_state.key(),
     )?;
+    assert_highest_bid_price(&ctx.accounts.listing_config, buyer_price)?;
 
     let cpi_program = ctx.accounts.auction_house_program.to_account_info();
     let cpi_accounts = AHExecuteSale {
@@ -209,10 +259,10 @@ pub fn auctioneer_execute_sale<'info>(
         })
         .collect();
 
-    cpi_account_metas.append(&mut ctx.remaining_accounts.to_vec().to_account_metas(None));
+    cpi_account_metas.append(&mut remaining_accounts.to_vec().to_account_metas(None));
 
     let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
-    cpi_account_infos.append(&mut ctx.remaining_accounts.to_vec());
+    cpi_account_infos.append(&mut remaining_accounts.to_vec());
 
     let ix = solana_program::instruction::Instruction {
         program_id: cpi_program.key(),
@@ -233,6 +283,30 @@ pub fn auctioneer_execute_sale<'info>(
 
     invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds])?;
 
+    record_blind_box_sale(blind_box, &ctx.accounts.buyer.key())?;
+
+    write_auction_summary(
+        auction_summary,
+        &ctx.accounts.listing_config,
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.metadata,
+        buyer_price,
+    )?;
+
+    let listing_config = &ctx.accounts.listing_config;
+    if !listing_config.lot_metadata.is_empty() {
+        emit!(LotSold {
+            listing_config: listing_config.key(),
+            token_mint: ctx.accounts.token_mint.key(),
+            buyer: ctx.accounts.buyer.key(),
+            price: buyer_price,
+            lot_metadata: listing_config.lot_metadata.clone(),
+        });
+    }
+
+    close_auto_accept(auto_accept, &ctx.accounts.seller)?;
+    close_backup_bids(backup_bids, &ctx.accounts.seller)?;
+
     // Close the Listing Config account.
     let listing_config = &ctx.accounts.listing_config.to_account_info();
     let seller = &ctx.accounts.seller.to_account_info();
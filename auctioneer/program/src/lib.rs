@@ -1,17 +1,45 @@
 #![allow(clippy::result_large_err)]
+pub mod adopt_listing;
+pub mod auction_summary;
 pub mod authorize;
+pub mod auto_accept;
+pub mod backup_bids;
 pub mod bid;
+pub mod bid_limit;
+pub mod bid_pool;
+pub mod bid_qualification;
+pub mod bid_rate_limit;
+pub mod bidder_allowlist;
+pub mod blind_box;
+pub mod buy_now;
 pub mod cancel;
 pub mod constants;
 pub mod deposit;
+pub mod drop_schedule;
+pub mod duration_bounds;
+pub mod end_time_index;
 pub mod errors;
 pub mod execute_sale;
+pub mod listing_fee;
+pub mod listing_pause;
+pub mod migrate_listing_config;
 pub mod pda;
+pub mod private_room;
+pub mod pseudonymous_bid;
 pub mod sell;
+pub mod sell_many;
 pub mod utils;
 pub mod withdraw;
 
-use crate::{authorize::*, bid::*, cancel::*, deposit::*, execute_sale::*, sell::*, withdraw::*};
+use crate::sell::config::ListingClock;
+use crate::{
+    adopt_listing::*, auction_summary::*, authorize::*, auto_accept::*, backup_bids::*, bid::*,
+    bid_limit::*, bid_pool::*, bid_qualification::*, bid_rate_limit::*, bidder_allowlist::*,
+    blind_box::*, buy_now::*, cancel::*, deposit::*, drop_schedule::*, duration_bounds::*,
+    end_time_index::*, execute_sale::*, listing_fee::*, listing_pause::*,
+    migrate_listing_config::*, private_room::*, pseudonymous_bid::*, sell::*, sell_many::*,
+    withdraw::*,
+};
 
 use anchor_lang::prelude::*;
 
@@ -83,6 +111,8 @@ pub mod auctioneer {
     }
 
     /// Create a sell bid by creating a `seller_trade_state` account and approving the program as the token delegate.
+    /// With a `clock` of `ListingClock::Slot` the auction's times are slots rather than Unix timestamps.
+    /// Up to 256 bytes of `lot_metadata`, such as lot notes or a provenance URI hash, can be attached for catalogued sales.
     pub fn sell<'info>(
         ctx: Context<'_, '_, '_, 'info, AuctioneerSell<'info>>,
         trade_state_bump: u8,
@@ -97,6 +127,9 @@ pub mod auctioneer {
         time_ext_period: Option<u32>,
         time_ext_delta: Option<u32>,
         allow_high_bid_cancel: Option<bool>,
+        tick_size: Option<u64>,
+        clock: Option<ListingClock>,
+        lot_metadata: Option<Vec<u8>>,
     ) -> Result<()> {
         auctioneer_sell(
             ctx,
@@ -112,6 +145,9 @@ pub mod auctioneer {
             time_ext_period,
             time_ext_delta,
             allow_high_bid_cancel,
+            tick_size,
+            clock,
+            lot_metadata,
         )
     }
 
@@ -133,4 +169,427 @@ pub mod auctioneer {
             token_size,
         )
     }
+
+    /// Make an unbid listing private, sealing all further bids until `committee` reveals them.
+    /// Bids still sealed `reveal_window` after the auction ends are dropped.
+    pub fn create_private_room<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatePrivateRoom<'info>>,
+        token_size: u64,
+        committee: Pubkey,
+        reveal_window: i64,
+    ) -> Result<()> {
+        private_room::create_private_room(ctx, token_size, committee, reveal_window)
+    }
+
+    /// Place a sealed bid in a private room, committing to `keccak(bidder, amount, salt)`.
+    pub fn place_sealed_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceSealedBid<'info>>,
+        commitment: [u8; 32],
+        ciphertext: Vec<u8>,
+    ) -> Result<()> {
+        private_room::place_sealed_bid(ctx, commitment, ciphertext)
+    }
+
+    /// Reveal a sealed bid after the auction ends and before the reveal deadline, updating the
+    /// private room's winner.
+    pub fn reveal_sealed_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealSealedBid<'info>>,
+        amount: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        private_room::reveal_sealed_bid(ctx, amount, salt)
+    }
+
+    /// Create a drop schedule that lists one escrowed item every `interval` seconds.
+    pub fn create_drop_schedule<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateDropSchedule<'info>>,
+        start_time: UnixTimestamp,
+        interval: i64,
+        listing_duration: i64,
+        reserve_price: Option<u64>,
+        min_bid_increment: Option<u64>,
+        time_ext_period: Option<u32>,
+        time_ext_delta: Option<u32>,
+        allow_high_bid_cancel: Option<bool>,
+    ) -> Result<()> {
+        drop_schedule::create_drop_schedule(
+            ctx,
+            start_time,
+            interval,
+            listing_duration,
+            reserve_price,
+            min_bid_increment,
+            time_ext_period,
+            time_ext_delta,
+            allow_high_bid_cancel,
+        )
+    }
+
+    /// Escrow a token into the drop schedule's pool.
+    pub fn add_drop_item<'info>(ctx: Context<'_, '_, '_, 'info, AddDropItem<'info>>) -> Result<()> {
+        drop_schedule::add_drop_item(ctx)
+    }
+
+    /// Open the listing for the schedule's next item once its slot has arrived. Callable by anyone.
+    pub fn open_next_drop<'info>(
+        ctx: Context<'_, '_, '_, 'info, OpenNextDrop<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+    ) -> Result<()> {
+        drop_schedule::open_next_drop(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+        )
+    }
+
+    /// Withdraw `amount` lamports of sale proceeds from the drop vault.
+    pub fn withdraw_drop_proceeds<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawDropProceeds<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        drop_schedule::withdraw_drop_proceeds(ctx, amount)
+    }
+
+    /// Cap the number of active bids and the total amount each wallet may bid on an unbid listing.
+    pub fn create_bid_limit<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidLimit<'info>>,
+        token_size: u64,
+        max_bids_per_wallet: u32,
+        max_amount_per_wallet: u64,
+    ) -> Result<()> {
+        bid_limit::create_bid_limit(ctx, token_size, max_bids_per_wallet, max_amount_per_wallet)
+    }
+
+    /// Sell an unrevealed token as a blind box, revealed through `reveal_program` once it sells.
+    pub fn create_blind_box<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBlindBox<'info>>,
+        token_size: u64,
+        reveal_program: Pubkey,
+    ) -> Result<()> {
+        blind_box::create_blind_box(ctx, token_size, reveal_program)
+    }
+
+    /// Invoke a sold blind box's reveal program and close the blind box. Callable by anyone.
+    pub fn reveal_blind_box<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealBlindBox<'info>>,
+    ) -> Result<()> {
+        blind_box::reveal_blind_box(ctx)
+    }
+
+    /// Let anyone settle a listing early once its highest bid reaches `price`.
+    pub fn create_auto_accept<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAutoAccept<'info>>,
+        token_size: u64,
+        price: u64,
+    ) -> Result<()> {
+        auto_accept::create_auto_accept(ctx, token_size, price)
+    }
+
+    /// Open a crowd-buy pool on a listing, bidding the pooled funds of its contributors.
+    pub fn create_bid_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidPool<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        bid_pool::create_bid_pool(ctx, token_size)
+    }
+
+    /// Contribute `amount` lamports to a bid pool while its listing is live.
+    pub fn contribute_to_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, ContributeToPool<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        bid_pool::contribute_to_pool(ctx, amount)
+    }
+
+    /// Bid everything contributed to a pool so far. Callable by anyone.
+    pub fn place_pool_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlacePoolBid<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+    ) -> Result<()> {
+        bid_pool::place_pool_bid(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            auctioneer_authority_bump,
+        )
+    }
+
+    /// Return a losing pool's escrowed bid to its vault. Callable by anyone.
+    pub fn withdraw_pool_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawPoolEscrow<'info>>,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+    ) -> Result<()> {
+        bid_pool::withdraw_pool_escrow(ctx, escrow_payment_bump, auctioneer_authority_bump)
+    }
+
+    /// Claim shares of the token a pool won, in proportion to the contribution spent on it.
+    pub fn claim_pool_shares<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimPoolShares<'info>>,
+    ) -> Result<()> {
+        bid_pool::claim_pool_shares(ctx)
+    }
+
+    /// Take the token a pool won out of its vault by burning the shares the pool spent on it.
+    pub fn redeem_pool_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemPoolToken<'info>>,
+    ) -> Result<()> {
+        bid_pool::redeem_pool_token(ctx)
+    }
+
+    /// Refund a contribution to a pool that lost its listing.
+    pub fn refund_pool_contribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundPoolContribution<'info>>,
+    ) -> Result<()> {
+        bid_pool::refund_pool_contribution(ctx)
+    }
+
+    /// Bid from the vault of a pseudonymous bidder, known only by a commitment to its wallet.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_pseudonymous_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlacePseudonymousBid<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+        commitment: [u8; 32],
+        buyer_price: u64,
+        token_size: u64,
+    ) -> Result<()> {
+        pseudonymous_bid::place_pseudonymous_bid(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            auctioneer_authority_bump,
+            commitment,
+            buyer_price,
+            token_size,
+        )
+    }
+
+    /// Return an outbid pseudonymous bidder's escrow to its vault. Callable by anyone.
+    pub fn withdraw_pseudonymous_escrow<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawPseudonymousEscrow<'info>>,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+    ) -> Result<()> {
+        pseudonymous_bid::withdraw_pseudonymous_escrow(
+            ctx,
+            escrow_payment_bump,
+            auctioneer_authority_bump,
+        )
+    }
+
+    /// Reveal the wallet behind a pseudonymous bidder and claim what its vault holds.
+    pub fn claim_pseudonymous_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimPseudonymousBid<'info>>,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        pseudonymous_bid::claim_pseudonymous_bid(ctx, salt)
+    }
+
+    /// Charge `fee_basis_points` instead of the house fee when a listing settles.
+    pub fn set_listing_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetListingFee<'info>>,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+        fee_basis_points: u16,
+    ) -> Result<()> {
+        listing_fee::set_listing_fee(ctx, auctioneer_authority_bump, token_size, fee_basis_points)
+    }
+
+    /// Bound how long new auctions on the house may run. A bound of zero is unbounded.
+    pub fn set_duration_bounds<'info>(
+        ctx: Context<'_, '_, '_, 'info, SetDurationBounds<'info>>,
+        min_duration: i64,
+        max_duration: i64,
+    ) -> Result<()> {
+        duration_bounds::set_duration_bounds(ctx, min_duration, max_duration)
+    }
+
+    /// Stop a live auction from taking bids or settling. Requires the `PauseListing` scope.
+    pub fn pause_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, PauseListing<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        listing_pause::pause_listing(ctx, token_size)
+    }
+
+    /// Resume a paused auction, extending its end time by the time it was paused.
+    pub fn resume_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResumeListing<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        listing_pause::resume_listing(ctx, token_size)
+    }
+
+    /// Create up to `MAX_SELL_MANY_LISTINGS` listings at once from the accounts in `remaining_accounts`.
+    pub fn sell_many<'info>(
+        ctx: Context<'_, '_, '_, 'info, SellMany<'info>>,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        listings: Vec<SellManyListing>,
+    ) -> Result<()> {
+        sell_many::sell_many(
+            ctx,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            listings,
+        )
+    }
+
+    /// Take over a listing as the new owner of a token that changed hands outside the house.
+    pub fn adopt_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, AdoptListing<'info>>,
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        adopt_listing::adopt_listing(
+            ctx,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            auctioneer_authority_bump,
+            token_size,
+        )
+    }
+
+    /// Rewrite a listing config created with an older layout in the current one.
+    pub fn migrate_listing_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateListingConfig<'info>>,
+    ) -> Result<()> {
+        migrate_listing_config::migrate_listing_config(ctx)
+    }
+
+    /// Create the index of listings ending in the bucket starting at `bucket_start`.
+    pub fn create_end_time_index<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateEndTimeIndex<'info>>,
+        bucket_start: i64,
+    ) -> Result<()> {
+        end_time_index::create_end_time_index(ctx, bucket_start)
+    }
+
+    /// Drop settled and cancelled listings from an end time index.
+    pub fn prune_end_time_index<'info>(
+        ctx: Context<'_, '_, '_, 'info, PruneEndTimeIndex<'info>>,
+    ) -> Result<()> {
+        end_time_index::prune_end_time_index(ctx)
+    }
+
+    /// Sell an unbid listing at a fixed `price` to the first buyer instead of by auction.
+    pub fn create_buy_now<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBuyNow<'info>>,
+        token_size: u64,
+        price: u64,
+    ) -> Result<()> {
+        buy_now::create_buy_now(ctx, token_size, price)
+    }
+
+    /// Buy a fixed-price listing, ending its auction so the sale can be executed immediately.
+    pub fn buy_now<'info>(
+        ctx: Context<'_, '_, '_, 'info, AuctioneerBuyNow<'info>>,
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        auctioneer_buy_now(
+            ctx,
+            trade_state_bump,
+            escrow_payment_bump,
+            auctioneer_authority_bump,
+            token_size,
+        )
+    }
+
+    /// Require bidders on an unbid listing to hold a minimum balance in escrow before bidding.
+    pub fn create_bid_qualification<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidQualification<'info>>,
+        token_size: u64,
+        min_escrow_balance: u64,
+    ) -> Result<()> {
+        bid_qualification::create_bid_qualification(ctx, token_size, min_escrow_balance)
+    }
+
+    /// Require each wallet to wait a number of slots between bids on an unbid listing, and cap the
+    /// bids each wallet may place on it.
+    pub fn create_bid_rate_limit<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidRateLimit<'info>>,
+        token_size: u64,
+        min_slot_gap: u64,
+        max_bids_per_wallet: u32,
+    ) -> Result<()> {
+        bid_rate_limit::create_bid_rate_limit(ctx, token_size, min_slot_gap, max_bids_per_wallet)
+    }
+
+    /// Only accept bids on an unbid listing from the listed bidders or the wallets in a merkle
+    /// allowlist.
+    pub fn create_bidder_allowlist<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBidderAllowlist<'info>>,
+        token_size: u64,
+        bidders: Vec<Pubkey>,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        bidder_allowlist::create_bidder_allowlist(ctx, token_size, bidders, merkle_root)
+    }
+
+    /// Prove a wallet is in a listing's merkle allowlist so it may bid.
+    pub fn accept_bidder_invitation<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptBidderInvitation<'info>>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        bidder_allowlist::accept_bidder_invitation(ctx, proof)
+    }
+
+    /// Cancel a listing on Auction House and close its Listing Config together.
+    pub fn cancel_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelListing<'info>>,
+        auctioneer_authority_bump: u8,
+        token_size: u64,
+    ) -> Result<()> {
+        cancel::cancel_listing(ctx, auctioneer_authority_bump, token_size)
+    }
+
+    /// Close a Listing Config left behind by a listing canceled directly on Auction House.
+    pub fn close_orphaned_listing_config<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseOrphanedListingConfig<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        cancel::close_orphaned_listing_config(ctx, token_size)
+    }
+
+    /// Keep the next-highest bids on a listing to fall back to if its winner cannot settle.
+    pub fn create_backup_bids<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBackupBids<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        backup_bids::create_backup_bids(ctx, token_size)
+    }
+
+    /// Replace an ended auction's unsettleable highest bid with its best backup bid. Callable by
+    /// anyone.
+    pub fn promote_backup_bid<'info>(
+        ctx: Context<'_, '_, '_, 'info, PromoteBackupBid<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        backup_bids::promote_backup_bid(ctx, token_size)
+    }
+
+    /// Reserve the auction summary a listing's sale will be recorded in, as its permanent
+    /// provenance record.
+    pub fn create_auction_summary<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateAuctionSummary<'info>>,
+        token_size: u64,
+    ) -> Result<()> {
+        auction_summary::create_auction_summary(ctx, token_size)
+    }
 }
@@ -0,0 +1,122 @@
+//! Per-listing fee overrides, e.g. for promotional zero-fee auctions.
+use crate::{constants::*, sell::config::*};
+
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::AuctioneerSetListingFee as AHSetListingFee,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse,
+};
+
+/// Accounts for the [`set_listing_fee` handler](fn.set_listing_fee.html).
+#[derive(Accounts)]
+#[instruction(auctioneer_authority_bump: u8, token_size: u64)]
+pub struct SetListingFee<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Auction House instance authority account.
+    pub authority: Signer<'info>,
+
+    /// CHECK: Validated in listing_config seeds.
+    /// Seller wallet account.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=authority, has_one=auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump=auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(seeds=[PREFIX.as_bytes(), wallet.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), auction_house.treasury_mint.as_ref(), token_account.mint.as_ref(), &u64::MAX.to_le_bytes(), &token_size.to_le_bytes()], seeds::program=auction_house_program, bump)]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House listing fee PDA account.
+    #[account(mut)]
+    pub listing_fee: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Charge `fee_basis_points` instead of the house fee when the listing settles. Requires the
+/// `SetFee` scope, and the fee cannot exceed the house fee.
+pub fn set_listing_fee<'info>(
+    ctx: Context<'_, '_, '_, 'info, SetListingFee<'info>>,
+    auctioneer_authority_bump: u8,
+    _token_size: u64,
+    fee_basis_points: u16,
+) -> Result<()> {
+    ctx.accounts.listing_config.fee_basis_points = Some(fee_basis_points);
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHSetListingFee {
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+        listing_fee: ctx.accounts.listing_fee.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    mpl_auction_house::cpi::auctioneer_set_listing_fee(
+        cpi_ctx.with_signer(&[&auctioneer_seeds]),
+        fee_basis_points,
+    )
+}
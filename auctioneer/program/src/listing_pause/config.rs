@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::UnixTimestamp;
+
+#[constant]
+pub const LISTING_PAUSE_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1;
+
+/// A paused listing's schedule, restored with the paused time added to `end_time` on resume.
+/// Times are on the listing's clock.
+#[account]
+pub struct ListingPause {
+    pub listing_config: Pubkey,
+    pub paused_at: UnixTimestamp,
+    pub start_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+    pub bump: u8,
+}
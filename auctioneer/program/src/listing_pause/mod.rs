@@ -0,0 +1,207 @@
+//! Pausing live auctions for incident response, e.g. a suspected stolen asset. A paused listing
+//! takes no bids and cannot be settled, and resuming it pushes its end back by the time paused.
+pub mod config;
+
+use crate::{constants::*, errors::*, listing_pause::config::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, PREFIX},
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_valid_auctioneer_and_scope,
+    AuctionHouse, AuthorityScope,
+};
+
+/// Accounts for the [`pause_listing` handler](fn.pause_listing.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct PauseListing<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Validated in listing_config seeds.
+    /// Seller wallet account.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=authority)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The listing's schedule while it is paused.
+    #[account(
+        init,
+        payer=authority,
+        space=LISTING_PAUSE_SIZE,
+        seeds=[LISTING_PAUSE.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub listing_pause: Box<Account<'info, ListingPause>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer program PDA running this auction.
+    #[account(seeds=[AUCTIONEER.as_bytes(), auction_house.key().as_ref()], bump)]
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the [`resume_listing` handler](fn.resume_listing.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct ResumeListing<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Auction House instance authority account.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Validated in listing_config seeds.
+    /// Seller wallet account.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=authority)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The listing's schedule while it is paused.
+    #[account(
+        mut,
+        close=authority,
+        seeds=[LISTING_PAUSE.as_bytes(), listing_config.key().as_ref()],
+        bump=listing_pause.bump,
+        has_one=listing_config,
+    )]
+    pub listing_pause: Box<Account<'info, ListingPause>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer program PDA running this auction.
+    #[account(seeds=[AUCTIONEER.as_bytes(), auction_house.key().as_ref()], bump)]
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+}
+
+/// Stop a live auction from taking bids or settling until it is resumed. Requires the
+/// `PauseListing` scope.
+pub fn pause_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, PauseListing<'info>>,
+    _token_size: u64,
+) -> Result<()> {
+    assert_valid_auctioneer_and_scope(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        &ctx.accounts.ah_auctioneer_pda,
+        AuthorityScope::PauseListing,
+    )?;
+
+    let listing_config = &mut ctx.accounts.listing_config;
+    assert_auction_active(listing_config)?;
+
+    let listing_pause = &mut ctx.accounts.listing_pause;
+    listing_pause.listing_config = listing_config.key();
+    listing_pause.paused_at = listing_clock_now(listing_config)?;
+    listing_pause.start_time = listing_config.start_time;
+    listing_pause.end_time = listing_config.end_time;
+    listing_pause.bump = *ctx
+        .bumps
+        .get("listing_pause")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    listing_config.start_time = PAUSED_TIMESTAMP;
+    listing_config.end_time = PAUSED_TIMESTAMP;
+
+    Ok(())
+}
+
+/// Restore a paused auction's schedule, extending its end by the time it was paused. Requires
+/// the `PauseListing` scope.
+pub fn resume_listing<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResumeListing<'info>>,
+    _token_size: u64,
+) -> Result<()> {
+    assert_valid_auctioneer_and_scope(
+        &ctx.accounts.auction_house,
+        &ctx.accounts.auctioneer_authority.key(),
+        &ctx.accounts.ah_auctioneer_pda,
+        AuthorityScope::PauseListing,
+    )?;
+
+    let listing_pause = &ctx.accounts.listing_pause;
+    let listing_config = &mut ctx.accounts.listing_config;
+    let paused_for = listing_clock_now(listing_config)?.saturating_sub(listing_pause.paused_at);
+
+    listing_config.start_time = listing_pause.start_time;
+    listing_config.end_time = listing_pause.end_time.saturating_add(paused_for);
+
+    Ok(())
+}
@@ -0,0 +1,332 @@
+//! Migration of listing configs written with an older `ListingConfig` layout.
+use crate::{constants::*, errors::*, sell::config::*, utils::next_feature_account};
+
+use anchor_lang::{prelude::*, AnchorDeserialize, Discriminator};
+use solana_program::{clock::UnixTimestamp, program::invoke, system_instruction};
+use std::slice::Iter;
+
+/// The highest bid as written before bids recorded their slot and sequence.
+#[derive(AnchorDeserialize)]
+struct LegacyBid {
+    _version: ListingConfigVersion,
+    amount: u64,
+    buyer_trade_state: Pubkey,
+}
+
+/// A `ListingConfigVersion::V0` listing config. `ListingConfigVersion::V1` listing configs follow
+/// it with their tick size, and `ListingConfigVersion::V2` listing configs with their tick size
+/// and fee override.
+#[derive(AnchorDeserialize)]
+struct LegacyListingConfig {
+    version: ListingConfigVersion,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    highest_bid: LegacyBid,
+    bump: u8,
+    reserve_price: u64,
+    min_bid_increment: u64,
+    time_ext_period: u32,
+    time_ext_delta: u32,
+    allow_high_bid_cancel: bool,
+}
+
+/// A `ListingConfigVersion::V3` listing config, written before listings recorded their clock.
+#[derive(AnchorDeserialize)]
+struct V3ListingConfig {
+    _version: ListingConfigVersion,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    highest_bid: Bid,
+    bump: u8,
+    reserve_price: u64,
+    min_bid_increment: u64,
+    time_ext_period: u32,
+    time_ext_delta: u32,
+    allow_high_bid_cancel: bool,
+    tick_size: u64,
+    fee_basis_points: Option<u16>,
+}
+
+/// A `ListingConfigVersion::V4` listing config, written before listings carried lot metadata.
+#[derive(AnchorDeserialize)]
+struct V4ListingConfig {
+    _version: ListingConfigVersion,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    highest_bid: Bid,
+    bump: u8,
+    reserve_price: u64,
+    min_bid_increment: u64,
+    time_ext_period: u32,
+    time_ext_delta: u32,
+    allow_high_bid_cancel: bool,
+    tick_size: u64,
+    fee_basis_points: Option<u16>,
+    clock: ListingClock,
+}
+
+/// A `ListingConfigVersion::V5` listing config, written before listings recorded their feature
+/// accounts.
+#[derive(AnchorDeserialize)]
+struct V5ListingConfig {
+    _version: ListingConfigVersion,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    highest_bid: Bid,
+    bump: u8,
+    reserve_price: u64,
+    min_bid_increment: u64,
+    time_ext_period: u32,
+    time_ext_delta: u32,
+    allow_high_bid_cancel: bool,
+    tick_size: u64,
+    fee_basis_points: Option<u16>,
+    clock: ListingClock,
+    lot_metadata: Vec<u8>,
+}
+
+/// Read the next fields of a listing config, failing when the account data ends early.
+fn read_fields<T: AnchorDeserialize>(fields: &mut &[u8]) -> Result<T> {
+    T::deserialize(fields).map_err(|_| error!(AuctioneerError::ListingConfigLayoutMismatch))
+}
+
+/// Whether the listing has the feature whose account is next in `remaining_accounts`.
+fn next_recorded_feature<'a, 'info>(
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+    seeds: &[&[u8]],
+) -> Result<bool> {
+    let account = match next_feature_account(remaining_accounts, true, seeds)? {
+        Some((account, _)) => account,
+        None => return Ok(false),
+    };
+
+    Ok(*account.owner == crate::id() && !account.data_is_empty())
+}
+
+/// Accounts for the [`migrate_listing_config` handler](fn.migrate_listing_config.html).
+///
+/// The listing's feature accounts follow in `remaining_accounts`, whether or not they exist: its
+/// private room, bid limit, bid rate limit, bid qualification, bidder allowlist, backup bids,
+/// blind box, auto-accept and auction summary.
+#[derive(Accounts)]
+pub struct MigrateListingConfig<'info> {
+    /// Pays for the space added to the listing config.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Owner checked in constraint, discriminator and version checked in migrate_listing_config.
+    /// The listing config to migrate.
+    #[account(mut, owner = crate::id())]
+    pub listing_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite a listing config in the current layout, growing the account when it was created
+/// before the newest fields were added. Fields the listing's version predates get their
+/// defaults and its features are read from the feature accounts that exist; data that does not
+/// match the layout of its version is rejected.
+pub fn migrate_listing_config<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateListingConfig<'info>>,
+) -> Result<()> {
+    let listing_config_info = ctx.accounts.listing_config.to_account_info();
+
+    let mut listing_config = {
+        let data = listing_config_info.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != ListingConfig::discriminator() {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+
+        let mut fields: &[u8] = &data[8..];
+        let listing_config = match ListingConfigVersion::deserialize(&mut &data[8..])? {
+            ListingConfigVersion::V6 => return err!(AuctioneerError::ListingConfigUpToDate),
+            ListingConfigVersion::V5 => {
+                let v5 = read_fields::<V5ListingConfig>(&mut fields)?;
+
+                ListingConfig {
+                    version: ListingConfigVersion::V6,
+                    start_time: v5.start_time,
+                    end_time: v5.end_time,
+                    highest_bid: Bid {
+                        version: ListingConfigVersion::V6,
+                        ..v5.highest_bid
+                    },
+                    bump: v5.bump,
+                    reserve_price: v5.reserve_price,
+                    min_bid_increment: v5.min_bid_increment,
+                    time_ext_period: v5.time_ext_period,
+                    time_ext_delta: v5.time_ext_delta,
+                    allow_high_bid_cancel: v5.allow_high_bid_cancel,
+                    tick_size: v5.tick_size,
+                    fee_basis_points: v5.fee_basis_points,
+                    clock: v5.clock,
+                    features: ListingFeatures::default(),
+                    lot_metadata: v5.lot_metadata,
+                }
+            }
+            ListingConfigVersion::V4 => {
+                let v4 = read_fields::<V4ListingConfig>(&mut fields)?;
+
+                ListingConfig {
+                    version: ListingConfigVersion::V6,
+                    start_time: v4.start_time,
+                    end_time: v4.end_time,
+                    highest_bid: Bid {
+                        version: ListingConfigVersion::V6,
+                        ..v4.highest_bid
+                    },
+                    bump: v4.bump,
+                    reserve_price: v4.reserve_price,
+                    min_bid_increment: v4.min_bid_increment,
+                    time_ext_period: v4.time_ext_period,
+                    time_ext_delta: v4.time_ext_delta,
+                    allow_high_bid_cancel: v4.allow_high_bid_cancel,
+                    tick_size: v4.tick_size,
+                    fee_basis_points: v4.fee_basis_points,
+                    clock: v4.clock,
+                    features: ListingFeatures::default(),
+                    lot_metadata: vec![],
+                }
+            }
+            ListingConfigVersion::V3 => {
+                let v3 = read_fields::<V3ListingConfig>(&mut fields)?;
+
+                ListingConfig {
+                    version: ListingConfigVersion::V6,
+                    start_time: v3.start_time,
+                    end_time: v3.end_time,
+                    highest_bid: Bid {
+                        version: ListingConfigVersion::V6,
+                        ..v3.highest_bid
+                    },
+                    bump: v3.bump,
+                    reserve_price: v3.reserve_price,
+                    min_bid_increment: v3.min_bid_increment,
+                    time_ext_period: v3.time_ext_period,
+                    time_ext_delta: v3.time_ext_delta,
+                    allow_high_bid_cancel: v3.allow_high_bid_cancel,
+                    tick_size: v3.tick_size,
+                    fee_basis_points: v3.fee_basis_points,
+                    clock: ListingClock::UnixTimestamp,
+                    features: ListingFeatures::default(),
+                    lot_metadata: vec![],
+                }
+            }
+            ListingConfigVersion::V0 | ListingConfigVersion::V1 | ListingConfigVersion::V2 => {
+                let legacy = read_fields::<LegacyListingConfig>(&mut fields)?;
+
+                let tick_size = match legacy.version {
+                    ListingConfigVersion::V0 => 0,
+                    _ => read_fields::<u64>(&mut fields)?,
+                };
+                let fee_basis_points = match legacy.version {
+                    ListingConfigVersion::V2 => read_fields::<Option<u16>>(&mut fields)?,
+                    _ => None,
+                };
+
+                ListingConfig {
+                    version: ListingConfigVersion::V6,
+                    start_time: legacy.start_time,
+                    end_time: legacy.end_time,
+                    highest_bid: Bid {
+                        version: ListingConfigVersion::V6,
+                        amount: legacy.highest_bid.amount,
+                        buyer_trade_state: legacy.highest_bid.buyer_trade_state,
+                        slot: 0,
+                        sequence: u64::from(legacy.highest_bid.amount > 0),
+                    },
+                    bump: legacy.bump,
+                    reserve_price: legacy.reserve_price,
+                    min_bid_increment: legacy.min_bid_increment,
+                    time_ext_period: legacy.time_ext_period,
+                    time_ext_delta: legacy.time_ext_delta,
+                    allow_high_bid_cancel: legacy.allow_high_bid_cancel,
+                    tick_size,
+                    fee_basis_points,
+                    clock: ListingClock::UnixTimestamp,
+                    features: ListingFeatures::default(),
+                    lot_metadata: vec![],
+                }
+            }
+        };
+
+        // Whatever follows the fields of the version is unused space and left zeroed.
+        if fields.iter().any(|byte| *byte != 0) {
+            return err!(AuctioneerError::ListingConfigLayoutMismatch);
+        }
+
+        listing_config
+    };
+
+    let listing_config_key = listing_config_info.key();
+    let listing_config_key = listing_config_key.as_ref();
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    listing_config.features = ListingFeatures {
+        private_room: next_recorded_feature(
+            remaining_accounts,
+            &[PRIVATE_ROOM.as_bytes(), listing_config_key],
+        )?,
+        bid_limit: next_recorded_feature(
+            remaining_accounts,
+            &[BID_LIMIT.as_bytes(), listing_config_key],
+        )?,
+        bid_rate_limit: next_recorded_feature(
+            remaining_accounts,
+            &[BID_RATE_LIMIT.as_bytes(), listing_config_key],
+        )?,
+        bid_qualification: next_recorded_feature(
+            remaining_accounts,
+            &[BID_QUALIFICATION.as_bytes(), listing_config_key],
+        )?,
+        bidder_allowlist: next_recorded_feature(
+            remaining_accounts,
+            &[BIDDER_ALLOWLIST.as_bytes(), listing_config_key],
+        )?,
+        backup_bids: next_recorded_feature(
+            remaining_accounts,
+            &[BACKUP_BIDS.as_bytes(), listing_config_key],
+        )?,
+        blind_box: next_recorded_feature(
+            remaining_accounts,
+            &[BLIND_BOX.as_bytes(), listing_config_key],
+        )?,
+        auto_accept: next_recorded_feature(
+            remaining_accounts,
+            &[AUTO_ACCEPT.as_bytes(), listing_config_key],
+        )?,
+        auction_summary: next_recorded_feature(
+            remaining_accounts,
+            &[
+                AUCTION_SUMMARY.as_bytes(),
+                listing_config_key,
+                &listing_config.start_time.to_le_bytes(),
+            ],
+        )?,
+    };
+
+    if listing_config_info.data_len() < LISTING_CONFIG_SIZE {
+        let rent_shortfall = Rent::get()?
+            .minimum_balance(LISTING_CONFIG_SIZE)
+            .saturating_sub(listing_config_info.lamports());
+        if rent_shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    &ctx.accounts.payer.key(),
+                    &listing_config_info.key(),
+                    rent_shortfall,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    listing_config_info.clone(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
+        listing_config_info.realloc(LISTING_CONFIG_SIZE, false)?;
+    }
+
+    let mut data = listing_config_info.try_borrow_mut_data()?;
+    listing_config.try_serialize(&mut *data)?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::Pubkey;
-use mpl_auction_house::constants::AUCTIONEER;
+use mpl_auction_common::seeds;
 
 use crate::{constants::*, id};
 
@@ -11,20 +11,205 @@ pub fn find_listing_config_address(
     token_mint: &Pubkey,
     token_size: u64,
 ) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &seeds::listing_config(
+            wallet,
+            auction_house,
+            token_account,
+            treasury_mint,
+            token_mint,
+            &token_size.to_le_bytes(),
+        ),
+        &id(),
+    )
+}
+
+pub fn find_auctioneer_authority_seeds(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&seeds::auctioneer_authority(auction_house), &id())
+}
+
+pub fn find_private_room_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRIVATE_ROOM.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_sealed_bid_address(listing_config: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEALED_BID.as_bytes(),
+            listing_config.as_ref(),
+            bidder.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_drop_schedule_address(auction_house: &Pubkey, authority: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
-            LISTING_CONFIG.as_bytes(),
+            DROP_SCHEDULE.as_bytes(),
+            auction_house.as_ref(),
+            authority.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_drop_vault_address(drop_schedule: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DROP_VAULT.as_bytes(), drop_schedule.as_ref()], &id())
+}
+
+pub fn find_drop_item_address(drop_schedule: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            DROP_ITEM.as_bytes(),
+            drop_schedule.as_ref(),
+            &index.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_bid_limit_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_LIMIT.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_wallet_bid_counter_address(listing_config: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WALLET_BID_COUNTER.as_bytes(),
+            listing_config.as_ref(),
             wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_blind_box_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BLIND_BOX.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_auto_accept_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUTO_ACCEPT.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_listing_pause_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LISTING_PAUSE.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_bid_pool_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_POOL.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_bid_pool_vault_address(bid_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_POOL_VAULT.as_bytes(), bid_pool.as_ref()], &id())
+}
+
+pub fn find_bid_pool_share_mint_address(bid_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_POOL_SHARES.as_bytes(), bid_pool.as_ref()], &id())
+}
+
+pub fn find_pool_contribution_address(bid_pool: &Pubkey, contributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            POOL_CONTRIBUTION.as_bytes(),
+            bid_pool.as_ref(),
+            contributor.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_end_time_index_address(auction_house: &Pubkey, bucket_start: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            END_TIME_INDEX.as_bytes(),
             auction_house.as_ref(),
-            token_account.as_ref(),
-            treasury_mint.as_ref(),
-            token_mint.as_ref(),
-            &token_size.to_le_bytes(),
+            &bucket_start.to_le_bytes(),
         ],
         &id(),
     )
 }
 
-pub fn find_auctioneer_authority_seeds(auction_house: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[AUCTIONEER.as_bytes(), auction_house.as_ref()], &id())
+pub fn find_buy_now_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BUY_NOW.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_bid_qualification_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BID_QUALIFICATION.as_bytes(), listing_config.as_ref()],
+        &id(),
+    )
+}
+
+pub fn find_backup_bids_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BACKUP_BIDS.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_bid_rate_limit_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BID_RATE_LIMIT.as_bytes(), listing_config.as_ref()], &id())
+}
+
+pub fn find_wallet_bid_rate_address(listing_config: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            WALLET_BID_RATE.as_bytes(),
+            listing_config.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_auction_summary_address(listing_config: &Pubkey, start_time: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AUCTION_SUMMARY.as_bytes(),
+            listing_config.as_ref(),
+            &start_time.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_pseudonymous_bidder_address(
+    listing_config: &Pubkey,
+    commitment: &[u8; 32],
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PSEUDONYMOUS_BIDDER.as_bytes(),
+            listing_config.as_ref(),
+            commitment,
+        ],
+        &id(),
+    )
+}
+
+pub fn find_pseudonymous_vault_address(pseudonymous_bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PSEUDONYMOUS_VAULT.as_bytes(), pseudonymous_bidder.as_ref()],
+        &id(),
+    )
+}
+
+pub fn find_bidder_allowlist_address(listing_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BIDDER_ALLOWLIST.as_bytes(), listing_config.as_ref()],
+        &id(),
+    )
+}
+
+pub fn find_bidder_invitation_address(listing_config: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BIDDER_INVITATION.as_bytes(),
+            listing_config.as_ref(),
+            wallet.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_duration_bounds_address(auction_house: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DURATION_BOUNDS.as_bytes(), auction_house.as_ref()], &id())
 }
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+#[constant]
+pub const PRIVATE_ROOM_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 4 + 4 + 32 + 8 + 1;
+#[constant]
+pub const SEALED_BID_SIZE: usize = 8 + 32 + 32 + 32 + 4 + MAX_SEALED_BID_CIPHERTEXT_LEN + 1 + 8 + 1;
+
+/// Sealed-bid room attached to a listing. Bid amounts stay encrypted until the committee reveals
+/// them after the auction closes; bids still sealed `reveal_window` after the end are dropped.
+#[account]
+pub struct PrivateRoom {
+    pub listing_config: Pubkey,
+    pub auction_house: Pubkey,
+    pub committee: Pubkey,
+    /// Time after the auction's end, in the listing's clock units, the committee has to reveal.
+    pub reveal_window: i64,
+    pub bid_count: u32,
+    pub revealed_count: u32,
+    pub winning_bidder: Pubkey,
+    pub winning_amount: u64,
+    pub bump: u8,
+}
+
+/// An encrypted bid placed in a private room. `commitment` is the keccak hash of the bidder,
+/// the little-endian bid amount and a salt, and is checked when the bid is revealed.
+#[account]
+pub struct SealedBid {
+    pub listing_config: Pubkey,
+    pub bidder: Pubkey,
+    pub commitment: [u8; 32],
+    pub ciphertext: Vec<u8>,
+    pub revealed_amount: Option<u64>,
+    pub bump: u8,
+}
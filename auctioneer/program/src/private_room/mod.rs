@@ -0,0 +1,317 @@
+//! Private auction rooms with sealed, committee-revealed bids.
+pub mod config;
+
+use crate::{constants::*, errors::*, private_room::config::*, sell::config::*, utils::*};
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use mpl_auction_house::{
+    constants::PREFIX, program::AuctionHouse as AuctionHouseProgram, AuctionHouse,
+};
+use solana_program::keccak;
+
+/// Accounts for the [`create_private_room` handler](fn.create_private_room.html).
+#[derive(Accounts)]
+#[instruction(token_size: u64)]
+pub struct CreatePrivateRoom<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token for sale.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The private room holding the sealed bids for the listing.
+    #[account(
+        init,
+        payer=wallet,
+        space=PRIVATE_ROOM_SIZE,
+        seeds=[PRIVATE_ROOM.as_bytes(), listing_config.key().as_ref()],
+        bump,
+    )]
+    pub private_room: Box<Account<'info, PrivateRoom>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Turn a listing without bids into a private room where bids are sealed until `committee`
+/// reveals them, within `reveal_window` of the auction closing.
+pub fn create_private_room<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatePrivateRoom<'info>>,
+    _token_size: u64,
+    committee: Pubkey,
+    reveal_window: i64,
+) -> Result<()> {
+    assert_listing_features_recorded(&ctx.accounts.listing_config)?;
+
+    if reveal_window <= 0 {
+        return err!(AuctioneerError::InvalidRevealWindow);
+    }
+
+    if ctx.accounts.listing_config.highest_bid.amount > 0 {
+        return err!(AuctioneerError::ListingHasBids);
+    }
+
+    ctx.accounts.listing_config.features.private_room = true;
+
+    let private_room = &mut ctx.accounts.private_room;
+    private_room.listing_config = ctx.accounts.listing_config.key();
+    private_room.auction_house = ctx.accounts.auction_house.key();
+    private_room.committee = committee;
+    private_room.reveal_window = reveal_window;
+    private_room.bid_count = 0;
+    private_room.revealed_count = 0;
+    private_room.winning_bidder = Pubkey::default();
+    private_room.winning_amount = 0;
+    private_room.bump = *ctx
+        .bumps
+        .get("private_room")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`place_sealed_bid` handler](fn.place_sealed_bid.html).
+#[derive(Accounts)]
+pub struct PlaceSealedBid<'info> {
+    /// Bidder wallet account.
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// The Listing Config used for listing settings
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The private room holding the sealed bids for the listing.
+    #[account(
+        mut,
+        seeds=[PRIVATE_ROOM.as_bytes(), listing_config.key().as_ref()],
+        bump=private_room.bump,
+        has_one=listing_config,
+        has_one=auction_house,
+    )]
+    pub private_room: Box<Account<'info, PrivateRoom>>,
+
+    /// Auction House instance the listing belongs to.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Bidder escrow payment account PDA backing the sealed bid.
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), bidder.key().as_ref()],
+        seeds::program=mpl_auction_house::id(),
+        bump,
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// The bidder's sealed bid.
+    #[account(
+        init,
+        payer=bidder,
+        space=SEALED_BID_SIZE,
+        seeds=[SEALED_BID.as_bytes(), listing_config.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub sealed_bid: Box<Account<'info, SealedBid>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Place an encrypted bid in a private room while the auction is active. The bidder's escrow
+/// must already cover the reserve price, and must cover the bid itself when it is revealed.
+pub fn place_sealed_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceSealedBid<'info>>,
+    commitment: [u8; 32],
+    ciphertext: Vec<u8>,
+) -> Result<()> {
+    assert_auction_active(&ctx.accounts.listing_config)?;
+
+    if ciphertext.len() > MAX_SEALED_BID_CIPHERTEXT_LEN {
+        return err!(AuctioneerError::SealedBidTooLarge);
+    }
+
+    if escrow_balance(
+        &ctx.accounts.escrow_payment_account,
+        &ctx.accounts.auction_house.treasury_mint,
+    )? < ctx.accounts.listing_config.reserve_price
+    {
+        return err!(AuctioneerError::InsufficientEscrowBalance);
+    }
+
+    let sealed_bid = &mut ctx.accounts.sealed_bid;
+    sealed_bid.listing_config = ctx.accounts.listing_config.key();
+    sealed_bid.bidder = ctx.accounts.bidder.key();
+    sealed_bid.commitment = commitment;
+    sealed_bid.ciphertext = ciphertext;
+    sealed_bid.revealed_amount = None;
+    sealed_bid.bump = *ctx
+        .bumps
+        .get("sealed_bid")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    ctx.accounts.private_room.bid_count = ctx
+        .accounts
+        .private_room
+        .bid_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`reveal_sealed_bid` handler](fn.reveal_sealed_bid.html).
+#[derive(Accounts)]
+pub struct RevealSealedBid<'info> {
+    /// The committee key designated to reveal the bids.
+    pub committee: Signer<'info>,
+
+    /// The Listing Config used for listing settings
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// The private room holding the sealed bids for the listing.
+    #[account(
+        mut,
+        seeds=[PRIVATE_ROOM.as_bytes(), listing_config.key().as_ref()],
+        bump=private_room.bump,
+        has_one=listing_config,
+        has_one=committee,
+        has_one=auction_house,
+    )]
+    pub private_room: Box<Account<'info, PrivateRoom>>,
+
+    /// Auction House instance the listing belongs to.
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The sealed bid being revealed.
+    #[account(
+        mut,
+        seeds=[SEALED_BID.as_bytes(), listing_config.key().as_ref(), sealed_bid.bidder.as_ref()],
+        bump=sealed_bid.bump,
+        has_one=listing_config,
+    )]
+    pub sealed_bid: Box<Account<'info, SealedBid>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Bidder escrow payment account PDA backing the sealed bid.
+    #[account(
+        seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), sealed_bid.bidder.as_ref()],
+        seeds::program=mpl_auction_house::id(),
+        bump,
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+}
+
+/// Reveal a sealed bid once the auction is over and before the room's reveal deadline, tracking
+/// the highest revealed bid at or above the reserve price as the room's winner. A bid its
+/// bidder's escrow no longer covers cannot be revealed.
+pub fn reveal_sealed_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealSealedBid<'info>>,
+    amount: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    assert_auction_over(&ctx.accounts.listing_config)?;
+
+    if listing_clock_now(&ctx.accounts.listing_config)?
+        >= reveal_deadline(&ctx.accounts.private_room, &ctx.accounts.listing_config)?
+    {
+        return err!(AuctioneerError::RevealWindowClosed);
+    }
+
+    let sealed_bid = &mut ctx.accounts.sealed_bid;
+    if sealed_bid.revealed_amount.is_some() {
+        return err!(AuctioneerError::BidAlreadyRevealed);
+    }
+
+    let commitment = keccak::hashv(&[sealed_bid.bidder.as_ref(), &amount.to_le_bytes(), &salt]);
+    if commitment.to_bytes() != sealed_bid.commitment {
+        return err!(AuctioneerError::InvalidBidReveal);
+    }
+
+    if escrow_balance(
+        &ctx.accounts.escrow_payment_account,
+        &ctx.accounts.auction_house.treasury_mint,
+    )? < amount
+    {
+        return err!(AuctioneerError::InsufficientEscrowBalance);
+    }
+
+    sealed_bid.revealed_amount = Some(amount);
+
+    let private_room = &mut ctx.accounts.private_room;
+    private_room.revealed_count = private_room
+        .revealed_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    if amount >= ctx.accounts.listing_config.reserve_price && amount > private_room.winning_amount {
+        private_room.winning_bidder = sealed_bid.bidder;
+        private_room.winning_amount = amount;
+    }
+
+    Ok(())
+}
+
+/// The time, in the listing's clock units, after which the room's unrevealed bids are dropped.
+fn reveal_deadline(private_room: &PrivateRoom, listing_config: &ListingConfig) -> Result<i64> {
+    listing_config
+        .end_time
+        .checked_add(private_room.reveal_window)
+        .ok_or_else(|| ProgramError::ArithmeticOverflow.into())
+}
+
+/// Check a bid against the listing's private room, if one exists. Returns `false` for public
+/// listings; for private listings only the revealed winner may bid, at the revealed price, once
+/// every bid is revealed or the reveal deadline has dropped the rest.
+pub fn assert_private_room_bid(
+    private_room: Option<&AccountInfo>,
+    listing_config: &Account<ListingConfig>,
+    wallet: &Pubkey,
+    buyer_price: u64,
+) -> Result<bool> {
+    let private_room = match private_room {
+        Some(private_room) => private_room,
+        None => return Ok(false),
+    };
+
+    let room = PrivateRoom::try_deserialize(&mut &private_room.try_borrow_data()?[..])?;
+    let now = listing_clock_now(listing_config)?;
+    if now < listing_config.end_time {
+        return err!(AuctioneerError::AuctionIsPrivate);
+    }
+
+    if room.revealed_count < room.bid_count && now < reveal_deadline(&room, listing_config)? {
+        return err!(AuctioneerError::PrivateRoomNotRevealed);
+    }
+
+    if room.winning_amount == 0
+        || room.winning_bidder != *wallet
+        || room.winning_amount != buyer_price
+    {
+        return err!(AuctioneerError::NotPrivateRoomWinner);
+    }
+
+    Ok(true)
+}
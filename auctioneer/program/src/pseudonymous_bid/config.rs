@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const PSEUDONYMOUS_BIDDER_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 32 + 32 + 1 + 1;
+
+/// A bidder on a listing known only by a commitment to its wallet, bidding from its vault until
+/// the wallet reveals itself to claim what the vault holds.
+#[account]
+pub struct PseudonymousBidder {
+    pub listing_config: Pubkey,
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_size: u64,
+    /// `hashv(&[wallet, salt])` of the wallet the bidder claims for.
+    pub commitment: [u8; 32],
+    pub bid_trade_state: Pubkey,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
@@ -0,0 +1,584 @@
+//! Pseudonymous bids, placed from a vault PDA keyed by a commitment to the bidder's wallet so the
+//! wallet is not revealed while the auction runs. Any account can fund and send the bids; once the
+//! listing is decided the wallet reveals itself with its salt to claim the token or its refund.
+pub mod config;
+
+use crate::{
+    bid::{assert_bidder_can_bid, record_listing_bid, BidFeatureAccounts},
+    constants::*,
+    errors::*,
+    pseudonymous_bid::config::*,
+    sell::config::*,
+};
+
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{self, spl_token, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, TRADE_STATE_SIZE},
+    cpi::accounts::{AuctioneerBuy as AHBuy, AuctioneerWithdraw as AHWithdraw},
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::{assert_is_ata, create_or_allocate_account_raw, make_ata},
+    AuctionHouse,
+};
+
+use solana_program::{
+    hash::hashv,
+    program::{invoke, invoke_signed},
+    system_instruction,
+};
+
+/// The commitment a pseudonymous bidder is keyed by, binding it to `wallet` without revealing it.
+pub fn pseudonym_commitment(wallet: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[wallet.as_ref(), salt]).to_bytes()
+}
+
+/// Accounts for the [`place_pseudonymous_bid` handler](fn.place_pseudonymous_bid.html).
+#[derive(Accounts)]
+#[instruction(
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+    commitment: [u8; 32],
+    buyer_price: u64,
+    token_size: u64
+)]
+pub struct PlacePseudonymousBid<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Account funding the bid, which need not be the wallet the bidder claims for.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The Listing Config used for listing settings
+    #[account(
+        mut,
+        seeds=[
+            LISTING_CONFIG.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &token_size.to_le_bytes()
+        ],
+        bump=listing_config.bump,
+    )]
+    pub listing_config: Box<Account<'info, ListingConfig>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The pseudonymous bidder, created on its first bid.
+    #[account(mut, seeds=[PSEUDONYMOUS_BIDDER.as_bytes(), listing_config.key().as_ref(), &commitment], bump)]
+    pub pseudonymous_bidder: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA bidding as the pseudonymous bidder's wallet.
+    #[account(mut, seeds=[PSEUDONYMOUS_VAULT.as_bytes(), pseudonymous_bidder.key().as_ref()], bump)]
+    pub pseudonymous_vault: UncheckedAccount<'info>,
+
+    /// The seller of the NFT
+    /// CHECK: Checked via trade state constraints
+    pub seller: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Verified through CPI
+    /// SPL token account metadata.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            pseudonymous_vault.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds = [PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump = auction_house.bump, has_one = authority, has_one = treasury_mint, has_one = auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds = [PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump = auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault trade state PDA.
+    #[account(mut, seeds = [PREFIX.as_bytes(), pseudonymous_vault.key().as_ref(), auction_house.key().as_ref(), token_account.key().as_ref(), treasury_mint.key().as_ref(), token_account.mint.as_ref(), buyer_price.to_le_bytes().as_ref(), token_size.to_le_bytes().as_ref()], seeds::program=auction_house_program, bump = trade_state_bump)]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Is used as a seed for ah_auctioneer_pda.
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Bid `buyer_price` from the vault of the pseudonymous bidder committed to by `commitment`,
+/// funded by the payer.
+#[allow(clippy::too_many_arguments)]
+pub fn place_pseudonymous_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlacePseudonymousBid<'info>>,
+    trade_state_bump: u8,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+    commitment: [u8; 32],
+    buyer_price: u64,
+    token_size: u64,
+) -> Result<()> {
+    if ctx.accounts.auction_house.treasury_mint != spl_token::native_mint::id() {
+        return err!(AuctioneerError::PseudonymousBidRequiresNativeTreasury);
+    }
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let features = BidFeatureAccounts::next(
+        &ctx.accounts.listing_config,
+        &ctx.accounts.pseudonymous_vault.key(),
+        remaining_accounts,
+    )?;
+
+    // Private rooms admit wallets, which a pseudonymous bidder does not reveal.
+    if features.private_room.is_some() {
+        return err!(AuctioneerError::AuctionIsPrivate);
+    }
+
+    // Limits are kept per vault and the payer funds its counters. The vault has no invitation, so
+    // it may only bid on an invite-only listing that lists it.
+    assert_bidder_can_bid(
+        &features,
+        &ctx.accounts.listing_config.key(),
+        &ctx.accounts.pseudonymous_vault.key(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.escrow_payment_account,
+        &ctx.accounts.treasury_mint.key(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        buyer_price,
+    )?;
+    record_listing_bid(
+        &features,
+        &mut ctx.accounts.listing_config,
+        &ctx.accounts.pseudonymous_vault.key(),
+        ctx.accounts.buyer_trade_state.key(),
+        buyer_price,
+    )?;
+
+    let listing_config_key = ctx.accounts.listing_config.key();
+    let pseudonymous_bidder = &ctx.accounts.pseudonymous_bidder;
+    let vault_bump = *ctx
+        .bumps
+        .get("pseudonymous_vault")
+        .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+    let mut bidder = if pseudonymous_bidder.data_is_empty() {
+        let pseudonymous_bidder_bump = *ctx
+            .bumps
+            .get("pseudonymous_bidder")
+            .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            &pseudonymous_bidder.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            PSEUDONYMOUS_BIDDER_SIZE,
+            &[],
+            &[
+                PSEUDONYMOUS_BIDDER.as_bytes(),
+                listing_config_key.as_ref(),
+                &commitment,
+                &[pseudonymous_bidder_bump],
+            ],
+        )?;
+
+        PseudonymousBidder {
+            listing_config: listing_config_key,
+            auction_house: ctx.accounts.auction_house.key(),
+            token_mint: ctx.accounts.token_account.mint,
+            token_size,
+            commitment,
+            bid_trade_state: Pubkey::default(),
+            bump: pseudonymous_bidder_bump,
+            vault_bump,
+        }
+    } else {
+        PseudonymousBidder::try_deserialize(&mut &pseudonymous_bidder.try_borrow_data()?[..])?
+    };
+
+    // The payer tops the vault up to the bid, the rent the vault is charged as the bidding wallet,
+    // and the vault's own rent, so the vault never spends anything it does not give back.
+    let rent = &ctx.accounts.rent;
+    let escrow_shortfall = buyer_price
+        .checked_add(rent.minimum_balance(0))
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .saturating_sub(ctx.accounts.escrow_payment_account.lamports());
+    let funding = escrow_shortfall
+        .checked_add(rent.minimum_balance(TRADE_STATE_SIZE))
+        .and_then(|funding| {
+            funding.checked_add(
+                rent.minimum_balance(0)
+                    .saturating_sub(ctx.accounts.pseudonymous_vault.lamports()),
+            )
+        })
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    invoke(
+        &system_instruction::transfer(
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.pseudonymous_vault.key(),
+            funding,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.pseudonymous_vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHBuy {
+        wallet: ctx.accounts.pseudonymous_vault.to_account_info(),
+        payment_account: ctx.accounts.pseudonymous_vault.to_account_info(),
+        transfer_authority: ctx.accounts.pseudonymous_vault.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        metadata: ctx.accounts.metadata.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    let pseudonymous_bidder_key = pseudonymous_bidder.key();
+    let vault_seeds = [
+        PSEUDONYMOUS_VAULT.as_bytes(),
+        pseudonymous_bidder_key.as_ref(),
+        &[vault_bump],
+    ];
+
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts)
+        .with_remaining_accounts(remaining_accounts.as_slice().to_vec());
+    mpl_auction_house::cpi::auctioneer_buy(
+        cpi_ctx.with_signer(&[&auctioneer_seeds, &vault_seeds]),
+        trade_state_bump,
+        escrow_payment_bump,
+        buyer_price,
+        token_size,
+    )?;
+
+    bidder.bid_trade_state = ctx.accounts.buyer_trade_state.key();
+    bidder.try_serialize(&mut *pseudonymous_bidder.try_borrow_mut_data()?)?;
+
+    Ok(())
+}
+
+/// Accounts for the [`withdraw_pseudonymous_escrow` handler](fn.withdraw_pseudonymous_escrow.html).
+#[derive(Accounts)]
+#[instruction(escrow_payment_bump: u8, auctioneer_authority_bump: u8)]
+pub struct WithdrawPseudonymousEscrow<'info> {
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// CHECK: Checked against the pseudonymous bidder.
+    /// The Listing Config of the listing bid on, closed once the listing settles.
+    pub listing_config: UncheckedAccount<'info>,
+
+    /// The pseudonymous bidder PDA account.
+    #[account(
+        seeds=[PSEUDONYMOUS_BIDDER.as_bytes(), listing_config.key().as_ref(), &pseudonymous_bidder.commitment],
+        bump=pseudonymous_bidder.bump,
+        has_one=listing_config,
+        has_one=auction_house,
+    )]
+    pub pseudonymous_bidder: Box<Account<'info, PseudonymousBidder>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA receiving the escrowed bid back.
+    #[account(mut, seeds=[PSEUDONYMOUS_VAULT.as_bytes(), pseudonymous_bidder.key().as_ref()], bump=pseudonymous_bidder.vault_bump)]
+    pub pseudonymous_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault escrow payment account PDA.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            pseudonymous_vault.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds = [PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump = auction_house.bump, has_one = authority, has_one = treasury_mint, has_one = auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds = [PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump = auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Is used as a seed for ah_auctioneer_pda.
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+        ], seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Permissionless crank returning a pseudonymous bidder's escrow to its vault once its bid is no
+/// longer the highest bid on a live listing.
+pub fn withdraw_pseudonymous_escrow<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawPseudonymousEscrow<'info>>,
+    escrow_payment_bump: u8,
+    auctioneer_authority_bump: u8,
+) -> Result<()> {
+    let listing_config = &ctx.accounts.listing_config;
+    if !listing_config.data_is_empty() {
+        let listing = ListingConfig::try_deserialize(&mut &listing_config.try_borrow_data()?[..])?;
+        if listing.highest_bid.buyer_trade_state == ctx.accounts.pseudonymous_bidder.bid_trade_state
+        {
+            return err!(AuctioneerError::PseudonymousBidHighest);
+        }
+    }
+
+    let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+    let cpi_accounts = AHWithdraw {
+        wallet: ctx.accounts.pseudonymous_vault.to_account_info(),
+        receipt_account: ctx.accounts.pseudonymous_vault.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        ata_program: ctx.accounts.ata_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let withdraw_data = mpl_auction_house::instruction::AuctioneerWithdraw {
+        escrow_payment_bump,
+        amount: ctx.accounts.escrow_payment_account.lamports(),
+    };
+
+    let ix = solana_program::instruction::Instruction {
+        program_id: cpi_program.key(),
+        accounts: cpi_accounts
+            .to_account_metas(None)
+            .into_iter()
+            .zip(cpi_accounts.to_account_infos())
+            .map(|mut pair| {
+                pair.0.is_signer = pair.1.is_signer;
+                if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                    pair.0.is_signer = true;
+                }
+                pair.0
+            })
+            .collect(),
+        data: withdraw_data.data(),
+    };
+
+    let ah_key = ctx.accounts.auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    invoke_signed(&ix, &cpi_accounts.to_account_infos(), &[&auctioneer_seeds])?;
+
+    Ok(())
+}
+
+/// Accounts for the [`claim_pseudonymous_bid` handler](fn.claim_pseudonymous_bid.html).
+#[derive(Accounts)]
+pub struct ClaimPseudonymousBid<'info> {
+    /// Wallet the pseudonymous bidder is committed to, revealed by this claim.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The pseudonymous bidder PDA account.
+    #[account(
+        seeds=[
+            PSEUDONYMOUS_BIDDER.as_bytes(),
+            pseudonymous_bidder.listing_config.as_ref(),
+            &pseudonymous_bidder.commitment
+        ],
+        bump=pseudonymous_bidder.bump,
+        has_one=token_mint,
+    )]
+    pub pseudonymous_bidder: Box<Account<'info, PseudonymousBidder>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault PDA paying out what it holds.
+    #[account(mut, seeds=[PSEUDONYMOUS_VAULT.as_bytes(), pseudonymous_bidder.key().as_ref()], bump=pseudonymous_bidder.vault_bump)]
+    pub pseudonymous_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked in constraint.
+    /// The vault's token account for the listed token, holding it if the bidder won.
+    #[account(mut, address=get_associated_token_address(&pseudonymous_vault.key(), &pseudonymous_bidder.token_mint))]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    /// Token mint account of the listed token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Created if needed and validated in claim_pseudonymous_bid.
+    /// Wallet token account receiving the token if the bidder won.
+    #[account(mut)]
+    pub wallet_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Reveal the wallet a pseudonymous bidder is committed to, moving the token it won and everything
+/// left in its vault to the wallet. A losing bidder's escrow must first be returned to the vault
+/// with `withdraw_pseudonymous_escrow`.
+pub fn claim_pseudonymous_bid<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimPseudonymousBid<'info>>,
+    salt: [u8; 32],
+) -> Result<()> {
+    let bidder = &ctx.accounts.pseudonymous_bidder;
+    if pseudonym_commitment(&ctx.accounts.wallet.key(), &salt) != bidder.commitment {
+        return err!(AuctioneerError::PseudonymCommitmentMismatch);
+    }
+
+    let bidder_key = bidder.key();
+    let vault_seeds = [
+        PSEUDONYMOUS_VAULT.as_bytes(),
+        bidder_key.as_ref(),
+        &[bidder.vault_bump],
+    ];
+
+    let vault_token_account = &ctx.accounts.vault_token_account;
+    if !vault_token_account.data_is_empty() {
+        let won = TokenAccount::try_deserialize(&mut &vault_token_account.try_borrow_data()?[..])?;
+
+        let wallet_token_account = &ctx.accounts.wallet_token_account;
+        if wallet_token_account.data_is_empty() {
+            make_ata(
+                wallet_token_account.to_account_info(),
+                ctx.accounts.wallet.to_account_info(),
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.wallet.to_account_info(),
+                ctx.accounts.ata_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.rent.to_account_info(),
+                &[],
+            )?;
+        }
+        assert_is_ata(
+            &wallet_token_account.to_account_info(),
+            &ctx.accounts.wallet.key(),
+            &bidder.token_mint,
+        )?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: vault_token_account.to_account_info(),
+                    to: wallet_token_account.to_account_info(),
+                    authority: ctx.accounts.pseudonymous_vault.to_account_info(),
+                },
+                &[&vault_seeds],
+            ),
+            won.amount,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault_token_account.to_account_info(),
+                destination: ctx.accounts.wallet.to_account_info(),
+                authority: ctx.accounts.pseudonymous_vault.to_account_info(),
+            },
+            &[&vault_seeds],
+        ))?;
+    }
+
+    let vault = &ctx.accounts.pseudonymous_vault;
+    invoke_signed(
+        &system_instruction::transfer(&vault.key(), &ctx.accounts.wallet.key(), vault.lamports()),
+        &[
+            vault.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&vault_seeds],
+    )?;
+
+    Ok(())
+}
@@ -1,12 +1,62 @@
 use anchor_lang::prelude::*;
 use solana_program::clock::UnixTimestamp;
 
-pub const BID_SIZE: usize = 8 + 1 + 32;
-pub const LISTING_CONFIG_SIZE: usize = 8 + 1 + 8 + 8 + BID_SIZE + 1 + 8 + 8 + 4 + 4 + 1;
+#[constant]
+pub const BID_SIZE: usize = 8 + 1 + 32 + 8 + 8;
+#[constant]
+pub const MAX_LOT_METADATA_LEN: usize = 256;
+#[constant]
+pub const LISTING_CONFIG_SIZE: usize =
+    8 + 1 + 8 + 8 + BID_SIZE + 1 + 8 + 8 + 4 + 4 + 1 + 8 + 3 + 1 + 9 + 4 + MAX_LOT_METADATA_LEN;
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub enum ListingConfigVersion {
+    /// Listings created before they had a tick size. They must be migrated before they can be
+    /// read.
     V0,
+    /// Listings created before they could override the auctioneer fee. They must be migrated
+    /// before they can be read.
+    V1,
+    /// Listings created before bids recorded the slot and sequence they were placed at. They must
+    /// be migrated before they can be read.
+    V2,
+    /// Listings created before auctions could be timed in slots. They must be migrated before
+    /// they can be read.
+    V3,
+    /// Listings created before they could carry lot metadata. They must be migrated before they
+    /// can be read.
+    V4,
+    /// Listings created before they recorded their feature accounts. They must be migrated
+    /// before they can be read.
+    V5,
+    /// The current `ListingConfig` layout.
+    V6,
+}
+
+/// What a listing's start and end times, and its time extension period and delta, are measured
+/// in.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq)]
+pub enum ListingClock {
+    /// Seconds of the cluster's Unix timestamp.
+    UnixTimestamp,
+    /// Slots, for sellers coordinating with slot-based programs or avoiding clock drift.
+    Slot,
+}
+
+/// The per-listing feature accounts a listing was set up with. Bids, sales and cancels take the
+/// accounts of the features set here from the front of their `remaining_accounts`, in field
+/// order, so listings without a feature pay nothing for it.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListingFeatures {
+    pub private_room: bool,
+    pub bid_limit: bool,
+    pub bid_rate_limit: bool,
+    pub bid_qualification: bool,
+    pub bidder_allowlist: bool,
+    pub backup_bids: bool,
+    pub blind_box: bool,
+    pub auto_accept: bool,
+    pub auction_summary: bool,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
@@ -14,12 +64,19 @@ pub struct Bid {
     pub version: ListingConfigVersion,
     pub amount: u64,
     pub buyer_trade_state: Pubkey,
+    /// Slot the bid was placed at.
+    pub slot: u64,
+    /// Number of bids that have led the listing, including this one. An equal bid never takes
+    /// the lead, so the first bid at an amount wins ties.
+    pub sequence: u64,
 }
 
 #[account]
 pub struct ListingConfig {
     pub version: ListingConfigVersion,
+    /// Start of the auction, a slot rather than a timestamp when `clock` is `ListingClock::Slot`.
     pub start_time: UnixTimestamp,
+    /// End of the auction, a slot rather than a timestamp when `clock` is `ListingClock::Slot`.
     pub end_time: UnixTimestamp,
     pub highest_bid: Bid,
     pub bump: u8,
@@ -28,4 +85,11 @@ pub struct ListingConfig {
     pub time_ext_period: u32,
     pub time_ext_delta: u32,
     pub allow_high_bid_cancel: bool,
+    pub tick_size: u64,
+    pub fee_basis_points: Option<u16>,
+    pub clock: ListingClock,
+    pub features: ListingFeatures,
+    /// Opaque seller-supplied data for catalogued sales, such as lot notes or the hash of a
+    /// provenance URI, at most `MAX_LOT_METADATA_LEN` bytes.
+    pub lot_metadata: Vec<u8>,
 }
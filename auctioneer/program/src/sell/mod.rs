@@ -1,6 +1,9 @@
 pub mod config;
 
-use crate::{constants::*, errors::*, sell::config::*};
+use crate::{
+    constants::*, duration_bounds::assert_duration_in_bounds, end_time_index::*, errors::*,
+    sell::config::*, utils::*,
+};
 
 use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
 use anchor_spl::token::{Token, TokenAccount};
@@ -15,6 +18,26 @@ use mpl_auction_house::{
 
 use solana_program::{clock::UnixTimestamp, program::invoke_signed};
 
+/// Emitted when a listing is created with lot metadata, so catalogues can index the lot.
+#[event]
+pub struct LotListed {
+    pub listing_config: Pubkey,
+    pub token_mint: Pubkey,
+    pub seller: Pubkey,
+    pub lot_metadata: Vec<u8>,
+}
+
+/// Emitted when a listing with lot metadata settles. The sale closes the listing config, so this
+/// is where the lot metadata outlives it.
+#[event]
+pub struct LotSold {
+    pub listing_config: Pubkey,
+    pub token_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub lot_metadata: Vec<u8>,
+}
+
 /// Accounts for the [`sell_with_auctioneer` handler](auction_house/fn.sell_with_auctioneer.html).
 #[derive(Accounts, Clone)]
 #[instruction(trade_state_bump: u8, free_trade_state_bump: u8, program_as_signer_bump: u8, auctioneer_authority_bump: u8, token_size: u64)]
@@ -41,6 +64,11 @@ pub struct AuctioneerSell<'info> {
     )]
     pub listing_config: Account<'info, ListingConfig>,
 
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The house's auction duration bounds, uninitialized for houses without them.
+    #[account(seeds=[DURATION_BOUNDS.as_bytes(), auction_house.key().as_ref()], bump)]
+    pub duration_bounds: UncheckedAccount<'info>,
+
     // Accounts passed into Auction House CPI call
     /// CHECK: Verified through CPI
     /// User wallet account.
@@ -105,6 +133,11 @@ pub struct AuctioneerSell<'info> {
 }
 
 /// Create a sell bid by creating a `seller_trade_state` account and approving the program as the token delegate.
+///
+/// An end time index passed as the first remaining account records the listing in its bucket.
+/// Listings timed in slots, with a `clock` of `ListingClock::Slot`, cannot be indexed.
+///
+/// `lot_metadata` is kept on the listing config and emitted in [`LotListed`] and [`LotSold`].
 pub fn auctioneer_sell<'info>(
     ctx: Context<'_, '_, '_, 'info, AuctioneerSell<'info>>,
     trade_state_bump: u8,
@@ -119,9 +152,22 @@ pub fn auctioneer_sell<'info>(
     time_ext_period: Option<u32>,
     time_ext_delta: Option<u32>,
     allow_high_bid_cancel: Option<bool>,
+    tick_size: Option<u64>,
+    clock: Option<ListingClock>,
+    lot_metadata: Option<Vec<u8>>,
 ) -> Result<()> {
-    ctx.accounts.listing_config.version = ListingConfigVersion::V0;
-    ctx.accounts.listing_config.highest_bid.version = ListingConfigVersion::V0;
+    let clock = clock.unwrap_or(ListingClock::UnixTimestamp);
+    let lot_metadata = lot_metadata.unwrap_or_default();
+    assert_lot_metadata_len(&lot_metadata)?;
+    assert_duration_in_bounds(
+        &ctx.accounts.duration_bounds.to_account_info(),
+        start_time,
+        end_time,
+        clock,
+    )?;
+
+    ctx.accounts.listing_config.version = ListingConfigVersion::V6;
+    ctx.accounts.listing_config.highest_bid.version = ListingConfigVersion::V6;
     ctx.accounts.listing_config.start_time = start_time;
     ctx.accounts.listing_config.end_time = end_time;
     ctx.accounts.listing_config.reserve_price = reserve_price.unwrap_or(0);
@@ -129,11 +175,39 @@ pub fn auctioneer_sell<'info>(
     ctx.accounts.listing_config.time_ext_period = time_ext_period.unwrap_or(0);
     ctx.accounts.listing_config.time_ext_delta = time_ext_delta.unwrap_or(0);
     ctx.accounts.listing_config.allow_high_bid_cancel = allow_high_bid_cancel.unwrap_or(false);
+    ctx.accounts.listing_config.tick_size = tick_size.unwrap_or(0);
+    ctx.accounts.listing_config.fee_basis_points = None;
+    ctx.accounts.listing_config.clock = clock;
+    ctx.accounts.listing_config.features = ListingFeatures::default();
     ctx.accounts.listing_config.bump = *ctx
         .bumps
         .get("listing_config")
         .ok_or(AuctioneerError::BumpSeedNotInHashMap)?;
 
+    if !lot_metadata.is_empty() {
+        emit!(LotListed {
+            listing_config: ctx.accounts.listing_config.key(),
+            token_mint: ctx.accounts.token_account.mint,
+            seller: ctx.accounts.wallet.key(),
+            lot_metadata: lot_metadata.clone(),
+        });
+    }
+    ctx.accounts.listing_config.lot_metadata = lot_metadata;
+
+    let (end_time_index, remaining_accounts) = split_end_time_index(ctx.remaining_accounts)?;
+    if let Some(end_time_index) = end_time_index {
+        // End time index buckets are measured in seconds.
+        if clock == ListingClock::Slot {
+            return err!(AuctioneerError::SlotListingNotIndexable);
+        }
+        index_listing(
+            end_time_index,
+            &ctx.accounts.auction_house.key(),
+            &ctx.accounts.listing_config.key(),
+            end_time,
+        )?;
+    }
+
     let cpi_program = ctx.accounts.auction_house_program.to_account_info();
     let cpi_accounts = AHSell {
         wallet: ctx.accounts.wallet.to_account_info(),
@@ -159,20 +233,27 @@ pub fn auctioneer_sell<'info>(
         token_size,
     };
 
+    let mut cpi_account_metas: Vec<AccountMeta> = cpi_accounts
+        .to_account_metas(None)
+        .into_iter()
+        .zip(cpi_accounts.to_account_infos())
+        .map(|mut pair| {
+            pair.0.is_signer = pair.1.is_signer;
+            if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                pair.0.is_signer = true;
+            }
+            pair.0
+        })
+        .collect();
+
+    cpi_account_metas.append(&mut remaining_accounts.to_vec().to_account_metas(None));
+
+    let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
+    cpi_account_infos.append(&mut remaining_accounts.to_vec());
+
     let ix = solana_program::instruction::Instruction {
         program_id: cpi_program.key(),
-        accounts: cpi_accounts
-            .to_account_metas(None)
-            .into_iter()
-            .zip(cpi_accounts.to_account_infos())
-            .map(|mut pair| {
-                pair.0.is_signer = pair.1.is_signer;
-                if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
-                    pair.0.is_signer = true;
-                }
-                pair.0
-            })
-            .collect(),
+        accounts: cpi_account_metas,
         data: sell_data.data(),
     };
 
@@ -187,7 +268,7 @@ pub fn auctioneer_sell<'info>(
         &[auctioneer_authority_bump],
     ];
 
-    invoke_signed(&ix, &cpi_accounts.to_account_infos(), &[&auctioneer_seeds])?;
+    invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds])?;
 
     Ok(())
 }
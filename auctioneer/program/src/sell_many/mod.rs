@@ -0,0 +1,263 @@
+//! Bulk listing: create several auctioneer listings from one transaction.
+use crate::{
+    constants::*, duration_bounds::assert_duration_in_bounds, errors::*,
+    pda::find_listing_config_address, sell::config::*, sell::LotListed, utils::*,
+};
+
+use anchor_lang::{prelude::*, AnchorDeserialize, InstructionData};
+use anchor_spl::token::{Token, TokenAccount};
+
+use mpl_auction_house::{
+    self,
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
+    cpi::accounts::AuctioneerSell as AHSell,
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::create_or_allocate_account_raw,
+    AuctionHouse,
+};
+
+use solana_program::{clock::UnixTimestamp, program::invoke_signed};
+
+/// Number of remaining accounts passed for each listing: the listing config, token account,
+/// metadata, seller trade state, free seller trade state and the Auction House lien for the mint.
+pub const SELL_MANY_ACCOUNTS_PER_LISTING: usize = 6;
+
+/// Settings and trade state bumps for one listing created by [`sell_many`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SellManyListing {
+    pub trade_state_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub token_size: u64,
+    pub start_time: UnixTimestamp,
+    pub end_time: UnixTimestamp,
+    pub reserve_price: Option<u64>,
+    pub min_bid_increment: Option<u64>,
+    pub time_ext_period: Option<u32>,
+    pub time_ext_delta: Option<u32>,
+    pub allow_high_bid_cancel: Option<bool>,
+    pub tick_size: Option<u64>,
+    pub clock: Option<ListingClock>,
+    pub lot_metadata: Option<Vec<u8>>,
+}
+
+/// Accounts for the [`sell_many` handler](fn.sell_many.html).
+#[derive(Accounts)]
+#[instruction(program_as_signer_bump: u8)]
+pub struct SellMany<'info> {
+    /// Auction House Program used for CPI call
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// Seller wallet account, paying for the listing configs.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(seeds=[PREFIX.as_bytes(), auction_house.creator.as_ref(), auction_house.treasury_mint.as_ref()], seeds::program=auction_house_program, bump=auction_house.bump, has_one=auction_house_fee_account)]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(mut, seeds=[PREFIX.as_bytes(), auction_house.key().as_ref(), FEE_PAYER.as_bytes()], seeds::program=auction_house_program, bump=auction_house.fee_payer_bump)]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The house's auction duration bounds, uninitialized for houses without them.
+    #[account(seeds=[DURATION_BOUNDS.as_bytes(), auction_house.key().as_ref()], bump)]
+    pub duration_bounds: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer program PDA running this auction.
+    pub auctioneer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            auctioneer_authority.key().as_ref()
+            ],
+        seeds::program=auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Account<'info, mpl_auction_house::Auctioneer>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(seeds=[PREFIX.as_bytes(), SIGNER.as_bytes()], seeds::program=auction_house_program, bump=program_as_signer_bump)]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Create a listing config and seller trade state for each of up to `MAX_SELL_MANY_LISTINGS`
+/// `listings`. The accounts for each listing are passed in `remaining_accounts`, in the order
+/// described by `SELL_MANY_ACCOUNTS_PER_LISTING`. Listings that need extra sell accounts, such as
+/// programmable NFTs, must be created with `sell` instead.
+pub fn sell_many<'info>(
+    ctx: Context<'_, '_, '_, 'info, SellMany<'info>>,
+    program_as_signer_bump: u8,
+    auctioneer_authority_bump: u8,
+    listings: Vec<SellManyListing>,
+) -> Result<()> {
+    if listings.is_empty() || listings.len() > MAX_SELL_MANY_LISTINGS {
+        return err!(AuctioneerError::InvalidListingCount);
+    }
+
+    if ctx.remaining_accounts.len() != listings.len() * SELL_MANY_ACCOUNTS_PER_LISTING {
+        return err!(AuctioneerError::MissingListingAccounts);
+    }
+
+    let wallet = &ctx.accounts.wallet;
+    let auction_house = &ctx.accounts.auction_house;
+    let ah_key = auction_house.key();
+    let auctioneer_seeds = [
+        AUCTIONEER.as_bytes(),
+        ah_key.as_ref(),
+        &[auctioneer_authority_bump],
+    ];
+
+    for (listing, accounts) in listings.iter().zip(
+        ctx.remaining_accounts
+            .chunks(SELL_MANY_ACCOUNTS_PER_LISTING),
+    ) {
+        let listing_config_info = &accounts[0];
+        let token_account_info = &accounts[1];
+        let metadata = &accounts[2];
+        let seller_trade_state = &accounts[3];
+        let free_seller_trade_state = &accounts[4];
+        let lien = &accounts[5];
+
+        let token_account = Account::<TokenAccount>::try_from(token_account_info)?;
+        let lot_metadata = listing.lot_metadata.clone().unwrap_or_default();
+        assert_lot_metadata_len(&lot_metadata)?;
+        assert_duration_in_bounds(
+            &ctx.accounts.duration_bounds.to_account_info(),
+            listing.start_time,
+            listing.end_time,
+            listing.clock.unwrap_or(ListingClock::UnixTimestamp),
+        )?;
+        let (listing_config_key, listing_config_bump) = find_listing_config_address(
+            &wallet.key(),
+            &ah_key,
+            &token_account.key(),
+            &auction_house.treasury_mint,
+            &token_account.mint,
+            listing.token_size,
+        );
+        if listing_config_key != listing_config_info.key() {
+            return err!(AuctioneerError::MissingListingAccounts);
+        }
+
+        create_or_allocate_account_raw(
+            crate::id(),
+            listing_config_info,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &wallet.to_account_info(),
+            LISTING_CONFIG_SIZE,
+            &[],
+            &[
+                LISTING_CONFIG.as_bytes(),
+                wallet.key().as_ref(),
+                ah_key.as_ref(),
+                token_account.key().as_ref(),
+                auction_house.treasury_mint.as_ref(),
+                token_account.mint.as_ref(),
+                &listing.token_size.to_le_bytes(),
+                &[listing_config_bump],
+            ],
+        )?;
+
+        let listing_config = ListingConfig {
+            version: ListingConfigVersion::V6,
+            start_time: listing.start_time,
+            end_time: listing.end_time,
+            highest_bid: Bid {
+                version: ListingConfigVersion::V6,
+                amount: 0,
+                buyer_trade_state: Pubkey::default(),
+                slot: 0,
+                sequence: 0,
+            },
+            bump: listing_config_bump,
+            reserve_price: listing.reserve_price.unwrap_or(0),
+            min_bid_increment: listing.min_bid_increment.unwrap_or(0),
+            time_ext_period: listing.time_ext_period.unwrap_or(0),
+            time_ext_delta: listing.time_ext_delta.unwrap_or(0),
+            allow_high_bid_cancel: listing.allow_high_bid_cancel.unwrap_or(false),
+            tick_size: listing.tick_size.unwrap_or(0),
+            fee_basis_points: None,
+            clock: listing.clock.unwrap_or(ListingClock::UnixTimestamp),
+            features: ListingFeatures::default(),
+            lot_metadata,
+        };
+        listing_config.try_serialize(&mut *listing_config_info.try_borrow_mut_data()?)?;
+
+        if !listing_config.lot_metadata.is_empty() {
+            emit!(LotListed {
+                listing_config: listing_config_key,
+                token_mint: token_account.mint,
+                seller: wallet.key(),
+                lot_metadata: listing_config.lot_metadata.clone(),
+            });
+        }
+
+        let cpi_program = ctx.accounts.auction_house_program.to_account_info();
+        let cpi_accounts = AHSell {
+            wallet: wallet.to_account_info(),
+            token_account: token_account_info.clone(),
+            metadata: metadata.clone(),
+            auction_house: auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            seller_trade_state: seller_trade_state.clone(),
+            free_seller_trade_state: free_seller_trade_state.clone(),
+            authority: ctx.accounts.authority.to_account_info(),
+            auctioneer_authority: ctx.accounts.auctioneer_authority.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        };
+
+        let sell_data = mpl_auction_house::instruction::AuctioneerSell {
+            trade_state_bump: listing.trade_state_bump,
+            free_trade_state_bump: listing.free_trade_state_bump,
+            program_as_signer_bump,
+            token_size: listing.token_size,
+        };
+
+        let mut cpi_account_metas: Vec<AccountMeta> = cpi_accounts
+            .to_account_metas(None)
+            .into_iter()
+            .zip(cpi_accounts.to_account_infos())
+            .map(|mut pair| {
+                pair.0.is_signer = pair.1.is_signer;
+                if pair.0.pubkey == ctx.accounts.auctioneer_authority.key() {
+                    pair.0.is_signer = true;
+                }
+                pair.0
+            })
+            .collect();
+        cpi_account_metas.push(AccountMeta::new_readonly(lien.key(), false));
+
+        let mut cpi_account_infos: Vec<AccountInfo> = cpi_accounts.to_account_infos();
+        cpi_account_infos.push(lien.clone());
+
+        let ix = solana_program::instruction::Instruction {
+            program_id: cpi_program.key(),
+            accounts: cpi_account_metas,
+            data: sell_data.data(),
+        };
+
+        invoke_signed(&ix, &cpi_account_infos, &[&auctioneer_seeds])?;
+    }
+
+    Ok(())
+}
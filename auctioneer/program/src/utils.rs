@@ -1,10 +1,34 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::{self, state::Account as SplAccount};
+use solana_program::program_pack::Pack;
+use std::slice::Iter;
 
-use crate::{errors::*, sell::config::*};
+use crate::{constants::*, errors::*, sell::config::*};
 
-pub fn assert_auction_active(listing_config: &Account<ListingConfig>) -> Result<()> {
+/// The current time on the listing's clock: the slot for listings timed in slots, otherwise the
+/// Unix timestamp.
+pub fn listing_clock_now(listing_config: &ListingConfig) -> Result<i64> {
     let clock = Clock::get()?;
-    let current_timestamp = clock.unix_timestamp;
+    match listing_config.clock {
+        ListingClock::UnixTimestamp => Ok(clock.unix_timestamp),
+        ListingClock::Slot => {
+            i64::try_from(clock.slot).map_err(|_| ProgramError::ArithmeticOverflow.into())
+        }
+    }
+}
+
+pub fn assert_not_paused(listing_config: &Account<ListingConfig>) -> Result<()> {
+    if listing_config.start_time == PAUSED_TIMESTAMP {
+        return err!(AuctioneerError::AuctionPaused);
+    }
+
+    Ok(())
+}
+
+pub fn assert_auction_active(listing_config: &Account<ListingConfig>) -> Result<()> {
+    assert_not_paused(listing_config)?;
+
+    let current_timestamp = listing_clock_now(listing_config)?;
 
     if current_timestamp < listing_config.start_time {
         return err!(AuctioneerError::AuctionNotStarted);
@@ -16,8 +40,9 @@ pub fn assert_auction_active(listing_config: &Account<ListingConfig>) -> Result<
 }
 
 pub fn assert_auction_over(listing_config: &Account<ListingConfig>) -> Result<()> {
-    let clock = Clock::get()?;
-    let current_timestamp = clock.unix_timestamp;
+    assert_not_paused(listing_config)?;
+
+    let current_timestamp = listing_clock_now(listing_config)?;
 
     if current_timestamp < listing_config.end_time {
         return err!(AuctioneerError::AuctionActive);
@@ -30,17 +55,38 @@ pub fn assert_higher_bid(
     listing_config: &Account<ListingConfig>,
     new_bid_price: u64,
 ) -> Result<()> {
-    if new_bid_price <= listing_config.highest_bid.amount {
+    if (listing_config.highest_bid.amount > 0)
+        && (new_bid_price == listing_config.highest_bid.amount)
+    {
+        return err!(AuctioneerError::BidTiesHighestBid);
+    } else if new_bid_price <= listing_config.highest_bid.amount {
         return err!(AuctioneerError::BidTooLow);
     } else if (listing_config.highest_bid.amount > 0)
         && (new_bid_price < (listing_config.highest_bid.amount + listing_config.min_bid_increment))
     {
         return err!(AuctioneerError::BelowBidIncrement);
+    } else if (listing_config.tick_size > 0) && (new_bid_price % listing_config.tick_size != 0) {
+        return err!(AuctioneerError::BidNotMultipleOfTickSize);
     }
 
     Ok(())
 }
 
+/// Make `buyer_trade_state` the highest bid, recording the slot and sequence it took the lead at.
+pub fn record_highest_bid(
+    listing_config: &mut Account<ListingConfig>,
+    buyer_price: u64,
+    buyer_trade_state: Pubkey,
+) -> Result<()> {
+    let highest_bid = &mut listing_config.highest_bid;
+    highest_bid.amount = buyer_price;
+    highest_bid.buyer_trade_state = buyer_trade_state;
+    highest_bid.slot = Clock::get()?.slot;
+    highest_bid.sequence = highest_bid.sequence.saturating_add(1);
+
+    Ok(())
+}
+
 pub fn assert_exceeds_reserve_price(
     listing_config: &Account<ListingConfig>,
     new_bid_price: u64,
@@ -63,9 +109,29 @@ pub fn assert_highest_bidder(
     Ok(())
 }
 
+/// Check seller-supplied lot metadata fits in the listing config.
+pub fn assert_lot_metadata_len(lot_metadata: &[u8]) -> Result<()> {
+    if lot_metadata.len() > MAX_LOT_METADATA_LEN {
+        return err!(AuctioneerError::LotMetadataTooLong);
+    }
+
+    Ok(())
+}
+
+/// Check a settlement is priced at the listing's recorded highest bid.
+pub fn assert_highest_bid_price(
+    listing_config: &Account<ListingConfig>,
+    buyer_price: u64,
+) -> Result<()> {
+    if buyer_price != listing_config.highest_bid.amount {
+        return err!(AuctioneerError::SettlementPriceMismatch);
+    }
+
+    Ok(())
+}
+
 pub fn process_time_extension(listing_config: &mut Account<ListingConfig>) -> Result<()> {
-    let clock = Clock::get()?;
-    let current_timestamp = clock.unix_timestamp;
+    let current_timestamp = listing_clock_now(listing_config)?;
 
     if current_timestamp >= (listing_config.end_time - i64::from(listing_config.time_ext_period)) {
         listing_config.end_time += i64::from(listing_config.time_ext_delta);
@@ -73,3 +139,44 @@ pub fn process_time_extension(listing_config: &mut Account<ListingConfig>) -> Re
 
     Ok(())
 }
+
+/// Balance held in a wallet's Auction House escrow, in the house's treasury mint.
+pub fn escrow_balance(escrow_payment_account: &AccountInfo, treasury_mint: &Pubkey) -> Result<u64> {
+    if *treasury_mint == spl_token::native_mint::id() {
+        Ok(escrow_payment_account.lamports())
+    } else if escrow_payment_account.data_is_empty() {
+        Ok(0)
+    } else {
+        Ok(SplAccount::unpack(&escrow_payment_account.try_borrow_data()?)?.amount)
+    }
+}
+
+/// Fail unless the listing config uses the current layout, the first to record which feature
+/// accounts the listing has.
+pub fn assert_listing_features_recorded(listing_config: &ListingConfig) -> Result<()> {
+    if !matches!(listing_config.version, ListingConfigVersion::V6) {
+        return err!(AuctioneerError::ListingConfigOutdated);
+    }
+
+    Ok(())
+}
+
+/// The next account of `remaining_accounts` and its bump when the listing has the feature it
+/// belongs to, checked to be the program address with `seeds`.
+pub fn next_feature_account<'a, 'info>(
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+    has_feature: bool,
+    seeds: &[&[u8]],
+) -> Result<Option<(&'a AccountInfo<'info>, u8)>> {
+    if !has_feature {
+        return Ok(None);
+    }
+
+    let account = next_account_info(remaining_accounts)?;
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::id());
+    if account.key() != address {
+        return err!(AuctioneerError::InvalidFeatureAccount);
+    }
+
+    Ok(Some((account, bump)))
+}
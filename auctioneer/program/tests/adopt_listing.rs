@@ -0,0 +1,162 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{pda::find_listing_config_address, sell::config::ListingConfig};
+use mpl_testing_utils::solana::{create_associated_token_account, transfer};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn list_for_adoption(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Metadata, Keypair, i64) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let (_, sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now - 60,
+        now + 60,
+        Some(ONE_SOL),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let new_owner = Keypair::new();
+    airdrop(context, &new_owner.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+
+    (ah, ahkey, test_metadata, new_owner, now + 60)
+}
+
+#[tokio::test]
+async fn adopt_listing_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, new_owner, end_time) = list_for_adoption(&mut context).await;
+    transfer(
+        &mut context,
+        &test_metadata.mint.pubkey(),
+        &test_metadata.token,
+        &new_owner,
+    )
+    .await
+    .unwrap();
+
+    let (listing_config_address, adopt_tx) =
+        adopt_listing(&mut context, &ahkey, &ah, &test_metadata, &new_owner);
+    context
+        .banks_client
+        .process_transaction(adopt_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .expect("Error getting listing config")
+        .expect("Listing config empty");
+    let listing_config = ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+        .expect("Failed to deserialize listing config data");
+    assert_eq!(listing_config.end_time, end_time);
+    assert_eq!(listing_config.reserve_price, ONE_SOL);
+    assert_eq!(listing_config.highest_bid.amount, 0);
+}
+
+#[tokio::test]
+async fn adopt_listing_still_held_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, new_owner, _) = list_for_adoption(&mut context).await;
+    create_associated_token_account(&mut context, &new_owner, &test_metadata.mint.pubkey())
+        .await
+        .unwrap();
+
+    let (_, adopt_tx) = adopt_listing(&mut context, &ahkey, &ah, &test_metadata, &new_owner);
+    let err = context
+        .banks_client
+        .process_transaction(adopt_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, LISTING_STILL_HELD);
+}
+
+#[tokio::test]
+async fn adopt_private_listing_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, new_owner, _) = list_for_adoption(&mut context).await;
+    let (previous_listing_config, _) = find_listing_config_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey()),
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (_, room_tx) = create_private_room(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &previous_listing_config,
+        &Keypair::new().pubkey(),
+        100,
+    );
+    context
+        .banks_client
+        .process_transaction(room_tx)
+        .await
+        .unwrap();
+    transfer(
+        &mut context,
+        &test_metadata.mint.pubkey(),
+        &test_metadata.token,
+        &new_owner,
+    )
+    .await
+    .unwrap();
+
+    // The private room and its sealed bids stay with the previous listing.
+    let (_, adopt_tx) = adopt_listing(&mut context, &ahkey, &ah, &test_metadata, &new_owner);
+    let err = context
+        .banks_client
+        .process_transaction(adopt_tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, LISTING_NOT_ADOPTABLE);
+}
@@ -0,0 +1,220 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{auction_summary::config::AuctionSummary, sell::config::ListingFeatures};
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+#[tokio::test]
+async fn execute_sale_writes_auction_summary() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let start_time = (now - 60) as i64;
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        start_time,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (summary_acc, summary_tx) = create_auction_summary(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        start_time,
+    );
+    context
+        .banks_client
+        .process_transaction(summary_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (bid_acc, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(120 * 400).unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let (_, execute_sale_tx) = execute_sale_with_features(
+        &mut context,
+        &listing_config_address,
+        start_time,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &sell_acc.wallet,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        1,
+        ONE_SOL,
+        &ListingFeatures {
+            auction_summary: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(execute_sale_tx)
+        .await
+        .unwrap();
+
+    let summary_account = context
+        .banks_client
+        .get_account(summary_acc.auction_summary)
+        .await
+        .unwrap()
+        .unwrap();
+    let summary = AuctionSummary::try_deserialize(&mut summary_account.data.as_ref()).unwrap();
+    assert!(summary.is_final);
+    assert_eq!(summary.listing_config, listing_config_address);
+    assert_eq!(summary.seller, test_metadata.token.pubkey());
+    assert_eq!(summary.winner, buyer.pubkey());
+    assert_eq!(summary.price, ONE_SOL);
+    assert_eq!(summary.bid_count, 1);
+    assert_eq!(summary.duration, 120);
+    assert_eq!(summary.royalty_basis_points, 10);
+    assert_eq!(summary.royalties, ONE_SOL * 10 / 10000);
+}
+
+#[tokio::test]
+async fn create_auction_summary_on_paused_listing_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, pause_tx) = pause_listing(
+        &mut context,
+        &ahkey,
+        &authority,
+        &sell_acc,
+        &listing_config_address,
+    );
+    context
+        .banks_client
+        .process_transaction(pause_tx)
+        .await
+        .unwrap();
+
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
+    let (_, summary_tx) = create_auction_summary(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        start_time,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(summary_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, AUCTION_PAUSED);
+}
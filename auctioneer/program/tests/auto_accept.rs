@@ -0,0 +1,179 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    auto_accept::config::AutoAccept, pda::find_auto_accept_address, sell::config::ListingFeatures,
+};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn auto_accept_listing(
+    context: &mut ProgramTestContext,
+    price: u64,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    mpl_auctioneer::accounts::AuctioneerSell,
+    Pubkey,
+) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 3600) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, auto_accept_tx) = create_auto_accept(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        price,
+    );
+    context
+        .banks_client
+        .process_transaction(auto_accept_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        authority,
+        test_metadata,
+        sell_acc,
+        listing_config_address,
+    )
+}
+
+async fn bid_and_execute_sale(
+    context: &mut ProgramTestContext,
+    price: u64,
+    bid: u64,
+) -> (Pubkey, Result<(), BanksClientError>) {
+    let (ah, ahkey, authority, test_metadata, sell_acc, listing_config_address) =
+        auto_accept_listing(context, price).await;
+
+    let (auto_accept_key, _) = find_auto_accept_address(&listing_config_address);
+    let auto_accept_account = context
+        .banks_client
+        .get_account(auto_accept_key)
+        .await
+        .unwrap()
+        .unwrap();
+    let auto_accept = AutoAccept::try_deserialize(&mut auto_accept_account.data.as_ref()).unwrap();
+    assert_eq!(auto_accept.listing_config, listing_config_address);
+    assert_eq!(auto_accept.price, price);
+
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let (bid_acc, buy_tx) = buy(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        bid,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    let start_time = listing_start_time(context, &listing_config_address).await;
+    let (_, execute_sale_tx) = execute_sale_with_features(
+        context,
+        &listing_config_address,
+        start_time,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &sell_acc.wallet,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        1,
+        bid,
+        &ListingFeatures {
+            auto_accept: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(execute_sale_tx)
+        .await;
+
+    (auto_accept_key, result)
+}
+
+#[tokio::test]
+async fn execute_sale_at_auto_accept_price_before_end_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (auto_accept_key, result) = bid_and_execute_sale(&mut context, ONE_SOL, ONE_SOL).await;
+    result.unwrap();
+
+    let auto_accept_account = context
+        .banks_client
+        .get_account(auto_accept_key)
+        .await
+        .unwrap();
+    assert!(auto_accept_account.is_none());
+}
+
+#[tokio::test]
+async fn execute_sale_below_auto_accept_price_before_end_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, result) = bid_and_execute_sale(&mut context, ONE_SOL, ONE_SOL / 2).await;
+    let result = result.unwrap_err();
+    assert_error!(result, AUCTION_ACTIVE);
+}
@@ -0,0 +1,300 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    backup_bids::config::BackupBids, pda::find_backup_bids_address, sell::config::ListingFeatures,
+};
+use solana_program::program_pack::Pack;
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+async fn backup_bids_listing(
+    context: &mut ProgramTestContext,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    mpl_auctioneer::accounts::AuctioneerSell,
+    Pubkey,
+) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, backup_bids_tx) =
+        create_backup_bids(context, &ahkey, &test_metadata, &listing_config_address);
+    context
+        .banks_client
+        .process_transaction(backup_bids_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        authority,
+        test_metadata,
+        sell_acc,
+        listing_config_address,
+    )
+}
+
+async fn place_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    price: u64,
+) -> (Keypair, mpl_auctioneer::accounts::AuctioneerBuy) {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let (bid_acc, buy_tx) = buy_with_features(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &test_metadata.token.pubkey(),
+        listing_config,
+        price,
+        &ListingFeatures {
+            backup_bids: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    (buyer, bid_acc)
+}
+
+#[tokio::test]
+async fn execute_sale_with_promoted_backup_bid_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority, test_metadata, sell_acc, listing_config_address) =
+        backup_bids_listing(&mut context).await;
+
+    let (outbid_buyer, outbid_acc) = place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL / 2,
+    )
+    .await;
+    let (winner, _) = place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL,
+    )
+    .await;
+    // Bids below the highest queue as backup bids.
+    let (_, low_acc) = place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL / 4,
+    )
+    .await;
+
+    let (backup_bids_key, _) = find_backup_bids_address(&listing_config_address);
+    let backup_bids_account = context
+        .banks_client
+        .get_account(backup_bids_key)
+        .await
+        .unwrap()
+        .unwrap();
+    let backup_bids = BackupBids::try_deserialize(&mut backup_bids_account.data.as_ref()).unwrap();
+    assert_eq!(backup_bids.bids.len(), 2);
+    assert_eq!(
+        backup_bids.bids[0].buyer_trade_state,
+        outbid_acc.buyer_trade_state
+    );
+    assert_eq!(backup_bids.bids[0].amount, ONE_SOL / 2);
+    assert_eq!(
+        backup_bids.bids[1].buyer_trade_state,
+        low_acc.buyer_trade_state
+    );
+
+    context.warp_to_slot(120 * 400).unwrap();
+
+    // The winner empties their escrow before settling.
+    let (_, withdraw_tx) = withdraw(
+        &mut context,
+        &winner,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        ONE_SOL,
+        ONE_SOL / 2,
+    );
+    context
+        .banks_client
+        .process_transaction(withdraw_tx)
+        .await
+        .unwrap();
+
+    let (_, promote_tx) = promote_backup_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        &winner.pubkey(),
+        ONE_SOL,
+        &authority,
+    );
+    context
+        .banks_client
+        .process_transaction(promote_tx)
+        .await
+        .unwrap();
+
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
+    let (_, execute_sale_tx) = execute_sale_with_features(
+        &mut context,
+        &listing_config_address,
+        start_time,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &outbid_buyer.pubkey(),
+        &sell_acc.wallet,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &outbid_acc.buyer_trade_state,
+        1,
+        ONE_SOL / 2,
+        &ListingFeatures {
+            backup_bids: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(execute_sale_tx)
+        .await
+        .unwrap();
+
+    let buyer_token_account = context
+        .banks_client
+        .get_account(get_associated_token_address(
+            &outbid_buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let buyer_token_account =
+        spl_token::state::Account::unpack_from_slice(&buyer_token_account.data).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+
+    let backup_bids_account = context
+        .banks_client
+        .get_account(backup_bids_key)
+        .await
+        .unwrap();
+    assert!(backup_bids_account.is_none());
+}
+
+#[tokio::test]
+async fn promote_backup_bid_winner_can_settle_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority, test_metadata, _, listing_config_address) =
+        backup_bids_listing(&mut context).await;
+
+    let (winner, _) = place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL,
+    )
+    .await;
+    place_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL / 2,
+    )
+    .await;
+
+    context.warp_to_slot(120 * 400).unwrap();
+
+    let (_, promote_tx) = promote_backup_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &listing_config_address,
+        &winner.pubkey(),
+        ONE_SOL,
+        &authority,
+    );
+    let result = context
+        .banks_client
+        .process_transaction(promote_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, HIGHEST_BID_CAN_SETTLE);
+}
@@ -0,0 +1,213 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    bid_limit::config::WalletBidCounter, pda::find_wallet_bid_counter_address,
+    sell::config::ListingFeatures,
+};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn limited_listing(
+    context: &mut ProgramTestContext,
+    max_bids_per_wallet: u32,
+    max_amount_per_wallet: u64,
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, limit_tx) = create_bid_limit(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        max_bids_per_wallet,
+        max_amount_per_wallet,
+    );
+    context
+        .banks_client
+        .process_transaction(limit_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        test_metadata,
+        sell_acc.wallet,
+        listing_config_address,
+    )
+}
+
+#[tokio::test]
+async fn bid_limit_exceeded_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        limited_listing(&mut context, 1, 0).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx0) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bid_limit: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx0)
+        .await
+        .unwrap();
+
+    let (counter_key, _) =
+        find_wallet_bid_counter_address(&listing_config_address, &buyer.pubkey());
+    let counter_account = context
+        .banks_client
+        .get_account(counter_key)
+        .await
+        .expect("Error getting wallet bid counter")
+        .expect("Wallet bid counter empty");
+    let counter = WalletBidCounter::try_deserialize(&mut counter_account.data.as_ref())
+        .expect("Failed to deserialize wallet bid counter data");
+    assert_eq!(counter.active_bids, 1);
+    assert_eq!(counter.committed_amount, ONE_SOL);
+
+    let (_, buy_tx1) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        2 * ONE_SOL,
+        &ListingFeatures {
+            bid_limit: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx1)
+        .await
+        .unwrap_err();
+    assert_error!(result, WALLET_BID_LIMIT_EXCEEDED);
+}
+
+#[tokio::test]
+async fn bid_amount_limit_exceeded_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        limited_listing(&mut context, 0, ONE_SOL).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        2 * ONE_SOL,
+        &ListingFeatures {
+            bid_limit: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, WALLET_BID_AMOUNT_EXCEEDED);
+}
@@ -0,0 +1,198 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    bid_pool::config::BidPool,
+    pda::{find_bid_pool_address, find_bid_pool_vault_address, find_pool_contribution_address},
+    sell::config::ListingConfig,
+};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn pooled_listing(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Keypair) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((_, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let organizer = Keypair::new();
+    airdrop(context, &organizer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, create_tx) = create_bid_pool(
+        context,
+        &ahkey,
+        &test_metadata,
+        &organizer,
+        &listing_config_address,
+    );
+    context
+        .banks_client
+        .process_transaction(create_tx)
+        .await
+        .unwrap();
+
+    (ah, ahkey, test_metadata, listing_config_address, organizer)
+}
+
+#[tokio::test]
+async fn place_pool_bid_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, listing_config_address, organizer) =
+        pooled_listing(&mut context).await;
+
+    for _ in 0..2 {
+        let contributor = Keypair::new();
+        airdrop(&mut context, &contributor.pubkey(), TEN_SOL)
+            .await
+            .unwrap();
+        let (_, contribute_tx) =
+            contribute_to_pool(&mut context, &contributor, &listing_config_address, ONE_SOL);
+        context
+            .banks_client
+            .process_transaction(contribute_tx)
+            .await
+            .unwrap();
+    }
+
+    let (bid_acc, bid_tx) = place_pool_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &organizer,
+        &listing_config_address,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .expect("Error getting listing config")
+        .expect("Listing config empty");
+    let listing_config = ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+        .expect("Failed to deserialize listing config data");
+    assert_eq!(listing_config.highest_bid.amount, 2 * ONE_SOL);
+    assert_eq!(
+        listing_config.highest_bid.buyer_trade_state,
+        bid_acc.buyer_trade_state
+    );
+
+    let (bid_pool_key, _) = find_bid_pool_address(&listing_config_address);
+    let bid_pool_account = context
+        .banks_client
+        .get_account(bid_pool_key)
+        .await
+        .expect("Error getting bid pool")
+        .expect("Bid pool empty");
+    let bid_pool = BidPool::try_deserialize(&mut bid_pool_account.data.as_ref())
+        .expect("Failed to deserialize bid pool data");
+    assert_eq!(bid_pool.total_contributed, 2 * ONE_SOL);
+    assert_eq!(bid_pool.bid_amount, 2 * ONE_SOL);
+    assert_eq!(bid_pool.bid_trade_state, bid_acc.buyer_trade_state);
+}
+
+#[tokio::test]
+async fn refund_open_listing_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, _, test_metadata, listing_config_address, _) = pooled_listing(&mut context).await;
+
+    let contributor = Keypair::new();
+    airdrop(&mut context, &contributor.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, contribute_tx) =
+        contribute_to_pool(&mut context, &contributor, &listing_config_address, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(contribute_tx)
+        .await
+        .unwrap();
+
+    let (bid_pool, _) = find_bid_pool_address(&listing_config_address);
+    let (bid_pool_vault, _) = find_bid_pool_vault_address(&bid_pool);
+    let accounts = mpl_auctioneer::accounts::RefundPoolContribution {
+        contributor: contributor.pubkey(),
+        listing_config: listing_config_address,
+        bid_pool,
+        bid_pool_vault,
+        bid_trade_state: Pubkey::default(),
+        pool_token_account: get_associated_token_address(
+            &bid_pool_vault,
+            &test_metadata.mint.pubkey(),
+        ),
+        pool_contribution: find_pool_contribution_address(&bid_pool, &contributor.pubkey()).0,
+        system_program: solana_program::system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::RefundPoolContribution {}.data(),
+        accounts,
+    };
+
+    let refund_tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&contributor.pubkey()),
+        &[&contributor],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(refund_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, POOL_LISTING_OPEN);
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::sell::config::ListingFeatures;
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+async fn qualified_listing(
+    context: &mut ProgramTestContext,
+    min_escrow_balance: u64,
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, qualification_tx) = create_bid_qualification(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        min_escrow_balance,
+    );
+    context
+        .banks_client
+        .process_transaction(qualification_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        test_metadata,
+        sell_acc.wallet,
+        listing_config_address,
+    )
+}
+
+#[tokio::test]
+async fn qualified_bidder_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        qualified_listing(&mut context, 2 * ONE_SOL).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        2 * ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bid_qualification: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn insufficient_escrow_balance_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        qualified_listing(&mut context, 2 * ONE_SOL).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, &buyer, ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bid_qualification: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, INSUFFICIENT_ESCROW_BALANCE);
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    bid_rate_limit::config::WalletBidRate, pda::find_wallet_bid_rate_address,
+    sell::config::ListingFeatures,
+};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn rate_limited_listing(
+    context: &mut ProgramTestContext,
+    min_slot_gap: u64,
+    max_bids_per_wallet: u32,
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, limit_tx) = create_bid_rate_limit(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        min_slot_gap,
+        max_bids_per_wallet,
+    );
+    context
+        .banks_client
+        .process_transaction(limit_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        test_metadata,
+        sell_acc.wallet,
+        listing_config_address,
+    )
+}
+
+/// Deposit for a new buyer and place their first bid of `ONE_SOL` on the listing.
+async fn first_bid(
+    context: &mut ProgramTestContext,
+    ah: &AuctionHouse,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    seller: &Pubkey,
+    listing_config_address: &Pubkey,
+) -> Keypair {
+    let buyer = Keypair::new();
+    airdrop(context, &buyer.pubkey(), TEN_SOL).await.unwrap();
+    let (_, deposit_tx) = deposit(context, ahkey, ah, test_metadata, &buyer, 2 * ONE_SOL);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy_with_features(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        seller,
+        listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bid_rate_limit: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    buyer
+}
+
+#[tokio::test]
+async fn bid_before_slot_gap_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        rate_limited_listing(&mut context, 1000, 0).await;
+    let buyer = first_bid(
+        &mut context,
+        &ah,
+        &ahkey,
+        &test_metadata,
+        &seller,
+        &listing_config_address,
+    )
+    .await;
+
+    let (rate_key, _) = find_wallet_bid_rate_address(&listing_config_address, &buyer.pubkey());
+    let rate_account = context
+        .banks_client
+        .get_account(rate_key)
+        .await
+        .expect("Error getting wallet bid rate")
+        .expect("Wallet bid rate empty");
+    let rate = WalletBidRate::try_deserialize(&mut rate_account.data.as_ref())
+        .expect("Failed to deserialize wallet bid rate data");
+    assert_eq!(rate.bids_placed, 1);
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        2 * ONE_SOL,
+        &ListingFeatures {
+            bid_rate_limit: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, WALLET_BID_TOO_SOON);
+}
+
+#[tokio::test]
+async fn bid_count_exceeded_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        rate_limited_listing(&mut context, 0, 1).await;
+    let buyer = first_bid(
+        &mut context,
+        &ah,
+        &ahkey,
+        &test_metadata,
+        &seller,
+        &listing_config_address,
+    )
+    .await;
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        2 * ONE_SOL,
+        &ListingFeatures {
+            bid_rate_limit: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, WALLET_BID_COUNT_EXCEEDED);
+}
@@ -0,0 +1,230 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::sell::config::ListingFeatures;
+use solana_program::keccak;
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+async fn invite_only_listing(
+    context: &mut ProgramTestContext,
+    bidders: Vec<Pubkey>,
+    merkle_root: [u8; 32],
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Pubkey) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, allowlist_tx) = create_bidder_allowlist(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        bidders,
+        merkle_root,
+    );
+    context
+        .banks_client
+        .process_transaction(allowlist_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        test_metadata,
+        sell_acc.wallet,
+        listing_config_address,
+    )
+}
+
+fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[wallet.as_ref()]).0
+}
+
+fn allowlist_root(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    if a <= b {
+        keccak::hashv(&[&a, &b]).0
+    } else {
+        keccak::hashv(&[&b, &a]).0
+    }
+}
+
+#[tokio::test]
+async fn listed_bidder_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        invite_only_listing(&mut context, vec![buyer.pubkey()], [0; 32]).await;
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bidder_allowlist: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn uninvited_bidder_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        invite_only_listing(&mut context, vec![Pubkey::new_unique()], [0; 32]).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bidder_allowlist: true,
+            ..Default::default()
+        },
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, BIDDER_NOT_INVITED);
+}
+
+#[tokio::test]
+async fn merkle_invitation_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let other_leaf = allowlist_leaf(&Pubkey::new_unique());
+    let merkle_root = allowlist_root(allowlist_leaf(&buyer.pubkey()), other_leaf);
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        invite_only_listing(&mut context, vec![], merkle_root).await;
+
+    let (_, accept_tx) = accept_bidder_invitation(
+        &mut context,
+        &buyer,
+        &listing_config_address,
+        vec![other_leaf],
+    );
+    context
+        .banks_client
+        .process_transaction(accept_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            bidder_allowlist: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn invalid_proof_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let merkle_root = allowlist_root(
+        allowlist_leaf(&Pubkey::new_unique()),
+        allowlist_leaf(&Pubkey::new_unique()),
+    );
+    let (_, _, _, _, listing_config_address) =
+        invite_only_listing(&mut context, vec![], merkle_root).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, accept_tx) =
+        accept_bidder_invitation(&mut context, &buyer, &listing_config_address, vec![[1; 32]]);
+    let err = context
+        .banks_client
+        .process_transaction(accept_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_ALLOWLIST_PROOF);
+}
@@ -0,0 +1,298 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    blind_box::config::{BlindBox, RevealArgs, REVEAL_DISCRIMINATOR},
+    pda::find_blind_box_address,
+    sell::config::ListingFeatures,
+};
+use solana_program::entrypoint::ProgramResult;
+use solana_sdk::{account::Account as SolanaAccount, instruction::AccountMeta};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+/// Stands in for a seller's reveal program. Fails unless the blind box is its only signer and the
+/// buyer, mint and metadata are read-only, then marks the record account it was given revealed.
+fn process_mock_reveal(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    input: &[u8],
+) -> ProgramResult {
+    let (blind_box, accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if !blind_box.is_signer || blind_box.is_writable {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if accounts[..3]
+        .iter()
+        .any(|account| account.is_signer || account.is_writable)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if accounts[3..].iter().any(|account| account.is_signer) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if input[..8] != REVEAL_DISCRIMINATOR {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let args = RevealArgs::try_from_slice(&input[8..])?;
+    if args.buyer != *accounts[0].key || args.token_mint != *accounts[1].key {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    accounts[3].try_borrow_mut_data()?[0] = 1;
+
+    Ok(())
+}
+
+async fn blind_box_listing(
+    context: &mut ProgramTestContext,
+    reveal_program: &Pubkey,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    mpl_auctioneer::accounts::AuctioneerSell,
+    Pubkey,
+) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (_, blind_box_tx) = create_blind_box(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        reveal_program,
+    );
+    context
+        .banks_client
+        .process_transaction(blind_box_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        authority,
+        test_metadata,
+        sell_acc,
+        listing_config_address,
+    )
+}
+
+#[tokio::test]
+async fn create_blind_box_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let reveal_program = Keypair::new().pubkey();
+    let (_, _, _, test_metadata, _, listing_config_address) =
+        blind_box_listing(&mut context, &reveal_program).await;
+
+    let (blind_box_key, _) = find_blind_box_address(&listing_config_address);
+    let blind_box_account = context
+        .banks_client
+        .get_account(blind_box_key)
+        .await
+        .expect("Error getting blind box")
+        .expect("Blind box empty");
+    let blind_box = BlindBox::try_deserialize(&mut blind_box_account.data.as_ref())
+        .expect("Failed to deserialize blind box data");
+
+    assert_eq!(blind_box.listing_config, listing_config_address);
+    assert_eq!(blind_box.reveal_program, reveal_program);
+    assert_eq!(blind_box.seller, test_metadata.token.pubkey());
+    assert_eq!(blind_box.token_mint, test_metadata.mint.pubkey());
+    assert_eq!(blind_box.buyer, None);
+}
+
+#[tokio::test]
+async fn reveal_blind_box_success() {
+    let reveal_program = Pubkey::new_unique();
+    let reveal_record = Pubkey::new_unique();
+    let mut program_test = auctioneer_program_test();
+    program_test.add_builtin_program(
+        "mock_reveal",
+        reveal_program,
+        processor!(process_mock_reveal),
+    );
+    program_test.add_account(
+        reveal_record,
+        SolanaAccount {
+            lamports: ONE_SOL,
+            data: vec![0],
+            owner: reveal_program,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    let (ah, ahkey, authority, test_metadata, sell_acc, listing_config_address) =
+        blind_box_listing(&mut context, &reveal_program).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let (bid_acc, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        100_000_000,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(120 * 400).unwrap();
+
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
+    let (_, execute_sale_tx) = execute_sale_with_features(
+        &mut context,
+        &listing_config_address,
+        start_time,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &sell_acc.wallet,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &bid_acc.buyer_trade_state,
+        1,
+        100_000_000,
+        &ListingFeatures {
+            blind_box: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(execute_sale_tx)
+        .await
+        .unwrap();
+
+    let (blind_box_key, _) = find_blind_box_address(&listing_config_address);
+    let blind_box_account = context
+        .banks_client
+        .get_account(blind_box_key)
+        .await
+        .expect("Error getting blind box")
+        .expect("Blind box empty");
+    let blind_box = BlindBox::try_deserialize(&mut blind_box_account.data.as_ref())
+        .expect("Failed to deserialize blind box data");
+    assert_eq!(blind_box.buyer, Some(buyer.pubkey()));
+
+    // The buyer pays for and signs the reveal, but the reveal program only ever sees the blind
+    // box signature.
+    let (_, reveal_tx) = reveal_blind_box(
+        &mut context,
+        &buyer,
+        &test_metadata,
+        &listing_config_address,
+        &buyer.pubkey(),
+        &reveal_program,
+        vec![AccountMeta::new(reveal_record, false)],
+    );
+    context
+        .banks_client
+        .process_transaction(reveal_tx)
+        .await
+        .unwrap();
+
+    let reveal_record_account = context
+        .banks_client
+        .get_account(reveal_record)
+        .await
+        .expect("Error getting reveal record")
+        .expect("Reveal record empty");
+    assert_eq!(reveal_record_account.data, vec![1]);
+    assert!(context
+        .banks_client
+        .get_account(blind_box_key)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn reveal_unsold_blind_box_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, _, _, test_metadata, _, listing_config_address) =
+        blind_box_listing(&mut context, &spl_token::id()).await;
+
+    let caller = Keypair::new();
+    airdrop(&mut context, &caller.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let (_, reveal_tx) = reveal_blind_box(
+        &mut context,
+        &caller,
+        &test_metadata,
+        &listing_config_address,
+        &caller.pubkey(),
+        &spl_token::id(),
+        vec![],
+    );
+    let result = context
+        .banks_client
+        .process_transaction(reveal_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, BLIND_BOX_NOT_SOLD);
+}
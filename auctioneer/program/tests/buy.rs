@@ -3,7 +3,7 @@ pub mod common;
 pub mod utils;
 
 use common::*;
-use mpl_auctioneer::sell::config::ListingConfig;
+use mpl_auctioneer::sell::config::{ListingClock, ListingConfig};
 use std::{assert_eq, time::SystemTime};
 use utils::setup_functions::*;
 
@@ -53,6 +53,9 @@ async fn buy_success() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -142,6 +145,9 @@ async fn multiple_bids() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -276,6 +282,9 @@ async fn buy_below_reserve_failure() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -366,6 +375,9 @@ async fn buy_above_reserve_success() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -455,6 +467,9 @@ async fn multiple_bids_increment_failure() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -544,6 +559,147 @@ async fn multiple_bids_increment_failure() {
     assert_eq!(config.highest_bid.amount, 1000000000);
 }
 
+#[tokio::test]
+async fn multiple_bids_equal_amount_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    // Payer Wallet
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            - 60) as i64,
+        (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer0 = Keypair::new();
+    airdrop(&mut context, &buyer0.pubkey(), 10000000000)
+        .await
+        .unwrap();
+    let (_, deposit_tx0) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer0,
+        1000000000,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx0)
+        .await
+        .unwrap();
+
+    let (acc0, buy_tx0) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer0,
+        &sell_acc.wallet,
+        &listing_config_address,
+        1000000000,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx0)
+        .await
+        .unwrap();
+
+    let buyer1 = Keypair::new();
+    airdrop(&mut context, &buyer1.pubkey(), 100000000000)
+        .await
+        .unwrap();
+    let (_, deposit_tx1) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer1,
+        1000000000,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx1)
+        .await
+        .unwrap();
+
+    let (_acc1, buy_tx1) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer1,
+        &sell_acc.wallet,
+        &listing_config_address,
+        1000000000,
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx1)
+        .await
+        .unwrap_err();
+    assert_error!(result, BID_TIES_HIGHEST_BID);
+
+    let listing = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let config = ListingConfig::try_deserialize(&mut listing.as_ref()).unwrap();
+    assert_eq!(config.highest_bid.amount, 1000000000);
+    assert_eq!(config.highest_bid.buyer_trade_state, acc0.buyer_trade_state);
+    assert_eq!(config.highest_bid.sequence, 1);
+    assert!(config.highest_bid.slot > 0);
+}
+
 #[tokio::test]
 async fn multiple_bids_increment_success() {
     let mut context = auctioneer_program_test().start_with_context().await;
@@ -590,6 +746,9 @@ async fn multiple_bids_increment_success() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -724,6 +883,9 @@ async fn multiple_bids_time_ext_success() {
         Some(60),
         Some(60),
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -838,3 +1000,195 @@ async fn multiple_bids_time_ext_success() {
     let config2 = ListingConfig::try_deserialize(&mut listing2.as_ref()).unwrap();
     assert_eq!(config2.end_time, end_time_t0 + 120);
 }
+
+#[tokio::test]
+async fn buy_off_tick_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    // Payer Wallet
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            - 60) as i64,
+        (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(100000000),
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10000000000)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, &buyer, 150000000);
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_acc, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        150000000,
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, BID_NOT_MULTIPLE_OF_TICK_SIZE);
+}
+
+#[tokio::test]
+async fn slot_auction_ends_at_end_slot() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    // Payer Wallet
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    // The auction runs from the current slot until slot 100, whatever the wall clock says.
+    let start_slot = context.banks_client.get_root_slot().await.unwrap() as i64;
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        start_slot,
+        100,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(ListingClock::Slot),
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10000000000)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        2000000000,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        1000000000,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+
+    context.warp_to_slot(101).unwrap();
+
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        2000000000,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, AUCTION_ENDED);
+}
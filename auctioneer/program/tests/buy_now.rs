@@ -0,0 +1,220 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::pda::find_buy_now_address;
+use solana_program::program_pack::Pack;
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn live_listing(
+    context: &mut ProgramTestContext,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    mpl_auctioneer::accounts::AuctioneerSell,
+    Pubkey,
+) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 3600) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        authority,
+        test_metadata,
+        sell_acc,
+        listing_config_address,
+    )
+}
+
+#[tokio::test]
+async fn buy_now_and_execute_sale_before_end_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority, test_metadata, sell_acc, listing_config_address) =
+        live_listing(&mut context).await;
+
+    let (_, buy_now_tx) = create_buy_now(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_now_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (buy_now_acc, buy_now_tx) = buy_now(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_now_tx)
+        .await
+        .unwrap();
+
+    let buy_now_account = context
+        .banks_client
+        .get_account(find_buy_now_address(&listing_config_address).0)
+        .await
+        .unwrap();
+    assert!(buy_now_account.is_none());
+
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
+    let (_, execute_sale_tx) = execute_sale(
+        &mut context,
+        &listing_config_address,
+        start_time,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &buyer.pubkey(),
+        &sell_acc.wallet,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &buy_now_acc.buyer_trade_state,
+        1,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(execute_sale_tx)
+        .await
+        .unwrap();
+
+    let buyer_token_account = context
+        .banks_client
+        .get_account(get_associated_token_address(
+            &buyer.pubkey(),
+            &test_metadata.mint.pubkey(),
+        ))
+        .await
+        .unwrap()
+        .unwrap();
+    let buyer_token_account =
+        spl_token::state::Account::unpack_from_slice(&buyer_token_account.data).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+}
+
+#[tokio::test]
+async fn bid_on_buy_now_listing_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _, test_metadata, sell_acc, listing_config_address) =
+        live_listing(&mut context).await;
+
+    let (_, buy_now_tx) = create_buy_now(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_now_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        ONE_SOL * 2,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, BELOW_RESERVE_PRICE);
+}
+
+#[tokio::test]
+async fn create_buy_now_zero_price_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, _, test_metadata, _, listing_config_address) = live_listing(&mut context).await;
+
+    let (_, buy_now_tx) = create_buy_now(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        0,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(buy_now_tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, INVALID_BUY_NOW_PRICE);
+}
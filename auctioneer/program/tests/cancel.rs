@@ -57,6 +57,9 @@ async fn cancel_listing() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -112,6 +115,200 @@ async fn cancel_listing() {
     assert!(listing_config_closed.is_none());
 }
 
+#[tokio::test]
+async fn cancel_listing_closes_listing_config() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(
+        &mut context,
+        &test_metadata.token.pubkey(),
+        100_000_000_000_000,
+    )
+    .await
+    .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Tests".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    context.warp_to_slot(100).unwrap();
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let ((acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now,
+        now + 60,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let accounts = mpl_auctioneer::accounts::CancelListing {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: listing_config_address,
+        seller: acc.wallet,
+        token_account: token,
+        token_mint: test_metadata.mint.pubkey(),
+        authority: ah.authority,
+        auction_house: ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        trade_state: acc.seller_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::CancelListing {
+            auctioneer_authority_bump: aa_bump,
+            token_size: 1,
+        }
+        .data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&test_metadata.token.pubkey()),
+        &[&test_metadata.token],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let listing_config = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap();
+    assert!(listing_config.is_none());
+
+    let seller_trade_state = context
+        .banks_client
+        .get_account(acc.seller_trade_state)
+        .await
+        .unwrap();
+    assert!(seller_trade_state.is_none());
+}
+
+#[tokio::test]
+async fn close_orphaned_listing_config_rejects_open_listing() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(
+        &mut context,
+        &test_metadata.token.pubkey(),
+        100_000_000_000_000,
+    )
+    .await
+    .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Tests".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    context.warp_to_slot(100).unwrap();
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let ((acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now,
+        now + 60,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let accounts = mpl_auctioneer::accounts::CloseOrphanedListingConfig {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: listing_config_address,
+        seller: acc.wallet,
+        token_account: token,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house: ahkey,
+        seller_trade_state: acc.seller_trade_state,
+    }
+    .to_account_metas(None);
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::CloseOrphanedListingConfig { token_size: 1 }.data(),
+        accounts,
+    };
+
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), 1_000_000_000)
+        .await
+        .unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(error, LISTING_NOT_ORPHANED);
+}
+
 #[tokio::test]
 async fn cancel_bid() {
     let mut context = auctioneer_program_test().start_with_context().await;
@@ -159,6 +356,9 @@ async fn cancel_bid() {
         None,
         None,
         Some(true),
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -283,6 +483,9 @@ async fn cancel_highest_bid() {
         None,
         None,
         Some(false),
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -38,3 +38,36 @@ pub const NOT_HIGH_BIDDER: u32 = 6006;
 pub const BELOW_RESERVE_PRICE: u32 = 6007;
 pub const BELOW_BID_INCREMENT: u32 = 6008;
 pub const CANNOT_CANCEL_HIGHEST_BID: u32 = 6009;
+pub const AUCTION_IS_PRIVATE: u32 = 6010;
+pub const SEALED_BID_TOO_LARGE: u32 = 6012;
+pub const PRIVATE_ROOM_NOT_REVEALED: u32 = 6015;
+pub const INVALID_DROP_SCHEDULE: u32 = 6017;
+pub const BID_NOT_MULTIPLE_OF_TICK_SIZE: u32 = 6019;
+pub const WALLET_BID_LIMIT_EXCEEDED: u32 = 6021;
+pub const WALLET_BID_AMOUNT_EXCEEDED: u32 = 6022;
+pub const BLIND_BOX_NOT_SOLD: u32 = 6023;
+pub const POOL_LISTING_OPEN: u32 = 6027;
+pub const LISTING_STILL_HELD: u32 = 6032;
+pub const AUCTION_PAUSED: u32 = 6035;
+pub const BID_TIES_HIGHEST_BID: u32 = 6036;
+pub const INVALID_END_TIME_BUCKET: u32 = 6037;
+pub const INVALID_END_TIME_INDEX: u32 = 6038;
+pub const INVALID_BUY_NOW_PRICE: u32 = 6040;
+pub const INSUFFICIENT_ESCROW_BALANCE: u32 = 6042;
+pub const LISTING_NOT_ORPHANED: u32 = 6044;
+pub const SETTLEMENT_PRICE_MISMATCH: u32 = 6045;
+pub const BACKUP_BID_TOO_LOW: u32 = 6046;
+pub const HIGHEST_BID_CAN_SETTLE: u32 = 6047;
+pub const WALLET_BID_TOO_SOON: u32 = 6051;
+pub const WALLET_BID_COUNT_EXCEEDED: u32 = 6052;
+pub const PSEUDONYM_COMMITMENT_MISMATCH: u32 = 6055;
+pub const BIDDER_NOT_INVITED: u32 = 6058;
+pub const INVALID_ALLOWLIST_PROOF: u32 = 6059;
+pub const LOT_METADATA_TOO_LONG: u32 = 6060;
+pub const INVALID_DURATION_BOUNDS: u32 = 6061;
+pub const AUCTION_TOO_SHORT: u32 = 6062;
+pub const AUCTION_TOO_LONG: u32 = 6063;
+pub const LISTING_CONFIG_LAYOUT_MISMATCH: u32 = 6064;
+pub const INVALID_FEATURE_ACCOUNT: u32 = 6066;
+pub const REVEAL_WINDOW_CLOSED: u32 = 6069;
+pub const LISTING_NOT_ADOPTABLE: u32 = 6070;
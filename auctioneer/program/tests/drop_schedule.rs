@@ -0,0 +1,74 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    drop_schedule::config::DropSchedule,
+    pda::{find_drop_schedule_address, find_drop_vault_address},
+};
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+#[tokio::test]
+async fn create_drop_schedule_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let creator = Keypair::new();
+    airdrop(&mut context, &creator.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let start_time = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    create_drop_schedule(&mut context, &ahkey, &creator, start_time, 3_600, 1_800)
+        .await
+        .unwrap();
+
+    let (drop_schedule_key, _) = find_drop_schedule_address(&ahkey, &creator.pubkey());
+    let drop_schedule_account = context
+        .banks_client
+        .get_account(drop_schedule_key)
+        .await
+        .expect("Error getting drop schedule")
+        .expect("Drop schedule empty");
+    let drop_schedule = DropSchedule::try_deserialize(&mut drop_schedule_account.data.as_ref())
+        .expect("Failed to deserialize drop schedule data");
+
+    assert_eq!(drop_schedule.authority, creator.pubkey());
+    assert_eq!(drop_schedule.start_time, start_time);
+    assert_eq!(drop_schedule.interval, 3_600);
+    assert_eq!(drop_schedule.item_count, 0);
+
+    let (drop_vault, _) = find_drop_vault_address(&drop_schedule_key);
+    let vault = context
+        .banks_client
+        .get_account(drop_vault)
+        .await
+        .expect("Error getting drop vault");
+    assert!(vault.is_some());
+}
+
+#[tokio::test]
+async fn create_drop_schedule_zero_interval_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let creator = Keypair::new();
+    airdrop(&mut context, &creator.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let err = create_drop_schedule(&mut context, &ahkey, &creator, 0, 0, 1_800)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_DROP_SCHEDULE);
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use utils::setup_functions::*;
+
+use mpl_auctioneer::{duration_bounds::config::DurationBounds, pda::find_duration_bounds_address};
+use mpl_testing_utils::{solana::airdrop, utils::Metadata};
+use solana_sdk::system_program;
+use std::time::SystemTime;
+
+const ONE_HOUR: i64 = 3600;
+const ONE_DAY: i64 = 86400;
+
+async fn set_duration_bounds(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    min_duration: i64,
+    max_duration: i64,
+) -> std::result::Result<(), BanksClientError> {
+    let accounts = mpl_auctioneer::accounts::SetDurationBounds {
+        auction_house_program: mpl_auction_house::id(),
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        duration_bounds: find_duration_bounds_address(ahkey).0,
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::SetDurationBounds {
+            min_duration,
+            max_duration,
+        }
+        .data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn sell_for(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    duration: i64,
+) -> std::result::Result<(), BanksClientError> {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let (_, sell_tx) = sell(
+        context,
+        ahkey,
+        ah,
+        &test_metadata,
+        now,
+        now + duration,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    context.banks_client.process_transaction(sell_tx).await
+}
+
+#[tokio::test]
+async fn sell_within_duration_bounds_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_duration_bounds(&mut context, &ahkey, &authority, ONE_HOUR, ONE_DAY)
+        .await
+        .unwrap();
+
+    let bounds_account = context
+        .banks_client
+        .get_account(find_duration_bounds_address(&ahkey).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let bounds = DurationBounds::try_deserialize(&mut bounds_account.data.as_ref()).unwrap();
+    assert_eq!(bounds.min_duration, ONE_HOUR);
+    assert_eq!(bounds.max_duration, ONE_DAY);
+
+    sell_for(&mut context, &ahkey, &ah, 2 * ONE_HOUR)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn sell_shorter_than_min_duration_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_duration_bounds(&mut context, &ahkey, &authority, ONE_HOUR, 0)
+        .await
+        .unwrap();
+
+    let err = sell_for(&mut context, &ahkey, &ah, 1).await.unwrap_err();
+
+    assert_error!(err, AUCTION_TOO_SHORT);
+}
+
+#[tokio::test]
+async fn sell_longer_than_max_duration_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    set_duration_bounds(&mut context, &ahkey, &authority, 0, ONE_DAY)
+        .await
+        .unwrap();
+
+    let err = sell_for(&mut context, &ahkey, &ah, 3650 * ONE_DAY)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, AUCTION_TOO_LONG);
+}
+
+#[tokio::test]
+async fn set_inverted_duration_bounds_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = set_duration_bounds(&mut context, &ahkey, &authority, ONE_DAY, ONE_HOUR)
+        .await
+        .unwrap_err();
+
+    assert_error!(err, INVALID_DURATION_BOUNDS);
+}
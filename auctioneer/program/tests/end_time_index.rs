@@ -0,0 +1,126 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{constants::END_TIME_BUCKET_SECONDS, end_time_index::config::EndTimeIndex};
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+async fn listing_metadata(context: &mut ProgramTestContext) -> Metadata {
+    let test_metadata = Metadata::new();
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    test_metadata
+}
+
+#[tokio::test]
+async fn sell_records_listing_in_end_time_index() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = listing_metadata(&mut context).await;
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let end_time = now + 7200;
+    let bucket_start = EndTimeIndex::bucket_start_for(end_time);
+    let end_time_index = create_end_time_index(&mut context, &ahkey, bucket_start)
+        .await
+        .unwrap();
+
+    let ((_, listing_config_address), sell_tx) = sell_with_end_time_index(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now - 60,
+        end_time,
+        &end_time_index,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let end_time_index_account = context
+        .banks_client
+        .get_account(end_time_index)
+        .await
+        .unwrap()
+        .unwrap();
+    let end_time_index = EndTimeIndex::try_from_account_data(&end_time_index_account.data).unwrap();
+    assert_eq!(end_time_index.auction_house, ahkey);
+    assert_eq!(end_time_index.bucket_start, bucket_start);
+    assert_eq!(end_time_index.listings(), &[listing_config_address]);
+}
+
+#[tokio::test]
+async fn sell_with_other_bucket_end_time_index_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = listing_metadata(&mut context).await;
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let end_time = now + 7200;
+    let end_time_index = create_end_time_index(
+        &mut context,
+        &ahkey,
+        EndTimeIndex::bucket_start_for(end_time) + END_TIME_BUCKET_SECONDS,
+    )
+    .await
+    .unwrap();
+
+    let (_, sell_tx) = sell_with_end_time_index(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now - 60,
+        end_time,
+        &end_time_index,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_END_TIME_INDEX);
+}
+
+#[tokio::test]
+async fn create_unaligned_end_time_index_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+
+    let err = create_end_time_index(&mut context, &ahkey, END_TIME_BUCKET_SECONDS + 1)
+        .await
+        .unwrap_err();
+    assert_error!(err, INVALID_END_TIME_BUCKET);
+}
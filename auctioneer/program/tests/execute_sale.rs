@@ -24,7 +24,10 @@ use mpl_auction_house::pda::{
     find_auctioneer_pda, find_escrow_payment_address, find_program_as_signer_address,
     find_trade_state_address,
 };
-use mpl_auctioneer::pda::find_auctioneer_authority_seeds;
+use mpl_auctioneer::pda::{
+    find_auction_summary_address, find_auctioneer_authority_seeds, find_auto_accept_address,
+    find_backup_bids_address, find_blind_box_address,
+};
 use mpl_token_metadata::state::Creator;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, transaction::Transaction};
 use spl_associated_token_account::get_associated_token_address;
@@ -74,6 +77,9 @@ async fn execute_sale_early_failure() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -107,9 +113,14 @@ async fn execute_sale_early_failure() {
 
     let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
     let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
     let accounts = mpl_auctioneer::accounts::AuctioneerExecuteSale {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        blind_box: find_blind_box_address(&listing_config_address).0,
+        auto_accept: find_auto_accept_address(&listing_config_address).0,
+        backup_bids: find_backup_bids_address(&listing_config_address).0,
+        auction_summary: find_auction_summary_address(&listing_config_address, start_time).0,
         buyer: buyer.pubkey(),
         seller: test_metadata.token.pubkey(),
         auction_house: ahkey,
@@ -229,6 +240,9 @@ async fn execute_sale_success() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -263,9 +277,14 @@ async fn execute_sale_success() {
 
     let (auctioneer_authority, _aa_bump) = find_auctioneer_authority_seeds(&ahkey);
     let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
     let accounts = mpl_auctioneer::accounts::AuctioneerExecuteSale {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        blind_box: find_blind_box_address(&listing_config_address).0,
+        auto_accept: find_auto_accept_address(&listing_config_address).0,
+        backup_bids: find_backup_bids_address(&listing_config_address).0,
+        auction_summary: find_auction_summary_address(&listing_config_address, start_time).0,
         buyer: buyer.pubkey(),
         seller: test_metadata.token.pubkey(),
         authority: ah.authority,
@@ -433,6 +452,9 @@ async fn execute_sale_two_bids_success() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -490,9 +512,14 @@ async fn execute_sale_two_bids_success() {
 
     let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
     let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
     let accounts = mpl_auctioneer::accounts::AuctioneerExecuteSale {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        blind_box: find_blind_box_address(&listing_config_address).0,
+        auto_accept: find_auto_accept_address(&listing_config_address).0,
+        backup_bids: find_backup_bids_address(&listing_config_address).0,
+        auction_summary: find_auction_summary_address(&listing_config_address, start_time).0,
         buyer: buyer1.pubkey(),
         seller: test_metadata.token.pubkey(),
         authority: ah.authority,
@@ -659,6 +686,9 @@ async fn execute_sale_two_bids_failure() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -716,9 +746,14 @@ async fn execute_sale_two_bids_failure() {
 
     let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
     let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
     let accounts = mpl_auctioneer::accounts::AuctioneerExecuteSale {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        blind_box: find_blind_box_address(&listing_config_address).0,
+        auto_accept: find_auto_accept_address(&listing_config_address).0,
+        backup_bids: find_backup_bids_address(&listing_config_address).0,
+        auction_summary: find_auction_summary_address(&listing_config_address, start_time).0,
         buyer: buyer0.pubkey(),
         seller: test_metadata.token.pubkey(),
         authority: ah.authority,
@@ -789,6 +824,165 @@ async fn execute_sale_two_bids_failure() {
     assert_error!(result, NOT_HIGH_BIDDER)
 }
 
+#[tokio::test]
+async fn execute_sale_wrong_price_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    // Payer Wallet
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            - 60) as i64,
+        (SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_secs()
+            + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    let (bid_acc, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        100_000_000,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+    let buyer_token_account =
+        get_associated_token_address(&buyer.pubkey(), &test_metadata.mint.pubkey());
+
+    context.warp_to_slot(120 * 400).unwrap();
+
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
+    let accounts = mpl_auctioneer::accounts::AuctioneerExecuteSale {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: listing_config_address,
+        blind_box: find_blind_box_address(&listing_config_address).0,
+        auto_accept: find_auto_accept_address(&listing_config_address).0,
+        backup_bids: find_backup_bids_address(&listing_config_address).0,
+        auction_summary: find_auction_summary_address(&listing_config_address, start_time).0,
+        buyer: buyer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        authority: ah.authority,
+        auction_house: ahkey,
+        metadata: test_metadata.pubkey,
+        token_account: sell_acc.token_account,
+        seller_trade_state: sell_acc.seller_trade_state,
+        buyer_trade_state: bid_acc.buyer_trade_state,
+        token_program: spl_token::id(),
+        free_trade_state: sell_acc.free_seller_trade_state,
+        seller_payment_receipt_account: test_metadata.token.pubkey(),
+        buyer_receipt_token_account: buyer_token_account,
+        escrow_payment_account: bid_acc.escrow_payment_account,
+        token_mint: test_metadata.mint.pubkey(),
+        auction_house_fee_account: ah.auction_house_fee_account,
+        auction_house_treasury: ah.auction_house_treasury,
+        treasury_mint: ah.treasury_mint,
+        program_as_signer: sell_acc.program_as_signer,
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+    }
+    .to_account_metas(None);
+    let (_, free_sts_bump) = find_trade_state_address(
+        &test_metadata.token.pubkey(),
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (_, escrow_bump) = find_escrow_payment_address(&ahkey, &buyer.pubkey());
+    let (_, pas_bump) = find_program_as_signer_address();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::ExecuteSale {
+            escrow_payment_bump: escrow_bump,
+            free_trade_state_bump: free_sts_bump,
+            program_as_signer_bump: pas_bump,
+            auctioneer_authority_bump: aa_bump,
+            token_size: 1,
+            buyer_price: 90_000_000,
+        }
+        .data(),
+        accounts,
+    };
+    airdrop(&mut context, &ah.auction_house_fee_account, 10_000_000_000)
+        .await
+        .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[&authority],
+        context.last_blockhash,
+    );
+
+    let result = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_error!(result, SETTLEMENT_PRICE_MISMATCH)
+}
+
 #[tokio::test]
 async fn execute_sale_one_creator() {
     execute_sale_with_creators(vec![(Pubkey::new_unique(), 100)]).await;
@@ -799,6 +993,16 @@ async fn execute_sale_two_creator() {
     execute_sale_with_creators(vec![(Pubkey::new_unique(), 25), (Pubkey::new_unique(), 75)]).await;
 }
 
+#[tokio::test]
+async fn execute_sale_three_creator() {
+    execute_sale_with_creators(vec![
+        (Pubkey::new_unique(), 20),
+        (Pubkey::new_unique(), 30),
+        (Pubkey::new_unique(), 50),
+    ])
+    .await;
+}
+
 async fn execute_sale_with_creators(metadata_creators: Vec<(Pubkey, u8)>) {
     let mut context = auctioneer_program_test().start_with_context().await;
     // Payer Wallet
@@ -857,6 +1061,9 @@ async fn execute_sale_with_creators(metadata_creators: Vec<(Pubkey, u8)>) {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
     context
         .banks_client
@@ -891,9 +1098,14 @@ async fn execute_sale_with_creators(metadata_creators: Vec<(Pubkey, u8)>) {
 
     let (auctioneer_authority, _aa_bump) = find_auctioneer_authority_seeds(&ahkey);
     let (auctioneer_pda, _) = find_auctioneer_pda(&ahkey, &auctioneer_authority);
+    let start_time = listing_start_time(&mut context, &listing_config_address).await;
     let mut accounts = mpl_auctioneer::accounts::AuctioneerExecuteSale {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        blind_box: find_blind_box_address(&listing_config_address).0,
+        auto_accept: find_auto_accept_address(&listing_config_address).0,
+        backup_bids: find_backup_bids_address(&listing_config_address).0,
+        auction_summary: find_auction_summary_address(&listing_config_address, start_time).0,
         buyer: buyer.pubkey(),
         seller: test_metadata.token.pubkey(),
         authority: ah.authority,
@@ -0,0 +1,105 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::pda::find_listing_fee_address;
+use mpl_auctioneer::pda::find_auctioneer_authority_seeds;
+use solana_program::{system_program, sysvar};
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+#[tokio::test]
+async fn set_listing_fee_without_scope_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, ah_authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&ahkey);
+    let (listing_fee, _) = find_listing_fee_address(&ahkey, &sell_acc.seller_trade_state);
+    let accounts = mpl_auctioneer::accounts::SetListingFee {
+        auction_house_program: mpl_auction_house::id(),
+        authority: ah_authority.pubkey(),
+        wallet: sell_acc.wallet,
+        token_account: sell_acc.token_account,
+        auction_house: ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        listing_config: listing_config_address,
+        seller_trade_state: sell_acc.seller_trade_state,
+        listing_fee,
+        auctioneer_authority,
+        ah_auctioneer_pda: sell_acc.ah_auctioneer_pda,
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::SetListingFee {
+            auctioneer_authority_bump: aa_bump,
+            token_size: 1,
+            fee_basis_points: 0,
+        }
+        .data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&ah_authority.pubkey()),
+        &[&ah_authority],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, MISSING_AUCTIONEER_SCOPE);
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::sell::config::ListingConfig;
+use std::time::SystemTime;
+use utils::{helpers::default_scopes, setup_functions::*};
+
+async fn live_listing(
+    context: &mut ProgramTestContext,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    mpl_auctioneer::accounts::AuctioneerSell,
+    Pubkey,
+) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 3600) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        authority,
+        test_metadata,
+        sell_acc,
+        listing_config_address,
+    )
+}
+
+#[tokio::test]
+async fn pause_listing_without_scope_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, authority, _, sell_acc, listing_config_address) =
+        live_listing(&mut context).await;
+
+    let (_, pause_tx) = pause_listing(
+        &mut context,
+        &ahkey,
+        &authority,
+        &sell_acc,
+        &listing_config_address,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(pause_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, MISSING_AUCTIONEER_SCOPE);
+}
+
+#[tokio::test]
+async fn paused_listing_rejects_bids_until_resumed() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority, test_metadata, sell_acc, listing_config_address) =
+        live_listing(&mut context).await;
+
+    let mut scopes = default_scopes();
+    scopes.push(AuthorityScope::PauseListing);
+    let scopes_tx = update_auctioneer_scopes(&mut context, &ahkey, &authority, scopes);
+    context
+        .banks_client
+        .process_transaction(scopes_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let original =
+        ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref()).unwrap();
+
+    let (_, pause_tx) = pause_listing(
+        &mut context,
+        &ahkey,
+        &authority,
+        &sell_acc,
+        &listing_config_address,
+    );
+    context
+        .banks_client
+        .process_transaction(pause_tx)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        ONE_SOL,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, AUCTION_PAUSED);
+
+    context.warp_to_slot(1000).unwrap();
+    let (_, resume_tx) = resume_listing(
+        &mut context,
+        &ahkey,
+        &authority,
+        &sell_acc,
+        &listing_config_address,
+    );
+    context
+        .banks_client
+        .process_transaction(resume_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let resumed =
+        ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref()).unwrap();
+    assert_eq!(resumed.start_time, original.start_time);
+    assert!(resumed.end_time >= original.end_time);
+
+    let (_, buy_tx) = buy(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &sell_acc.wallet,
+        &listing_config_address,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,283 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use anchor_lang::Discriminator;
+use common::*;
+use mpl_auctioneer::{
+    pda::*,
+    private_room::config::{PrivateRoom, PRIVATE_ROOM_SIZE},
+    sell::config::{ListingClock, ListingConfig, ListingConfigVersion, LISTING_CONFIG_SIZE},
+};
+use solana_program::system_program;
+use solana_sdk::{account::Account, instruction::AccountMeta};
+use std::assert_eq;
+use utils::setup_functions::*;
+
+/// Account data of a listing config written in the layout of `version`, with every field it has
+/// set to a value other than its migration default.
+fn listing_config_data(version: u8, highest_bid_trade_state: &Pubkey) -> Vec<u8> {
+    let mut data = ListingConfig::discriminator().to_vec();
+    data.push(version); // version
+    data.extend_from_slice(&1_000i64.to_le_bytes()); // start time
+    data.extend_from_slice(&2_000i64.to_le_bytes()); // end time
+    data.push(version); // highest bid version
+    data.extend_from_slice(&ONE_SOL.to_le_bytes()); // highest bid amount
+    data.extend_from_slice(highest_bid_trade_state.as_ref()); // highest bid trade state
+    if version >= 3 {
+        data.extend_from_slice(&50u64.to_le_bytes()); // highest bid slot
+        data.extend_from_slice(&3u64.to_le_bytes()); // highest bid sequence
+    }
+    data.push(254); // bump
+    data.extend_from_slice(&(ONE_SOL / 2).to_le_bytes()); // reserve price
+    data.extend_from_slice(&10_000u64.to_le_bytes()); // min bid increment
+    data.extend_from_slice(&30u32.to_le_bytes()); // time ext period
+    data.extend_from_slice(&60u32.to_le_bytes()); // time ext delta
+    data.push(1); // allow high bid cancel
+    if version >= 1 {
+        data.extend_from_slice(&100u64.to_le_bytes()); // tick size
+    }
+    if version >= 2 {
+        data.push(1); // fee basis points
+        data.extend_from_slice(&250u16.to_le_bytes());
+    }
+    if version >= 4 {
+        data.push(1); // clock
+    }
+    if version >= 5 {
+        data.extend_from_slice(&3u32.to_le_bytes()); // lot metadata
+        data.extend_from_slice(&[1, 2, 3]);
+    }
+    data
+}
+
+/// Migrate a listing config account holding `data`, with a private room when `has_private_room`,
+/// and return the migrated listing config.
+async fn migrate(
+    data: Vec<u8>,
+    has_private_room: bool,
+) -> std::result::Result<ListingConfig, BanksClientError> {
+    let listing_config_address = Pubkey::new_unique();
+    let mut program_test = auctioneer_program_test();
+    program_test.add_account(
+        listing_config_address,
+        Account {
+            lamports: ONE_SOL,
+            data,
+            owner: mpl_auctioneer::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    if has_private_room {
+        let mut private_room = PrivateRoom::discriminator().to_vec();
+        private_room.resize(PRIVATE_ROOM_SIZE, 0);
+        program_test.add_account(
+            find_private_room_address(&listing_config_address).0,
+            Account {
+                lamports: ONE_SOL,
+                data: private_room,
+                owner: mpl_auctioneer::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+    let mut context = program_test.start_with_context().await;
+
+    let mut accounts = mpl_auctioneer::accounts::MigrateListingConfig {
+        payer: context.payer.pubkey(),
+        listing_config: listing_config_address,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+    for feature_account in [
+        find_private_room_address(&listing_config_address).0,
+        find_bid_limit_address(&listing_config_address).0,
+        find_bid_rate_limit_address(&listing_config_address).0,
+        find_bid_qualification_address(&listing_config_address).0,
+        find_bidder_allowlist_address(&listing_config_address).0,
+        find_backup_bids_address(&listing_config_address).0,
+        find_blind_box_address(&listing_config_address).0,
+        find_auto_accept_address(&listing_config_address).0,
+        find_auction_summary_address(&listing_config_address, 1_000).0,
+    ] {
+        accounts.push(AccountMeta::new_readonly(feature_account, false));
+    }
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::MigrateListingConfig {}.data(),
+        accounts,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await?;
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .expect("Error getting listing config")
+        .expect("Listing config empty");
+    assert_eq!(listing_config_account.data.len(), LISTING_CONFIG_SIZE);
+
+    Ok(
+        ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+            .expect("Failed to deserialize listing config data"),
+    )
+}
+
+/// Check the fields every listing config version has were carried over unchanged.
+fn assert_shared_fields(listing_config: &ListingConfig, highest_bid_trade_state: &Pubkey) {
+    assert!(matches!(listing_config.version, ListingConfigVersion::V6));
+    assert_eq!(listing_config.start_time, 1_000);
+    assert_eq!(listing_config.end_time, 2_000);
+    assert!(matches!(
+        listing_config.highest_bid.version,
+        ListingConfigVersion::V6
+    ));
+    assert_eq!(listing_config.highest_bid.amount, ONE_SOL);
+    assert_eq!(
+        listing_config.highest_bid.buyer_trade_state,
+        *highest_bid_trade_state
+    );
+    assert_eq!(listing_config.bump, 254);
+    assert_eq!(listing_config.reserve_price, ONE_SOL / 2);
+    assert_eq!(listing_config.min_bid_increment, 10_000);
+    assert_eq!(listing_config.time_ext_period, 30);
+    assert_eq!(listing_config.time_ext_delta, 60);
+    assert!(listing_config.allow_high_bid_cancel);
+}
+
+#[tokio::test]
+async fn migrate_v0_listing_config_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(0, &trade_state), false)
+        .await
+        .unwrap();
+
+    assert_shared_fields(&listing_config, &trade_state);
+    assert!(listing_config.lot_metadata.is_empty());
+    assert_eq!(listing_config.highest_bid.slot, 0);
+    assert_eq!(listing_config.highest_bid.sequence, 1);
+    assert_eq!(listing_config.tick_size, 0);
+    assert_eq!(listing_config.fee_basis_points, None);
+    assert!(listing_config.clock == ListingClock::UnixTimestamp);
+}
+
+#[tokio::test]
+async fn migrate_v1_listing_config_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(1, &trade_state), false)
+        .await
+        .unwrap();
+
+    assert_shared_fields(&listing_config, &trade_state);
+    assert!(listing_config.lot_metadata.is_empty());
+    assert_eq!(listing_config.highest_bid.slot, 0);
+    assert_eq!(listing_config.highest_bid.sequence, 1);
+    assert_eq!(listing_config.tick_size, 100);
+    assert_eq!(listing_config.fee_basis_points, None);
+    assert!(listing_config.clock == ListingClock::UnixTimestamp);
+}
+
+#[tokio::test]
+async fn migrate_v2_listing_config_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(2, &trade_state), false)
+        .await
+        .unwrap();
+
+    assert_shared_fields(&listing_config, &trade_state);
+    assert!(listing_config.lot_metadata.is_empty());
+    assert_eq!(listing_config.highest_bid.slot, 0);
+    assert_eq!(listing_config.highest_bid.sequence, 1);
+    assert_eq!(listing_config.tick_size, 100);
+    assert_eq!(listing_config.fee_basis_points, Some(250));
+    assert!(listing_config.clock == ListingClock::UnixTimestamp);
+}
+
+#[tokio::test]
+async fn migrate_v3_listing_config_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(3, &trade_state), false)
+        .await
+        .unwrap();
+
+    assert_shared_fields(&listing_config, &trade_state);
+    assert!(listing_config.lot_metadata.is_empty());
+    assert_eq!(listing_config.highest_bid.slot, 50);
+    assert_eq!(listing_config.highest_bid.sequence, 3);
+    assert_eq!(listing_config.tick_size, 100);
+    assert_eq!(listing_config.fee_basis_points, Some(250));
+    assert!(listing_config.clock == ListingClock::UnixTimestamp);
+}
+
+#[tokio::test]
+async fn migrate_v4_listing_config_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(4, &trade_state), false)
+        .await
+        .unwrap();
+
+    assert_shared_fields(&listing_config, &trade_state);
+    assert!(listing_config.lot_metadata.is_empty());
+    assert_eq!(listing_config.highest_bid.slot, 50);
+    assert_eq!(listing_config.highest_bid.sequence, 3);
+    assert_eq!(listing_config.tick_size, 100);
+    assert_eq!(listing_config.fee_basis_points, Some(250));
+    assert!(listing_config.clock == ListingClock::Slot);
+}
+
+#[tokio::test]
+async fn migrate_truncated_listing_config_failure() {
+    // A V2 listing config missing its fee override.
+    let mut data = listing_config_data(2, &Pubkey::new_unique());
+    data.truncate(data.len() - 3);
+
+    let err = migrate(data, false).await.unwrap_err();
+    assert_error!(err, LISTING_CONFIG_LAYOUT_MISMATCH);
+}
+
+#[tokio::test]
+async fn migrate_listing_config_with_unexpected_fields_failure() {
+    // A V0 listing config followed by a tick size only V1 listing configs have.
+    let mut data = listing_config_data(0, &Pubkey::new_unique());
+    data.extend_from_slice(&100u64.to_le_bytes());
+
+    let err = migrate(data, false).await.unwrap_err();
+    assert_error!(err, LISTING_CONFIG_LAYOUT_MISMATCH);
+}
+
+#[tokio::test]
+async fn migrate_v5_listing_config_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(5, &trade_state), false)
+        .await
+        .unwrap();
+
+    assert_shared_fields(&listing_config, &trade_state);
+    assert_eq!(listing_config.highest_bid.slot, 50);
+    assert_eq!(listing_config.highest_bid.sequence, 3);
+    assert_eq!(listing_config.tick_size, 100);
+    assert_eq!(listing_config.fee_basis_points, Some(250));
+    assert!(listing_config.clock == ListingClock::Slot);
+    assert_eq!(listing_config.lot_metadata, vec![1, 2, 3]);
+    assert!(!listing_config.features.private_room);
+}
+
+#[tokio::test]
+async fn migrate_listing_config_records_existing_features_success() {
+    let trade_state = Pubkey::new_unique();
+    let listing_config = migrate(listing_config_data(5, &trade_state), true)
+        .await
+        .unwrap();
+
+    assert!(listing_config.features.private_room);
+    assert!(!listing_config.features.bid_limit);
+    assert!(!listing_config.features.auction_summary);
+}
@@ -0,0 +1,366 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::{
+    pda::find_private_room_address,
+    private_room::config::PrivateRoom,
+    sell::config::{ListingClock, ListingFeatures},
+};
+use solana_program::keccak;
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+const REVEAL_WINDOW: i64 = 100;
+
+async fn private_listing(
+    context: &mut ProgramTestContext,
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Pubkey) {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs() as i64;
+    let (ah, ahkey, test_metadata, seller, listing_config_address, _) =
+        private_listing_with_clock(context, now - 60, now + 60, None).await;
+
+    (ah, ahkey, test_metadata, seller, listing_config_address)
+}
+
+async fn private_listing_with_clock(
+    context: &mut ProgramTestContext,
+    start_time: i64,
+    end_time: i64,
+    clock: Option<ListingClock>,
+) -> (AuctionHouse, Pubkey, Metadata, Pubkey, Pubkey, Keypair) {
+    let (ah, ahkey, _) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        start_time,
+        end_time,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        clock,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let committee = Keypair::new();
+    let (_, room_tx) = create_private_room(
+        context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        &committee.pubkey(),
+        REVEAL_WINDOW,
+    );
+    context
+        .banks_client
+        .process_transaction(room_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        test_metadata,
+        sell_acc.wallet,
+        listing_config_address,
+        committee,
+    )
+}
+
+fn sealed_bid_commitment(bidder: &Pubkey, amount: u64, salt: [u8; 32]) -> [u8; 32] {
+    keccak::hashv(&[bidder.as_ref(), &amount.to_le_bytes(), &salt]).0
+}
+
+#[tokio::test]
+async fn place_sealed_bid_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, _, _, listing_config_address) = private_listing(&mut context).await;
+
+    let bidder = Keypair::new();
+    airdrop(&mut context, &bidder.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let (_, bid_tx) = place_sealed_bid(
+        &mut context,
+        &ahkey,
+        &bidder,
+        &listing_config_address,
+        [7; 32],
+        vec![1; 64],
+    );
+    context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap();
+
+    let (private_room_key, _) = find_private_room_address(&listing_config_address);
+    let private_room_account = context
+        .banks_client
+        .get_account(private_room_key)
+        .await
+        .expect("Error getting private room")
+        .expect("Private room empty");
+    let private_room = PrivateRoom::try_deserialize(&mut private_room_account.data.as_ref())
+        .expect("Failed to deserialize private room data");
+
+    assert_eq!(private_room.bid_count, 1);
+    assert_eq!(private_room.revealed_count, 0);
+}
+
+#[tokio::test]
+async fn sealed_bid_too_large() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (_, ahkey, _, _, listing_config_address) = private_listing(&mut context).await;
+
+    let bidder = Keypair::new();
+    airdrop(&mut context, &bidder.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let (_, bid_tx) = place_sealed_bid(
+        &mut context,
+        &ahkey,
+        &bidder,
+        &listing_config_address,
+        [7; 32],
+        vec![1; 257],
+    );
+    let result = context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, SEALED_BID_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn public_buy_on_private_listing_fails() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        private_listing(&mut context).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10000000000)
+        .await
+        .unwrap();
+    let (_, deposit_tx) = deposit(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &buyer,
+        1000000000,
+    );
+    context
+        .banks_client
+        .process_transaction(deposit_tx)
+        .await
+        .unwrap();
+
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        1000000000,
+        &ListingFeatures {
+            private_room: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, AUCTION_IS_PRIVATE);
+}
+
+#[tokio::test]
+async fn buy_with_wrong_feature_account_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, test_metadata, seller, listing_config_address) =
+        private_listing(&mut context).await;
+
+    let buyer = Keypair::new();
+    airdrop(&mut context, &buyer.pubkey(), 10000000000)
+        .await
+        .unwrap();
+
+    // The bid passes a bid limit where the listing's private room belongs.
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &buyer,
+        &seller,
+        &listing_config_address,
+        1000000000,
+        &ListingFeatures {
+            bid_limit: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, INVALID_FEATURE_ACCOUNT);
+}
+
+#[tokio::test]
+async fn unrevealed_bid_dropped_after_reveal_deadline() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    // The auction runs until slot 100 and the committee has until slot 200 to reveal.
+    let start_slot = context.banks_client.get_root_slot().await.unwrap() as i64;
+    let (ah, ahkey, test_metadata, seller, listing_config_address, committee) =
+        private_listing_with_clock(&mut context, start_slot, 100, Some(ListingClock::Slot)).await;
+    airdrop(&mut context, &committee.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+
+    let winner = Keypair::new();
+    let silent_bidder = Keypair::new();
+    for (bidder, amount) in [(&winner, ONE_SOL), (&silent_bidder, 2 * ONE_SOL)] {
+        airdrop(&mut context, &bidder.pubkey(), TEN_SOL)
+            .await
+            .unwrap();
+        let (_, deposit_tx) = deposit(&mut context, &ahkey, &ah, &test_metadata, bidder, amount);
+        context
+            .banks_client
+            .process_transaction(deposit_tx)
+            .await
+            .unwrap();
+        let (_, bid_tx) = place_sealed_bid(
+            &mut context,
+            &ahkey,
+            bidder,
+            &listing_config_address,
+            sealed_bid_commitment(&bidder.pubkey(), amount, [3; 32]),
+            vec![1; 64],
+        );
+        context
+            .banks_client
+            .process_transaction(bid_tx)
+            .await
+            .unwrap();
+    }
+
+    context.warp_to_slot(101).unwrap();
+    let (_, reveal_tx) = reveal_sealed_bid(
+        &mut context,
+        &ahkey,
+        &committee,
+        &listing_config_address,
+        &winner.pubkey(),
+        ONE_SOL,
+        [3; 32],
+    );
+    context
+        .banks_client
+        .process_transaction(reveal_tx)
+        .await
+        .unwrap();
+
+    // The other bid is still sealed, so the winner cannot settle yet.
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &winner,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            private_room: true,
+            ..Default::default()
+        },
+    );
+    let result = context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, PRIVATE_ROOM_NOT_REVEALED);
+
+    context.warp_to_slot(201).unwrap();
+    let (_, reveal_tx) = reveal_sealed_bid(
+        &mut context,
+        &ahkey,
+        &committee,
+        &listing_config_address,
+        &silent_bidder.pubkey(),
+        2 * ONE_SOL,
+        [3; 32],
+    );
+    let result = context
+        .banks_client
+        .process_transaction(reveal_tx)
+        .await
+        .unwrap_err();
+    assert_error!(result, REVEAL_WINDOW_CLOSED);
+
+    // Past the deadline the unrevealed bid is dropped and the revealed winner bids.
+    let (_, buy_tx) = buy_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &test_metadata.token.pubkey(),
+        &winner,
+        &seller,
+        &listing_config_address,
+        ONE_SOL,
+        &ListingFeatures {
+            private_room: true,
+            ..Default::default()
+        },
+    );
+    context
+        .banks_client
+        .process_transaction(buy_tx)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,337 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::pda::find_trade_state_address;
+use mpl_auctioneer::{
+    pda::{find_pseudonymous_bidder_address, find_pseudonymous_vault_address},
+    pseudonymous_bid::{config::PseudonymousBidder, pseudonym_commitment},
+    sell::config::{ListingConfig, ListingFeatures},
+};
+use solana_program::program_pack::Pack;
+use solana_sdk::clock::UnixTimestamp;
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+async fn pseudonymous_listing(
+    context: &mut ProgramTestContext,
+) -> (
+    AuctionHouse,
+    Pubkey,
+    Keypair,
+    Metadata,
+    mpl_auctioneer::accounts::AuctioneerSell,
+    Pubkey,
+    UnixTimestamp,
+) {
+    let (ah, ahkey, authority) = existing_auction_house_test_context(context).await.unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config_address), sell_tx) = sell(
+        context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    (
+        ah,
+        ahkey,
+        authority,
+        test_metadata,
+        sell_acc,
+        listing_config_address,
+        (now - 60) as i64,
+    )
+}
+
+#[tokio::test]
+async fn pseudonymous_bid_settle_and_claim_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority, test_metadata, sell_acc, listing_config_address, start_time) =
+        pseudonymous_listing(&mut context).await;
+
+    let wallet = Keypair::new();
+    let funder = Keypair::new();
+    airdrop(&mut context, &wallet.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &funder.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let salt = [7; 32];
+    let commitment = pseudonym_commitment(&wallet.pubkey(), &salt);
+
+    let (bid_acc, bid_tx) = place_pseudonymous_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &funder,
+        &listing_config_address,
+        commitment,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .expect("Error getting listing config")
+        .expect("Listing config empty");
+    let listing_config = ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+        .expect("Failed to deserialize listing config data");
+    assert_eq!(
+        listing_config.highest_bid.buyer_trade_state,
+        bid_acc.buyer_trade_state
+    );
+
+    let (pseudonymous_bidder_key, _) =
+        find_pseudonymous_bidder_address(&listing_config_address, &commitment);
+    let pseudonymous_bidder_account = context
+        .banks_client
+        .get_account(pseudonymous_bidder_key)
+        .await
+        .expect("Error getting pseudonymous bidder")
+        .expect("Pseudonymous bidder empty");
+    let pseudonymous_bidder =
+        PseudonymousBidder::try_deserialize(&mut pseudonymous_bidder_account.data.as_ref())
+            .expect("Failed to deserialize pseudonymous bidder data");
+    assert_eq!(pseudonymous_bidder.commitment, commitment);
+    assert_eq!(
+        pseudonymous_bidder.bid_trade_state,
+        bid_acc.buyer_trade_state
+    );
+
+    context.warp_to_slot(120 * 400).unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+    let (pseudonymous_vault, _) = find_pseudonymous_vault_address(&pseudonymous_bidder_key);
+    let (buyer_trade_state, _) = find_trade_state_address(
+        &pseudonymous_vault,
+        &ahkey,
+        &sell_acc.token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        ONE_SOL,
+        1,
+    );
+    let (_, execute_sale_tx) = execute_sale(
+        &mut context,
+        &listing_config_address,
+        start_time,
+        &ahkey,
+        &ah,
+        &authority,
+        &test_metadata,
+        &pseudonymous_vault,
+        &sell_acc.wallet,
+        &sell_acc.token_account,
+        &sell_acc.seller_trade_state,
+        &buyer_trade_state,
+        1,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(execute_sale_tx)
+        .await
+        .unwrap();
+
+    let (claim_acc, claim_tx) = claim_pseudonymous_bid(
+        &mut context,
+        &test_metadata,
+        &wallet,
+        &listing_config_address,
+        commitment,
+        salt,
+    );
+    context
+        .banks_client
+        .process_transaction(claim_tx)
+        .await
+        .unwrap();
+
+    let wallet_token_account = context
+        .banks_client
+        .get_account(claim_acc.wallet_token_account)
+        .await
+        .expect("Error getting wallet token account")
+        .expect("Wallet token account empty");
+    let wallet_token =
+        spl_token::state::Account::unpack_from_slice(&wallet_token_account.data).unwrap();
+    assert_eq!(wallet_token.amount, 1);
+    assert!(context
+        .banks_client
+        .get_account(claim_acc.vault_token_account)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        context
+            .banks_client
+            .get_balance(pseudonymous_vault)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[tokio::test]
+async fn claim_wrong_wallet_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _, test_metadata, _, listing_config_address, _) =
+        pseudonymous_listing(&mut context).await;
+
+    let wallet = Keypair::new();
+    let impostor = Keypair::new();
+    airdrop(&mut context, &wallet.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    airdrop(&mut context, &impostor.pubkey(), ONE_SOL)
+        .await
+        .unwrap();
+    let salt = [7; 32];
+    let commitment = pseudonym_commitment(&wallet.pubkey(), &salt);
+
+    let (_, bid_tx) = place_pseudonymous_bid(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &wallet,
+        &listing_config_address,
+        commitment,
+        ONE_SOL,
+    );
+    context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap();
+
+    let (_, claim_tx) = claim_pseudonymous_bid(
+        &mut context,
+        &test_metadata,
+        &impostor,
+        &listing_config_address,
+        commitment,
+        salt,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(claim_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, PSEUDONYM_COMMITMENT_MISMATCH);
+}
+
+#[tokio::test]
+async fn pseudonymous_bid_over_bid_limit_failure() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _, test_metadata, _, listing_config_address, _) =
+        pseudonymous_listing(&mut context).await;
+
+    let (_, limit_tx) = create_bid_limit(
+        &mut context,
+        &ahkey,
+        &test_metadata,
+        &listing_config_address,
+        1,
+        0,
+    );
+    context
+        .banks_client
+        .process_transaction(limit_tx)
+        .await
+        .unwrap();
+
+    let funder = Keypair::new();
+    airdrop(&mut context, &funder.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    let commitment = pseudonym_commitment(&Pubkey::new_unique(), &[7; 32]);
+    let features = ListingFeatures {
+        bid_limit: true,
+        ..Default::default()
+    };
+
+    let (_, bid_tx) = place_pseudonymous_bid_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &funder,
+        &listing_config_address,
+        commitment,
+        ONE_SOL,
+        &features,
+    );
+    context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap();
+
+    // The vault is held to the same per-wallet limit as any other bidder.
+    let (_, bid_tx) = place_pseudonymous_bid_with_features(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        &funder,
+        &listing_config_address,
+        commitment,
+        ONE_SOL * 2,
+        &features,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(bid_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, WALLET_BID_LIMIT_EXCEEDED);
+}
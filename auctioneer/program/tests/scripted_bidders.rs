@@ -0,0 +1,220 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auction_house::pda::{find_escrow_payment_address, find_trade_state_address};
+use mpl_auctioneer::pda::find_auctioneer_authority_seeds;
+use mpl_testing_utils::utils::{AuctionDriver, BidderScript};
+use solana_sdk::clock::UnixTimestamp;
+use std::time::SystemTime;
+use utils::setup_functions::*;
+
+/// Sends the bids, cancels and settlement of a [`BidderScript`] to a single auctioneer listing.
+struct ListingDriver<'a> {
+    ah: &'a AuctionHouse,
+    ahkey: Pubkey,
+    authority: &'a Keypair,
+    test_metadata: &'a Metadata,
+    sell_acc: &'a mpl_auctioneer::accounts::AuctioneerSell,
+    listing_config: Pubkey,
+    start_time: UnixTimestamp,
+}
+
+impl<'a> AuctionDriver for ListingDriver<'a> {
+    fn bid(&self, context: &mut ProgramTestContext, bidder: &Keypair, price: u64) -> Transaction {
+        let (_, buy_tx) = buy(
+            context,
+            &self.ahkey,
+            self.ah,
+            self.test_metadata,
+            &self.test_metadata.token.pubkey(),
+            bidder,
+            &self.sell_acc.wallet,
+            &self.listing_config,
+            price,
+        );
+        buy_tx
+    }
+
+    fn cancel(
+        &self,
+        context: &mut ProgramTestContext,
+        bidder: &Keypair,
+        price: u64,
+    ) -> Transaction {
+        let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(&self.ahkey);
+        let (auctioneer_pda, _) = find_auctioneer_pda(&self.ahkey, &auctioneer_authority);
+        let accounts = mpl_auctioneer::accounts::AuctioneerCancel {
+            auction_house_program: mpl_auction_house::id(),
+            listing_config: self.listing_config,
+            seller: self.sell_acc.wallet,
+            auction_house: self.ahkey,
+            wallet: bidder.pubkey(),
+            token_account: self.sell_acc.token_account,
+            authority: self.ah.authority,
+            trade_state: self.receipt_account(&bidder.pubkey(), price),
+            token_program: spl_token::id(),
+            token_mint: self.test_metadata.mint.pubkey(),
+            auction_house_fee_account: self.ah.auction_house_fee_account,
+            auctioneer_authority,
+            ah_auctioneer_pda: auctioneer_pda,
+        }
+        .to_account_metas(None);
+        let instruction = Instruction {
+            program_id: mpl_auctioneer::id(),
+            data: mpl_auctioneer::instruction::Cancel {
+                auctioneer_authority_bump: aa_bump,
+                buyer_price: price,
+                token_size: 1,
+            }
+            .data(),
+            accounts,
+        };
+
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        )
+    }
+
+    fn settle(
+        &self,
+        context: &mut ProgramTestContext,
+        winner: &Keypair,
+        price: u64,
+    ) -> Transaction {
+        let (_, execute_sale_tx) = execute_sale(
+            context,
+            &self.listing_config,
+            self.start_time,
+            &self.ahkey,
+            self.ah,
+            self.authority,
+            self.test_metadata,
+            &winner.pubkey(),
+            &self.sell_acc.wallet,
+            &self.sell_acc.token_account,
+            &self.sell_acc.seller_trade_state,
+            &self.receipt_account(&winner.pubkey(), price),
+            1,
+            price,
+        );
+        execute_sale_tx
+    }
+
+    fn escrow_account(&self, bidder: &Pubkey) -> Pubkey {
+        find_escrow_payment_address(&self.ahkey, bidder).0
+    }
+
+    fn receipt_account(&self, bidder: &Pubkey, price: u64) -> Pubkey {
+        find_trade_state_address(
+            bidder,
+            &self.ahkey,
+            &self.sell_acc.token_account,
+            &self.ah.treasury_mint,
+            &self.test_metadata.mint.pubkey(),
+            price,
+            1,
+        )
+        .0
+    }
+}
+
+#[tokio::test]
+async fn scripted_bidders_outbid_cancel_and_settle() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, authority) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), 10_000_000_000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let ((sell_acc, listing_config), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+    airdrop(&mut context, &ah.auction_house_fee_account, TEN_SOL)
+        .await
+        .unwrap();
+
+    let driver = ListingDriver {
+        ah: &ah,
+        ahkey,
+        authority: &authority,
+        test_metadata: &test_metadata,
+        sell_acc: &sell_acc,
+        listing_config,
+        start_time: (now - 60) as i64,
+    };
+    let mut script = BidderScript::new(&mut context, 3, TEN_SOL).await.unwrap();
+    script
+        .bid(0, ONE_SOL / 2)
+        .outbid(2, ONE_SOL * 3 / 4)
+        .outbid(1, ONE_SOL)
+        .cancel(2, ONE_SOL * 3 / 4)
+        .warp_to_slot(120 * 400)
+        .settle(1, ONE_SOL);
+    let report = script.run(&mut context, &driver).await.unwrap();
+
+    // Cancelling a bid leaves its funds in escrow until the bidder withdraws them.
+    let escrow_rent = context
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(0);
+    report.assert_escrow_balance(0, ONE_SOL / 2 + escrow_rent);
+    report.assert_escrow_balance(1, escrow_rent);
+    report.assert_escrow_balance(2, ONE_SOL * 3 / 4 + escrow_rent);
+    report.assert_receipt_open(0, true);
+    report.assert_receipt_open(1, false);
+    report.assert_receipt_open(2, false);
+
+    let winner_token_account =
+        get_associated_token_address(&script.bidders[1].pubkey(), &test_metadata.mint.pubkey());
+    assert!(context
+        .banks_client
+        .get_account(winner_token_account)
+        .await
+        .unwrap()
+        .is_some());
+}
@@ -3,6 +3,7 @@ pub mod common;
 pub mod utils;
 
 use common::*;
+use mpl_auctioneer::sell::config::{ListingConfig, MAX_LOT_METADATA_LEN};
 use utils::setup_functions::*;
 
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
@@ -51,6 +52,9 @@ async fn sell_success() {
         None,
         None,
         None,
+        None,
+        None,
+        None,
     );
 
     context
@@ -66,3 +70,115 @@ async fn sell_success() {
         .expect("Trade State Empty");
     assert_eq!(sts.data.len(), 1);
 }
+
+#[tokio::test]
+async fn sell_with_lot_metadata_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let lot_metadata = b"Lot 12: provenance sha256 0f1e2d".to_vec();
+    let ((_, listing_config_address), sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(lot_metadata.clone()),
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .expect("Error getting listing config")
+        .expect("Listing config empty");
+    let listing_config = ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+        .expect("Failed to deserialize listing config data");
+    assert_eq!(listing_config.lot_metadata, lot_metadata);
+}
+
+#[tokio::test]
+async fn sell_lot_metadata_too_long() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+    airdrop(&mut context, &test_metadata.token.pubkey(), TEN_SOL)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let (_, sell_tx) = sell(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        now as i64,
+        (now + 60) as i64,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(vec![0; MAX_LOT_METADATA_LEN + 1]),
+    );
+    let err = context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap_err();
+    assert_error!(err, LOT_METADATA_TOO_LONG);
+}
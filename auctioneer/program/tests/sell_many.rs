@@ -0,0 +1,63 @@
+#![cfg(feature = "test-bpf")]
+pub mod common;
+pub mod utils;
+
+use common::*;
+use mpl_auctioneer::sell::config::ListingConfig;
+use std::{assert_eq, time::SystemTime};
+use utils::setup_functions::*;
+
+#[tokio::test]
+async fn sell_many_success() {
+    let mut context = auctioneer_program_test().start_with_context().await;
+    let (ah, ahkey, _) = existing_auction_house_test_context(&mut context)
+        .await
+        .unwrap();
+    let test_metadata = Metadata::new();
+
+    airdrop(&mut context, &test_metadata.token.pubkey(), 1000000000)
+        .await
+        .unwrap();
+    test_metadata
+        .create(
+            &mut context,
+            "Test".to_string(),
+            "TST".to_string(),
+            "uri".to_string(),
+            None,
+            10,
+            false,
+            1,
+        )
+        .await
+        .unwrap();
+
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let (listing_config_address, sell_tx) = sell_many(
+        &mut context,
+        &ahkey,
+        &ah,
+        &test_metadata,
+        (now - 60) as i64,
+        (now + 60) as i64,
+    );
+    context
+        .banks_client
+        .process_transaction(sell_tx)
+        .await
+        .unwrap();
+
+    let listing_config_account = context
+        .banks_client
+        .get_account(listing_config_address)
+        .await
+        .expect("Error getting listing config")
+        .expect("Listing config empty");
+    let listing_config = ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+        .expect("Failed to deserialize listing config data");
+    assert_eq!(listing_config.end_time, (now + 60) as i64);
+    assert_eq!(listing_config.highest_bid.amount, 0);
+}
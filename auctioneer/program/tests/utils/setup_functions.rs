@@ -10,18 +10,25 @@ use mpl_auction_house::{
     pda::{
         find_auction_house_address, find_auction_house_fee_account_address,
         find_auction_house_treasury_address, find_auctioneer_pda,
-        find_auctioneer_trade_state_address, find_escrow_payment_address,
+        find_auctioneer_trade_state_address, find_escrow_payment_address, find_lien_address,
         find_program_as_signer_address, find_trade_state_address,
     },
-    AuctionHouse,
+    AuctionHouse, AuthorityScope,
+};
+use mpl_auctioneer::{
+    pda::*,
+    sell::config::{ListingClock, ListingConfig, ListingFeatures},
 };
-use mpl_auctioneer::pda::*;
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
 use std::result::Result as StdResult;
 
 use mpl_token_metadata::pda::find_metadata_account;
 use solana_program_test::*;
-use solana_sdk::{clock::UnixTimestamp, instruction::Instruction, transaction::Transaction};
+use solana_sdk::{
+    clock::UnixTimestamp,
+    instruction::{AccountMeta, Instruction},
+    transaction::Transaction,
+};
 use spl_associated_token_account::get_associated_token_address;
 
 use crate::utils::helpers::default_scopes;
@@ -49,6 +56,7 @@ pub async fn create_auction_house(
     seller_fee_basis_points: u16,
     requires_sign_off: bool,
     can_change_sale_price: bool,
+    immutable: bool,
 ) -> StdResult<Pubkey, BanksClientError> {
     let create_accounts = mpl_auction_house::accounts::CreateAuctionHouse {
         treasury_mint: *t_mint_key,
@@ -74,6 +82,7 @@ pub async fn create_auction_house(
         seller_fee_basis_points,
         requires_sign_off,
         can_change_sale_price,
+        immutable,
     }
     .data();
 
@@ -202,6 +211,98 @@ pub fn deposit(
     )
 }
 
+/// Account metas of the feature accounts a bid from `bidder` passes on a listing with `features`.
+pub fn bid_feature_account_metas(
+    listing_config: &Pubkey,
+    bidder: &Pubkey,
+    features: &ListingFeatures,
+) -> Vec<AccountMeta> {
+    let mut account_metas = vec![];
+    if features.private_room {
+        account_metas.push(AccountMeta::new_readonly(
+            find_private_room_address(listing_config).0,
+            false,
+        ));
+    }
+    if features.bid_limit {
+        account_metas.push(AccountMeta::new_readonly(
+            find_bid_limit_address(listing_config).0,
+            false,
+        ));
+        account_metas.push(AccountMeta::new(
+            find_wallet_bid_counter_address(listing_config, bidder).0,
+            false,
+        ));
+    }
+    if features.bid_rate_limit {
+        account_metas.push(AccountMeta::new_readonly(
+            find_bid_rate_limit_address(listing_config).0,
+            false,
+        ));
+        account_metas.push(AccountMeta::new(
+            find_wallet_bid_rate_address(listing_config, bidder).0,
+            false,
+        ));
+    }
+    if features.bid_qualification {
+        account_metas.push(AccountMeta::new_readonly(
+            find_bid_qualification_address(listing_config).0,
+            false,
+        ));
+    }
+    if features.bidder_allowlist {
+        account_metas.push(AccountMeta::new_readonly(
+            find_bidder_allowlist_address(listing_config).0,
+            false,
+        ));
+        account_metas.push(AccountMeta::new_readonly(
+            find_bidder_invitation_address(listing_config, bidder).0,
+            false,
+        ));
+    }
+    if features.backup_bids {
+        account_metas.push(AccountMeta::new(
+            find_backup_bids_address(listing_config).0,
+            false,
+        ));
+    }
+    account_metas
+}
+
+/// Account metas of the feature accounts a sale passes on a listing with `features`.
+pub fn execute_sale_feature_account_metas(
+    listing_config: &Pubkey,
+    start_time: UnixTimestamp,
+    features: &ListingFeatures,
+) -> Vec<AccountMeta> {
+    let mut account_metas = vec![];
+    if features.blind_box {
+        account_metas.push(AccountMeta::new(
+            find_blind_box_address(listing_config).0,
+            false,
+        ));
+    }
+    if features.auto_accept {
+        account_metas.push(AccountMeta::new(
+            find_auto_accept_address(listing_config).0,
+            false,
+        ));
+    }
+    if features.backup_bids {
+        account_metas.push(AccountMeta::new(
+            find_backup_bids_address(listing_config).0,
+            false,
+        ));
+    }
+    if features.auction_summary {
+        account_metas.push(AccountMeta::new(
+            find_auction_summary_address(listing_config, start_time).0,
+            false,
+        ));
+    }
+    account_metas
+}
+
 pub fn buy(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -212,6 +313,33 @@ pub fn buy(
     seller: &Pubkey,
     listing_config: &Pubkey,
     sale_price: u64,
+) -> (mpl_auctioneer::accounts::AuctioneerBuy, Transaction) {
+    buy_with_features(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        owner,
+        buyer,
+        seller,
+        listing_config,
+        sale_price,
+        &ListingFeatures::default(),
+    )
+}
+
+/// Bid on a listing set up with `features`, passing their feature accounts.
+pub fn buy_with_features(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    owner: &Pubkey,
+    buyer: &Keypair,
+    seller: &Pubkey,
+    listing_config: &Pubkey,
+    sale_price: u64,
+    features: &ListingFeatures,
 ) -> (mpl_auctioneer::accounts::AuctioneerBuy, Transaction) {
     let seller_token_account = get_associated_token_address(owner, &test_metadata.mint.pubkey());
     let trade_state = find_trade_state_address(
@@ -249,7 +377,12 @@ pub fn buy(
         ah_auctioneer_pda: auctioneer_pda,
     };
 
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(bid_feature_account_metas(
+        listing_config,
+        &buyer.pubkey(),
+        features,
+    ));
 
     let buy_ix = mpl_auctioneer::instruction::Buy {
         trade_state_bump: bts_bump,
@@ -277,9 +410,62 @@ pub fn buy(
     )
 }
 
+/// Start time of the listing, which keys its auction summary.
+pub async fn listing_start_time(
+    context: &mut ProgramTestContext,
+    listing_config: &Pubkey,
+) -> UnixTimestamp {
+    let listing_config_account = context
+        .banks_client
+        .get_account(*listing_config)
+        .await
+        .unwrap()
+        .unwrap();
+    ListingConfig::try_deserialize(&mut listing_config_account.data.as_ref())
+        .unwrap()
+        .start_time
+}
+
 pub fn execute_sale(
     context: &mut ProgramTestContext,
     listing_config: &Pubkey,
+    start_time: UnixTimestamp,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    authority: &Keypair,
+    test_metadata: &Metadata,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    token_account: &Pubkey,
+    seller_trade_state: &Pubkey,
+    buyer_trade_state: &Pubkey,
+    token_size: u64,
+    buyer_price: u64,
+) -> (mpl_auctioneer::accounts::AuctioneerExecuteSale, Transaction) {
+    execute_sale_with_features(
+        context,
+        listing_config,
+        start_time,
+        ahkey,
+        ah,
+        authority,
+        test_metadata,
+        buyer,
+        seller,
+        token_account,
+        seller_trade_state,
+        buyer_trade_state,
+        token_size,
+        buyer_price,
+        &ListingFeatures::default(),
+    )
+}
+
+/// Settle a sale of a listing set up with `features`, passing their feature accounts.
+pub fn execute_sale_with_features(
+    context: &mut ProgramTestContext,
+    listing_config: &Pubkey,
+    start_time: UnixTimestamp,
     ahkey: &Pubkey,
     ah: &AuctionHouse,
     authority: &Keypair,
@@ -291,6 +477,7 @@ pub fn execute_sale(
     buyer_trade_state: &Pubkey,
     token_size: u64,
     buyer_price: u64,
+    features: &ListingFeatures,
 ) -> (mpl_auctioneer::accounts::AuctioneerExecuteSale, Transaction) {
     let buyer_token_account = get_associated_token_address(buyer, &test_metadata.mint.pubkey());
 
@@ -337,7 +524,12 @@ pub fn execute_sale(
         ah_auctioneer_pda: auctioneer_pda,
     };
 
-    let execute_sale_account_metas = execute_sale_accounts.to_account_metas(None);
+    let mut execute_sale_account_metas = execute_sale_accounts.to_account_metas(None);
+    execute_sale_account_metas.extend(execute_sale_feature_account_metas(
+        listing_config,
+        start_time,
+        features,
+    ));
 
     let execute_sale_instruction = Instruction {
         program_id: mpl_auctioneer::id(),
@@ -363,6 +555,12 @@ pub fn execute_sale(
     (execute_sale_accounts, tx)
 }
 
+/// The Auction House lien account every sell forwards to Auction House, uninitialized unless
+/// `mint` was sold with seller financing.
+pub fn lien_account_meta(ahkey: &Pubkey, mint: &Pubkey) -> AccountMeta {
+    AccountMeta::new_readonly(find_lien_address(ahkey, mint).0, false)
+}
+
 pub fn sell_mint(
     context: &mut ProgramTestContext,
     ahkey: &Pubkey,
@@ -376,6 +574,9 @@ pub fn sell_mint(
     time_ext_period: Option<u32>,
     time_ext_delta: Option<u32>,
     allow_high_bid_cancel: Option<bool>,
+    tick_size: Option<u64>,
+    clock: Option<ListingClock>,
+    lot_metadata: Option<Vec<u8>>,
 ) -> (
     (mpl_auctioneer::accounts::AuctioneerSell, Pubkey),
     Transaction,
@@ -416,6 +617,7 @@ pub fn sell_mint(
     let accounts = mpl_auctioneer::accounts::AuctioneerSell {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        duration_bounds: find_duration_bounds_address(ahkey).0,
         wallet: seller.pubkey(),
         token_account: token,
         metadata,
@@ -431,7 +633,8 @@ pub fn sell_mint(
         auctioneer_authority,
         ah_auctioneer_pda: auctioneer_pda,
     };
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, test_metadata_mint));
 
     let data = mpl_auctioneer::instruction::Sell {
         trade_state_bump: sts_bump,
@@ -446,6 +649,9 @@ pub fn sell_mint(
         time_ext_period,
         time_ext_delta,
         allow_high_bid_cancel,
+        tick_size,
+        clock,
+        lot_metadata,
     }
     .data();
 
@@ -478,6 +684,9 @@ pub fn sell(
     time_ext_period: Option<u32>,
     time_ext_delta: Option<u32>,
     allow_high_bid_cancel: Option<bool>,
+    tick_size: Option<u64>,
+    clock: Option<ListingClock>,
+    lot_metadata: Option<Vec<u8>>,
 ) -> (
     (mpl_auctioneer::accounts::AuctioneerSell, Pubkey),
     Transaction,
@@ -519,6 +728,7 @@ pub fn sell(
     let accounts = mpl_auctioneer::accounts::AuctioneerSell {
         auction_house_program: mpl_auction_house::id(),
         listing_config: listing_config_address,
+        duration_bounds: find_duration_bounds_address(ahkey).0,
         wallet: test_metadata.token.pubkey(),
         token_account: token,
         metadata: test_metadata.pubkey,
@@ -534,7 +744,8 @@ pub fn sell(
         auctioneer_authority,
         ah_auctioneer_pda: auctioneer_pda,
     };
-    let account_metas = accounts.to_account_metas(None);
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
 
     let data = mpl_auctioneer::instruction::Sell {
         trade_state_bump: sts_bump,
@@ -549,6 +760,9 @@ pub fn sell(
         time_ext_period,
         time_ext_delta,
         allow_high_bid_cancel,
+        tick_size,
+        clock,
+        lot_metadata,
     }
     .data();
 
@@ -569,103 +783,1607 @@ pub fn sell(
     )
 }
 
-pub fn withdraw(
+pub fn sell_many(
     context: &mut ProgramTestContext,
-    buyer: &Keypair,
     ahkey: &Pubkey,
     ah: &AuctionHouse,
     test_metadata: &Metadata,
-    sale_price: u64,
-    withdraw_amount: u64,
-) -> ((mpl_auctioneer::accounts::AuctioneerWithdraw,), Transaction) {
-    let seller_token_account =
-        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
-    let (_buyer_trade_state, _sts_bump) = find_trade_state_address(
-        &buyer.pubkey(),
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+) -> (Pubkey, Transaction) {
+    let wallet = test_metadata.token.pubkey();
+    let token = get_associated_token_address(&wallet, &test_metadata.mint.pubkey());
+    let (seller_trade_state, sts_bump) = find_auctioneer_trade_state_address(
+        &wallet,
         ahkey,
-        &seller_token_account,
+        &token,
         &ah.treasury_mint,
         &test_metadata.mint.pubkey(),
-        sale_price,
         1,
     );
-    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (free_seller_trade_state, free_sts_bump) = find_trade_state_address(
+        &wallet,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (listing_config_address, _) = find_listing_config_address(
+        &wallet,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+
+    let (pas, pas_bump) = find_program_as_signer_address();
     let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
     let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
 
-    let accounts = mpl_auctioneer::accounts::AuctioneerWithdraw {
+    let mut account_metas = mpl_auctioneer::accounts::SellMany {
         auction_house_program: mpl_auction_house::id(),
-        wallet: buyer.pubkey(),
-        escrow_payment_account,
-        receipt_account: buyer.pubkey(),
-        treasury_mint: ah.treasury_mint,
+        wallet,
         authority: ah.authority,
         auction_house: *ahkey,
         auction_house_fee_account: ah.auction_house_fee_account,
+        duration_bounds: find_duration_bounds_address(ahkey).0,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        program_as_signer: pas,
         token_program: spl_token::id(),
         system_program: system_program::id(),
-        ata_program: spl_associated_token_account::id(),
         rent: sysvar::rent::id(),
-        auctioneer_authority,
-        ah_auctioneer_pda: auctioneer_pda,
+    }
+    .to_account_metas(None);
+    account_metas.extend([
+        AccountMeta::new(listing_config_address, false),
+        AccountMeta::new(token, false),
+        AccountMeta::new_readonly(test_metadata.pubkey, false),
+        AccountMeta::new(seller_trade_state, false),
+        AccountMeta::new(free_seller_trade_state, false),
+        lien_account_meta(ahkey, &test_metadata.mint.pubkey()),
+    ]);
+
+    let data = mpl_auctioneer::instruction::SellMany {
+        program_as_signer_bump: pas_bump,
+        auctioneer_authority_bump: aa_bump,
+        listings: vec![mpl_auctioneer::sell_many::SellManyListing {
+            trade_state_bump: sts_bump,
+            free_trade_state_bump: free_sts_bump,
+            token_size: 1,
+            start_time,
+            end_time,
+            reserve_price: None,
+            min_bid_increment: None,
+            time_ext_period: None,
+            time_ext_delta: None,
+            allow_high_bid_cancel: None,
+            tick_size: None,
+            clock: None,
+            lot_metadata: None,
+        }],
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
     };
 
-    let accounts_metas = accounts.to_account_metas(None);
+    (
+        listing_config_address,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&wallet),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
 
-    let data = mpl_auctioneer::instruction::Withdraw {
-        escrow_payment_bump: escrow_bump,
+pub fn adopt_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    new_owner: &Keypair,
+) -> (Pubkey, Transaction) {
+    let previous_token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let token = get_associated_token_address(&new_owner.pubkey(), &test_metadata.mint.pubkey());
+    let (previous_listing_config, _) = find_listing_config_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &previous_token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (listing_config, _) = find_listing_config_address(
+        &new_owner.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (previous_seller_trade_state, _) = find_auctioneer_trade_state_address(
+        &test_metadata.token.pubkey(),
+        ahkey,
+        &previous_token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (seller_trade_state, sts_bump) = find_auctioneer_trade_state_address(
+        &new_owner.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        1,
+    );
+    let (free_seller_trade_state, free_sts_bump) = find_trade_state_address(
+        &new_owner.pubkey(),
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        0,
+        1,
+    );
+    let (pas, pas_bump) = find_program_as_signer_address();
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let mut accounts = mpl_auctioneer::accounts::AdoptListing {
+        auction_house_program: mpl_auction_house::id(),
+        previous_listing_config,
+        previous_seller: test_metadata.token.pubkey(),
+        previous_token_account: previous_token,
+        previous_seller_trade_state,
+        listing_config,
+        wallet: new_owner.pubkey(),
+        token_account: token,
+        metadata: test_metadata.pubkey,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        program_as_signer: pas,
+        token_program: spl_token::id(),
+        system_program: solana_program::system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+    accounts.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+
+    let data = mpl_auctioneer::instruction::AdoptListing {
+        trade_state_bump: sts_bump,
+        free_trade_state_bump: free_sts_bump,
+        program_as_signer_bump: pas_bump,
         auctioneer_authority_bump: aa_bump,
-        amount: withdraw_amount,
+        token_size: 1,
     }
     .data();
 
     let instruction = Instruction {
         program_id: mpl_auctioneer::id(),
         data,
-        accounts: accounts_metas,
+        accounts,
     };
-    let tx = Transaction::new_signed_with_payer(
-        &[instruction],
-        Some(&buyer.pubkey()),
-        &[buyer],
-        context.last_blockhash,
-    );
 
-    ((accounts,), tx)
+    (
+        listing_config,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&new_owner.pubkey()),
+            &[new_owner],
+            context.last_blockhash,
+        ),
+    )
 }
 
-pub async fn existing_auction_house_test_context(
+pub fn create_private_room(
     context: &mut ProgramTestContext,
-) -> StdResult<(AuctionHouse, Pubkey, Keypair), BanksClientError> {
-    let twd_key = context.payer.pubkey();
-    let fwd_key = context.payer.pubkey();
-    let t_mint_key = spl_token::native_mint::id();
-    let tdw_ata = twd_key;
-    let seller_fee_basis_points: u16 = 100;
-    let authority = Keypair::new();
-    airdrop(context, &authority.pubkey(), 10_000_000_000).await?;
-    // Derive Auction House Key
-    let (auction_house_address, bump) =
-        find_auction_house_address(&authority.pubkey(), &t_mint_key);
-    let (auction_fee_account_key, fee_payer_bump) =
-        find_auction_house_fee_account_address(&auction_house_address);
-    // Derive Auction House Treasury Key
-    let (auction_house_treasury_key, treasury_bump) =
-        find_auction_house_treasury_address(&auction_house_address);
-    let auction_house = create_auction_house(
-        context,
-        &authority,
-        &twd_key,
-        &fwd_key,
-        &t_mint_key,
-        &tdw_ata,
-        &auction_house_address,
-        bump,
-        &auction_fee_account_key,
-        fee_payer_bump,
-        &auction_house_treasury_key,
-        treasury_bump,
-        seller_fee_basis_points,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    committee: &Pubkey,
+    reveal_window: i64,
+) -> (mpl_auctioneer::accounts::CreatePrivateRoom, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (private_room, _) = find_private_room_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreatePrivateRoom {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        private_room,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreatePrivateRoom {
+        token_size: 1,
+        committee: *committee,
+        reveal_window,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_bid_limit(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    max_bids_per_wallet: u32,
+    max_amount_per_wallet: u64,
+) -> (mpl_auctioneer::accounts::CreateBidLimit, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (bid_limit, _) = find_bid_limit_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBidLimit {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        bid_limit,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBidLimit {
+        token_size: 1,
+        max_bids_per_wallet,
+        max_amount_per_wallet,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_bid_qualification(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    min_escrow_balance: u64,
+) -> (
+    mpl_auctioneer::accounts::CreateBidQualification,
+    Transaction,
+) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (bid_qualification, _) = find_bid_qualification_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBidQualification {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        bid_qualification,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBidQualification {
+        token_size: 1,
+        min_escrow_balance,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_bidder_allowlist(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    bidders: Vec<Pubkey>,
+    merkle_root: [u8; 32],
+) -> (mpl_auctioneer::accounts::CreateBidderAllowlist, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (bidder_allowlist, _) = find_bidder_allowlist_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBidderAllowlist {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        bidder_allowlist,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBidderAllowlist {
+        token_size: 1,
+        bidders,
+        merkle_root,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn accept_bidder_invitation(
+    context: &mut ProgramTestContext,
+    wallet: &Keypair,
+    listing_config: &Pubkey,
+    proof: Vec<[u8; 32]>,
+) -> (
+    mpl_auctioneer::accounts::AcceptBidderInvitation,
+    Transaction,
+) {
+    let accounts = mpl_auctioneer::accounts::AcceptBidderInvitation {
+        wallet: wallet.pubkey(),
+        bidder_allowlist: find_bidder_allowlist_address(listing_config).0,
+        bidder_invitation: find_bidder_invitation_address(listing_config, &wallet.pubkey()).0,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::AcceptBidderInvitation { proof }.data(),
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&wallet.pubkey()),
+            &[wallet],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_bid_rate_limit(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    min_slot_gap: u64,
+    max_bids_per_wallet: u32,
+) -> (mpl_auctioneer::accounts::CreateBidRateLimit, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (bid_rate_limit, _) = find_bid_rate_limit_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBidRateLimit {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        bid_rate_limit,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBidRateLimit {
+        token_size: 1,
+        min_slot_gap,
+        max_bids_per_wallet,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_blind_box(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    reveal_program: &Pubkey,
+) -> (mpl_auctioneer::accounts::CreateBlindBox, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (blind_box, _) = find_blind_box_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBlindBox {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        token_mint: test_metadata.mint.pubkey(),
+        blind_box,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBlindBox {
+        token_size: 1,
+        reveal_program: *reveal_program,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn reveal_blind_box(
+    context: &mut ProgramTestContext,
+    payer: &Keypair,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    buyer: &Pubkey,
+    reveal_program: &Pubkey,
+    reveal_accounts: Vec<AccountMeta>,
+) -> (mpl_auctioneer::accounts::RevealBlindBox, Transaction) {
+    let (blind_box, _) = find_blind_box_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::RevealBlindBox {
+        blind_box,
+        seller: test_metadata.token.pubkey(),
+        buyer: *buyer,
+        token_mint: test_metadata.mint.pubkey(),
+        metadata: test_metadata.pubkey,
+        reveal_program: *reveal_program,
+    };
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(reveal_accounts);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::RevealBlindBox {}.data(),
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_auto_accept(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    price: u64,
+) -> (mpl_auctioneer::accounts::CreateAutoAccept, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (auto_accept, _) = find_auto_accept_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateAutoAccept {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        auto_accept,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateAutoAccept {
+        token_size: 1,
+        price,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_backup_bids(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+) -> (mpl_auctioneer::accounts::CreateBackupBids, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (backup_bids, _) = find_backup_bids_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBackupBids {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        backup_bids,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBackupBids { token_size: 1 }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_auction_summary(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    start_time: UnixTimestamp,
+) -> (mpl_auctioneer::accounts::CreateAuctionSummary, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (auction_summary, _) = find_auction_summary_address(listing_config, start_time);
+
+    let accounts = mpl_auctioneer::accounts::CreateAuctionSummary {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        auction_summary,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateAuctionSummary { token_size: 1 }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn promote_backup_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    buyer: &Pubkey,
+    buyer_price: u64,
+    payer: &Keypair,
+) -> (mpl_auctioneer::accounts::PromoteBackupBid, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, buyer);
+    let (buyer_trade_state, _) = find_trade_state_address(
+        buyer,
+        ahkey,
+        &token,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        buyer_price,
+        1,
+    );
+
+    let accounts = mpl_auctioneer::accounts::PromoteBackupBid {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: *listing_config,
+        backup_bids: find_backup_bids_address(listing_config).0,
+        seller: test_metadata.token.pubkey(),
+        token_account: token,
+        token_mint: test_metadata.mint.pubkey(),
+        buyer: *buyer,
+        escrow_payment_account,
+        buyer_trade_state,
+        auction_house: *ahkey,
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::PromoteBackupBid { token_size: 1 }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_buy_now(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    listing_config: &Pubkey,
+    price: u64,
+) -> (mpl_auctioneer::accounts::CreateBuyNow, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (buy_now, _) = find_buy_now_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBuyNow {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        buy_now,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBuyNow {
+        token_size: 1,
+        price,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn buy_now(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    buyer: &Keypair,
+    seller: &Pubkey,
+    listing_config: &Pubkey,
+    price: u64,
+) -> (mpl_auctioneer::accounts::AuctioneerBuyNow, Transaction) {
+    let seller_token_account = get_associated_token_address(seller, &test_metadata.mint.pubkey());
+    let (buyer_trade_state, trade_state_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &seller_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        price,
+        1,
+    );
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (escrow, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+    let accounts = mpl_auctioneer::accounts::AuctioneerBuyNow {
+        auction_house_program: mpl_auction_house::id(),
+        listing_config: *listing_config,
+        buy_now: find_buy_now_address(listing_config).0,
+        seller: *seller,
+        wallet: buyer.pubkey(),
+        payment_account: buyer.pubkey(),
+        transfer_authority: buyer.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        token_account: seller_token_account,
+        metadata: test_metadata.pubkey,
+        escrow_payment_account: escrow,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        buyer_trade_state,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: solana_program::system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::BuyNow {
+        trade_state_bump,
+        escrow_payment_bump: escrow_bump,
+        auctioneer_authority_bump: aa_bump,
+        token_size: 1,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&buyer.pubkey()),
+            &[buyer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn update_auctioneer_scopes(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    scopes: Vec<AuthorityScope>,
+) -> Transaction {
+    let (auctioneer_authority, _) = find_auctioneer_authority_seeds(ahkey);
+    let (ah_auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auction_house::accounts::UpdateAuctioneer {
+        auction_house: *ahkey,
+        authority: authority.pubkey(),
+        auctioneer_authority,
+        ah_auctioneer_pda,
+        system_program: system_program::id(),
+    };
+
+    let instruction = Instruction {
+        program_id: mpl_auction_house::id(),
+        data: mpl_auction_house::instruction::UpdateAuctioneer { scopes }.data(),
+        accounts: accounts.to_account_metas(None),
+    };
+
+    Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    )
+}
+
+pub fn pause_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    sell_acc: &mpl_auctioneer::accounts::AuctioneerSell,
+    listing_config: &Pubkey,
+) -> (mpl_auctioneer::accounts::PauseListing, Transaction) {
+    let (listing_pause, _) = find_listing_pause_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::PauseListing {
+        auction_house_program: mpl_auction_house::id(),
+        authority: authority.pubkey(),
+        wallet: sell_acc.wallet,
+        token_account: sell_acc.token_account,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        listing_pause,
+        auctioneer_authority: sell_acc.auctioneer_authority,
+        ah_auctioneer_pda: sell_acc.ah_auctioneer_pda,
+        system_program: system_program::id(),
+    };
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::PauseListing { token_size: 1 }.data(),
+        accounts: accounts.to_account_metas(None),
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn resume_listing(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    sell_acc: &mpl_auctioneer::accounts::AuctioneerSell,
+    listing_config: &Pubkey,
+) -> (mpl_auctioneer::accounts::ResumeListing, Transaction) {
+    let (listing_pause, _) = find_listing_pause_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::ResumeListing {
+        auction_house_program: mpl_auction_house::id(),
+        authority: authority.pubkey(),
+        wallet: sell_acc.wallet,
+        token_account: sell_acc.token_account,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        listing_pause,
+        auctioneer_authority: sell_acc.auctioneer_authority,
+        ah_auctioneer_pda: sell_acc.ah_auctioneer_pda,
+    };
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::ResumeListing { token_size: 1 }.data(),
+        accounts: accounts.to_account_metas(None),
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&authority.pubkey()),
+            &[authority],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn create_bid_pool(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    test_metadata: &Metadata,
+    payer: &Keypair,
+    listing_config: &Pubkey,
+) -> (mpl_auctioneer::accounts::CreateBidPool, Transaction) {
+    let token =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (bid_pool, _) = find_bid_pool_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::CreateBidPool {
+        auction_house_program: mpl_auction_house::id(),
+        payer: payer.pubkey(),
+        seller: test_metadata.token.pubkey(),
+        token_account: token,
+        auction_house: *ahkey,
+        listing_config: *listing_config,
+        bid_pool,
+        bid_pool_vault: find_bid_pool_vault_address(&bid_pool).0,
+        share_mint: find_bid_pool_share_mint_address(&bid_pool).0,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateBidPool { token_size: 1 }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn contribute_to_pool(
+    context: &mut ProgramTestContext,
+    contributor: &Keypair,
+    listing_config: &Pubkey,
+    amount: u64,
+) -> (mpl_auctioneer::accounts::ContributeToPool, Transaction) {
+    let (bid_pool, _) = find_bid_pool_address(listing_config);
+
+    let accounts = mpl_auctioneer::accounts::ContributeToPool {
+        contributor: contributor.pubkey(),
+        listing_config: *listing_config,
+        bid_pool,
+        bid_pool_vault: find_bid_pool_vault_address(&bid_pool).0,
+        pool_contribution: find_pool_contribution_address(&bid_pool, &contributor.pubkey()).0,
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::ContributeToPool { amount }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&contributor.pubkey()),
+            &[contributor],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn place_pool_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    payer: &Keypair,
+    listing_config: &Pubkey,
+    pool_total: u64,
+) -> (mpl_auctioneer::accounts::PlacePoolBid, Transaction) {
+    let seller = test_metadata.token.pubkey();
+    let seller_token_account = get_associated_token_address(&seller, &test_metadata.mint.pubkey());
+    let (bid_pool, _) = find_bid_pool_address(listing_config);
+    let (bid_pool_vault, _) = find_bid_pool_vault_address(&bid_pool);
+    let (bts, bts_bump) = find_trade_state_address(
+        &bid_pool_vault,
+        ahkey,
+        &seller_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        pool_total,
+        1,
+    );
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (escrow, escrow_bump) = find_escrow_payment_address(ahkey, &bid_pool_vault);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::PlacePoolBid {
+        auction_house_program: mpl_auction_house::id(),
+        payer: payer.pubkey(),
+        listing_config: *listing_config,
+        bid_pool,
+        bid_pool_vault,
+        seller,
+        treasury_mint: ah.treasury_mint,
+        token_account: seller_token_account,
+        metadata: test_metadata.pubkey,
+        escrow_payment_account: escrow,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        buyer_trade_state: bts,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::PlacePoolBid {
+        trade_state_bump: bts_bump,
+        escrow_payment_bump: escrow_bump,
+        auctioneer_authority_bump: aa_bump,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn place_pseudonymous_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    payer: &Keypair,
+    listing_config: &Pubkey,
+    commitment: [u8; 32],
+    buyer_price: u64,
+) -> (mpl_auctioneer::accounts::PlacePseudonymousBid, Transaction) {
+    place_pseudonymous_bid_with_features(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        payer,
+        listing_config,
+        commitment,
+        buyer_price,
+        &ListingFeatures::default(),
+    )
+}
+
+/// Bid pseudonymously on a listing set up with `features`, passing their feature accounts.
+pub fn place_pseudonymous_bid_with_features(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    payer: &Keypair,
+    listing_config: &Pubkey,
+    commitment: [u8; 32],
+    buyer_price: u64,
+    features: &ListingFeatures,
+) -> (mpl_auctioneer::accounts::PlacePseudonymousBid, Transaction) {
+    let seller = test_metadata.token.pubkey();
+    let seller_token_account = get_associated_token_address(&seller, &test_metadata.mint.pubkey());
+    let (pseudonymous_bidder, _) = find_pseudonymous_bidder_address(listing_config, &commitment);
+    let (pseudonymous_vault, _) = find_pseudonymous_vault_address(&pseudonymous_bidder);
+    let (bts, bts_bump) = find_trade_state_address(
+        &pseudonymous_vault,
+        ahkey,
+        &seller_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        buyer_price,
+        1,
+    );
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (escrow, escrow_bump) = find_escrow_payment_address(ahkey, &pseudonymous_vault);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::PlacePseudonymousBid {
+        auction_house_program: mpl_auction_house::id(),
+        payer: payer.pubkey(),
+        listing_config: *listing_config,
+        pseudonymous_bidder,
+        pseudonymous_vault,
+        seller,
+        treasury_mint: ah.treasury_mint,
+        token_account: seller_token_account,
+        metadata: test_metadata.pubkey,
+        escrow_payment_account: escrow,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        buyer_trade_state: bts,
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    };
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.extend(bid_feature_account_metas(
+        listing_config,
+        &pseudonymous_vault,
+        features,
+    ));
+
+    let data = mpl_auctioneer::instruction::PlacePseudonymousBid {
+        trade_state_bump: bts_bump,
+        escrow_payment_bump: escrow_bump,
+        auctioneer_authority_bump: aa_bump,
+        commitment,
+        buyer_price,
+        token_size: 1,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn claim_pseudonymous_bid(
+    context: &mut ProgramTestContext,
+    test_metadata: &Metadata,
+    wallet: &Keypair,
+    listing_config: &Pubkey,
+    commitment: [u8; 32],
+    salt: [u8; 32],
+) -> (mpl_auctioneer::accounts::ClaimPseudonymousBid, Transaction) {
+    let token_mint = test_metadata.mint.pubkey();
+    let (pseudonymous_bidder, _) = find_pseudonymous_bidder_address(listing_config, &commitment);
+    let (pseudonymous_vault, _) = find_pseudonymous_vault_address(&pseudonymous_bidder);
+
+    let accounts = mpl_auctioneer::accounts::ClaimPseudonymousBid {
+        wallet: wallet.pubkey(),
+        pseudonymous_bidder,
+        pseudonymous_vault,
+        vault_token_account: get_associated_token_address(&pseudonymous_vault, &token_mint),
+        token_mint,
+        wallet_token_account: get_associated_token_address(&wallet.pubkey(), &token_mint),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: mpl_auctioneer::instruction::ClaimPseudonymousBid { salt }.data(),
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&wallet.pubkey()),
+            &[wallet],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn place_sealed_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    bidder: &Keypair,
+    listing_config: &Pubkey,
+    commitment: [u8; 32],
+    ciphertext: Vec<u8>,
+) -> (mpl_auctioneer::accounts::PlaceSealedBid, Transaction) {
+    let (private_room, _) = find_private_room_address(listing_config);
+    let (sealed_bid, _) = find_sealed_bid_address(listing_config, &bidder.pubkey());
+    let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, &bidder.pubkey());
+
+    let accounts = mpl_auctioneer::accounts::PlaceSealedBid {
+        bidder: bidder.pubkey(),
+        listing_config: *listing_config,
+        private_room,
+        auction_house: *ahkey,
+        escrow_payment_account,
+        sealed_bid,
+        system_program: system_program::id(),
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::PlaceSealedBid {
+        commitment,
+        ciphertext,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&bidder.pubkey()),
+            &[bidder],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn reveal_sealed_bid(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    committee: &Keypair,
+    listing_config: &Pubkey,
+    bidder: &Pubkey,
+    amount: u64,
+    salt: [u8; 32],
+) -> (mpl_auctioneer::accounts::RevealSealedBid, Transaction) {
+    let (private_room, _) = find_private_room_address(listing_config);
+    let (sealed_bid, _) = find_sealed_bid_address(listing_config, bidder);
+    let (escrow_payment_account, _) = find_escrow_payment_address(ahkey, bidder);
+
+    let accounts = mpl_auctioneer::accounts::RevealSealedBid {
+        committee: committee.pubkey(),
+        listing_config: *listing_config,
+        private_room,
+        auction_house: *ahkey,
+        sealed_bid,
+        escrow_payment_account,
+    };
+    let account_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::RevealSealedBid { amount, salt }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: account_metas,
+    };
+
+    (
+        accounts,
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&committee.pubkey()),
+            &[committee],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub async fn create_drop_schedule(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    authority: &Keypair,
+    start_time: UnixTimestamp,
+    interval: i64,
+    listing_duration: i64,
+) -> StdResult<(), BanksClientError> {
+    let (drop_schedule, _) = find_drop_schedule_address(ahkey, &authority.pubkey());
+    let (drop_vault, _) = find_drop_vault_address(&drop_schedule);
+
+    let accounts = mpl_auctioneer::accounts::CreateDropSchedule {
+        auction_house_program: mpl_auction_house::id(),
+        authority: authority.pubkey(),
+        auction_house: *ahkey,
+        drop_schedule,
+        drop_vault,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateDropSchedule {
+        start_time,
+        interval,
+        listing_duration,
+        reserve_price: None,
+        min_bid_increment: None,
+        time_ext_period: None,
+        time_ext_delta: None,
+        allow_high_bid_cancel: None,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority.pubkey()),
+        &[authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub async fn create_end_time_index(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    bucket_start: i64,
+) -> StdResult<Pubkey, BanksClientError> {
+    let (end_time_index, _) = find_end_time_index_address(ahkey, bucket_start);
+
+    let accounts = mpl_auctioneer::accounts::CreateEndTimeIndex {
+        auction_house_program: mpl_auction_house::id(),
+        payer: context.payer.pubkey(),
+        auction_house: *ahkey,
+        end_time_index,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::CreateEndTimeIndex { bucket_start }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .map(|_| end_time_index)
+}
+
+/// Build a `sell` transaction that also records the listing in `end_time_index`.
+pub fn sell_with_end_time_index(
+    context: &mut ProgramTestContext,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    start_time: UnixTimestamp,
+    end_time: UnixTimestamp,
+    end_time_index: &Pubkey,
+) -> (
+    (mpl_auctioneer::accounts::AuctioneerSell, Pubkey),
+    Transaction,
+) {
+    let ((accounts, listing_config_address), sell_tx) = sell(
+        context,
+        ahkey,
+        ah,
+        test_metadata,
+        start_time,
+        end_time,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    let mut account_metas = accounts.to_account_metas(None);
+    account_metas.push(AccountMeta::new(*end_time_index, false));
+    account_metas.push(lien_account_meta(ahkey, &test_metadata.mint.pubkey()));
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data: sell_tx.message.instructions[0].data.clone(),
+        accounts: account_metas,
+    };
+
+    (
+        (accounts, listing_config_address),
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&test_metadata.token.pubkey()),
+            &[&test_metadata.token],
+            context.last_blockhash,
+        ),
+    )
+}
+
+pub fn withdraw(
+    context: &mut ProgramTestContext,
+    buyer: &Keypair,
+    ahkey: &Pubkey,
+    ah: &AuctionHouse,
+    test_metadata: &Metadata,
+    sale_price: u64,
+    withdraw_amount: u64,
+) -> ((mpl_auctioneer::accounts::AuctioneerWithdraw,), Transaction) {
+    let seller_token_account =
+        get_associated_token_address(&test_metadata.token.pubkey(), &test_metadata.mint.pubkey());
+    let (_buyer_trade_state, _sts_bump) = find_trade_state_address(
+        &buyer.pubkey(),
+        ahkey,
+        &seller_token_account,
+        &ah.treasury_mint,
+        &test_metadata.mint.pubkey(),
+        sale_price,
+        1,
+    );
+    let (escrow_payment_account, escrow_bump) = find_escrow_payment_address(ahkey, &buyer.pubkey());
+    let (auctioneer_authority, aa_bump) = find_auctioneer_authority_seeds(ahkey);
+    let (auctioneer_pda, _) = find_auctioneer_pda(ahkey, &auctioneer_authority);
+
+    let accounts = mpl_auctioneer::accounts::AuctioneerWithdraw {
+        auction_house_program: mpl_auction_house::id(),
+        wallet: buyer.pubkey(),
+        escrow_payment_account,
+        receipt_account: buyer.pubkey(),
+        treasury_mint: ah.treasury_mint,
+        authority: ah.authority,
+        auction_house: *ahkey,
+        auction_house_fee_account: ah.auction_house_fee_account,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+        auctioneer_authority,
+        ah_auctioneer_pda: auctioneer_pda,
+    };
+
+    let accounts_metas = accounts.to_account_metas(None);
+
+    let data = mpl_auctioneer::instruction::Withdraw {
+        escrow_payment_bump: escrow_bump,
+        auctioneer_authority_bump: aa_bump,
+        amount: withdraw_amount,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_auctioneer::id(),
+        data,
+        accounts: accounts_metas,
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&buyer.pubkey()),
+        &[buyer],
+        context.last_blockhash,
+    );
+
+    ((accounts,), tx)
+}
+
+pub async fn existing_auction_house_test_context(
+    context: &mut ProgramTestContext,
+) -> StdResult<(AuctionHouse, Pubkey, Keypair), BanksClientError> {
+    let twd_key = context.payer.pubkey();
+    let fwd_key = context.payer.pubkey();
+    let t_mint_key = spl_token::native_mint::id();
+    let tdw_ata = twd_key;
+    let seller_fee_basis_points: u16 = 100;
+    let authority = Keypair::new();
+    airdrop(context, &authority.pubkey(), 10_000_000_000).await?;
+    // Derive Auction House Key
+    let (auction_house_address, bump) =
+        find_auction_house_address(&authority.pubkey(), &t_mint_key);
+    let (auction_fee_account_key, fee_payer_bump) =
+        find_auction_house_fee_account_address(&auction_house_address);
+    // Derive Auction House Treasury Key
+    let (auction_house_treasury_key, treasury_bump) =
+        find_auction_house_treasury_address(&auction_house_address);
+    let auction_house = create_auction_house(
+        context,
+        &authority,
+        &twd_key,
+        &fwd_key,
+        &t_mint_key,
+        &tdw_ata,
+        &auction_house_address,
+        bump,
+        &auction_fee_account_key,
+        fee_payer_bump,
+        &auction_house_treasury_key,
+        treasury_bump,
+        seller_fee_basis_points,
+        false,
         false,
         false,
     );
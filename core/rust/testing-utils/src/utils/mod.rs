@@ -2,12 +2,14 @@ mod edition_marker;
 mod external_price;
 mod master_edition_v2;
 mod metadata;
+mod scripted_bidder;
 mod vault;
 
 pub use edition_marker::EditionMarker;
 pub use external_price::ExternalPrice;
 pub use master_edition_v2::MasterEditionV2;
 pub use metadata::Metadata;
+pub use scripted_bidder::{AuctionDriver, BidderScript, ScriptReport, ScriptStep};
 use solana_program_test::*;
 use solana_sdk::{
     account::Account, program_pack::Pack, pubkey::Pubkey, signature::Signer,
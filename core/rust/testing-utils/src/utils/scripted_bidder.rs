@@ -0,0 +1,175 @@
+use crate::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{
+    pubkey::Pubkey, signature::Signer, signer::keypair::Keypair, transaction::Transaction,
+};
+
+/// Builds the transactions a [`BidderScript`] sends, for the auction program under test.
+pub trait AuctionDriver {
+    /// Place a bid of `price` from `bidder`.
+    fn bid(&self, context: &mut ProgramTestContext, bidder: &Keypair, price: u64) -> Transaction;
+
+    /// Cancel the bid of `price` placed by `bidder`.
+    fn cancel(&self, context: &mut ProgramTestContext, bidder: &Keypair, price: u64)
+        -> Transaction;
+
+    /// Settle the auction to `winner` at `price`.
+    fn settle(&self, context: &mut ProgramTestContext, winner: &Keypair, price: u64)
+        -> Transaction;
+
+    /// Escrow account holding the bid funds of `bidder`.
+    fn escrow_account(&self, bidder: &Pubkey) -> Pubkey;
+
+    /// Account that records the open bid of `bidder` at `price`, e.g. its trade state.
+    fn receipt_account(&self, bidder: &Pubkey, price: u64) -> Pubkey;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptStep {
+    Bid { bidder: usize, price: u64 },
+    Cancel { bidder: usize, price: u64 },
+    WarpToSlot(u64),
+    Settle { bidder: usize, price: u64 },
+}
+
+/// A multi-party auction scenario: a set of funded bidders and the steps they take, in order.
+#[derive(Debug)]
+pub struct BidderScript {
+    pub bidders: Vec<Keypair>,
+    pub steps: Vec<ScriptStep>,
+}
+
+impl BidderScript {
+    /// Create `count` bidders, each airdropped `lamports`.
+    pub async fn new(
+        context: &mut ProgramTestContext,
+        count: usize,
+        lamports: u64,
+    ) -> Result<Self, BanksClientError> {
+        let mut bidders = Vec::with_capacity(count);
+        for _ in 0..count {
+            let bidder = Keypair::new();
+            airdrop(context, &bidder.pubkey(), lamports).await?;
+            bidders.push(bidder);
+        }
+
+        Ok(Self {
+            bidders,
+            steps: vec![],
+        })
+    }
+
+    pub fn bid(&mut self, bidder: usize, price: u64) -> &mut Self {
+        self.steps.push(ScriptStep::Bid { bidder, price });
+        self
+    }
+
+    /// Bid above every bid placed so far in the script.
+    pub fn outbid(&mut self, bidder: usize, price: u64) -> &mut Self {
+        assert!(
+            price > self.highest_bid(),
+            "outbid of {} does not beat the highest bid",
+            price
+        );
+        self.bid(bidder, price)
+    }
+
+    pub fn cancel(&mut self, bidder: usize, price: u64) -> &mut Self {
+        self.steps.push(ScriptStep::Cancel { bidder, price });
+        self
+    }
+
+    pub fn warp_to_slot(&mut self, slot: u64) -> &mut Self {
+        self.steps.push(ScriptStep::WarpToSlot(slot));
+        self
+    }
+
+    pub fn settle(&mut self, bidder: usize, price: u64) -> &mut Self {
+        self.steps.push(ScriptStep::Settle { bidder, price });
+        self
+    }
+
+    pub fn highest_bid(&self) -> u64 {
+        self.steps
+            .iter()
+            .filter_map(|step| match step {
+                ScriptStep::Bid { price, .. } => Some(*price),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Send every step through `driver`, stopping at the first failed transaction, and report the
+    /// escrow balances and receipts of all bidders afterwards.
+    pub async fn run<D: AuctionDriver>(
+        &self,
+        context: &mut ProgramTestContext,
+        driver: &D,
+    ) -> Result<ScriptReport, BanksClientError> {
+        let mut last_bids: Vec<Option<u64>> = vec![None; self.bidders.len()];
+
+        for step in &self.steps {
+            let tx = match *step {
+                ScriptStep::Bid { bidder, price } => {
+                    last_bids[bidder] = Some(price);
+                    driver.bid(context, &self.bidders[bidder], price)
+                }
+                ScriptStep::Cancel { bidder, price } => {
+                    driver.cancel(context, &self.bidders[bidder], price)
+                }
+                ScriptStep::Settle { bidder, price } => {
+                    driver.settle(context, &self.bidders[bidder], price)
+                }
+                ScriptStep::WarpToSlot(slot) => {
+                    context.warp_to_slot(slot).unwrap();
+                    continue;
+                }
+            };
+            context.banks_client.process_transaction(tx).await?;
+        }
+
+        let mut escrow_balances = Vec::with_capacity(self.bidders.len());
+        let mut receipts = Vec::with_capacity(self.bidders.len());
+        for (bidder, last_bid) in self.bidders.iter().zip(last_bids) {
+            let escrow = driver.escrow_account(&bidder.pubkey());
+            escrow_balances.push(context.banks_client.get_balance(escrow).await?);
+
+            let receipt = match last_bid {
+                Some(price) => {
+                    let receipt = driver.receipt_account(&bidder.pubkey(), price);
+                    context.banks_client.get_account(receipt).await?.is_some()
+                }
+                None => false,
+            };
+            receipts.push(receipt);
+        }
+
+        Ok(ScriptReport {
+            escrow_balances,
+            receipts,
+        })
+    }
+}
+
+/// The state of every bidder after a [`BidderScript`] has run, indexed like its bidders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptReport {
+    pub escrow_balances: Vec<u64>,
+    /// Whether the receipt account of each bidder's last bid is still open.
+    pub receipts: Vec<bool>,
+}
+
+impl ScriptReport {
+    pub fn assert_escrow_balance(&self, bidder: usize, expected: u64) {
+        assert_eq!(
+            self.escrow_balances[bidder], expected,
+            "escrow balance of bidder {}",
+            bidder
+        );
+    }
+
+    pub fn assert_receipt_open(&self, bidder: usize, open: bool) {
+        assert_eq!(self.receipts[bidder], open, "receipt of bidder {}", bidder);
+    }
+}